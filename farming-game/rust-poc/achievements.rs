@@ -0,0 +1,139 @@
+// src/achievements.rs
+// Milestones players earn across games. Unlocks are read off the game's
+// event stream and kept in a small file in the config directory.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::game::GameEvent;
+use crate::models::{GameState, Money};
+use crate::settings;
+
+/// Name of the achievements file inside the config directory.
+const ACHIEVEMENTS_FILE: &str = "achievements";
+
+/// A game must be won before this year for a quick win.
+pub const QUICK_WIN_YEARS: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Achievement {
+    /// Leased a ridge for the first time
+    FirstRidgeLease,
+    /// Ended a year owing the bank nothing
+    DebtFreeYear,
+    /// Still had cows on the farm after rolling for the Mt. St. Helens ash
+    SurvivedMtStHelens,
+    /// Won a game before year `QUICK_WIN_YEARS`
+    QuickWin,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 4] = [
+        Achievement::FirstRidgeLease,
+        Achievement::DebtFreeYear,
+        Achievement::SurvivedMtStHelens,
+        Achievement::QuickWin,
+    ];
+
+    /// Stable name used in the achievements file and locale keys.
+    pub fn id(self) -> &'static str {
+        match self {
+            Achievement::FirstRidgeLease => "first_ridge_lease",
+            Achievement::DebtFreeYear => "debt_free_year",
+            Achievement::SurvivedMtStHelens => "survived_mt_st_helens",
+            Achievement::QuickWin => "quick_win",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|achievement| achievement.id() == id)
+    }
+
+    /// The achievement `event` earns, if any.
+    pub fn earned_by(event: &GameEvent) -> Option<Self> {
+        match event {
+            GameEvent::RidgeLeased { .. } => Some(Achievement::FirstRidgeLease),
+            GameEvent::YearEnded { debt: Money::ZERO, .. } => Some(Achievement::DebtFreeYear),
+            GameEvent::AshRolled { cows, .. } if *cows > 0 => Some(Achievement::SurvivedMtStHelens),
+            GameEvent::GameWon { year, .. } if *year < QUICK_WIN_YEARS => Some(Achievement::QuickWin),
+            _ => None,
+        }
+    }
+}
+
+/// Achievements unlocked so far, by player name, persisted between games.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Achievements {
+    unlocked: BTreeSet<(Achievement, String)>,
+}
+
+impl Achievements {
+    /// Location of the achievements file, next to the settings file.
+    fn file_path() -> Option<PathBuf> {
+        settings::config_dir().map(|dir| dir.join(ACHIEVEMENTS_FILE))
+    }
+
+    /// Loads saved achievements; a missing or unreadable file means none yet.
+    pub fn load() -> Self {
+        Self::file_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// Writes the unlocked achievements to disk.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::file_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory on this platform"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.serialize())
+    }
+
+    pub(crate) fn parse(contents: &str) -> Self {
+        let mut achievements = Self::default();
+        for line in contents.lines() {
+            let Some((id, player)) = line.split_once('=') else { continue };
+            if let Some(achievement) = Achievement::from_id(id.trim()) {
+                achievements.unlocked.insert((achievement, player.trim().to_string()));
+            }
+        }
+        achievements
+    }
+
+    pub(crate) fn serialize(&self) -> String {
+        self.unlocked.iter()
+            .map(|(achievement, player)| format!("{}={}\n", achievement.id(), player))
+            .collect()
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement, player: &str) -> bool {
+        self.unlocked.contains(&(achievement, player.to_string()))
+    }
+
+    /// Names of the players who have unlocked `achievement`.
+    pub fn holders(&self, achievement: Achievement) -> Vec<&str> {
+        self.unlocked.iter()
+            .filter(|(unlocked, _)| *unlocked == achievement)
+            .map(|(_, player)| player.as_str())
+            .collect()
+    }
+
+    /// Unlocks whatever `events` earn and returns the new unlocks with the
+    /// name of the player who earned each.
+    pub fn observe(&mut self, game: &GameState, events: &[GameEvent]) -> Vec<(Achievement, String)> {
+        let mut new_unlocks = Vec::new();
+        for event in events {
+            let Some(achievement) = Achievement::earned_by(event) else { continue };
+            let Some(player) = game.players.get(event.player_id()) else { continue };
+            if self.unlocked.insert((achievement, player.name.clone())) {
+                tracing::info!(achievement = achievement.id(), player = %player.name, "achievement unlocked");
+                new_unlocks.push((achievement, player.name.clone()));
+            }
+        }
+        new_unlocks
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use crate::achievements::{Achievement, Achievements};
+    use crate::config::GameRules;
+    use crate::game::GameEvent;
+    use crate::models::{GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Ann".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Bo".to_string(), PlayerType::Human));
+        GameState::new_with_players(players, vec![0, 1], GameRules::default())
+    }
+
+    #[test]
+    fn test_events_unlock_achievements_once_per_player() {
+        let game = setup_game();
+        let mut achievements = Achievements::default();
+        let events = vec![
+            GameEvent::YearEnded { player_id: 0, year: 1, cash: Money(100), debt: Money(5000) },
+            GameEvent::RidgeLeased { player_id: 1, ridge: "Toppenish Ridge".to_string() },
+            GameEvent::AshRolled { player_id: 0, escaped: false, cows: 0 },
+            GameEvent::AshRolled { player_id: 1, escaped: true, cows: 10 },
+            GameEvent::YearEnded { player_id: 0, year: 3, cash: Money(100), debt: Money::ZERO },
+            GameEvent::GameWon { player_id: 1, year: 12 },
+        ];
+
+        let unlocked = achievements.observe(&game, &events);
+        assert_eq!(unlocked, vec![
+            (Achievement::FirstRidgeLease, "Bo".to_string()),
+            (Achievement::SurvivedMtStHelens, "Bo".to_string()),
+            (Achievement::DebtFreeYear, "Ann".to_string()),
+        ]);
+        assert!(!achievements.is_unlocked(Achievement::QuickWin, "Bo"), "Year 12 is too slow");
+
+        let again = achievements.observe(&game, &[GameEvent::RidgeLeased { player_id: 1, ridge: "Cascade Ridge".to_string() }]);
+        assert!(again.is_empty(), "Already unlocked");
+        let quick = achievements.observe(&game, &[GameEvent::GameWon { player_id: 0, year: 9 }]);
+        assert_eq!(quick, vec![(Achievement::QuickWin, "Ann".to_string())]);
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let game = setup_game();
+        let mut achievements = Achievements::default();
+        achievements.observe(&game, &[
+            GameEvent::RidgeLeased { player_id: 0, ridge: "Toppenish Ridge".to_string() },
+            GameEvent::GameWon { player_id: 1, year: 5 },
+        ]);
+
+        let saved = achievements.serialize();
+        assert_eq!(Achievements::parse(&saved), achievements);
+        assert_eq!(Achievements::parse("quick_win=Bo\nnonsense\nunknown=Ann\n").holders(Achievement::QuickWin), vec!["Bo"]);
+    }
+}
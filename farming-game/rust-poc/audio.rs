@@ -0,0 +1,113 @@
+// src/audio.rs
+// Short sound cues for the big moments of a game, picked off the event
+// stream. Tones are only played in builds with the `audio` feature; other
+// builds (and machines without a sound device) stay silent.
+
+use std::time::Duration;
+
+use crate::game::{EffectCategory, GameEvent};
+use crate::models::Money;
+
+/// A sound played when something happens in the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SoundCue {
+    DiceRoll,
+    HarvestPayout,
+    /// A lost year, slaughtered herd or natural disaster card
+    Disaster,
+    Victory,
+}
+
+impl SoundCue {
+    /// The cue `event` plays, if any.
+    pub fn for_event(event: &GameEvent) -> Option<Self> {
+        match event {
+            GameEvent::DiceRolled { .. } => Some(SoundCue::DiceRoll),
+            GameEvent::HarvestCollected { income, .. } if *income > Money::ZERO => Some(SoundCue::HarvestPayout),
+            GameEvent::CardPlayed { category: EffectCategory::Disaster, .. } => Some(SoundCue::Disaster),
+            GameEvent::GameWon { .. } => Some(SoundCue::Victory),
+            _ => None,
+        }
+    }
+
+    /// Notes of the cue as (frequency in Hz, length).
+    pub fn notes(self) -> &'static [(f32, Duration)] {
+        const SHORT: Duration = Duration::from_millis(60);
+        const LONG: Duration = Duration::from_millis(180);
+        match self {
+            SoundCue::DiceRoll => &[(660.0, SHORT), (880.0, SHORT)],
+            SoundCue::HarvestPayout => &[(523.0, SHORT), (659.0, SHORT), (784.0, LONG)],
+            SoundCue::Disaster => &[(220.0, LONG), (165.0, LONG)],
+            SoundCue::Victory => &[(523.0, SHORT), (659.0, SHORT), (784.0, SHORT), (1047.0, LONG)],
+        }
+    }
+}
+
+/// Cues for a batch of events, each played at most once and in the order of
+/// `SoundCue` so a win isn't drowned out by the roll that led to it.
+pub fn cues_for(events: &[GameEvent]) -> Vec<SoundCue> {
+    let mut cues: Vec<SoundCue> = events.iter().filter_map(SoundCue::for_event).collect();
+    cues.sort();
+    cues.dedup();
+    cues
+}
+
+/// Plays cues on the default output device.
+pub struct Audio {
+    muted: bool,
+    #[cfg(feature = "audio")]
+    output: Option<(rodio::OutputStream, rodio::OutputStreamHandle)>,
+}
+
+impl Audio {
+    /// Opens the default output device; without one (or without the `audio`
+    /// feature) cues are silently dropped.
+    pub fn new() -> Self {
+        Self {
+            muted: false,
+            #[cfg(feature = "audio")]
+            output: rodio::OutputStream::try_default().ok(),
+        }
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    pub fn toggle_mute(&mut self) {
+        self.muted = !self.muted;
+    }
+
+    /// Plays the cues for `events` unless muted.
+    pub fn play_events(&self, events: &[GameEvent]) {
+        if self.muted {
+            return;
+        }
+        for cue in cues_for(events) {
+            self.play(cue);
+        }
+    }
+
+    #[cfg(feature = "audio")]
+    fn play(&self, cue: SoundCue) {
+        use rodio::{source::SineWave, Sink, Source};
+        let Some((_, handle)) = &self.output else { return };
+        let Ok(sink) = Sink::try_new(handle) else { return };
+        for (frequency, length) in cue.notes() {
+            sink.append(SineWave::new(*frequency).take_duration(*length).amplify(0.2));
+        }
+        // Let the cue finish on its own while the game carries on
+        sink.detach();
+    }
+
+    #[cfg(not(feature = "audio"))]
+    fn play(&self, cue: SoundCue) {
+        tracing::trace!(?cue, "sound cue (audio feature off)");
+    }
+}
+
+impl Default for Audio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use crate::audio::{cues_for, Audio, SoundCue};
+    use crate::game::{EffectCategory, GameEvent};
+    use crate::models::HarvestType;
+    use crate::models::Money;
+
+    #[test]
+    fn test_cues_follow_the_event_stream() {
+        let events = vec![
+            GameEvent::DiceRolled { player_id: 0, roll: 4 },
+            GameEvent::HarvestCollected { player_id: 0, harvest: HarvestType::Wheat, income: Money::ZERO },
+            GameEvent::CardPlayed { player_id: 0, card: "Windfall".to_string(), category: EffectCategory::Income },
+            GameEvent::DiceRolled { player_id: 1, roll: 2 },
+        ];
+        assert_eq!(cues_for(&events), vec![SoundCue::DiceRoll], "A harvest that paid nothing stays quiet");
+
+        let events = vec![
+            GameEvent::GameWon { player_id: 1, year: 9 },
+            GameEvent::CardPlayed { player_id: 1, card: "Hail".to_string(), category: EffectCategory::Disaster },
+            GameEvent::HarvestCollected { player_id: 1, harvest: HarvestType::Corn, income: Money(4000) },
+            GameEvent::DiceRolled { player_id: 1, roll: 6 },
+        ];
+        assert_eq!(cues_for(&events), vec![SoundCue::DiceRoll, SoundCue::HarvestPayout, SoundCue::Disaster, SoundCue::Victory]);
+    }
+
+    #[test]
+    fn test_mute_toggles() {
+        let mut audio = Audio::new();
+        assert!(!audio.is_muted());
+        audio.toggle_mute();
+        assert!(audio.is_muted());
+        audio.play_events(&[GameEvent::GameWon { player_id: 0, year: 3 }]);
+        audio.toggle_mute();
+        assert!(!audio.is_muted());
+    }
+}
@@ -0,0 +1,62 @@
+// Benchmarks for the turn loop: a single turn, cloning a game, and a full
+// six-player game played out over fifty years.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use farming_game::config::GameRules;
+use farming_game::game::game_loop::handle_player_turn;
+use farming_game::models::{GameState, Player, PlayerRegistry, PlayerType};
+
+fn new_game(num_players: usize, seed: u64) -> GameState {
+    let mut players = PlayerRegistry::new();
+    let mut turn_order = Vec::new();
+    for id in 0..num_players {
+        players.insert(Player::new(id, format!("Player {}", id + 1), PlayerType::Human));
+        turn_order.push(id);
+    }
+    let mut game = GameState::new_with_players(players, turn_order, GameRules::default());
+    game.reseed(seed);
+    game
+}
+
+/// Rotates through the players until everyone has finished `years` years.
+fn simulate_years(game: &mut GameState, years: u32) {
+    let mut turn = 0;
+    while game.players.iter().any(|p| p.year <= years) {
+        let player_id = game.turn_order[turn % game.turn_order.len()];
+        let roll = (turn % 6) as u32 + 1;
+        let _ = handle_player_turn(game, player_id, roll);
+        turn += 1;
+    }
+}
+
+fn bench_player_turn(c: &mut Criterion) {
+    let game = new_game(4, 1803);
+    c.bench_function("handle_player_turn", |b| {
+        b.iter_batched(
+            || game.clone(),
+            |mut game| handle_player_turn(&mut game, 0, black_box(4)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let game = new_game(6, 1803);
+    c.bench_function("clone GameState (6 players)", |b| b.iter(|| black_box(&game).clone()));
+}
+
+fn bench_fifty_years(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simulation");
+    group.sample_size(10);
+    group.bench_function("6 players, 50 years", |b| {
+        b.iter_batched(
+            || new_game(6, 1803),
+            |mut game| simulate_years(&mut game, 50),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_player_turn, bench_clone, bench_fifty_years);
+criterion_main!(benches);
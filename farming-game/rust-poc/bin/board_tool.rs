@@ -0,0 +1,83 @@
+// Checks and summarizes custom boards.
+//
+//   board-tool check <board.toml>    validate a board and print its seasons
+//   board-tool summary [board.toml]  print a board's seasons (the built-in board by default)
+//   board-tool export [board.toml]   write the built-in board, to stdout by default
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use farming_game::game::board;
+use farming_game::game::board_file::{builtin_board_toml, load_board, season_summary, validate_board};
+use farming_game::models::BoardTile;
+
+const USAGE: &str = "usage: board-tool check <board.toml> | summary [board.toml] | export [board.toml]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match (args.first().map(String::as_str), args.get(1)) {
+        (Some("check"), Some(path)) => check(Path::new(path)),
+        (Some("summary"), path) => summary(path.map(Path::new)),
+        (Some("export"), path) => export(path.map(Path::new)),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("board-tool: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints every issue and the season summary; `false` if any issue is an error.
+fn check(path: &Path) -> Result<bool, Box<dyn Error>> {
+    let tiles = load_board(path)?;
+    let issues = validate_board(&tiles);
+    for issue in &issues {
+        println!("{}: {}", if issue.is_error() { "error" } else { "warning" }, issue);
+    }
+    let errors = issues.iter().filter(|issue| issue.is_error()).count();
+    println!("{}: {} tiles, {} errors, {} warnings\n", path.display(), tiles.len(), errors, issues.len() - errors);
+    if errors == 0 {
+        print_seasons(&tiles);
+    }
+    Ok(errors == 0)
+}
+
+fn summary(path: Option<&Path>) -> Result<bool, Box<dyn Error>> {
+    let tiles = match path {
+        Some(path) => load_board(path)?,
+        None => board::create_full_board(),
+    };
+    print_seasons(&tiles);
+    Ok(true)
+}
+
+fn export(path: Option<&Path>) -> Result<bool, Box<dyn Error>> {
+    let contents = builtin_board_toml();
+    match path {
+        Some(path) => {
+            fs::write(path, contents)?;
+            eprintln!("Wrote the built-in board to {}", path.display());
+        }
+        None => print!("{}", contents),
+    }
+    Ok(true)
+}
+
+fn print_seasons(tiles: &[BoardTile]) {
+    for season in season_summary(tiles) {
+        println!("{} ({} to {}): {} tiles, {} card draws", season.phase.label(), season.first, season.last, season.tiles, season.card_draws);
+        let harvests: Vec<&str> = season.harvests.iter().map(|harvest| harvest.title()).collect();
+        println!("  Harvests: {}", if harvests.is_empty() { "none".to_string() } else { harvests.join(", ") });
+        for (from, to) in season.jumps {
+            println!("  Jump: {} -> {}", tiles[from].name, tiles.get(to).map_or("off the board", |tile| tile.name.as_str()));
+        }
+    }
+}
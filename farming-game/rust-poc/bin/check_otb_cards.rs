@@ -19,30 +19,17 @@ fn main() {
     // Initialize player hands with OTB cards manually, like main.rs does
     println!("Manually distributing cards to players...");
     for player_id in 0..NATIVE_PLAYERS.len() {
-        if let Some(player) = game.players.get(&player_id) {
+        if let Some(player) = game.players.get(player_id) {
             println!("\nDistributing OTB cards to {}", player.name);
             
             for i in 0..2 {
                 match game.draw_card(TileType::OptionToBuy) {
                     Ok(card) => {
                         // Note: Using unwrap_or_else to handle the case when player might not exist
-                        game.players.get_mut(&player_id).unwrap().hand.push(card.clone());
+                        game.players.get_mut(player_id).unwrap().hand.push(card.clone());
                         println!("  Card {}: {} - {}", i + 1, card.title, card.description);
                     }
-                    Err(e) => {
-                        println!("  Error drawing card: {}", e);
-                        if !game.option_to_buy_deck.discard_pile.is_empty() {
-                            println!("  Reshuffling discard pile...");
-                            game.option_to_buy_deck.draw_pile = game.option_to_buy_deck.discard_pile.clone();
-                            game.option_to_buy_deck.discard_pile.clear();
-                            game.option_to_buy_deck.shuffle();
-                            
-                            if let Ok(card) = game.draw_card(TileType::OptionToBuy) {
-                                game.players.get_mut(&player_id).unwrap().hand.push(card.clone());
-                                println!("  Card {}: {} - {}", i + 1, card.title, card.description);
-                            }
-                        }
-                    }
+                    Err(e) => println!("  Error drawing card: {}", e),
                 }
             }
         }
@@ -51,7 +38,7 @@ fn main() {
     // Verify the OTB card distribution
     println!("\n==== VERIFYING CARD DISTRIBUTION ====");
     for player_id in 0..NATIVE_PLAYERS.len() {
-        if let Some(player) = game.players.get(&player_id) {
+        if let Some(player) = game.players.get(player_id) {
             // Get all cards and then count OTB cards specifically, including BOTH types of OTB cards
             let otb_cards: Vec<&Card> = player.hand.iter()
                 .filter(|card| matches!(card.effect, 
@@ -0,0 +1,21 @@
+// Hosts games over HTTP for web and mobile clients (see `farming_game::server`).
+//
+//   farming-server [address] [--log-file <path>]   listen on address, 127.0.0.1:8080 by default
+
+use std::error::Error;
+use std::path::Path;
+use farming_game::{logging, server};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(path) = logging::log_file_arg(args.iter().cloned()) {
+        logging::init_file_logging(Path::new(&path))?;
+    }
+    let address = args.first().filter(|arg| !arg.starts_with("--")).map_or(DEFAULT_ADDRESS, String::as_str);
+    println!("Serving games on http://{}", address);
+    server::serve(address, server::SharedGames::default()).await?;
+    Ok(())
+}
@@ -1,9 +1,25 @@
-use crate::game::GameEffect;
+use crate::game::{EffectCategory, GameEffect};
+use crate::models::HarvestType;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CardSource {
     BaseGame,
     Expansion,
+    /// Loaded from a card pack (see `cards::pack`)
+    Pack,
+}
+
+/// What drawing a card means for the player, as the draw odds overlay counts it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DrawKind {
+    /// Cash coming in
+    Income,
+    /// Cash going out
+    Expense,
+    /// Kept in hand for later: hold cards and options to buy
+    Hold,
+    /// Harvest changes, disasters, moves and the rest
+    Other,
 }
 
 #[derive(Debug, Clone)]
@@ -15,4 +31,29 @@ pub struct Card {
     pub effect: GameEffect,
     pub default_quantity: u32,
     pub source: CardSource,
-} 
\ No newline at end of file
+    /// Harvest a hold card is kept in front of the player for; its effect
+    /// applies then instead of when drawn
+    pub hold_until: Option<HarvestType>,
+}
+
+impl Card {
+    /// Cards the player keeps in front of them instead of discarding right away
+    /// until a harvest (`hold_until`), like Weed Infestation.
+    pub fn is_hold_card(&self) -> bool {
+        self.hold_until.is_some()
+    }
+
+    /// How drawing this card plays out. Options to buy go to the player's
+    /// hand like hold cards do, whatever they would cost.
+    pub fn draw_kind(&self) -> DrawKind {
+        if self.is_hold_card() {
+            return DrawKind::Hold;
+        }
+        match self.effect.category() {
+            EffectCategory::Income => DrawKind::Income,
+            EffectCategory::Expense => DrawKind::Expense,
+            _ if matches!(self.effect, GameEffect::OptionalBuyAsset { .. } | GameEffect::LeaseRidge { .. }) => DrawKind::Hold,
+            _ => DrawKind::Other,
+        }
+    }
+}
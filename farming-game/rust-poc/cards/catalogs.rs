@@ -1,4 +1,4 @@
-use crate::models::{Card, AssetType};
+use crate::models::{Card, AssetType, HarvestType, Money};
 use crate::game::GameEffect;
 use crate::cards::card::CardSource;
 use CardSource::*;
@@ -11,108 +11,120 @@ pub fn operating_expense_catalog() -> Vec<Card> {
             title: "Fertilizer Bill".to_string(), 
             description: "Fertilizer Bill. Pay $100 per acre.".to_string(), 
             description_brief: "Fertilizer Bill. Pay $100 per acre.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Grain, rate: 100 }, 
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Grain, rate: Money(100) }, 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 101, 
             title: "Fuel Bill".to_string(), 
             description: "Fuel Bill. Pay $1,000.".to_string(), 
             description_brief: "Fuel Bill. Pay $1,000.".to_string(),
-            effect: GameEffect::Expense(1000), 
+            effect: GameEffect::Expense(Money(1000)), 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 102, 
             title: "Electric Bill for Irrigation".to_string(), 
             description: "Electric Bill for Irrigation. Pay $500.".to_string(), 
             description_brief: "Electric Bill for Irrigation. Pay $500.".to_string(),
-            effect: GameEffect::Expense(500), 
+            effect: GameEffect::Expense(Money(500)), 
             default_quantity: 1, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 103, 
             title: "Custom Hire - No Tractor".to_string(), 
             description: "Pay $2,000 if you do not own a Tractor.".to_string(), 
             description_brief: "Pay $2,000 if you do not own a Tractor.".to_string(),
-            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Tractor, amount: 2000 }, 
+            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Tractor, amount: Money(2000) }, 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 104, 
             title: "Custom Hire - No Harvester".to_string(), 
             description: "Pay $2,000 if you do not own a Harvester.".to_string(), 
             description_brief: "Pay $2,000 if you do not own a Harvester.".to_string(),
-            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Harvester, amount: 2000 }, 
+            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Harvester, amount: Money(2000) }, 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 105, 
             title: "Parts Bill".to_string(), 
             description: "Parts Bill. Pay $500.".to_string(), 
             description_brief: "Parts Bill. Pay $500.".to_string(),
-            effect: GameEffect::Expense(500), 
+            effect: GameEffect::Expense(Money(500)), 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 106, 
             title: "Wire Worm in Grain".to_string(), 
             description: "Wire Worm in Grain. Pay $100 per Grain acre to fumigate.".to_string(), 
             description_brief: "Wire Worm in Grain. Pay $100 per Grain acre to fumigate.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Grain, rate: 100 }, 
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Grain, rate: Money(100) }, 
             default_quantity: 1, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 107, 
             title: "Equipment Breakdown".to_string(), 
             description: "Equipment Breakdown. Pay $500.".to_string(), 
             description_brief: "Equipment Breakdown. Pay $500.".to_string(),
-            effect: GameEffect::Expense(500), 
+            effect: GameEffect::Expense(Money(500)), 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 108, 
             title: "Feed Bill".to_string(), 
             description: "Feed Bill. Pay $100 per cow.".to_string(), 
             description_brief: "Feed Bill. Pay $100 per cow.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Cows, rate: 100 }, 
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Cows, rate: Money(100) }, 
             default_quantity: 1, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 109, 
             title: "Farmowner's Insurance".to_string(), 
             description: "Farmowner's Insurance. Pay $1,500.".to_string(), 
             description_brief: "Farmowner's Insurance. Pay $1,500.".to_string(),
-            effect: GameEffect::Expense(1500), 
+            effect: GameEffect::Expense(Money(1500)), 
             default_quantity: 1, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 110, 
             title: "Seed Bill".to_string(), 
             description: "Seed Bill. Pay $3,000.".to_string(), 
             description_brief: "Seed Bill. Pay $3,000.".to_string(),
-            effect: GameEffect::Expense(3000), 
+            effect: GameEffect::Expense(Money(3000)), 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 111, 
             title: "Farm Taxes".to_string(), 
             description: "Farm Taxes. Pay $1,500.".to_string(), 
             description_brief: "Farm Taxes. Pay $1,500.".to_string(),
-            effect: GameEffect::Expense(1500), 
+            effect: GameEffect::Expense(Money(1500)), 
             default_quantity: 1, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 112, 
@@ -121,25 +133,28 @@ pub fn operating_expense_catalog() -> Vec<Card> {
             description_brief: "Pay 10% on Bank Notes on hand.".to_string(),
             effect: GameEffect::PayInterest, 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 113, 
             title: "Veterinary Bill (Cows)".to_string(), 
             description: "Veterinary Bill. Pay $500 if you own cows.".to_string(), 
             description_brief: "Veterinary Bill. Pay $500 if you own cows.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Cows, rate: 500 }, 
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Cows, rate: Money(500) }, 
             default_quantity: 1, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         },
         Card { 
             id: 114, 
             title: "Equipment in Shop".to_string(), 
             description: "Equipment in the shop. Pay $1,000 for the delay.".to_string(), 
             description_brief: "Equipment in the shop. Pay $1,000 for the delay.".to_string(),
-            effect: GameEffect::Expense(1000), 
+            effect: GameEffect::Expense(Money(1000)), 
             default_quantity: 2, 
-            source: BaseGame 
+            source: BaseGame,
+            hold_until: None
         }
     ]
 }
@@ -152,18 +167,20 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             title: "Calves Market Jump".to_string(),
             description: "Held some of your calves and the market jumped. Collect $2,000 if you have cows.".to_string(),
             description_brief: "Collect $2,000 if you have cows.".to_string(),
-            effect: GameEffect::IncomeIfHas { asset: AssetType::Cows, amount: 2000 },
+            effect: GameEffect::IncomeIfHas { asset: AssetType::Cows, amount: Money(2000) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 201,
             title: "Federal Crop Disaster".to_string(),
             description: "Federal Crop Disaster payment saves your bacon. Collect $100 per Grain acre.".to_string(),
             description_brief: "Taxpayers bailed you out. Collect $100 per Grain acre.".to_string(),
-            effect: GameEffect::IncomePerAsset { asset: AssetType::Grain, rate: 100 },
+            effect: GameEffect::IncomePerAsset { asset: AssetType::Grain, rate: Money(100) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 202,
@@ -172,43 +189,48 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "No income for you this year - only Operating Expenses!".to_string(),
             effect: GameEffect::DrawOperatingExpenseNoHarvest,
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 205,
             title: "Drought Year".to_string(),
             description: "Drought year! Go to the 2nd week of January. Do not collect your $5,000 year's wages.".to_string(),
             description_brief: "Drought year! Skip to 2nd week of January. Do not collect $5,000.".to_string(),
-            effect: GameEffect::SkipYear,
+            effect: GameEffect::DroughtYear,
             default_quantity: 2,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 206,
             title: "Truckers Strike".to_string(),
             description: "Truckers strike delays Fruit in transport, lots of spoilage. Pay $1,000 per Fruit acre.".to_string(),
             description_brief: "Truckers strike: Transport delays cause spoilage. Pay $1,000 per Fruit acre.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Fruit, rate: 1000 },
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Fruit, rate: Money(1000) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 207,
             title: "Uncle Bert's Legacy".to_string(),
             description: "Uncle Bert dies and leaves you 10 acres of Hay, if you can raise the $10,000 cash to pay Inheritance Tax and small remaining mortgage.".to_string(),
             description_brief: "Uncle Bert dies: Inherit 10 acres of Hay for $10,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Hay, quantity: 10, cost: 10000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Hay, quantity: 10, cost: Money(10000) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 208,
             title: "Premium Hay Sale".to_string(),
             description: "Rich folks from the city bought the neighboring farm and pay you a premium for your best hay to feed their show horses. Collect $100 per Hay Acre.".to_string(),
             description_brief: "Premium Hay Sale: Collect $100 per Hay Acre.".to_string(),
-            effect: GameEffect::IncomePerAsset { asset: AssetType::Hay, rate: 100 },
+            effect: GameEffect::IncomePerAsset { asset: AssetType::Hay, rate: Money(100) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 209,
@@ -217,7 +239,8 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "Weeds cut your wheat crop in half.".to_string(),
             effect: GameEffect::OneTimeHarvestMultiplier { asset: AssetType::Grain, multiplier: 0.5 },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: Some(HarvestType::Wheat)
         },
         Card {
             id: 210,
@@ -226,16 +249,18 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "TV talking head ruins cherry market. Cut your cherry crop in half.".to_string(),
             effect: GameEffect::OneTimeHarvestMultiplier { asset: AssetType::Fruit, multiplier: 0.5 },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 211,
             title: "Income Taxes Due".to_string(),
             description: "Income taxes due. Pay $7,000.".to_string(),
             description_brief: "Income taxes due. Pay $7,000.".to_string(),
-            effect: GameEffect::Expense(7000),
+            effect: GameEffect::Expense(Money(7000)),
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 212,
@@ -244,16 +269,18 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "Volcano! You are safe and collect $500 per Hay acre. Other players roll to escape or pay.".to_string(),
             effect: GameEffect::MtStHelensDisaster,
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 213,
             title: "Cut Worms".to_string(),
             description: "Cut worms eat sprouting Fruit buds. EPA bans control spray. Pay $300 per Fruit acre.".to_string(),
             description_brief: "Worms in the fruit. EPA bans spray. Pay $300 per Fruit acre.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Fruit, rate: 800 },
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Fruit, rate: Money(800) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 214,
@@ -262,7 +289,8 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "Prime Rate Hike. Pay 10% of outstanding loan balance.".to_string(),
             effect: GameEffect::PayInterest,
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 215,
@@ -271,7 +299,8 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "Leaky motor contaiminates feed. Slaughter cows without compensation.".to_string(),
             effect: GameEffect::SlaughterCowsWithoutCompensation,
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 216,
@@ -280,55 +309,82 @@ pub fn farmers_fate_catalog() -> Vec<Card> {
             description_brief: "You're a cattle management genius. 50% bonus for 2 years.".to_string(),
             effect: GameEffect::AddPersistentEffect {
                 effect_type: EffectType::LivestockHarvestBonus(1.5),
-                years: 2
+                years: 2,
+                harvests: None,
             },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 217,
             title: "Tractor Hire Bill - $3,000".to_string(),
             description: "Custom hire bill due. If you have no Tractor Pay $3,000.".to_string(),
             description_brief: "Pay $3,000 to hire a tractor.".to_string(),
-            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Tractor, amount: 3000 },
+            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Tractor, amount: Money(3000) },
             default_quantity: 2,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 219,
             title: "Russian Wheat Sale".to_string(),
             description: "Russian sale boosts wheat prices. Collect $2,000.".to_string(),
             description_brief: "Putin buys your wheat. Collect $2,000.".to_string(),
-            effect: GameEffect::Income(2000),
+            effect: GameEffect::Income(Money(2000)),
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 220,
             title: "Apple Maggot Fly".to_string(),
             description: "The Apple Maggot Fly, cousin of the dreaded Medfly, is found in an insect trap in your Fruit. Your Fruit is quarantined, but you get a lucrative export contract. Pay $500 per Fruit acre.".to_string(),
             description_brief: "Stupid Apple Maggot fly. Pay $500 per Fruit acre.".to_string(),
-            effect: GameEffect::ExpensePerAsset { asset: AssetType::Fruit, rate: 500 },
+            effect: GameEffect::ExpensePerAsset { asset: AssetType::Fruit, rate: Money(500) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 221,
             title: "Grain Embargo".to_string(),
             description: "The President slaps on a Grain Embargo while you're waiting for the custom harvester to show up. Instant market collapse. Pay $2,500 if you don't own your own Harvester.".to_string(),
             description_brief: "Trump and his grain embargos! Pay $2,500 if you don't own your own Harvester.".to_string(),
-            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Harvester, amount: 2500 },
+            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Harvester, amount: Money(2500) },
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 222,
             title: "Marketing Co-op Success".to_string(),
             description: "Marketing Co-op holds out for higher price. Processor gives in! Collect $1,000.".to_string(),
             description_brief: "Marketing Co-op negotiates $1,000 bonus.".to_string(),
-            effect: GameEffect::Income(1000),
+            effect: GameEffect::Income(Money(1000)),
             default_quantity: 1,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
+        },
+        Card {
+            id: 223,
+            title: "Brucellosis Buyout".to_string(),
+            description: "Brucellosis turns up in a neighbor's herd. State Ag Inspector orders all cows (not cows on lease range land) slaughtered, but the state buys them out at this year's market price.".to_string(),
+            description_brief: "Herd slaughtered for brucellosis. State pays market price per cow.".to_string(),
+            effect: GameEffect::SlaughterCowsWithCompensation,
+            default_quantity: 1,
+            source: BaseGame,
+            hold_until: None
+        },
+        Card {
+            id: 224,
+            title: "Machinery Co-op".to_string(),
+            description: "You and the neighbors pool your equipment in a machinery co-op. Operating Expenses drawn at harvest cost you 20% less for the next two years (interest is still due in full).".to_string(),
+            description_brief: "Join the machinery co-op. 20% off Operating Expenses for 2 years.".to_string(),
+            effect: GameEffect::MachineryCoop { percent: 20, years: 2 },
+            default_quantity: 1,
+            source: BaseGame,
+            hold_until: None
         }
     ]
 }
@@ -341,45 +397,50 @@ pub fn option_to_buy_catalog() -> Vec<Card> {
             title: "Livestock Auction".to_string(),
             description: "Livestock auction 10 pregnant cows at $500 each Total $5,000".to_string(),
             description_brief: "Buy 10 cows for $5,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 10, cost: 5000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 10, cost: Money(5000) },
             default_quantity: 6,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 301,
             title: "Buy Grain Land".to_string(),
             description: "Neighbor sells out 10 acres of Grain at $2,000 per acre Total $20,000".to_string(),
             description_brief: "Buy 10 acres of Grain at $2,000 per acre for $20,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Grain, quantity: 10, cost: 20000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Grain, quantity: 10, cost: Money(20000) },
             default_quantity: 5,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 302,
             title: "Buy Fruit Land".to_string(),
             description: "Neighbor goes broke 5 acres of Fruit at $5,000 per acre Total $25,000".to_string(),
             description_brief: "Buy 5 acres of Fruit at $5,000 per acre for $25,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Fruit, quantity: 5, cost: 25000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Fruit, quantity: 5, cost: Money(25000) },
             default_quantity: 6,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 303,
             title: "Buy Used Tractor".to_string(),
             description: "Equipment sale old but useable tractor Total $10,000".to_string(),
             description_brief: "Buy a used tractor for $10,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Tractor, quantity: 1, cost: 10000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Tractor, quantity: 1, cost: Money(10000) },
             default_quantity: 3,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 304,
             title: "Buy Used Harvester".to_string(),
             description: "Equipment sale old but useable harvester Total $10,000".to_string(),
             description_brief: "Buy a used harvester for $10,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Harvester, quantity: 1, cost: 10000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Harvester, quantity: 1, cost: Money(10000) },
             default_quantity: 3,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 305,
@@ -388,11 +449,12 @@ pub fn option_to_buy_catalog() -> Vec<Card> {
             description_brief: "Lease Toppenish Ridge and buy 50 cows for $50,000.".to_string(),
             effect: GameEffect::LeaseRidge { 
                 name: "Toppenish Ridge".to_string(), 
-                cost: 50000,
+                cost: Money(50000),
                 cow_count: 50 
             },
             default_quantity: 3,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 306,
@@ -401,11 +463,12 @@ pub fn option_to_buy_catalog() -> Vec<Card> {
             description_brief: "Lease Rattlesnake Ridge and buy 30 cows for $30,000.".to_string(),
             effect: GameEffect::LeaseRidge { 
                 name: "Rattlesnake Ridge".to_string(), 
-                cost: 30000,
+                cost: Money(30000),
                 cow_count: 30 
             },
             default_quantity: 3,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 307,
@@ -414,11 +477,12 @@ pub fn option_to_buy_catalog() -> Vec<Card> {
             description_brief: "Lease Ahtanum Ridge and buy 20 cows for $20,000.".to_string(),
             effect: GameEffect::LeaseRidge { 
                 name: "Ahtanum Ridge".to_string(), 
-                cost: 20000,
+                cost: Money(20000),
                 cow_count: 20 
             },
             default_quantity: 3,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 308,
@@ -427,20 +491,52 @@ pub fn option_to_buy_catalog() -> Vec<Card> {
             description_brief: "Lease Cascade Range and buy 40 cows for $40,000.".to_string(),
             effect: GameEffect::LeaseRidge { 
                 name: "Cascade Ridge".to_string(), 
-                cost: 40000,
+                cost: Money(40000),
                 cow_count: 40 
             },
             default_quantity: 3,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
         },
         Card {
             id: 309,
             title: "Buy Hay Land".to_string(),
             description: "Neighbor sells out 10 acres of Hay at $2,000 per acre Total $20,000".to_string(),
             description_brief: "Buy 10 acres of Hay at $2,000 per acre for $20,000.".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Hay, quantity: 10, cost: 20000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Hay, quantity: 10, cost: Money(20000) },
             default_quantity: 5,
-            source: BaseGame
+            source: BaseGame,
+            hold_until: None
+        },
+        Card {
+            id: 310,
+            title: "Install Irrigation".to_string(),
+            description: "Sprinkler line goes in on your Hay ground. Hay harvest checks are 25% bigger from now on. Total $10,000".to_string(),
+            description_brief: "Install irrigation for $10,000. Hay checks +25%.".to_string(),
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Irrigation, quantity: 1, cost: Money(10000) },
+            default_quantity: 2,
+            source: BaseGame,
+            hold_until: None
+        },
+        Card {
+            id: 311,
+            title: "Build a Barn".to_string(),
+            description: "Put up a loafing barn for the herd. Livestock harvest checks are 20% bigger from now on. Total $8,000".to_string(),
+            description_brief: "Build a barn for $8,000. Livestock checks +20%.".to_string(),
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Barn, quantity: 1, cost: Money(8000) },
+            default_quantity: 2,
+            source: BaseGame,
+            hold_until: None
+        },
+        Card {
+            id: 312,
+            title: "Grain Silo".to_string(),
+            description: "Store your wheat in your own silo. Rain and weather no longer cut the price of your Grain. Total $6,000".to_string(),
+            description_brief: "Buy a grain silo for $6,000. No Grain price cuts.".to_string(),
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Silo, quantity: 1, cost: Money(6000) },
+            default_quantity: 2,
+            source: BaseGame,
+            hold_until: None
         },
     ]
 } 
\ No newline at end of file
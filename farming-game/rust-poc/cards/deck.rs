@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
+use rand::Rng;
 use rand::seq::SliceRandom;
-use crate::cards::card::Card;
-use crate::game::GameEffect;
+use crate::cards::card::{Card, DrawKind};
+use crate::game::{EffectCategory, GameEffect};
 use crate::models::asset::AssetType;
 
 #[derive(Debug, Clone)]
@@ -18,52 +20,35 @@ impl Deck {
     }
 
     pub fn from_catalog(catalog: Vec<Card>) -> Self {
-        let draw_pile = catalog.clone();
-        let discard_pile = Vec::new();
-        let _rng = rand::thread_rng();
-        
         // Create deck from catalog
         Deck {
-            draw_pile,
-            discard_pile,
+            draw_pile: catalog,
+            discard_pile: Vec::new(),
         }
     }
 
+    /// Draws the top card. When the draw pile runs out, the discard pile is
+    /// shuffled back in first, so this only returns `None` if both piles are empty.
     pub fn draw(&mut self) -> Option<Card> {
-        // Determine deck type based on first card's effect
-        let deck_type = if !self.draw_pile.is_empty() {
-            match &self.draw_pile[0].effect {
-                GameEffect::OptionalBuyAsset { .. } => "Option to Buy",
-                GameEffect::Expense(..) | GameEffect::ExpensePerAsset { .. } | GameEffect::PayIfNoAssetDistribute { .. } => "Operating Cost",
-                _ => "Farmer's Fate",
-            }
-        } else if !self.discard_pile.is_empty() {
-            match &self.discard_pile[0].effect {
-                GameEffect::OptionalBuyAsset { .. } => "Option to Buy",
-                GameEffect::Expense(..) | GameEffect::ExpensePerAsset { .. } | GameEffect::PayIfNoAssetDistribute { .. } => "Operating Cost",
-                _ => "Farmer's Fate",
-            }
-        } else {
-            "Empty"
-        };
+        self.draw_with(&mut rand::thread_rng())
+    }
 
+    /// `draw` using the caller's RNG for any reshuffle.
+    pub fn draw_with<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<Card> {
         if self.draw_pile.is_empty() {
-            // If draw pile is empty, shuffle discard pile into draw pile
-            if !self.discard_pile.is_empty() {
-                println!("\nDraw pile empty, shuffling {} cards from discard pile", self.discard_pile.len());
-                self.draw_pile.append(&mut self.discard_pile);
-                self.shuffle();
-            } else {
-                println!("\nBoth draw pile and discard pile are empty for {} deck", deck_type);
+            if self.discard_pile.is_empty() {
+                tracing::debug!("draw and discard piles are both empty");
                 return None;
             }
+            tracing::debug!(cards = self.discard_pile.len(), "draw pile empty, shuffling discard pile into draw pile");
+            self.draw_pile.append(&mut self.discard_pile);
+            self.shuffle_with(rng);
         }
-        
-        // Remove from beginning and ensure we're not duplicating cards
+
         let card = self.draw_pile.remove(0);
-        // Verify this card isn't in the discard pile
+        // A card can't be in both piles at once
         if self.discard_pile.iter().any(|c| c.id == card.id) {
-            println!("WARNING: Found duplicate card ID {} in discard pile!", card.id);
+            tracing::warn!(card_id = card.id, "drawn card is also in the discard pile");
         }
         Some(card)
     }
@@ -72,7 +57,48 @@ impl Deck {
         self.discard_pile.push(card);
     }
 
+    /// Cards left to draw before the discard pile is shuffled back in.
+    pub fn remaining(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    /// Cards left to draw, counted by what their effects do.
+    pub fn composition(&self) -> BTreeMap<EffectCategory, usize> {
+        let mut counts = BTreeMap::new();
+        for card in &self.draw_pile {
+            *counts.entry(card.effect.category()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Cards the next draw comes from: the draw pile, or once that's empty
+    /// the discards it's reshuffled from.
+    pub fn next_draw_pool(&self) -> &[Card] {
+        if self.draw_pile.is_empty() { &self.discard_pile } else { &self.draw_pile }
+    }
+
+    /// Cards the next draw comes from, counted by how drawing them plays out.
+    pub fn draw_kinds(&self) -> BTreeMap<DrawKind, usize> {
+        let mut counts = BTreeMap::new();
+        for card in self.next_draw_pool() {
+            *counts.entry(card.draw_kind()).or_insert(0) += 1;
+        }
+        counts
+    }
+
     pub fn shuffle(&mut self) {
+        self.shuffle_with(&mut rand::thread_rng());
+    }
+
+    /// Puts the draw pile back in catalog (id) order and shuffles it with `rng`,
+    /// so the resulting order depends only on the RNG and not on earlier shuffles.
+    pub fn reshuffle_from_catalog_order<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.draw_pile.sort_by_key(|c| c.id);
+        self.shuffle_with(rng);
+    }
+
+    /// `shuffle` using the caller's RNG, so seeded games deal the same cards.
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         // Determine deck type for printing
         let deck_type = if !self.draw_pile.is_empty() {
             match &self.draw_pile[0].effect {
@@ -85,13 +111,11 @@ impl Deck {
         };
 
         if self.draw_pile.is_empty() {
-             println!("Cannot shuffle an empty deck ({})", deck_type);
+             tracing::debug!(deck_type, "cannot shuffle an empty deck");
              return;
         }
         
-        println!("Shuffling {} deck of {} cards", deck_type, self.draw_pile.len());
-
-        let mut rng = rand::thread_rng();
+        tracing::debug!(deck_type, cards = self.draw_pile.len(), "shuffling deck");
 
         // For Option to Buy deck, shuffle and check for excessive clumping, reshuffle up to 5 times.
         if matches!(deck_type, "Option to Buy") {
@@ -101,7 +125,7 @@ impl Deck {
 
             while is_clumpy && attempts < MAX_SHUFFLE_ATTEMPTS {
                 attempts += 1;
-                self.draw_pile.shuffle(&mut rng);
+                self.draw_pile.shuffle(rng);
 
                 // Check distribution in top 20 cards only if deck is large enough
                 if self.draw_pile.len() >= 20 {
@@ -115,6 +139,7 @@ impl Deck {
                             GameEffect::OptionalBuyAsset { asset, .. } => match asset {
                                 AssetType::Grain | AssetType::Hay | AssetType::Fruit => land_count += 1,
                                 AssetType::Tractor | AssetType::Harvester => equipment_count += 1,
+                                AssetType::Irrigation | AssetType::Barn | AssetType::Silo => equipment_count += 1,
                                 AssetType::Cows => other_count += 1, // Cows OTB are 'Other'
                             },
                             GameEffect::LeaseRidge { .. } => ridge_count += 1,
@@ -127,8 +152,8 @@ impl Deck {
                     is_clumpy = ridge_count > 9 || land_count > 9 || equipment_count > 7 || other_count > 7;
                     
                     if attempts > 1 {
-                         println!("  Shuffle attempt {}, Top 20 counts: R={}, L={}, E={}, O={}. Clumpy: {}", 
-                                  attempts, ridge_count, land_count, equipment_count, other_count, is_clumpy);
+                         tracing::debug!(attempts, ridge_count, land_count, equipment_count, other_count, is_clumpy,
+                                         "reshuffling clumpy Option to Buy deck");
                     }
                     
                     if !is_clumpy {
@@ -142,18 +167,59 @@ impl Deck {
             }
             
             if attempts == MAX_SHUFFLE_ATTEMPTS && is_clumpy {
-                println!("  Reached max shuffle attempts ({}), accepting potentially clumpy distribution.", MAX_SHUFFLE_ATTEMPTS);
+                tracing::debug!(attempts, "reached max shuffle attempts, accepting potentially clumpy distribution");
             }
 
-            // Print top cards for verification
-            println!("Top 6 cards after shuffle:");
+            // Record top cards for verification
             for (i, card) in self.draw_pile.iter().take(6).enumerate() {
-                println!("  {}. {}", i + 1, card.title);
+                tracing::debug!(position = i + 1, card = %card.title, "top card after shuffle");
             }
 
         } else {
             // For other decks, just perform a single standard shuffle
-            self.draw_pile.shuffle(&mut rng);
+            self.draw_pile.shuffle(rng);
         }
     }
-} 
\ No newline at end of file
+}
+
+/// Public counts for one deck: what's left to draw and what's been played.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeckStats {
+    pub name: &'static str,
+    pub remaining: usize,
+    pub discarded: usize,
+    pub composition: BTreeMap<EffectCategory, usize>,
+    /// Cards the next draw comes from, by how drawing them plays out
+    pub draws: BTreeMap<DrawKind, usize>,
+}
+
+impl DeckStats {
+    pub fn of(name: &'static str, deck: &Deck) -> Self {
+        Self {
+            name,
+            remaining: deck.remaining(),
+            discarded: deck.discard_pile.len(),
+            composition: deck.composition(),
+            draws: deck.draw_kinds(),
+        }
+    }
+
+    /// Chance the next card comes from `category`, judged by the draw pile
+    /// alone; 0 when the draw pile is empty.
+    pub fn chance_of(&self, category: EffectCategory) -> f64 {
+        if self.remaining == 0 {
+            return 0.0;
+        }
+        self.composition.get(&category).copied().unwrap_or(0) as f64 / self.remaining as f64
+    }
+
+    /// Chance the next card drawn is of `kind`, counting the discards when
+    /// they're about to be reshuffled in; 0 when the deck has no cards at all.
+    pub fn chance_of_draw(&self, kind: DrawKind) -> f64 {
+        let pool: usize = self.draws.values().sum();
+        if pool == 0 {
+            return 0.0;
+        }
+        self.draws.get(&kind).copied().unwrap_or(0) as f64 / pool as f64
+    }
+}
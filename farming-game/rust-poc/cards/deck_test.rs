@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::cards::deck::Deck;
-    use crate::cards::card::{Card, CardSource};
-    use crate::game::GameEffect;
+    use crate::cards::deck::{Deck, DeckStats};
+    use crate::cards::card::{Card, CardSource, DrawKind};
+    use crate::config::GameRules;
+    use crate::game::{EffectCategory, GameEffect, GameEngine};
+    use crate::models::{AssetType, HarvestType};
+    use crate::models::Money;
 
     // Helper to create a simple test card
     fn create_test_card(id: usize, effect: GameEffect) -> Card {
@@ -14,6 +17,7 @@ mod tests {
             effect,
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         }
     }
 
@@ -27,8 +31,8 @@ mod tests {
 
     #[test]
     fn test_deck_draw_success() {
-        let card1 = create_test_card(1, GameEffect::Income(100));
-        let card2 = create_test_card(2, GameEffect::Expense(50));
+        let card1 = create_test_card(1, GameEffect::Income(Money(100)));
+        let card2 = create_test_card(2, GameEffect::Expense(Money(50)));
         let mut deck = Deck::new();
         deck.draw_pile = vec![card1.clone(), card2.clone()];
 
@@ -57,7 +61,7 @@ mod tests {
 
     #[test]
     fn test_deck_discard() {
-        let card1 = create_test_card(1, GameEffect::Income(100));
+        let card1 = create_test_card(1, GameEffect::Income(Money(100)));
         let mut deck = Deck::new();
 
         deck.discard(card1.clone());
@@ -69,8 +73,8 @@ mod tests {
 
     #[test]
     fn test_deck_reshuffle_from_discard() {
-        let card1 = create_test_card(1, GameEffect::Income(100));
-        let card2 = create_test_card(2, GameEffect::Expense(50));
+        let card1 = create_test_card(1, GameEffect::Income(Money(100)));
+        let card2 = create_test_card(2, GameEffect::Expense(Money(50)));
         let mut deck = Deck::new();
         // Put cards in discard pile
         deck.discard_pile = vec![card1.clone(), card2.clone()];
@@ -94,7 +98,67 @@ mod tests {
         assert!(remaining_card_id == card1.id || remaining_card_id == card2.id, "Remaining card is not one of the original cards.");
         assert_ne!(drawn_id, remaining_card_id, "Drawn card and remaining card should be different.");
     }
-    
+
+    #[test]
+    fn test_deck_composition_counts_only_cards_left_to_draw() {
+        let mut deck = Deck::new();
+        deck.draw_pile = vec![
+            create_test_card(1, GameEffect::Income(Money(100))),
+            create_test_card(2, GameEffect::IncomePerAsset { asset: AssetType::Cows, rate: Money(-100) }),
+            create_test_card(3, GameEffect::Expense(Money(50))),
+            create_test_card(4, GameEffect::SkipYear),
+        ];
+        deck.discard_pile = vec![create_test_card(5, GameEffect::Income(Money(200)))];
+
+        assert_eq!(deck.remaining(), 4);
+        let composition = deck.composition();
+        assert_eq!(composition[&EffectCategory::Expense], 2, "A price cut counts as an expense");
+        assert_eq!(composition[&EffectCategory::Income], 1);
+        assert!(!composition.contains_key(&EffectCategory::Purchase));
+
+        let stats = DeckStats::of("Test", &deck);
+        assert_eq!(stats.discarded, 1);
+        assert_eq!(stats.chance_of(EffectCategory::Expense), 0.5);
+        assert_eq!(DeckStats::of("Empty", &Deck::new()).chance_of(EffectCategory::Income), 0.0);
+    }
+
+    #[test]
+    fn test_draw_odds_follow_the_cards_drawn_and_the_reshuffle() {
+        let mut hold = create_test_card(3, GameEffect::Income(Money(500)));
+        hold.hold_until = Some(HarvestType::Wheat);
+        let mut deck = Deck::new();
+        deck.draw_pile = vec![
+            create_test_card(1, GameEffect::Income(Money(100))),
+            create_test_card(2, GameEffect::Expense(Money(50))),
+            hold,
+            create_test_card(4, GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 10, cost: Money(5000) }),
+        ];
+        let stats = DeckStats::of("Test", &deck);
+        assert_eq!(stats.chance_of_draw(DrawKind::Hold), 0.5, "Hold cards and options to buy both stay in hand");
+        assert_eq!(stats.chance_of_draw(DrawKind::Income), 0.25);
+
+        let income = deck.draw().unwrap();
+        deck.discard(income);
+        let stats = DeckStats::of("Test", &deck);
+        assert_eq!(stats.chance_of_draw(DrawKind::Income), 0.0, "A discarded card isn't drawn again until the reshuffle");
+        assert!((stats.chance_of_draw(DrawKind::Hold) - 2.0 / 3.0).abs() < 1e-9);
+
+        let played: Vec<Card> = deck.draw_pile.drain(..).collect();
+        deck.discard_pile.extend(played);
+        let stats = DeckStats::of("Test", &deck);
+        assert_eq!(stats.chance_of_draw(DrawKind::Income), 0.25, "An empty draw pile is refilled from the discards");
+        assert_eq!(DeckStats::of("Empty", &Deck::new()).chance_of_draw(DrawKind::Expense), 0.0);
+    }
+
+    #[test]
+    fn test_game_reports_stats_for_every_deck() {
+        let engine = GameEngine::with_seed(&["Ann".to_string(), "Bo".to_string()], GameRules::default(), 4).unwrap();
+        let stats = engine.state().deck_stats();
+        assert_eq!(stats.iter().map(|deck| deck.name).collect::<Vec<_>>(), vec!["Farmer's Fate", "Option to Buy", "Operating Cost"]);
+        assert_eq!(stats[1].remaining, engine.state().option_to_buy_deck.draw_pile.len());
+        assert_eq!(stats[0].composition.values().sum::<usize>(), stats[0].remaining);
+    }
+
     #[test]
     fn it_works() { 
         assert_eq!(2 + 2, 4);
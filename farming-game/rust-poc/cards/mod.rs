@@ -1,6 +1,7 @@
 pub mod card;
 pub mod deck;
 pub mod catalogs;
+pub mod pack;
 
 #[cfg(test)]
 mod deck_test;
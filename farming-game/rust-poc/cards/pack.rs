@@ -0,0 +1,118 @@
+// Extra cards loaded from TOML. Each card names an effect key from the game's
+// `EffectRegistry` plus its parameters, so a pack can add cards (and, with a
+// registered handler, new effects) without touching the catalogs.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use thiserror::Error;
+use crate::cards::card::{Card, CardSource};
+use crate::game::{EffectParams, EffectRegistry, GameEffect};
+use crate::models::HarvestType;
+use crate::models::brief::brief_description;
+
+#[derive(Debug, Error)]
+pub enum CardPackError {
+    #[error("Could not parse card pack: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Invalid card '{title}': {reason}")]
+    Invalid { title: String, reason: String },
+}
+
+/// A pack of cards, e.g.
+///
+/// ```toml
+/// name = "County Fair"
+///
+/// [[cards]]
+/// deck = "farmers_fate"
+/// title = "Blue Ribbon Heifer"
+/// description = "Your heifer takes best in show. Collect $100 per cow."
+/// effect = "income_per_asset"
+/// params = { asset = "Cows", rate = 100 }
+/// quantity = 2
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CardPack {
+    #[serde(default)]
+    pub name: String,
+    pub cards: Vec<CardDefinition>,
+}
+
+/// Which deck a pack card is shuffled into. Option to Buy cards are kept in
+/// hand and priced by their effect, so packs can't add to that deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PackDeck {
+    FarmersFate,
+    OperatingCost,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CardDefinition {
+    pub deck: PackDeck,
+    pub title: String,
+    pub description: String,
+    /// Short text for tight spaces; derived from the description when unset
+    #[serde(default)]
+    pub brief: Option<String>,
+    /// Key of the effect in the game's `EffectRegistry`
+    pub effect: String,
+    #[serde(default)]
+    pub params: toml::Table,
+    #[serde(default = "one")]
+    pub quantity: u32,
+    /// Harvest the card is held for, e.g. `"Wheat"`; played when drawn if unset
+    #[serde(default)]
+    pub hold_until: Option<HarvestType>,
+}
+
+fn one() -> u32 {
+    1
+}
+
+impl CardPack {
+    /// Parses a pack; effect keys are checked when it's added to a game.
+    pub fn from_toml(contents: &str) -> Result<Self, CardPackError> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    /// Loads a pack from a TOML file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::from_toml(&contents)?)
+    }
+
+    /// Checks every card names an effect in `registry`.
+    pub fn validate(&self, registry: &EffectRegistry) -> Result<(), CardPackError> {
+        for card in &self.cards {
+            let invalid = |reason: String| Err(CardPackError::Invalid { title: card.title.clone(), reason });
+            if card.title.trim().is_empty() {
+                return invalid("title is empty".to_string());
+            }
+            if !registry.contains(&card.effect) {
+                return invalid(format!("no effect named '{}'", card.effect));
+            }
+        }
+        Ok(())
+    }
+
+    /// Every copy of every card, numbered from `first_id`.
+    pub fn cards(&self, first_id: usize) -> Vec<(PackDeck, Card)> {
+        let copies = self.cards.iter().flat_map(|card| (0..card.quantity).map(move |_| card));
+        copies.enumerate().map(|(i, card)| (card.deck, Card {
+            id: first_id + i,
+            title: card.title.clone(),
+            description: card.description.clone(),
+            description_brief: card.brief.clone().unwrap_or_else(|| brief_description(&card.description)),
+            effect: GameEffect::Custom { key: card.effect.clone(), params: EffectParams(card.params.clone()) },
+            default_quantity: card.quantity,
+            source: CardSource::Pack,
+            hold_until: card.hold_until.clone(),
+        })).collect()
+    }
+}
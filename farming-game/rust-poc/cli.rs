@@ -0,0 +1,334 @@
+// Plain stdin/stdout game for terminals where the TUI misbehaves.
+//
+// Drives a GameEngine through its Action API: the current player rolls, then
+// picks from the turn menu until they end their turn.
+
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+use crate::game::{Action, Decision, DecisionKind, GameEngine, GameError, PlayerOutcome, SnapshotWriter};
+use crate::models::{AssetType, GameState, Money, PlayerType};
+use crate::locale::Locale;
+
+/// Line-based prompts over any reader/writer pair (stdin/stdout in `main`).
+struct Cli<R, W> {
+    input: R,
+    output: W,
+    locale: Arc<Locale>,
+}
+
+impl<R: BufRead, W: Write> Cli<R, W> {
+    fn say(&mut self, key: &str, args: &[(&str, &dyn Display)]) -> io::Result<()> {
+        writeln!(self.output, "{}", self.locale.tr(key, args))
+    }
+
+    fn print_logs(&mut self, logs: &[String]) -> io::Result<()> {
+        for log in logs {
+            writeln!(self.output, "  {}", log)?;
+        }
+        Ok(())
+    }
+
+    /// Shows a prompt and reads the trimmed answer; `None` once input runs out.
+    fn ask(&mut self, key: &str, args: &[(&str, &dyn Display)]) -> io::Result<Option<String>> {
+        write!(self.output, "{} ", self.locale.tr(key, args))?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(line.trim().to_string()))
+    }
+}
+
+/// Plays until someone wins, a player quits or input ends.
+pub fn run<R: BufRead, W: Write>(engine: &mut GameEngine, input: R, output: W) -> io::Result<()> {
+    run_with_spectator(engine, input, output, None)
+}
+
+/// Like `run`, but also writes a spectator snapshot at the start and after every turn.
+pub fn run_with_spectator<R: BufRead, W: Write>(
+    engine: &mut GameEngine,
+    input: R,
+    output: W,
+    mut spectator: Option<SnapshotWriter>,
+) -> io::Result<()> {
+    let locale = Arc::clone(&engine.state().locale);
+    let mut cli = Cli { input, output, locale };
+
+    loop {
+        write_snapshot(spectator.as_mut(), engine);
+        let player_id = engine.current_player_id();
+        let name = engine.state().players[player_id].name.clone();
+        cli.say("cli.turn_header", &[("player", &name)])?;
+        match cli.ask("cli.roll_prompt", &[])?.as_deref() {
+            None | Some("q") | Some("Q") => return Ok(()),
+            _ => {}
+        }
+
+        match engine.apply(Action::Roll) {
+            Ok(logs) => cli.print_logs(&logs)?,
+            Err(e) => cli.say("app.turn_error", &[("error", &e)])?,
+        }
+
+        if !answer_decisions(engine, &mut cli)? {
+            return Ok(());
+        }
+        // A player who went bankrupt this turn has nothing left to do
+        if engine.state().players[player_id].is_active && !turn_menu(engine, &mut cli, player_id)? {
+            return Ok(());
+        }
+
+        if let Some(winner) = engine.winner() {
+            write_snapshot(spectator.as_mut(), engine);
+            let name = engine.state().players[winner].name.clone();
+            cli.say("app.winner", &[("player", &name)])?;
+            say_run_score(engine.state(), &mut cli, winner)?;
+            if !play_again(engine, &mut cli)? {
+                return Ok(());
+            }
+            continue;
+        }
+        // A run against the clock ends when time or money runs out
+        let state = engine.state();
+        if state.rules.year_limit.is_some() && state.player_outcome(player_id) == PlayerOutcome::Lost {
+            if state.players[player_id].is_active {
+                let (name, target, year) = (state.players[player_id].name.clone(), state.rules.winning_net_worth, state.deadline().unwrap_or_default());
+                cli.say("app.out_of_time", &[("player", &name), ("target", &target), ("year", &year)])?;
+            }
+            say_run_score(state, &mut cli, player_id)?;
+            if !play_again(engine, &mut cli)? {
+                return Ok(());
+            }
+            continue;
+        }
+        engine.end_turn();
+        // Over the hand limit: the turn ends once the discards are made
+        if engine.state().pending_decision().is_some() {
+            if !answer_decisions(engine, &mut cli)? {
+                return Ok(());
+            }
+            engine.end_turn();
+        }
+    }
+}
+
+/// Offers a rematch at the same table once the game is over, and sets it up
+/// if taken. Recorded games end here, since a record holds one game.
+fn play_again<R: BufRead, W: Write>(engine: &mut GameEngine, cli: &mut Cli<R, W>) -> io::Result<bool> {
+    if engine.record().is_some() {
+        return Ok(false);
+    }
+    let answer = cli.ask("cli.play_again", &[])?;
+    if !matches!(answer.as_deref(), Some("y") | Some("Y")) {
+        return Ok(false);
+    }
+    let rematch = engine.state().rematch().map_err(io::Error::other)?;
+    *engine = GameEngine::new(rematch);
+    Ok(true)
+}
+
+/// Scores a run against the clock (`--solo`); other games aren't scored.
+fn say_run_score<R: BufRead, W: Write>(state: &GameState, cli: &mut Cli<R, W>, player_id: usize) -> io::Result<()> {
+    if state.rules.year_limit.is_none() {
+        return Ok(());
+    }
+    cli.say("app.run_score", &[("net_worth", &state.players[player_id].net_worth),
+        ("years", &state.years_played(player_id)), ("per_year", &state.net_worth_per_year(player_id))])
+}
+
+/// Spectators are never worth stopping the game for, so a failed write is only logged.
+fn write_snapshot(spectator: Option<&mut SnapshotWriter>, engine: &GameEngine) {
+    let Some(spectator) = spectator else { return };
+    if let Err(e) = spectator.write(engine.state()) {
+        tracing::warn!(error = %e, path = %spectator.path().display(), "couldn't write spectator snapshot");
+    }
+}
+
+/// Reads a sale like `cows 2` toward a bill, for an asset the bank buys.
+fn parse_sale(state: &GameState, player_id: usize, answer: &str) -> Option<Decision> {
+    let (name, quantity) = answer.split_once(' ')?;
+    let sellable = state.sellable_assets(player_id).into_iter()
+        .find(|sellable| format!("{:?}", sellable.asset).eq_ignore_ascii_case(name.trim()))?;
+    Some(Decision::Sell { asset: sellable.asset, quantity: quantity.trim().parse().ok()? })
+}
+
+/// Asks for every decision the roll left pending; AI players answer their own.
+/// Returns `false` when input runs out.
+fn answer_decisions<R: BufRead, W: Write>(engine: &mut GameEngine, cli: &mut Cli<R, W>) -> io::Result<bool> {
+    while let Some(request) = engine.state().pending_decision().cloned() {
+        let is_ai = matches!(engine.state().players[request.player_id].player_type, PlayerType::AI(_));
+        let decision = if is_ai {
+            engine.state().ai_decision(&request)
+        } else {
+            let key = match request.kind {
+                DecisionKind::YesNo => "cli.decision_yes_no",
+                DecisionKind::Quantity { .. } => "cli.decision_quantity",
+                DecisionKind::Roll => "cli.decision_roll",
+                DecisionKind::RaiseCash { .. } => "cli.decision_raise_cash",
+                DecisionKind::Price { .. } => "cli.decision_price",
+                DecisionKind::Discard { .. } => {
+                    let state = engine.state();
+                    for (number, card) in state.players[request.player_id].hand.iter().enumerate() {
+                        cli.say("cli.option_card", &[("number", &(number + 1)), ("card", &state.locale.card_title(card)),
+                            ("description", &state.locale.card_description_brief(card))])?;
+                    }
+                    "cli.decision_discard"
+                }
+            };
+            let Some(answer) = cli.ask(key, &[("prompt", &request.prompt)])? else { return Ok(false) };
+            match (request.kind, answer.to_lowercase().as_str()) {
+                (DecisionKind::YesNo, "y") => Some(Decision::Yes),
+                (DecisionKind::YesNo, "n") => Some(Decision::No),
+                (DecisionKind::Quantity { .. }, number) => number.parse().ok().map(Decision::Quantity),
+                (DecisionKind::Price { .. }, price) => price.trim_start_matches('$').parse().ok().map(Decision::Price),
+                (DecisionKind::Roll, _) => Some(Decision::Roll),
+                (DecisionKind::RaiseCash { .. }, "y") => Some(Decision::Yes),
+                (DecisionKind::RaiseCash { .. }, sale) => parse_sale(engine.state(), request.player_id, sale),
+                (DecisionKind::Discard { .. }, number) => number.parse::<usize>().ok()
+                    .and_then(|number| number.checked_sub(1))
+                    .and_then(|index| engine.state().players[request.player_id].hand.get(index))
+                    .map(|card| Decision::Discard { card_id: card.id }),
+                _ => None,
+            }
+        };
+        let Some(decision) = decision else {
+            cli.say("cli.invalid_answer", &[])?;
+            continue;
+        };
+        match engine.apply(Action::Decide(decision)) {
+            Ok(logs) => cli.print_logs(&logs)?,
+            Err(e) => {
+                cli.say("app.error", &[("error", &e)])?;
+                // An AI answer that keeps failing would block the game; drop the question
+                if is_ai && engine.state().pending_decision() == Some(&request) {
+                    engine.state_mut().pending_decisions.pop_front();
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Menu after the roll. Returns `false` when the player quits the game.
+fn turn_menu<R: BufRead, W: Write>(engine: &mut GameEngine, cli: &mut Cli<R, W>, player_id: usize) -> io::Result<bool> {
+    loop {
+        let player = &engine.state().players[player_id];
+        let (cash, debt) = (player.cash, player.debt);
+        let Some(choice) = cli.ask("cli.turn_menu", &[("cash", &cash), ("debt", &debt)])? else {
+            return Ok(false);
+        };
+        match choice.to_lowercase().as_str() {
+            "o" => option_to_buy(engine, cli, player_id)?,
+            "p" => pay_loan(engine, cli, player_id)?,
+            "s" => sell_cows(engine, cli, player_id)?,
+            "e" | "" => return Ok(true),
+            "q" => return Ok(false),
+            _ => cli.say("cli.unknown_choice", &[("choice", &choice)])?,
+        }
+    }
+}
+
+/// Lists the player's Option to Buy cards and exercises the one they pick.
+fn option_to_buy<R: BufRead, W: Write>(engine: &mut GameEngine, cli: &mut Cli<R, W>, player_id: usize) -> io::Result<()> {
+    let state = engine.state();
+    if !state.can_exercise_option_to_buy(player_id) {
+        return cli.say("cli.option_unavailable", &[]);
+    }
+    let cards: Vec<(usize, String, String)> = state.get_option_to_buy_cards(player_id).into_iter()
+        .map(|card| (card.id, state.locale.card_title(card).to_string(), state.locale.card_description_brief(card).to_string()))
+        .collect();
+    if cards.is_empty() {
+        return cli.say("cli.no_option_cards", &[]);
+    }
+    for (number, (_, title, brief)) in cards.iter().enumerate() {
+        cli.say("cli.option_card", &[("number", &(number + 1)), ("card", title), ("description", brief)])?;
+    }
+
+    let Some(answer) = cli.ask("cli.pick_card", &[])? else { return Ok(()) };
+    if answer.is_empty() {
+        return Ok(());
+    }
+    let Some((card_id, title, _)) = answer.parse::<usize>().ok()
+        .and_then(|number| number.checked_sub(1))
+        .and_then(|index| cards.get(index)) else {
+        return cli.say("app.invalid_card", &[]);
+    };
+    let card_id = *card_id;
+
+    let mut result = engine.apply(Action::ExerciseOption { card_id, confirm_loan: false });
+    if result == Err(GameError::LoanConfirmationRequired) {
+        let answer = cli.ask("cli.confirm_loan", &[("card", title)])?;
+        if !matches!(answer.as_deref(), Some("y") | Some("Y")) {
+            return Ok(());
+        }
+        result = engine.apply(Action::ExerciseOption { card_id, confirm_loan: true });
+    }
+    match result {
+        Ok(logs) => {
+            cli.print_logs(&logs)?;
+            // Stocking a leased ridge past the minimum
+            answer_decisions(engine, cli).map(|_| ())
+        }
+        Err(e) => cli.say("app.option_failed", &[("error", &e)]),
+    }
+}
+
+/// Lists the player's notes, asks which to pay when there's a choice and how
+/// much, and pays it from cash.
+fn pay_loan<R: BufRead, W: Write>(engine: &mut GameEngine, cli: &mut Cli<R, W>, player_id: usize) -> io::Result<()> {
+    let player = &engine.state().players[player_id];
+    let mut notes = player.notes.clone();
+    notes.reconcile(player.debt, engine.state().rules.interest_rate, player.year);
+    for note in notes.iter() {
+        cli.say("cli.note", &[("note", &note.id), ("principal", &note.principal),
+            ("percent", &(note.rate * 100.0).round()), ("year", &note.year)])?;
+    }
+    let mut target = None;
+    if notes.iter().count() > 1 {
+        let Some(answer) = cli.ask("cli.pick_note", &[])? else { return Ok(()) };
+        if !answer.is_empty() {
+            let Some(id) = answer.trim_start_matches('#').parse::<usize>().ok().filter(|&id| notes.get(id).is_some()) else {
+                return cli.say("cli.invalid_note", &[("note", &answer)]);
+            };
+            target = Some(id);
+        }
+    }
+
+    let Some(answer) = cli.ask("cli.loan_amount", &[])? else { return Ok(()) };
+    if answer.is_empty() {
+        return cli.say("app.no_payment", &[]);
+    }
+    let Ok(amount) = answer.parse::<Money>() else {
+        return cli.say("cli.invalid_amount", &[("amount", &answer)]);
+    };
+    let action = match target {
+        Some(note) => Action::PayNote { note, amount },
+        None => Action::PayLoan(amount),
+    };
+    match engine.apply(action) {
+        Ok(logs) => cli.print_logs(&logs),
+        Err(e) => cli.say("app.error", &[("error", &e)]),
+    }
+}
+
+/// Shows the cow market price and sells however many cows the player asks for.
+fn sell_cows<R: BufRead, W: Write>(engine: &mut GameEngine, cli: &mut Cli<R, W>, player_id: usize) -> io::Result<()> {
+    let player = &engine.state().players[player_id];
+    let cows = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+    if cows == 0 {
+        return cli.say("app.no_cows_to_sell", &[]);
+    }
+    let price = engine.state().cow_price(player.year);
+    let Some(answer) = cli.ask("cli.cows_to_sell", &[("price", &price), ("cows", &cows)])? else { return Ok(()) };
+    if answer.is_empty() {
+        return cli.say("app.no_sale", &[]);
+    }
+    let Ok(quantity) = answer.parse::<i32>() else {
+        return cli.say("cli.invalid_amount", &[("amount", &answer)]);
+    };
+    match engine.apply(Action::SellCows(quantity)) {
+        Ok(logs) => cli.print_logs(&logs),
+        Err(e) => cli.say("app.error", &[("error", &e)]),
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use crate::cli;
+    use crate::config::GameRules;
+    use crate::game::{GameEngine, GameStateBuilder};
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 354).unwrap()
+    }
+
+    // With this seed Ann's first roll draws Mt. St. Helens, so Bo is asked to
+    // roll for the ash (the extra blank line after Ann's roll).
+
+    fn play(engine: &mut GameEngine, input: &str) -> String {
+        let mut output = Vec::new();
+        cli::run(engine, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_rolls_and_ends_turns_until_quit() {
+        let mut engine = engine();
+        let output = play(&mut engine, "\n\ne\n\ne\nq\n");
+
+        assert!(output.contains("=== Ann's turn ==="));
+        assert!(output.contains("=== Bo's turn ==="));
+        assert!(output.contains("Rolled a "), "Turn logs are printed");
+        assert!(output.contains("Bo, roll for the Mt. St. Helens ash"), "Other players answer their decisions");
+        assert_eq!(engine.state().players.iter().map(|p| p.turns_taken).sum::<i32>(), 2);
+        assert_eq!(engine.current_player_id(), 0, "Back to the first player when they quit");
+    }
+
+    #[test]
+    fn test_end_of_input_stops_the_game() {
+        let mut engine = engine();
+        play(&mut engine, "\n");
+        assert_eq!(engine.current_player_id(), 0, "Turn never ended");
+    }
+
+    #[test]
+    fn test_menu_lists_option_cards_and_rejects_bad_input() {
+        let mut engine = engine();
+        let first_card = engine.state().players[0].hand[0].title.clone();
+        let output = play(&mut engine, "\n\nx\no\n9\np\nlots\nq\n");
+
+        assert!(output.contains("Unknown choice 'x'."));
+        assert!(output.contains(&format!("1. {}", first_card)));
+        assert!(output.contains("Error: Invalid card selection."));
+        assert!(output.contains("'lots' is not an amount."));
+    }
+
+    #[test]
+    fn test_pays_down_debt() {
+        let mut engine = engine();
+        engine.state_mut().players[0].cash = Money(10_000);
+        engine.state_mut().players[0].debt = Money(5_000);
+        play(&mut engine, "\n\np\n$1000\nq\n");
+        assert_eq!(engine.state().players[0].debt, Money(4_000));
+    }
+
+    #[test]
+    fn test_offers_a_rematch_once_someone_wins() {
+        let rules = GameRules { winning_net_worth: Money(1), ..GameRules::default() };
+        let state = GameStateBuilder::default().rules(rules).add_human("Ann").add_human("Bo").seed(8).build().unwrap();
+        let mut engine = GameEngine::new(state);
+        let output = play(&mut engine, "\n\ny\nq\n");
+
+        assert!(output.contains("Play again with the same table?"), "{}", output);
+        assert_eq!(output.matches("=== Ann's turn ===").count(), 1);
+        assert!(output.contains("=== Bo's turn ==="), "Bo starts the rematch");
+        assert_eq!(engine.state().turn_order, vec![1, 0]);
+        assert!(engine.state().players.iter().all(|p| p.turns_taken == 0));
+    }
+}
@@ -1,14 +1,65 @@
 // Consolidated config file for the Farming Game
 // This replaces the individual modules in the config folder
 
-use crate::models::PlayerType;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::models::{AssetType, BoardTile, CalendarDate, HarvestType, Money, Month, PlayerColor, PlayerType};
 
 //----------------------------------------
 // Game Rules (from game_rules.rs)
 //----------------------------------------
 
 // Winning condition
-pub const WINNING_NET_WORTH: i32 = 250_000;
+pub const WINNING_NET_WORTH: Money = Money(250_000);
+
+// Year's wages from the off-farm side job, paid at the start of each year
+pub const SIDE_JOB_WAGE: Money = Money(5000);
+
+// Most cows a player can keep on the farm itself (ridges hold the rest)
+pub const FARM_COW_LIMIT: u32 = 20;
+
+// A farm has at most one of each upgrade
+pub const UPGRADE_LIMIT: u32 = 1;
+
+// What the bank pays per unit for assets sold to cover a bill, about half
+// their value; cows go at the market price instead
+pub const RESALE_VALUES: [(AssetType, Money); 8] = [
+    (AssetType::Grain, Money(1000)),
+    (AssetType::Hay, Money(1000)),
+    (AssetType::Fruit, Money(2500)),
+    (AssetType::Tractor, Money(5000)),
+    (AssetType::Harvester, Money(5000)),
+    (AssetType::Irrigation, Money(5000)),
+    (AssetType::Barn, Money(4000)),
+    (AssetType::Silo, Money(3000)),
+];
+
+// What can befall a stocked ridge's herd over the year, by the roll of a die;
+// any other roll is a quiet year
+pub const RIDGE_DANGERS: [RidgeDanger; 2] = [
+    RidgeDanger { roll: 1, threat: RidgeThreat::Coyotes { percent: 10 } },
+    RidgeDanger { roll: 2, threat: RidgeThreat::HardWinter { feed_per_cow: Money(100) } },
+];
+
+// Yearly interest charged on outstanding debt
+pub const INTEREST_RATE: f32 = 0.10;
+
+// Bank loans: issued in fixed increments, the bank keeps a fee, and total debt is capped
+pub const MAX_DEBT: Money = Money(50_000);
+pub const LOAN_INCREMENT: Money = Money(5000);
+pub const LOAN_FEE_RATE: f32 = 0.20;
+// Debt is paid back in multiples of this, or all at once
+pub const PAYMENT_INCREMENT: Money = Money(1000);
+
+// Option to Buy cards dealt to each player before the first turn
+pub const OPENING_OTB_CARDS: usize = 2;
+
+// Yearly insurance premiums, per insured crop acre and per insured cow
+pub const CROP_INSURANCE_PREMIUM: Money = Money(20);
+pub const LIVESTOCK_INSURANCE_PREMIUM: Money = Money(50);
 
 //----------------------------------------
 // Player Configuration (from player_config.rs)
@@ -17,24 +68,275 @@ pub const WINNING_NET_WORTH: i32 = 250_000;
 #[derive(Clone)]
 pub struct NativePlayer {
     pub name: &'static str,
-    pub color: &'static str,
+    pub color: PlayerColor,
 }
 
 pub const NATIVE_PLAYERS: [NativePlayer; 6] = [
-    NativePlayer { name: "Roza Ray", color: "Red" },
-    NativePlayer { name: "Harrah Harry", color: "Brown" },
-    NativePlayer { name: "Toppenish Tom", color: "Green" },
-    NativePlayer { name: "Satus Sam", color: "Blue" },
-    NativePlayer { name: "Sunnyside Sidney", color: "White" },
-    NativePlayer { name: "Wapato Willie", color: "Yellow" },
+    NativePlayer { name: "Roza Ray", color: PlayerColor::Red },
+    NativePlayer { name: "Harrah Harry", color: PlayerColor::Brown },
+    NativePlayer { name: "Toppenish Tom", color: PlayerColor::Green },
+    NativePlayer { name: "Satus Sam", color: PlayerColor::Blue },
+    NativePlayer { name: "Sunnyside Sidney", color: PlayerColor::White },
+    NativePlayer { name: "Wapato Willie", color: PlayerColor::Yellow },
 ];
 
-pub const STARTING_CASH: i32 = 5000;
+pub const STARTING_CASH: Money = Money(5000);
 pub const STARTING_LAND: i32 = 20;  // 20 acres from Grandpa
-pub const STARTING_DEBT: i32 = 0;
+pub const STARTING_DEBT: Money = Money::ZERO;
 pub const STARTING_YEAR: u32 = 1;
 pub const STARTING_POSITION: usize = 0;  // Kept this as it's used in Player::new()
 
 pub fn create_ai_player(name: &str) -> PlayerType {
     PlayerType::AI(name.to_string())
 }
+
+//----------------------------------------
+// House Rules
+//----------------------------------------
+
+/// Economy presets for solo play against the AI. Each one sets the interest
+/// rate and scales harvest income and the operating expenses drawn at harvest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Harvest income multiplier, operating expense multiplier and interest rate.
+    pub fn economy(&self) -> (f32, f32, f32) {
+        match self {
+            Difficulty::Easy => (1.2, 0.8, 0.05),
+            Difficulty::Normal => (1.0, 1.0, INTEREST_RATE),
+            Difficulty::Hard => (0.85, 1.25, 0.15),
+        }
+    }
+
+    /// Reads a difficulty typed at setup, e.g. "hard" or "H".
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+        Self::ALL.into_iter().find(|difficulty| {
+            let label = difficulty.label().to_lowercase();
+            !text.is_empty() && label.starts_with(&text)
+        })
+    }
+}
+
+/// Rules for a single game. Defaults to the constants above; any field can be
+/// overridden from a `rules.toml`, e.g.
+///
+/// ```toml
+/// difficulty = "hard"
+/// starting_cash = 10000
+/// winning_net_worth = 150000
+/// otb_window = { through_month = "june" }
+/// asset_limits = { Tractor = 1, Harvester = 1, Irrigation = 1, Barn = 1, Silo = 1 }
+/// ```
+///
+/// A difficulty sets the economy fields it covers unless the file sets them too.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameRules {
+    /// Preset the economy was last set from (see `with_difficulty`)
+    pub difficulty: Difficulty,
+    /// Scales every harvest's income
+    pub harvest_multiplier: f32,
+    /// Scales the operating expense cards drawn at harvest
+    pub operating_expense_multiplier: f32,
+    /// Replaces each player's starting cash when set
+    pub starting_cash: Option<Money>,
+    /// Replaces each player's starting debt when set
+    pub starting_debt: Option<Money>,
+    pub winning_net_worth: Money,
+    pub side_job_wage: Money,
+    /// Most cows one farm may own; ridges hold their own herds
+    pub farm_cow_limit: u32,
+    /// Fewest cows a new ridge lease may be stocked with; unset, the lease
+    /// takes the card's full herd
+    pub ridge_min_cows: Option<i32>,
+    /// Most of each other asset one farm may own, e.g. `{ Tractor = 1 }`;
+    /// unlisted assets are unlimited (see `game::constraints`)
+    pub asset_limits: BTreeMap<AssetType, u32>,
+    /// Hay, grain and fruit acres together may not exceed the farm's land
+    pub acres_limited_by_land: bool,
+    /// Rolled once a year for each stocked ridge as its leasee passes Go,
+    /// e.g. `[{ roll = 1, threat = "coyotes", percent = 10 }]`; empty for
+    /// ridges that never come to harm (see `game::ridge_dangers`)
+    pub ridge_dangers: Vec<RidgeDanger>,
+    /// What the bank pays per unit of each asset sold to raise cash, e.g.
+    /// `{ Tractor = 5000 }`; unlisted assets can't be sold (see `game::raise_cash`)
+    pub resale_values: BTreeMap<AssetType, Money>,
+    pub interest_rate: f32,
+    /// How long into the year Option to Buy cards may be exercised
+    pub otb_window: OtbWindow,
+    /// Option to Buy cards each player is dealt before the first turn
+    pub opening_otb_cards: usize,
+    /// Most cards a player may hold at the end of their turn; anything over
+    /// is discarded (see `game::discard`). No limit if unset.
+    pub hand_limit: Option<usize>,
+    pub max_debt: Money,
+    pub loan_increment: Money,
+    pub loan_fee_rate: f32,
+    pub payment_increment: Money,
+    pub crop_insurance_premium: Money,
+    pub livestock_insurance_premium: Money,
+    /// Settle turn order with the opening high roll instead of keeping the
+    /// order players were added in (see `game::turn_order`)
+    pub roll_for_turn_order: bool,
+    /// Racing the clock: anyone short of the winning net worth when this
+    /// year ends loses (solo mode)
+    pub year_limit: Option<u32>,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::Normal,
+            harvest_multiplier: 1.0,
+            operating_expense_multiplier: 1.0,
+            starting_cash: None,
+            starting_debt: None,
+            winning_net_worth: WINNING_NET_WORTH,
+            side_job_wage: SIDE_JOB_WAGE,
+            farm_cow_limit: FARM_COW_LIMIT,
+            ridge_min_cows: None,
+            asset_limits: AssetType::UPGRADES.iter().map(|upgrade| (*upgrade, UPGRADE_LIMIT)).collect(),
+            acres_limited_by_land: false,
+            ridge_dangers: RIDGE_DANGERS.to_vec(),
+            resale_values: RESALE_VALUES.into_iter().collect(),
+            interest_rate: INTEREST_RATE,
+            otb_window: OtbWindow::default(),
+            opening_otb_cards: OPENING_OTB_CARDS,
+            hand_limit: None,
+            max_debt: MAX_DEBT,
+            loan_increment: LOAN_INCREMENT,
+            loan_fee_rate: LOAN_FEE_RATE,
+            payment_increment: PAYMENT_INCREMENT,
+            crop_insurance_premium: CROP_INSURANCE_PREMIUM,
+            livestock_insurance_premium: LIVESTOCK_INSURANCE_PREMIUM,
+            roll_for_turn_order: false,
+            year_limit: None,
+        }
+    }
+}
+
+/// Part of the year in which Option to Buy cards may be exercised, read off
+/// the board's calendar so it holds for any board layout.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtbWindow {
+    /// Until the player reaches the year's first hay cutting
+    #[default]
+    UntilFirstHayCutting,
+    /// Through the last week of the month
+    ThroughMonth(Month),
+}
+
+/// One row of the ridge danger table: what a herd meets on `roll`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RidgeDanger {
+    pub roll: u32,
+    #[serde(flatten)]
+    pub threat: RidgeThreat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "threat", rename_all = "snake_case")]
+pub enum RidgeThreat {
+    /// Coyotes take this share of the herd, rounded up
+    Coyotes { percent: u32 },
+    /// The herd has to be fed through a hard winter at this much a head
+    HardWinter { feed_per_cow: Money },
+}
+
+impl OtbWindow {
+    /// Whether a player standing on `position` of `board` may still exercise options.
+    pub fn is_open(&self, board: &[BoardTile], position: usize) -> bool {
+        let Some(date) = CalendarDate::at(board, position) else { return false };
+        match self {
+            OtbWindow::UntilFirstHayCutting => board.iter()
+                .find(|tile| tile.harvest_type == HarvestType::HayCutting1)
+                .is_none_or(|hay| date < hay.date()),
+            OtbWindow::ThroughMonth(month) => date.month <= *month,
+        }
+    }
+}
+
+impl GameRules {
+    /// Parses rules from TOML; missing fields keep their defaults, or the
+    /// difficulty's where one is given.
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        let rules: Self = toml::from_str(contents)?;
+        let table: toml::Table = toml::from_str(contents)?;
+        let preset = Self::default().with_difficulty(rules.difficulty);
+        Ok(Self {
+            harvest_multiplier: if table.contains_key("harvest_multiplier") { rules.harvest_multiplier } else { preset.harvest_multiplier },
+            operating_expense_multiplier: if table.contains_key("operating_expense_multiplier") {
+                rules.operating_expense_multiplier
+            } else {
+                preset.operating_expense_multiplier
+            },
+            interest_rate: if table.contains_key("interest_rate") { rules.interest_rate } else { preset.interest_rate },
+            ..rules
+        })
+    }
+
+    /// Cows a lease must stock a ridge holding `capacity` cows with.
+    pub fn ridge_stocking_minimum(&self, capacity: i32) -> i32 {
+        self.ridge_min_cows.map_or(capacity, |cows| cows.clamp(0, capacity))
+    }
+
+    /// These rules with `difficulty`'s economy.
+    pub fn with_difficulty(self, difficulty: Difficulty) -> Self {
+        let (harvest_multiplier, operating_expense_multiplier, interest_rate) = difficulty.economy();
+        Self { difficulty, harvest_multiplier, operating_expense_multiplier, interest_rate, ..self }
+    }
+
+    /// Loads rules from a `rules.toml` file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::from_toml(&contents)?)
+    }
+
+    /// Interest owed on `debt` at the configured rate.
+    pub fn interest_on(&self, debt: Money) -> Money {
+        debt.times(self.interest_rate)
+    }
+
+    /// Whether `amount` may be paid off `owed`: a whole number of payment
+    /// increments, or everything that's owed.
+    pub fn is_valid_payment(&self, amount: Money, owed: Money) -> bool {
+        amount.is_positive() && (amount == owed || amount.dollars() % self.payment_increment.dollars().max(1) == 0)
+    }
+
+    /// Borrowing room left before hitting the debt cap.
+    pub fn remaining_credit(&self, debt: Money) -> Money {
+        self.max_debt - debt
+    }
+
+    /// Loan the bank makes to cover `shortfall`, in whole loan increments,
+    /// and the fee it keeps out of it. What's left after the fee still covers
+    /// the shortfall, so a forced loan never leaves cash negative.
+    pub fn forced_loan(&self, shortfall: Money) -> (Money, Money) {
+        let increment = self.loan_increment.max(Money(1));
+        let fee = |loan: Money| loan.times(self.loan_fee_rate);
+        let mut loan = increment * shortfall.units_at(increment);
+        while loan - fee(loan) < shortfall && fee(loan + increment) < loan + increment {
+            loan += increment;
+        }
+        (loan, fee(loan))
+    }
+}
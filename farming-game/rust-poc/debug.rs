@@ -0,0 +1,174 @@
+// src/debug.rs
+// Developer console for games started with `--debug`. A handful of commands
+// set up the moment a bug needs (cash, a board position, the next card off a
+// deck, a card's effect) without playing the game up to it.
+
+use crate::cards::card::Card;
+use crate::cards::deck::Deck;
+use crate::game::GameError;
+use crate::models::{GameState, Money};
+
+/// How many lines of output the console keeps.
+const MAX_OUTPUT: usize = 100;
+
+/// Command syntax, shown by `help` and when a command doesn't parse.
+pub const USAGE: &[&str] = &[
+    "cash <player> <amount>       give (or take) cash",
+    "move <player> <tile>         put a player on a tile, without resolving it",
+    "stack <deck> <card title>    put a card on top of fate, expense or otb",
+    "effect <player> <card title> apply a card's effect to a player",
+];
+
+/// A deck the console can stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckName {
+    FarmerFate,
+    OperatingCost,
+    OptionToBuy,
+}
+
+impl DeckName {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "fate" => Some(DeckName::FarmerFate),
+            "expense" => Some(DeckName::OperatingCost),
+            "otb" => Some(DeckName::OptionToBuy),
+            _ => None,
+        }
+    }
+}
+
+/// A console command, as typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebugCommand {
+    Help,
+    GiveCash { player_id: usize, amount: Money },
+    SetPosition { player_id: usize, position: usize },
+    /// Puts the card titled `title` on top of `deck`'s draw pile
+    StackCard { deck: DeckName, title: String },
+    /// Applies the effect of the card titled `title`, from any deck
+    Trigger { player_id: usize, title: String },
+}
+
+impl DebugCommand {
+    /// Parses a line like `cash 0 5000`. Card titles match without regard to case.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let usage = || USAGE.iter().find(|usage| usage.starts_with(&format!("{} ", name)))
+            .map_or_else(|| format!("unknown command '{}'; try help", name), |usage| format!("usage: {}", usage));
+        let index = |word: Option<&str>| word.and_then(|word| word.parse::<usize>().ok());
+        let command = match name {
+            "" | "help" => Some(DebugCommand::Help),
+            "cash" => match (index(words.next()), words.next().and_then(|word| word.parse().ok())) {
+                (Some(player_id), Some(amount)) => Some(DebugCommand::GiveCash { player_id, amount }),
+                _ => None,
+            },
+            "move" => match (index(words.next()), index(words.next())) {
+                (Some(player_id), Some(position)) => Some(DebugCommand::SetPosition { player_id, position }),
+                _ => None,
+            },
+            "stack" => words.next().and_then(DeckName::parse)
+                .map(|deck| DebugCommand::StackCard { deck, title: words.collect::<Vec<_>>().join(" ") }),
+            "effect" => index(words.next())
+                .map(|player_id| DebugCommand::Trigger { player_id, title: words.collect::<Vec<_>>().join(" ") }),
+            _ => None,
+        };
+        match command {
+            Some(DebugCommand::StackCard { title, .. } | DebugCommand::Trigger { title, .. }) if title.is_empty() => Err(usage()),
+            Some(command) => Ok(command),
+            None => Err(usage()),
+        }
+    }
+}
+
+impl GameState {
+    fn deck_mut(&mut self, deck: DeckName) -> &mut Deck {
+        match deck {
+            DeckName::FarmerFate => &mut self.farmer_fate_deck,
+            DeckName::OperatingCost => &mut self.operating_cost_deck,
+            DeckName::OptionToBuy => &mut self.option_to_buy_deck,
+        }
+    }
+
+    /// The card titled `title` in any deck's draw or discard pile.
+    fn find_card(&self, title: &str) -> Option<&Card> {
+        [&self.farmer_fate_deck, &self.operating_cost_deck, &self.option_to_buy_deck].into_iter()
+            .flat_map(|deck| deck.draw_pile.iter().chain(&deck.discard_pile))
+            .find(|card| card.title.eq_ignore_ascii_case(title))
+    }
+
+    /// Runs a console command. Changes skip the ledger and the rules' limits;
+    /// effects resolve as if a card had been drawn, decisions and all.
+    pub fn run_debug_command(&mut self, command: &DebugCommand, logs: &mut Vec<String>) -> Result<(), GameError> {
+        tracing::info!(?command, "debug command");
+        match command {
+            // The console prints the usage itself; nothing happens in the game
+            DebugCommand::Help => {}
+            DebugCommand::GiveCash { player_id, amount } => {
+                let player = self.players.get_mut(*player_id).ok_or(GameError::PlayerNotFound(*player_id))?;
+                player.cash += *amount;
+                logs.push(self.locale.tr("debug.cash", &[("player", &player.name), ("amount", amount), ("cash", &player.cash)]));
+                self.update_player_valuation(*player_id);
+            }
+            DebugCommand::SetPosition { player_id, position } => {
+                let tile = self.board.get(*position).ok_or(GameError::InvalidPosition(*position))?.name.clone();
+                let player = self.players.get_mut(*player_id).ok_or(GameError::PlayerNotFound(*player_id))?;
+                player.position = *position;
+                logs.push(self.locale.tr("debug.moved", &[("player", &player.name), ("tile", &tile)]));
+                self.update_phase();
+            }
+            DebugCommand::StackCard { deck, title } => {
+                let deck = self.deck_mut(*deck);
+                let card = match deck.draw_pile.iter().position(|card| card.title.eq_ignore_ascii_case(title)) {
+                    Some(index) => deck.draw_pile.remove(index),
+                    None => {
+                        let index = deck.discard_pile.iter().position(|card| card.title.eq_ignore_ascii_case(title))
+                            .ok_or_else(|| GameError::InvalidCard(title.clone()))?;
+                        deck.discard_pile.remove(index)
+                    }
+                };
+                deck.draw_pile.insert(0, card);
+                logs.push(self.locale.tr("debug.stacked", &[("card", title)]));
+            }
+            DebugCommand::Trigger { player_id, title } => {
+                let card = self.find_card(title).cloned().ok_or_else(|| GameError::InvalidCard(title.clone()))?;
+                self.apply_card_effect(*player_id, &card, logs)?;
+                self.update_player_valuation(*player_id);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Console state: whether it's showing, the line being typed and what
+/// earlier commands printed.
+#[derive(Debug, Clone, Default)]
+pub struct DebugConsole {
+    pub open: bool,
+    pub input: String,
+    pub output: Vec<String>,
+}
+
+impl DebugConsole {
+    /// Runs the typed line against `game` and clears it. Returns the game's
+    /// log lines, which also go to the console's own output.
+    pub fn submit(&mut self, game: &mut GameState) -> Vec<String> {
+        let line = std::mem::take(&mut self.input);
+        self.output.push(format!("> {}", line));
+        let mut logs = Vec::new();
+        match DebugCommand::parse(&line) {
+            Ok(DebugCommand::Help) => self.output.extend(USAGE.iter().map(|usage| usage.to_string())),
+            Ok(command) => {
+                if let Err(e) = game.run_debug_command(&command, &mut logs) {
+                    self.output.push(e.to_string());
+                }
+                self.output.extend(logs.iter().cloned());
+            }
+            Err(usage) => self.output.push(usage),
+        }
+        let overflow = self.output.len().saturating_sub(MAX_OUTPUT);
+        self.output.drain(..overflow);
+        logs
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::debug::{DebugCommand, DebugConsole, DeckName};
+    use crate::game::GameError;
+    use crate::models::GameState;
+    use crate::models::Money;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(DebugCommand::parse("cash 1 -500"), Ok(DebugCommand::GiveCash { player_id: 1, amount: Money(-500) }));
+        assert_eq!(DebugCommand::parse("  move 0 14 "), Ok(DebugCommand::SetPosition { player_id: 0, position: 14 }));
+        assert_eq!(DebugCommand::parse("stack Expense fuel bill"),
+                   Ok(DebugCommand::StackCard { deck: DeckName::OperatingCost, title: "fuel bill".to_string() }));
+        assert_eq!(DebugCommand::parse("effect 2 Bad at Taxes"),
+                   Ok(DebugCommand::Trigger { player_id: 2, title: "Bad at Taxes".to_string() }));
+        assert_eq!(DebugCommand::parse(""), Ok(DebugCommand::Help));
+
+        assert_eq!(DebugCommand::parse("cash one 500"), Err("usage: cash <player> <amount>       give (or take) cash".to_string()));
+        assert!(DebugCommand::parse("stack fate").is_err(), "A card title is required");
+        assert!(DebugCommand::parse("stack attic Fuel Bill").is_err());
+        assert_eq!(DebugCommand::parse("win"), Err("unknown command 'win'; try help".to_string()));
+    }
+
+    #[test]
+    fn test_commands_change_the_game() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let cash = game.players[player_id].cash;
+        let mut logs = Vec::new();
+
+        game.run_debug_command(&DebugCommand::GiveCash { player_id, amount: Money(2500) }, &mut logs).unwrap();
+        assert_eq!(game.players[player_id].cash, cash + Money(2500));
+
+        game.run_debug_command(&DebugCommand::SetPosition { player_id, position: 14 }, &mut logs).unwrap();
+        assert_eq!(game.players[player_id].position, 14);
+        assert_eq!(game.run_debug_command(&DebugCommand::SetPosition { player_id, position: 99 }, &mut logs),
+                   Err(GameError::InvalidPosition(99)));
+
+        let stack = DebugCommand::StackCard { deck: DeckName::OperatingCost, title: "FUEL BILL".to_string() };
+        game.run_debug_command(&stack, &mut logs).unwrap();
+        assert_eq!(game.operating_cost_deck.draw().unwrap().title, "Fuel Bill");
+        let stack = DebugCommand::StackCard { deck: DeckName::FarmerFate, title: "Fuel Bill".to_string() };
+        assert_eq!(game.run_debug_command(&stack, &mut logs), Err(GameError::InvalidCard("Fuel Bill".to_string())),
+                   "Cards are only stacked on their own deck");
+
+        game.run_debug_command(&DebugCommand::Trigger { player_id, title: "bad at taxes".to_string() }, &mut logs).unwrap();
+        assert!(game.players[player_id].harvest_income_garnished);
+    }
+
+    #[test]
+    fn test_console_reports_errors_and_keeps_the_game_log_clean() {
+        let mut game = GameState::new();
+        let mut console = DebugConsole { input: "help".to_string(), ..DebugConsole::default() };
+        assert!(console.submit(&mut game).is_empty(), "Help is for the console only");
+        assert!(console.input.is_empty());
+
+        console.input = "cash 9 100".to_string();
+        assert!(console.submit(&mut game).is_empty());
+        assert_eq!(console.output.last().map(String::as_str), Some("Player 9 not found"));
+
+        console.input = format!("cash {} 100", game.turn_order[0]);
+        let logs = console.submit(&mut game);
+        assert_eq!(logs.len(), 1);
+        assert_eq!(console.output.last(), logs.last());
+    }
+}
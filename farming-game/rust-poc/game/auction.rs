@@ -0,0 +1,179 @@
+// src/game/auction.rs
+// Auctioning an Option to Buy card the holder declined to exercise.
+
+use crate::cards::card::Card;
+use crate::game::{GameEffect, GameError};
+use crate::models::{format_money, GameState, Money, PlayerType, PlayerView};
+
+/// Smallest raise over the current high bid.
+pub const MIN_BID_INCREMENT: Money = Money(100);
+
+/// Share of the card's exercise cost an AI bidder is willing to pay for the card itself.
+const AI_MAX_BID_SHARE: f32 = 0.10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuctionStatus {
+    /// Waiting on this player to bid or pass
+    Bidding { bidder: usize },
+    Sold { winner: usize, price: Money },
+    /// Everyone passed; the card goes back to the seller
+    Unsold,
+}
+
+/// An open-outcry auction for one card. Bidders take turns (in turn order,
+/// skipping the seller) raising or passing; passing drops a bidder out. The
+/// auction ends when one bidder is left holding the high bid, or when all pass.
+#[derive(Debug, Clone)]
+pub struct Auction {
+    pub card: Card,
+    pub seller: usize,
+    /// Bidders still in, in bidding order
+    bidders: Vec<usize>,
+    current: usize,
+    pub high_bid: Money,
+    pub high_bidder: Option<usize>,
+}
+
+impl Auction {
+    pub fn new(card: Card, seller: usize, turn_order: &[usize]) -> Self {
+        // Bidding starts with the player after the seller
+        let seller_index = turn_order.iter().position(|&id| id == seller).unwrap_or(0);
+        let bidders = turn_order.iter().cycle()
+            .skip(seller_index + 1)
+            .take(turn_order.len())
+            .copied()
+            .filter(|&id| id != seller)
+            .collect();
+        Self { card, seller, bidders, current: 0, high_bid: Money::ZERO, high_bidder: None }
+    }
+
+    pub fn status(&self) -> AuctionStatus {
+        match (self.high_bidder, self.bidders.as_slice()) {
+            (Some(winner), [only]) if *only == winner => AuctionStatus::Sold { winner, price: self.high_bid },
+            (None, []) => AuctionStatus::Unsold,
+            _ => AuctionStatus::Bidding { bidder: self.bidders[self.current] },
+        }
+    }
+
+    pub fn current_bidder(&self) -> Option<usize> {
+        match self.status() {
+            AuctionStatus::Bidding { bidder } => Some(bidder),
+            _ => None,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_bidder().is_none()
+    }
+
+    /// Lowest bid the current bidder may make.
+    pub fn minimum_bid(&self) -> Money {
+        if self.high_bidder.is_some() {
+            self.high_bid + MIN_BID_INCREMENT
+        } else {
+            MIN_BID_INCREMENT
+        }
+    }
+
+    /// Raises the high bid. Bids are paid from cash, so they can't exceed it.
+    pub fn bid(&mut self, bidder: usize, amount: Money, cash: Money) -> Result<(), GameError> {
+        self.check_turn(bidder)?;
+        if amount < self.minimum_bid() {
+            return Err(GameError::InvalidBid(format!("bid must be at least {}", format_money(self.minimum_bid()))));
+        }
+        if amount > cash {
+            return Err(GameError::InsufficientFunds { required: amount, available: cash });
+        }
+        self.high_bid = amount;
+        self.high_bidder = Some(bidder);
+        self.advance();
+        Ok(())
+    }
+
+    /// Drops the current bidder out of the auction.
+    pub fn pass(&mut self, bidder: usize) -> Result<(), GameError> {
+        self.check_turn(bidder)?;
+        self.bidders.remove(self.current);
+        if self.current >= self.bidders.len() {
+            self.current = 0;
+        }
+        self.skip_high_bidder();
+        Ok(())
+    }
+
+    fn check_turn(&self, bidder: usize) -> Result<(), GameError> {
+        match self.current_bidder() {
+            Some(current) if current == bidder => Ok(()),
+            Some(current) => Err(GameError::InvalidBid(format!("it is player {}'s turn to bid", current))),
+            None => Err(GameError::InvalidBid("the auction is over".to_string())),
+        }
+    }
+
+    fn advance(&mut self) {
+        self.current = (self.current + 1) % self.bidders.len();
+        self.skip_high_bidder();
+    }
+
+    /// The high bidder never has to outbid themselves.
+    fn skip_high_bidder(&mut self) {
+        if self.bidders.len() > 1 && Some(self.bidders[self.current]) == self.high_bidder {
+            self.current = (self.current + 1) % self.bidders.len();
+        }
+    }
+}
+
+impl GameState {
+    /// Takes an Option to Buy card out of the player's hand and opens bidding on it.
+    pub fn start_auction(&mut self, seller: usize, card_id: usize) -> Result<Auction, GameError> {
+        let player = self.players.get_mut(seller).ok_or(GameError::PlayerNotFound(seller))?;
+        let index = player.hand.iter().position(|c| c.id == card_id)
+            .ok_or(GameError::CardNotFound { player_id: seller, card_id })?;
+        let card = player.hand.remove(index);
+        tracing::info!(seller, card_id, card = %card.title, "auction started");
+        Ok(Auction::new(card, seller, &self.turn_order))
+    }
+
+    /// Pays the seller and hands the card to the winner, or returns an unsold
+    /// card to the seller. The auction must be finished.
+    pub fn settle_auction(&mut self, auction: Auction, logs: &mut Vec<String>) -> Result<(), GameError> {
+        match auction.status() {
+            AuctionStatus::Bidding { bidder } => {
+                Err(GameError::InvalidBid(format!("auction still waiting on player {}", bidder)))
+            }
+            AuctionStatus::Unsold => {
+                let seller = self.players.get_mut(auction.seller).ok_or(GameError::PlayerNotFound(auction.seller))?;
+                logs.push(self.locale.tr("auction.unsold", &[("card", &self.locale.card_title(&auction.card)), ("player", &seller.name)]));
+                seller.hand.push(auction.card);
+                Ok(())
+            }
+            AuctionStatus::Sold { winner, price } => {
+                self.transfer_between_players(winner, auction.seller, price, logs)?;
+                let buyer = self.players.get_mut(winner).ok_or(GameError::PlayerNotFound(winner))?;
+                logs.push(self.locale.tr("auction.sold", &[("player", &buyer.name), ("card", &self.locale.card_title(&auction.card)), ("price", &price)]));
+                tracing::info!(winner, seller = auction.seller, %price, card_id = auction.card.id, "auction sold");
+                buyer.hand.push(auction.card);
+                Ok(())
+            }
+        }
+    }
+
+    /// What an AI bidder does on their turn: `Some(amount)` to raise, `None` to pass.
+    /// The bidder only gets to see the game from their own seat.
+    pub fn ai_auction_bid(&self, auction: &Auction, bidder: usize) -> Option<Money> {
+        ai_auction_bid(&self.view_for(bidder).ok()?, auction)
+    }
+}
+
+/// AIs value the card at a fixed share of what it costs to exercise.
+fn ai_auction_bid(view: &PlayerView, auction: &Auction) -> Option<Money> {
+    if !matches!(view.viewer.player_type, PlayerType::AI(_)) {
+        return None;
+    }
+    let cost = match &auction.card.effect {
+        GameEffect::OptionalBuyAsset { cost, .. } | GameEffect::LeaseRidge { cost, .. } => *cost,
+        _ => Money::ZERO,
+    };
+    let limit = cost.times(AI_MAX_BID_SHARE).min(view.viewer.cash);
+    let bid = auction.minimum_bid();
+    (bid <= limit).then_some(bid)
+}
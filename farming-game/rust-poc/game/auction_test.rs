@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{AuctionStatus, GameError};
+    use crate::models::{GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    /// Seller 0 plus two bidders; bidder 2 is an AI.
+    fn setup_auction_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Seller".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Bidder".to_string(), PlayerType::Human));
+        players.insert(Player::new(2, "Bot".to_string(), PlayerType::AI("test".to_string())));
+        let mut game = GameState::new_with_players(players, vec![0, 1, 2], GameRules::default());
+        for player in game.players.iter_mut() {
+            player.cash = Money(5000);
+        }
+        let card = game.option_to_buy_deck.draw_pile.remove(0);
+        game.players.get_mut(0).unwrap().hand.push(card);
+        game
+    }
+
+    #[test]
+    fn test_auction_sells_to_last_bidder_standing() {
+        let mut game = setup_auction_game();
+        let card_id = game.players[0].hand[0].id;
+        let mut auction = game.start_auction(0, card_id).unwrap();
+        assert!(game.players[0].hand.is_empty());
+
+        assert_eq!(auction.status(), AuctionStatus::Bidding { bidder: 1 });
+        assert!(matches!(auction.bid(2, Money(500), Money(5000)), Err(GameError::InvalidBid(_))), "Not bidder 2's turn");
+        auction.bid(1, Money(500), Money(5000)).unwrap();
+        assert!(matches!(auction.bid(2, Money(550), Money(5000)), Err(GameError::InvalidBid(_))), "Raise is below the increment");
+        auction.bid(2, Money(600), Money(5000)).unwrap();
+        // Bidder 1 is next again and drops out, leaving the bot with the high bid
+        auction.pass(1).unwrap();
+        assert_eq!(auction.status(), AuctionStatus::Sold { winner: 2, price: Money(600) });
+
+        let mut logs = Vec::new();
+        game.settle_auction(auction, &mut logs).unwrap();
+        assert_eq!(game.players[2].hand[0].id, card_id);
+        assert_eq!(game.players[2].cash, Money(4400));
+        assert_eq!(game.players[0].cash, Money(5600));
+        assert!(logs.iter().any(|l| l.starts_with("Bot won")));
+    }
+
+    #[test]
+    fn test_unsold_card_returns_to_seller() {
+        let mut game = setup_auction_game();
+        let card_id = game.players[0].hand[0].id;
+        let mut auction = game.start_auction(0, card_id).unwrap();
+        auction.pass(1).unwrap();
+        auction.pass(2).unwrap();
+        assert_eq!(auction.status(), AuctionStatus::Unsold);
+
+        game.settle_auction(auction, &mut Vec::new()).unwrap();
+        assert_eq!(game.players[0].hand[0].id, card_id);
+        assert_eq!(game.players[0].cash, Money(5000));
+    }
+
+    #[test]
+    fn test_bids_are_limited_to_cash() {
+        let mut game = setup_auction_game();
+        let card_id = game.players[0].hand[0].id;
+        let mut auction = game.start_auction(0, card_id).unwrap();
+        assert_eq!(auction.bid(1, Money(6000), Money(5000)), Err(GameError::InsufficientFunds { required: Money(6000), available: Money(5000) }));
+    }
+
+    #[test]
+    fn test_ai_bids_up_to_a_share_of_the_exercise_cost() {
+        let mut game = setup_auction_game();
+        let card_id = game.players[0].hand[0].id;
+        let mut auction = game.start_auction(0, card_id).unwrap();
+        auction.pass(1).unwrap();
+
+        assert_eq!(game.ai_auction_bid(&auction, 2), Some(auction.minimum_bid()));
+        assert_eq!(game.ai_auction_bid(&auction, 1), None, "Human bidders are never automated");
+
+        game.players.get_mut(2).unwrap().cash = Money::ZERO;
+        assert_eq!(game.ai_auction_bid(&auction, 2), None);
+    }
+}
@@ -0,0 +1,94 @@
+// src/game/autosnapshot.rs
+// A debugging layer that audits every turn. The engine keeps a snapshot of the
+// game as each turn begins; when the turn ends, the turn's bookkeeping (every
+// ledger entry booked and every note borrowed or repaid) is replayed against
+// the snapshot as mutation commands and the result compared with the live
+// game. Cash that an effect applied twice or never booked shows up as a
+// difference, and so do scoreboard totals that missed an update. Snapshots
+// cost a clone of the game per turn, so the layer is off unless turned on with
+// `GameEngine::set_autosnapshots`.
+
+use thiserror::Error;
+use crate::game::{GameError, InvariantViolation, StateMutation};
+use crate::models::GameState;
+use crate::models::Money;
+
+/// Something the replayed turn disagrees with the live game about.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum SnapshotMismatch {
+    #[error("Player {player_id} has ${live} cash, but replaying the turn's books gives ${replayed}")]
+    Cash { player_id: usize, live: Money, replayed: Money },
+
+    #[error("Player {player_id} owes ${live}, but replaying the turn's books gives ${replayed}")]
+    Debt { player_id: usize, live: Money, replayed: Money },
+
+    #[error("Player {player_id}'s income and expense totals (${income}/${expenses}) are out of step with their ledger")]
+    LedgerTotals { player_id: usize, income: Money, expenses: Money },
+
+    #[error("The turn's books don't replay: {0}")]
+    Replay(GameError),
+
+    #[error(transparent)]
+    Invariant(InvariantViolation),
+}
+
+/// The turn's bookkeeping between `snapshot` and `live` as mutation
+/// commands: a cash change for each ledger entry booked since, and each
+/// player's net borrowing.
+pub fn turn_mutations(snapshot: &GameState, live: &GameState) -> Vec<StateMutation> {
+    let mut mutations = Vec::new();
+    for player in live.players.iter() {
+        let Some(before) = snapshot.players.get(player.id) else { continue };
+        let booked = player.ledger.transactions.get(before.ledger.transactions.len()..).unwrap_or_default();
+        mutations.extend(booked.iter().map(|transaction| StateMutation::CashDelta {
+            player_id: player.id,
+            amount: transaction.amount,
+            category: transaction.category,
+        }));
+        if player.debt != before.debt {
+            mutations.push(StateMutation::DebtDelta { player_id: player.id, amount: player.debt - before.debt });
+        }
+    }
+    mutations
+}
+
+/// `snapshot` with `mutations` applied. Loan principal moves cash without a
+/// ledger entry, so borrowing and repaying move cash here too.
+pub fn replay_turn(snapshot: &GameState, mutations: &[StateMutation]) -> Result<GameState, GameError> {
+    let mut replayed = snapshot.clone();
+    replayed.apply_mutations(mutations)?;
+    for mutation in mutations {
+        if let StateMutation::DebtDelta { player_id, amount } = *mutation {
+            replayed.players[player_id].cash += amount;
+        }
+    }
+    Ok(replayed)
+}
+
+/// Everything wrong with `live` as a continuation of `snapshot`: differences
+/// from the replayed turn, then any broken invariant of the live game.
+pub fn audit_turn(snapshot: &GameState, live: &GameState) -> Vec<SnapshotMismatch> {
+    let mut mismatches = Vec::new();
+    match replay_turn(snapshot, &turn_mutations(snapshot, live)) {
+        Err(e) => mismatches.push(SnapshotMismatch::Replay(e)),
+        Ok(replayed) => {
+            for player in live.players.iter() {
+                let Some(expected) = replayed.players.get(player.id) else { continue };
+                let player_id = player.id;
+                if player.cash != expected.cash {
+                    mismatches.push(SnapshotMismatch::Cash { player_id, live: player.cash, replayed: expected.cash });
+                }
+                if player.debt != expected.debt {
+                    mismatches.push(SnapshotMismatch::Debt { player_id, live: player.debt, replayed: expected.debt });
+                }
+                if player.total_income != player.ledger.income() || player.total_expenses != player.ledger.expenses() {
+                    mismatches.push(SnapshotMismatch::LedgerTotals { player_id, income: player.total_income, expenses: player.total_expenses });
+                }
+            }
+        }
+    }
+    if let Err(violations) = live.check_invariants() {
+        mismatches.extend(violations.into_iter().map(SnapshotMismatch::Invariant));
+    }
+    mismatches
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::autosnapshot::{audit_turn, turn_mutations};
+    use crate::game::balance::{answer_decisions, finish_turn};
+    use crate::game::{Action, GameEngine, InvariantViolation, SnapshotMismatch, StateMutation};
+    use crate::models::LedgerCategory;
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string(), "Cy".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 21).unwrap()
+    }
+
+    #[test]
+    fn test_audited_turns_of_a_whole_game_pass() {
+        let mut engine = engine();
+        engine.set_autosnapshots(true);
+        // A failed audit panics in tests, which run with debug assertions
+        for _ in 0..120 {
+            let player_id = engine.current_player_id();
+            engine.apply(Action::Roll).unwrap();
+            answer_decisions(&mut engine);
+            for card in engine.state().players[player_id].hand.clone() {
+                let _ = engine.apply(Action::ExerciseOption { card_id: card.id, confirm_loan: true });
+                answer_decisions(&mut engine);
+            }
+            finish_turn(&mut engine);
+        }
+    }
+
+    #[test]
+    fn test_the_turns_books_become_mutations() {
+        let snapshot = engine().state().fork();
+        let mut live = snapshot.clone();
+        live.apply_mutations(&[
+            StateMutation::CashDelta { player_id: 1, amount: Money(700), category: LedgerCategory::Bonus },
+            StateMutation::DebtDelta { player_id: 2, amount: Money(5000) },
+        ]).unwrap();
+        live.players[2].cash += Money(5000);
+        assert_eq!(turn_mutations(&snapshot, &live), vec![
+            StateMutation::CashDelta { player_id: 1, amount: Money(700), category: LedgerCategory::Bonus },
+            StateMutation::DebtDelta { player_id: 2, amount: Money(5000) },
+        ]);
+        live.update_all_valuations();
+        assert_eq!(audit_turn(&snapshot, &live), vec![]);
+    }
+
+    #[test]
+    fn test_cash_applied_twice_is_caught() {
+        let snapshot = engine().state().fork();
+        let mut live = snapshot.clone();
+        let bonus = StateMutation::CashDelta { player_id: 0, amount: Money(500), category: LedgerCategory::Bonus };
+        live.apply_mutations(&[bonus]).unwrap();
+        live.players[0].cash += Money(500);
+        live.update_all_valuations();
+        let cash = snapshot.players[0].cash;
+        assert_eq!(audit_turn(&snapshot, &live), vec![SnapshotMismatch::Cash { player_id: 0, live: cash + Money(1000), replayed: cash + Money(500) }]);
+    }
+
+    #[test]
+    fn test_skipped_scoreboard_updates_are_caught() {
+        let snapshot = engine().state().fork();
+        let mut live = snapshot.clone();
+        // Booked straight into the ledger, skipping the income total and net worth
+        live.players[1].cash += Money(300);
+        live.players[1].ledger.record(1, LedgerCategory::Harvest, Money(300));
+        let mismatches = audit_turn(&snapshot, &live);
+        assert!(matches!(mismatches[0], SnapshotMismatch::LedgerTotals { player_id: 1, income: Money::ZERO, .. }), "{:?}", mismatches);
+        assert!(mismatches.iter().any(|mismatch| matches!(mismatch, SnapshotMismatch::Invariant(InvariantViolation::NetWorthMismatch { player_id: 1, .. }))));
+    }
+}
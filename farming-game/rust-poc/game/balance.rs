@@ -0,0 +1,175 @@
+// src/game/balance.rs
+// Card and tile balance from simulated games: AI farmers play out seeded
+// games and every card played and tile landed on is credited with the cash
+// it moved (`GameEvent::CardResolved` and `GameEvent::TileResolved`), for
+// tuning card packs and house rules.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::game::{Action, Decision, GameEngine, GameError, GameEvent, GameStateBuilder};
+use crate::game::monte_carlo::{plays_monte_carlo, MonteCarlo};
+use crate::models::{GameState, Money};
+use crate::models::ledger::csv_field;
+
+/// Farmers at a simulated table unless the caller seats more.
+pub const SIMULATED_PLAYERS: usize = 4;
+
+/// `PlayerType::AI` strategy name for farmers who exercise every option they can pay for.
+pub const SIMULATED_STRATEGY: &str = "simulated";
+
+/// Games still going after this many years are called off.
+pub const MAX_SIMULATED_YEARS: u32 = 20;
+
+/// First line of a balance report's CSV.
+pub const BALANCE_CSV_HEADER: &str = "kind,name,times,average_cash,total_cash\n";
+
+/// How often something happened and the cash it moved in all.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectStats {
+    pub times: u32,
+    pub total_cash: i64,
+}
+
+impl EffectStats {
+    fn add(&mut self, cash_change: Money) {
+        self.times += 1;
+        self.total_cash = self.total_cash.saturating_add(cash_change.dollars());
+    }
+
+    /// Average cash change each time; 0 if it never happened.
+    pub fn average(&self) -> f64 {
+        if self.times == 0 { 0.0 } else { self.total_cash as f64 / f64::from(self.times) }
+    }
+}
+
+/// What every card and tile was worth over a batch of games.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BalanceReport {
+    pub games: usize,
+    /// By card title; Option to Buy cards aren't played, so they don't show up
+    pub cards: BTreeMap<String, EffectStats>,
+    /// By board position, with the tile's name
+    pub tiles: BTreeMap<usize, (String, EffectStats)>,
+}
+
+impl BalanceReport {
+    /// Tallies the cards and tiles of one finished game.
+    pub fn record(&mut self, game: &GameState) {
+        self.games += 1;
+        for event in &game.events {
+            match event {
+                GameEvent::CardResolved { card, cash_change, .. } => {
+                    self.cards.entry(card.clone()).or_default().add(*cash_change);
+                }
+                GameEvent::TileResolved { tile, cash_change, .. } => {
+                    let name = game.board.get(*tile).map_or_else(|| format!("Tile {}", tile), |tile| tile.name.clone());
+                    self.tiles.entry(*tile).or_insert_with(|| (name, EffectStats::default())).1.add(*cash_change);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Cards, worst average first.
+    pub fn cards_by_average(&self) -> Vec<(&str, &EffectStats)> {
+        let mut cards: Vec<(&str, &EffectStats)> = self.cards.iter().map(|(name, stats)| (name.as_str(), stats)).collect();
+        cards.sort_by(|a, b| a.1.average().total_cmp(&b.1.average()));
+        cards
+    }
+
+    /// Every card and tile as CSV, with a header.
+    pub fn csv(&self) -> String {
+        let row = |kind: &str, name: &str, stats: &EffectStats| {
+            format!("{},{},{},{:.0},{}\n", kind, csv_field(name), stats.times, stats.average(), stats.total_cash)
+        };
+        let cards = self.cards_by_average().into_iter().map(|(name, stats)| row("card", name, stats));
+        let tiles = self.tiles.values().map(|(name, stats)| row("tile", name, stats));
+        std::iter::once(BALANCE_CSV_HEADER.to_string()).chain(cards).chain(tiles).collect()
+    }
+}
+
+impl fmt::Display for BalanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Balance over {} games (average cash change, times seen)", self.games)?;
+        writeln!(f, "\nCards, worst first:")?;
+        for (name, stats) in self.cards_by_average() {
+            writeln!(f, "  {:>+9.0} {:>7}  {}", stats.average(), stats.times, name)?;
+        }
+        writeln!(f, "\nTiles, in board order:")?;
+        for (index, (name, stats)) in &self.tiles {
+            writeln!(f, "  {:>+9.0} {:>7}  {:>2} {}", stats.average(), stats.times, index, name)?;
+        }
+        Ok(())
+    }
+}
+
+/// A table of `players` AI farmers playing by `strategy` (`SIMULATED_STRATEGY`
+/// or `monte_carlo::MONTE_CARLO_STRATEGY`) to simulate with `simulate`.
+pub fn simulation_table(players: usize, strategy: &str) -> GameStateBuilder {
+    (1..=players).fold(GameStateBuilder::default(), |builder, seat| builder.add_ai(format!("Farmer {}", seat), strategy))
+}
+
+/// Plays `games` games as rematches of `table` (same rules, board, card
+/// packs and scenario, with the start player rotating) and tallies them.
+/// Simulated farmers exercise every option they can pay for in cash, or
+/// weigh them with rollouts if they play by `MONTE_CARLO_STRATEGY`, and
+/// answer decisions as the AI would. Seed `table` for a repeatable report.
+pub fn simulate(table: &GameState, games: usize) -> Result<BalanceReport, GameError> {
+    let mut report = BalanceReport::default();
+    let mut previous = table.clone();
+    for game in 0..games {
+        let state = previous.rematch()?;
+        let mut engine = GameEngine::new(state);
+        play_out(&mut engine);
+        tracing::debug!(game, winner = ?engine.winner(), "simulated game");
+        report.record(engine.state());
+        previous = engine.into_state();
+    }
+    Ok(report)
+}
+
+/// Plays until someone wins, everyone is out, or the year limit is reached.
+fn play_out(engine: &mut GameEngine) {
+    let over = |state: &GameState| state.players.iter().all(|player| !player.is_active || player.year > MAX_SIMULATED_YEARS);
+    // Every roll moves at least a tile, so this is enough turns for the years; a stuck game stops here
+    let turns = engine.state().board.len() * engine.state().players.len() * MAX_SIMULATED_YEARS as usize;
+    for _ in 0..turns {
+        if engine.winner().is_some() || over(engine.state()) {
+            break;
+        }
+        let player_id = engine.current_player_id();
+        if engine.state().players[player_id].is_active {
+            let _ = engine.apply(Action::Roll);
+            answer_decisions(engine);
+            if plays_monte_carlo(engine.state(), player_id) {
+                MonteCarlo::default().play(engine, player_id);
+            } else {
+                let options: Vec<usize> = engine.state().players[player_id].hand.iter().map(|card| card.id).collect();
+                for card_id in options {
+                    let _ = engine.apply(Action::ExerciseOption { card_id, confirm_loan: false });
+                    answer_decisions(engine);
+                }
+            }
+        }
+        finish_turn(engine);
+    }
+}
+
+/// Ends the turn. Discarding down to the hand limit holds the turn until it's done.
+pub(crate) fn finish_turn(engine: &mut GameEngine) {
+    engine.end_turn();
+    if engine.state().pending_decision().is_some() {
+        answer_decisions(engine);
+        engine.end_turn();
+    }
+}
+
+/// Answers pending decisions the way the AI would.
+pub(crate) fn answer_decisions(engine: &mut GameEngine) {
+    while let Some(request) = engine.state().pending_decision().cloned() {
+        let decision = engine.state().ai_decision(&request).unwrap_or(Decision::No);
+        if engine.apply(Action::Decide(decision)).is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::game::balance::{simulate, simulation_table, BALANCE_CSV_HEADER, SIMULATED_STRATEGY};
+    use crate::game::{BalanceReport, EffectStats, GameEffect, GameEvent};
+    use crate::models::Money;
+
+    #[test]
+    fn test_report_credits_cards_and_tiles_with_their_cash() {
+        let mut game = simulation_table(1, SIMULATED_STRATEGY).seed(1867).build().unwrap();
+        game.players[0].cash = Money(1000);
+        let card = Card {
+            id: 1,
+            title: "Windfall".to_string(),
+            description: "Collect $700".to_string(),
+            description_brief: "Collect $700".to_string(),
+            effect: GameEffect::Income(Money(700)),
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        };
+        game.apply_card_effect(0, &card, &mut Vec::new()).unwrap();
+        game.apply_card_effect(0, &card, &mut Vec::new()).unwrap();
+        // Christmas Vacation collects $1000
+        let tile = game.board[0].clone();
+        game.handle_tile_event(0, &tile, &mut Vec::new()).unwrap();
+
+        let mut report = BalanceReport::default();
+        report.record(&game);
+
+        assert_eq!(report.games, 1);
+        assert_eq!(report.cards["Windfall"], EffectStats { times: 2, total_cash: 1400 });
+        assert_eq!(report.cards["Windfall"].average(), 700.0);
+        assert_eq!(report.tiles[&0], ("Christmas Vacation".to_string(), EffectStats { times: 1, total_cash: 1000 }));
+        assert!(game.events.contains(&GameEvent::TileResolved { player_id: 0, tile: 0, cash_change: Money(1000) }));
+        assert_eq!(report.csv(), format!("{}card,Windfall,2,700,1400\ntile,Christmas Vacation,1,1000,1000\n", BALANCE_CSV_HEADER));
+    }
+
+    #[test]
+    fn test_simulation_is_repeatable_from_the_seed() {
+        let table = simulation_table(3, SIMULATED_STRATEGY).seed(1867).build().unwrap();
+
+        let report = simulate(&table, 2).unwrap();
+
+        assert_eq!(report.games, 2);
+        assert!(!report.cards.is_empty(), "no Farmer's Fate cards were played");
+        assert!(report.tiles.values().map(|(_, stats)| stats.times).sum::<u32>() > 0);
+        assert_eq!(simulate(&table, 2).unwrap(), report);
+    }
+}
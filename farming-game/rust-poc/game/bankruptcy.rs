@@ -1,8 +1,9 @@
-use crate::models::{GameState, AssetType, AssetRecord, PlayerType};
+use crate::models::{format_money, GameState, AssetType, AssetRecord, LedgerCategory, PlayerType, Money};
+use crate::game::{constraints, GameError, GameEvent};
 
 impl GameState {
-    pub fn run_bankruptcy_auction(&mut self, player_id: usize) {
-        let player = self.players.get(&player_id).unwrap();
+    pub fn run_bankruptcy_auction(&mut self, player_id: usize) -> Result<(), GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
         let mut assets: Vec<(AssetType, AssetRecord)> = player.assets.iter()
             .map(|(asset_type, record)| (*asset_type, record.clone()))
             .collect();
@@ -12,30 +13,32 @@ impl GameState {
 
         for (asset_type, record) in assets {
             let total_value = record.total_cost;
-            println!("\nAuctioning {} (Quantity: {}, Value: ${})", 
-                format!("{:?}", asset_type), record.quantity, total_value);
+            println!("\nAuctioning {} (Quantity: {}, Value: {})", 
+                format!("{:?}", asset_type), record.quantity, format_money(total_value));
 
-            let mut highest_bid = 0;
+            let mut highest_bid = Money::ZERO;
             let mut highest_bidder = None;
 
-            // Run auction among other players
-            for (other_id, other_player) in self.players.iter() {
-                if *other_id != player_id && other_player.cash > highest_bid {
+            // Run auction among other players with room on their farms for the lot
+            for other_player in self.players.iter() {
+                let other_id = other_player.id;
+                let has_room = constraints::check_acquisition(&self.rules, other_player, asset_type, record.quantity).is_ok();
+                if other_id != player_id && has_room && other_player.cash > highest_bid {
                     // AI players bid based on their cash and asset value
                     if let PlayerType::AI(_) = other_player.player_type {
-                        let bid = (other_player.cash as f32 * 0.8) as i32;
+                        let bid = other_player.cash.times(0.8);
                         if bid > highest_bid {
                             highest_bid = bid;
-                            highest_bidder = Some(*other_id);
+                            highest_bidder = Some(other_id);
                         }
                     } else {
-                        println!("{} has ${}. Enter bid (0 to pass): ", other_player.name, other_player.cash);
+                        println!("{} has {}. Enter bid (0 to pass): ", other_player.name, format_money(other_player.cash));
                         let mut input = String::new();
                         std::io::stdin().read_line(&mut input).unwrap();
-                        let bid: i32 = input.trim().parse().unwrap_or(0);
+                        let bid: Money = input.trim().parse().unwrap_or_default();
                         if bid > highest_bid && bid <= other_player.cash {
                             highest_bid = bid;
-                            highest_bidder = Some(*other_id);
+                            highest_bidder = Some(other_id);
                         }
                     }
                 }
@@ -43,21 +46,23 @@ impl GameState {
 
             if let Some(bidder_id) = highest_bidder {
                 // Transfer asset to highest bidder
-                let bidder = self.players.get_mut(&bidder_id).unwrap();
-                bidder.cash -= highest_bid;
-                bidder.add_asset(asset_type, record.quantity, highest_bid);
+                let mut logs = Vec::new();
+                self.debit(bidder_id, highest_bid, LedgerCategory::Purchase, &mut logs)?;
+                let bidder = &mut self.players[bidder_id];
+                bidder.acquire_asset(asset_type, record.quantity, highest_bid);
                 
-                println!("{} won the auction for {} with a bid of ${}", 
-                    bidder.name, format!("{:?}", asset_type), highest_bid);
+                println!("{} won the auction for {} with a bid of {}", 
+                    bidder.name, format!("{:?}", asset_type), format_money(highest_bid));
             } else {
                 println!("No bids received for {}", format!("{:?}", asset_type));
             }
         }
+        Ok(())
     }
 
-    pub fn attempt_bank_loan(&mut self, player_id: usize) -> bool {
-        let player = self.players.get(&player_id).unwrap();
-        let total_asset_value: i32 = player.assets.values()
+    pub fn attempt_bank_loan(&mut self, player_id: usize) -> Result<bool, GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let total_asset_value: Money = player.assets.values()
             .map(|record| record.total_cost)
             .sum();
         
@@ -68,40 +73,79 @@ impl GameState {
         let loan_amount = if let PlayerType::AI(_) = player.player_type {
             max_loan
         } else {
-            println!("Bank offers a loan of ${}. Accept? (y/n): ", max_loan);
+            println!("Bank offers a loan of {}. Accept? (y/n): ", format_money(max_loan));
             let mut input = String::new();
             std::io::stdin().read_line(&mut input).unwrap();
             if input.trim().to_lowercase() == "y" {
                 max_loan
             } else {
-                0
+                Money::ZERO
             }
         };
 
-        if loan_amount > 0 {
-            let player = self.players.get_mut(&player_id).unwrap();
+        if loan_amount > Money::ZERO {
+            let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
             player.cash += loan_amount;
-            player.debt += loan_amount;
-            println!("Loan of ${} accepted. New debt: ${}", loan_amount, player.debt);
-            true
+            player.borrow(loan_amount, self.rules.interest_rate);
+            println!("Loan of {} accepted. New debt: {}", format_money(loan_amount), format_money(player.debt));
+            let debt = player.debt;
+            self.emit(GameEvent::LoanTaken { player_id, amount: loan_amount, debt });
+            Ok(true)
         } else {
-            false
+            Ok(false)
         }
     }
 
-    pub fn check_bankruptcy_and_trigger_auction(&mut self, player_id: usize) {
-        let player = self.players.get(&player_id).unwrap();
-        if player.cash < 0 {
+    pub fn check_bankruptcy_and_trigger_auction(&mut self, player_id: usize) -> Result<(), GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        if player.cash.is_negative() {
             println!("\n{} is bankrupt!", player.name);
             
             // Try to get a bank loan first
-            if self.attempt_bank_loan(player_id) {
-                return;
+            if self.attempt_bank_loan(player_id)? {
+                return Ok(());
             }
             
             // If no loan or loan refused, run the auction
             println!("Starting bankruptcy auction...");
-            self.run_bankruptcy_auction(player_id);
+            self.run_bankruptcy_auction(player_id)?;
+
+            // The auction settles what it can; the player is out either way
+            let mut logs = Vec::new();
+            self.eliminate_player(player_id, &mut logs)?;
+            for log in logs {
+                println!("{}", log);
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes a player who can't cover what they owe out of the game. Their
+    /// turns are skipped from now on and the cards they held go back to the
+    /// decks. If that leaves one player in, that player has won.
+    pub fn eliminate_player(&mut self, player_id: usize, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        if !player.is_active {
+            return Ok(());
         }
+        player.is_active = false;
+        self.option_to_buy_deck.discard_pile.append(&mut player.hand);
+        self.farmer_fate_deck.discard_pile.extend(player.active_persistent_cards.drain(..).map(|(card, _)| card));
+        tracing::info!(player_id, cash = %player.cash, debt = %player.debt, "player eliminated");
+        logs.push(self.locale.tr("bankrupt.eliminated", &[("player", &player.name)]));
+        self.emit(GameEvent::PlayerEliminated { player_id });
+
+        if let Some(survivor) = self.last_player_standing() {
+            logs.push(self.locale.tr("bankrupt.last_standing", &[("player", &self.players[survivor].name)]));
+            self.record_outcome(survivor);
+        }
+        Ok(())
+    }
+
+    /// The one player still in once everyone else has gone bankrupt.
+    pub fn last_player_standing(&self) -> Option<usize> {
+        let mut active = self.turn_order.iter().copied().filter(|id| self.players.get(*id).is_some_and(|player| player.is_active));
+        let survivor = active.next()?;
+        (active.next().is_none() && self.turn_order.len() > 1).then_some(survivor)
     }
 } 
\ No newline at end of file
@@ -0,0 +1,204 @@
+// src/game/board_file.rs
+// Custom boards saved as TOML: one `[[tiles]]` table per square, in board
+// order. `validate_board` checks a board can be played before it's handed to
+// `GameStateBuilder::board`, and `season_summary` lays it out by season for
+// the `board-tool` binary.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::game::{board, Dice, GamePhase};
+use crate::models::{AssetType, BoardTile, CalendarDate, HarvestType, TileEffect, TileType};
+
+/// Crops a board has to bring in at least once a year.
+const HARVESTED_ASSETS: [AssetType; 4] = [AssetType::Hay, AssetType::Grain, AssetType::Fruit, AssetType::Cows];
+
+#[derive(Debug, Error)]
+pub enum BoardError {
+    #[error("Could not parse board: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Could not write board: {0}")]
+    Write(#[from] toml::ser::Error),
+}
+
+/// A board as saved to disk, e.g.
+///
+/// ```toml
+/// [[tiles]]
+/// index = 0
+/// name = "Christmas Vacation"
+/// month = "january"
+/// week = 1
+/// tile_type = "SpecialEvent"
+/// harvest_type = "None"
+/// description = "COLLECT $1000 Christmas bonus!"
+///
+/// [tiles.effect]
+/// GainCash = 1000
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BoardFile {
+    tiles: Vec<BoardTile>,
+}
+
+/// Parses a board; see `validate_board` for whether it can be played.
+/// Tiles without a brief description get one derived from their description.
+pub fn board_from_toml(contents: &str) -> Result<Vec<BoardTile>, BoardError> {
+    let mut file: BoardFile = toml::from_str(contents)?;
+    file.tiles.iter_mut().for_each(BoardTile::derive_brief);
+    Ok(file.tiles)
+}
+
+pub fn board_to_toml(tiles: &[BoardTile]) -> Result<String, BoardError> {
+    Ok(toml::to_string(&BoardFile { tiles: tiles.to_vec() })?)
+}
+
+/// Loads a board from a TOML file.
+pub fn load_board(path: &Path) -> Result<Vec<BoardTile>, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    Ok(board_from_toml(&contents)?)
+}
+
+/// The stock board in the saved format, for a starting point to edit.
+pub fn builtin_board_toml() -> String {
+    board_to_toml(&board::create_full_board()).expect("the built-in board always serializes")
+}
+
+/// Something wrong with a board. Errors make it unplayable; warnings make
+/// for an odd game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardIssue {
+    Empty,
+    /// The tile at `position` says it's at `index`
+    IndexMismatch { position: usize, index: usize },
+    /// A jump to a tile past the end of the board
+    JumpOffBoard { tile: usize, target: usize },
+    /// A jump back onto the tile itself
+    JumpToSelf { tile: usize },
+    /// No roll or jump ever lands on the tile
+    Unreachable { tile: usize },
+    /// The tile's date is earlier than the one before it
+    DateOutOfOrder { tile: usize },
+    /// Nothing on the board harvests the crop
+    NoHarvest(AssetType),
+}
+
+impl BoardIssue {
+    pub fn is_error(&self) -> bool {
+        matches!(self, BoardIssue::Empty | BoardIssue::IndexMismatch { .. } | BoardIssue::JumpOffBoard { .. } | BoardIssue::JumpToSelf { .. })
+    }
+}
+
+impl fmt::Display for BoardIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardIssue::Empty => write!(f, "the board has no tiles"),
+            BoardIssue::IndexMismatch { position, index } => write!(f, "tile {} has index {}", position, index),
+            BoardIssue::JumpOffBoard { tile, target } => write!(f, "tile {} jumps to tile {}, past the end of the board", tile, target),
+            BoardIssue::JumpToSelf { tile } => write!(f, "tile {} jumps to itself", tile),
+            BoardIssue::Unreachable { tile } => write!(f, "tile {} can't be reached by a roll or a jump", tile),
+            BoardIssue::DateOutOfOrder { tile } => write!(f, "tile {} is dated before the tile ahead of it", tile),
+            BoardIssue::NoHarvest(asset) => write!(f, "nothing harvests {}", asset),
+        }
+    }
+}
+
+/// Everything wrong with the board, errors and warnings alike, in board order.
+pub fn validate_board(tiles: &[BoardTile]) -> Vec<BoardIssue> {
+    if tiles.is_empty() {
+        return vec![BoardIssue::Empty];
+    }
+    let mut issues = Vec::new();
+    for (position, tile) in tiles.iter().enumerate() {
+        if tile.index != position {
+            issues.push(BoardIssue::IndexMismatch { position, index: tile.index });
+        }
+        match tile.effect.jump_target() {
+            Some(target) if target >= tiles.len() => issues.push(BoardIssue::JumpOffBoard { tile: position, target }),
+            Some(target) if target == position => issues.push(BoardIssue::JumpToSelf { tile: position }),
+            _ => {}
+        }
+        if position > 0 && tile.date() < tiles[position - 1].date() {
+            issues.push(BoardIssue::DateOutOfOrder { tile: position });
+        }
+    }
+    let reachable = reachable_tiles(tiles);
+    issues.extend((0..tiles.len()).filter(|tile| !reachable.contains(tile)).map(|tile| BoardIssue::Unreachable { tile }));
+    for asset in HARVESTED_ASSETS {
+        if !tiles.iter().any(|tile| tile.harvest_type.asset() == Some(asset) || harvest_jump(&tile.effect).is_some_and(|harvest| harvest.asset() == Some(asset))) {
+            issues.push(BoardIssue::NoHarvest(asset));
+        }
+    }
+    issues
+}
+
+/// Tiles a player starting on the first tile can land on.
+fn reachable_tiles(tiles: &[BoardTile]) -> BTreeSet<usize> {
+    let (lowest, highest) = Dice::ONE.range();
+    let mut reached = BTreeSet::from([0]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(position) = queue.pop_front() {
+        let rolls = (lowest..=highest).map(|roll| (position + roll as usize) % tiles.len());
+        let jump = tiles[position].effect.jump_target().filter(|target| *target < tiles.len());
+        for next in rolls.chain(jump) {
+            if reached.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    reached
+}
+
+fn harvest_jump(effect: &TileEffect) -> Option<&HarvestType> {
+    match effect {
+        TileEffect::MoveAndHarvestIfAsset { harvest_type, .. } => Some(harvest_type),
+        _ => None,
+    }
+}
+
+/// One season of a board, as `board-tool` prints it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeasonSummary {
+    pub phase: GamePhase,
+    pub first: CalendarDate,
+    pub last: CalendarDate,
+    pub tiles: usize,
+    /// Harvests in the season, in board order, each listed once
+    pub harvests: Vec<HarvestType>,
+    /// Card draws, Farmer's Fate and Option to Buy alike
+    pub card_draws: usize,
+    /// `(from, to)` for every jump tile in the season
+    pub jumps: Vec<(usize, usize)>,
+}
+
+/// The board split into seasons, in board order.
+pub fn season_summary(tiles: &[BoardTile]) -> Vec<SeasonSummary> {
+    let mut seasons: Vec<SeasonSummary> = Vec::new();
+    for (position, tile) in tiles.iter().enumerate() {
+        let phase = GamePhase::from_date(tile.date());
+        if seasons.last().is_none_or(|season| season.phase != phase) {
+            seasons.push(SeasonSummary { phase, first: tile.date(), last: tile.date(), tiles: 0, harvests: Vec::new(), card_draws: 0, jumps: Vec::new() });
+        }
+        let season = seasons.last_mut().expect("a season was just pushed");
+        season.last = tile.date();
+        season.tiles += 1;
+        for harvest in [Some(&tile.harvest_type), harvest_jump(&tile.effect)].into_iter().flatten() {
+            if *harvest != HarvestType::None && !season.harvests.contains(harvest) {
+                season.harvests.push(harvest.clone());
+            }
+        }
+        if matches!(tile.effect, TileEffect::DrawCard(TileType::FarmerFate | TileType::OptionToBuy)) {
+            season.card_draws += 1;
+        }
+        if let Some(target) = tile.effect.jump_target() {
+            season.jumps.push((position, target));
+        }
+    }
+    seasons
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::board::create_full_board;
+    use crate::game::board_file::{board_from_toml, board_to_toml, builtin_board_toml, season_summary, validate_board, BoardIssue};
+    use crate::game::GamePhase;
+    use crate::models::{AssetType, HarvestType, TileEffect};
+
+    #[test]
+    fn test_builtin_board_round_trips() {
+        let board = create_full_board();
+
+        let parsed = board_from_toml(&builtin_board_toml()).unwrap();
+
+        assert_eq!(parsed, board);
+        assert_eq!(board_to_toml(&parsed).unwrap(), builtin_board_toml());
+    }
+
+    #[test]
+    fn test_builtin_board_is_valid() {
+        assert_eq!(validate_board(&create_full_board()), vec![]);
+    }
+
+    #[test]
+    fn test_validation_finds_broken_indexes_jumps_and_harvests() {
+        let mut board = create_full_board();
+        board[3].index = 30;
+        board[7].effect = TileEffect::GoToTile(99);
+        board[8].effect = TileEffect::GoToTile(8);
+        for tile in board.iter_mut().filter(|tile| tile.harvest_type.asset() == Some(AssetType::Fruit)) {
+            tile.harvest_type = HarvestType::None;
+        }
+        // Tile 34's tractor jump harvests fruit too
+        board[34].effect = TileEffect::None;
+
+        let issues = validate_board(&board);
+
+        assert_eq!(issues, vec![
+            BoardIssue::IndexMismatch { position: 3, index: 30 },
+            BoardIssue::JumpOffBoard { tile: 7, target: 99 },
+            BoardIssue::JumpToSelf { tile: 8 },
+            BoardIssue::NoHarvest(AssetType::Fruit),
+        ]);
+        assert_eq!(issues.iter().filter(|issue| issue.is_error()).count(), 3);
+        assert_eq!(validate_board(&[]), vec![BoardIssue::Empty]);
+    }
+
+    #[test]
+    fn test_loading_derives_missing_briefs() {
+        let mut board = create_full_board();
+        board[0].description = Some("A long week of chores around the farm, but it pays off. COLLECT $700 from the co-op.".to_string());
+        board[0].description_brief = None;
+        board[1].description_brief = Some("Own words.".to_string());
+
+        let parsed = board_from_toml(&board_to_toml(&board).unwrap()).unwrap();
+
+        assert_eq!(parsed[0].description_brief.as_deref(), Some("COLLECT $700 from the co-op."));
+        assert_eq!(parsed[1].description_brief.as_deref(), Some("Own words."), "A board's own brief is kept");
+    }
+
+    #[test]
+    fn test_parse_errors_are_reported() {
+        assert!(board_from_toml("[[tiles]]\nindex = 0\n").is_err());
+    }
+
+    #[test]
+    fn test_stock_board_seasons() {
+        let seasons = season_summary(&create_full_board());
+
+        let phases: Vec<GamePhase> = seasons.iter().map(|season| season.phase).collect();
+        assert_eq!(phases, vec![GamePhase::SpringPlanting, GamePhase::EarlySummer, GamePhase::LateSummer, GamePhase::EndOfYear]);
+        assert_eq!(seasons.iter().map(|season| season.tiles).sum::<usize>(), create_full_board().len());
+        assert!(seasons[0].harvests.is_empty(), "nothing is harvested before Spring Planting");
+        assert_eq!(seasons[0].jumps, vec![(7, 14)]);
+        assert!(seasons[2].harvests.contains(&HarvestType::Apple));
+    }
+}
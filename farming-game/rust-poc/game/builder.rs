@@ -0,0 +1,160 @@
+// src/game/builder.rs
+// One place to configure a new game for code embedding the engine, instead of
+// picking between `GameEngine`'s constructors, and for setting up a bare
+// `GameState` without assembling every `Player` by hand.
+
+use std::sync::Arc;
+use crate::config::GameRules;
+use crate::game::{GameEngine, GameError, Scenario};
+use crate::models::{BoardTile, GameState, Player, PlayerRegistry, PlayerType};
+
+/// Configures and starts a `GameEngine`. Players take turns in the order
+/// they're added; everything else defaults to a standard, unseeded game.
+#[derive(Debug, Clone, Default)]
+pub struct GameBuilder {
+    names: Vec<String>,
+    rules: GameRules,
+    seed: Option<u64>,
+    scenario: Option<Scenario>,
+}
+
+impl GameBuilder {
+    pub fn player(mut self, name: impl Into<String>) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    pub fn players<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.names.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Ignored when a scenario is set, since scenarios bring their own rules.
+    pub fn rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Makes every shuffle, deal and roll reproducible.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn scenario(mut self, scenario: Scenario) -> Self {
+        self.scenario = Some(scenario);
+        self
+    }
+
+    pub fn build(self) -> Result<GameEngine, GameError> {
+        if self.names.is_empty() {
+            return Err(GameError::NoPlayers);
+        }
+        match (self.scenario, self.seed) {
+            (Some(scenario), seed) => GameEngine::with_scenario(&self.names, &scenario, seed),
+            (None, Some(seed)) => GameEngine::with_seed(&self.names, self.rules, seed),
+            (None, None) => GameEngine::with_players(&self.names, self.rules),
+        }
+    }
+}
+
+/// Configures a `GameState`: who's playing, the rules, the board and the
+/// seed. Players take turns in the order they're added and get the usual
+/// starting farm. Unlike `GameBuilder`, nobody is dealt cards.
+#[derive(Debug, Clone, Default)]
+pub struct GameStateBuilder {
+    players: Vec<Player>,
+    rules: GameRules,
+    seed: Option<u64>,
+    board: Option<Vec<BoardTile>>,
+    turn_order: Option<Vec<usize>>,
+}
+
+impl GameStateBuilder {
+    pub fn add_human(self, name: impl Into<String>) -> Self {
+        self.add_player_of(name, PlayerType::Human)
+    }
+
+    /// `strategy` is the `PlayerType::AI` name the AI plays by.
+    pub fn add_ai(self, name: impl Into<String>, strategy: impl Into<String>) -> Self {
+        self.add_player_of(name, PlayerType::AI(strategy.into()))
+    }
+
+    fn add_player_of(self, name: impl Into<String>, player_type: PlayerType) -> Self {
+        let seat = self.players.len();
+        self.add_player(Player::new(seat, name.into(), player_type))
+    }
+
+    /// Adds a player set up by the caller, e.g. with a chosen color. They
+    /// take the next seat whatever id they were given.
+    pub fn add_player(mut self, mut player: Player) -> Self {
+        player.id = self.players.len();
+        self.players.push(player);
+        self
+    }
+
+    pub fn rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Makes every shuffle, deal and roll reproducible.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Seats take turns in `order` instead of the order they were added,
+    /// with no opening roll even if the rules ask for one.
+    pub fn turn_order(mut self, order: Vec<usize>) -> Self {
+        self.turn_order = Some(order);
+        self
+    }
+
+    /// Plays on `board` instead of the standard one.
+    pub fn board(mut self, board: Vec<BoardTile>) -> Self {
+        self.board = Some(board);
+        self
+    }
+
+    /// The game, ready for the first turn. Turn order is settled by the
+    /// opening high roll when the rules ask for it and none was given.
+    pub fn build(self) -> Result<GameState, GameError> {
+        if self.players.is_empty() {
+            return Err(GameError::NoPlayers);
+        }
+        if let Some(order) = &self.turn_order {
+            let mut seats = order.clone();
+            seats.sort_unstable();
+            if !seats.iter().copied().eq(0..self.players.len()) {
+                return Err(GameError::InvalidTurnOrder(order.clone()));
+            }
+        }
+        if let Some(board) = &self.board {
+            if let Some(player) = self.players.iter().find(|player| player.position >= board.len()) {
+                return Err(GameError::InvalidPosition(player.position));
+            }
+        }
+        let roll_for_turn_order = self.rules.roll_for_turn_order && self.turn_order.is_none();
+        let turn_order = self.turn_order.unwrap_or_else(|| (0..self.players.len()).collect());
+        let mut players = PlayerRegistry::new();
+        for player in self.players {
+            players.insert(player);
+        }
+        let mut state = GameState::new_with_players(players, turn_order, self.rules);
+        if let Some(board) = self.board {
+            state.board = Arc::new(board);
+        }
+        if let Some(seed) = self.seed {
+            state.reseed(seed);
+        }
+        if roll_for_turn_order {
+            state.roll_for_turn_order(&mut Vec::new());
+        }
+        Ok(state)
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{Action, GameEngine, GameError, GameStateBuilder, Scenario};
+    use crate::game::board::create_full_board;
+    use crate::models::{Player, PlayerType};
+
+    #[test]
+    fn test_builder_matches_the_constructors() {
+        let built = GameEngine::builder().player("Ann").players(["Bo", "Cy"]).seed(9).build().unwrap();
+        let names: Vec<String> = ["Ann", "Bo", "Cy"].iter().map(|n| n.to_string()).collect();
+        let constructed = GameEngine::with_seed(&names, GameRules::default(), 9).unwrap();
+        assert_eq!(built.state().turn_order, vec![0, 1, 2]);
+        assert_eq!(built.state().players[2].name, "Cy");
+        let hand = |engine: &GameEngine| engine.state().players[0].hand.iter().map(|card| card.id).collect::<Vec<_>>();
+        assert_eq!(hand(&built), hand(&constructed), "Same seed, same deal");
+        assert!(built.record().is_some());
+
+        let mut engine = GameEngine::builder().players(["Ann"]).build().unwrap();
+        assert!(engine.record().is_none(), "Only seeded games are recorded");
+        engine.apply(Action::Move(2)).unwrap();
+        assert!(!engine.events().is_empty());
+    }
+
+    #[test]
+    fn test_builder_starts_scenarios_and_needs_players() {
+        let scenario = Scenario::find_builtin("deep_in_debt").unwrap();
+        let engine = GameEngine::builder().player("Ann").scenario(scenario.clone()).build().unwrap();
+        assert_eq!(engine.state().rules, scenario.rules);
+
+        assert_eq!(GameEngine::builder().seed(1).build().err(), Some(GameError::NoPlayers));
+    }
+
+    #[test]
+    fn test_state_builder_seats_players_in_the_order_added() {
+        let mut bo = Player::new(7, "Bo".to_string(), PlayerType::Human);
+        bo.color = Player::new(3, String::new(), PlayerType::Human).color;
+        let mut state = GameStateBuilder::default()
+            .add_human("Ann")
+            .add_player(bo.clone())
+            .add_ai("Cy", "cautious")
+            .seed(4)
+            .build()
+            .unwrap();
+
+        assert_eq!(state.turn_order, vec![0, 1, 2]);
+        assert_eq!(state.players[1].id, 1, "Players take the next seat");
+        assert_eq!(state.players[1].color, bo.color);
+        assert_eq!(state.players[2].player_type, PlayerType::AI("cautious".to_string()));
+        assert_eq!(state.players[0].assets[&crate::models::AssetType::Hay].quantity, 10, "Grandpa's hay and grain");
+
+        let mut again = GameStateBuilder::default().add_human("Ann").add_human("Bo").add_ai("Cy", "cautious").seed(4).build().unwrap();
+        let draw = |state: &mut crate::models::GameState| state.farmer_fate_deck.draw().map(|card| card.id);
+        assert_eq!(draw(&mut state), draw(&mut again), "Same seed, same decks");
+    }
+
+    #[test]
+    fn test_state_builder_checks_players_and_board() {
+        assert_eq!(GameStateBuilder::default().build().err(), Some(GameError::NoPlayers));
+
+        let short_board: Vec<_> = create_full_board().into_iter().take(10).collect();
+        let state = GameStateBuilder::default().add_human("Ann").board(short_board).build().unwrap();
+        assert_eq!(state.board.len(), 10);
+        assert_eq!(GameStateBuilder::default().add_human("Ann").board(Vec::new()).build().err(), Some(GameError::InvalidPosition(0)));
+    }
+
+    #[test]
+    fn test_state_builder_takes_a_turn_order_instead_of_rolling() {
+        let rules = GameRules { roll_for_turn_order: true, ..GameRules::default() };
+        let builder = GameStateBuilder::default().rules(rules).add_human("Ann").add_human("Bo").add_human("Cy");
+
+        let game = builder.clone().turn_order(vec![2, 0, 1]).build().unwrap();
+        assert_eq!(game.turn_order, vec![2, 0, 1]);
+        assert!(game.events.is_empty(), "Nobody rolls for the order");
+        assert_eq!(builder.turn_order(vec![0, 0, 1]).build().unwrap_err(), GameError::InvalidTurnOrder(vec![0, 0, 1]));
+    }
+}
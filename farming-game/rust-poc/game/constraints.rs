@@ -0,0 +1,62 @@
+// src/game/constraints.rs
+// How much of each asset one farm may own, per the game's rules. Every way of
+// acquiring an asset (cards, tiles, Option to Buy, bankruptcy auctions,
+// scenario starts) asks here before the asset changes hands.
+
+use crate::config::GameRules;
+use crate::game::GameError;
+use crate::models::{AssetType, Player};
+
+impl GameRules {
+    /// Most of `asset` one farm may own, if it's limited.
+    pub fn asset_limit(&self, asset: AssetType) -> Option<u32> {
+        match asset {
+            AssetType::Cows => Some(self.farm_cow_limit),
+            _ => self.asset_limits.get(&asset).copied(),
+        }
+    }
+}
+
+fn held(player: &Player, asset: AssetType) -> u32 {
+    player.assets.get(&asset).map_or(0, |record| record.quantity)
+}
+
+/// Land a player can plant, which is none while they're short of land.
+fn usable_land(player: &Player) -> u32 {
+    u32::try_from(player.land).unwrap_or(0)
+}
+
+/// Checks a count a player asked for, which must not be negative.
+pub fn quantity(requested: i32) -> Result<u32, GameError> {
+    u32::try_from(requested).map_err(|_| GameError::InvalidQuantity(requested))
+}
+
+/// Checks `player` may take on `quantity` more of `asset`.
+pub fn check_acquisition(rules: &GameRules, player: &Player, asset: AssetType, quantity: u32) -> Result<(), GameError> {
+    if quantity == 0 {
+        return Ok(());
+    }
+    let current = held(player, asset);
+    if let Some(limit) = rules.asset_limit(asset) {
+        if current.saturating_add(quantity) > limit {
+            return Err(GameError::AssetLimitExceeded { asset, requested: quantity, current, limit });
+        }
+    }
+    if rules.acres_limited_by_land && asset.is_acres() {
+        let acres: u32 = AssetType::ACRES.iter().map(|crop| held(player, *crop)).sum();
+        if acres.saturating_add(quantity) > usable_land(player) {
+            return Err(GameError::LandLimitExceeded { requested: quantity, acres, land: player.land });
+        }
+    }
+    Ok(())
+}
+
+/// How many more of `asset` `player` may take on; `u32::MAX` when unlimited.
+pub fn room_for(rules: &GameRules, player: &Player, asset: AssetType) -> u32 {
+    let mut room = rules.asset_limit(asset).map_or(u32::MAX, |limit| limit.saturating_sub(held(player, asset)));
+    if rules.acres_limited_by_land && asset.is_acres() {
+        let acres: u32 = AssetType::ACRES.iter().map(|crop| held(player, *crop)).sum();
+        room = room.min(usable_land(player).saturating_sub(acres));
+    }
+    room
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::constraints::{check_acquisition, quantity, room_for};
+    use crate::game::{GameEffect, GameError, StateMutation};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    fn setup_game(rules: GameRules) -> GameState {
+        let mut players = PlayerRegistry::new();
+        let mut player = Player::new(0, "Farmer".to_string(), PlayerType::Human);
+        player.cash = Money(100_000);
+        players.insert(player);
+        GameState::new_with_players(players, vec![0], rules)
+    }
+
+    #[test]
+    fn test_limits_come_from_the_rules() {
+        let rules = GameRules::from_toml("farm_cow_limit = 5\nasset_limits = { Tractor = 1 }\n").unwrap();
+        let mut player = Player::new(0, "Farmer".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Cows, 3, Money::ZERO);
+
+        assert_eq!(room_for(&rules, &player, AssetType::Cows), 2);
+        assert_eq!(room_for(&rules, &player, AssetType::Tractor), 1);
+        assert_eq!(room_for(&rules, &player, AssetType::Barn), u32::MAX, "Listing limits replaces the defaults");
+        assert_eq!(check_acquisition(&rules, &player, AssetType::Cows, 3),
+                   Err(GameError::AssetLimitExceeded { asset: AssetType::Cows, requested: 3, current: 3, limit: 5 }));
+        assert_eq!(GameRules::default().asset_limit(AssetType::Silo), Some(1));
+        assert_eq!(GameRules::default().asset_limit(AssetType::Tractor), None);
+    }
+
+    #[test]
+    fn test_acres_can_be_held_to_the_farms_land() {
+        let rules = GameRules { acres_limited_by_land: true, ..GameRules::default() };
+        let mut game = setup_game(rules);
+        game.players[0].land = 25;
+        let mut logs = Vec::new();
+
+        // Grandpa's 10 hay and 10 grain leave room for 5 more acres
+        let too_many = GameEffect::BuyAsset { asset: AssetType::Fruit, quantity: 10, cost: Money(5000) };
+        let card = crate::game::EffectResolver::new(&mut game, 0).resolve(&too_many, &mut logs);
+        assert_eq!(card, Err(GameError::LandLimitExceeded { requested: 10, acres: 20, land: 25 }));
+        assert_eq!(game.players[0].cash, Money(100_000), "Nothing is paid for a purchase that can't complete");
+        assert_eq!(room_for(&game.rules, &game.players[0], AssetType::Hay), 5);
+        assert_eq!(room_for(&game.rules, &game.players[0], AssetType::Cows), 20, "Livestock doesn't take acres");
+    }
+
+    #[test]
+    fn test_quantities_are_never_negative() {
+        assert_eq!(quantity(4), Ok(4));
+        assert_eq!(quantity(-4), Err(GameError::InvalidQuantity(-4)));
+
+        let rules = GameRules { acres_limited_by_land: true, ..GameRules::default() };
+        let mut game = setup_game(rules);
+        game.players[0].land = -5;
+        assert_eq!(room_for(&game.rules, &game.players[0], AssetType::Hay), 0, "A farm short of land has no room for crops");
+
+        let lost = StateMutation::AssetDelta { player_id: 0, asset: AssetType::Hay, quantity: -11 };
+        assert_eq!(game.apply_mutations(&[lost]),
+                   Err(GameError::InsufficientAssets { asset: AssetType::Hay, requested: 11, available: 10 }));
+    }
+
+    #[test]
+    fn test_assets_gained_for_free_respect_the_limits_too() {
+        let mut game = setup_game(GameRules::default());
+        let gift = StateMutation::AssetDelta { player_id: 0, asset: AssetType::Barn, quantity: 1 };
+        game.apply_mutations(std::slice::from_ref(&gift)).unwrap();
+        assert_eq!(game.apply_mutations(&[gift]),
+                   Err(GameError::AssetLimitExceeded { asset: AssetType::Barn, requested: 1, current: 1, limit: 1 }));
+    }
+}
@@ -0,0 +1,87 @@
+// src/game/custom_work.rs
+// Custom work between neighbors. A player hit with a custom hire bill for a
+// Tractor or Harvester they don't own can hire a player who owns one instead
+// of paying the bank. Owners are asked in turn order for a price up to the
+// bill; the hirer takes the first price they like, and pays the bank if they
+// turn every owner down or nobody quotes.
+
+use crate::game::decision::PendingEffect;
+use crate::game::{DecisionKind, DecisionRequest, GameError};
+use crate::models::{AssetType, GameState, LedgerCategory, Money};
+
+/// Quotes are in multiples of this.
+pub const CUSTOM_WORK_PRICE_STEP: Money = Money(100);
+
+/// A custom hire bill someone else might do the work for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomWorkJob {
+    /// The player without the equipment
+    pub hirer: usize,
+    pub asset: AssetType,
+    /// What the bank charges
+    pub bill: Money,
+}
+
+impl GameState {
+    /// Players still in who own `asset`, in turn order after `hirer`.
+    pub fn custom_work_contractors(&self, hirer: usize, asset: AssetType) -> Vec<usize> {
+        let start = self.turn_order.iter().position(|id| *id == hirer).unwrap_or(0);
+        let count = self.turn_order.len();
+        (1..count)
+            .map(|step| self.turn_order[(start + step) % count])
+            .filter(|id| self.players.get(*id).is_some_and(|player| player.is_active && player.assets.contains_key(&asset)))
+            .collect()
+    }
+
+    /// Asks the first of `contractors` to quote for `job`. Once there's no
+    /// one left to ask, the hirer pays the bank.
+    pub(crate) fn ask_for_custom_work(&mut self, job: CustomWorkJob, mut contractors: Vec<usize>, logs: &mut Vec<String>) -> Result<(), GameError> {
+        if contractors.is_empty() {
+            logs.push(self.locale.tr("custom_work.bank", &[("hirer", &self.players[job.hirer].name), ("bill", &job.bill)]));
+            return self.debit(job.hirer, job.bill, LedgerCategory::CardExpense, logs);
+        }
+        let contractor = contractors.remove(0);
+        let prompt = self.locale.tr("custom_work.quote_prompt", &[("hirer", &self.players[job.hirer].name),
+            ("asset", &self.locale.asset_name(&job.asset)), ("bill", &job.bill)]);
+        // The bill is settled before anything else waits on an answer
+        self.pending_decisions.push_front(DecisionRequest {
+            player_id: contractor,
+            kind: DecisionKind::Price { max: job.bill, step: CUSTOM_WORK_PRICE_STEP },
+            prompt,
+            pending: PendingEffect::CustomWorkQuote { job, contractors },
+        });
+        Ok(())
+    }
+
+    /// Takes `contractor`'s quote to the hirer. A price of 0 passes on the work.
+    pub(crate) fn quote_custom_work(&mut self, job: CustomWorkJob, contractor: usize, price: Money, contractors: Vec<usize>, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let contractor_name = self.players[contractor].name.clone();
+        if price <= Money::ZERO {
+            logs.push(self.locale.tr("custom_work.passed", &[("contractor", &contractor_name), ("hirer", &self.players[job.hirer].name)]));
+            return self.ask_for_custom_work(job, contractors, logs);
+        }
+        let prompt = self.locale.tr("custom_work.offer_prompt", &[("contractor", &contractor_name), ("price", &price), ("bill", &job.bill)]);
+        self.pending_decisions.push_front(DecisionRequest {
+            player_id: job.hirer,
+            kind: DecisionKind::YesNo,
+            prompt,
+            pending: PendingEffect::CustomWorkOffer { job, contractor, price, contractors },
+        });
+        Ok(())
+    }
+
+    /// Pays `contractor` their price if the hirer took it, otherwise asks the next owner.
+    pub(crate) fn settle_custom_work(&mut self, job: CustomWorkJob, contractor: usize, price: Money, accepted: bool,
+                                     contractors: Vec<usize>, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let (hirer_name, contractor_name) = (self.players[job.hirer].name.clone(), self.players[contractor].name.clone());
+        if !accepted {
+            logs.push(self.locale.tr("custom_work.turned_down", &[("hirer", &hirer_name), ("contractor", &contractor_name), ("price", &price)]));
+            return self.ask_for_custom_work(job, contractors, logs);
+        }
+        tracing::info!(hirer = job.hirer, contractor, %price, bill = %job.bill, "custom work");
+        logs.push(self.locale.tr("custom_work.done", &[("contractor", &contractor_name), ("hirer", &hirer_name),
+            ("asset", &self.locale.asset_name(&job.asset))]));
+        self.transfer_between_players(job.hirer, contractor, price, logs)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::config::GameRules;
+    use crate::game::{Decision, DecisionKind, GameEffect};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    /// Ann has no tractor; Bo and Cy each own one. Everyone has $5,000.
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        for (id, name) in ["Ann", "Bo", "Cy"].into_iter().enumerate() {
+            players.insert(Player::new(id, name.to_string(), PlayerType::Human));
+        }
+        let mut game = GameState::new_with_players(players, vec![0, 1, 2], GameRules::default());
+        for player in game.players.iter_mut() {
+            player.cash = Money(5000);
+        }
+        game.players[1].add_asset(AssetType::Tractor, 1, Money(10000));
+        game.players[2].add_asset(AssetType::Tractor, 1, Money(10000));
+        game
+    }
+
+    fn custom_hire(amount: Money) -> Card {
+        Card {
+            id: 900,
+            title: "Custom Hire".to_string(),
+            description: "Test card".to_string(),
+            description_brief: "Test card".to_string(),
+            effect: GameEffect::PayIfNoAssetDistribute { required_asset: AssetType::Tractor, amount },
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        }
+    }
+
+    #[test]
+    fn test_hirer_pays_the_contractor_they_accept() {
+        let mut game = setup_game();
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &custom_hire(Money(2000)), &mut logs).unwrap();
+
+        let request = game.pending_decision().unwrap();
+        assert_eq!(request.player_id, 1, "Owners are asked in turn order after the hirer");
+        assert_eq!(request.kind, DecisionKind::Price { max: Money(2000), step: Money(100) });
+        assert_eq!(game.players[0].cash, Money(5000), "Nothing is paid while the work is being quoted");
+        assert!(!request.accepts(Decision::Price(Money(2100))));
+        assert!(!request.accepts(Decision::Price(Money(1550))));
+
+        game.answer_decision(1, Decision::Price(Money(1500)), &mut logs).unwrap();
+        assert_eq!(game.pending_decision().unwrap().player_id, 0);
+        game.answer_decision(0, Decision::Yes, &mut logs).unwrap();
+
+        assert!(game.pending_decision().is_none());
+        assert_eq!(game.players[0].cash, Money(3500));
+        assert_eq!(game.players[1].cash, Money(6500));
+        assert_eq!(game.players[2].cash, Money(5000));
+    }
+
+    #[test]
+    fn test_hirer_pays_the_bank_when_every_owner_passes_or_is_turned_down() {
+        let mut game = setup_game();
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &custom_hire(Money(2000)), &mut logs).unwrap();
+
+        game.answer_decision(1, Decision::Price(Money::ZERO), &mut logs).unwrap();
+        assert_eq!(game.pending_decision().unwrap().player_id, 2);
+        game.answer_decision(2, Decision::Price(Money(2000)), &mut logs).unwrap();
+        game.answer_decision(0, Decision::No, &mut logs).unwrap();
+
+        assert!(game.pending_decision().is_none());
+        assert_eq!(game.players[0].cash, Money(3000));
+        assert_eq!([game.players[1].cash, game.players[2].cash], [Money(5000), Money(5000)]);
+    }
+
+    #[test]
+    fn test_custom_hire_goes_to_the_bank_without_owners_in_the_game() {
+        let mut game = setup_game();
+        game.players[1].is_active = false;
+        game.players[2].assets.clear();
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &custom_hire(Money(2000)), &mut logs).unwrap();
+
+        assert!(game.pending_decision().is_none());
+        assert_eq!(game.players[0].cash, Money(3000));
+    }
+
+    #[test]
+    fn test_ai_undercuts_the_bank_and_hires_below_it() {
+        let mut game = setup_game();
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &custom_hire(Money(3000)), &mut logs).unwrap();
+
+        let quote = game.ai_decision(game.pending_decision().unwrap()).unwrap();
+        assert_eq!(quote, Decision::Price(Money(2200)));
+        game.answer_decision(1, quote, &mut logs).unwrap();
+        assert_eq!(game.ai_decision(game.pending_decision().unwrap()), Some(Decision::Yes));
+    }
+}
@@ -0,0 +1,208 @@
+// src/game/decision.rs
+// Choices a player makes partway through an effect. The effect stops and
+// queues a DecisionRequest on the game; the UI or AI answers it with a
+// Decision and the effect finishes from where it stopped.
+
+use serde::{Deserialize, Serialize};
+use crate::game::{constraints, discard, CustomWorkJob, EffectResolver, GameError};
+use crate::game::custom_work::CUSTOM_WORK_PRICE_STEP;
+use crate::game::market::BASE_COW_PRICE;
+use crate::models::{AssetType, GameState, LedgerCategory, Money, PlayerView};
+
+/// What kind of answer a request needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DecisionKind {
+    YesNo,
+    /// A whole number in `min..=max`
+    Quantity { min: i32, max: i32 },
+    /// The player rolls the die themselves
+    Roll,
+    /// The player owes `amount`: they may sell assets to the bank toward it
+    /// (`Decision::Sell`) before paying, borrowing whatever is still short (`Decision::Yes`)
+    RaiseCash { amount: Money },
+    /// A price from 0 to `max` in multiples of `step` (`Decision::Price`); 0 passes
+    Price { max: Money, step: Money },
+    /// The player is `over` cards past the hand limit and picks one from
+    /// their hand to discard (`Decision::Discard`)
+    Discard { over: usize },
+}
+
+/// A player's answer to a `DecisionRequest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Decision {
+    Yes,
+    No,
+    Quantity(i32),
+    Roll,
+    Sell { asset: AssetType, quantity: u32 },
+    Price(Money),
+    Discard { card_id: usize },
+}
+
+/// Where a paused effect picks up once the player answers.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PendingEffect {
+    /// Buying some or all of a lot of `quantity` assets costing `cost` in total
+    OptionalBuy { asset: AssetType, quantity: u32, cost: Money },
+    /// Rolling to escape the Mt. St. Helens ash
+    AshRoll,
+    /// Paying `cost` to clean the ash off `acres`, after selling some cows toward it
+    AshCleanup { cost: Money, acres: u32, cow_price: Money },
+    /// Paying a `category` bill of `amount` the player is short of cash for,
+    /// once they're done raising cash toward it
+    ForcedLoan { amount: Money, category: LedgerCategory },
+    /// An equipment owner quoting for someone else's custom hire bill; owners
+    /// in `contractors` are asked next if they pass (see `game::custom_work`)
+    CustomWorkQuote { job: CustomWorkJob, contractors: Vec<usize> },
+    /// The hirer deciding on `contractor`'s price
+    CustomWorkOffer { job: CustomWorkJob, contractor: usize, price: Money, contractors: Vec<usize> },
+    /// Moving cows onto a newly leased ridge, past the rules' minimum
+    StockRidge { ridge: String },
+    /// Discarding down to the hand limit before the turn ends
+    DiscardDown,
+}
+
+/// A question waiting on one player before an effect can finish.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionRequest {
+    pub player_id: usize,
+    pub kind: DecisionKind,
+    /// Question to show the player, in the game's language
+    pub prompt: String,
+    pub(crate) pending: PendingEffect,
+}
+
+impl DecisionRequest {
+    /// Whether `decision` is a valid answer to this request.
+    pub fn accepts(&self, decision: Decision) -> bool {
+        match (self.kind, decision) {
+            (DecisionKind::YesNo, Decision::Yes | Decision::No) => true,
+            (DecisionKind::Quantity { min, max }, Decision::Quantity(n)) => (min..=max).contains(&n),
+            (DecisionKind::Roll, Decision::Roll) => true,
+            (DecisionKind::RaiseCash { .. }, Decision::Yes | Decision::Sell { .. }) => true,
+            (DecisionKind::Price { max, step }, Decision::Price(price)) => (Money::ZERO..=max).contains(&price) && price.dollars() % step.dollars().max(1) == 0,
+            (DecisionKind::Discard { .. }, Decision::Discard { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+impl GameState {
+    /// The question the game is waiting on, if any. Decisions are answered in
+    /// the order their effects raised them.
+    pub fn pending_decision(&self) -> Option<&DecisionRequest> {
+        self.pending_decisions.front()
+    }
+
+    /// Answers the pending decision and finishes its effect. A rejected answer,
+    /// or a purchase the player can't make, leaves the request pending. A bill
+    /// the bank won't lend toward puts the player out of the game.
+    pub fn answer_decision(&mut self, player_id: usize, decision: Decision, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let request = self.pending_decisions.front().ok_or(GameError::NoDecisionPending)?;
+        if request.player_id != player_id {
+            return Err(GameError::InvalidDecision(format!("waiting on player {}", request.player_id)));
+        }
+        if !request.accepts(decision) {
+            return Err(GameError::InvalidDecision(format!("{:?} does not answer a {:?} question", decision, request.kind)));
+        }
+        if let (DecisionKind::RaiseCash { amount }, Decision::Sell { asset, quantity }) = (request.kind, decision) {
+            // The bill waits until the player is done selling
+            logs.push(self.sell_to_bank(player_id, asset, quantity)?);
+            let prompt = self.raise_cash_prompt(player_id, amount);
+            if let Some(request) = self.pending_decisions.front_mut() {
+                request.prompt = prompt;
+            }
+            return Ok(());
+        }
+        if let Decision::Discard { card_id } = decision {
+            // Asked again until the hand is down to the limit
+            logs.push(self.discard_card(player_id, card_id)?);
+            let over = self.cards_over_limit(player_id);
+            if over == 0 {
+                self.pending_decisions.pop_front();
+            } else {
+                let prompt = self.discard_prompt(player_id);
+                if let Some(request) = self.pending_decisions.front_mut() {
+                    request.kind = DecisionKind::Discard { over };
+                    request.prompt = prompt;
+                }
+            }
+            return Ok(());
+        }
+        let request = self.pending_decisions.pop_front().ok_or(GameError::NoDecisionPending)?;
+        let mut result = EffectResolver::new(self, player_id).resume(&request.pending, decision, logs);
+        match (&result, &request.pending) {
+            // Let them buy fewer or pass instead
+            (Err(_), PendingEffect::OptionalBuy { .. }) => self.pending_decisions.push_front(request),
+            // Sold what they could and the bank still won't lend the rest: bankrupt
+            (Err(GameError::DebtLimitExceeded { .. }), PendingEffect::ForcedLoan { .. }) => {
+                result = self.eliminate_player(player_id, logs);
+            }
+            _ => {}
+        }
+        self.update_player_valuation(player_id);
+        result
+    }
+
+    /// What the AI would answer for `player_id`'s pending decision.
+    pub fn ai_decision(&self, request: &DecisionRequest) -> Option<Decision> {
+        Some(ai_decision(&self.view_for(request.player_id).ok()?, request))
+    }
+}
+
+/// AI answers use only what the deciding player can see. Purchases are made
+/// from cash on hand, never on credit, and stay within the farm limits.
+pub fn ai_decision(view: &PlayerView, request: &DecisionRequest) -> Decision {
+    match (&request.pending, request.kind) {
+        (PendingEffect::OptionalBuy { asset, quantity, cost }, kind) => {
+            let unit_cost = (*cost / (*quantity).max(1)).max(Money(1));
+            let room = constraints::room_for(&view.rules, &view.viewer, *asset);
+            let affordable = (view.viewer.cash.dollars() / unit_cost.dollars()).min(room.into());
+            match kind {
+                DecisionKind::Quantity { min, max } => Decision::Quantity(affordable.clamp(min.into(), max.into()) as i32),
+                _ if affordable >= (*quantity).into() => Decision::Yes,
+                _ => Decision::No,
+            }
+        }
+        (PendingEffect::AshRoll, _) => Decision::Roll,
+        (PendingEffect::AshCleanup { cost, cow_price, .. }, kind) => {
+            // Borrow when the bank allows it; sell only the cows the loan can't cover
+            let credit = view.rules.remaining_credit(view.viewer.debt);
+            let uncovered = *cost - view.viewer.cash - credit;
+            let cows = uncovered.units_at(*cow_price).min(i32::MAX.into()) as i32;
+            match kind {
+                DecisionKind::Quantity { min, max } => Decision::Quantity(cows.clamp(min, max)),
+                _ => Decision::No,
+            }
+        }
+        (PendingEffect::ForcedLoan { amount, .. }, _) => {
+            // Borrow when the bank allows it; otherwise sell, cows first, until a loan will do
+            let credit = view.rules.remaining_credit(view.viewer.debt);
+            let shortfall = *amount - view.viewer.cash;
+            if shortfall <= Money::ZERO || view.rules.forced_loan(shortfall).0 <= credit {
+                return Decision::Yes;
+            }
+            let cow_price = view.cow_price_by_year.range(..=view.viewer.year).next_back().map_or(BASE_COW_PRICE, |(_, price)| *price);
+            std::iter::once((AssetType::Cows, cow_price))
+                .chain(view.rules.resale_values.iter().map(|(asset, price)| (*asset, *price)))
+                .find_map(|(asset, price)| {
+                    let owned = view.viewer.assets.get(&asset).map_or(0, |record| record.quantity);
+                    let quantity = (shortfall - credit).units_at(price).clamp(0, owned.into()) as u32;
+                    (quantity > 0).then_some(Decision::Sell { asset, quantity })
+                })
+                .unwrap_or(Decision::Yes)
+        }
+        // Undercut the bank by a quarter, and hire anyone who does
+        (PendingEffect::CustomWorkQuote { job, .. }, _) => {
+            Decision::Price(job.bill * 3 / 4 / CUSTOM_WORK_PRICE_STEP.dollars() * CUSTOM_WORK_PRICE_STEP.dollars())
+        }
+        (PendingEffect::CustomWorkOffer { job, price, .. }, _) if *price < job.bill => Decision::Yes,
+        (PendingEffect::CustomWorkOffer { .. }, _) => Decision::No,
+        // The lease is paid for either way, so fill the ridge
+        (PendingEffect::StockRidge { .. }, DecisionKind::Quantity { max, .. }) => Decision::Quantity(max),
+        (PendingEffect::StockRidge { .. }, _) => Decision::No,
+        (PendingEffect::DiscardDown, _) => {
+            discard::least_useful(&view.viewer.hand, view.viewer.cash).map_or(Decision::No, |card_id| Decision::Discard { card_id })
+        }
+    }
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_support::card_with;
+    use crate::config::GameRules;
+    use crate::game::{Action, Decision, DecisionKind, GameEffect, GameEngine, GameError, GameEvent};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    /// Two farmers with $5,000 each; player 1 is an AI.
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Farmer".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Bot".to_string(), PlayerType::AI("test".to_string())));
+        let mut game = GameState::new_with_players(players, vec![0, 1], GameRules::default());
+        for player in game.players.iter_mut() {
+            player.cash = Money(5000);
+        }
+        game
+    }
+
+
+    #[test]
+    fn test_optional_buy_waits_for_quantity() {
+        let mut game = setup_game();
+        let card = card_with(900, GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 10, cost: Money(5000) });
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &card, &mut logs).unwrap();
+
+        let request = game.pending_decision().unwrap();
+        assert_eq!(request.player_id, 0);
+        assert_eq!(request.kind, DecisionKind::Quantity { min: 0, max: 10 });
+        assert!(!game.players[0].assets.contains_key(&AssetType::Cows), "Nothing is bought before the answer");
+
+        assert!(matches!(game.answer_decision(0, Decision::Yes, &mut logs), Err(GameError::InvalidDecision(_))));
+        assert!(matches!(game.answer_decision(0, Decision::Quantity(11), &mut logs), Err(GameError::InvalidDecision(_))));
+        game.answer_decision(0, Decision::Quantity(4), &mut logs).unwrap();
+
+        assert!(game.pending_decision().is_none());
+        assert_eq!(game.players[0].assets[&AssetType::Cows].quantity, 4);
+        assert_eq!(game.players[0].cash, Money(3000));
+    }
+
+    #[test]
+    fn test_engine_only_accepts_decisions_while_one_is_pending() {
+        let mut engine = GameEngine::new(setup_game());
+        let card = card_with(900, GameEffect::OptionalBuyAsset { asset: AssetType::Tractor, quantity: 1, cost: Money(10000) });
+        engine.state_mut().apply_card_effect(0, &card, &mut Vec::new()).unwrap();
+        assert_eq!(engine.state().pending_decision().unwrap().kind, DecisionKind::YesNo);
+
+        assert_eq!(engine.apply(Action::EndTurn), Err(GameError::DecisionPending));
+        let logs = engine.apply(Action::Decide(Decision::No)).unwrap();
+
+        assert!(logs.iter().any(|l| l.contains("passed on")));
+        assert_eq!(engine.state().players[0].cash, Money(5000));
+        assert_eq!(engine.apply(Action::Decide(Decision::No)), Err(GameError::NoDecisionPending));
+    }
+
+    #[test]
+    fn test_mt_st_helens_asks_other_players_to_roll() {
+        let mut game = setup_game();
+        let card = card_with(900, GameEffect::MtStHelensDisaster);
+        game.apply_card_effect(0, &card, &mut Vec::new()).unwrap();
+
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!((request.player_id, request.kind), (1, DecisionKind::Roll));
+        assert!(matches!(game.answer_decision(0, Decision::Roll, &mut Vec::new()), Err(GameError::InvalidDecision(_))));
+
+        assert_eq!(game.ai_decision(&request), Some(Decision::Roll));
+        let mut logs = Vec::new();
+        game.answer_decision(1, Decision::Roll, &mut logs).unwrap();
+        assert!(logs.iter().any(|l| l.contains("Bot rolled")));
+        assert!(game.pending_decision().is_none());
+    }
+
+    /// The farmer rolls for the ash with 10 acres of hay, 10 cows and no cash,
+    /// on the first seed where the roll comes up even.
+    fn farmer_hit_by_ash() -> GameState {
+        let card = card_with(900, GameEffect::MtStHelensDisaster);
+        (0..100).find_map(|seed| {
+            let mut game = setup_game();
+            game.reseed(seed);
+            game.players[0].cash = Money::ZERO;
+            game.players[0].assets.clear();
+            game.players[0].add_asset(AssetType::Hay, 10, Money::ZERO);
+            game.players[0].add_asset(AssetType::Cows, 10, Money::ZERO);
+            game.apply_card_effect(1, &card, &mut Vec::new()).unwrap();
+            let mut logs = Vec::new();
+            game.answer_decision(0, Decision::Roll, &mut logs).unwrap();
+            logs.iter().any(|l| l.contains("hit by the ash")).then_some(game)
+        }).expect("some seed rolls even")
+    }
+
+    #[test]
+    fn test_ash_cleanup_lets_player_sell_cows_before_borrowing() {
+        let mut game = farmer_hit_by_ash();
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(request.player_id, 0);
+        assert_eq!(request.kind, DecisionKind::Quantity { min: 0, max: 2 }, "Two cows cover the $1000 cleanup");
+        assert!(request.prompt.contains("$1,000"));
+        assert_eq!(game.ai_decision(&request), Some(Decision::Quantity(0)), "AI borrows while the bank allows it");
+
+        let debt = game.players[0].debt;
+        let mut logs = Vec::new();
+        game.answer_decision(0, Decision::Quantity(1), &mut logs).unwrap();
+        let player = &game.players[0];
+        assert_eq!(player.assets[&AssetType::Cows].quantity, 9);
+        assert!(player.debt > debt, "The rest of the cleanup is borrowed");
+        assert!(game.pending_decision().is_none());
+        assert!(game.events.contains(&GameEvent::AshRolled { player_id: 0, escaped: false, cows: 9 }));
+    }
+
+    #[test]
+    fn test_ai_sells_cows_when_the_bank_says_no() {
+        let mut game = farmer_hit_by_ash();
+        game.players[0].debt = game.rules.max_debt;
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(game.ai_decision(&request), Some(Decision::Quantity(2)));
+    }
+
+    #[test]
+    fn test_forced_loans_wait_for_human_players_to_confirm() {
+        let mut game = setup_game();
+        game.confirm_forced_loans = true;
+        game.players[0].add_asset(AssetType::Cows, 10, Money::ZERO);
+        let card = card_with(900, GameEffect::Expense(Money(6000)));
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &card, &mut logs).unwrap();
+
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(request.kind, DecisionKind::RaiseCash { amount: Money(6000) });
+        assert!(request.prompt.contains("$5,000") && request.prompt.contains("$1,000 fee"), "{}", request.prompt);
+        assert_eq!(game.players[0].cash, Money(5000), "Nothing is paid before the answer");
+
+        game.answer_decision(0, Decision::Sell { asset: AssetType::Cows, quantity: 2 }, &mut logs).unwrap();
+        assert!(game.pending_decision().unwrap().prompt.contains("have the cash"), "The question waits for the player to pay");
+        game.answer_decision(0, Decision::Yes, &mut logs).unwrap();
+        let player = &game.players[0];
+        assert_eq!((player.cash, player.debt), (Money::ZERO, Money::ZERO), "Selling the cows covered the bill");
+        assert_eq!(player.assets[&AssetType::Cows].quantity, 8);
+
+        // The AI borrows straight away
+        game.apply_card_effect(1, &card, &mut Vec::new()).unwrap();
+        assert!(game.pending_decision().is_none());
+        assert_eq!(game.players[1].debt, Money(5000));
+    }
+
+    #[test]
+    fn test_ai_buys_only_what_cash_covers() {
+        let mut game = setup_game();
+        let card = card_with(900, GameEffect::OptionalBuyAsset { asset: AssetType::Grain, quantity: 10, cost: Money(20000) });
+        game.apply_card_effect(1, &card, &mut Vec::new()).unwrap();
+
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(game.ai_decision(&request), Some(Decision::Quantity(2)));
+    }
+
+    #[test]
+    fn test_ridge_lease_stocks_between_the_minimum_and_capacity() {
+        let lease = |game: &mut GameState, player_id: usize| {
+            let card = card_with(900, GameEffect::LeaseRidge { name: "Ahtanum Ridge".to_string(), cost: Money(20000), cow_count: 20 });
+            game.players[player_id].cash = Money(20000);
+            game.players[player_id].hand.push(card);
+            game.exercise_option_to_buy(player_id, 900, false).unwrap();
+        };
+        let ridge_cows = |game: &GameState| game.ridges.iter().find(|r| r.name == "Ahtanum Ridge").unwrap().cow_count;
+
+        // By default the lease takes the card's full herd
+        let mut game = setup_game();
+        lease(&mut game, 0);
+        assert!(game.pending_decision().is_none());
+        assert_eq!(ridge_cows(&game), 20);
+
+        let mut game = setup_game();
+        game.rules.ridge_min_cows = Some(12);
+        lease(&mut game, 0);
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(request.kind, DecisionKind::Quantity { min: 12, max: 20 });
+        assert_eq!(ridge_cows(&game), 12, "The lease is stocked with the minimum until the player answers");
+        assert!(matches!(game.answer_decision(0, Decision::Quantity(21), &mut Vec::new()), Err(GameError::InvalidDecision(_))));
+        let mut logs = Vec::new();
+        game.answer_decision(0, Decision::Quantity(15), &mut logs).unwrap();
+        assert_eq!(ridge_cows(&game), 15);
+        assert!(logs.iter().any(|l| l.contains("stocked Ahtanum Ridge with 15 cows")), "{:?}", logs);
+
+        // The AI fills the ridge
+        let mut game = setup_game();
+        game.rules.ridge_min_cows = Some(12);
+        lease(&mut game, 1);
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(game.ai_decision(&request), Some(Decision::Quantity(20)));
+    }
+}
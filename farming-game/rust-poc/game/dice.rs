@@ -0,0 +1,95 @@
+// src/game/dice.rs
+// Dice rolled for movement, harvests and everything else left to chance.
+// Faces come from a `DiceSource`: the game's seeded RNG in play, or a set
+// of loaded dice when a test needs particular rolls.
+
+use std::collections::VecDeque;
+use rand::Rng;
+
+/// Where dice get their faces.
+pub trait DiceSource {
+    /// A face from 1 to `sides`.
+    fn face(&mut self, sides: u32) -> u32;
+}
+
+impl<R: Rng + ?Sized> DiceSource for R {
+    fn face(&mut self, sides: u32) -> u32 {
+        self.gen_range(1..=sides)
+    }
+}
+
+/// Dice that come up with the faces they were loaded with, in order.
+/// For tests that need particular rolls; panics once the faces run out.
+#[derive(Debug, Clone, Default)]
+pub struct LoadedDice {
+    faces: VecDeque<u32>,
+}
+
+impl LoadedDice {
+    pub fn new(faces: impl IntoIterator<Item = u32>) -> Self {
+        Self { faces: faces.into_iter().collect() }
+    }
+
+    /// Faces not rolled yet.
+    pub fn remaining(&self) -> usize {
+        self.faces.len()
+    }
+}
+
+impl DiceSource for LoadedDice {
+    fn face(&mut self, sides: u32) -> u32 {
+        let face = self.faces.pop_front().expect("loaded dice ran out of faces");
+        assert!((1..=sides).contains(&face), "loaded face {} doesn't fit a {}-sided die", face, sides);
+        face
+    }
+}
+
+/// A number of identical dice rolled together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dice {
+    pub count: u32,
+    pub sides: u32,
+}
+
+impl Dice {
+    /// The single six-sided die the board game uses for moving and harvests.
+    pub const ONE: Dice = Dice { count: 1, sides: 6 };
+    /// A pair of six-sided dice.
+    pub const TWO: Dice = Dice { count: 2, sides: 6 };
+
+    pub fn new(count: u32, sides: u32) -> Self {
+        Self { count: count.max(1), sides: sides.max(1) }
+    }
+
+    /// Rolls every die once.
+    pub fn roll<S: DiceSource + ?Sized>(&self, source: &mut S) -> Roll {
+        Roll { faces: (0..self.count).map(|_| source.face(self.sides)).collect() }
+    }
+
+    /// Lowest and highest totals these dice can roll.
+    pub fn range(&self) -> (u32, u32) {
+        (self.count, self.count * self.sides)
+    }
+}
+
+/// The faces one roll of the dice came up with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Roll {
+    pub faces: Vec<u32>,
+}
+
+impl Roll {
+    pub fn total(&self) -> u32 {
+        self.faces.iter().sum()
+    }
+
+    /// Whether two or more dice all came up the same.
+    pub fn is_doubles(&self) -> bool {
+        self.faces.len() > 1 && self.faces.windows(2).all(|pair| pair[0] == pair[1])
+    }
+
+    /// Whether the total is odd, as when rolling to escape the Mt. St. Helens ash.
+    pub fn is_odd(&self) -> bool {
+        self.total() % 2 == 1
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::{Dice, LoadedDice};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_loaded_dice_roll_their_faces_in_order() {
+        let mut dice = LoadedDice::new([3, 5, 5]);
+        assert_eq!(Dice::ONE.roll(&mut dice).total(), 3);
+
+        let roll = Dice::TWO.roll(&mut dice);
+        assert_eq!(roll.faces, vec![5, 5]);
+        assert_eq!(roll.total(), 10);
+        assert!(roll.is_doubles());
+        assert_eq!(dice.remaining(), 0);
+    }
+
+    #[test]
+    fn test_doubles_and_odd_totals() {
+        let mut dice = LoadedDice::new([2, 4, 3]);
+        let pair = Dice::TWO.roll(&mut dice);
+        assert!(!pair.is_doubles());
+        assert!(!pair.is_odd());
+
+        // A single die is never doubles
+        let single = Dice::ONE.roll(&mut dice);
+        assert!(!single.is_doubles());
+        assert!(single.is_odd());
+    }
+
+    #[test]
+    #[should_panic(expected = "ran out of faces")]
+    fn test_loaded_dice_panic_when_empty() {
+        Dice::ONE.roll(&mut LoadedDice::new([]));
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit")]
+    fn test_loaded_face_must_fit_the_die() {
+        Dice::ONE.roll(&mut LoadedDice::new([7]));
+    }
+
+    #[test]
+    fn test_seeded_rolls_stay_in_range_and_repeat() {
+        let dice = Dice::TWO;
+        let (low, high) = dice.range();
+        assert_eq!((low, high), (2, 12));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let first: Vec<u32> = (0..200).map(|_| dice.roll(&mut rng).total()).collect();
+        assert!(first.iter().all(|total| (low..=high).contains(total)));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let again: Vec<u32> = (0..200).map(|_| dice.roll(&mut rng).total()).collect();
+        assert_eq!(first, again);
+    }
+}
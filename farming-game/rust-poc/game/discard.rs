@@ -0,0 +1,76 @@
+// src/game/discard.rs
+// The hand limit (`GameRules::hand_limit`): a player ending their turn with
+// more cards than the limit discards down to it first, one card per
+// `Decision::Discard`. Discards go on the Option to Buy discard pile.
+
+use std::sync::Arc;
+use crate::cards::card::Card;
+use crate::game::{DecisionKind, DecisionRequest, GameError, GameEffect};
+use crate::game::decision::PendingEffect;
+use crate::models::GameState;
+use crate::models::Money;
+
+impl GameState {
+    /// How many cards `player_id` holds past the hand limit.
+    pub fn cards_over_limit(&self, player_id: usize) -> usize {
+        match (self.rules.hand_limit, self.players.get(player_id)) {
+            (Some(limit), Some(player)) => player.hand.len().saturating_sub(limit),
+            _ => 0,
+        }
+    }
+
+    /// Asks `player_id` to discard down to the hand limit before their turn
+    /// ends. True if they have discards to make, whether asked now or already.
+    pub fn request_discards(&mut self, player_id: usize) -> bool {
+        let over = self.cards_over_limit(player_id);
+        if over == 0 {
+            return false;
+        }
+        let asked = self.pending_decisions.iter()
+            .any(|request| request.player_id == player_id && request.pending == PendingEffect::DiscardDown);
+        if !asked {
+            let prompt = self.discard_prompt(player_id);
+            self.pending_decisions.push_back(DecisionRequest {
+                player_id, kind: DecisionKind::Discard { over }, prompt, pending: PendingEffect::DiscardDown,
+            });
+        }
+        true
+    }
+
+    /// Moves a card from the player's hand to the Option to Buy discard pile.
+    pub fn discard_card(&mut self, player_id: usize, card_id: usize) -> Result<String, GameError> {
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let index = player.hand.iter().position(|card| card.id == card_id)
+            .ok_or(GameError::CardNotFound { player_id, card_id })?;
+        let card = player.hand.remove(index);
+        tracing::info!(player_id, card_id, hand = player.hand.len(), "discarded to the hand limit");
+        let locale = Arc::clone(&self.locale);
+        let log = locale.tr("card.discarded", &[("player", &player.name), ("card", &locale.card_title(&card))]);
+        self.option_to_buy_deck.discard(card);
+        Ok(log)
+    }
+
+    pub(crate) fn discard_prompt(&self, player_id: usize) -> String {
+        let player = &self.players[player_id];
+        self.locale.tr("decision.discard", &[("player", &player.name), ("hand", &player.hand.len()),
+            ("limit", &self.rules.hand_limit.unwrap_or_default()), ("over", &self.cards_over_limit(player_id))])
+    }
+}
+
+/// The card a farmer with `cash` gets least from: the priciest option they
+/// can't pay for outright, or failing that the priciest one they can.
+pub(crate) fn least_useful(hand: &[Card], cash: Money) -> Option<usize> {
+    hand.iter()
+        .max_by_key(|card| {
+            let cost = option_cost(card);
+            (cost > cash, cost)
+        })
+        .map(|card| card.id)
+}
+
+fn option_cost(card: &Card) -> Money {
+    match card.effect {
+        GameEffect::OptionalBuyAsset { cost, .. } | GameEffect::LeaseRidge { cost, .. } => cost,
+        _ => Money::ZERO,
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::config::GameRules;
+    use crate::game::{Action, Decision, DecisionKind, GameEffect, GameEngine, GameError, Replay};
+    use crate::models::AssetType;
+    use crate::models::Money;
+
+    fn option(id: usize, cost: Money) -> Card {
+        Card {
+            id,
+            title: format!("Option {}", id),
+            description: "Test option".to_string(),
+            description_brief: "Test option".to_string(),
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Hay, quantity: 10, cost },
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        }
+    }
+
+    /// Two farmers dealt the usual two options, with a hand limit of one.
+    fn limited_engine() -> GameEngine {
+        let rules = GameRules { hand_limit: Some(1), ..GameRules::default() };
+        GameEngine::with_seed(&["Ann".to_string(), "Bob".to_string()], rules, 7).unwrap()
+    }
+
+    #[test]
+    fn test_ending_a_turn_over_the_limit_asks_for_a_discard() {
+        let mut engine = limited_engine();
+        let discards = engine.state().option_to_buy_deck.discard_pile.len();
+
+        engine.apply(Action::EndTurn).unwrap();
+        assert_eq!(engine.current_player_id(), 0, "The turn waits on the discard");
+        let request = engine.state().pending_decision().unwrap();
+        assert_eq!((request.player_id, request.kind), (0, DecisionKind::Discard { over: 1 }));
+        assert_eq!(engine.apply(Action::Roll), Err(GameError::DecisionPending));
+
+        engine.end_turn();
+        assert_eq!(engine.state().pending_decisions.len(), 1, "Asked once however often the turn is ended");
+
+        let card_id = engine.state().players[0].hand[0].id;
+        engine.apply(Action::Decide(Decision::Discard { card_id })).unwrap();
+        assert_eq!(engine.state().players[0].hand.len(), 1);
+        assert_eq!(engine.state().option_to_buy_deck.discard_pile.len(), discards + 1);
+        assert_eq!(engine.state().option_to_buy_deck.discard_pile.last().unwrap().id, card_id);
+        assert!(engine.state().pending_decision().is_none());
+
+        engine.apply(Action::EndTurn).unwrap();
+        assert_eq!(engine.current_player_id(), 1);
+    }
+
+    #[test]
+    fn test_discarding_a_card_not_in_hand_leaves_the_request() {
+        let mut engine = limited_engine();
+        engine.end_turn();
+
+        let missing = engine.apply(Action::Decide(Decision::Discard { card_id: 9999 }));
+        assert_eq!(missing, Err(GameError::CardNotFound { player_id: 0, card_id: 9999 }));
+        assert!(engine.state().pending_decision().is_some());
+    }
+
+    #[test]
+    fn test_no_limit_by_default() {
+        let mut engine = GameEngine::with_seed(&["Ann".to_string(), "Bob".to_string()], GameRules::default(), 7).unwrap();
+        engine.state_mut().players[0].hand.extend((900..910).map(|id| option(id, Money(1000))));
+
+        engine.end_turn();
+        assert_eq!(engine.current_player_id(), 1);
+    }
+
+    #[test]
+    fn test_ai_discards_the_priciest_option_it_cannot_afford() {
+        let mut engine = limited_engine();
+        let state = engine.state_mut();
+        state.players[0].cash = Money(5000);
+        state.players[0].hand = vec![option(900, Money(4000)), option(901, Money(12000)), option(902, Money(8000))];
+        state.rules.hand_limit = Some(2);
+        assert!(state.request_discards(0));
+
+        let request = engine.state().pending_decision().unwrap().clone();
+        assert_eq!(engine.state().ai_decision(&request), Some(Decision::Discard { card_id: 901 }));
+    }
+
+    #[test]
+    fn test_replay_keeps_the_discard_in_its_turn() {
+        let mut engine = limited_engine();
+        engine.apply(Action::EndTurn).unwrap();
+        let card_id = engine.state().players[0].hand[0].id;
+        engine.apply(Action::Decide(Decision::Discard { card_id })).unwrap();
+        engine.apply(Action::EndTurn).unwrap();
+
+        let replay = Replay::new(engine.record().unwrap()).unwrap();
+        assert_eq!(replay.turn_count(), 1);
+    }
+}
@@ -0,0 +1,126 @@
+// Effects looked up by name, so cards loaded from data (see `cards::pack`) can
+// do things without a new `GameEffect` variant. Built-in keys wrap the
+// existing effects; mods register their own handlers next to them.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use crate::game::{EffectResolver, GameEffect, GameError};
+use crate::models::{AssetType, GameState, Money};
+
+/// Runs an effect for one player: `(game, player_id, params, logs)`.
+pub type EffectHandler = Arc<dyn Fn(&mut GameState, usize, &EffectParams, &mut Vec<String>) -> Result<(), GameError> + Send + Sync>;
+
+/// Parameters a card passes to its effect, e.g. `{ asset = "Cows", amount = 500 }`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EffectParams(pub toml::Table);
+
+impl EffectParams {
+    pub fn int(&self, name: &str) -> Result<i32, GameError> {
+        self.get(name)?.as_integer()
+            .and_then(|value| i32::try_from(value).ok())
+            .ok_or_else(|| invalid(name, "must be a whole number"))
+    }
+
+    /// A number of assets, which can't be negative.
+    pub fn count(&self, name: &str) -> Result<u32, GameError> {
+        self.get(name)?.as_integer()
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or_else(|| invalid(name, "must be a whole number, zero or more"))
+    }
+
+    /// A dollar amount, written as a whole number.
+    pub fn money(&self, name: &str) -> Result<Money, GameError> {
+        self.get(name)?.as_integer()
+            .map(Money)
+            .ok_or_else(|| invalid(name, "must be a whole number of dollars"))
+    }
+
+    pub fn float(&self, name: &str) -> Result<f32, GameError> {
+        let value = self.get(name)?;
+        value.as_float().or_else(|| value.as_integer().map(|i| i as f64))
+            .map(|value| value as f32)
+            .ok_or_else(|| invalid(name, "must be a number"))
+    }
+
+    pub fn text(&self, name: &str) -> Result<&str, GameError> {
+        self.get(name)?.as_str().ok_or_else(|| invalid(name, "must be text"))
+    }
+
+    /// An asset by its TOML name, e.g. `"Cows"`.
+    pub fn asset(&self, name: &str) -> Result<AssetType, GameError> {
+        self.get(name)?.clone().try_into().map_err(|_| invalid(name, "must be an asset such as \"Cows\""))
+    }
+
+    fn get(&self, name: &str) -> Result<&toml::Value, GameError> {
+        self.0.get(name).ok_or_else(|| invalid(name, "is missing"))
+    }
+}
+
+fn invalid(param: &str, reason: &str) -> GameError {
+    GameError::InvalidEffectParams { param: param.to_string(), reason: reason.to_string() }
+}
+
+/// Handlers by effect key. Cloning is cheap; handlers are shared.
+#[derive(Clone, Default)]
+pub struct EffectRegistry {
+    handlers: BTreeMap<String, EffectHandler>,
+}
+
+impl fmt::Debug for EffectRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
+
+impl EffectRegistry {
+    /// A registry holding the built-in effects.
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry.register_builtin("income", |p| Ok(GameEffect::Income(p.money("amount")?)));
+        registry.register_builtin("expense", |p| Ok(GameEffect::Expense(p.money("amount")?)));
+        registry.register_builtin("adjust_debt", |p| Ok(GameEffect::AdjustDebt(p.money("amount")?)));
+        registry.register_builtin("adjust_land", |p| Ok(GameEffect::AdjustLand(p.int("acres")?)));
+        registry.register_builtin("income_if_has", |p| Ok(GameEffect::IncomeIfHas { asset: p.asset("asset")?, amount: p.money("amount")? }));
+        registry.register_builtin("expense_if_has", |p| Ok(GameEffect::ExpenseIfHas { asset: p.asset("asset")?, amount: p.money("amount")? }));
+        registry.register_builtin("income_per_asset", |p| Ok(GameEffect::IncomePerAsset { asset: p.asset("asset")?, rate: p.money("rate")? }));
+        registry.register_builtin("expense_per_asset", |p| Ok(GameEffect::ExpensePerAsset { asset: p.asset("asset")?, rate: p.money("rate")? }));
+        registry.register_builtin("income_per_land_acre", |p| Ok(GameEffect::IncomePerLandAcre { rate: p.money("rate")? }));
+        registry.register_builtin("collect_from_others_if_has", |p| Ok(GameEffect::CollectFromOthersIfHas { asset: p.asset("asset")?, amount: p.money("amount")? }));
+        registry.register_builtin("pay_if_no_asset", |p| Ok(GameEffect::PayIfNoAssetDistribute { required_asset: p.asset("asset")?, amount: p.money("amount")? }));
+        registry.register_builtin("buy_asset", |p| Ok(GameEffect::BuyAsset { asset: p.asset("asset")?, quantity: p.count("quantity")?, cost: p.money("cost")? }));
+        registry.register_builtin("double_yield", |p| Ok(GameEffect::DoubleYieldForCrop(p.asset("asset")?)));
+        registry.register_builtin("move_to_tile", |p| Ok(GameEffect::MoveToTile(p.int("tile")?.max(0) as usize)));
+        registry.register_builtin("pay_interest", |_| Ok(GameEffect::PayInterest));
+        registry.register_builtin("skip_year", |_| Ok(GameEffect::SkipYear));
+        registry.register_builtin("drought_year", |_| Ok(GameEffect::DroughtYear));
+        registry.register_builtin("slaughter_cows", |_| Ok(GameEffect::SlaughterCowsWithoutCompensation));
+        registry.register_builtin("slaughter_cows_with_compensation", |_| Ok(GameEffect::SlaughterCowsWithCompensation));
+        registry.register_builtin("machinery_coop", |p| Ok(GameEffect::MachineryCoop { percent: p.int("percent")?.clamp(0, 100) as u32, years: p.int("years")?.max(0) as u32 }));
+        registry
+    }
+
+    /// Registers `handler` under `key`, replacing any handler already there.
+    pub fn register<F>(&mut self, key: &str, handler: F)
+    where
+        F: Fn(&mut GameState, usize, &EffectParams, &mut Vec<String>) -> Result<(), GameError> + Send + Sync + 'static,
+    {
+        self.handlers.insert(key.to_string(), Arc::new(handler));
+    }
+
+    /// A built-in effect: the params pick out a `GameEffect`, which resolves as usual.
+    fn register_builtin(&mut self, key: &str, effect: fn(&EffectParams) -> Result<GameEffect, GameError>) {
+        self.register(key, move |game, player_id, params, logs| {
+            EffectResolver::new(game, player_id).resolve(&effect(params)?, logs)
+        });
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.handlers.contains_key(key)
+    }
+
+    pub fn apply(&self, key: &str, game: &mut GameState, player_id: usize, params: &EffectParams, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let handler = self.handlers.get(key).ok_or_else(|| GameError::UnknownEffect(key.to_string()))?;
+        handler(game, player_id, params, logs)
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::CardSource;
+    use crate::cards::pack::{CardPack, CardPackError};
+    use crate::config::GameRules;
+    use crate::game::{EffectParams, GameEffect, GameError};
+    use crate::models::{GameState, LedgerCategory, Money, Player, PlayerRegistry, PlayerType};
+
+    const PACK: &str = r#"
+        name = "County Fair"
+
+        [[cards]]
+        deck = "farmers_fate"
+        title = "Blue Ribbon Heifer"
+        description = "Your heifer takes best in show. Collect $100 per hay acre."
+        effect = "income_per_asset"
+        params = { asset = "Hay", rate = 100 }
+        quantity = 2
+
+        [[cards]]
+        deck = "operating_cost"
+        title = "Fair Booth"
+        description = "Rent a booth at the fair."
+        effect = "booth_rent"
+    "#;
+
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Farmer".to_string(), PlayerType::Human));
+        GameState::new_with_players(players, vec![0], GameRules::default())
+    }
+
+    fn booth_rent(game: &mut GameState, player_id: usize, _params: &EffectParams, logs: &mut Vec<String>) -> Result<(), GameError> {
+        game.pay_expense(player_id, LedgerCategory::CardExpense, Money(250), logs)
+    }
+
+    #[test]
+    fn test_pack_cards_need_a_registered_effect() {
+        let pack = CardPack::from_toml(PACK).unwrap();
+        let mut game = setup_game();
+        let cards_before = game.operating_cost_deck.remaining();
+        assert!(matches!(game.add_card_pack(&pack), Err(CardPackError::Invalid { title, .. }) if title == "Fair Booth"));
+        assert_eq!(game.operating_cost_deck.remaining(), cards_before, "Nothing is added from an invalid pack");
+
+        game.register_effect("booth_rent", booth_rent);
+        assert_eq!(game.add_card_pack(&pack).unwrap(), 3);
+        let booth = game.operating_cost_deck.draw_pile.iter().find(|card| card.title == "Fair Booth").unwrap();
+        assert_eq!(booth.source, CardSource::Pack);
+        assert!(game.farmer_fate_deck.draw_pile.iter().all(|card| card.id != booth.id), "Pack cards get fresh ids");
+    }
+
+    #[test]
+    fn test_builtin_and_registered_effects_resolve_like_cards() {
+        let pack = CardPack::from_toml(PACK).unwrap();
+        let mut game = setup_game();
+        game.register_effect("booth_rent", booth_rent);
+        game.players[0].cash = Money(1000);
+        let mut logs = Vec::new();
+        for (_, card) in pack.cards(900).iter().skip(1) {
+            game.apply_card_effect(0, card, &mut logs).unwrap();
+        }
+
+        // 10 hay acres from Grandpa at $100 each, then the booth
+        assert_eq!(game.players[0].cash, Money(1750));
+        assert_eq!(game.players[0].ledger.total(LedgerCategory::CardExpense), Money(-250));
+    }
+
+    #[test]
+    fn test_bad_params_and_unknown_keys_are_errors() {
+        let mut game = setup_game();
+        let mut logs = Vec::new();
+        let params = EffectParams(toml::toml! { amount = "lots" });
+        let income = GameEffect::Custom { key: "income".to_string(), params };
+        let effects = std::sync::Arc::clone(&game.effects);
+        assert!(matches!(effects.apply("income", &mut game, 0, &EffectParams::default(), &mut logs),
+            Err(GameError::InvalidEffectParams { param, .. }) if param == "amount"));
+        assert!(matches!(game.apply_card_effect(0, &pack_card(income), &mut logs), Err(GameError::InvalidEffectParams { .. })));
+        assert_eq!(effects.apply("hailstorm", &mut game, 0, &EffectParams::default(), &mut logs),
+                   Err(GameError::UnknownEffect("hailstorm".to_string())));
+    }
+
+    fn pack_card(effect: GameEffect) -> crate::cards::card::Card {
+        let mut card = CardPack::from_toml(PACK).unwrap().cards(1).remove(0).1;
+        card.effect = effect;
+        card
+    }
+}
@@ -0,0 +1,551 @@
+// Applies a GameEffect to one player. Board tiles and cards both end up here
+// (tiles through `GameEffect::from(&TileEffect)`), so each effect is
+// implemented once.
+
+use std::sync::Arc;
+use crate::cards::card::Card;
+use crate::game::{constraints, CustomWorkJob, Decision, DecisionKind, DecisionRequest, Dice, GameEffect, GameError, GameEvent, StateMutation, Weather};
+use crate::game::decision::PendingEffect;
+use crate::game::insurance::{Coverage, LIVESTOCK_CLAIM_PER_HEAD};
+use crate::models::{AssetType, GameState, LedgerCategory, Money, TileType};
+use crate::models::persistent_effects::EffectType;
+
+/// What Mt. St. Helens' ash costs to clean up, per crop acre
+const ASH_CLEANUP_PER_ACRE: Money = Money(100);
+
+pub struct EffectResolver<'a> {
+    game: &'a mut GameState,
+    player_id: usize,
+    /// Card the effect is printed on; some effects log its text
+    card: Option<&'a Card>,
+}
+
+impl<'a> EffectResolver<'a> {
+    pub fn new(game: &'a mut GameState, player_id: usize) -> Self {
+        Self { game, player_id, card: None }
+    }
+
+    pub fn with_card(mut self, card: &'a Card) -> Self {
+        self.card = Some(card);
+        self
+    }
+
+    fn has_asset(&self, asset: &AssetType) -> bool {
+        self.game.players[self.player_id].assets.get(asset).is_some_and(|record| record.quantity > 0)
+    }
+
+    pub fn resolve(&mut self, effect: &GameEffect, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player_id = self.player_id;
+        let game = &mut *self.game;
+        let player_name = game.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?.name.clone();
+        let locale = Arc::clone(&game.locale);
+
+        match effect {
+            GameEffect::None => Ok(()),
+            GameEffect::Income(amount) => {
+                game.apply_mutations(&[StateMutation::CashDelta { player_id, amount: *amount, category: LedgerCategory::Bonus }])?;
+                logs.push(locale.tr("effect.gained_cash", &[("player", &player_name), ("amount", amount)]));
+                Ok(())
+            }
+            GameEffect::Expense(amount) => {
+                logs.push(locale.tr("effect.must_pay", &[("player", &player_name), ("amount", amount)]));
+
+                game.debit(player_id, *amount, LedgerCategory::CardExpense, logs)
+            }
+            GameEffect::BuyAsset { asset: asset_type, quantity, cost } => {
+                let total_cost = *quantity * *cost;
+                logs.push(locale.tr("buy.attempt", &[("player", &player_name), ("quantity", quantity),
+                    ("asset", &locale.asset_name(asset_type)), ("cost", cost), ("total", &total_cost)]));
+
+                let player = &game.players[player_id];
+                if player.cash < total_cost {
+                    return Err(GameError::InsufficientFunds { required: total_cost, available: player.cash });
+                }
+                game.check_farm_limit(player_id, *asset_type, *quantity)?;
+
+                game.debit(player_id, total_cost, LedgerCategory::Purchase, logs)?;
+                let player = &mut game.players[player_id];
+                player.acquire_asset(*asset_type, *quantity, total_cost);
+                logs.push(locale.tr("buy.success", &[("quantity", quantity),
+                    ("asset", &locale.asset_name(asset_type)), ("cash", &player.cash)]));
+                Ok(())
+            }
+            GameEffect::ExpensePerAsset { asset: asset_type, rate } => {
+                let count = game.players[player_id].assets.get(asset_type).map_or(0, |r| r.quantity);
+                let total_payment = count * *rate;
+                if total_payment.is_positive() {
+                    logs.push(locale.tr("effect.pay_per_asset", &[("player", &player_name), ("amount", &total_payment),
+                        ("count", &count), ("rate", rate), ("asset", &locale.asset_name(asset_type))]));
+                    game.debit(player_id, total_payment, LedgerCategory::CardExpense, logs)?;
+                } else {
+                    logs.push(locale.tr("effect.no_expense_for_asset", &[("player", &player_name), ("asset", &locale.asset_name(asset_type))]));
+                }
+                Ok(())
+            }
+            GameEffect::IncomePerAsset { asset: asset_type, rate } => {
+                let player = &mut game.players[player_id];
+                let count = player.assets.get(asset_type).map_or(0, |r| r.quantity);
+                let total_gain = count * *rate;
+                if total_gain.is_positive() {
+                    player.cash += total_gain;
+                    player.record_transaction(LedgerCategory::Bonus, total_gain);
+                    logs.push(locale.tr("effect.income_per_asset", &[("player", &player_name), ("amount", &total_gain),
+                        ("count", &count), ("rate", rate), ("asset", &locale.asset_name(asset_type))]));
+                    if let Some(record) = player.assets.get_mut(asset_type) {
+                        record.total_income += total_gain;
+                    }
+                } else if total_gain.is_negative() {
+                    // A price cut; crops kept in storage are spared
+                    if let Some(storage) = asset_type.storage().filter(|storage| player.assets.get(storage).is_some_and(|r| r.quantity > 0)) {
+                        logs.push(locale.tr("effect.price_cut_avoided", &[("player", &player_name),
+                            ("asset", &locale.asset_name(asset_type)), ("storage", &locale.asset_name(&storage))]));
+                        return Ok(());
+                    }
+                    logs.push(locale.tr("effect.price_cut_per_asset", &[("player", &player_name), ("amount", &-total_gain),
+                        ("count", &count), ("rate", &-*rate), ("asset", &locale.asset_name(asset_type))]));
+                    if let Some(record) = player.assets.get_mut(asset_type) {
+                        record.total_income += total_gain;
+                    }
+                    game.debit(player_id, -total_gain, LedgerCategory::CardExpense, logs)?;
+                } else {
+                    logs.push(locale.tr("effect.no_income_for_asset", &[("player", &player_name), ("asset", &locale.asset_name(asset_type))]));
+                }
+                Ok(())
+            }
+            GameEffect::IncomePerLandAcre { rate } => {
+                let acres = game.players[player_id].land;
+                let total_bonus = acres * *rate;
+                if total_bonus.is_positive() {
+                    logs.push(locale.tr("effect.income_per_land_acre", &[("player", &player_name), ("amount", &total_bonus),
+                        ("acres", &acres), ("rate", rate)]));
+                    game.apply_mutations(&[StateMutation::CashDelta { player_id, amount: total_bonus, category: LedgerCategory::Bonus }])?;
+                } else {
+                    logs.push(locale.tr("effect.no_land_income", &[("player", &player_name)]));
+                }
+                Ok(())
+            }
+            GameEffect::AdjustDebt(amount) => {
+                // Forgiveness beyond what's owed just clears the debt
+                let amount = (*amount).max(-game.players[player_id].debt);
+                game.apply_mutations(&[StateMutation::DebtDelta { player_id, amount }])?;
+                logs.push(locale.tr("effect.debt_adjusted", &[("player", &player_name), ("amount", &amount), ("debt", &game.players[player_id].debt)]));
+                Ok(())
+            }
+            GameEffect::AdjustLand(amount) => {
+                let player = &mut game.players[player_id];
+                player.land += *amount;
+                logs.push(locale.tr("effect.land_adjusted", &[("player", &player_name), ("amount", amount), ("land", &player.land)]));
+                Ok(())
+            }
+            GameEffect::Special(desc) => {
+                // Special tiles carry their own wording
+                match self.card {
+                    Some(_) => logs.push(locale.tr("effect.special_card", &[("player", &player_name), ("description", desc)])),
+                    None => logs.push(desc.clone()),
+                }
+                Ok(())
+            }
+            GameEffect::CollectFromOthersIfHas { asset, amount } => {
+                logs.push(locale.tr("collect.announce", &[("player", &player_name), ("amount", amount),
+                    ("asset", &locale.asset_name(asset))]));
+
+                let mut total_collected = Money::ZERO;
+                let other_player_ids: Vec<usize> = game.players.ids().filter(|&id| id != player_id).collect();
+                for other_player_id in other_player_ids {
+                    let other_player = &game.players[other_player_id];
+                    if !other_player.assets.contains_key(asset) {
+                        logs.push(locale.tr("collect.not_owned", &[("player", &other_player.name),
+                            ("asset", &locale.asset_name(asset))]));
+                        continue;
+                    }
+                    logs.push(locale.tr("collect.owned", &[("player", &other_player.name),
+                        ("asset", &locale.asset_name(asset)), ("amount", amount)]));
+                    total_collected += game.transfer_between_players(other_player_id, player_id, *amount, logs)?;
+                }
+
+                logs.push(locale.tr("collect.total", &[("player", &player_name), ("amount", &total_collected),
+                    ("cash", &game.players[player_id].cash)]));
+                Ok(())
+            }
+            GameEffect::PayIfNoAssetDistribute { required_asset, amount } => {
+                if !game.players[player_id].assets.contains_key(required_asset) {
+                    logs.push(locale.tr("effect.pay_without_asset", &[("asset", &locale.asset_name(required_asset)), ("amount", amount)]));
+                    // A neighbor with the equipment may do the work instead
+                    let contractors = game.custom_work_contractors(player_id, *required_asset);
+                    if contractors.is_empty() {
+                        game.debit(player_id, *amount, LedgerCategory::CardExpense, logs)?;
+                    } else {
+                        let job = CustomWorkJob { hirer: player_id, asset: *required_asset, bill: *amount };
+                        game.ask_for_custom_work(job, contractors, logs)?;
+                    }
+                } else {
+                    logs.push(locale.tr("effect.has_asset_no_pay", &[("asset", &locale.asset_name(required_asset))]));
+                }
+                Ok(())
+            }
+            GameEffect::IncomeIfHas { asset, amount } => {
+                if self.has_asset(asset) {
+                    self.game.apply_mutations(&[StateMutation::CashDelta { player_id, amount: *amount, category: LedgerCategory::Bonus }])?;
+                    logs.push(locale.tr("effect.gained_for_asset", &[("player", &player_name), ("amount", amount), ("asset", &locale.asset_name(asset))]));
+                } else {
+                    logs.push(locale.tr("effect.not_gained_no_asset", &[("amount", amount), ("asset", &locale.asset_name(asset))]));
+                }
+                Ok(())
+            }
+            GameEffect::ExpenseIfHas { asset, amount } => {
+                if self.has_asset(asset) {
+                    logs.push(locale.tr("effect.pay_for_asset", &[("player", &player_name), ("amount", amount), ("asset", &locale.asset_name(asset))]));
+                    self.game.debit(player_id, *amount, LedgerCategory::CardExpense, logs)?;
+                } else {
+                    logs.push(locale.tr("effect.no_pay_no_asset", &[("player", &player_name), ("asset", &locale.asset_name(asset))]));
+                }
+                Ok(())
+            }
+            GameEffect::SuppressHarvestIncome => {
+                // Bad at Taxes without losing the side job wage
+                game.players[player_id].garnish_harvest_income();
+                logs.push(locale.tr("effect.harvest_income_suppressed", &[("player", &player_name)]));
+                Ok(())
+            }
+            GameEffect::DrawOperatingExpenseNoHarvest => {
+                let player = &mut game.players[player_id];
+                player.revoke_side_job_pay();
+                player.garnish_harvest_income();
+                if let Some(card) = self.card {
+                    logs.push(locale.card_description_brief(card).to_string());
+                }
+                logs.push(locale.tr("effect.no_side_job_pay", &[("player", &player_name)]));
+                logs.push(locale.tr("effect.harvest_garnished", &[("player", &player_name)]));
+                Ok(())
+            }
+            GameEffect::SkipYear => {
+                game.skip_year(player_id, logs);
+                Ok(())
+            }
+            GameEffect::DroughtYear => {
+                // The drought hits the whole county for the rest of the year
+                let year = game.players[player_id].year;
+                if game.weather_for_year(year) != Weather::Drought {
+                    game.weather_by_year.insert(year, Weather::Drought);
+                    logs.push(locale.tr("weather.drought_declared", &[("year", &year), ("forecast", &locale.weather_forecast(Weather::Drought))]));
+                }
+                game.skip_year(player_id, logs);
+                Ok(())
+            }
+            GameEffect::AddPersistentEffect { effect_type, years, harvests } => {
+                game.players[player_id].add_persistent_effect_for_harvests(effect_type.clone(), *years, *harvests);
+                if let Some(card) = self.card {
+                    logs.push(locale.card_description_brief(card).to_string());
+                }
+                Ok(())
+            }
+            GameEffect::MachineryCoop { percent, years } => {
+                let discount = EffectType::ExpenseDiscount(*percent as f32 / 100.0);
+                game.players[player_id].add_persistent_effect(discount, *years);
+                logs.push(locale.tr("effect.joined_coop", &[("player", &player_name), ("percent", percent), ("years", years)]));
+                Ok(())
+            }
+            GameEffect::SlaughterCowsWithoutCompensation => {
+                let lost = game.players[player_id].assets.get(&AssetType::Cows).map_or(0, |r| r.quantity);
+                if lost > 0 {
+                    logs.push(locale.tr("effect.cows_lost", &[("player", &player_name), ("cows", &lost)]));
+                    game.apply_mutations(&[StateMutation::AssetDelta { player_id, asset: AssetType::Cows, quantity: -i32::try_from(lost).unwrap_or(i32::MAX) }])?;
+                    game.claim_insurance(player_id, Coverage::Livestock, lost, LIVESTOCK_CLAIM_PER_HEAD, logs);
+                } else {
+                    logs.push(locale.tr("effect.no_cows_lost", &[("player", &player_name)]));
+                }
+                Ok(())
+            }
+            GameEffect::SlaughterCowsWithCompensation => {
+                let lost = game.players[player_id].assets.get(&AssetType::Cows).map_or(0, |r| r.quantity);
+                if lost > 0 {
+                    // Paid for, so there is nothing to claim on insurance
+                    let price = game.cow_price(game.players[player_id].year);
+                    game.players[player_id].sell_asset(AssetType::Cows, lost, price);
+                    game.players[player_id].cash += price * lost;
+                    logs.push(locale.tr("effect.cows_compensated", &[("player", &player_name), ("cows", &lost),
+                        ("price", &price), ("total", &(price * lost))]));
+                } else {
+                    logs.push(locale.tr("effect.no_cows_lost", &[("player", &player_name)]));
+                }
+                Ok(())
+            }
+            GameEffect::PayInterest => {
+                let interest = game.players[player_id].interest_due(game.rules.interest_rate);
+                if interest > Money::ZERO {
+                    logs.push(locale.tr("effect.pay_interest", &[("player", &player_name), ("amount", &interest)]));
+                    game.debit(player_id, interest, LedgerCategory::Interest, logs)?;
+                } else {
+                    logs.push(locale.tr("effect.no_interest", &[("player", &player_name)]));
+                }
+                Ok(())
+            }
+            GameEffect::OneTimeHarvestMultiplier { asset, multiplier } => {
+                game.players[player_id]._set_one_time_harvest_multiplier(*asset, *multiplier);
+                logs.push(locale.tr("effect.one_time_multiplier", &[("player", &player_name),
+                    ("multiplier", &format!("{:.1}", multiplier)), ("asset", &locale.asset_name(asset))]));
+                // A livestock market swing moves the price of cows at sale too
+                if *asset == AssetType::Cows {
+                    let year = game.players[player_id].year;
+                    game.adjust_cow_price(year, *multiplier, logs);
+                }
+                Ok(())
+            }
+            GameEffect::DoubleYieldForCrop(asset) => {
+                game.players[player_id].set_crop_multiplier(*asset, 2.0);
+                logs.push(locale.tr("effect.yield_doubled", &[("player", &player_name), ("asset", &locale.asset_name(asset))]));
+                Ok(())
+            }
+            GameEffect::LeaseRidge { name, cost, cow_count } => {
+                logs.push(locale.tr("ridge.lease_option", &[("ridge", name), ("cost", cost), ("cows", cow_count)]));
+                Ok(())
+            }
+            GameEffect::OptionalBuyAsset { asset, quantity, cost } => {
+                // Lots of several units can be bought in part
+                let (kind, prompt) = if *quantity > 1 {
+                    (DecisionKind::Quantity { min: 0, max: i32::try_from(*quantity).unwrap_or(i32::MAX) },
+                     locale.tr("decision.buy_quantity", &[("player", &player_name), ("asset", &locale.asset_name(asset)),
+                        ("price", &(*cost / *quantity)), ("max", quantity)]))
+                } else {
+                    (DecisionKind::YesNo,
+                     locale.tr("decision.buy_yes_no", &[("player", &player_name), ("quantity", quantity),
+                        ("asset", &locale.asset_name(asset)), ("cost", cost)]))
+                };
+                game.pending_decisions.push_back(DecisionRequest {
+                    player_id, kind, prompt,
+                    pending: PendingEffect::OptionalBuy { asset: *asset, quantity: *quantity, cost: *cost },
+                });
+                Ok(())
+            }
+            GameEffect::MtStHelensDisaster => {
+                // First, give the card holder $500 per Hay acre
+                if let Some(acres) = game.players[player_id].assets.get(&AssetType::Hay).map(|record| record.quantity) {
+                    let bonus = acres * Money(500);
+                    game.apply_mutations(&[StateMutation::CashDelta { player_id, amount: bonus, category: LedgerCategory::Bonus }])?;
+                    logs.push(locale.tr("mt_st_helens.hay_bonus", &[("player", &player_name), ("amount", &bonus), ("acres", &acres)]));
+                }
+
+                // Then every other player rolls for the ash (see `roll_for_ash`)
+                let other_player_ids: Vec<usize> = game.players.ids().filter(|&id| id != player_id).collect();
+                for other_id in other_player_ids {
+                    let prompt = locale.tr("decision.ash_roll", &[("player", &game.players[other_id].name)]);
+                    game.pending_decisions.push_back(DecisionRequest {
+                        player_id: other_id, kind: DecisionKind::Roll, prompt, pending: PendingEffect::AshRoll,
+                    });
+                }
+                Ok(())
+            }
+            GameEffect::DrawCard(TileType::FarmerFate) => {
+                let card = game.farmer_fate_deck.draw_with(&mut game.rng).ok_or(GameError::DeckEmpty("Farmer's Fate"))?;
+                tracing::info!(player_id, deck = "farmer_fate", card_id = card.id, card = %card.title, "card drawn");
+                game.players[player_id].cards_drawn += 1;
+                logs.push(locale.tr("card.drew_farmers_fate", &[("card", &locale.card_title(&card))]));
+                if let Some(harvest) = card.hold_until.clone() {
+                    // Effect waits for the matching harvest (see process_harvest);
+                    // an unused card is returned to the discard pile at year end
+                    logs.push(locale.tr("card.held_until_harvest",
+                        &[("player", &player_name), ("card", &locale.card_title(&card)), ("harvest", &locale.harvest_name(&harvest))]));
+                    game.players[player_id].add_persistent_card(card, 1);
+                    Ok(())
+                } else {
+                    let result = game.apply_card_effect(player_id, &card, logs);
+                    // Played cards go back to the deck even if the effect failed
+                    game.farmer_fate_deck.discard(card);
+                    result
+                }
+            }
+            GameEffect::DrawCard(TileType::OptionToBuy) => {
+                let card = game.option_to_buy_deck.draw_with(&mut game.rng).ok_or(GameError::DeckEmpty("Option to Buy"))?;
+                tracing::info!(player_id, deck = "option_to_buy", card_id = card.id, card = %card.title, "card drawn");
+                game.players[player_id].cards_drawn += 1;
+                logs.push(locale.tr("card.drew_option_to_buy", &[("card", &locale.card_title(&card))]));
+                game.players[player_id].hand.push(card);
+                Ok(())
+            }
+            GameEffect::DrawCard(_) => Ok(()),
+            GameEffect::MoveToTile(tile_index) => {
+                let rules = effect.jump_rules().expect("moves have jump rules");
+                game.jump(player_id, *tile_index, Money::ZERO, rules, logs)?;
+                logs.push(locale.tr("effect.moved_to", &[("player", &player_name), ("tile", &locale.tile_name(&game.board[*tile_index]))]));
+                if rules.apply_destination {
+                    game.land_on(player_id, *tile_index, logs)?;
+                }
+                Ok(())
+            }
+            GameEffect::MoveToTileWithIncome { tile_index, amount } => {
+                let rules = effect.jump_rules().expect("moves have jump rules");
+                game.jump(player_id, *tile_index, *amount, rules, logs)?;
+                logs.push(locale.tr("effect.moved_to_and_gained", &[("player", &player_name), ("tile", &locale.tile_name(&game.board[*tile_index])), ("amount", amount)]));
+                if rules.apply_destination {
+                    game.land_on(player_id, *tile_index, logs)?;
+                }
+                Ok(())
+            }
+            GameEffect::MoveAndHarvestIfHas { asset, destination, bonus, harvest_type } => {
+                if !self.has_asset(asset) {
+                    logs.push(locale.tr("effect.cannot_move", &[("player", &player_name), ("asset", &locale.asset_name(asset))]));
+                    return Ok(());
+                }
+                let game = &mut *self.game;
+                let rules = effect.jump_rules().expect("moves have jump rules");
+                game.jump(player_id, *destination, *bonus, rules, logs)?;
+                logs.push(locale.tr("effect.moved_to", &[("player", &player_name), ("tile", &locale.tile_name(&game.board[*destination]))]));
+                if *bonus > Money::ZERO {
+                    logs.push(locale.tr("effect.gained_bonus", &[("player", &player_name), ("amount", bonus)]));
+                }
+                match game.process_harvest(player_id, harvest_type.clone()) {
+                    Ok(harvest_logs) => logs.extend(harvest_logs),
+                    Err(e) => logs.push(locale.tr("harvest.error", &[("error", &e)])),
+                }
+                if rules.apply_destination {
+                    game.land_on(player_id, *destination, logs)?;
+                }
+                Ok(())
+            }
+            GameEffect::Custom { key, params } => {
+                let effects = Arc::clone(&game.effects);
+                effects.apply(key, game, player_id, params, logs)
+            }
+        }
+    }
+
+    /// Finishes an effect that was waiting on the player's `decision`.
+    pub(crate) fn resume(&mut self, pending: &PendingEffect, decision: Decision, logs: &mut Vec<String>) -> Result<(), GameError> {
+        match pending {
+            PendingEffect::OptionalBuy { asset, quantity, cost } => {
+                let bought = match decision {
+                    Decision::Yes => *quantity,
+                    Decision::Quantity(n) => constraints::quantity(n)?,
+                    _ => 0,
+                };
+                self.buy_optional(*asset, bought, *cost * bought / (*quantity).max(1), logs)
+            }
+            PendingEffect::AshRoll => self.roll_for_ash(logs),
+            PendingEffect::AshCleanup { cost, acres, .. } => {
+                let cows_sold = match decision {
+                    Decision::Quantity(n) => constraints::quantity(n)?,
+                    _ => 0,
+                };
+                self.pay_ash_cleanup(*cost, *acres, cows_sold, logs)
+            }
+            // Whatever the player sold toward the bill was sold as they answered
+            PendingEffect::ForcedLoan { amount, category } => self.game.pay_expense(self.player_id, *category, *amount, logs),
+            PendingEffect::CustomWorkQuote { job, contractors } => {
+                let price = match decision {
+                    Decision::Price(price) => price,
+                    _ => Money::ZERO,
+                };
+                self.game.quote_custom_work(job.clone(), self.player_id, price, contractors.clone(), logs)
+            }
+            PendingEffect::CustomWorkOffer { job, contractor, price, contractors } => {
+                self.game.settle_custom_work(job.clone(), *contractor, *price, decision == Decision::Yes, contractors.clone(), logs)
+            }
+            PendingEffect::StockRidge { ridge } => match decision {
+                Decision::Quantity(cows) => self.game.stock_ridge(self.player_id, ridge, cows, logs),
+                _ => Ok(()),
+            },
+            // Each discard is made as the player answers
+            PendingEffect::DiscardDown => Ok(()),
+        }
+    }
+
+    /// Buys `quantity` of an optional lot, borrowing any shortfall.
+    fn buy_optional(&mut self, asset: AssetType, quantity: u32, cost: Money, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player_id = self.player_id;
+        let game = &mut *self.game;
+        let locale = Arc::clone(&game.locale);
+        let player_name = game.players[player_id].name.clone();
+        if quantity == 0 {
+            logs.push(locale.tr("buy.declined", &[("player", &player_name), ("asset", &locale.asset_name(&asset))]));
+            return Ok(());
+        }
+
+        game.check_farm_limit(player_id, asset, quantity)?;
+
+        let remaining_capacity = game.rules.remaining_credit(game.players[player_id].debt);
+        let player = &mut game.players[player_id];
+        let required_loan = (cost - player.cash).max(Money::ZERO);
+        if required_loan > remaining_capacity {
+            return Err(GameError::DebtLimitExceeded { required: required_loan, available: remaining_capacity });
+        }
+        player.borrow(required_loan, game.rules.interest_rate);
+        player.cash += required_loan;
+        if required_loan.is_positive() {
+            let debt = player.debt;
+            game.emit(GameEvent::LoanTaken { player_id, amount: required_loan, debt });
+        }
+        game.debit(player_id, cost, LedgerCategory::Purchase, logs)?;
+        game.players[player_id].acquire_asset(asset, quantity, cost);
+        if required_loan.is_positive() {
+            logs.push(locale.tr("buy.paid_with_loan", &[("player", &player_name), ("loan", &required_loan),
+                ("cost", &cost), ("quantity", &quantity), ("asset", &locale.asset_name(&asset))]));
+        } else {
+            logs.push(locale.tr("buy.paid", &[("player", &player_name), ("cost", &cost),
+                ("quantity", &quantity), ("asset", &locale.asset_name(&asset))]));
+        }
+        Ok(())
+    }
+
+    /// Mt. St. Helens: odd escapes the ash, even pays $100 per crop acre to clean up.
+    /// A player who can't cover the cleanup from cash is asked how many cows to
+    /// sell toward it before borrowing the rest.
+    fn roll_for_ash(&mut self, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player_id = self.player_id;
+        let game = &mut *self.game;
+        let locale = Arc::clone(&game.locale);
+        let roll = Dice::ONE.roll(&mut game.rng);
+        let player = &game.players[player_id];
+        let escaped = roll.is_odd();
+        let roll = roll.total();
+        if escaped {
+            logs.push(locale.tr("mt_st_helens.escaped", &[("player", &player.name), ("roll", &roll)]));
+        } else {
+            logs.push(locale.tr("mt_st_helens.hit", &[("player", &player.name), ("roll", &roll)]));
+
+            let total_acres: u32 = player.assets.iter()
+                .filter(|(asset_type, _)| matches!(asset_type, AssetType::Hay | AssetType::Grain | AssetType::Fruit))
+                .map(|(_, record)| record.quantity)
+                .sum();
+            if total_acres > 0 {
+                let cleanup_cost = total_acres * ASH_CLEANUP_PER_ACRE;
+                logs.push(locale.tr("mt_st_helens.cleanup", &[("player", &player.name), ("amount", &cleanup_cost), ("rate", &ASH_CLEANUP_PER_ACRE)]));
+                let shortfall = cleanup_cost - player.cash;
+                let cows = player.assets.get(&AssetType::Cows).map_or(0, |r| r.quantity);
+                if shortfall.is_positive() && cows > 0 {
+                    let cow_price = game.cow_price(player.year);
+                    // Never more cows than it takes to cover what cash doesn't
+                    let max = shortfall.units_at(cow_price).min(cows.into()) as i32;
+                    let prompt = locale.tr("decision.ash_cleanup", &[("player", &player.name), ("amount", &cleanup_cost),
+                        ("cash", &player.cash), ("price", &cow_price), ("max", &max)]);
+                    // Answered before anyone else rolls for the ash
+                    game.pending_decisions.push_front(DecisionRequest {
+                        player_id, kind: DecisionKind::Quantity { min: 0, max }, prompt,
+                        pending: PendingEffect::AshCleanup { cost: cleanup_cost, acres: total_acres, cow_price },
+                    });
+                    return Ok(());
+                }
+                return self.pay_ash_cleanup(cleanup_cost, total_acres, 0, logs);
+            } else {
+                logs.push(locale.tr("mt_st_helens.no_acres", &[("player", &player.name)]));
+            }
+        }
+        let cows = game.players[player_id].assets.get(&AssetType::Cows).map_or(0, |r| r.quantity);
+        game.emit(GameEvent::AshRolled { player_id, escaped, cows });
+        Ok(())
+    }
+
+    /// Pays for cleaning up the ash after selling `cows_sold` cows toward it,
+    /// borrowing whatever cash doesn't cover.
+    fn pay_ash_cleanup(&mut self, cost: Money, acres: u32, cows_sold: u32, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player_id = self.player_id;
+        let game = &mut *self.game;
+        if cows_sold > 0 {
+            logs.push(game.sell_cows(player_id, cows_sold)?);
+        }
+        game.pay_expense(player_id, LedgerCategory::CardExpense, cost, logs)?;
+        game.claim_insurance(player_id, Coverage::Crop, acres, ASH_CLEANUP_PER_ACRE, logs);
+        let cows = game.players[player_id].assets.get(&AssetType::Cows).map_or(0, |r| r.quantity);
+        game.emit(GameEvent::AshRolled { player_id, escaped: false, cows });
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_support::card_with;
+    use crate::config::GameRules;
+    use crate::game::{EffectResolver, GameEffect, GameError};
+    use crate::models::{AssetType, BoardTile, GameState, HarvestType, Money, Month, Player, PlayerRegistry, PlayerType, TileEffect, TileType};
+
+    fn setup_game(cash: Money) -> GameState {
+        let mut players = PlayerRegistry::new();
+        let mut player = Player::new(0, "Farmer".to_string(), PlayerType::Human);
+        player.cash = cash;
+        players.insert(player);
+        GameState::new_with_players(players, vec![0], GameRules::default())
+    }
+
+    fn tile_with(effect: TileEffect) -> BoardTile {
+        BoardTile {
+            index: 0,
+            name: "Test Tile".to_string(),
+            month: Month::January,
+            week: 1,
+            tile_type: TileType::Blank,
+            harvest_type: HarvestType::None,
+            effect,
+            description: None,
+            description_brief: None,
+        }
+    }
+
+
+    #[test]
+    fn test_tile_and_card_resolve_the_same_effect() {
+        let mut from_tile = setup_game(Money(1000));
+        let mut from_card = setup_game(Money(1000));
+        let (mut tile_logs, mut card_logs) = (Vec::new(), Vec::new());
+
+        from_tile.handle_tile_event(0, &tile_with(TileEffect::GainCashIfAsset { asset: AssetType::Hay, amount: Money(700) }), &mut tile_logs).unwrap();
+        from_card.apply_card_effect(0, &card_with(1, GameEffect::IncomeIfHas { asset: AssetType::Hay, amount: Money(700) }), &mut card_logs).unwrap();
+
+        assert_eq!(from_tile.players[0].cash, Money(1700));
+        assert_eq!(from_card.players[0].cash, Money(1700));
+        assert_eq!(tile_logs, card_logs);
+    }
+
+    #[test]
+    fn test_tile_effects_convert_to_game_effects() {
+        assert!(matches!(GameEffect::from(&TileEffect::GoToTile(4)), GameEffect::MoveToTile(4)));
+        assert!(matches!(GameEffect::from(&TileEffect::PayCashIfAsset { asset: AssetType::Cows, amount: Money(500) }),
+            GameEffect::ExpenseIfHas { asset: AssetType::Cows, amount: Money(500) }));
+        assert!(matches!(GameEffect::from(&TileEffect::HarvestBonusPerAcre { asset: AssetType::Grain, bonus: Money(50) }),
+            GameEffect::IncomePerAsset { asset: AssetType::Grain, rate: Money(50) }));
+        assert!(matches!(GameEffect::from(&TileEffect::None), GameEffect::None));
+    }
+
+    #[test]
+    fn test_asset_conditions_ignore_sold_out_assets() {
+        let mut game = setup_game(Money(1000));
+        game.players.get_mut(0).unwrap().add_asset(AssetType::Tractor, 1, Money::ZERO);
+        game.players.get_mut(0).unwrap().assets.get_mut(&AssetType::Tractor).unwrap().quantity = 0;
+        let mut logs = Vec::new();
+
+        EffectResolver::new(&mut game, 0)
+            .resolve(&GameEffect::IncomeIfHas { asset: AssetType::Tractor, amount: Money(500) }, &mut logs)
+            .unwrap();
+        EffectResolver::new(&mut game, 0)
+            .resolve(&GameEffect::ExpenseIfHas { asset: AssetType::Tractor, amount: Money(500) }, &mut logs)
+            .unwrap();
+
+        assert_eq!(game.players[0].cash, Money(1000));
+    }
+
+    #[test]
+    fn test_silo_spares_grain_from_price_cuts() {
+        let rain_sprouts_wheat = GameEffect::from(&TileEffect::HarvestBonusPerAcre { asset: AssetType::Grain, bonus: Money(-50) });
+        // Every farm starts with 10 acres of Grain
+        let mut game = setup_game(Money(1000));
+        EffectResolver::new(&mut game, 0).resolve(&rain_sprouts_wheat, &mut Vec::new()).unwrap();
+        assert_eq!(game.players[0].cash, Money(500));
+
+        game.players.get_mut(0).unwrap().add_asset(AssetType::Silo, 1, Money(6000));
+        let mut logs = Vec::new();
+        EffectResolver::new(&mut game, 0).resolve(&rain_sprouts_wheat, &mut logs).unwrap();
+        assert_eq!(game.players[0].cash, Money(500));
+        assert!(logs.iter().any(|l| l.contains("safe in the Silo")));
+    }
+
+    #[test]
+    fn test_farm_has_one_of_each_upgrade() {
+        let mut game = setup_game(Money(20000));
+        let buy_barn = GameEffect::BuyAsset { asset: AssetType::Barn, quantity: 1, cost: Money(8000) };
+        EffectResolver::new(&mut game, 0).resolve(&buy_barn, &mut Vec::new()).unwrap();
+
+        let second = EffectResolver::new(&mut game, 0).resolve(&buy_barn, &mut Vec::new());
+        assert!(matches!(second, Err(GameError::AssetLimitExceeded { asset: AssetType::Barn, limit: 1, .. })));
+        assert_eq!(game.players[0].cash, Money(12000));
+        assert_eq!(game.players[0].assets[&AssetType::Barn].quantity, 1);
+    }
+}
@@ -0,0 +1,274 @@
+// src/game/engine.rs
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::config::GameRules;
+use crate::game::{constraints, game_loop, Decision, Dice, GameBuilder, GameError, GameStateBuilder, GameEvent, GameObserver, GameRecord, GameSetup, Scenario};
+use crate::game::autosnapshot;
+use crate::game::observer::Observers;
+use crate::models::{GameState, Money, PlayerColor, PlayerType};
+
+/// Something a player can do on their turn, independent of any UI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Roll the game's die and move
+    Roll,
+    /// Move by a roll chosen by the caller
+    Move(u32),
+    /// Exercise an Option to Buy card, borrowing the shortfall if `confirm_loan` is set
+    ExerciseOption { card_id: usize, confirm_loan: bool },
+    /// Pay down debt with cash, oldest notes first
+    PayLoan(Money),
+    /// Pay cash off one note
+    PayNote { note: usize, amount: Money },
+    /// Sell farm cows at this year's market price
+    SellCows(i32),
+    /// Move farm cows up to a ridge the player leases, or its cows back
+    /// down to the farm when `cows` is negative
+    MoveCows { ridge: String, cows: i32 },
+    /// Pass the turn to the next player
+    EndTurn,
+    /// Answer the pending decision; the only action allowed while one is pending
+    Decide(Decision),
+}
+
+/// A single running game: owns the state and drives turns without any UI.
+#[derive(Debug, Clone)]
+pub struct GameEngine {
+    state: GameState,
+    /// Actions applied so far, kept for seeded games so they can be replayed
+    record: Option<GameRecord>,
+    observers: Observers,
+    /// The game as the current turn began, while turns are being audited
+    /// (see `game::autosnapshot`)
+    autosnapshot: Option<GameState>,
+}
+
+impl GameEngine {
+    /// Starts configuring a new game; see `GameBuilder`.
+    pub fn builder() -> GameBuilder {
+        GameBuilder::default()
+    }
+
+    /// Wraps an existing game state (e.g. one built by the TUI setup).
+    pub fn new(state: GameState) -> Self {
+        Self::from_parts(state, None)
+    }
+
+    /// Starts a new game with one human player per name, in the given turn order,
+    /// and deals the opening Option to Buy cards (see `GameRules::opening_otb_cards`).
+    pub fn with_players(names: &[String], rules: GameRules) -> Result<Self, GameError> {
+        Self::start(names, rules, None)
+    }
+
+    /// Like `with_players`, but every shuffle, deal and roll is reproducible from `seed`.
+    pub fn with_seed(names: &[String], rules: GameRules, seed: u64) -> Result<Self, GameError> {
+        Self::start(names, rules, Some(seed))
+    }
+
+    /// Starts a scenario game: the scenario's rules, starting position and win condition.
+    pub fn with_scenario(names: &[String], scenario: &Scenario, seed: Option<u64>) -> Result<Self, GameError> {
+        let mut engine = Self::start(names, scenario.rules.clone(), seed)?;
+        scenario.apply(&mut engine.state);
+        // Records don't carry the scenario, so a replay couldn't rebuild this game
+        engine.record = None;
+        Ok(engine)
+    }
+
+    fn start(names: &[String], rules: GameRules, seed: Option<u64>) -> Result<Self, GameError> {
+        let mut builder = names.iter().fold(GameStateBuilder::default().rules(rules.clone()), |builder, name| builder.add_human(name.as_str()));
+        if let Some(seed) = seed {
+            builder = builder.seed(seed);
+        }
+        let mut state = builder.build()?;
+        state.deal_opening_hands()?;
+        let record = seed.map(|seed| GameRecord::new(seed, names, rules));
+        Ok(Self::from_parts(state, record))
+    }
+
+    fn from_parts(mut state: GameState, record: Option<GameRecord>) -> Self {
+        let mut observers = Observers::default();
+        // Takes note of everyone's starting cash
+        observers.notify(&mut state);
+        Self { state, record, observers, autosnapshot: None }
+    }
+
+    /// Calls `observer` with every event from now on, after the action that
+    /// caused it. Cash changes are reported as `GameEvent::CashChanged`.
+    pub fn observe(&mut self, observer: impl GameObserver + 'static) {
+        self.observers.add(Box::new(observer), self.state.events.len());
+    }
+
+    /// Turns auditing every turn on or off (see `game::autosnapshot`). A turn
+    /// that fails its audit is logged and, in debug builds, panics.
+    pub fn set_autosnapshots(&mut self, on: bool) {
+        self.autosnapshot = on.then(|| self.state.fork());
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut GameState {
+        &mut self.state
+    }
+
+    pub fn into_state(self) -> GameState {
+        self.state
+    }
+
+    /// The game so far as a replayable record; `None` unless the game was
+    /// started from a seed.
+    pub fn record(&self) -> Option<&GameRecord> {
+        self.record.as_ref()
+    }
+
+    /// Sets who plays `player_id` and their token color, in the record too.
+    pub fn seat(&mut self, player_id: usize, player_type: PlayerType, color: PlayerColor) {
+        if let Some(recorded) = self.record.as_mut().and_then(|record| record.players.get_mut(player_id)) {
+            recorded.ai = match &player_type {
+                PlayerType::AI(strategy) => Some(strategy.clone()),
+                PlayerType::Human => None,
+            };
+            recorded.color = Some(color);
+        }
+        let player = &mut self.state.players[player_id];
+        player.player_type = player_type;
+        player.color = color;
+    }
+
+    /// How the game began, for rebuilding it from its events; `None` unless
+    /// the game was started from a seed.
+    pub fn setup(&self) -> Option<GameSetup> {
+        self.record.as_ref().map(GameRecord::setup)
+    }
+
+    /// Everything that has happened so far, oldest first.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.state.events
+    }
+
+    pub fn current_player_id(&self) -> usize {
+        self.state.turn_order[self.state.current_turn_index]
+    }
+
+    /// Moves the current player by `roll` and resolves the tile. The turn stays
+    /// with them (so they can still exercise options) until `end_turn` is called.
+    pub fn play_turn(&mut self, roll: u32) -> Result<Vec<String>, GameError> {
+        let player_id = self.current_player_id();
+        let logs = game_loop::handle_player_turn(&mut self.state, player_id, roll);
+        self.observers.notify(&mut self.state);
+        logs
+    }
+
+    /// Rolls a die for the current player and plays their turn.
+    pub fn roll_and_play<R: Rng>(&mut self, rng: &mut R) -> Result<(u32, Vec<String>), GameError> {
+        let roll = Dice::ONE.roll(rng).total();
+        let logs = self.play_turn(roll)?;
+        Ok((roll, logs))
+    }
+
+    /// Rolls with the game's own RNG, so seeded games replay identically.
+    pub fn roll(&mut self) -> Result<(u32, Vec<String>), GameError> {
+        let roll = Dice::ONE.roll(&mut self.state.rng).total();
+        let logs = self.play_turn(roll)?;
+        Ok((roll, logs))
+    }
+
+    /// Applies an action for the current player and returns its log lines.
+    /// Decisions are answered by whichever player was asked, which may not be
+    /// the current player. Seeded games record every action that applies.
+    pub fn apply(&mut self, action: Action) -> Result<Vec<String>, GameError> {
+        let player_id = self.state.pending_decision().map_or_else(|| self.current_player_id(), |request| request.player_id);
+        let logs = self.apply_unrecorded(action.clone());
+        // `end_turn` logs itself, like it records itself
+        if logs.is_ok() && action != Action::EndTurn {
+            self.state.emit(GameEvent::ActionApplied { player_id, action: action.clone() });
+        }
+        self.observers.notify(&mut self.state);
+        let logs = logs?;
+        // `end_turn` records itself, since callers often end turns directly
+        if let Some(record) = self.record.as_mut().filter(|_| action != Action::EndTurn) {
+            record.actions.push(action);
+        }
+        Ok(logs)
+    }
+
+    fn apply_unrecorded(&mut self, action: Action) -> Result<Vec<String>, GameError> {
+        let player_id = self.current_player_id();
+        if self.state.pending_decision().is_some() && !matches!(action, Action::Decide(_)) {
+            return Err(GameError::DecisionPending);
+        }
+        if !self.state.players[player_id].is_active && !matches!(action, Action::Decide(_) | Action::EndTurn) {
+            return Err(GameError::PlayerEliminated(player_id));
+        }
+        match action {
+            Action::Roll => {
+                let (roll, logs) = self.roll()?;
+                tracing::debug!(player_id, roll, "action: roll");
+                Ok(logs)
+            }
+            Action::Move(roll) => self.play_turn(roll),
+            Action::ExerciseOption { card_id, confirm_loan } => {
+                self.state.exercise_option_to_buy(player_id, card_id, confirm_loan)?;
+                Ok(vec![self.state.locale.tr("card.exercised_option", &[("card_id", &card_id)])])
+            }
+            Action::PayLoan(amount) => self.state.repay_debt(player_id, amount).map(|log| vec![log]),
+            Action::PayNote { note, amount } => self.state.pay_note(player_id, Some(note), amount).map(|log| vec![log]),
+            Action::SellCows(quantity) => self.state.sell_cows(player_id, constraints::quantity(quantity)?).map(|log| vec![log]),
+            Action::MoveCows { ref ridge, cows } => self.state.move_cows(player_id, ridge, cows).map(|log| vec![log]),
+            Action::EndTurn => {
+                self.end_turn();
+                Ok(Vec::new())
+            }
+            Action::Decide(decision) => {
+                let asked = self.state.pending_decision().ok_or(GameError::NoDecisionPending)?.player_id;
+                let mut logs = Vec::new();
+                self.state.answer_decision(asked, decision, &mut logs)?;
+                Ok(logs)
+            }
+        }
+    }
+
+    /// Passes the turn to the next player still in, recording a win if they just made it.
+    /// A player over the hand limit is asked to discard instead, and the turn
+    /// ends on the next call once they're down to it.
+    pub fn end_turn(&mut self) {
+        if let Some(record) = self.record.as_mut() {
+            record.actions.push(Action::EndTurn);
+        }
+        let player_id = self.current_player_id();
+        if !self.state.request_discards(player_id) {
+            self.state.settle_win(player_id, &mut Vec::new());
+            self.state.record_outcome(player_id);
+            self.state.snapshot_net_worths();
+            self.state.advance_turn();
+            self.state.update_phase();
+            self.audit_turn();
+        }
+        self.state.emit(GameEvent::ActionApplied { player_id, action: Action::EndTurn });
+        self.observers.notify(&mut self.state);
+    }
+
+    /// Checks the turn just ended against its snapshot and snapshots the next one.
+    fn audit_turn(&mut self) {
+        let Some(snapshot) = self.autosnapshot.as_mut() else { return };
+        let snapshot = std::mem::replace(snapshot, self.state.fork());
+        let mismatches = autosnapshot::audit_turn(&snapshot, &self.state);
+        for mismatch in &mismatches {
+            tracing::error!(%mismatch, "turn failed its audit");
+        }
+        debug_assert!(mismatches.is_empty(), "turn failed its audit: {:?}", mismatches);
+    }
+
+    /// Player who has won: the winning net worth, or the scenario's goals
+    /// when playing one, with shared wins settled (see `game::victory`).
+    pub fn winner(&self) -> Option<usize> {
+        self.state.winner()
+    }
+
+    /// Every move `player_id` can make right now (see `game::legal_actions`).
+    pub fn legal_actions(&self, player_id: usize) -> Vec<Action> {
+        self.state.legal_actions(player_id)
+    }
+}
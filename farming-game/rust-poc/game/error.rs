@@ -0,0 +1,107 @@
+// src/game/error.rs
+
+use thiserror::Error;
+use crate::models::{AssetType, Money, TileType};
+
+/// Errors returned by the game engine's fallible operations.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum GameError {
+    #[error("Player {0} not found")]
+    PlayerNotFound(usize),
+
+    #[error("{0} deck is empty")]
+    DeckEmpty(&'static str),
+
+    #[error("No deck to draw from for {0:?} tiles")]
+    NoDeckForTile(TileType),
+
+    #[error("Insufficient funds. Required: {required}, Available: {available}")]
+    InsufficientFunds { required: Money, available: Money },
+
+    #[error("Insufficient funds (Max Additional Loan: {available}, Required: {required})")]
+    DebtLimitExceeded { required: Money, available: Money },
+
+    #[error("No debt to pay")]
+    NoDebt,
+
+    #[error("No note #{0} outstanding")]
+    NoteNotFound(usize),
+
+    #[error("Payments are made in multiples of {increment} (or the full {owed} owed), not {amount}")]
+    InvalidPayment { amount: Money, increment: Money, owed: Money },
+
+    #[error("Can't pay {amount}; only {owed} is owed")]
+    Overpayment { amount: Money, owed: Money },
+
+    #[error("Loan confirmation required")]
+    LoanConfirmationRequired,
+
+    #[error("Invalid card: {0}")]
+    InvalidCard(String),
+
+    #[error("Card ID {card_id} not found in player {player_id}'s hand")]
+    CardNotFound { player_id: usize, card_id: usize },
+
+    #[error("Cannot buy {requested} {asset:?}. Would exceed farm limit of {limit} (Current: {current}).")]
+    AssetLimitExceeded { asset: AssetType, requested: u32, current: u32, limit: u32 },
+
+    #[error("Cannot sell {requested} {asset:?}. Only {available} owned.")]
+    InsufficientAssets { asset: AssetType, requested: u32, available: u32 },
+
+    #[error("Invalid quantity {0}; quantities can't be negative")]
+    InvalidQuantity(i32),
+
+    #[error("The bank doesn't buy back {0:?}")]
+    NotResellable(AssetType),
+
+    #[error("Cannot buy {requested} acres. Would exceed the farm's {land} acres of land (Current: {acres}).")]
+    LandLimitExceeded { requested: u32, acres: u32, land: i32 },
+
+    #[error("Ridge '{0}' not found")]
+    RidgeNotFound(String),
+
+    #[error("{0} is already leased")]
+    RidgeAlreadyLeased(String),
+
+    #[error("Cannot stock ridge: {0}")]
+    RidgeStocking(String),
+
+    #[error("Invalid bid: {0}")]
+    InvalidBid(String),
+
+    #[error("Waiting on a player decision")]
+    DecisionPending,
+
+    #[error("No decision is pending")]
+    NoDecisionPending,
+
+    #[error("Invalid decision: {0}")]
+    InvalidDecision(String),
+
+    #[error("Insurance unavailable: {0}")]
+    InsuranceUnavailable(String),
+
+    #[error("Invalid board position {0}")]
+    InvalidPosition(usize),
+
+    #[error("No effect registered as '{0}'")]
+    UnknownEffect(String),
+
+    #[error("Effect parameter '{param}' {reason}")]
+    InvalidEffectParams { param: String, reason: String },
+
+    #[error("Player {0} is out of the game")]
+    PlayerEliminated(usize),
+
+    #[error("A game needs at least one player")]
+    NoPlayers,
+
+    #[error("Turn order {0:?} must name every player once")]
+    InvalidTurnOrder(Vec<usize>),
+
+    #[error("Unsupported asset type for harvest calculation: {0:?}")]
+    UnsupportedHarvestAsset(AssetType),
+
+    #[error("Event {0} doesn't match the rebuilt game; the log is from another game or version")]
+    EventsDiverged(usize),
+}
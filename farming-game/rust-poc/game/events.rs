@@ -0,0 +1,111 @@
+// src/game/events.rs
+// Structured record of what happened in a game. Log lines are for people;
+// events are for code that reacts to play (achievements, statistics)
+// without parsing log text. Every action the engine applies is logged too,
+// so a seeded game can be rebuilt from its events (see `GameState::from_events`).
+
+use serde::{Deserialize, Serialize};
+use crate::game::{Action, EffectCategory, PlayerOutcome};
+use crate::models::{GameState, HarvestType, Money};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The engine applied `action` for `player_id` (the player asked, for a
+    /// decision), after the events it caused
+    ActionApplied { player_id: usize, action: Action },
+    /// A player rolled to move
+    DiceRolled { player_id: usize, roll: u32 },
+    /// A player rolled for turn order before the game began (see `game::turn_order`)
+    OpeningRolled { player_id: usize, roll: u32, tiebreak: bool },
+    /// A player brought in a harvest; `income` is before the operating cost card
+    HarvestCollected { player_id: usize, harvest: HarvestType, income: Money },
+    /// A card's effect was applied to a player
+    CardPlayed { player_id: usize, card: String, category: EffectCategory },
+    /// A played card's effect finished, having moved the player's cash by `cash_change`
+    CardResolved { player_id: usize, card: String, cash_change: Money },
+    /// A player landed on `tile`; `cash_change` covers everything it set off,
+    /// from harvests to cards drawn and tiles jumped to
+    TileResolved { player_id: usize, tile: usize, cash_change: Money },
+    /// A player finished a year, by passing Go or skipping the rest of it.
+    /// Cash and debt are as they stood before the next year began.
+    YearEnded { player_id: usize, year: u32, cash: Money, debt: Money },
+    RidgeLeased { player_id: usize, ridge: String },
+    /// A player took out a new note for `amount`; `debt` is their total afterwards
+    LoanTaken { player_id: usize, amount: Money, debt: Money },
+    /// A player paid cash toward their debt; `note` is the note they named, if any
+    DebtRepaid { player_id: usize, amount: Money, note: Option<usize>, debt: Money },
+    /// A player lost `cows` from a ridge herd to coyotes or a winter they
+    /// couldn't buy feed for (see `game::ridge_dangers`)
+    RidgeCowsLost { player_id: usize, ridge: String, cows: u32 },
+    /// A player rolled for the Mt. St. Helens ash; `cows` is their farm herd afterwards
+    AshRolled { player_id: usize, escaped: bool, cows: u32 },
+    /// A player met the win condition (recorded once per player)
+    GameWon { player_id: usize, year: u32 },
+    /// A player rolled in a dice-off for a win shared at the same net worth
+    TieBreakRolled { player_id: usize, roll: u32 },
+    /// A win several `contenders` shared in the same year went to `player_id`
+    /// (see `game::victory`)
+    TieBroken { player_id: usize, contenders: Vec<usize> },
+    /// A player couldn't pay and is out of the game (see `game::bankruptcy`)
+    PlayerEliminated { player_id: usize },
+    /// A player's turn ended, in game year `year`, with this cash and debt
+    TurnEnded { player_id: usize, year: u32, cash: Money, debt: Money },
+    /// The turn passed to a player
+    TurnStarted { player_id: usize },
+    /// A player's cash moved by `change` over an engine action, to `cash`
+    /// (see `game::observer`)
+    CashChanged { player_id: usize, cash: Money, change: Money },
+}
+
+impl GameEvent {
+    pub fn player_id(&self) -> usize {
+        match self {
+            GameEvent::ActionApplied { player_id, .. }
+            | GameEvent::DiceRolled { player_id, .. }
+            | GameEvent::OpeningRolled { player_id, .. }
+            | GameEvent::HarvestCollected { player_id, .. }
+            | GameEvent::CardPlayed { player_id, .. }
+            | GameEvent::CardResolved { player_id, .. }
+            | GameEvent::TileResolved { player_id, .. }
+            | GameEvent::YearEnded { player_id, .. }
+            | GameEvent::RidgeLeased { player_id, .. }
+            | GameEvent::LoanTaken { player_id, .. }
+            | GameEvent::DebtRepaid { player_id, .. }
+            | GameEvent::RidgeCowsLost { player_id, .. }
+            | GameEvent::AshRolled { player_id, .. }
+            | GameEvent::GameWon { player_id, .. }
+            | GameEvent::TieBreakRolled { player_id, .. }
+            | GameEvent::TieBroken { player_id, .. }
+            | GameEvent::PlayerEliminated { player_id }
+            | GameEvent::TurnEnded { player_id, .. }
+            | GameEvent::TurnStarted { player_id }
+            | GameEvent::CashChanged { player_id, .. } => *player_id,
+        }
+    }
+}
+
+impl GameState {
+    /// Appends an event to the game's event stream.
+    pub fn emit(&mut self, event: GameEvent) {
+        tracing::debug!(?event, "game event");
+        self.events.push(event);
+    }
+
+    /// Events recorded since `cursor`, for observers that keep their place
+    /// in the stream between calls.
+    pub fn events_since(&self, cursor: usize) -> &[GameEvent] {
+        self.events.get(cursor..).unwrap_or_default()
+    }
+
+    /// Checks the player's outcome at the end of their turn and records the
+    /// first time they win.
+    pub fn record_outcome(&mut self, player_id: usize) -> PlayerOutcome {
+        let outcome = self.player_outcome(player_id);
+        let already_won = self.events.iter().any(|event| matches!(event, GameEvent::GameWon { player_id: id, .. } if *id == player_id));
+        if outcome == PlayerOutcome::Won && !already_won {
+            let year = self.players[player_id].year;
+            self.emit(GameEvent::GameWon { player_id, year });
+        }
+        outcome
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::GameEvent;
+    use crate::models::{GameState, Player, PlayerRegistry, PlayerType};
+
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Ann".to_string(), PlayerType::Human));
+        GameState::new_with_players(players, vec![0], GameRules::default())
+    }
+
+    #[test]
+    fn test_skipping_a_year_ends_it() {
+        let mut game = setup_game();
+        let (cash, debt) = (game.players[0].cash, game.players[0].debt);
+        game.skip_year(0, &mut Vec::new());
+        assert_eq!(game.events, vec![GameEvent::YearEnded { player_id: 0, year: 1, cash, debt }]);
+        assert!(game.events_since(1).is_empty());
+    }
+
+    #[test]
+    fn test_win_is_recorded_once() {
+        let mut game = setup_game();
+        game.players[0].net_worth = game.rules.winning_net_worth;
+        game.record_outcome(0);
+        game.record_outcome(0);
+        assert_eq!(game.events, vec![GameEvent::GameWon { player_id: 0, year: 1 }]);
+    }
+}
@@ -1,77 +1,71 @@
 // src/game/game_loop.rs
 
+use std::sync::Arc;
 use crate::models::GameState;
+use crate::game::{GameError, GameEvent};
 
 // Change function signature to return logs or an error string
-pub fn handle_player_turn(game: &mut GameState, player_id: usize, roll: u32) -> Result<Vec<String>, String> {
+pub fn handle_player_turn(game: &mut GameState, player_id: usize, roll: u32) -> Result<Vec<String>, GameError> {
     let mut turn_logs: Vec<String> = Vec::new();
 
     // --- 1. Get immutable info ---
-    let _player_name = game.players.get(&player_id)
-        .ok_or_else(|| format!("Player with ID {} not found.", player_id))?
+    let _player_name = game.players.get(player_id)
+        .ok_or(GameError::PlayerNotFound(player_id))?
         .name.clone();
-    let old_position = game.players.get(&player_id)
-        .ok_or_else(|| format!("Invalid player ID: {}", player_id))?
+    let old_position = game.players.get(player_id)
+        .ok_or(GameError::PlayerNotFound(player_id))?
         .position;
     let board_len = game.board.len(); 
     let total_pos = old_position + roll as usize;
     let new_position = total_pos % board_len;
-    let current_tile = game.board.get(new_position)
-        .ok_or_else(|| format!("Invalid board position: {}", new_position))?
-        .clone();
+    // Share the board rather than copying the tile, since handling it needs `game` mutably
+    let board = Arc::clone(&game.board);
+    let current_tile = board.get(new_position)
+        .ok_or(GameError::InvalidPosition(new_position))?;
+    game.emit(GameEvent::DiceRolled { player_id, roll });
 
     // --- 2. Handle Passing Go and Move Player ---
     {
-        let player = game.players.get_mut(&player_id)
-             .ok_or_else(|| format!("Invalid player ID: {}", player_id))?;
+        let player = game.players.get_mut(player_id)
+             .ok_or(GameError::PlayerNotFound(player_id))?;
 
         // Increment turns taken
         player.turns_taken += 1;
         
-        if old_position + roll as usize >= board_len {
-            turn_logs.push(format!("{} passed Go (Tile 0)!", player.name));
-
-            player.year += 1;
-            turn_logs.push(format!("Year advanced to {}.", player.year));
-
-            if player.eligible_for_side_job_pay {
-                player.cash += 5000;
-                turn_logs.push(format!("Collected $5000 side job pay. Cash: ${}", player.cash));
-            } else {
-                turn_logs.push("Did not collect side job pay (ineligible this year).".to_string());
-            }
-            player.eligible_for_side_job_pay = true;
-            player.reset_crop_multipliers();
-        }
+        tracing::info!(player_id, roll, from = old_position, to = new_position, tile = %current_tile.name, "player moved");
+    }
 
-        // Move player
-        player.position = new_position;
-    } 
+    if old_position + roll as usize >= board_len {
+        game.pass_start(player_id, &mut turn_logs);
+    }
+    // Move player
+    game.players[player_id].position = new_position;
 
     // --- 3. Handle Tile Effects & Harvest ---
-    turn_logs.push(format!("Rolled a {} - landed on {}", 
-        roll, 
-        current_tile.name.as_str()
-    ));
+    turn_logs.push(game.locale.tr("turn.landed", &[("roll", &roll), ("tile", &game.locale.tile_name(current_tile))]));
     
     // Only show tile description if it's meaningful
-    if let Some(desc) = &current_tile.description {
+    if let Some(desc) = game.locale.tile_description(current_tile) {
         if !desc.is_empty() {
-            turn_logs.push(desc.clone());
+            turn_logs.push(desc.to_string());
         }
     }
     
     // Call handle_tile_event, passing mutable logs
-    if let Err(e) = game.handle_tile_event(player_id, &current_tile, &mut turn_logs) {
+    if let Err(e) = game.handle_tile_event(player_id, current_tile, &mut turn_logs) {
          // Log error from primary tile effect handling
-         turn_logs.push(format!("Error handling tile event: {}", e));
+         turn_logs.push(game.locale.tr("turn.tile_error", &[("error", &e)]));
+         // A bill the bank won't lend toward is bankruptcy
+         if matches!(e, GameError::DebtLimitExceeded { .. }) {
+             game.eliminate_player(player_id, &mut turn_logs)?;
+         }
     }
 
     // --- 4. Display Summaries (Removed - handled by TUI) ---
     /* (Commented out summary section)
     {
-        let player = game.players.get(&player_id)
-            .ok_or_else(|| format!("Invalid player ID: {}", player_id))?;
+        let player = game.players.get(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?;
         // ... all println! for summaries ...
     }
     */
@@ -88,8 +82,12 @@ pub fn handle_player_turn(game: &mut GameState, player_id: usize, roll: u32) ->
 
     // --- 6. Update Player Scoreboard (Done implicitly by game state changes) ---
     // Ensure scoreboard data is updated within the game logic where changes occur
-    if let Some(player) = game.players.get_mut(&player_id) {
-        player.update_scoreboard();
+    // Tiles and cards can move money between players, so refresh everyone
+    game.update_all_valuations();
+    game.update_phase();
+    if let Some(player) = game.players.get(player_id) {
+        tracing::debug!(player_id, cash = %player.cash, debt = %player.debt, net_worth = %player.net_worth,
+            position = player.position, year = player.year, "turn finished");
     }
     
     // Return the accumulated logs for this turn
@@ -0,0 +1,83 @@
+// src/game/game_over.rs
+// The final tally once someone has won: where everyone finished, a few
+// numbers from each player's game, and a rematch at the same table.
+
+use std::sync::Arc;
+use rand::Rng;
+use crate::game::{GameError, GameStateBuilder};
+use crate::models::{GameState, Money, Player};
+use crate::models::ledger::csv_field;
+
+/// First line of exported results.
+pub const RESULTS_CSV_HEADER: &str = "rank,player,net_worth,years,total_income,max_debt\n";
+
+/// One player's place and numbers at the end of the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalStanding {
+    pub player_id: usize,
+    /// 1 for the richest player
+    pub rank: usize,
+    pub net_worth: Money,
+    pub years_played: u32,
+    /// Everything that came in over the game (see `Ledger::income`)
+    pub total_income: Money,
+    /// Most the player owed at once
+    pub max_debt: Money,
+}
+
+impl GameState {
+    /// Every player, richest first.
+    pub fn final_standings(&self) -> Vec<FinalStanding> {
+        self.rankings().into_iter()
+            .map(|ranking| {
+                let player = &self.players[ranking.player_id];
+                FinalStanding {
+                    player_id: ranking.player_id,
+                    rank: ranking.rank,
+                    net_worth: player.net_worth,
+                    years_played: self.years_played(ranking.player_id),
+                    total_income: player.ledger.income(),
+                    max_debt: player.peak_debt.max(player.debt),
+                }
+            })
+            .collect()
+    }
+
+    /// The final standings as CSV, with a header.
+    pub fn results_csv(&self) -> String {
+        let rows: String = self.final_standings().iter()
+            .map(|standing| format!("{},{},{},{},{},{}\n", standing.rank, csv_field(&self.players[standing.player_id].name),
+                standing.net_worth, standing.years_played, standing.total_income, standing.max_debt))
+            .collect();
+        format!("{}{}", RESULTS_CSV_HEADER, rows)
+    }
+
+    /// A new game for the same players, with the same rules, board, language,
+    /// card packs and scenario. Whoever played second goes first this time.
+    /// The seed comes from this game's RNG, so seeded games rematch the same
+    /// way every time.
+    pub fn rematch(&self) -> Result<GameState, GameError> {
+        let mut turn_order = self.turn_order.clone();
+        turn_order.rotate_left(1);
+        let seed = self.rng.clone().gen();
+        let builder = self.players.iter()
+            .fold(GameStateBuilder::default().rules(self.rules.clone()), |builder, player| {
+                let mut seat = Player::new(player.id, player.name.clone(), player.player_type.clone());
+                seat.color = player.color;
+                builder.add_player(seat)
+            });
+        let mut state = builder.turn_order(turn_order).seed(seed).build()?;
+        state.board = Arc::clone(&self.board);
+        state.locale = Arc::clone(&self.locale);
+        state.effects = Arc::clone(&self.effects);
+        state.confirm_forced_loans = self.confirm_forced_loans;
+        for pack in &self.card_packs {
+            state.add_card_pack(pack).map_err(|e| GameError::InvalidCard(e.to_string()))?;
+        }
+        if let Some(scenario) = &self.scenario {
+            scenario.apply(&mut state);
+        }
+        state.deal_opening_hands()?;
+        Ok(state)
+    }
+}
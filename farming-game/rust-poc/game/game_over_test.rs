@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::GameStateBuilder;
+    use crate::game::game_over::RESULTS_CSV_HEADER;
+    use crate::models::{GameState, LedgerCategory, Money, Player, PlayerColor, PlayerType};
+
+    /// Ann, a human, and Bo, an AI, at the end of a game Bo led.
+    fn finished_game() -> GameState {
+        let mut ann = Player::new(0, "Ann".to_string(), PlayerType::Human);
+        ann.color = PlayerColor::Blue;
+        let mut game = GameStateBuilder::default().add_player(ann).add_ai("Bo, Jr.", "test").build().unwrap();
+        game.players[0].year = 4;
+        game.players[1].cash = Money(300_000);
+        game.players[1].year = 3;
+        game.update_all_valuations();
+        game
+    }
+
+    #[test]
+    fn test_final_standings_rank_players_with_their_game_stats() {
+        let mut game = finished_game();
+        game.players[0].borrow(Money(20_000), game.rules.interest_rate);
+        game.players[0].repay_debt(Money(20_000), None, game.rules.interest_rate);
+        game.players[1].record_transaction(LedgerCategory::Harvest, Money(12_000));
+        game.update_all_valuations();
+
+        let standings = game.final_standings();
+        assert_eq!(standings.iter().map(|s| (s.player_id, s.rank)).collect::<Vec<_>>(), vec![(1, 1), (0, 2)]);
+        assert_eq!((standings[0].years_played, standings[0].total_income), (3, Money(12_000)));
+        assert_eq!(standings[1].years_played, 4);
+        assert_eq!(standings[1].max_debt, game.players[0].debt + Money(20_000), "The peak outlasts the repayment");
+
+        let csv = game.results_csv();
+        assert!(csv.starts_with(RESULTS_CSV_HEADER));
+        assert!(csv.lines().nth(1).unwrap().starts_with("1,\"Bo, Jr.\","), "{}", csv);
+    }
+
+    #[test]
+    fn test_rematch_seats_the_same_players_in_a_fresh_game() {
+        let mut game = finished_game();
+        game.rules.winning_net_worth = Money(100_000);
+
+        let rematch = game.rematch().unwrap();
+        assert_eq!(rematch.rules, game.rules);
+        assert_eq!(rematch.players.len(), 2);
+        let ann = &rematch.players[0];
+        assert_eq!((ann.name.as_str(), ann.color, &ann.player_type), ("Ann", PlayerColor::Blue, &PlayerType::Human));
+        assert_eq!(rematch.players[1].player_type, PlayerType::AI("test".to_string()));
+        assert_eq!((rematch.players[1].cash, rematch.players[1].year), (Player::new(1, String::new(), PlayerType::Human).cash, 1));
+        assert!(rematch.players.iter().all(|player| player.hand.len() == 2), "Opening hands are dealt");
+        assert_eq!(rematch.turn_order, vec![1, 0], "The start player rotates");
+        assert_eq!(rematch.rematch().unwrap().turn_order, vec![0, 1]);
+    }
+}
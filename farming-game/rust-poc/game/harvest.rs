@@ -1,217 +1,433 @@
-use crate::models::{Player, AssetType, HarvestType};
-use crate::game::GameEffect;
+use crate::models::{GameState, Player, AssetType, HarvestType, Money};
+use crate::game::{Dice, GameEffect, GameError};
+use crate::game::weather::Weather;
 use crate::cards::deck::Deck;
+use crate::locale::Locale;
+use crate::config::{GameRules, INTEREST_RATE};
 use rand::Rng; // Needed for random roll
+use std::sync::Arc;
 
-#[derive(Debug)]
-pub struct HarvestManager {
-    operating_cost_deck: Deck,
+/// Income for each roll of the die: (first block, each block after it).
+const HAY_YIELDS: [(Money, Money); 6] = [(Money(400), Money(400)), (Money(600), Money(600)), (Money(1000), Money(1000)), (Money(1500), Money(1500)), (Money(2200), Money(2200)), (Money(3000), Money(3000))];
+const FRUIT_YIELDS: [(Money, Money); 6] = [(Money(2000), Money(2000)), (Money(3500), Money(3500)), (Money(6000), Money(6000)), (Money(9000), Money(9000)), (Money(13000), Money(13000)), (Money(17500), Money(17500))];
+const GRAIN_YIELDS: [(Money, Money); 6] = [(Money(800), Money(800)), (Money(1500), Money(1500)), (Money(2500), Money(2500)), (Money(3800), Money(3800)), (Money(5300), Money(5300)), (Money(7000), Money(7000))];
+const LIVESTOCK_YIELDS: [(Money, Money); 6] = [(Money(1400), Money(1400)), (Money(2000), Money(2000)), (Money(2800), Money(2800)), (Money(3800), Money(3800)), (Money(5000), Money(5000)), (Money(7500), Money(7500))];
+
+fn yield_table(asset: AssetType) -> Option<&'static [(Money, Money); 6]> {
+    match asset {
+        AssetType::Hay => Some(&HAY_YIELDS),
+        AssetType::Grain => Some(&GRAIN_YIELDS),
+        AssetType::Fruit => Some(&FRUIT_YIELDS),
+        AssetType::Cows => Some(&LIVESTOCK_YIELDS),
+        _ => None,
+    }
 }
 
-impl Clone for HarvestManager {
-    fn clone(&self) -> Self {
-        Self {
-            operating_cost_deck: self.operating_cost_deck.clone(),
+/// Acres (or head) in each block a harvest pays on.
+fn units_per_block(asset: AssetType) -> Option<u32> {
+    match asset {
+        AssetType::Hay | AssetType::Grain | AssetType::Cows => Some(10),
+        AssetType::Fruit => Some(5),
+        _ => None,
+    }
+}
+
+/// Scales a rolled income by every modifier, rounding once at the end.
+fn scaled_income(income: Money, modifiers: &[YieldModifier]) -> Money {
+    Money(modifiers.iter().fold(income.dollars() as f32, |income, modifier| income * modifier.multiplier()).round() as i64)
+}
+
+/// Something that scales a harvest's income.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YieldModifier {
+    /// One-time multiplier from a card (including cards held for this harvest)
+    Card(f32),
+    Weather(Weather, f32),
+    /// A farm upgrade that raises this crop's yield
+    Upgrade(AssetType, f32),
+    /// Harvest multipliers cards left in play (see `Player::harvest_multiplier`)
+    Persistent(AssetType, f32),
+    /// The game's difficulty (see `GameRules::harvest_multiplier`)
+    Difficulty(f32),
+}
+
+impl YieldModifier {
+    pub fn multiplier(&self) -> f32 {
+        match *self {
+            YieldModifier::Card(multiplier)
+            | YieldModifier::Weather(_, multiplier)
+            | YieldModifier::Upgrade(_, multiplier)
+            | YieldModifier::Persistent(_, multiplier)
+            | YieldModifier::Difficulty(multiplier) => multiplier,
         }
     }
 }
 
+/// What the operating expense card drawn at a harvest might cost.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExpenseOdds {
+    /// Cards the expense could be drawn from
+    pub cards: usize,
+    /// How many of those cost nothing
+    pub free: usize,
+    pub min: Money,
+    pub max: Money,
+    pub average: Money,
+}
+
+/// A harvest's possible outcomes for one player (see `GameState::preview_harvest`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarvestPreview {
+    pub harvest_type: HarvestType,
+    /// What the harvest pays on; `None` for tiles that aren't a harvest
+    pub asset: Option<AssetType>,
+    /// Acres or head of that asset the player has
+    pub quantity: u32,
+    /// Income on a roll of one and on a roll of six, after modifiers
+    pub income_range: (Money, Money),
+    /// Average income over the six rolls
+    pub expected_income: Money,
+    pub modifiers: Vec<YieldModifier>,
+    pub expense: ExpenseOdds,
+}
+
+impl GameState {
+    /// Previews `harvest_type` for a player as it would play out now: this
+    /// year's weather, their cards (held ones included) and upgrades, and the
+    /// operating expense cards still to draw. Doesn't change the game.
+    pub fn preview_harvest(&self, player_id: usize, harvest_type: HarvestType) -> Result<HarvestPreview, GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        // Held cards only apply their multipliers when the harvest comes in
+        let mut player = player.clone();
+        for card in player.held_cards(&harvest_type).cloned().collect::<Vec<_>>() {
+            if let GameEffect::OneTimeHarvestMultiplier { asset, multiplier } = card.effect {
+                let combined = player.get_crop_multiplier(&asset) * multiplier;
+                player.set_crop_multiplier(asset, combined);
+            }
+        }
+        let mut manager = self.harvest_manager.clone();
+        manager.set_weather(self.weather_for_year(player.year));
+        Ok(manager.preview(&player, &harvest_type, &self.operating_cost_deck))
+    }
+}
+
+/// Works out harvest income and expenses. The operating cost cards drawn for
+/// each harvest come from the game's own deck, passed in by the caller, so
+/// harvests and Operating Cost tiles share one draw and discard pile.
+#[derive(Debug, Clone)]
+pub struct HarvestManager {
+    interest_rate: f32,
+    harvest_multiplier: f32,
+    operating_expense_multiplier: f32,
+    weather: Weather,
+    locale: Arc<Locale>,
+}
+
+impl Default for HarvestManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl HarvestManager {
-    pub fn new(operating_cost_deck: Deck) -> Self {
+    pub fn new() -> Self {
         Self {
-            operating_cost_deck,
+            interest_rate: INTEREST_RATE,
+            harvest_multiplier: 1.0,
+            operating_expense_multiplier: 1.0,
+            weather: Weather::Normal,
+            locale: Arc::new(Locale::english()),
         }
     }
 
-    // Method to check if the operating cost deck draw pile is empty
-    pub fn is_op_cost_deck_empty(&self) -> bool {
-        self.operating_cost_deck.draw_pile.is_empty()
+    // Rate used by "pay interest" operating expense cards
+    pub fn set_interest_rate(&mut self, rate: f32) {
+        self.interest_rate = rate;
+    }
+
+    // Interest rate and the difficulty's harvest and expense multipliers
+    pub fn apply_rules(&mut self, rules: &GameRules) {
+        self.interest_rate = rules.interest_rate;
+        self.harvest_multiplier = rules.harvest_multiplier;
+        self.operating_expense_multiplier = rules.operating_expense_multiplier;
+    }
+
+    // Weather that scales every harvest until the next year's roll
+    pub fn set_weather(&mut self, weather: Weather) {
+        self.weather = weather;
+    }
+
+    // Language for harvest log lines; follows the game's locale
+    pub fn set_locale(&mut self, locale: Arc<Locale>) {
+        self.locale = locale;
     }
 
+
     // Modified to return logs
-    pub fn calculate_harvest(&mut self, player: &mut Player, harvest_type: &HarvestType) -> Result<(i32, i32, Vec<String>), String> {
+    pub fn calculate_harvest(&mut self, player: &mut Player, harvest_type: &HarvestType, operating_cost_deck: &mut Deck) -> Result<(Money, Money, Vec<String>), GameError> {
+        self.calculate_harvest_with(player, harvest_type, operating_cost_deck, &mut rand::thread_rng())
+    }
+
+    // Same as calculate_harvest, rolling with the caller's RNG (seeded games, WASM)
+    pub fn calculate_harvest_with<R: Rng + ?Sized>(&mut self, player: &mut Player, harvest_type: &HarvestType, operating_cost_deck: &mut Deck, rng: &mut R) -> Result<(Money, Money, Vec<String>), GameError> {
         let mut harvest_logs = Vec::new();
         
         // First determine which asset type is required for this harvest type
-        let required_asset = match harvest_type {
-            HarvestType::Corn | HarvestType::Wheat => AssetType::Grain,
-            HarvestType::Apple | HarvestType::Cherry => AssetType::Fruit,
-            HarvestType::Livestock => AssetType::Cows,
-            HarvestType::HayCutting1 | HarvestType::HayCutting2 | 
-            HarvestType::HayCutting3 | HarvestType::HayCutting4 => AssetType::Hay,
-            HarvestType::None => return Ok((0, 0, vec!["No harvest type specified.".to_string()])),
+        let Some(required_asset) = harvest_type.asset() else {
+            return Ok((Money::ZERO, Money::ZERO, vec![self.locale.tr("harvest.no_type", &[])]));
         };
         
         // Check if player owns the required asset
         let owns_asset = player.assets.get(&required_asset).map_or(0, |a| a.quantity) > 0;
         if !owns_asset {
-            harvest_logs.push(format!("No {:?} to harvest.", required_asset));
-            return Ok((0, 0, harvest_logs));
+            harvest_logs.push(self.locale.tr("harvest.nothing_to_harvest", &[("asset", &self.locale.asset_name(&required_asset))]));
+            return Ok((Money::ZERO, Money::ZERO, harvest_logs));
         }
         
         // 1. Draw and apply operating cost card (only if player owns the relevant asset)
-        let expense_card = self.operating_cost_deck.draw().ok_or("Operating cost deck is empty")?;
-        let expense = match expense_card.effect {
-            GameEffect::Expense(amount) => {
-                harvest_logs.push(format!("Operating Expense: {} - ${}", expense_card.title, amount));
-                amount
+        let expense_card = operating_cost_deck.draw_with(rng).ok_or(GameError::DeckEmpty("Operating Cost"))?;
+        let expense = self.expense_for(&expense_card.effect, player);
+        let full_expense = self.full_expense_for(&expense_card.effect, player);
+        let card_title = self.locale.card_title(&expense_card);
+        match expense_card.effect {
+            GameEffect::Expense(_) => {
+                harvest_logs.push(self.locale.tr("harvest.expense_flat", &[("card", &card_title), ("amount", &full_expense)]));
             },
             GameEffect::ExpensePerAsset { asset, rate } => {
-                let asset_count = player.assets.get(&asset).map_or(0, |r| r.quantity as i32);
-                let calc_expense = asset_count * rate;
-                harvest_logs.push(format!("Operating Expense: {} - ${}/{} x {} {} = ${}", 
-                    expense_card.title,
-                    rate,
-                    if asset == AssetType::Cows { "cow" } else { "acre" },
-                    asset_count,
-                    if asset == AssetType::Cows { "cows" } else { "acres" },
-                    calc_expense
-                ));
-                calc_expense
+                let asset_count = player.assets.get(&asset).map_or(0, |r| r.quantity);
+                let key = if asset == AssetType::Cows { "harvest.expense_per_cow" } else { "harvest.expense_per_acre" };
+                harvest_logs.push(self.locale.tr(key, &[("card", &card_title),
+                    ("rate", &rate), ("count", &asset_count), ("amount", &full_expense)]));
+            },
+            GameEffect::PayInterest if expense.is_positive() => {
+                harvest_logs.push(self.locale.tr("harvest.expense_interest", &[("card", &card_title),
+                    ("percent", &(self.interest_rate * 100.0).round()), ("debt", &player.debt), ("amount", &expense)]));
             },
             GameEffect::PayInterest => {
-                // Calculate 10% interest on the player's debt
-                let interest = (player.debt as f32 * 0.1).round() as i32;
-                if interest > 0 {
-                    harvest_logs.push(format!("Operating Expense: {} - 10% of ${} debt = ${}", expense_card.title, player.debt, interest));
-                    interest
-                } else {
-                    harvest_logs.push(format!("Operating Expense: {} - No interest (debt: $0)", expense_card.title));
-                    0
-                }
+                harvest_logs.push(self.locale.tr("harvest.expense_no_interest", &[("card", &card_title)]));
             },
             _ => {
-                harvest_logs.push(format!("Operating Expense: {} - None", expense_card.title));
-                0 // Default to 0 for unhandled effect types
+                harvest_logs.push(self.locale.tr("harvest.expense_none", &[("card", &card_title)]));
             }
-        };
+        }
+        if expense < full_expense {
+            harvest_logs.push(self.locale.tr("harvest.expense_discount", &[
+                ("percent", &(player.expense_discount() * 100.0).round()),
+                ("saved", &(full_expense - expense)),
+                ("amount", &expense),
+            ]));
+        }
         
-        // 2. Calculate harvest income
-        let (income, resolve_logs) = match harvest_type {
+        // 2. Calculate harvest income, unless the IRS is taking it (Bad at Taxes)
+        let resolved = match harvest_type {
+            _ if player.harvest_income_garnished => {
+                player.harvest_income_garnished = false;
+                Ok((Money::ZERO, vec![self.locale.tr("harvest.income_garnished", &[])]))
+            }
             HarvestType::Corn | HarvestType::Wheat => {
-                let (income_result, logs) = self.resolve_grain_harvest(player, AssetType::Grain, harvest_type, expense)?;
-                (income_result, logs)
+                self.resolve_grain_harvest(player, AssetType::Grain, harvest_type, expense, rng)
             }
             HarvestType::Apple | HarvestType::Cherry => {
-                let (income_result, logs) = self.resolve_fruit_harvest(player, harvest_type, expense)?;
-                (income_result, logs)
+                self.resolve_fruit_harvest(player, harvest_type, expense, rng)
             }
             HarvestType::Livestock => {
-                let (income_result, logs) = self.resolve_livestock_harvest(player, harvest_type, expense)?;
-                (income_result, logs)
+                self.resolve_livestock_harvest(player, harvest_type, expense, rng)
             }
             HarvestType::HayCutting1 | HarvestType::HayCutting2 | 
             HarvestType::HayCutting3 | HarvestType::HayCutting4 => {
-                let (income_result, logs) = self.resolve_hay_harvest(player, harvest_type, expense)?;
-                (income_result, logs)
+                self.resolve_hay_harvest(player, harvest_type, expense, rng)
             }
-            _ => (0, vec![]) // No income, no logs for HarvestType::None
+            _ => Ok((Money::ZERO, vec![])) // No income, no logs for HarvestType::None
         };
+
+        // Discard the expense card before surfacing any error so the deck never shrinks
+        operating_cost_deck.discard(expense_card);
+        let (income, resolve_logs) = resolved?;
         
         harvest_logs.extend(resolve_logs); // Add logs from the specific resolve function
 
-        // Reset crop multipliers after the harvest is completed
-        player.reset_crop_multipliers();
+        // The one-time multiplier for this crop has been used; other crops keep theirs
+        player.take_crop_multiplier(&required_asset);
+        player.use_harvest_effects(required_asset);
 
-        // Discard the expense card
-        self.operating_cost_deck.discard_pile.push(expense_card);
+        Ok((income, expense, harvest_logs))
+    }
 
-        Ok((income - expense, expense, harvest_logs))
+    /// What an operating expense card drawn at harvest costs `player`.
+    /// Cards other than expenses and interest cost nothing.
+    /// Flat and per-asset expenses scale with the difficulty, then come down
+    /// by the player's expense discount (a machinery co-op); interest follows
+    /// the difficulty's rate instead and isn't discounted.
+    fn expense_for(&self, effect: &GameEffect, player: &Player) -> Money {
+        let expense = self.full_expense_for(effect, player);
+        match effect {
+            GameEffect::Expense(_) | GameEffect::ExpensePerAsset { .. } => expense.times(1.0 - player.expense_discount()),
+            _ => expense,
+        }
+    }
+
+    /// What an operating expense card would cost `player` before any discount.
+    fn full_expense_for(&self, effect: &GameEffect, player: &Player) -> Money {
+        let scaled = |amount: Money| amount.times(self.operating_expense_multiplier);
+        match effect {
+            GameEffect::Expense(amount) => scaled(*amount),
+            GameEffect::ExpensePerAsset { asset, rate } => scaled(*rate * player.assets.get(asset).map_or(0, |r| r.quantity)),
+            GameEffect::PayInterest => player.interest_due(self.interest_rate),
+            _ => Money::ZERO,
+        }
+    }
+
+    /// Everything that will scale `player`'s income from `asset`, in the order it's applied.
+    pub fn yield_modifiers(&self, player: &Player, asset: AssetType) -> Vec<YieldModifier> {
+        let mut modifiers = Vec::new();
+        let crop_multiplier = player.get_crop_multiplier(&asset);
+        if (crop_multiplier - 1.0).abs() > f32::EPSILON {
+            modifiers.push(YieldModifier::Card(crop_multiplier));
+        }
+        let weather_modifier = self.weather.yield_modifier(&asset);
+        if (weather_modifier - 1.0).abs() > f32::EPSILON {
+            modifiers.push(YieldModifier::Weather(self.weather, weather_modifier));
+        }
+        for upgrade in AssetType::UPGRADES {
+            match upgrade.yield_bonus() {
+                Some((crop, bonus)) if crop == asset && player.assets.get(&upgrade).is_some_and(|r| r.quantity > 0) => {
+                    modifiers.push(YieldModifier::Upgrade(upgrade, bonus));
+                }
+                _ => {}
+            }
+        }
+        let persistent_multiplier = player.harvest_multiplier(asset);
+        if (persistent_multiplier - 1.0).abs() > f32::EPSILON {
+            modifiers.push(YieldModifier::Persistent(asset, persistent_multiplier));
+        }
+        if (self.harvest_multiplier - 1.0).abs() > f32::EPSILON {
+            modifiers.push(YieldModifier::Difficulty(self.harvest_multiplier));
+        }
+        modifiers
+    }
+
+    /// What a harvest could bring `player`, worked out from the yield table,
+    /// the modifiers in play and the operating expense cards left to draw.
+    /// Nothing is rolled or drawn.
+    pub fn preview(&self, player: &Player, harvest_type: &HarvestType, operating_cost_deck: &Deck) -> HarvestPreview {
+        let asset = harvest_type.asset();
+        let quantity = asset.and_then(|asset| player.assets.get(&asset)).map_or(0, |r| r.quantity);
+        let mut preview = HarvestPreview {
+            harvest_type: harvest_type.clone(),
+            asset,
+            quantity,
+            income_range: (Money::ZERO, Money::ZERO),
+            expected_income: Money::ZERO,
+            modifiers: Vec::new(),
+            expense: ExpenseOdds::default(),
+        };
+        let Some(asset) = asset.filter(|_| quantity > 0) else { return preview };
+
+        // The expense card is drawn whenever the player owns the crop at all
+        let pile = if operating_cost_deck.draw_pile.is_empty() { &operating_cost_deck.discard_pile } else { &operating_cost_deck.draw_pile };
+        let expenses: Vec<Money> = pile.iter().map(|card| self.expense_for(&card.effect, player)).collect();
+        if !expenses.is_empty() {
+            preview.expense = ExpenseOdds {
+                cards: expenses.len(),
+                free: expenses.iter().filter(|&&expense| expense == Money::ZERO).count(),
+                min: expenses.iter().copied().min().unwrap_or_default(),
+                max: expenses.iter().copied().max().unwrap_or_default(),
+                average: expenses.iter().sum::<Money>() / expenses.len() as i64,
+            };
+        }
+
+        let (Some(table), Some(per_block)) = (yield_table(asset), units_per_block(asset)) else { return preview };
+        let blocks = quantity / per_block;
+        if blocks == 0 {
+            return preview;
+        }
+        preview.modifiers = self.yield_modifiers(player, asset);
+        let incomes: Vec<Money> = table.iter()
+            .map(|(base, increment)| scaled_income(*base + *increment * (blocks - 1), &preview.modifiers) + player.income_bonus())
+            .collect();
+        preview.income_range = (incomes[0], incomes[5]);
+        preview.expected_income = incomes.iter().sum::<Money>() / 6;
+        preview
     }
 
     // Modified helper to return logs
-    fn resolve_harvest_helper(&mut self, player: &Player, asset: AssetType, yield_table: &[(i32, i32); 6], harvest_type: &HarvestType, expense: i32) -> Result<(i32, Vec<String>), String> {
+    fn resolve_harvest_helper<R: Rng + ?Sized>(&mut self, player: &Player, asset: AssetType, yield_table: &[(Money, Money); 6], harvest_type: &HarvestType, expense: Money, rng: &mut R) -> Result<(Money, Vec<String>), GameError> {
         let mut logs = Vec::new();
         let quantity = player.assets.get(&asset).map(|a| a.quantity).unwrap_or(0);
         if quantity == 0 {
-            logs.push(format!("No {:?} to harvest.", asset));
-            return Ok((0, logs)); 
+            logs.push(self.locale.tr("harvest.nothing_to_harvest", &[("asset", &self.locale.asset_name(&asset))]));
+            return Ok((Money::ZERO, logs)); 
         }
 
-        let units_per_block = match asset {
-            AssetType::Hay | AssetType::Grain => 10,
-            AssetType::Fruit => 5,
-            AssetType::Cows => 10,
-            _ => return Err("Unsupported asset type for harvest calculation".to_string()),
-        };
+        let units_per_block = units_per_block(asset).ok_or(GameError::UnsupportedHarvestAsset(asset))?;
 
         let blocks = quantity / units_per_block;
         if blocks == 0 {
-            logs.push(format!("Not enough {:?} for harvest (need {}).", asset, units_per_block));
-            return Ok((0, logs)); 
+            logs.push(self.locale.tr("harvest.not_enough", &[("asset", &self.locale.asset_name(&asset)), ("needed", &units_per_block)]));
+            return Ok((Money::ZERO, logs)); 
         }
 
-        let roll = rand::thread_rng().gen_range(0..6u8);
-        let (base, increment) = yield_table[roll as usize];
+        let roll = Dice::ONE.roll(rng).total();
+        tracing::debug!(player_id = player.id, harvest = ?harvest_type, roll, quantity, "harvest roll");
+        let (base, increment) = yield_table[roll as usize - 1];
         let blocks_minus_one = blocks.saturating_sub(1);
         let increment_total = increment * blocks_minus_one;
         let initial_income = base + increment_total;
+
+        let key = if asset == AssetType::Cows { "harvest.roll_cows" } else { "harvest.roll_acres" };
+        let mut harvest_msg = self.locale.tr(key, &[
+            ("harvest", &self.locale.harvest_name(harvest_type)),
+            ("roll", &roll),
+            ("base", &base),
+            ("quantity", &quantity),
+        ]);
         
-        let mut final_income = initial_income as f32;
-
-        // Format the harvest name based on type
-        let harvest_name = match harvest_type {
-            HarvestType::HayCutting1 => "Hay: First Cutting",
-            HarvestType::HayCutting2 => "Hay: Second Cutting",
-            HarvestType::HayCutting3 => "Hay: Third Cutting",
-            HarvestType::HayCutting4 => "Hay: Fourth Cutting",
-            HarvestType::Wheat => "Wheat",
-            HarvestType::Corn => "Corn",
-            HarvestType::Apple => "Apple",
-            HarvestType::Cherry => "Cherry",
-            HarvestType::Livestock => "Livestock Sales",
-            HarvestType::None => "Unknown Harvest",
-        };
-        
-        let mut harvest_msg = format!("{}: Roll {} = ${}/block x {} {}", 
-            harvest_name,
-            roll + 1,
-            base,
-            quantity,
-            if asset == AssetType::Cows { "cows" } else { "acres" }
-        );
-        
-        // Apply crop multiplier
-        let crop_multiplier = player.get_crop_multiplier(&asset);
-        if (crop_multiplier - 1.0).abs() > f32::EPSILON {
-            final_income *= crop_multiplier;
-            harvest_msg.push_str(&format!(" x{:.1} multiplier", crop_multiplier));
+        // Apply card multipliers, this year's weather, farm upgrades and livestock bonuses
+        let modifiers = self.yield_modifiers(player, asset);
+        for modifier in &modifiers {
+            harvest_msg.push_str(&self.modifier_label(modifier));
         }
-        
-        // Apply livestock bonus if this is a livestock harvest
-        if asset == AssetType::Cows {
-            let livestock_multiplier = player.get_livestock_harvest_multiplier();
-            if (livestock_multiplier - 1.0).abs() > f32::EPSILON {
-                final_income *= livestock_multiplier;
-                harvest_msg.push_str(&format!(" x{:.1} livestock", livestock_multiplier));
-            }
+        let mut rounded_income = scaled_income(initial_income, &modifiers);
+        let bonus = player.income_bonus();
+        if bonus != Money::ZERO {
+            rounded_income += bonus;
+            harvest_msg.push_str(&self.locale.tr("harvest.income_bonus", &[("bonus", &bonus)]));
         }
-
-        let rounded_income = final_income.round() as i32;
-        harvest_msg.push_str(&format!(" - ${} operating expense = ${}", expense, rounded_income - expense));
+        harvest_msg.push_str(&self.locale.tr("harvest.net", &[("expense", &expense), ("net", &(rounded_income - expense))]));
         logs.push(harvest_msg);
 
-        Ok((rounded_income - expense, logs))
+        Ok((rounded_income, logs))
+    }
+
+    /// Log text for one modifier, e.g. " (x1.25 irrigation)".
+    fn modifier_label(&self, modifier: &YieldModifier) -> String {
+        match *modifier {
+            YieldModifier::Card(multiplier) => self.locale.tr("harvest.crop_multiplier", &[("multiplier", &format!("{:.1}", multiplier))]),
+            YieldModifier::Weather(weather, multiplier) => self.locale.tr("harvest.weather_modifier", &[("multiplier", &format!("{:.2}", multiplier)),
+                ("weather", &self.locale.weather_label(weather).to_lowercase())]),
+            YieldModifier::Upgrade(upgrade, multiplier) => self.locale.tr("harvest.upgrade_bonus", &[("multiplier", &format!("{:.2}", multiplier)),
+                ("upgrade", &self.locale.asset_name(&upgrade).to_lowercase())]),
+            YieldModifier::Persistent(AssetType::Cows, multiplier) => self.locale.tr("harvest.livestock_multiplier", &[("multiplier", &format!("{:.1}", multiplier))]),
+            YieldModifier::Persistent(asset, multiplier) => self.locale.tr("harvest.persistent_multiplier", &[("multiplier", &format!("{:.1}", multiplier)),
+                ("asset", &self.locale.asset_name(&asset).to_lowercase())]),
+            YieldModifier::Difficulty(multiplier) => self.locale.tr("harvest.difficulty_multiplier", &[("multiplier", &format!("{:.2}", multiplier))]),
+        }
     }
 
     // Update wrappers to pass harvest_type and expense
-    pub fn resolve_hay_harvest(&mut self, player: &Player, harvest_type: &HarvestType, expense: i32) -> Result<(i32, Vec<String>), String> {
-        let hay_table = [(400, 400), (600, 600), (1000, 1000), (1500, 1500), (2200, 2200), (3000, 3000)];
-        self.resolve_harvest_helper(player, AssetType::Hay, &hay_table, harvest_type, expense)
+    pub fn resolve_hay_harvest<R: Rng + ?Sized>(&mut self, player: &Player, harvest_type: &HarvestType, expense: Money, rng: &mut R) -> Result<(Money, Vec<String>), GameError> {
+        self.resolve_harvest_helper(player, AssetType::Hay, &HAY_YIELDS, harvest_type, expense, rng)
     }
 
-    pub fn resolve_fruit_harvest(&mut self, player: &Player, harvest_type: &HarvestType, expense: i32) -> Result<(i32, Vec<String>), String> {
-        let fruit_table = [(2000, 2000), (3500, 3500), (6000, 6000), (9000, 9000), (13000, 13000), (17500, 17500)];
-        self.resolve_harvest_helper(player, AssetType::Fruit, &fruit_table, harvest_type, expense)
+    pub fn resolve_fruit_harvest<R: Rng + ?Sized>(&mut self, player: &Player, harvest_type: &HarvestType, expense: Money, rng: &mut R) -> Result<(Money, Vec<String>), GameError> {
+        self.resolve_harvest_helper(player, AssetType::Fruit, &FRUIT_YIELDS, harvest_type, expense, rng)
     }
 
-    pub fn resolve_grain_harvest(&mut self, player: &Player, crop: AssetType, harvest_type: &HarvestType, expense: i32) -> Result<(i32, Vec<String>), String> {
-        let grain_table = [(800, 800), (1500, 1500), (2500, 2500), (3800, 3800), (5300, 5300), (7000, 7000)];
-        self.resolve_harvest_helper(player, crop, &grain_table, harvest_type, expense)
+    pub fn resolve_grain_harvest<R: Rng + ?Sized>(&mut self, player: &Player, crop: AssetType, harvest_type: &HarvestType, expense: Money, rng: &mut R) -> Result<(Money, Vec<String>), GameError> {
+        self.resolve_harvest_helper(player, crop, &GRAIN_YIELDS, harvest_type, expense, rng)
     }
 
-    pub fn resolve_livestock_harvest(&mut self, player: &Player, harvest_type: &HarvestType, expense: i32) -> Result<(i32, Vec<String>), String> {
-        let livestock_table = [(1400, 1400), (2000, 2000), (2800, 2800), (3800, 3800), (5000, 5000), (7500, 7500)];
-        self.resolve_harvest_helper(player, AssetType::Cows, &livestock_table, harvest_type, expense)
+    pub fn resolve_livestock_harvest<R: Rng + ?Sized>(&mut self, player: &Player, harvest_type: &HarvestType, expense: Money, rng: &mut R) -> Result<(Money, Vec<String>), GameError> {
+        self.resolve_harvest_helper(player, AssetType::Cows, &LIVESTOCK_YIELDS, harvest_type, expense, rng)
     }
 } 
\ No newline at end of file
@@ -1,19 +1,22 @@
 #[cfg(test)]
 mod tests {
-    use crate::game::harvest::HarvestManager;
-    use crate::models::{Player, AssetType, HarvestType};
+    use crate::game::harvest::{ExpenseOdds, HarvestManager, YieldModifier};
+    use crate::models::{GameState, Player, AssetType, HarvestType, Money};
     use crate::models::player::{PlayerType, EffectType};
     use crate::cards::deck::Deck;
     use crate::cards::card::{Card, CardSource};
-    use crate::game::GameEffect;
+    use crate::game::{EffectResolver, GameEffect};
     use std::collections::HashMap;
+    use crate::config::{Difficulty, GameRules, INTEREST_RATE};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
 
     // Helper to create a test player
-    fn create_test_player(cash: i32, assets: HashMap<AssetType, i32>) -> Player {
+    fn create_test_player(cash: Money, assets: HashMap<AssetType, u32>) -> Player {
         let mut player = Player::new(0, "Test Harvester".to_string(), PlayerType::Human);
         player.cash = cash;
         for (asset, quantity) in assets {
-            player.add_asset(asset, quantity, 0); // Cost doesn't matter for harvest calc
+            player.add_asset(asset, quantity, Money::ZERO); // Cost doesn't matter for harvest calc
         }
         player
     }
@@ -28,6 +31,7 @@ mod tests {
             effect,
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         }
     }
 
@@ -35,20 +39,20 @@ mod tests {
     #[test]
     fn test_calculate_harvest_hay_simple_expense() {
         // Setup Deck
-        let expense_amount = 500;
+        let expense_amount = Money(500);
         let op_cost_card = create_op_cost_card(1, GameEffect::Expense(expense_amount));
         let mut op_cost_deck = Deck::new();
         op_cost_deck.draw_pile = vec![op_cost_card]; // Manually set draw pile
 
         // Setup HarvestManager
-        let mut harvest_manager = HarvestManager::new(op_cost_deck);
+        let mut harvest_manager = HarvestManager::new();
 
         // Setup Player
-        let mut player = create_test_player(10000, HashMap::from([(AssetType::Hay, 20)])); // 2 blocks of Hay
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Hay, 20)])); // 2 blocks of Hay
 
         // Perform harvest calculation
         let harvest_type = HarvestType::HayCutting1;
-        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type);
+        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type, &mut op_cost_deck);
 
         assert!(result.is_ok(), "calculate_harvest failed: {:?}", result.err());
         let (income, expense, logs) = result.unwrap();
@@ -60,39 +64,39 @@ mod tests {
         // Hay table: (400, 400), (600, 600), (1000, 1000), (1500, 1500), (2200, 2200), (3000, 3000)
         // Income (2 blocks) = base + increment * (2-1) = base + increment
         let possible_incomes = vec![800, 1200, 2000, 3000, 4400, 6000];
-        assert!(possible_incomes.contains(&income), 
+        assert!(possible_incomes.contains(&income.dollars()), 
                 "Income {} not in possible range {:?} for 2 blocks of Hay", income, possible_incomes);
 
         // Check if deck is now empty
-        assert!(harvest_manager.is_op_cost_deck_empty(), "Deck draw pile should be empty after drawing");
+        assert!(op_cost_deck.draw_pile.is_empty(), "Deck draw pile should be empty after drawing");
         
         // Verify logs contain expected entries
-        assert!(logs.iter().any(|log| log.contains("Op Cost:")), 
+        assert!(logs.iter().any(|log| log.contains("Operating Expense: Test OpCost Card - $500")), 
                 "Expected log about drawing expense card missing");
-        assert!(logs.iter().any(|log| log.contains("Income: $")),
+        assert!(logs.iter().any(|log| log.contains("operating expense = $")),
                 "Expected log about calculated income missing");
     }
 
      #[test]
     fn test_calculate_harvest_grain_expense_per_asset() {
         // Setup Deck
-        let expense_rate = 100;
+        let expense_rate = Money(100);
         let expense_asset = AssetType::Grain;
         let op_cost_card = create_op_cost_card(2, GameEffect::ExpensePerAsset { asset: expense_asset, rate: expense_rate });
         let mut op_cost_deck = Deck::new();
         op_cost_deck.draw_pile = vec![op_cost_card];
 
         // Setup HarvestManager
-        let mut harvest_manager = HarvestManager::new(op_cost_deck);
+        let mut harvest_manager = HarvestManager::new();
 
         // Setup Player
         let grain_quantity = 35; // 3 blocks (10 per block) + 5 extra
-        let mut player = create_test_player(10000, HashMap::from([(AssetType::Grain, grain_quantity)]));
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Grain, grain_quantity)]));
         let expected_expense = grain_quantity * expense_rate;
 
         // Perform harvest calculation (Wheat is Grain)
         let harvest_type = HarvestType::Wheat;
-        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type);
+        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type, &mut op_cost_deck);
 
         assert!(result.is_ok(), "calculate_harvest failed: {:?}", result.err());
         let (income, expense, logs) = result.unwrap();
@@ -104,65 +108,65 @@ mod tests {
         // Grain table: (800, 800), (1500, 1500), (2500, 2500), (3800, 3800), (5300, 5300), (7000, 7000)
         // Income (3 blocks) = base + increment * (3-1) = base + 2*increment
         let possible_incomes = vec![2400, 4500, 7500, 11400, 15900, 21000];
-        assert!(possible_incomes.contains(&income), 
+        assert!(possible_incomes.contains(&income.dollars()), 
                 "Income {} not in possible range {:?} for 3 blocks of Grain", income, possible_incomes);
         
         // Verify logs contain expected entries
-        assert!(logs.iter().any(|log| log.contains("Expense: $")), 
+        assert!(logs.iter().any(|log| log.contains("$100/acre x 35 acres = $3,500")), 
                 "Expected log about expense per asset calculation missing");
     }
 
     #[test]
     fn test_calculate_harvest_no_assets() {
         // Setup Deck
-        let expense_amount = 200;
+        let expense_amount = Money(200);
         let op_cost_card = create_op_cost_card(3, GameEffect::Expense(expense_amount));
         let mut op_cost_deck = Deck::new();
         op_cost_deck.draw_pile = vec![op_cost_card];
 
         // Setup HarvestManager
-        let mut harvest_manager = HarvestManager::new(op_cost_deck);
+        let mut harvest_manager = HarvestManager::new();
 
         // Setup Player with NO Hay
-        let mut player = create_test_player(10000, HashMap::new());
+        let mut player = create_test_player(Money(10000), HashMap::new());
 
         // Perform harvest calculation
         let harvest_type = HarvestType::HayCutting1;
-        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type);
+        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type, &mut op_cost_deck);
 
         // Expect Ok with 0 income/expense because player has no assets to harvest
         assert!(result.is_ok(), "calculate_harvest should succeed even if player has no assets, returning 0 income/expense. Got: {:?}", result.err());
         let (income, expense, logs) = result.unwrap();
         
-        assert_eq!(income, 0, "Income should be 0 when no assets are harvested.");
-        assert_eq!(expense, 0, "Expense should be 0 when harvest is skipped due to no assets.");
+        assert_eq!(income, Money::ZERO, "Income should be 0 when no assets are harvested.");
+        assert_eq!(expense, Money::ZERO, "Expense should be 0 when harvest is skipped due to no assets.");
         assert!(logs.iter().any(|log| log.contains("No Hay to harvest.")), 
                 "Expected log message about skipping harvest missing.");
 
         // Check that the op cost card was NOT drawn (deck should still contain it)
-        assert!(!harvest_manager.is_op_cost_deck_empty(), "Deck should NOT be empty as the harvest was skipped.");
+        assert!(!op_cost_deck.draw_pile.is_empty(), "Deck should NOT be empty as the harvest was skipped.");
     }
 
      #[test]
     fn test_calculate_harvest_with_crop_multiplier() {
         // Setup Deck
-        let expense_amount = 300;
+        let expense_amount = Money(300);
         let op_cost_card = create_op_cost_card(4, GameEffect::Expense(expense_amount));
         let mut op_cost_deck = Deck::new();
         op_cost_deck.draw_pile = vec![op_cost_card];
 
         // Setup HarvestManager
-        let mut harvest_manager = HarvestManager::new(op_cost_deck);
+        let mut harvest_manager = HarvestManager::new();
 
         // Setup Player
         let hay_quantity = 10; // 1 block
-        let mut player = create_test_player(10000, HashMap::from([(AssetType::Hay, hay_quantity)]));
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Hay, hay_quantity)]));
         let multiplier = 2.0;
         player.set_crop_multiplier(AssetType::Hay, multiplier); // Double yield!
 
         // Perform harvest calculation
         let harvest_type = HarvestType::HayCutting2;
-        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type);
+        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type, &mut op_cost_deck);
 
         assert!(result.is_ok(), "calculate_harvest failed: {:?}", result.err());
         let (income, expense, logs) = result.unwrap();
@@ -173,14 +177,14 @@ mod tests {
         // Assert Income (check against possible values for 1 block with x2 multiplier)
         // Hay table: (400, 400), (600, 600), (1000, 1000), (1500, 1500), (2200, 2200), (3000, 3000)
         let possible_base_incomes = vec![400, 600, 1000, 1500, 2200, 3000];
-        let possible_final_incomes: Vec<i32> = possible_base_incomes.iter()
-            .map(|&x| (x as f32 * multiplier).round() as i32).collect();
+        let possible_final_incomes: Vec<Money> = possible_base_incomes.iter()
+            .map(|&x| Money((x as f32 * multiplier).round() as i64)).collect();
             
         assert!(possible_final_incomes.contains(&income), 
                 "Income {} not in possible range {:?} with multiplier {}", income, possible_final_incomes, multiplier);
                 
         // Verify crop multiplier in logs
-        assert!(logs.iter().any(|log| log.contains("multiplier =")), 
+        assert!(logs.iter().any(|log| log.contains("x2.0 multiplier")), 
                 "Expected log about crop multiplier application missing");
                 
         // Verify multiplier reset - NOTE: reset_crop_multipliers itself doesn't log currently
@@ -191,23 +195,23 @@ mod tests {
     #[test]
     fn test_calculate_harvest_livestock_with_multiplier() {
         // Setup Deck
-        let expense_amount = 1000;
+        let expense_amount = Money(1000);
         let op_cost_card = create_op_cost_card(5, GameEffect::Expense(expense_amount));
         let mut op_cost_deck = Deck::new();
         op_cost_deck.draw_pile = vec![op_cost_card];
 
         // Setup HarvestManager
-        let mut harvest_manager = HarvestManager::new(op_cost_deck);
+        let mut harvest_manager = HarvestManager::new();
 
         // Setup Player
         let cow_quantity = 25; // 2 blocks (10 per block) + 5 extra
-        let mut player = create_test_player(10000, HashMap::from([(AssetType::Cows, cow_quantity)]));
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Cows, cow_quantity)]));
         let multiplier = 1.5; // From a persistent effect
         player.add_persistent_effect(EffectType::LivestockHarvestBonus(multiplier), 1); // Add the effect
 
         // Perform harvest calculation
         let harvest_type = HarvestType::Livestock;
-        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type);
+        let result = harvest_manager.calculate_harvest(&mut player, &harvest_type, &mut op_cost_deck);
 
         assert!(result.is_ok(), "calculate_harvest failed: {:?}", result.err());
         let (income, expense, logs) = result.unwrap();
@@ -219,17 +223,287 @@ mod tests {
         // Livestock table: (1400, 1400), (2000, 2000), (2800, 2800), (3800, 3800), (5000, 5000), (7500, 7500)
         // Base income (2 blocks) = base + increment * (2-1) = base + increment
         let possible_base_incomes = vec![2800, 4000, 5600, 7600, 10000, 15000];
-        let possible_final_incomes: Vec<i32> = possible_base_incomes.iter()
-            .map(|&x| (x as f32 * multiplier).round() as i32).collect();
+        let possible_final_incomes: Vec<Money> = possible_base_incomes.iter()
+            .map(|&x| Money((x as f32 * multiplier).round() as i64)).collect();
             
         assert!(possible_final_incomes.contains(&income), 
                 "Income {} not in possible range {:?} with multiplier {}", income, possible_final_incomes, multiplier);
                 
         // Verify livestock multiplier in logs
-        assert!(logs.iter().any(|log| log.contains("livestock =")), 
+        assert!(logs.iter().any(|log| log.contains("x1.5 livestock")), 
                 "Expected log about livestock multiplier application missing");
     }
 
+    #[test]
+    fn test_persistent_effects_scale_income_and_expenses_until_used_up() {
+        let mut harvest_manager = HarvestManager::new();
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Grain, 10)]));
+        player.add_persistent_effect_for_harvests(EffectType::HarvestMultiplier { asset: AssetType::Grain, multiplier: 2.0 }, 3, Some(1));
+        player.add_persistent_effect(EffectType::IncomeBonus(Money(100)), 1);
+        player.add_persistent_effect(EffectType::ExpenseDiscount(0.25), 1);
+        player.add_persistent_effect(EffectType::LivestockHarvestBonus(1.5), 1);
+
+        let mut op_cost_deck = Deck::new();
+        op_cost_deck.draw_pile = vec![create_op_cost_card(20, GameEffect::Expense(Money(1000))), create_op_cost_card(21, GameEffect::Expense(Money(1000)))];
+        let (income, expense, logs) = harvest_manager.calculate_harvest(&mut player, &HarvestType::Wheat, &mut op_cost_deck).unwrap();
+        // One block of grain pays $800-$7000 on the roll, doubled, plus the bonus
+        assert!([800, 1500, 2500, 3800, 5300, 7000].map(|base| base * 2 + 100).contains(&income.dollars()), "{}", income);
+        assert_eq!(expense, Money(750), "A quarter off the operating expense");
+        assert!(logs.iter().any(|log| log.contains("x2.0 grain bonus") && log.contains("+ $100 bonus")), "{:?}", logs);
+
+        // The multiplier only lasted one harvest; the rest last the year
+        assert_eq!(player.harvest_multiplier(AssetType::Grain), 1.0);
+        assert_eq!(player.harvest_multiplier(AssetType::Cows), 1.5, "Cow bonuses aren't used up by a grain harvest");
+        let (income, _, _) = harvest_manager.calculate_harvest(&mut player, &HarvestType::Wheat, &mut op_cost_deck).unwrap();
+        assert!([800, 1500, 2500, 3800, 5300, 7000].map(|base| base + 100).contains(&income.dollars()), "{}", income);
+
+        player.start_new_year(Money::ZERO);
+        assert!(player.persistent_effects.is_empty(), "Year-long effects wear off as the next year starts");
+    }
+
+    #[test]
+    fn test_machinery_coop_discounts_operating_expenses_but_not_interest() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        EffectResolver::new(&mut game, player_id).resolve(&GameEffect::MachineryCoop { percent: 20, years: 2 }, &mut Vec::new()).unwrap();
+        let mut player = game.players[player_id].clone();
+        player.assets.clear();
+        player.add_asset(AssetType::Grain, 10, Money::ZERO);
+        player.debt = Money(10000);
+
+        let mut harvest_manager = HarvestManager::new();
+        let mut op_cost_deck = Deck::new();
+        op_cost_deck.draw_pile = vec![create_op_cost_card(22, GameEffect::ExpensePerAsset { asset: AssetType::Grain, rate: Money(100) }), create_op_cost_card(23, GameEffect::PayInterest)];
+        let (_, expense, logs) = harvest_manager.calculate_harvest(&mut player, &HarvestType::Wheat, &mut op_cost_deck).unwrap();
+        assert_eq!(expense, Money(800), "$100/acre x 10 acres, 20% off");
+        assert!(logs.iter().any(|log| log.contains("= $1,000")), "The card shows the full expense: {:?}", logs);
+        assert!(logs.iter().any(|log| log.contains("Co-op discount: 20% off saves $200")), "{:?}", logs);
+
+        let (_, expense, logs) = harvest_manager.calculate_harvest(&mut player, &HarvestType::Wheat, &mut op_cost_deck).unwrap();
+        assert_eq!(expense, player.interest_due(INTEREST_RATE), "Interest is due in full");
+        assert!(!logs.iter().any(|log| log.contains("Co-op discount")), "{:?}", logs);
+
+        player.start_new_year(Money::ZERO);
+        assert_eq!(player.expense_discount(), 0.2, "The co-op lasts two years");
+        player.start_new_year(Money::ZERO);
+        assert_eq!(player.expense_discount(), 0.0);
+    }
+
+    #[test]
+    fn test_one_time_multiplier_consumed_by_matching_harvest_only() {
+        let mut op_cost_deck = Deck::new();
+        op_cost_deck.draw_pile = vec![
+            create_op_cost_card(6, GameEffect::Expense(Money::ZERO)),
+            create_op_cost_card(7, GameEffect::Expense(Money::ZERO)),
+            create_op_cost_card(8, GameEffect::Expense(Money::ZERO)),
+        ];
+        let mut harvest_manager = HarvestManager::new();
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Hay, 10), (AssetType::Grain, 10)]));
+        player.set_crop_multiplier(AssetType::Grain, 0.5);
+
+        // A hay harvest must not use up the grain multiplier
+        harvest_manager.calculate_harvest(&mut player, &HarvestType::HayCutting1, &mut op_cost_deck).unwrap();
+        assert_eq!(player.get_crop_multiplier(&AssetType::Grain), 0.5);
+
+        let (_, _, logs) = harvest_manager.calculate_harvest(&mut player, &HarvestType::Wheat, &mut op_cost_deck).unwrap();
+        assert!(logs.iter().any(|log| log.contains("x0.5 multiplier")));
+        assert_eq!(player.get_crop_multiplier(&AssetType::Grain), 1.0, "Multiplier should be consumed exactly once");
+
+        let (_, _, logs) = harvest_manager.calculate_harvest(&mut player, &HarvestType::Corn, &mut op_cost_deck).unwrap();
+        assert!(!logs.iter().any(|log| log.contains("multiplier")));
+    }
+
+    #[test]
+    fn test_harvest_multiplier_does_not_rewrite_income_history() {
+        let mut op_cost_deck = Deck::new();
+        op_cost_deck.draw_pile = vec![create_op_cost_card(9, GameEffect::Expense(Money::ZERO))];
+        let mut harvest_manager = HarvestManager::new();
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Grain, 10)]));
+        player.add_income(AssetType::Grain, Money(4000));
+        player.set_crop_multiplier(AssetType::Grain, 0.5);
+
+        harvest_manager.calculate_harvest(&mut player, &HarvestType::Wheat, &mut op_cost_deck).unwrap();
+        assert_eq!(player.assets[&AssetType::Grain].total_income, Money(4000));
+    }
+
+    #[test]
+    fn test_irrigation_and_barn_raise_their_crops_harvest() {
+        let harvest_income = |assets: HashMap<AssetType, u32>, harvest_type: HarvestType| {
+            let mut op_cost_deck = Deck::new();
+            op_cost_deck.draw_pile = vec![create_op_cost_card(10, GameEffect::Expense(Money::ZERO))];
+            let mut harvest_manager = HarvestManager::new();
+            let mut player = create_test_player(Money(10000), assets);
+            let (income, _, logs) = harvest_manager
+                .calculate_harvest_with(&mut player, &harvest_type, &mut op_cost_deck, &mut StdRng::seed_from_u64(7))
+                .unwrap();
+            (income, logs)
+        };
+
+        let (plain_hay, _) = harvest_income(HashMap::from([(AssetType::Hay, 20)]), HarvestType::HayCutting1);
+        let (irrigated_hay, logs) = harvest_income(HashMap::from([(AssetType::Hay, 20), (AssetType::Irrigation, 1)]), HarvestType::HayCutting1);
+        assert_eq!(irrigated_hay, plain_hay.times(1.25));
+        assert!(logs.iter().any(|log| log.contains("x1.25 irrigation")));
+
+        let (plain_cows, _) = harvest_income(HashMap::from([(AssetType::Cows, 20)]), HarvestType::Livestock);
+        let (barn_cows, _) = harvest_income(HashMap::from([(AssetType::Cows, 20), (AssetType::Barn, 1)]), HarvestType::Livestock);
+        assert_eq!(barn_cows, plain_cows.times(1.2));
+
+        let (irrigated_cows, _) = harvest_income(HashMap::from([(AssetType::Cows, 20), (AssetType::Irrigation, 1)]), HarvestType::Livestock);
+        assert_eq!(irrigated_cows, plain_cows, "Irrigation does nothing for the herd");
+    }
+
+    #[test]
+    fn test_harvest_draws_from_the_games_operating_cost_deck() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let draw_pile = game.operating_cost_deck.draw_pile.len();
+        let discard_pile = game.operating_cost_deck.discard_pile.len();
+
+        game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        assert_eq!(game.operating_cost_deck.draw_pile.len(), draw_pile - 1);
+        assert_eq!(game.operating_cost_deck.discard_pile.len(), discard_pile + 1,
+            "The expense card goes to the same discard pile tiles use");
+    }
+
+    #[test]
+    fn test_harvest_pays_its_operating_expense_once() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let player = &mut game.players[player_id];
+        player.assets.clear();
+        player.add_asset(AssetType::Hay, 10, Money::ZERO);
+        player.cash = Money(10000);
+        game.operating_cost_deck.draw_pile = vec![create_op_cost_card(13, GameEffect::Expense(Money(300)))];
+
+        game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        let player = &game.players[player_id];
+        let (_, income) = *player.harvests_collected.last().unwrap();
+        assert!(income >= Money(400), "Income is recorded before the expense comes out");
+        assert_eq!(player.cash, Money(10000) + income - Money(300));
+    }
+
+    #[test]
+    fn test_bad_at_taxes_harvest_draws_the_expense_but_brings_in_nothing() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let player = &mut game.players[player_id];
+        player.assets.clear();
+        player.add_asset(AssetType::Hay, 10, Money::ZERO);
+        player.cash = Money(10000);
+        game.operating_cost_deck.draw_pile = vec![
+            create_op_cost_card(14, GameEffect::Expense(Money(300))),
+            create_op_cost_card(15, GameEffect::Expense(Money::ZERO)),
+        ];
+        let bad_at_taxes = create_op_cost_card(16, GameEffect::DrawOperatingExpenseNoHarvest);
+        game.apply_card_effect(player_id, &bad_at_taxes, &mut Vec::new()).unwrap();
+
+        let logs = game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        assert!(logs.iter().any(|log| log.contains("IRS garnishes")));
+        assert_eq!(game.operating_cost_deck.discard_pile.len(), 1, "The expense card is still drawn and discarded");
+        let player = &game.players[player_id];
+        assert_eq!(player.cash, Money(9700));
+        assert_eq!(player.harvests_collected.last(), Some(&(HarvestType::HayCutting1, Money::ZERO)));
+
+        game.process_harvest(player_id, HarvestType::HayCutting2).unwrap();
+        assert!(game.players[player_id].harvests_collected.last().unwrap().1 > Money::ZERO, "Only one harvest is garnished");
+    }
+
+    #[test]
+    fn test_a_garnish_waits_through_the_year_end_for_the_next_harvest() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let player = &mut game.players[player_id];
+        player.assets.clear();
+        player.add_asset(AssetType::Hay, 10, Money::ZERO);
+        player.cash = Money(10000);
+        game.operating_cost_deck.draw_pile = vec![create_op_cost_card(14, GameEffect::Expense(Money(300)))];
+
+        EffectResolver::new(&mut game, player_id).resolve(&GameEffect::SuppressHarvestIncome, &mut Vec::new()).unwrap();
+        assert!(game.players[player_id].eligible_for_side_job_pay, "Only Bad at Taxes costs the wage too");
+        game.players[player_id].start_new_year(Money::ZERO);
+        assert!(game.players[player_id].harvest_income_garnished, "No harvest came before the year ended");
+
+        game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        let player = &game.players[player_id];
+        assert_eq!(player.cash, Money(9700), "The expense is paid and nothing comes in");
+        assert!(!player.harvest_income_garnished, "The garnish is used up");
+    }
+
+    #[test]
+    fn test_each_hay_cutting_pays_once_a_year() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let player = &mut game.players[player_id];
+        player.assets.clear();
+        player.add_asset(AssetType::Hay, 10, Money::ZERO);
+        player.cash = Money(10000);
+
+        game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        let cash = game.players[player_id].cash;
+        let logs = game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        assert!(logs[0].contains("already collected"), "{:?}", logs);
+        assert_eq!(game.players[player_id].cash, cash, "A second tile of the same cutting pays nothing");
+        assert_eq!(game.players[player_id].harvests_collected.len(), 1);
+
+        game.process_harvest(player_id, HarvestType::HayCutting2).unwrap();
+        let year = game.players[player_id].year;
+        assert_eq!(game.players[player_id].hay_cuttings_in_year(year), 2);
+
+        game.players[player_id].year += 1;
+        assert_eq!(game.players[player_id].hay_cuttings_in_year(year + 1), 0);
+        game.process_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        assert_eq!(game.players[player_id].harvests_collected.len(), 3, "A new year brings a new first cutting");
+    }
+
+    #[test]
+    fn test_preview_harvest_reports_range_modifiers_and_expense_odds() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[0];
+        let player = &mut game.players[player_id];
+        player.assets.clear();
+        player.add_asset(AssetType::Hay, 20, Money::ZERO);
+        player.add_asset(AssetType::Irrigation, 1, Money::ZERO);
+        let cash = player.cash;
+        game.operating_cost_deck.draw_pile = vec![
+            create_op_cost_card(11, GameEffect::Expense(Money::ZERO)),
+            create_op_cost_card(12, GameEffect::Expense(Money(600))),
+        ];
+
+        let preview = game.preview_harvest(player_id, HarvestType::HayCutting1).unwrap();
+        assert_eq!(preview.quantity, 20);
+        assert_eq!(preview.modifiers, vec![YieldModifier::Upgrade(AssetType::Irrigation, 1.25)]);
+        // Two blocks of hay pay $800-$6000 before irrigation
+        assert_eq!(preview.income_range, (Money(1000), Money(7500)));
+        assert_eq!(preview.expected_income, Money(3625));
+        assert_eq!(preview.expense, ExpenseOdds { cards: 2, free: 1, min: Money::ZERO, max: Money(600), average: Money(300) });
+
+        assert_eq!(game.operating_cost_deck.draw_pile.len(), 2, "Previewing draws nothing");
+        assert_eq!(game.players[player_id].cash, cash);
+
+        let apples = game.preview_harvest(player_id, HarvestType::Apple).unwrap();
+        assert_eq!((apples.quantity, apples.income_range, apples.expense), (0, (Money::ZERO, Money::ZERO), ExpenseOdds::default()),
+            "No fruit means no harvest and no expense card");
+    }
+
+    #[test]
+    fn test_difficulty_scales_harvest_income_and_operating_expenses() {
+        let mut harvest_manager = HarvestManager::new();
+        harvest_manager.apply_rules(&GameRules::default().with_difficulty(Difficulty::Hard));
+        let mut op_cost_deck = Deck::new();
+        op_cost_deck.draw_pile = vec![create_op_cost_card(1, GameEffect::Expense(Money(500)))];
+        let mut player = create_test_player(Money(10000), HashMap::from([(AssetType::Hay, 20)]));
+
+        let preview = harvest_manager.preview(&player, &HarvestType::HayCutting1, &op_cost_deck);
+        assert_eq!(preview.modifiers, vec![YieldModifier::Difficulty(0.85)]);
+        // Two blocks of hay pay $800-$6000 at normal difficulty
+        assert_eq!(preview.income_range, (Money(680), Money(5100)));
+
+        let (income, expense, logs) = harvest_manager.calculate_harvest(&mut player, &HarvestType::HayCutting1, &mut op_cost_deck).unwrap();
+        assert_eq!(expense, Money(625));
+        assert!([680, 1020, 1700, 2550, 3740, 5100].contains(&income.dollars()), "{} isn't a hard-difficulty hay income", income);
+        assert!(logs.iter().any(|log| log.contains("x0.85 difficulty")));
+    }
+
     // Simple test to ensure test framework is working
     #[test]
     fn it_works() { 
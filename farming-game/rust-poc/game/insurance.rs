@@ -0,0 +1,114 @@
+// src/game/insurance.rs
+// Yearly crop and livestock insurance against disaster cards.
+
+use crate::game::GameError;
+use crate::models::{AssetType, GameState, LedgerCategory, Money};
+
+/// What a claim pays per insured cow lost, i.e. what the cow cost to buy.
+pub const LIVESTOCK_CLAIM_PER_HEAD: Money = Money(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coverage {
+    /// Grain, Hay and Fruit acres
+    Crop,
+    /// Cows
+    Livestock,
+}
+
+impl Coverage {
+    pub const ALL: [Coverage; 2] = [Coverage::Crop, Coverage::Livestock];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Coverage::Crop => "Crop",
+            Coverage::Livestock => "Livestock",
+        }
+    }
+
+    /// Acres or head the coverage is priced on.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Coverage::Crop => "acre",
+            Coverage::Livestock => "head",
+        }
+    }
+
+    pub fn covers(&self, asset: &AssetType) -> bool {
+        match self {
+            Coverage::Crop => matches!(asset, AssetType::Grain | AssetType::Hay | AssetType::Fruit),
+            Coverage::Livestock => matches!(asset, AssetType::Cows),
+        }
+    }
+}
+
+/// A policy bought in Spring Planting. It covers the units held when it was
+/// bought and lapses when the player passes Go.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsurancePolicy {
+    pub coverage: Coverage,
+    pub insured_units: u32,
+    pub premium: Money,
+}
+
+impl GameState {
+    /// Units the player would insure and the premium for them.
+    pub fn insurance_quote(&self, player_id: usize, coverage: Coverage) -> Result<(u32, Money), GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let units: u32 = player.assets.iter()
+            .filter(|(asset, _)| coverage.covers(asset))
+            .map(|(_, record)| record.quantity)
+            .sum();
+        let rate = match coverage {
+            Coverage::Crop => self.rules.crop_insurance_premium,
+            Coverage::Livestock => self.rules.livestock_insurance_premium,
+        };
+        Ok((units, units * rate))
+    }
+
+    /// Policies can only be bought at the start of the year, before the crops go in.
+    pub fn can_buy_insurance(&self, player_id: usize) -> bool {
+        self.player_phase(player_id).allows_insurance()
+    }
+
+    /// Buys a policy for this year with cash.
+    pub fn buy_insurance(&mut self, player_id: usize, coverage: Coverage) -> Result<String, GameError> {
+        if !self.can_buy_insurance(player_id) {
+            return Err(GameError::InsuranceUnavailable("policies are only sold during Spring Planting".to_string()));
+        }
+        let (units, premium) = self.insurance_quote(player_id, coverage)?;
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        if player.insurance_policy(coverage).is_some() {
+            return Err(GameError::InsuranceUnavailable(format!("{} insurance already bought this year", coverage.label())));
+        }
+        if units == 0 {
+            return Err(GameError::InsuranceUnavailable(format!("nothing to insure under {} coverage", coverage.label())));
+        }
+        if player.cash < premium {
+            return Err(GameError::InsufficientFunds { required: premium, available: player.cash });
+        }
+
+        let mut logs = Vec::new();
+        self.debit(player_id, premium, LedgerCategory::Insurance, &mut logs)?;
+        let player = &mut self.players[player_id];
+        player.insurance.push(InsurancePolicy { coverage, insured_units: units, premium });
+        tracing::info!(player_id, ?coverage, units, %premium, "insurance bought");
+        let key = format!("insurance.bought.{}", coverage.label().to_lowercase());
+        Ok(self.locale.tr(&key, &[("player", &player.name), ("units", &units), ("premium", &premium)]))
+    }
+
+    /// Pays out on a disaster loss of `units_lost` at `loss_per_unit`, up to the
+    /// insured units. Returns the amount paid, 0 when uninsured.
+    pub fn claim_insurance(&mut self, player_id: usize, coverage: Coverage, units_lost: u32, loss_per_unit: Money, logs: &mut Vec<String>) -> Money {
+        let Some(player) = self.players.get_mut(player_id) else { return Money::ZERO };
+        let Some(policy) = player.insurance_policy(coverage) else { return Money::ZERO };
+        let payout = units_lost.min(policy.insured_units) * loss_per_unit;
+        if payout > Money::ZERO {
+            player.cash += payout;
+            player.record_transaction(LedgerCategory::Insurance, payout);
+            let key = format!("insurance.payout.{}", coverage.label().to_lowercase());
+            logs.push(self.locale.tr(&key, &[("player", &player.name), ("amount", &payout)]));
+            tracing::info!(player_id, ?coverage, %payout, "insurance claim paid");
+        }
+        payout
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::config::GameRules;
+    use crate::game::{Coverage, GameEffect, GameError};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    /// One farmer in January with 10 cows and Grandpa's 10 hay and 10 grain acres.
+    fn setup_insured_game(cash: Money) -> GameState {
+        let mut players = PlayerRegistry::new();
+        let mut player = Player::new(0, "Farmer".to_string(), PlayerType::Human);
+        player.cash = cash;
+        player.position = 2;
+        player.add_asset(AssetType::Cows, 10, Money(5000));
+        players.insert(player);
+        GameState::new_with_players(players, vec![0], GameRules::default())
+    }
+
+    #[test]
+    fn test_buy_insurance_charges_premium_per_unit() {
+        let mut game = setup_insured_game(Money(5000));
+
+        assert_eq!(game.insurance_quote(0, Coverage::Livestock).unwrap(), (10, Money(500)));
+        assert_eq!(game.insurance_quote(0, Coverage::Crop).unwrap(), (20, Money(400)));
+        game.buy_insurance(0, Coverage::Livestock).unwrap();
+        game.buy_insurance(0, Coverage::Crop).unwrap();
+
+        let player = &game.players[0];
+        assert_eq!(player.cash, Money(5000) - Money(500) - Money(400));
+        assert_eq!(player.insurance_policy(Coverage::Livestock).unwrap().insured_units, 10);
+        assert_eq!(player.insurance_policy(Coverage::Crop).unwrap().insured_units, 20);
+    }
+
+    #[test]
+    fn test_buy_insurance_rejected_outside_window_twice_or_without_cash() {
+        let mut game = setup_insured_game(Money(5000));
+        game.buy_insurance(0, Coverage::Livestock).unwrap();
+        assert!(matches!(game.buy_insurance(0, Coverage::Livestock), Err(GameError::InsuranceUnavailable(_))));
+
+        game.players.get_mut(0).unwrap().cash = Money(100);
+        assert_eq!(
+            game.buy_insurance(0, Coverage::Crop),
+            Err(GameError::InsufficientFunds { required: Money(400), available: Money(100) })
+        );
+
+        game.players.get_mut(0).unwrap().cash = Money(5000);
+        game.players.get_mut(0).unwrap().position = 30;
+        assert!(matches!(game.buy_insurance(0, Coverage::Crop), Err(GameError::InsuranceUnavailable(_))));
+    }
+
+    #[test]
+    fn test_livestock_insurance_pays_for_slaughtered_cows() {
+        let mut game = setup_insured_game(Money(5000));
+        game.buy_insurance(0, Coverage::Livestock).unwrap();
+        let cash_after_premium = game.players[0].cash;
+        let card = Card {
+            id: 900,
+            title: "Slaughter".to_string(),
+            description: "Cows slaughtered".to_string(),
+            description_brief: "Cows slaughtered".to_string(),
+            effect: GameEffect::SlaughterCowsWithoutCompensation,
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        };
+
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &card, &mut logs).unwrap();
+
+        let player = &game.players[0];
+        assert_eq!(player.assets[&AssetType::Cows].quantity, 0);
+        assert_eq!(player.cash, cash_after_premium + Money(10) * 500);
+    }
+
+    #[test]
+    fn test_policies_lapse_at_new_year() {
+        let mut game = setup_insured_game(Money(5000));
+        game.buy_insurance(0, Coverage::Crop).unwrap();
+
+        let player = game.players.get_mut(0).unwrap();
+        player.start_new_year(Money::ZERO);
+        assert!(player.insurance.is_empty());
+
+        let mut logs = Vec::new();
+        assert_eq!(game.claim_insurance(0, Coverage::Crop, 10, Money(100), &mut logs), Money::ZERO);
+        assert!(logs.is_empty());
+    }
+}
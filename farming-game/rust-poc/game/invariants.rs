@@ -0,0 +1,116 @@
+// src/game/invariants.rs
+// Consistency checks over a whole GameState, used by tests and fuzzing.
+
+use std::collections::HashSet;
+use thiserror::Error;
+use crate::cards::catalogs::{farmers_fate_catalog, operating_expense_catalog, option_to_buy_catalog};
+use crate::cards::{card::Card, deck::Deck};
+use crate::models::{GameState, Money};
+
+/// A rule the game state should always satisfy between actions.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InvariantViolation {
+    #[error("Current turn index {index} is out of range for {players} players")]
+    TurnIndexOutOfRange { index: usize, players: usize },
+
+    #[error("Turn order lists player {0}, who is not in the game")]
+    UnknownPlayerInTurnOrder(usize),
+
+    #[error("Player {player_id} is still playing with ${cash} cash")]
+    NegativeCash { player_id: usize, cash: Money },
+
+    #[error("Player {player_id} owes ${debt}, above the ${max_debt} debt limit")]
+    DebtOverLimit { player_id: usize, debt: Money, max_debt: Money },
+
+    #[error("Player {player_id}'s net worth is ${recorded}, but their holdings add up to ${expected}")]
+    NetWorthMismatch { player_id: usize, recorded: Money, expected: Money },
+
+    #[error("{deck} deck accounts for {found} of {expected} cards")]
+    CardCountMismatch { deck: &'static str, found: usize, expected: usize },
+
+    #[error("{deck} card {card_id} is in more than one place")]
+    DuplicateCard { deck: &'static str, card_id: usize },
+}
+
+impl GameState {
+    /// Checks every invariant and returns all violations found.
+    pub fn check_invariants(&self) -> Result<(), Vec<InvariantViolation>> {
+        let mut violations = Vec::new();
+
+        if self.current_turn_index >= self.turn_order.len() {
+            violations.push(InvariantViolation::TurnIndexOutOfRange {
+                index: self.current_turn_index,
+                players: self.turn_order.len(),
+            });
+        }
+        for id in &self.turn_order {
+            if !self.players.contains(*id) {
+                violations.push(InvariantViolation::UnknownPlayerInTurnOrder(*id));
+            }
+        }
+
+        for player in self.players.iter() {
+            let player_id = player.id;
+
+            // Payments borrow whatever cash doesn't cover, so only a player
+            // who couldn't borrow enough, and is out, can be overdrawn
+            if player.is_active && player.cash < Money::ZERO {
+                violations.push(InvariantViolation::NegativeCash { player_id, cash: player.cash });
+            }
+            if player.debt > self.rules.max_debt {
+                violations.push(InvariantViolation::DebtOverLimit {
+                    player_id, debt: player.debt, max_debt: self.rules.max_debt,
+                });
+            }
+
+            // A payment applied twice (or skipped) leaves the recorded net worth
+            // out of step with what the player actually holds
+            let expected = player.cash - player.debt
+                + self.value_table.assets_value(player)
+                + self.value_table.ridge_value(player_id, &self.ridges)
+                + self.value_table.land_value(player);
+            if player.net_worth != expected {
+                violations.push(InvariantViolation::NetWorthMismatch {
+                    player_id, recorded: player.net_worth, expected,
+                });
+            }
+        }
+
+        let hands: Vec<&Card> = self.players.iter().flat_map(|p| p.hand.iter()).collect();
+        let held: Vec<&Card> = self.players.iter()
+            .flat_map(|p| p.active_persistent_cards.iter().map(|(card, _)| card))
+            .collect();
+
+        check_conservation("Farmer's Fate", &self.farmer_fate_deck, &held, &farmers_fate_catalog(), &mut violations);
+        check_conservation("Option to Buy", &self.option_to_buy_deck, &hands, &option_to_buy_catalog(), &mut violations);
+        check_conservation("Operating Cost", &self.operating_cost_deck, &[], &operating_expense_catalog(), &mut violations);
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Every card of a deck must be in its draw pile, its discard pile, or with a
+/// player, and in exactly one of those places.
+fn check_conservation(deck_name: &'static str, deck: &Deck, outside: &[&Card], catalog: &[Card],
+    violations: &mut Vec<InvariantViolation>) {
+    let catalog_ids: HashSet<usize> = catalog.iter().map(|c| c.id).collect();
+
+    let mut seen = HashSet::new();
+    let mut found = 0;
+    let cards = deck.draw_pile.iter()
+        .chain(deck.discard_pile.iter())
+        .chain(outside.iter().copied().filter(|c| catalog_ids.contains(&c.id)));
+    for card in cards {
+        found += 1;
+        if !seen.insert(card.id) {
+            violations.push(InvariantViolation::DuplicateCard { deck: deck_name, card_id: card.id });
+        }
+    }
+    if found != catalog.len() {
+        violations.push(InvariantViolation::CardCountMismatch { deck: deck_name, found, expected: catalog.len() });
+    }
+}
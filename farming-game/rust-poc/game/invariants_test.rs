@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{Action, GameEngine, GameError, InvariantViolation};
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 7).unwrap()
+    }
+
+    #[test]
+    fn test_fresh_game_satisfies_invariants() {
+        assert_eq!(engine().state().check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_detects_broken_state() {
+        let mut engine = engine();
+        let state = engine.state_mut();
+        state.current_turn_index = 5;
+        state.players.get_mut(0).unwrap().cash += Money(1000); // not reflected in net worth
+        state.players.get_mut(1).unwrap().cash = Money(-50);
+        state.farmer_fate_deck.draw_pile.pop();
+
+        let violations = state.check_invariants().unwrap_err();
+        assert!(violations.contains(&InvariantViolation::TurnIndexOutOfRange { index: 5, players: 2 }));
+        assert!(violations.iter().any(|v| matches!(v, InvariantViolation::NetWorthMismatch { player_id: 0, .. })));
+        assert!(violations.contains(&InvariantViolation::NegativeCash { player_id: 1, cash: Money(-50) }));
+        assert!(violations.iter().any(|v| matches!(v, InvariantViolation::CardCountMismatch { deck: "Farmer's Fate", .. })));
+    }
+
+    #[test]
+    fn test_exercised_option_returns_to_deck() {
+        let mut engine = engine();
+        let player_id = engine.current_player_id();
+        let card_id = engine.state().players[player_id].hand[0].id;
+
+        engine.apply(Action::ExerciseOption { card_id, confirm_loan: true }).unwrap();
+        assert!(engine.state().option_to_buy_deck.discard_pile.iter().any(|c| c.id == card_id));
+        assert_eq!(engine.state().check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_pay_loan_without_debt_is_rejected() {
+        let mut engine = engine();
+        let player_id = engine.current_player_id();
+        engine.state_mut().players.get_mut(player_id).unwrap().debt = Money::ZERO;
+        assert_eq!(engine.apply(Action::PayLoan(Money(500))), Err(GameError::NoDebt));
+    }
+}
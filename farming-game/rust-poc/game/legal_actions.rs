@@ -0,0 +1,99 @@
+// src/game/legal_actions.rs
+// What a player may do right now, for front ends to build their menus from
+// and for AI and network clients to pick a move from or check one against.
+// Amounts listed are the most allowed: smaller loan payments in whole payment
+// increments, and fewer cows, are legal too.
+
+use crate::game::{Action, Decision, DecisionKind, DecisionRequest, GameEvent};
+use crate::models::{AssetType, GameState, Money};
+
+impl GameState {
+    /// Every move `player_id` can make right now. While a decision is pending
+    /// only its answers are listed, and only to the player it asks; otherwise
+    /// the current player rolls, then has the turn menu's options until they
+    /// end the turn. Nobody moves once the game is won.
+    pub fn legal_actions(&self, player_id: usize) -> Vec<Action> {
+        if self.winner().is_some() {
+            return Vec::new();
+        }
+        if let Some(request) = self.pending_decision() {
+            return if request.player_id == player_id { self.decision_answers(request) } else { Vec::new() };
+        }
+        if self.turn_order.get(self.current_turn_index) != Some(&player_id) {
+            return Vec::new();
+        }
+        let Some(player) = self.players.get(player_id) else { return Vec::new() };
+        if !player.is_active {
+            return vec![Action::EndTurn];
+        }
+        if !self.has_rolled(player_id) {
+            return vec![Action::Roll];
+        }
+        let mut actions = self.turn_actions(player_id);
+        actions.push(Action::EndTurn);
+        actions
+    }
+
+    /// What `player_id` can do between rolling and ending their turn: the
+    /// options the turn menu offers.
+    pub fn turn_actions(&self, player_id: usize) -> Vec<Action> {
+        let Some(player) = self.players.get(player_id) else { return Vec::new() };
+        let mut actions = Vec::new();
+        if self.can_exercise_option_to_buy(player_id) {
+            for card in self.get_option_to_buy_cards(player_id) {
+                let confirm_loan = [false, true].into_iter()
+                    .find(|confirm_loan| self.check_option_to_buy(player_id, card.id, *confirm_loan).is_ok());
+                if let Some(confirm_loan) = confirm_loan {
+                    actions.push(Action::ExerciseOption { card_id: card.id, confirm_loan });
+                }
+            }
+        }
+
+        // Everything owed if cash covers it, else as many whole payment increments as it does
+        let increment = self.rules.payment_increment.max(Money(1)).dollars();
+        let payment = if player.cash >= player.debt { player.debt } else { Money(player.cash.dollars() - player.cash.dollars() % increment) };
+        if payment > Money::ZERO {
+            actions.push(Action::PayLoan(payment));
+        }
+
+        let cows = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+        if cows > 0 {
+            actions.push(Action::SellCows(i32::try_from(cows).unwrap_or(i32::MAX)));
+        }
+        for ridge in self.leased_ridges(player_id) {
+            let (up, down) = self.movable_cows(player_id, ridge);
+            for cows in [up, -down].into_iter().filter(|cows| *cows != 0) {
+                actions.push(Action::MoveCows { ridge: ridge.name.clone(), cows });
+            }
+        }
+        actions
+    }
+
+    /// Whether `player_id` has rolled to move since their turn began.
+    fn has_rolled(&self, player_id: usize) -> bool {
+        self.events.iter().rev()
+            .take_while(|event| !matches!(event, GameEvent::TurnStarted { .. }))
+            .any(|event| matches!(event, GameEvent::DiceRolled { player_id: id, .. } if *id == player_id))
+    }
+
+    /// Every answer `request` accepts; sales toward a bill offer all the
+    /// player holds of each asset.
+    fn decision_answers(&self, request: &DecisionRequest) -> Vec<Action> {
+        let answers = match request.kind {
+            DecisionKind::YesNo => vec![Decision::Yes, Decision::No],
+            DecisionKind::Quantity { min, max } => (min..=max).map(Decision::Quantity).collect(),
+            DecisionKind::Roll => vec![Decision::Roll],
+            DecisionKind::RaiseCash { .. } => self.sellable_assets(request.player_id).iter()
+                .map(|sellable| Decision::Sell { asset: sellable.asset, quantity: sellable.owned })
+                .chain([Decision::Yes])
+                .collect(),
+            DecisionKind::Price { max, step } => {
+                let step = step.max(Money(1));
+                (0..=max.dollars() / step.dollars()).map(|units| Decision::Price(step * units)).collect()
+            }
+            DecisionKind::Discard { .. } => self.players.get(request.player_id)
+                .map_or_else(Vec::new, |player| player.hand.iter().map(|card| Decision::Discard { card_id: card.id }).collect()),
+        };
+        answers.into_iter().map(Action::Decide).collect()
+    }
+}
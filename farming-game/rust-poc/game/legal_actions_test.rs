@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::{Action, Decision, GameEffect, GameEngine, GameEvent, GameStateBuilder};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerType};
+    use crate::test_support::card_with;
+
+    /// Ann and Bo. Ann has $3,500, owes $5,000, keeps 8 cows on the farm and
+    /// 25 on Rattlesnake Ridge, and holds a tractor option she can only
+    /// afford with a loan and a lease on the ridge she already has.
+    fn setup_game() -> GameState {
+        let mut ann = Player::new(0, "Ann".to_string(), PlayerType::Human);
+        ann.cash = Money(3500);
+        ann.debt = Money(5000);
+        ann.add_asset(AssetType::Cows, 8, Money(4000));
+        ann.hand.push(card_with(901, GameEffect::OptionalBuyAsset { asset: AssetType::Tractor, quantity: 1, cost: Money(10000) }));
+        ann.hand.push(card_with(902, GameEffect::LeaseRidge { name: "Rattlesnake Ridge".to_string(), cost: Money(30000), cow_count: 30 }));
+        let mut game = GameStateBuilder::default().add_player(ann).add_human("Bo").build().unwrap();
+        game.ridges[3].lease(0, 25, 15).unwrap();
+        game
+    }
+
+    #[test]
+    fn test_the_current_player_rolls_then_picks_from_the_turn_menu() {
+        let mut game = setup_game();
+        assert_eq!(game.legal_actions(0), vec![Action::Roll]);
+        assert!(game.legal_actions(1).is_empty(), "Not their turn");
+
+        game.emit(GameEvent::DiceRolled { player_id: 0, roll: 4 });
+        assert_eq!(game.legal_actions(0), vec![
+            Action::ExerciseOption { card_id: 901, confirm_loan: true },
+            Action::PayLoan(Money(3000)),
+            Action::SellCows(8),
+            Action::MoveCows { ridge: "Rattlesnake Ridge".to_string(), cows: 5 },
+            Action::MoveCows { ridge: "Rattlesnake Ridge".to_string(), cows: -12 },
+            Action::EndTurn,
+        ]);
+    }
+
+    #[test]
+    fn test_the_next_turn_starts_with_a_roll() {
+        let mut engine = GameEngine::new(setup_game());
+        engine.apply(Action::EndTurn).unwrap();
+        assert_eq!(engine.legal_actions(1), vec![Action::Roll]);
+        assert!(engine.legal_actions(0).is_empty());
+    }
+
+    #[test]
+    fn test_a_pending_decision_lists_its_answers_for_the_player_asked() {
+        let mut game = setup_game();
+        let card = card_with(900, GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 3, cost: Money(1500) });
+        game.apply_card_effect(1, &card, &mut Vec::new()).unwrap();
+
+        assert_eq!(game.legal_actions(1), (0..=3).map(|cows| Action::Decide(Decision::Quantity(cows))).collect::<Vec<_>>());
+        assert!(game.legal_actions(0).is_empty(), "The current player waits for the answer");
+        for action in game.legal_actions(1) {
+            let Action::Decide(decision) = action else { unreachable!() };
+            assert!(game.pending_decision().unwrap().accepts(decision));
+        }
+    }
+}
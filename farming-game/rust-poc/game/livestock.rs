@@ -0,0 +1,61 @@
+// src/game/livestock.rs
+// Moving cows between the farm and the ridges a player leases, any time on
+// their turn. The farm holds no more than the farm cow limit and each ridge
+// no more than it was leased to carry. Cows are worth the same on either
+// (see `ValueTable::ridge_value`), so moving them never changes net worth;
+// cows brought down carry no purchase cost onto the farm.
+
+use crate::game::{constraints, GameError};
+use crate::models::{AssetType, GameState, Money, Ridge};
+
+impl GameState {
+    /// The ridges `player_id` leases, in board order.
+    pub fn leased_ridges(&self, player_id: usize) -> Vec<&Ridge> {
+        self.ridges.iter().filter(|ridge| ridge.leased_by == Some(player_id)).collect()
+    }
+
+    /// Most cows `player_id` could move up to `ridge` (what the farm has and
+    /// the ridge has room for) and down from it (what the ridge has and the
+    /// farm has room for).
+    pub fn movable_cows(&self, player_id: usize, ridge: &Ridge) -> (i32, i32) {
+        let Some(player) = self.players.get(player_id) else { return (0, 0) };
+        let on_farm = i32::try_from(player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity)).unwrap_or(i32::MAX);
+        let farm_room = i32::try_from(constraints::room_for(&self.rules, player, AssetType::Cows)).unwrap_or(i32::MAX);
+        let up = on_farm.min(ridge.initial_cow_count - ridge.cow_count).max(0);
+        let down = ridge.cow_count.min(farm_room).max(0);
+        (up, down)
+    }
+
+    /// Moves `cows` of the player's farm cows up to `ridge`, or that many of
+    /// the ridge's cows down to the farm when `cows` is negative.
+    pub fn move_cows(&mut self, player_id: usize, ridge: &str, cows: i32) -> Result<String, GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let index = self.ridges.iter()
+            .position(|r| r.name == ridge && r.leased_by == Some(player_id))
+            .ok_or_else(|| GameError::RidgeNotFound(ridge.to_string()))?;
+        let count = cows.unsigned_abs();
+        let on_farm = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+        let key = if cows > 0 {
+            if count > on_farm {
+                return Err(GameError::InsufficientAssets { asset: AssetType::Cows, requested: count, available: on_farm });
+            }
+            self.ridges[index].add_cows(cows).map_err(GameError::RidgeStocking)?;
+            self.players[player_id].remove_asset(AssetType::Cows, count);
+            "ridge.cows_moved_up"
+        } else if cows < 0 {
+            self.check_farm_limit(player_id, AssetType::Cows, count)?;
+            self.ridges[index].remove_cows(cows.saturating_neg()).map_err(GameError::RidgeStocking)?;
+            self.players[player_id].acquire_asset(AssetType::Cows, count, Money::ZERO);
+            "ridge.cows_moved_down"
+        } else {
+            return Err(GameError::RidgeStocking("no cows to move".to_string()));
+        };
+        self.update_player_valuation(player_id);
+
+        let on_ridge = self.ridges[index].cow_count;
+        let on_farm = self.players[player_id].assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+        tracing::info!(player_id, ridge, cows, on_ridge, on_farm, "moved cows");
+        Ok(self.locale.tr(key, &[("player", &self.players[player_id].name), ("cows", &count), ("ridge", &ridge),
+            ("on_ridge", &on_ridge), ("on_farm", &on_farm)]))
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::{Action, GameEngine, GameError, GameStateBuilder};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerType};
+
+    /// One rancher with 12 cows on the farm and 15 on Ahtanum Ridge (capacity 20).
+    fn setup_game() -> GameState {
+        let mut player = Player::new(0, "Rancher".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Cows, 12, Money(6000));
+        let mut game = GameStateBuilder::default().add_player(player).build().unwrap();
+        game.ridges[1].lease(0, 15, 10).unwrap();
+        game
+    }
+
+    fn farm_cows(game: &GameState) -> u32 {
+        game.players[0].assets.get(&AssetType::Cows).map_or(0, |record| record.quantity)
+    }
+
+    #[test]
+    fn test_cows_move_up_and_back_down() {
+        let mut game = setup_game();
+
+        let log = game.move_cows(0, "Ahtanum Ridge", 5).unwrap();
+        assert_eq!(log, "Rancher moved 5 cows up to Ahtanum Ridge. Ridge: 20, farm: 7.");
+        assert_eq!((game.ridges[1].cow_count, farm_cows(&game)), (20, 7));
+
+        let log = game.move_cows(0, "Ahtanum Ridge", -13).unwrap();
+        assert_eq!(log, "Rancher brought 13 cows down from Ahtanum Ridge. Ridge: 7, farm: 20.");
+        assert_eq!((game.ridges[1].cow_count, farm_cows(&game)), (7, 20));
+        assert_eq!(game.get_player_ridges(0), vec!["Ahtanum Ridge: 7 of 20 cows".to_string()]);
+    }
+
+    #[test]
+    fn test_moves_stay_within_the_ridge_and_the_farm() {
+        let mut game = setup_game();
+
+        assert!(matches!(game.move_cows(0, "Ahtanum Ridge", 6), Err(GameError::RidgeStocking(_))), "The ridge carries 20");
+        assert!(matches!(game.move_cows(0, "Ahtanum Ridge", -9), Err(GameError::AssetLimitExceeded { limit: 20, .. })));
+        assert!(matches!(game.move_cows(0, "Ahtanum Ridge", 0), Err(GameError::RidgeStocking(_))));
+
+        game.players[0].remove_asset(AssetType::Cows, 10);
+        assert!(matches!(game.move_cows(0, "Ahtanum Ridge", 3), Err(GameError::InsufficientAssets { available: 2, .. })));
+        assert!(matches!(game.move_cows(0, "Ahtanum Ridge", -16), Err(GameError::RidgeStocking(_))), "Only 15 up there");
+        assert_eq!((game.ridges[1].cow_count, farm_cows(&game)), (15, 2), "Failed moves change nothing");
+    }
+
+    #[test]
+    fn test_moving_cows_leaves_net_worth_alone() {
+        let mut game = setup_game();
+        game.update_player_valuation(0);
+        let net_worth = game.players[0].net_worth;
+
+        game.move_cows(0, "Ahtanum Ridge", 5).unwrap();
+        assert_eq!(game.players[0].net_worth, net_worth);
+        game.move_cows(0, "Ahtanum Ridge", -13).unwrap();
+        assert_eq!(game.players[0].net_worth, net_worth);
+        game.move_cows(0, "Ahtanum Ridge", 8).unwrap();
+        assert_eq!(game.players[0].net_worth, net_worth, "Back where it started");
+    }
+
+    #[test]
+    fn test_only_leased_ridges_take_cows() {
+        let mut engine = GameEngine::new(setup_game());
+
+        let unleased = Action::MoveCows { ridge: "Toppenish Ridge".to_string(), cows: 2 };
+        assert!(matches!(engine.apply(unleased), Err(GameError::RidgeNotFound(_))));
+
+        let logs = engine.apply(Action::MoveCows { ridge: "Ahtanum Ridge".to_string(), cows: 2 }).unwrap();
+        assert!(logs[0].contains("moved 2 cows up"));
+        assert_eq!(engine.state().ridges[1].cow_count, 17);
+        assert_eq!(engine.state().leased_ridges(0).len(), 1);
+    }
+}
@@ -0,0 +1,83 @@
+// src/game/manager.rs
+
+use std::collections::BTreeMap;
+use serde::Serialize;
+use crate::config::GameRules;
+use crate::game::engine::GameEngine;
+use crate::game::GameError;
+
+/// Identifies a game hosted by a `GameManager`.
+pub type GameId = u64;
+
+/// Overview of a hosted game, for lobby listings.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameSummary {
+    pub id: GameId,
+    pub player_names: Vec<String>,
+    pub current_player: String,
+    pub winner: Option<String>,
+}
+
+/// Hosts any number of independent games, e.g. behind a server.
+#[derive(Debug, Default)]
+pub struct GameManager {
+    games: BTreeMap<GameId, GameEngine>,
+    next_id: GameId,
+}
+
+impl GameManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new game and returns its ID.
+    pub fn create_game(&mut self, player_names: &[String], rules: GameRules) -> Result<GameId, GameError> {
+        let engine = GameEngine::with_players(player_names, rules)?;
+        Ok(self.insert(engine))
+    }
+
+    /// Hosts an already-built game and returns its ID.
+    pub fn insert(&mut self, engine: GameEngine) -> GameId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.games.insert(id, engine);
+        id
+    }
+
+    pub fn get(&self, id: GameId) -> Option<&GameEngine> {
+        self.games.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: GameId) -> Option<&mut GameEngine> {
+        self.games.get_mut(&id)
+    }
+
+    /// Tears down a game, handing back its engine if it existed.
+    pub fn remove(&mut self, id: GameId) -> Option<GameEngine> {
+        self.games.remove(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// Summaries of all hosted games, ordered by ID.
+    pub fn list(&self) -> Vec<GameSummary> {
+        self.games.iter().map(|(id, engine)| {
+            let state = engine.state();
+            let name_of = |player_id: usize| state.players.get(player_id)
+                .map(|p| p.name.clone())
+                .unwrap_or_default();
+            GameSummary {
+                id: *id,
+                player_names: state.turn_order.iter().map(|&pid| name_of(pid)).collect(),
+                current_player: name_of(engine.current_player_id()),
+                winner: engine.winner().map(name_of),
+            }
+        }).collect()
+    }
+}
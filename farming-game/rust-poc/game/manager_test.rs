@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{GameEngine, GameManager};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::models::Money;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_engine_deals_opening_hands_and_rotates_turns() {
+        let mut engine = GameEngine::with_players(&names(&["Ann", "Bo", "Cy"]), GameRules::default()).unwrap();
+        for player in engine.state().players.iter() {
+            assert_eq!(player.hand.len(), 2, "{} should start with two Option to Buy cards", player.name);
+        }
+
+        let first = engine.current_player_id();
+        engine.play_turn(3).unwrap();
+        assert_eq!(engine.state().players[first].position, 3);
+        assert_eq!(engine.current_player_id(), first, "Turn stays with the player until end_turn");
+
+        engine.end_turn();
+        assert_ne!(engine.current_player_id(), first);
+    }
+
+    #[test]
+    fn test_manager_hosts_independent_games() {
+        let mut manager = GameManager::new();
+        let a = manager.create_game(&names(&["Ann", "Bo"]), GameRules::default()).unwrap();
+        let b = manager.create_game(&names(&["Cy", "Di", "Ed"]), GameRules::default()).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(manager.len(), 2);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        manager.get_mut(a).unwrap().roll_and_play(&mut rng).unwrap();
+
+        // Game B is untouched by turns played in game A
+        assert!(manager.get(b).unwrap().state().players.iter().all(|p| p.position == 0));
+
+        let summaries = manager.list();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].id, a);
+        assert_eq!(summaries[1].player_names, names(&["Cy", "Di", "Ed"]));
+        assert_eq!(summaries[1].winner, None);
+
+        assert!(manager.remove(a).is_some());
+        assert!(manager.get(a).is_none());
+        assert!(manager.remove(a).is_none());
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn test_winner_uses_rules_threshold() {
+        let rules = GameRules { winning_net_worth: Money(100_000), ..GameRules::default() };
+        let mut engine = GameEngine::with_players(&names(&["Ann", "Bo"]), rules).unwrap();
+        assert_eq!(engine.winner(), None);
+
+        let player_id = engine.current_player_id();
+        engine.state_mut().players.get_mut(player_id).unwrap().net_worth = Money(100_000);
+        assert_eq!(engine.winner(), Some(player_id));
+    }
+
+    #[test]
+    fn test_seeded_engines_replay_identically() {
+        let play = |seed: u64| {
+            let mut engine = GameEngine::with_seed(&names(&["Ann", "Bo", "Cy"]), GameRules::default(), seed).unwrap();
+            let mut history = Vec::new();
+            for _ in 0..60 {
+                let (roll, logs) = engine.roll().unwrap();
+                history.push((roll, logs));
+                engine.end_turn();
+            }
+            let hands: Vec<Vec<usize>> = engine.state().turn_order.iter()
+                .map(|id| engine.state().players[*id].hand.iter().map(|c| c.id).collect())
+                .collect();
+            (history, hands)
+        };
+
+        assert_eq!(play(42), play(42));
+    }
+}
@@ -0,0 +1,76 @@
+// src/game/market.rs
+// Livestock market. The price a cow fetches drifts from year to year and
+// is knocked about by market cards; players can sell cows at it on their turn.
+
+use rand::Rng;
+use crate::game::{Dice, GameError};
+use crate::models::{AssetType, GameState, Money};
+
+/// Price of a cow before the market has moved, i.e. what a cow costs to buy.
+pub const BASE_COW_PRICE: Money = Money(500);
+/// The market never pays less than this per head, however bad the year.
+pub const MIN_COW_PRICE: Money = Money(200);
+/// Nor more than this.
+pub const MAX_COW_PRICE: Money = Money(900);
+
+/// Rolls a die for how far the market moves from last year: 1 is -$200,
+/// 2 is -$100, 5 is +$100, 6 is +$200, anything else holds steady.
+pub fn roll_price_change<R: Rng + ?Sized>(rng: &mut R) -> Money {
+    match Dice::ONE.roll(rng).total() {
+        1 => Money(-200),
+        2 => Money(-100),
+        5 => Money(100),
+        6 => Money(200),
+        _ => Money::ZERO,
+    }
+}
+
+impl GameState {
+    /// Price per head for cows sold in `year`. Years nobody has reached yet
+    /// carry over the latest known price.
+    pub fn cow_price(&self, year: u32) -> Money {
+        self.cow_price_by_year.range(..=year).next_back().map_or(BASE_COW_PRICE, |(_, price)| *price)
+    }
+
+    /// Moves the cow market the first time any player reaches `year`.
+    /// Returns the new price, or `None` if the year's price is already set.
+    pub fn roll_cow_price_for_year(&mut self, year: u32, logs: &mut Vec<String>) -> Option<Money> {
+        if self.cow_price_by_year.contains_key(&year) {
+            return None;
+        }
+        let change = roll_price_change(&mut self.rng);
+        let price = (self.cow_price(year) + change).clamp(MIN_COW_PRICE, MAX_COW_PRICE);
+        self.cow_price_by_year.insert(year, price);
+        logs.push(self.locale.tr("market.cow_price", &[("year", &year), ("price", &price)]));
+        tracing::info!(year, %price, "cow market moved");
+        Some(price)
+    }
+
+    /// Scales `year`'s cow price, e.g. halving it when the market collapses.
+    pub fn adjust_cow_price(&mut self, year: u32, multiplier: f32, logs: &mut Vec<String>) -> Money {
+        let price = self.cow_price(year).times(multiplier).clamp(MIN_COW_PRICE, MAX_COW_PRICE);
+        self.cow_price_by_year.insert(year, price);
+        logs.push(self.locale.tr("market.cow_price_adjusted", &[("year", &year), ("price", &price)]));
+        price
+    }
+
+    /// Sells `quantity` of the player's farm cows at their year's market price.
+    pub fn sell_cows(&mut self, player_id: usize, quantity: u32) -> Result<String, GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let owned = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+        if quantity == 0 || quantity > owned {
+            return Err(GameError::InsufficientAssets { asset: AssetType::Cows, requested: quantity, available: owned });
+        }
+
+        let price = self.cow_price(player.year);
+        let player = &mut self.players[player_id];
+        player.sell_asset(AssetType::Cows, quantity, price);
+        player.cash += price * quantity;
+        tracing::info!(player_id, quantity, %price, cash = %player.cash, "sold cows");
+        let log = self.locale.tr("market.sold_cows", &[("player", &player.name), ("cows", &quantity),
+            ("price", &price), ("total", &(price * quantity))]);
+
+        self.update_player_valuation(player_id);
+        Ok(log)
+    }
+}
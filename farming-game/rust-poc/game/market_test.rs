@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_support::card_with;
+    use crate::config::GameRules;
+    use crate::game::market::{BASE_COW_PRICE, MAX_COW_PRICE, MIN_COW_PRICE};
+    use crate::game::{Action, EffectResolver, GameEffect, GameEngine, GameError};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType, TileEffect};
+
+    /// One rancher with 10 cows and $1,000.
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        let mut player = Player::new(0, "Rancher".to_string(), PlayerType::Human);
+        player.cash = Money(1000);
+        player.add_asset(AssetType::Cows, 10, Money(5000));
+        players.insert(player);
+        GameState::new_with_players(players, vec![0], GameRules::default())
+    }
+
+
+    #[test]
+    fn test_cow_price_rolls_once_per_year_within_bounds() {
+        let mut game = setup_game();
+        game.reseed(11);
+        assert_eq!(game.cow_price(1), BASE_COW_PRICE);
+
+        let mut logs = Vec::new();
+        for year in 2..40 {
+            let price = game.roll_cow_price_for_year(year, &mut logs).unwrap();
+            assert!((MIN_COW_PRICE..=MAX_COW_PRICE).contains(&price));
+            assert!((price - game.cow_price(year - 1)).abs() <= Money(200), "The market drifts from last year's price");
+        }
+        assert_eq!(game.roll_cow_price_for_year(5, &mut logs), None);
+        assert_eq!(game.cow_price(50), game.cow_price(39), "Later years carry the latest price");
+    }
+
+    #[test]
+    fn test_sell_cows_at_market_price() {
+        let mut engine = GameEngine::new(setup_game());
+        engine.state_mut().cow_price_by_year.insert(1, Money(700));
+
+        let logs = engine.apply(Action::SellCows(4)).unwrap();
+        assert!(logs[0].contains("sold 4 cows at $700"));
+        let player = &engine.state().players[0];
+        assert_eq!(player.cash, Money(1000) + Money(4) * 700);
+        assert_eq!(player.assets[&AssetType::Cows].quantity, 6);
+
+        assert!(matches!(engine.apply(Action::SellCows(7)), Err(GameError::InsufficientAssets { available: 6, .. })));
+        assert!(matches!(engine.apply(Action::SellCows(0)), Err(GameError::InsufficientAssets { .. })));
+        assert!(matches!(engine.apply(Action::SellCows(-2)), Err(GameError::InvalidQuantity(-2))));
+        assert_eq!(engine.state().players[0].assets[&AssetType::Cows].quantity, 6);
+    }
+
+    #[test]
+    fn test_market_collapse_halves_cow_price() {
+        let mut game = setup_game();
+        let mut logs = Vec::new();
+        let market_collapse = GameEffect::from(&TileEffect::OneTimeHarvestMultiplier { asset: AssetType::Cows, multiplier: 0.5 });
+        EffectResolver::new(&mut game, 0).resolve(&market_collapse, &mut logs).unwrap();
+        assert_eq!(game.cow_price(1), BASE_COW_PRICE / 2);
+
+        let hay_loss = GameEffect::from(&TileEffect::OneTimeHarvestMultiplier { asset: AssetType::Hay, multiplier: 0.5 });
+        EffectResolver::new(&mut game, 0).resolve(&hay_loss, &mut logs).unwrap();
+        assert_eq!(game.cow_price(1), BASE_COW_PRICE / 2, "Only livestock swings move the cow market");
+    }
+
+    #[test]
+    fn test_compensated_slaughter_pays_market_price() {
+        let mut game = setup_game();
+        game.cow_price_by_year.insert(1, Money(300));
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &card_with(900, GameEffect::SlaughterCowsWithCompensation), &mut logs).unwrap();
+
+        let player = &game.players[0];
+        assert!(!player.assets.contains_key(&AssetType::Cows));
+        assert_eq!(player.cash, Money(1000) + Money(10) * 300);
+        assert!(logs.iter().any(|l| l.contains("$300 a head")));
+    }
+}
@@ -0,0 +1,53 @@
+// src/game/migrations.rs
+// Upgrades saved game records written by earlier releases. Every record
+// carries the format version it was written in; on load, the raw JSON is
+// stepped through each upgrade from its version to the current one before
+// it's read as a `GameRecord`. Fields that merely gain a default don't need
+// a step (serde fills them in); a step is for anything that changed shape.
+
+use serde_json::{Map, Value};
+
+/// Format version records are written in.
+pub const RECORD_VERSION: u32 = 2;
+
+/// One upgrade per version, from that version to the next: `MIGRATIONS[0]`
+/// turns a version 1 record into version 2.
+const MIGRATIONS: [fn(&mut Map<String, Value>); (RECORD_VERSION - 1) as usize] = [players_to_seats];
+
+/// Brings a saved record up to `RECORD_VERSION`. Records from before
+/// versioning are version 1. Fails on a record that isn't a JSON object or
+/// was written by a newer release.
+pub fn migrate(mut record: Value) -> Result<Value, String> {
+    let Some(fields) = record.as_object_mut() else {
+        return Err("a game record must be a JSON object".to_string());
+    };
+    let version = match fields.get("version") {
+        None => 1,
+        Some(version) => version.as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .filter(|version| *version >= 1)
+            .ok_or_else(|| format!("invalid record version {}", version))?,
+    };
+    if version > RECORD_VERSION {
+        return Err(format!("record format {} is newer than this release reads (up to {})", version, RECORD_VERSION));
+    }
+    for migration in &MIGRATIONS[(version - 1) as usize..] {
+        migration(fields);
+    }
+    fields.insert("version".to_string(), Value::from(RECORD_VERSION));
+    Ok(record)
+}
+
+/// Version 1 kept only each player's name; version 2 keeps a seat per
+/// player, whose AI strategy and color default when missing.
+fn players_to_seats(record: &mut Map<String, Value>) {
+    if let Some(Value::Array(players)) = record.get_mut("players") {
+        for player in players.iter_mut() {
+            if let Value::String(name) = player {
+                let mut seat = Map::new();
+                seat.insert("name".to_string(), Value::String(std::mem::take(name)));
+                *player = Value::Object(seat);
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::config::GameRules;
+    use crate::game::migrations::{migrate, RECORD_VERSION};
+    use crate::game::{Action, GameEngine, GameRecord, RecordedPlayer, Replay};
+    use crate::models::{PlayerColor, PlayerType};
+
+    /// Written before records were versioned, and before the rules had a
+    /// difficulty, a hand limit or a year limit
+    const RECORD_V1: &str = include_str!("fixtures/record_v1.json");
+
+    #[test]
+    fn test_a_version_1_record_loads_and_replays() {
+        let record = GameRecord::from_json(RECORD_V1).unwrap();
+        assert_eq!(record.version, RECORD_VERSION);
+        assert_eq!(record.names(), vec!["Ann".to_string(), "Bo".to_string()]);
+        assert!(record.players.iter().all(|player| player.ai.is_none() && player.color.is_none()));
+        assert_eq!(record.rules.hand_limit, None);
+        assert_eq!(record.rules.opening_otb_cards, GameRules::default().opening_otb_cards);
+
+        let replay = Replay::new(&record).unwrap();
+        assert_eq!(replay.turn_count(), 3);
+    }
+
+    #[test]
+    fn test_a_current_record_is_left_as_it_is() {
+        let record = GameEngine::with_seed(&["Ann".to_string()], GameRules::default(), 2).unwrap().record().unwrap().clone();
+        let json: serde_json::Value = serde_json::from_str(&record.to_json()).unwrap();
+        assert_eq!(migrate(json.clone()).unwrap(), json);
+    }
+
+    #[test]
+    fn test_records_from_a_newer_release_are_refused() {
+        let newer = json!({ "version": RECORD_VERSION + 1, "seed": 1, "players": [], "rules": {}, "actions": [] });
+        assert!(migrate(newer.clone()).unwrap_err().contains("newer"));
+        assert!(GameRecord::from_json(&newer.to_string()).is_err());
+        assert!(migrate(json!(["not", "a", "record"])).is_err());
+        assert!(migrate(json!({ "version": 0 })).is_err());
+    }
+
+    #[test]
+    fn test_seats_are_kept_through_saving_and_replaying() {
+        let names = vec!["Ann".to_string(), "Bo".to_string()];
+        let mut engine = GameEngine::with_seed(&names, GameRules::default(), 4).unwrap();
+        engine.seat(1, PlayerType::AI("greedy".to_string()), PlayerColor::Yellow);
+        engine.apply(Action::Roll).unwrap();
+
+        let record = GameRecord::from_json(&engine.record().unwrap().to_json()).unwrap();
+        assert_eq!(record.players[1], RecordedPlayer { name: "Bo".to_string(), ai: Some("greedy".to_string()), color: Some(PlayerColor::Yellow) });
+
+        let replay = Replay::new(&record).unwrap();
+        let bo = &replay.current().state.players[1];
+        assert_eq!(bo.player_type, PlayerType::AI("greedy".to_string()));
+        assert_eq!(bo.color, PlayerColor::Yellow);
+        assert_eq!(replay.current().state.players[0].player_type, PlayerType::Human);
+    }
+}
@@ -3,13 +3,152 @@ pub mod harvest;
 pub mod bankruptcy;
 pub mod board;
 pub mod game_loop;
+pub mod error;
+pub mod engine;
+pub mod builder;
+pub mod manager;
+pub mod invariants;
+pub mod auction;
+pub mod insurance;
+pub mod weather;
+pub mod scenario;
+pub mod effect_resolver;
+pub mod decision;
+pub mod market;
+pub mod events;
+pub mod turn_summary;
+pub mod turn_history;
+pub mod dice;
+pub mod spectator;
+pub mod replay;
+pub mod effect_registry;
+pub mod mutation;
+pub mod constraints;
+pub mod standings;
+pub mod raise_cash;
+pub mod turn_order;
+pub mod custom_work;
+pub mod observer;
+pub mod game_over;
+pub mod movement;
+pub mod board_file;
+pub mod balance;
+pub mod discard;
+pub mod monte_carlo;
+pub mod migrations;
+pub mod autosnapshot;
+pub mod victory;
+pub mod ridge_dangers;
+pub mod livestock;
+pub mod legal_actions;
 
 pub use phase::GamePhase;
-pub use crate::models::effects::GameEffect;
+pub use error::GameError;
+pub use engine::{Action, GameEngine};
+pub use builder::{GameBuilder, GameStateBuilder};
+pub use manager::{GameManager, GameId, GameSummary};
+pub use invariants::InvariantViolation;
+pub use auction::{Auction, AuctionStatus};
+pub use insurance::{Coverage, InsurancePolicy};
+pub use weather::Weather;
+pub use scenario::{PlayerOutcome, Scenario, ScenarioError};
+pub use crate::models::effects::{EffectCategory, GameEffect};
+pub use effect_resolver::EffectResolver;
+pub use decision::{Decision, DecisionKind, DecisionRequest};
+pub use raise_cash::Sellable;
+pub use events::GameEvent;
+pub use turn_summary::{PlayerSnapshot, TurnSummary};
+pub use turn_history::TurnRecord;
+pub use dice::{Dice, DiceSource, LoadedDice, Roll};
+pub use spectator::{SnapshotWriter, SpectatorSnapshot, Standing};
+pub use replay::{GameRecord, GameSetup, RecordedPlayer, Replay, ReplayTurn};
+pub use effect_registry::{EffectHandler, EffectParams, EffectRegistry};
+pub use mutation::StateMutation;
+pub use standings::Ranking;
+pub use turn_order::OpeningRoll;
+pub use custom_work::CustomWorkJob;
+pub use observer::GameObserver;
+pub use game_over::FinalStanding;
+pub use balance::{BalanceReport, EffectStats};
+pub use monte_carlo::MonteCarlo;
+pub use autosnapshot::SnapshotMismatch;
+pub use victory::TieBreak;
 
 #[cfg(test)]
 mod board_test;
 #[cfg(test)]
 mod harvest_test;
 #[cfg(test)]
-mod bankruptcy_test; 
\ No newline at end of file
+mod bankruptcy_test;
+#[cfg(test)]
+mod builder_test;
+#[cfg(test)]
+mod manager_test;
+#[cfg(test)]
+mod invariants_test;
+#[cfg(test)]
+mod auction_test;
+#[cfg(test)]
+mod insurance_test;
+#[cfg(test)]
+mod weather_test;
+#[cfg(test)]
+mod scenario_test;
+#[cfg(test)]
+mod effect_resolver_test;
+#[cfg(test)]
+mod movement_test;
+#[cfg(test)]
+mod board_file_test;
+#[cfg(test)]
+mod balance_test;
+#[cfg(test)]
+mod discard_test;
+#[cfg(test)]
+mod monte_carlo_test;
+#[cfg(test)]
+mod decision_test;
+#[cfg(test)]
+mod market_test;
+#[cfg(test)]
+mod events_test;
+#[cfg(test)]
+mod turn_summary_test;
+#[cfg(test)]
+mod turn_history_test;
+#[cfg(test)]
+mod dice_test;
+#[cfg(test)]
+mod spectator_test;
+#[cfg(test)]
+mod replay_test;
+#[cfg(test)]
+mod migrations_test;
+#[cfg(test)]
+mod autosnapshot_test;
+#[cfg(test)]
+mod victory_test;
+#[cfg(test)]
+mod effect_registry_test;
+#[cfg(test)]
+mod mutation_test;
+#[cfg(test)]
+mod constraints_test;
+#[cfg(test)]
+mod standings_test;
+#[cfg(test)]
+mod raise_cash_test;
+#[cfg(test)]
+mod turn_order_test;
+#[cfg(test)]
+mod custom_work_test;
+#[cfg(test)]
+mod observer_test;
+#[cfg(test)]
+mod game_over_test;
+#[cfg(test)]
+mod ridge_dangers_test;
+#[cfg(test)]
+mod livestock_test;
+#[cfg(test)]
+mod legal_actions_test;
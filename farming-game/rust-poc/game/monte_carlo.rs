@@ -0,0 +1,135 @@
+// src/game/monte_carlo.rs
+// A stronger AI for the choices made between rolling and ending a turn:
+// exercising an Option to Buy or passing on it, paying down debt or holding
+// on to cash. Each candidate is played out in copies of the game run ahead
+// with simple AI turns, and the one leaving the farmer the best average net
+// worth is taken. AI players created with the `monte_carlo` strategy play
+// this way in simulations (`--simulate` with `--monte-carlo`).
+
+use rand::Rng;
+use crate::game::balance::{answer_decisions, finish_turn};
+use crate::game::{Action, GameEngine};
+use crate::models::{GameState, Money, PlayerType};
+
+/// `PlayerType::AI` strategy name for farmers who look ahead.
+pub const MONTE_CARLO_STRATEGY: &str = "monte_carlo";
+
+/// Copies of the game each candidate is played out in.
+pub const DEFAULT_ROLLOUTS: usize = 8;
+
+/// Turns each player takes in a rollout before net worths are compared.
+pub const DEFAULT_ROLLOUT_ROUNDS: usize = 12;
+
+/// How far ahead, and how often, candidates are played out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonteCarlo {
+    pub rollouts: usize,
+    pub rounds: usize,
+}
+
+impl Default for MonteCarlo {
+    fn default() -> Self {
+        Self { rollouts: DEFAULT_ROLLOUTS, rounds: DEFAULT_ROLLOUT_ROUNDS }
+    }
+}
+
+impl MonteCarlo {
+    /// Plays the rest of `player_id`'s turn after the roll, taking the best
+    /// action until passing looks best. Rollouts are seeded from the game's
+    /// RNG, so a seeded game stays repeatable.
+    pub fn play(&self, engine: &mut GameEngine, player_id: usize) {
+        // Every action spends cash or a card, so a turn can't take more than this
+        let limit = engine.state().players[player_id].hand.len() + 1;
+        for _ in 0..limit {
+            let seed = engine.state_mut().rng.gen();
+            let Some(action) = self.choose(engine.state(), player_id, seed) else { return };
+            if engine.apply(action).is_err() {
+                return;
+            }
+            answer_decisions(engine);
+        }
+    }
+
+    /// The action leaving `player_id` the best expected net worth, or `None`
+    /// when passing does at least as well. Every candidate is played out with
+    /// the same seeds, so they're compared over the same dice and draws.
+    pub fn choose(&self, state: &GameState, player_id: usize, seed: u64) -> Option<Action> {
+        let base = state.fork();
+        let mut best = (self.expected_net_worth(&base, player_id, None, seed)?, None);
+        for action in candidates(state, player_id) {
+            match self.expected_net_worth(&base, player_id, Some(&action), seed) {
+                Some(value) if value > best.0 => best = (value, Some(action)),
+                _ => {}
+            }
+        }
+        best.1
+    }
+
+    /// Average net worth `player_id` ends the rollouts with after `action`;
+    /// `None` if the action can't be taken.
+    fn expected_net_worth(&self, base: &GameState, player_id: usize, action: Option<&Action>, seed: u64) -> Option<f64> {
+        let rollouts = self.rollouts.max(1);
+        let mut total = 0i64;
+        for rollout in 0..rollouts {
+            let mut game = base.clone();
+            game.reseed(seed.wrapping_add(rollout as u64));
+            let mut engine = GameEngine::new(game);
+            if let Some(action) = action {
+                engine.apply(action.clone()).ok()?;
+                answer_decisions(&mut engine);
+            }
+            finish_turn(&mut engine);
+            self.roll_out(&mut engine);
+            let state = engine.state_mut();
+            state.update_player_valuation(player_id);
+            total += state.players[player_id].net_worth.dollars();
+        }
+        Some(total as f64 / rollouts as f64)
+    }
+
+    /// Plays `rounds` turns for every player: roll, answer decisions, end the turn.
+    fn roll_out(&self, engine: &mut GameEngine) {
+        for _ in 0..self.rounds * engine.state().turn_order.len() {
+            if engine.winner().is_some() {
+                return;
+            }
+            let player_id = engine.current_player_id();
+            if engine.state().players[player_id].is_active {
+                let _ = engine.apply(Action::Roll);
+                answer_decisions(engine);
+            }
+            finish_turn(engine);
+        }
+    }
+}
+
+/// What `player_id` could do between rolling and ending the turn: exercise
+/// each Option to Buy in hand, or pay off as much debt as cash allows.
+pub fn candidates(state: &GameState, player_id: usize) -> Vec<Action> {
+    let player = &state.players[player_id];
+    let mut actions: Vec<Action> = player.hand.iter()
+        .map(|card| Action::ExerciseOption { card_id: card.id, confirm_loan: false })
+        .collect();
+    let repayment = player.cash.min(player.debt);
+    if repayment > Money::ZERO {
+        actions.push(Action::PayLoan(repayment));
+    }
+    actions
+}
+
+/// True if `player_id` is an AI playing by `MONTE_CARLO_STRATEGY`.
+pub fn plays_monte_carlo(state: &GameState, player_id: usize) -> bool {
+    matches!(&state.players[player_id].player_type, PlayerType::AI(strategy) if strategy == MONTE_CARLO_STRATEGY)
+}
+
+impl GameState {
+    /// A copy to play ahead in, without the event history and standings
+    /// snapshots. Both only grow as a game goes on and no rule depends on
+    /// them, so clones of a fork stay cheap however long the game has run.
+    pub fn fork(&self) -> GameState {
+        let mut fork = self.clone();
+        fork.events.clear();
+        fork.net_worth_snapshots.clear();
+        fork
+    }
+}
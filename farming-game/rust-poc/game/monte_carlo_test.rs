@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::config::GameRules;
+    use crate::game::balance::{simulation_table, SIMULATED_STRATEGY};
+    use crate::game::monte_carlo::{candidates, plays_monte_carlo, MONTE_CARLO_STRATEGY};
+    use crate::game::{Action, GameEffect, GameEngine, GameEvent, MonteCarlo};
+    use crate::models::AssetType;
+    use crate::models::Money;
+
+    /// Rollouts short enough for tests.
+    const QUICK: MonteCarlo = MonteCarlo { rollouts: 2, rounds: 2 };
+
+    fn option(id: usize, quantity: u32, cost: Money) -> Card {
+        Card {
+            id,
+            title: format!("Option {}", id),
+            description: "Test option".to_string(),
+            description_brief: "Test option".to_string(),
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Hay, quantity, cost },
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        }
+    }
+
+    fn engine() -> GameEngine {
+        GameEngine::with_seed(&["Ann".to_string(), "Bob".to_string()], GameRules::default(), 1874).unwrap()
+    }
+
+    #[test]
+    fn test_candidates_are_each_option_and_repaying_debt() {
+        let mut engine = engine();
+        let ann = &mut engine.state_mut().players[0];
+        ann.hand = vec![option(900, 10, Money(1000)), option(901, 1, Money(4000))];
+        ann.cash = Money(3000);
+        ann.debt = Money(5000);
+
+        assert_eq!(candidates(engine.state(), 0), vec![
+            Action::ExerciseOption { card_id: 900, confirm_loan: false },
+            Action::ExerciseOption { card_id: 901, confirm_loan: false },
+            Action::PayLoan(Money(3000)),
+        ]);
+    }
+
+    #[test]
+    fn test_takes_the_bargain_and_passes_on_the_rest() {
+        let mut engine = engine();
+        let ann = &mut engine.state_mut().players[0];
+        ann.hand = vec![option(901, 1, Money(4000)), option(900, 10, Money(1))];
+        ann.cash = Money(5000);
+
+        assert_eq!(QUICK.choose(engine.state(), 0, 7), Some(Action::ExerciseOption { card_id: 900, confirm_loan: false }));
+
+        QUICK.play(&mut engine, 0);
+        let hand: Vec<usize> = engine.state().players[0].hand.iter().map(|card| card.id).collect();
+        assert_eq!(hand, vec![901], "Paying $4,000 for one acre of hay isn't worth it");
+    }
+
+    #[test]
+    fn test_options_it_cannot_pay_for_are_never_chosen() {
+        let mut engine = engine();
+        let ann = &mut engine.state_mut().players[0];
+        ann.hand = vec![option(900, 10, Money(1_000_000))];
+        ann.cash = Money(1000);
+
+        assert_eq!(QUICK.choose(engine.state(), 0, 7), None);
+    }
+
+    #[test]
+    fn test_looking_ahead_leaves_the_game_untouched() {
+        let mut engine = engine();
+        engine.state_mut().emit(GameEvent::DiceRolled { player_id: 0, roll: 3 });
+        let before = engine.state().clone();
+
+        assert!(engine.state().fork().events.is_empty());
+        QUICK.choose(engine.state(), 0, 7);
+        assert_eq!(engine.state().players[0].cash, before.players[0].cash);
+        assert_eq!(engine.state().events, before.events);
+        assert_eq!(engine.current_player_id(), 0);
+    }
+
+    #[test]
+    fn test_simulation_tables_pick_the_strategy() {
+        let table = simulation_table(2, MONTE_CARLO_STRATEGY).build().unwrap();
+        assert!(plays_monte_carlo(&table, 0) && plays_monte_carlo(&table, 1));
+
+        let table = simulation_table(2, SIMULATED_STRATEGY).build().unwrap();
+        assert!(!plays_monte_carlo(&table, 0));
+    }
+}
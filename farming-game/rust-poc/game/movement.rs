@@ -0,0 +1,113 @@
+// src/game/movement.rs
+// Tiles and cards that move a player all go through `jump`, which follows
+// the effect's `JumpRules`. They don't resolve the destination themselves:
+// they queue it, and the outermost `handle_tile_event` resolves queued tiles
+// one after another. A chain that comes back to a tile it has already
+// resolved stops there, so boards whose tiles send players to each other
+// can't loop forever.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::Arc;
+use crate::game::{EffectResolver, GameEffect, GameError, GameEvent, StateMutation};
+use crate::models::{BoardTile, GameState, HarvestType, JumpRules, LedgerCategory, Money};
+
+impl GameState {
+    /// Moves the player forward to `to` for an effect, paying `bonus` on
+    /// arrival and starting a new year if they go round the start and the
+    /// `rules` collect it. Resolving the destination is left to the caller
+    /// (see `land_on`). Fails without moving them if `to` is off the board.
+    pub(crate) fn jump(&mut self, player_id: usize, to: usize, bonus: Money, rules: JumpRules, logs: &mut Vec<String>) -> Result<(), GameError> {
+        if to >= self.board.len() {
+            return Err(GameError::InvalidPosition(to));
+        }
+        let from = self.players[player_id].position;
+        if rules.collect_pass_through && to < from {
+            self.pass_start(player_id, logs);
+        }
+        let mut changes = vec![StateMutation::Move { player_id, from, to }];
+        if bonus > Money::ZERO {
+            changes.push(StateMutation::CashDelta { player_id, amount: bonus, category: LedgerCategory::Bonus });
+        }
+        self.apply_mutations(&changes)
+    }
+
+    /// Starts the player's next year as they go round the start of the
+    /// board: the side job wage, held cards expiring, the dangers facing
+    /// their ridge herds and, for the first player into the year, its
+    /// weather and cow market.
+    pub(crate) fn pass_start(&mut self, player_id: usize, logs: &mut Vec<String>) {
+        let player = &mut self.players[player_id];
+        logs.push(self.locale.tr("turn.passed_go", &[("player", &player.name)]));
+        let ended_year = GameEvent::YearEnded { player_id, year: player.year, cash: player.cash, debt: player.debt };
+
+        let side_job_pay = player.start_new_year(self.rules.side_job_wage);
+        tracing::info!(player_id, year = player.year, side_job_pay = side_job_pay.is_some(), "passed go");
+        logs.push(self.locale.tr("turn.year_advanced", &[("year", &player.year)]));
+        match side_job_pay {
+            Some(wage) => logs.push(self.locale.tr("turn.side_job_paid", &[("wage", &wage), ("cash", &player.cash)])),
+            None => logs.push(self.locale.tr("turn.side_job_skipped", &[])),
+        }
+        // Held Farmer's Fate cards expire with the year
+        player.update_persistent_cards(&mut self.farmer_fate_deck.discard_pile);
+        let year = player.year;
+        self.emit(ended_year);
+        self.roll_ridge_dangers(player_id, logs);
+
+        // The first player into a new year rolls its weather and moves the cow market
+        self.roll_weather_for_year(year, logs);
+        self.roll_cow_price_for_year(year, logs);
+    }
+
+    /// Resolves the tile the player was just moved to: queued while a tile
+    /// is being resolved, right away otherwise (e.g. for a card played on
+    /// its own).
+    pub(crate) fn land_on(&mut self, player_id: usize, tile_index: usize, logs: &mut Vec<String>) -> Result<(), GameError> {
+        if let Some(landings) = self.landings.as_mut() {
+            landings.push_back(tile_index);
+            return Ok(());
+        }
+        let board = Arc::clone(&self.board);
+        let tile = board.get(tile_index).ok_or(GameError::InvalidPosition(tile_index))?;
+        self.handle_tile_event(player_id, tile, logs)
+    }
+
+    /// Resolves `tile` and then every tile its effects moved the player to.
+    pub(crate) fn resolve_landings(&mut self, player_id: usize, tile: &BoardTile, logs: &mut Vec<String>) -> Result<(), GameError> {
+        // Moving back onto the tile they're standing on counts as a loop too
+        let mut resolved: BTreeSet<usize> = (self.players[player_id].position == tile.index).then_some(tile.index).into_iter().collect();
+        let outer = self.landings.replace(VecDeque::new());
+        let mut result = self.resolve_tile(player_id, tile, logs);
+        let board = Arc::clone(&self.board);
+        while result.is_ok() {
+            let Some(index) = self.landings.as_mut().and_then(VecDeque::pop_front) else { break };
+            if !resolved.insert(index) {
+                tracing::warn!(player_id, tile = index, "movement loop; stopping");
+                let player_name = self.players[player_id].name.clone();
+                logs.push(self.locale.tr("effect.move_loop", &[("player", &player_name), ("tile", &self.locale.tile_name(&board[index]))]));
+                break;
+            }
+            result = self.resolve_tile(player_id, &board[index], logs);
+        }
+        self.landings = outer;
+        result
+    }
+
+    /// A tile's harvest and effect, without following any moves it makes.
+    fn resolve_tile(&mut self, player_id: usize, tile: &BoardTile, logs: &mut Vec<String>) -> Result<(), GameError> {
+        tracing::debug!(player_id, tile = %tile.name, effect = ?tile.effect, "tile event");
+
+        // Process harvest first if this is a harvest tile
+        if tile.harvest_type != HarvestType::None {
+            if let Ok(harvest_logs) = self.process_harvest(player_id, tile.harvest_type.clone()) {
+                logs.extend(harvest_logs);
+            }
+        }
+
+        EffectResolver::new(self, player_id).resolve(&GameEffect::from(&tile.effect), logs)?;
+
+        // Update scoreboard after all effects are applied
+        self.update_player_valuation(player_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::cards::card::{Card, CardSource};
+    use crate::game::{GameEffect, GameError, GameEvent, GameStateBuilder};
+    use crate::models::{AssetType, GameState, HarvestType, JumpRules, Money, Player, PlayerType, TileEffect};
+
+    /// One player with no assets standing on `position` of the stock board.
+    fn stock_game(position: usize) -> GameState {
+        let mut player = Player::new(0, "Farmer".to_string(), PlayerType::Human);
+        player.cash = Money(1000);
+        player.position = position;
+        let mut game = GameStateBuilder::default().add_player(player).build().unwrap();
+        // Without Grandpa's hay and grain, harvest tiles bring nothing in
+        game.players[0].assets.clear();
+        game
+    }
+
+    /// One player standing on tile 3, with tiles 3 and 4 set to `effects`.
+    fn setup_game(effects: [TileEffect; 2]) -> GameState {
+        let mut game = stock_game(3);
+        let board = Arc::make_mut(&mut game.board);
+        for (index, effect) in [3, 4].into_iter().zip(effects) {
+            board[index].effect = effect;
+            board[index].harvest_type = HarvestType::None;
+        }
+        game
+    }
+
+    fn land(game: &mut GameState, index: usize) -> (Result<(), GameError>, Vec<String>) {
+        let tile = game.board[index].clone();
+        let mut logs = Vec::new();
+        (game.handle_tile_event(0, &tile, &mut logs), logs)
+    }
+
+    #[test]
+    fn test_tiles_sending_players_to_each_other_stop_instead_of_recursing() {
+        let mut game = setup_game([TileEffect::GoToTile(4), TileEffect::GoToTile(3)]);
+
+        let (result, logs) = land(&mut game, 3);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.players[0].position, 3);
+        assert!(logs.iter().any(|log| log.contains("already sent them there")), "{logs:?}");
+        assert!(game.landings.is_none());
+    }
+
+    #[test]
+    fn test_destination_tile_resolves_once_after_the_move() {
+        let mut game = setup_game([TileEffect::GoToTile(4), TileEffect::GainCash(Money(500))]);
+
+        let (result, _) = land(&mut game, 3);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.players[0].position, 4);
+        assert_eq!(game.players[0].cash, Money(1500));
+    }
+
+    #[test]
+    fn test_move_card_played_on_its_own_resolves_the_destination() {
+        let mut game = setup_game([TileEffect::None, TileEffect::GainCash(Money(500))]);
+        let card = Card {
+            id: 1,
+            title: "Test Card".to_string(),
+            description: "Test card".to_string(),
+            description_brief: "Test card".to_string(),
+            effect: GameEffect::MoveToTile(4),
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        };
+
+        game.apply_card_effect(0, &card, &mut Vec::new()).unwrap();
+
+        assert_eq!(game.players[0].position, 4);
+        assert_eq!(game.players[0].cash, Money(1500));
+    }
+
+    #[test]
+    fn test_moves_off_the_board_fail_without_moving() {
+        for effect in [
+            TileEffect::GoToTile(999),
+            TileEffect::GoToTileAndGainCash { tile_index: 999, amount: Money(5000) },
+            TileEffect::MoveAndHarvestIfAsset { asset: AssetType::Hay, destination: 999, bonus: Money(1000), harvest_type: HarvestType::Apple },
+        ] {
+            let mut game = setup_game([effect, TileEffect::None]);
+            game.players[0].add_asset(AssetType::Hay, 10, Money::ZERO);
+
+            let (result, _) = land(&mut game, 3);
+
+            assert_eq!(result, Err(GameError::InvalidPosition(999)));
+            assert_eq!(game.players[0].position, 3);
+            assert_eq!(game.players[0].cash, Money(1000));
+        }
+    }
+
+    #[test]
+    fn test_stock_board_jump_tiles_declare_their_rules() {
+        let game = stock_game(0);
+        let jumps: Vec<(usize, JumpRules)> = game.board.iter()
+            .filter_map(|tile| tile.effect.jump_rules().map(|rules| (tile.index, rules)))
+            .collect();
+
+        assert_eq!(jumps, vec![
+            (7, JumpRules { apply_destination: true, collect_pass_through: true }),
+            (28, JumpRules { apply_destination: true, collect_pass_through: true }),
+            (30, JumpRules { apply_destination: false, collect_pass_through: false }),
+            (34, JumpRules { apply_destination: false, collect_pass_through: true }),
+        ]);
+    }
+
+    #[test]
+    fn test_ground_thaws_applies_spring_planting() {
+        let mut game = stock_game(7);
+
+        assert_eq!(land(&mut game, 7).0, Ok(()));
+
+        assert_eq!(game.players[0].position, 14);
+        assert_eq!(game.players[0].get_crop_multiplier(&AssetType::Grain), 2.0);
+    }
+
+    #[test]
+    fn test_cooker_applies_harvest_moon() {
+        let mut game = stock_game(28);
+
+        assert_eq!(land(&mut game, 28).0, Ok(()));
+
+        assert_eq!(game.players[0].position, 37);
+        assert_eq!(game.players[0].cash, Money(1500));
+    }
+
+    #[test]
+    fn test_right_on_time_pays_the_wage_without_resolving_february() {
+        let mut game = stock_game(30);
+        let year = game.players[0].year;
+
+        assert_eq!(land(&mut game, 30).0, Ok(()));
+
+        assert_eq!(game.players[0].position, 8);
+        assert_eq!(game.players[0].cash, Money(6000));
+        assert_eq!(game.players[0].year, year);
+        assert!(game.players[0].hand.is_empty(), "February Week 4's O.T.B. draw was applied");
+    }
+
+    #[test]
+    fn test_tractor_owners_harvest_in_november_without_resolving_it() {
+        let mut without_tractor = stock_game(34);
+        assert_eq!(land(&mut without_tractor, 34).0, Ok(()));
+        assert_eq!(without_tractor.players[0].position, 34);
+
+        let mut game = stock_game(34);
+        game.players[0].add_asset(AssetType::Tractor, 1, Money::ZERO);
+
+        assert_eq!(land(&mut game, 34).0, Ok(()));
+
+        assert_eq!(game.players[0].position, 45);
+        // The $1000 bonus, but not November Week 3's own $1000
+        assert_eq!(game.players[0].cash, Money(2000));
+    }
+
+    #[test]
+    fn test_jump_round_the_start_begins_a_new_year_when_collected() {
+        let mut game = stock_game(3);
+        Arc::make_mut(&mut game.board)[3].effect = TileEffect::GoToTile(2);
+        let year = game.players[0].year;
+
+        assert_eq!(land(&mut game, 3).0, Ok(()));
+
+        assert_eq!(game.players[0].position, 2);
+        assert_eq!(game.players[0].year, year + 1);
+        assert_eq!(game.players[0].cash, Money(1000) + game.rules.side_job_wage);
+        assert!(game.events.iter().any(|event| matches!(event, GameEvent::YearEnded { year: ended, .. } if *ended == year)));
+    }
+}
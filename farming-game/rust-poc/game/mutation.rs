@@ -0,0 +1,113 @@
+// src/game/mutation.rs
+// Changes to players collected while an effect is worked out and applied
+// together afterwards, so effects don't have to juggle borrows of the player
+// registry. Every mutation has an inverse, which is enough to undo a batch.
+
+use std::collections::HashMap;
+use crate::game::GameError;
+use crate::models::{AssetType, GameState, LedgerCategory, Money};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateMutation {
+    /// Cash in (positive) or out, booked to `category` in the ledger
+    CashDelta { player_id: usize, amount: Money, category: LedgerCategory },
+    /// Borrowed on a new note (positive) or repaid, oldest notes first
+    DebtDelta { player_id: usize, amount: Money },
+    /// Units of an asset gained or lost, without paying or being paid for them
+    AssetDelta { player_id: usize, asset: AssetType, quantity: i32 },
+    Move { player_id: usize, from: usize, to: usize },
+}
+
+impl StateMutation {
+    pub fn player_id(&self) -> usize {
+        match self {
+            StateMutation::CashDelta { player_id, .. }
+            | StateMutation::DebtDelta { player_id, .. }
+            | StateMutation::AssetDelta { player_id, .. }
+            | StateMutation::Move { player_id, .. } => *player_id,
+        }
+    }
+
+    /// The mutation that undoes this one.
+    pub fn inverse(&self) -> StateMutation {
+        match *self {
+            StateMutation::CashDelta { player_id, amount, category } => StateMutation::CashDelta { player_id, amount: -amount, category },
+            StateMutation::DebtDelta { player_id, amount } => StateMutation::DebtDelta { player_id, amount: -amount },
+            StateMutation::AssetDelta { player_id, asset, quantity } => StateMutation::AssetDelta { player_id, asset, quantity: -quantity },
+            StateMutation::Move { player_id, from, to } => StateMutation::Move { player_id, from: to, to: from },
+        }
+    }
+}
+
+impl GameState {
+    /// Applies `mutations` in order, or none of them if any would leave a
+    /// player with negative debt or assets, over a farm limit, or off the board.
+    pub fn apply_mutations(&mut self, mutations: &[StateMutation]) -> Result<(), GameError> {
+        self.check_mutations(mutations)?;
+        let rate = self.rules.interest_rate;
+        for mutation in mutations {
+            let player = &mut self.players[mutation.player_id()];
+            match *mutation {
+                StateMutation::CashDelta { amount, category, .. } => {
+                    player.cash += amount;
+                    player.record_transaction(category, amount);
+                }
+                StateMutation::DebtDelta { amount, .. } if amount > Money::ZERO => player.borrow(amount, rate),
+                StateMutation::DebtDelta { amount, .. } => {
+                    player.repay_debt(-amount, None, rate);
+                }
+                StateMutation::AssetDelta { asset, quantity, .. } if quantity < 0 => {
+                    player.remove_asset(asset, quantity.unsigned_abs());
+                }
+                StateMutation::AssetDelta { asset, quantity, .. } => player.add_asset(asset, quantity.unsigned_abs(), Money::ZERO),
+                StateMutation::Move { to, .. } => player.position = to,
+            }
+        }
+        Ok(())
+    }
+
+    /// Undoes mutations applied by `apply_mutations`, last first.
+    pub fn revert_mutations(&mut self, mutations: &[StateMutation]) -> Result<(), GameError> {
+        let inverses: Vec<StateMutation> = mutations.iter().rev().map(StateMutation::inverse).collect();
+        self.apply_mutations(&inverses)
+    }
+
+    /// Runs through the batch on a tally of debts and holdings without touching the players.
+    fn check_mutations(&self, mutations: &[StateMutation]) -> Result<(), GameError> {
+        let mut debts: HashMap<usize, Money> = HashMap::new();
+        let mut holdings: HashMap<(usize, AssetType), u32> = HashMap::new();
+        for mutation in mutations {
+            let player = self.players.get(mutation.player_id()).ok_or(GameError::PlayerNotFound(mutation.player_id()))?;
+            match *mutation {
+                StateMutation::CashDelta { .. } => {}
+                StateMutation::DebtDelta { player_id, amount } => {
+                    let debt = debts.entry(player_id).or_insert(player.debt);
+                    if *debt + amount < Money::ZERO {
+                        return Err(GameError::Overpayment { amount: -amount, owed: *debt });
+                    }
+                    *debt += amount;
+                }
+                StateMutation::AssetDelta { player_id, asset, quantity } => {
+                    let held = holdings.entry((player_id, asset))
+                        .or_insert_with(|| player.assets.get(&asset).map_or(0, |record| record.quantity));
+                    let count = quantity.unsigned_abs();
+                    if quantity < 0 {
+                        *held = held.checked_sub(count)
+                            .ok_or(GameError::InsufficientAssets { asset, requested: count, available: *held })?;
+                        continue;
+                    }
+                    if let Some(limit) = self.rules.asset_limit(asset).filter(|limit| held.saturating_add(count) > *limit) {
+                        return Err(GameError::AssetLimitExceeded { asset, requested: count, current: *held, limit });
+                    }
+                    *held += count;
+                }
+                StateMutation::Move { to, .. } => {
+                    if to >= self.board.len() {
+                        return Err(GameError::InvalidPosition(to));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{GameError, StateMutation};
+    use crate::models::{AssetType, GameState, LedgerCategory, Money, Player, PlayerRegistry, PlayerType};
+
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        for id in 0..2 {
+            let mut player = Player::new(id, format!("Farmer {}", id), PlayerType::Human);
+            player.cash = Money(1000);
+            players.insert(player);
+        }
+        GameState::new_with_players(players, vec![0, 1], GameRules::default())
+    }
+
+    #[test]
+    fn test_a_batch_applies_in_full_and_reverts_in_full() {
+        let mut game = setup_game();
+        let before = (game.players[0].cash, game.players[0].debt, game.players[1].position);
+        let batch = [
+            StateMutation::CashDelta { player_id: 0, amount: Money(500), category: LedgerCategory::Bonus },
+            StateMutation::DebtDelta { player_id: 0, amount: Money(2000) },
+            StateMutation::AssetDelta { player_id: 0, asset: AssetType::Hay, quantity: -4 },
+            StateMutation::Move { player_id: 1, from: 0, to: 7 },
+        ];
+        game.apply_mutations(&batch).unwrap();
+        assert_eq!((game.players[0].cash, game.players[0].debt), (Money(1500), Money(2000)));
+        assert_eq!(game.players[0].assets[&AssetType::Hay].quantity, 6);
+        assert_eq!(game.players[1].position, 7);
+
+        game.revert_mutations(&batch).unwrap();
+        assert_eq!((game.players[0].cash, game.players[0].debt, game.players[1].position), before);
+        assert_eq!(game.players[0].assets[&AssetType::Hay].quantity, 10);
+        assert!(game.players[0].notes.is_empty());
+    }
+
+    #[test]
+    fn test_an_invalid_mutation_leaves_the_whole_batch_unapplied() {
+        let mut game = setup_game();
+        let batch = [
+            StateMutation::CashDelta { player_id: 0, amount: Money(500), category: LedgerCategory::Bonus },
+            StateMutation::AssetDelta { player_id: 0, asset: AssetType::Hay, quantity: -6 },
+            StateMutation::AssetDelta { player_id: 0, asset: AssetType::Hay, quantity: -6 },
+        ];
+        assert_eq!(game.apply_mutations(&batch),
+                   Err(GameError::InsufficientAssets { asset: AssetType::Hay, requested: 6, available: 4 }));
+        assert_eq!(game.players[0].cash, Money(1000));
+        assert_eq!(game.players[0].assets[&AssetType::Hay].quantity, 10);
+
+        let off_board = StateMutation::Move { player_id: 0, from: 0, to: game.board.len() };
+        assert_eq!(game.apply_mutations(&[off_board]), Err(GameError::InvalidPosition(game.board.len())));
+        assert_eq!(game.apply_mutations(&[StateMutation::DebtDelta { player_id: 1, amount: Money(-100) }]),
+                   Err(GameError::Overpayment { amount: Money(100), owed: Money::ZERO }));
+    }
+}
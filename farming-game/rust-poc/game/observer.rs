@@ -0,0 +1,71 @@
+// src/game/observer.rs
+// Hooks for code embedding the engine (a network layer, chat bots,
+// analytics) to hear about play as it happens instead of polling
+// `GameEngine::events`.
+
+use std::collections::HashMap;
+use std::fmt;
+use crate::game::GameEvent;
+use crate::models::GameState;
+use crate::models::Money;
+
+/// Receives each event once, in order, after the action that caused it.
+/// Closures taking `&GameEvent` are observers too.
+pub trait GameObserver: Send {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent) + Send> GameObserver for F {
+    fn on_event(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+/// An engine's observers, each with its place in the event stream, and the
+/// cash every player had when they were last told about it.
+#[derive(Default)]
+pub(crate) struct Observers {
+    observers: Vec<(Box<dyn GameObserver>, usize)>,
+    cash: HashMap<usize, Money>,
+}
+
+impl Observers {
+    /// Registers `observer` for events from `from` on.
+    pub(crate) fn add(&mut self, observer: Box<dyn GameObserver>, from: usize) {
+        self.observers.push((observer, from));
+    }
+
+    /// Emits `CashChanged` for every player whose cash moved since the last
+    /// call, then passes each observer the events it hasn't seen.
+    pub(crate) fn notify(&mut self, state: &mut GameState) {
+        let changes: Vec<(usize, Money, Money)> = state.players.iter()
+            .filter_map(|player| {
+                let before = self.cash.insert(player.id, player.cash)?;
+                (before != player.cash).then_some((player.id, player.cash, player.cash - before))
+            })
+            .collect();
+        for (player_id, cash, change) in changes {
+            state.emit(GameEvent::CashChanged { player_id, cash, change });
+        }
+        for (observer, seen) in self.observers.iter_mut() {
+            for event in state.events_since(*seen) {
+                observer.on_event(event);
+            }
+            *seen = state.events.len();
+        }
+    }
+}
+
+/// Copies of an engine (e.g. to look ahead) don't report to the original's
+/// observers, since what happens in them never happened in the game.
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self { observers: Vec::new(), cash: self.cash.clone() }
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Observers({})", self.observers.len())
+    }
+}
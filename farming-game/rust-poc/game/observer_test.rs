@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use crate::config::GameRules;
+    use crate::game::{Action, GameEngine, GameEvent, GameObserver};
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 3).unwrap()
+    }
+
+    /// Collects what it's told, for checking afterwards.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<GameEvent>>>);
+
+    impl GameObserver for Recorder {
+        fn on_event(&mut self, event: &GameEvent) {
+            self.0.lock().unwrap().push(event.clone());
+        }
+    }
+
+    #[test]
+    fn test_observers_hear_each_event_once_from_when_they_register() {
+        let mut engine = engine();
+        engine.apply(Action::Move(2)).unwrap();
+        let recorder = Recorder::default();
+        engine.observe(recorder.clone());
+
+        engine.apply(Action::EndTurn).unwrap();
+        engine.apply(Action::Move(3)).unwrap();
+
+        let heard = recorder.0.lock().unwrap().clone();
+        let since = engine.events().len() - heard.len();
+        assert_eq!(heard, engine.events()[since..].to_vec(), "Everything since registering, in order");
+        assert!(matches!(heard[0], GameEvent::TurnEnded { player_id: 0, .. }));
+        assert_eq!(heard[1], GameEvent::TurnStarted { player_id: 1 });
+        assert!(heard.contains(&GameEvent::DiceRolled { player_id: 1, roll: 3 }));
+    }
+
+    #[test]
+    fn test_cash_changes_are_reported_with_the_new_balance() {
+        let mut engine = engine();
+        let heard = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&heard);
+        engine.observe(move |event: &GameEvent| {
+            if let GameEvent::CashChanged { player_id, cash, change } = event {
+                sink.lock().unwrap().push((*player_id, *cash, *change));
+            }
+        });
+
+        let cash = engine.state().players[0].cash;
+        engine.apply(Action::SellCows(0)).ok();
+        assert!(heard.lock().unwrap().is_empty(), "Nothing moved, nothing reported");
+
+        engine.state_mut().players[0].cash += Money(500);
+        engine.apply(Action::EndTurn).unwrap();
+        assert_eq!(*heard.lock().unwrap(), vec![(0, cash + Money(500), Money(500))]);
+    }
+
+    #[test]
+    fn test_clones_do_not_report_to_the_original_observers() {
+        let mut engine = engine();
+        let recorder = Recorder::default();
+        engine.observe(recorder.clone());
+        let mut lookahead = engine.clone();
+        lookahead.apply(Action::Move(4)).unwrap();
+        assert!(recorder.0.lock().unwrap().is_empty());
+    }
+}
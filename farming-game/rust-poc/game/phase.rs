@@ -1,7 +1,38 @@
-#[derive(Debug, Clone, PartialEq)]
+use crate::models::{CalendarDate, Month};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GamePhase {
     SpringPlanting,
     EarlySummer,
     LateSummer,
     EndOfYear,
-} // Correct per game board
\ No newline at end of file
+} // Correct per game board
+
+impl GamePhase {
+    /// Season section a date falls in.
+    pub fn from_date(date: CalendarDate) -> Self {
+        if date <= CalendarDate::new(Month::April, 1) {
+            GamePhase::SpringPlanting // Christmas Vacation through Spring Planting
+        } else if date.month <= Month::June {
+            GamePhase::EarlySummer
+        } else if date.month <= Month::September {
+            GamePhase::LateSummer // Independence Day onwards
+        } else {
+            GamePhase::EndOfYear
+        }
+    }
+
+    /// Insurance is sold at the start of the year, before the crops go in.
+    pub fn allows_insurance(&self) -> bool {
+        matches!(self, GamePhase::SpringPlanting)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GamePhase::SpringPlanting => "Spring Planting",
+            GamePhase::EarlySummer => "Early Summer",
+            GamePhase::LateSummer => "Late Summer",
+            GamePhase::EndOfYear => "End of Year",
+        }
+    }
+}
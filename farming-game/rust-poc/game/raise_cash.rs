@@ -0,0 +1,99 @@
+// src/game/raise_cash.rs
+// Selling assets back to the bank to cover a bill, so a loan is the last
+// resort instead of the only one. Cows fetch the market price; everything
+// else fetches its resale value from the rules.
+
+use std::collections::BTreeMap;
+use crate::game::GameError;
+use crate::models::{AssetType, GameState, LedgerCategory, Money};
+
+/// Something a player owns that the bank will buy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sellable {
+    pub asset: AssetType,
+    pub owned: u32,
+    /// What the bank pays per unit
+    pub price: Money,
+}
+
+impl GameState {
+    /// What the bank pays `player_id` per unit of `asset`, if it buys it at all.
+    pub fn resale_price(&self, player_id: usize, asset: AssetType) -> Option<Money> {
+        match asset {
+            AssetType::Cows => Some(self.cow_price(self.players.get(player_id)?.year)),
+            _ => self.rules.resale_values.get(&asset).copied(),
+        }
+    }
+
+    /// Everything the player could sell to raise cash, in asset order.
+    pub fn sellable_assets(&self, player_id: usize) -> Vec<Sellable> {
+        let Some(player) = self.players.get(player_id) else { return Vec::new() };
+        let mut sellable: Vec<Sellable> = player.assets.iter()
+            .filter(|(_, record)| record.quantity > 0)
+            .filter_map(|(asset, record)| Some(Sellable {
+                asset: *asset,
+                owned: record.quantity,
+                price: self.resale_price(player_id, *asset)?,
+            }))
+            .collect();
+        sellable.sort_by_key(|sellable| sellable.asset);
+        sellable
+    }
+
+    /// Sells `quantity` of an asset back to the bank.
+    pub fn sell_to_bank(&mut self, player_id: usize, asset: AssetType, quantity: u32) -> Result<String, GameError> {
+        if asset == AssetType::Cows {
+            return self.sell_cows(player_id, quantity);
+        }
+        let price = self.check_sale(player_id, asset, quantity)?;
+        let player = &mut self.players[player_id];
+        player.sell_asset(asset, quantity, price);
+        player.cash += price * quantity;
+        tracing::info!(player_id, ?asset, quantity, %price, cash = %player.cash, "sold to the bank");
+        let log = self.locale.tr("market.sold_to_bank", &[("player", &player.name), ("quantity", &quantity),
+            ("asset", &self.locale.asset_name(&asset)), ("total", &(price * quantity))]);
+        self.update_player_valuation(player_id);
+        Ok(log)
+    }
+
+    /// Pays `amount` for `category`, first selling `sales` to the bank and
+    /// then borrowing whatever cash still doesn't cover. No sale is made
+    /// unless all of them can be.
+    pub fn settle_expense(&mut self, player_id: usize, category: LedgerCategory, amount: Money,
+                          sales: &[(AssetType, u32)], logs: &mut Vec<String>) -> Result<(), GameError> {
+        let mut totals: BTreeMap<AssetType, u32> = BTreeMap::new();
+        for &(asset, quantity) in sales {
+            *totals.entry(asset).or_default() += quantity;
+        }
+        for (asset, total) in totals {
+            self.check_sale(player_id, asset, total)?;
+        }
+        for &(asset, quantity) in sales {
+            logs.push(self.sell_to_bank(player_id, asset, quantity)?);
+        }
+        self.pay_expense(player_id, category, amount, logs)
+    }
+
+    /// The asset's resale price, if the player can sell `quantity` of it.
+    fn check_sale(&self, player_id: usize, asset: AssetType, quantity: u32) -> Result<Money, GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let owned = player.assets.get(&asset).map_or(0, |record| record.quantity);
+        if quantity == 0 || quantity > owned {
+            return Err(GameError::InsufficientAssets { asset, requested: quantity, available: owned });
+        }
+        self.resale_price(player_id, asset).ok_or(GameError::NotResellable(asset))
+    }
+
+    /// Question for a player who owes `amount` and may raise cash toward it:
+    /// how much is short and what a loan for the rest would cost.
+    pub(crate) fn raise_cash_prompt(&self, player_id: usize, amount: Money) -> String {
+        let player = &self.players[player_id];
+        let shortfall = amount - player.cash;
+        if shortfall <= Money::ZERO {
+            return self.locale.tr("decision.raise_cash_covered", &[("player", &player.name), ("amount", &amount)]);
+        }
+        let (loan, fee) = self.rules.forced_loan(shortfall);
+        self.locale.tr("decision.raise_cash", &[("player", &player.name), ("amount", &amount), ("shortfall", &shortfall),
+            ("loan", &loan), ("fee", &fee), ("debt", &(player.debt + loan))])
+    }
+}
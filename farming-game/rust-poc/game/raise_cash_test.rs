@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::config::GameRules;
+    use crate::game::{Decision, GameEffect, GameError, Sellable};
+    use crate::models::{AssetType, GameState, LedgerCategory, Money, Player, PlayerRegistry, PlayerType};
+
+    /// A farmer with Grandpa's hay and grain, 4 cows and $1,000.
+    fn setup_game(rules: GameRules) -> GameState {
+        let mut players = PlayerRegistry::new();
+        let mut player = Player::new(0, "Farmer".to_string(), PlayerType::Human);
+        player.cash = Money(1000);
+        player.add_asset(AssetType::Cows, 4, Money::ZERO);
+        players.insert(player);
+        GameState::new_with_players(players, vec![0], rules)
+    }
+
+    #[test]
+    fn test_resale_values_come_from_the_rules() {
+        let game = setup_game(GameRules::from_toml("resale_values = { Hay = 1500 }\n").unwrap());
+        assert_eq!(game.sellable_assets(0), vec![
+            Sellable { asset: AssetType::Hay, owned: 10, price: Money(1500) },
+            Sellable { asset: AssetType::Cows, owned: 4, price: Money(500) },
+        ], "Cows always sell at the market price; unlisted grain doesn't sell");
+        assert_eq!(game.resale_price(0, AssetType::Grain), None);
+    }
+
+    #[test]
+    fn test_settling_sells_first_and_borrows_the_rest() {
+        let mut game = setup_game(GameRules::default());
+        let mut logs = Vec::new();
+
+        let overdrawn = game.settle_expense(0, LedgerCategory::CardExpense, Money(5000), &[(AssetType::Hay, 6), (AssetType::Hay, 6)], &mut logs);
+        assert_eq!(overdrawn, Err(GameError::InsufficientAssets { asset: AssetType::Hay, requested: 12, available: 10 }));
+        assert_eq!(game.sell_to_bank(0, AssetType::Tractor, 1), Err(GameError::InsufficientAssets { asset: AssetType::Tractor, requested: 1, available: 0 }));
+        assert_eq!(game.players[0].cash, Money(1000), "Nothing is sold unless every sale can be made");
+
+        game.settle_expense(0, LedgerCategory::CardExpense, Money(4000), &[(AssetType::Grain, 2), (AssetType::Cows, 2)], &mut logs).unwrap();
+        let player = &game.players[0];
+        assert_eq!((player.cash, player.debt), (Money::ZERO, Money::ZERO), "$2000 of grain and $1000 of cows covered the bill");
+        assert_eq!(player.assets[&AssetType::Grain].quantity, 8);
+
+        game.settle_expense(0, LedgerCategory::CardExpense, Money(1000), &[], &mut logs).unwrap();
+        assert_eq!(game.players[0].debt, Money(5000), "Without sales it's a forced loan");
+    }
+
+    #[test]
+    fn test_ai_sells_until_a_loan_will_do() {
+        let mut game = setup_game(GameRules::default());
+        game.confirm_forced_loans = true;
+        game.players[0].debt = game.rules.max_debt;
+        let card = Card {
+            id: 900,
+            title: "Test Card".to_string(),
+            description: "Test card".to_string(),
+            description_brief: "Test card".to_string(),
+            effect: GameEffect::Expense(Money(3500)),
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        };
+        game.apply_card_effect(0, &card, &mut Vec::new()).unwrap();
+
+        let request = game.pending_decision().unwrap().clone();
+        assert_eq!(game.ai_decision(&request), Some(Decision::Sell { asset: AssetType::Cows, quantity: 4 }));
+        game.answer_decision(0, Decision::Sell { asset: AssetType::Cows, quantity: 4 }, &mut Vec::new()).unwrap();
+        assert_eq!(game.ai_decision(&request), Some(Decision::Sell { asset: AssetType::Grain, quantity: 1 }),
+                   "Then whatever the bank buys, in asset order");
+    }
+}
@@ -0,0 +1,199 @@
+// src/game/replay.rs
+// Recorded games and stepping back through them. A seeded game is fully
+// determined by its seed, players, rules and the actions applied to it, so
+// a record keeps only those; a replay re-applies the actions to rebuild the
+// game as it stood at the end of every turn. The event log carries the same
+// actions, so a game can also be rebuilt from its setup and events. Record
+// files carry a format version and are upgraded on load (see
+// `game::migrations`), so replays saved by earlier releases still open.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+use crate::config::GameRules;
+use crate::game::migrations::{self, RECORD_VERSION};
+use crate::game::{Action, GameEngine, GameError, GameEvent};
+use crate::models::{GameState, PlayerColor, PlayerType};
+
+/// Everything needed to play a seeded game again, action for action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// Format the record was written in (see `game::migrations`)
+    pub version: u32,
+    pub seed: u64,
+    /// Players in turn order
+    pub players: Vec<RecordedPlayer>,
+    pub rules: GameRules,
+    /// Actions in the order they were applied, each turn closed by `EndTurn`
+    pub actions: Vec<Action>,
+}
+
+/// A player in a record. Neither who played the seat nor its color changes
+/// how the game plays out; they're kept so a replay looks like the game did.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedPlayer {
+    pub name: String,
+    /// Strategy the seat was played by, if an AI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai: Option<String>,
+    /// Token color, if not the seat's default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<PlayerColor>,
+}
+
+/// How a seeded game began, before any action: what `GameState::from_events`
+/// rebuilds a game from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSetup {
+    pub seed: u64,
+    /// Player names in turn order
+    pub players: Vec<String>,
+    pub rules: GameRules,
+}
+
+impl GameRecord {
+    pub fn new(seed: u64, players: &[String], rules: GameRules) -> Self {
+        let players = players.iter().map(|name| RecordedPlayer { name: name.clone(), ai: None, color: None }).collect();
+        Self { version: RECORD_VERSION, seed, players, rules, actions: Vec::new() }
+    }
+
+    /// Player names in turn order.
+    pub fn names(&self) -> Vec<String> {
+        self.players.iter().map(|player| player.name.clone()).collect()
+    }
+
+    pub fn setup(&self) -> GameSetup {
+        GameSetup { seed: self.seed, players: self.names(), rules: self.rules.clone() }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("records always serialize")
+    }
+
+    /// Reads a record written by this release or, upgrading it first, an earlier one.
+    pub fn from_json(contents: &str) -> Result<Self, serde_json::Error> {
+        let record = migrations::migrate(serde_json::from_str(contents)?).map_err(serde_json::Error::custom)?;
+        serde_json::from_value(record)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_json())?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::from_json(&contents)?)
+    }
+}
+
+/// The game as it stood after one turn of a replay.
+#[derive(Debug, Clone)]
+pub struct ReplayTurn {
+    /// Whose turn it was; `None` for the starting position
+    pub player_id: Option<usize>,
+    pub state: GameState,
+    /// Log lines the turn's actions produced
+    pub logs: Vec<String>,
+}
+
+/// A recorded game rebuilt turn by turn, with a cursor to step through it.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    turns: Vec<ReplayTurn>,
+    current: usize,
+}
+
+impl Replay {
+    /// Replays every action in `record`. Fails if an action doesn't apply,
+    /// which means the record came from a different version of the rules.
+    pub fn new(record: &GameRecord) -> Result<Self, GameError> {
+        let mut engine = GameEngine::with_seed(&record.names(), record.rules.clone(), record.seed)?;
+        for (player_id, player) in record.players.iter().enumerate() {
+            let player_type = player.ai.clone().map_or(PlayerType::Human, PlayerType::AI);
+            let color = player.color.unwrap_or(engine.state().players[player_id].color);
+            engine.seat(player_id, player_type, color);
+        }
+        let mut turns = vec![ReplayTurn { player_id: None, state: engine.state().clone(), logs: Vec::new() }];
+        let mut logs = Vec::new();
+        let mut player_id = engine.current_player_id();
+        for action in &record.actions {
+            logs.extend(engine.apply(action.clone())?);
+            // An end of turn that asks for discards to the hand limit doesn't end it yet
+            let ends_turn = *action == Action::EndTurn && engine.state().pending_decision().is_none();
+            if ends_turn {
+                turns.push(ReplayTurn { player_id: Some(player_id), state: engine.state().clone(), logs: std::mem::take(&mut logs) });
+                player_id = engine.current_player_id();
+            }
+        }
+        // A game saved partway through a turn still shows how far that turn got
+        if record.actions.last().is_some_and(|action| *action != Action::EndTurn) {
+            turns.push(ReplayTurn { player_id: Some(player_id), state: engine.state().clone(), logs });
+        }
+        tracing::info!(turns = turns.len() - 1, actions = record.actions.len(), "replay rebuilt");
+        Ok(Self { turns, current: 0 })
+    }
+
+    pub fn current(&self) -> &ReplayTurn {
+        &self.turns[self.current]
+    }
+
+    /// Index of the turn being shown; 0 is the starting position.
+    pub fn position(&self) -> usize {
+        self.current
+    }
+
+    /// Number of turns played, not counting the starting position.
+    pub fn turn_count(&self) -> usize {
+        self.turns.len() - 1
+    }
+
+    /// Moves to the next turn. Returns false at the end of the game.
+    pub fn forward(&mut self) -> bool {
+        if self.current + 1 >= self.turns.len() {
+            return false;
+        }
+        self.current += 1;
+        true
+    }
+
+    /// Moves to the previous turn. Returns false at the starting position.
+    pub fn back(&mut self) -> bool {
+        if self.current == 0 {
+            return false;
+        }
+        self.current -= 1;
+        true
+    }
+
+    pub fn jump_to(&mut self, position: usize) {
+        self.current = position.min(self.turns.len() - 1);
+    }
+}
+
+impl GameState {
+    /// Rebuilds a game from how it began and its event log by applying the
+    /// logged actions (`GameEvent::ActionApplied`) in order; every other event
+    /// follows from those. A log cut off after any action rebuilds the game
+    /// as it stood then, so a player joining late can catch up from the
+    /// events so far. Fails if the rebuilt game's events don't match the log, which
+    /// means it came from another game or another version of the rules.
+    pub fn from_events(setup: &GameSetup, events: &[GameEvent]) -> Result<GameState, GameError> {
+        let mut engine = GameEngine::with_seed(&setup.players, setup.rules.clone(), setup.seed)?;
+        for (index, event) in events.iter().enumerate() {
+            if let GameEvent::ActionApplied { action, .. } = event {
+                engine.apply(action.clone()).map_err(|_| GameError::EventsDiverged(index))?;
+            }
+        }
+        let state = engine.into_state();
+        if let Some(index) = events.iter().zip(&state.events).position(|(logged, rebuilt)| logged != rebuilt) {
+            return Err(GameError::EventsDiverged(index));
+        }
+        if events.len() > state.events.len() {
+            return Err(GameError::EventsDiverged(state.events.len()));
+        }
+        Ok(state)
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{Action, GameEngine, GameError, GameEvent, GameRecord, Replay, Scenario};
+    use crate::models::GameState;
+
+    fn names() -> Vec<String> {
+        vec!["Ann".to_string(), "Bo".to_string()]
+    }
+
+    /// Plays `turns` turns of a seeded game, answering decisions as the AI would.
+    fn play(turns: usize) -> GameEngine {
+        let mut engine = GameEngine::with_seed(&names(), GameRules::default(), 11).unwrap();
+        for _ in 0..turns {
+            engine.apply(Action::Roll).unwrap();
+            while let Some(request) = engine.state().pending_decision().cloned() {
+                let decision = engine.state().ai_decision(&request).unwrap();
+                engine.apply(Action::Decide(decision)).unwrap();
+            }
+            engine.end_turn();
+        }
+        engine
+    }
+
+    #[test]
+    fn test_replay_rebuilds_every_turn_of_a_recorded_game() {
+        let engine = play(12);
+        let record = GameRecord::from_json(&engine.record().unwrap().to_json()).unwrap();
+        assert_eq!(&record, engine.record().unwrap());
+
+        let mut replay = Replay::new(&record).unwrap();
+        assert_eq!(replay.turn_count(), 12);
+        assert_eq!(replay.current().player_id, None);
+
+        replay.jump_to(usize::MAX);
+        let last = &replay.current().state;
+        for (replayed, played) in last.players.iter().zip(engine.state().players.iter()) {
+            assert_eq!(replayed.cash, played.cash);
+            assert_eq!(replayed.debt, played.debt);
+            assert_eq!(replayed.position, played.position);
+            assert_eq!(replayed.year, played.year);
+        }
+        assert!(!replay.forward());
+        assert_eq!(replay.current().player_id, Some(1), "Turns alternate between the two players");
+    }
+
+    #[test]
+    fn test_stepping_stops_at_either_end() {
+        let mut replay = Replay::new(play(2).record().unwrap()).unwrap();
+        assert!(!replay.back());
+        assert!(replay.forward());
+        assert_eq!(replay.current().player_id, Some(0));
+        assert!(!replay.current().logs.is_empty());
+        assert!(replay.forward());
+        assert!(!replay.forward());
+        assert_eq!(replay.position(), 2);
+        assert!(replay.back());
+        assert_eq!(replay.position(), 1);
+    }
+
+    #[test]
+    fn test_only_seeded_games_are_recorded() {
+        let unseeded = GameEngine::with_players(&names(), GameRules::default()).unwrap();
+        assert!(unseeded.record().is_none());
+
+        let scenario = Scenario::builtin().remove(0);
+        let with_scenario = GameEngine::with_scenario(&names(), &scenario, Some(3)).unwrap();
+        assert!(with_scenario.record().is_none());
+    }
+
+    #[test]
+    fn test_from_events_rebuilds_the_game_from_its_log() {
+        let engine = play(10);
+        let setup = engine.setup().unwrap();
+        let rebuilt = GameState::from_events(&setup, engine.events()).unwrap();
+        assert_eq!(rebuilt.events, engine.events());
+        assert_eq!(rebuilt.current_turn_index, engine.state().current_turn_index);
+        for (rebuilt, played) in rebuilt.players.iter().zip(engine.state().players.iter()) {
+            assert_eq!(rebuilt.cash, played.cash);
+            assert_eq!(rebuilt.debt, played.debt);
+            assert_eq!(rebuilt.position, played.position);
+        }
+    }
+
+    #[test]
+    fn test_from_events_catches_up_from_a_log_cut_off_after_an_action() {
+        let engine = play(10);
+        let events = engine.events();
+        let cut = events.iter().rposition(|event| matches!(event, GameEvent::ActionApplied { .. })).unwrap() / 2;
+        let cut = cut + events[cut..].iter().position(|event| matches!(event, GameEvent::ActionApplied { .. })).unwrap() + 1;
+        let rebuilt = GameState::from_events(&engine.setup().unwrap(), &events[..cut]).unwrap();
+        assert_eq!(rebuilt.events, &events[..cut]);
+    }
+
+    #[test]
+    fn test_from_events_rejects_a_log_from_another_game() {
+        let engine = play(4);
+        let mut events = engine.events().to_vec();
+        let index = events.iter().position(|event| matches!(event, GameEvent::ActionApplied { .. })).unwrap();
+        if let GameEvent::ActionApplied { player_id, .. } = &mut events[index] {
+            *player_id += 1;
+        }
+        let result = GameState::from_events(&engine.setup().unwrap(), &events);
+        assert!(matches!(result, Err(GameError::EventsDiverged(at)) if at == index));
+    }
+}
@@ -0,0 +1,52 @@
+// src/game/ridge_dangers.rs
+// What can go wrong up on the ridges. Each ridge a player has cows on rolls
+// once a year, as they pass Go, against the rules' danger table: coyotes
+// take part of the herd, and a hard winter means buying feed for all of it.
+// A player the bank won't lend the feed money to loses the herd instead.
+
+use crate::config::RidgeThreat;
+use crate::game::{Dice, GameEvent};
+use crate::models::{GameState, LedgerCategory};
+
+impl GameState {
+    /// Rolls for every ridge `player_id` has stocked as they start a new year.
+    pub(crate) fn roll_ridge_dangers(&mut self, player_id: usize, logs: &mut Vec<String>) {
+        let stocked: Vec<usize> = self.ridges.iter().enumerate()
+            .filter(|(_, ridge)| ridge.leased_by == Some(player_id) && ridge.cow_count > 0)
+            .map(|(index, _)| index)
+            .collect();
+        let player_name = self.players[player_id].name.clone();
+        for index in stocked {
+            let roll = Dice::ONE.roll(&mut self.rng).total();
+            let Some(danger) = self.rules.ridge_dangers.iter().find(|danger| danger.roll == roll).copied() else { continue };
+            let ridge = &mut self.ridges[index];
+            let ridge_name = ridge.name.clone();
+            match danger.threat {
+                RidgeThreat::Coyotes { percent } => {
+                    let herd = ridge.cow_count.unsigned_abs();
+                    let lost = i32::try_from(herd.saturating_mul(percent).div_ceil(100).min(herd)).unwrap_or(ridge.cow_count);
+                    if lost == 0 {
+                        continue;
+                    }
+                    ridge.cow_count -= lost;
+                    tracing::info!(player_id, ridge = %ridge_name, lost, "coyotes on the ridge");
+                    logs.push(self.locale.tr("ridge.coyotes", &[("player", &player_name), ("ridge", &ridge_name), ("cows", &lost)]));
+                    self.emit(GameEvent::RidgeCowsLost { player_id, ridge: ridge_name, cows: lost.unsigned_abs() });
+                }
+                RidgeThreat::HardWinter { feed_per_cow } => {
+                    let cows = ridge.cow_count;
+                    let feed = feed_per_cow * cows;
+                    tracing::info!(player_id, ridge = %ridge_name, cows, %feed, "hard winter on the ridge");
+                    logs.push(self.locale.tr("ridge.hard_winter", &[("player", &player_name), ("ridge", &ridge_name),
+                        ("cows", &cows), ("amount", &feed)]));
+                    if self.pay_expense(player_id, LedgerCategory::CardExpense, feed, logs).is_err() {
+                        self.ridges[index].cow_count = 0;
+                        logs.push(self.locale.tr("ridge.herd_starved", &[("player", &player_name), ("ridge", &ridge_name), ("cows", &cows)]));
+                        self.emit(GameEvent::RidgeCowsLost { player_id, ridge: ridge_name, cows: cows.unsigned_abs() });
+                    }
+                }
+            }
+        }
+        self.update_player_valuation(player_id);
+    }
+}
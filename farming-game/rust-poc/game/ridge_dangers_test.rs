@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{GameRules, RidgeDanger, RidgeThreat};
+    use crate::game::{GameEvent, GameStateBuilder};
+    use crate::models::{GameState, LedgerCategory, Money, Player, PlayerType};
+
+    /// Every roll brings `threat`, so the dice don't matter.
+    fn always(threat: RidgeThreat) -> Vec<RidgeDanger> {
+        (1..=6).map(|roll| RidgeDanger { roll, threat }).collect()
+    }
+
+    /// One player with 20 cows on Ahtanum Ridge.
+    fn setup_game(ridge_dangers: Vec<RidgeDanger>) -> GameState {
+        let mut player = Player::new(0, "Rancher".to_string(), PlayerType::Human);
+        player.cash = Money(5000);
+        let mut game = GameStateBuilder::default()
+            .add_player(player)
+            .rules(GameRules { ridge_dangers, ..GameRules::default() })
+            .build()
+            .unwrap();
+        game.ridges[1].lease(0, 20, 20).unwrap();
+        game
+    }
+
+    #[test]
+    fn test_coyotes_take_part_of_the_herd_each_year() {
+        let mut game = setup_game(always(RidgeThreat::Coyotes { percent: 15 }));
+        let mut logs = Vec::new();
+
+        game.pass_start(0, &mut logs);
+        assert_eq!(game.ridges[1].cow_count, 17, "15% of 20 rounds up to 3");
+        assert!(logs.iter().any(|log| log == "Coyotes took 3 of Rancher's cows on Ahtanum Ridge."));
+        assert!(game.events.contains(&GameEvent::RidgeCowsLost { player_id: 0, ridge: "Ahtanum Ridge".to_string(), cows: 3 }));
+        assert_eq!(game.ridges[0].cow_count, 0, "Unleased ridges have nothing to lose");
+    }
+
+    #[test]
+    fn test_hard_winter_is_fed_on_credit_or_the_herd_is_lost() {
+        let mut game = setup_game(always(RidgeThreat::HardWinter { feed_per_cow: Money(100) }));
+        let mut logs = Vec::new();
+
+        game.roll_ridge_dangers(0, &mut logs);
+        assert_eq!(game.players[0].cash, Money(3000));
+        assert_eq!(game.players[0].ledger.total(LedgerCategory::CardExpense), Money(-2000));
+        assert_eq!(game.ridges[1].cow_count, 20);
+        assert!(!game.events.iter().any(|event| matches!(event, GameEvent::RidgeCowsLost { .. })), "A fed herd is kept");
+
+        game.players[0].cash = Money::ZERO;
+        game.rules.max_debt = Money::ZERO;
+        game.roll_ridge_dangers(0, &mut logs);
+        assert_eq!(game.ridges[1].cow_count, 0);
+        assert_eq!(game.ridges[1].leased_by, Some(0), "The lease outlives the herd");
+        assert!(logs.last().unwrap().contains("lost the herd"));
+        assert_eq!(game.events.last(), Some(&GameEvent::RidgeCowsLost { player_id: 0, ridge: "Ahtanum Ridge".to_string(), cows: 20 }));
+    }
+
+    #[test]
+    fn test_dangers_come_from_the_rules() {
+        let rules = GameRules::from_toml("ridge_dangers = [{ roll = 3, threat = \"hard_winter\", feed_per_cow = 75 }]\n").unwrap();
+        assert_eq!(rules.ridge_dangers, vec![RidgeDanger { roll: 3, threat: RidgeThreat::HardWinter { feed_per_cow: Money(75) } }]);
+        assert_eq!(GameRules::default().ridge_dangers.len(), 2);
+
+        let mut game = setup_game(Vec::new());
+        let mut logs = Vec::new();
+        game.roll_ridge_dangers(0, &mut logs);
+        assert_eq!((game.ridges[1].cow_count, game.players[0].cash), (20, Money(5000)), "No table, no dangers");
+        assert!(logs.is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+// src/game/scenario.rs
+// Single-player challenges: preset starting conditions and win conditions, loaded from TOML.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use thiserror::Error;
+use crate::config::GameRules;
+use crate::models::{format_money, AssetType, GameState, Player, Money};
+
+/// Scenarios shipped with the game, from `scenarios/`.
+const BUILTIN_SCENARIOS: [&str; 2] = [
+    include_str!("../scenarios/deep_in_debt.toml"),
+    include_str!("../scenarios/orchardist.toml"),
+];
+
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error("Could not parse scenario: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Invalid scenario '{name}': {reason}")]
+    Invalid { name: String, reason: String },
+}
+
+/// A challenge, e.g.
+///
+/// ```toml
+/// name = "Deep in Debt"
+/// description = "Start $30,000 in debt with 30 cows."
+///
+/// [start]
+/// debt = 30000
+/// assets = { Cows = 30 }
+///
+/// [rules]
+/// farm_cow_limit = 30
+///
+/// [win]
+/// net_worth = 100000
+/// debt_free = true
+/// by_year = 10
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub start: ScenarioStart,
+    /// House rules for the scenario; unset fields keep their defaults
+    #[serde(default)]
+    pub rules: GameRules,
+    pub win: WinCondition,
+}
+
+/// Applied to every player in place of the usual starting position.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScenarioStart {
+    pub cash: Option<Money>,
+    pub debt: Option<Money>,
+    pub land: Option<i32>,
+    /// Replaces Grandpa's 10 hay and 10 grain acres when set
+    pub assets: Option<HashMap<AssetType, u32>>,
+}
+
+/// Every goal that is set must be met at once. With `by_year`, the player
+/// loses if they are still short when that year ends.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WinCondition {
+    pub net_worth: Option<Money>,
+    pub debt_free: bool,
+    /// Minimum holdings, e.g. `{ Fruit = 25 }`
+    pub assets: HashMap<AssetType, u32>,
+    pub by_year: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerOutcome {
+    InProgress,
+    Won,
+    /// Ran out of time on a scenario's `by_year` or the rules' `year_limit`,
+    /// or went bankrupt
+    Lost,
+}
+
+impl Scenario {
+    /// Parses and validates a scenario.
+    pub fn from_toml(contents: &str) -> Result<Self, ScenarioError> {
+        let scenario: Scenario = toml::from_str(contents)?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Loads a scenario from a TOML file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::from_toml(&contents)?)
+    }
+
+    /// The scenarios that ship with the game.
+    pub fn builtin() -> Vec<Scenario> {
+        BUILTIN_SCENARIOS.iter()
+            .map(|contents| Self::from_toml(contents).expect("built-in scenarios are valid"))
+            .collect()
+    }
+
+    /// Finds a built-in scenario by name, ignoring case, spaces and underscores
+    /// (so `deep_in_debt` finds "Deep in Debt").
+    pub fn find_builtin(name: &str) -> Option<Scenario> {
+        let key = |s: &str| s.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+        Self::builtin().into_iter().find(|scenario| key(&scenario.name) == key(name))
+    }
+
+    /// Checks the scenario can start a playable game under its own rules.
+    pub fn validate(&self) -> Result<(), ScenarioError> {
+        let invalid = |reason: String| Err(ScenarioError::Invalid { name: self.name.clone(), reason });
+        if self.name.trim().is_empty() {
+            return invalid("name is empty".to_string());
+        }
+        if self.start.cash.is_some_and(|cash| cash < Money::ZERO) {
+            return invalid("starting cash is negative".to_string());
+        }
+        if let Some(debt) = self.start.debt {
+            if debt < Money::ZERO || debt > self.rules.max_debt {
+                return invalid(format!("starting debt must be between $0 and {}", format_money(self.rules.max_debt)));
+            }
+        }
+        if self.start.land.is_some_and(|land| land < 0) {
+            return invalid("starting land is negative".to_string());
+        }
+        for (asset, quantity) in self.start.assets.iter().flatten() {
+            if let Some(limit) = self.rules.asset_limit(*asset).filter(|limit| quantity > limit) {
+                return invalid(format!("{} {} exceeds the farm limit of {}", quantity, asset, limit));
+            }
+        }
+        let win = &self.win;
+        if win.net_worth.is_none() && !win.debt_free && win.assets.is_empty() {
+            return invalid("win condition has no goals".to_string());
+        }
+        Ok(())
+    }
+
+    /// Puts every player in the scenario's starting position and adopts its rules.
+    pub fn apply(&self, game: &mut GameState) {
+        game.rules = self.rules.clone();
+        game.harvest_manager.apply_rules(&self.rules);
+        for player in game.players.iter_mut() {
+            if let Some(cash) = self.start.cash {
+                player.cash = cash;
+            }
+            if let Some(debt) = self.start.debt {
+                player.debt = debt;
+                player.reconcile_notes(self.rules.interest_rate);
+            }
+            if let Some(land) = self.start.land {
+                player.land = land;
+            }
+            if let Some(assets) = &self.start.assets {
+                player.assets.clear();
+                for (asset, quantity) in assets.iter().filter(|(_, quantity)| **quantity > 0) {
+                    player.add_asset(*asset, *quantity, Money::ZERO);
+                }
+            }
+        }
+        game.scenario = Some(self.clone());
+        game.update_all_valuations();
+        tracing::info!(scenario = %self.name, "scenario applied");
+    }
+}
+
+impl WinCondition {
+    pub fn is_met(&self, player: &Player) -> bool {
+        self.net_worth.is_none_or(|target| player.net_worth >= target)
+            && (!self.debt_free || player.debt == Money::ZERO)
+            && self.assets.iter().all(|(asset, minimum)| {
+                player.assets.get(asset).map_or(0, |record| record.quantity) >= *minimum
+            })
+    }
+}
+
+impl GameState {
+    /// Whether the player has won: the scenario's win condition when playing
+    /// one, otherwise the rules' winning net worth.
+    pub fn player_outcome(&self, player_id: usize) -> PlayerOutcome {
+        let Some(player) = self.players.get(player_id) else { return PlayerOutcome::InProgress };
+        if !player.is_active {
+            return PlayerOutcome::Lost;
+        }
+        if self.last_player_standing() == Some(player_id) {
+            return PlayerOutcome::Won;
+        }
+        if self.lost_tie_break(player_id) {
+            return PlayerOutcome::Lost;
+        }
+        let won = self.meets_win_condition(player);
+        match self.deadline() {
+            _ if won => PlayerOutcome::Won,
+            Some(year) if player.year > year => PlayerOutcome::Lost,
+            _ => PlayerOutcome::InProgress,
+        }
+    }
+
+    /// Whether `player` has reached the scenario's win condition when playing
+    /// one, otherwise the rules' winning net worth.
+    pub fn meets_win_condition(&self, player: &Player) -> bool {
+        match &self.scenario {
+            None => player.net_worth >= self.rules.winning_net_worth,
+            Some(scenario) => scenario.win.is_met(player),
+        }
+    }
+
+    /// Last year a win still counts: the scenario's `by_year` when playing
+    /// one, otherwise the rules' `year_limit`.
+    pub fn deadline(&self) -> Option<u32> {
+        match &self.scenario {
+            Some(scenario) => scenario.win.by_year,
+            None => self.rules.year_limit,
+        }
+    }
+
+    /// Years the player has farmed, counting the one in progress but none
+    /// past the deadline.
+    pub fn years_played(&self, player_id: usize) -> u32 {
+        let year = self.players.get(player_id).map_or(1, |player| player.year);
+        year.min(self.deadline().unwrap_or(u32::MAX)).max(1)
+    }
+
+    /// Net worth per year played, the score for a run against the clock.
+    pub fn net_worth_per_year(&self, player_id: usize) -> Money {
+        let net_worth = self.players.get(player_id).map_or(Money::ZERO, |player| player.net_worth);
+        net_worth / self.years_played(player_id)
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{GameEngine, PlayerOutcome, Scenario, ScenarioError};
+    use crate::models::AssetType;
+    use crate::models::Money;
+
+    fn names() -> Vec<String> {
+        vec!["Solo".to_string(), "Rival".to_string()]
+    }
+
+    #[test]
+    fn test_builtin_scenarios_are_valid_and_found_by_name() {
+        assert_eq!(Scenario::builtin().len(), 2);
+        let scenario = Scenario::find_builtin("deep_in_debt").expect("Deep in Debt is built in");
+        assert_eq!(scenario.name, "Deep in Debt");
+        assert_eq!(scenario.start.debt, Some(Money(30000)));
+        assert_eq!(scenario.rules.farm_cow_limit, 30);
+        assert!(Scenario::find_builtin("Orchardist").is_some());
+        assert!(Scenario::find_builtin("no such scenario").is_none());
+    }
+
+    #[test]
+    fn test_scenario_validation() {
+        let too_many_cows = "name = \"Ranch\"\n[start]\nassets = { Cows = 40 }\n[win]\nnet_worth = 1\n";
+        assert!(matches!(Scenario::from_toml(too_many_cows), Err(ScenarioError::Invalid { .. })));
+
+        let no_goals = "name = \"Idle\"\n[win]\n";
+        assert!(matches!(Scenario::from_toml(no_goals), Err(ScenarioError::Invalid { .. })));
+
+        let over_debt_cap = "name = \"Broke\"\n[start]\ndebt = 60000\n[win]\ndebt_free = true\n";
+        assert!(matches!(Scenario::from_toml(over_debt_cap), Err(ScenarioError::Invalid { .. })));
+
+        let unknown_field = "name = \"Typo\"\n[start]\ncows = 5\n[win]\nnet_worth = 1\n";
+        assert!(matches!(Scenario::from_toml(unknown_field), Err(ScenarioError::Parse(_))));
+    }
+
+    #[test]
+    fn test_scenario_sets_starting_position_for_every_player() {
+        let scenario = Scenario::find_builtin("orchardist").unwrap();
+        let engine = GameEngine::with_scenario(&names(), &scenario, Some(5)).unwrap();
+        let state = engine.state();
+
+        for player in state.players.iter() {
+            assert_eq!(player.cash, Money(5000));
+            assert_eq!(player.assets.len(), 1, "Only the orchard, no hay or grain");
+            assert_eq!(player.assets[&AssetType::Fruit].quantity, 5);
+        }
+        assert_eq!(state.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_scenario_outcome_and_winner() {
+        let scenario = Scenario::find_builtin("deep_in_debt").unwrap();
+        let mut engine = GameEngine::with_scenario(&names(), &scenario, Some(5)).unwrap();
+        assert_eq!(engine.winner(), None);
+
+        // Net worth alone is not enough while still in debt
+        let rival = engine.state_mut().players.get_mut(1).unwrap();
+        rival.net_worth = Money(200_000);
+        assert_eq!(engine.state().player_outcome(1), PlayerOutcome::InProgress);
+        engine.state_mut().players.get_mut(1).unwrap().debt = Money::ZERO;
+        assert_eq!(engine.winner(), Some(1));
+
+        engine.state_mut().players.get_mut(0).unwrap().year = 11;
+        assert_eq!(engine.state().player_outcome(0), PlayerOutcome::Lost);
+    }
+
+    #[test]
+    fn test_solo_run_is_lost_after_the_year_limit_and_scored_per_year() {
+        let rules = GameRules { year_limit: Some(5), ..GameRules::default() };
+        let mut engine = GameEngine::with_seed(&["Solo".to_string()], rules, 2).unwrap();
+        assert_eq!(engine.state().deadline(), Some(5));
+
+        let player = engine.state_mut().players.get_mut(0).unwrap();
+        player.year = 5;
+        player.net_worth = Money(100_000);
+        assert_eq!(engine.state().player_outcome(0), PlayerOutcome::InProgress, "The last year still counts");
+        assert_eq!(engine.state().net_worth_per_year(0), Money(20_000));
+
+        engine.state_mut().players.get_mut(0).unwrap().year = 6;
+        assert_eq!(engine.state().player_outcome(0), PlayerOutcome::Lost);
+        assert_eq!(engine.state().years_played(0), 5, "Years past the limit aren't counted");
+
+        let player = engine.state_mut().players.get_mut(0).unwrap();
+        player.net_worth = player.net_worth.max(GameRules::default().winning_net_worth);
+        assert_eq!(engine.winner(), Some(0), "A win stands even when it's noticed late");
+    }
+}
@@ -0,0 +1,133 @@
+// src/game/spectator.rs
+// Read-only snapshots of the game for spectators. A snapshot holds only what
+// anyone watching the table could see, built from the same OpponentView the
+// players get of each other, so hands and deck order stay hidden. The writer
+// drops it as JSON in a file a spectator UI or stream overlay can poll.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+use crate::models::player_view::OpponentView;
+use crate::models::GameState;
+use crate::models::Money;
+
+/// One player's public standing.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Standing {
+    /// 1 for the richest player, by net worth
+    pub rank: usize,
+    pub id: usize,
+    pub name: String,
+    pub cash: Money,
+    pub debt: Money,
+    pub land: i32,
+    pub net_worth: Money,
+    pub position: usize,
+    pub year: u32,
+    /// Asset quantities by asset name, leaving out anything the player has none of
+    pub assets: BTreeMap<String, u32>,
+    /// Farmer's Fate cards played face up in front of the player
+    pub face_up_cards: Vec<String>,
+    /// Option to Buy cards in hand, which spectators only see the backs of
+    pub hand_size: usize,
+}
+
+impl Standing {
+    fn of(view: &OpponentView) -> Self {
+        Self {
+            rank: 0,
+            id: view.id,
+            name: view.name.clone(),
+            cash: view.cash,
+            debt: view.debt,
+            land: view.land,
+            net_worth: view.net_worth,
+            position: view.position,
+            year: view.year,
+            assets: view.assets.iter()
+                .filter(|(_, record)| record.quantity > 0)
+                .map(|(asset, record)| (format!("{:?}", asset), record.quantity))
+                .collect(),
+            face_up_cards: view.active_persistent_cards.iter().map(|(card, _)| card.title.clone()).collect(),
+            hand_size: view.hand_size,
+        }
+    }
+}
+
+/// The public state of the whole table at one moment.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpectatorSnapshot {
+    /// Counts up with every snapshot a writer emits, so watchers can spot new ones
+    pub sequence: u64,
+    pub current_player_id: usize,
+    pub phase: String,
+    /// The current player's year, with its weather and cow price once they're rolled
+    pub year: u32,
+    pub weather: Option<String>,
+    pub cow_price: Option<Money>,
+    /// Players from richest to poorest
+    pub standings: Vec<Standing>,
+    pub winner: Option<usize>,
+}
+
+impl SpectatorSnapshot {
+    pub fn take(game: &GameState, sequence: u64) -> Self {
+        let current_player_id = game.turn_order[game.current_turn_index];
+        let year = game.players.get(current_player_id).map_or(0, |player| player.year);
+        let mut standings: Vec<Standing> = game.turn_order.iter()
+            .filter_map(|&id| game.players.get(id))
+            .map(|player| Standing::of(&OpponentView::of(player)))
+            .collect();
+        // Stable sort keeps turn order between players with the same net worth
+        standings.sort_by_key(|standing| std::cmp::Reverse(standing.net_worth));
+        for (i, standing) in standings.iter_mut().enumerate() {
+            standing.rank = i + 1;
+        }
+        Self {
+            sequence,
+            current_player_id,
+            phase: game.phase.label().to_string(),
+            year,
+            weather: game.weather_by_year.get(&year).map(|weather| weather.label().to_string()),
+            cow_price: game.cow_price_by_year.get(&year).copied(),
+            standings,
+            winner: game.winner(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("snapshot fields always serialize")
+    }
+}
+
+/// Writes a fresh snapshot to one file each time it's asked to.
+#[derive(Debug, Clone)]
+pub struct SnapshotWriter {
+    path: PathBuf,
+    sequence: u64,
+}
+
+impl SnapshotWriter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), sequence: 0 }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the game's current public state. The file is replaced in one
+    /// rename, so a reader never sees half a snapshot.
+    pub fn write(&mut self, game: &GameState) -> io::Result<()> {
+        self.sequence += 1;
+        let snapshot = SpectatorSnapshot::take(game, self.sequence);
+        let mut partial = self.path.clone().into_os_string();
+        partial.push(".tmp");
+        fs::write(&partial, snapshot.to_json())?;
+        fs::rename(&partial, &self.path)?;
+        tracing::debug!(path = %self.path.display(), sequence = self.sequence, "spectator snapshot written");
+        Ok(())
+    }
+}
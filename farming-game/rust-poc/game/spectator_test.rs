@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::cards::card::{Card, CardSource};
+    use crate::config::GameRules;
+    use crate::game::{GameEffect, SnapshotWriter, SpectatorSnapshot};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Ann".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Bo".to_string(), PlayerType::Human));
+        players.insert(Player::new(2, "Cy".to_string(), PlayerType::Human));
+        GameState::new_with_players(players, vec![0, 1, 2], GameRules::default())
+    }
+
+    fn secret_card() -> Card {
+        Card {
+            id: 901,
+            title: "Secret Orchard".to_string(),
+            description: "Buy 5 acres of fruit".to_string(),
+            description_brief: "Fruit".to_string(),
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Fruit, quantity: 5, cost: Money(25000) },
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_ranks_players_and_hides_their_hands() {
+        let mut game = setup_game();
+        game.players[1].hand.push(secret_card());
+        game.players[1].add_asset(AssetType::Cows, 10, Money::ZERO);
+        game.players[1].net_worth = Money(90000);
+        game.players[2].net_worth = Money(60000);
+        game.players[0].net_worth = Money(30000);
+
+        let snapshot = SpectatorSnapshot::take(&game, 1);
+        let order: Vec<usize> = snapshot.standings.iter().map(|standing| standing.id).collect();
+        assert_eq!(order, vec![1, 2, 0]);
+        assert_eq!(snapshot.standings[0].rank, 1);
+        assert_eq!(snapshot.standings[0].hand_size, 1);
+        assert_eq!(snapshot.standings[0].assets.get("Cows"), Some(&10));
+        assert_eq!(snapshot.current_player_id, 0);
+
+        let json = snapshot.to_json();
+        assert!(json.contains("\"name\": \"Bo\""));
+        assert!(!json.contains("Secret Orchard"));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["standings"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_writer_replaces_the_file_with_each_snapshot() {
+        let path = std::env::temp_dir().join(format!("farming_game_spectator_{}.json", std::process::id()));
+        let mut writer = SnapshotWriter::new(&path);
+        let mut game = setup_game();
+
+        writer.write(&game).unwrap();
+        game.current_turn_index = 1;
+        writer.write(&game).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(parsed["sequence"], 2);
+        assert_eq!(parsed["current_player_id"], 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+// src/game/standings.rs
+// Players ranked by net worth, and how their places moved over the last turn.
+// Net worths are snapshotted as each turn ends; the older of the last two
+// snapshots is the baseline movement is measured against.
+
+use std::collections::BTreeMap;
+use crate::models::GameState;
+use crate::models::Money;
+
+/// Turn-end snapshots kept: the last one and the baseline before it.
+pub const SNAPSHOTS_KEPT: usize = 2;
+
+/// One player's place at the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranking {
+    pub player_id: usize,
+    /// 1 for the richest player
+    pub rank: usize,
+    /// Places gained (positive) or lost since before the last turn
+    pub movement: i32,
+}
+
+/// Ranks for `net_worths`, listed in turn order. Players with the same net
+/// worth keep their turn order.
+fn ranks(net_worths: &[(usize, Money)]) -> BTreeMap<usize, usize> {
+    let mut sorted = net_worths.to_vec();
+    sorted.sort_by_key(|(_, net_worth)| std::cmp::Reverse(*net_worth));
+    sorted.iter().enumerate().map(|(i, (player_id, _))| (*player_id, i + 1)).collect()
+}
+
+impl GameState {
+    fn net_worths(&self) -> Vec<(usize, Money)> {
+        self.turn_order.iter()
+            .filter_map(|id| self.players.get(*id))
+            .map(|player| (player.id, player.net_worth))
+            .collect()
+    }
+
+    /// Records every player's net worth as a turn ends.
+    pub fn snapshot_net_worths(&mut self) {
+        let snapshot = self.net_worths().into_iter().collect();
+        self.net_worth_snapshots.push_back(snapshot);
+        while self.net_worth_snapshots.len() > SNAPSHOTS_KEPT {
+            self.net_worth_snapshots.pop_front();
+        }
+    }
+
+    /// Every player, richest first.
+    pub fn rankings(&self) -> Vec<Ranking> {
+        let now = ranks(&self.net_worths());
+        let before = match self.net_worth_snapshots.len() {
+            SNAPSHOTS_KEPT => {
+                let baseline = &self.net_worth_snapshots[0];
+                let net_worths: Vec<(usize, Money)> = self.turn_order.iter()
+                    .filter_map(|id| baseline.get(id).map(|net_worth| (*id, *net_worth)))
+                    .collect();
+                ranks(&net_worths)
+            }
+            _ => now.clone(),
+        };
+        let mut rankings: Vec<Ranking> = now.iter().map(|(player_id, rank)| Ranking {
+            player_id: *player_id,
+            rank: *rank,
+            movement: before.get(player_id).map_or(0, |was| *was as i32 - *rank as i32),
+        }).collect();
+        rankings.sort_by_key(|ranking| ranking.rank);
+        rankings
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{GameEngine, Ranking};
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string(), "Cy".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 4).unwrap()
+    }
+
+    fn set_net_worths(engine: &mut GameEngine, net_worths: [i32; 3]) {
+        for (id, net_worth) in net_worths.into_iter().enumerate() {
+            engine.state_mut().players[id].net_worth = Money::from(net_worth);
+        }
+    }
+
+    #[test]
+    fn test_ties_keep_turn_order_and_nobody_moves_before_two_turns_end() {
+        let mut engine = engine();
+        set_net_worths(&mut engine, [100, 300, 100]);
+        let rankings = engine.state().rankings();
+        let order: Vec<usize> = rankings.iter().map(|ranking| ranking.player_id).collect();
+        let first = engine.state().turn_order.iter().copied().find(|id| *id != 1).unwrap();
+        assert_eq!(rankings[0], Ranking { player_id: 1, rank: 1, movement: 0 });
+        assert_eq!(order[1], first, "Equal net worths rank in turn order");
+
+        engine.end_turn();
+        assert!(engine.state().rankings().iter().all(|ranking| ranking.movement == 0));
+    }
+
+    #[test]
+    fn test_movement_is_measured_from_before_the_last_turn() {
+        let mut engine = engine();
+        set_net_worths(&mut engine, [300, 200, 100]);
+        engine.end_turn();
+        set_net_worths(&mut engine, [300, 100, 200]);
+        engine.end_turn();
+
+        let rankings = engine.state().rankings();
+        let movement = |id: usize| rankings.iter().find(|ranking| ranking.player_id == id).unwrap().movement;
+        assert_eq!((movement(0), movement(1), movement(2)), (0, -1, 1));
+
+        // Another quiet turn and the arrows settle
+        engine.end_turn();
+        assert!(engine.state().rankings().iter().all(|ranking| ranking.movement == 0));
+        assert_eq!(engine.state().net_worth_snapshots.len(), 2);
+    }
+}
@@ -0,0 +1,88 @@
+// src/game/turn_history.rs
+// Every turn a player has taken, rebuilt from the event stream, so a
+// hot-seat table can settle "wait, when did you get that loan?" without
+// scrolling the log. A turn runs from the end of the turn before it, whoever's
+// that was, to its own `TurnEnded`; the turn being played is included as it
+// stands. Cash changes compare the cash each turn ended with, so money a
+// player lost or gained on someone else's turn shows up on their next one.
+
+use crate::config::STARTING_CASH;
+use crate::game::GameEvent;
+use crate::models::GameState;
+use crate::models::Money;
+
+/// What one player did on one of their turns.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TurnRecord {
+    pub player_id: usize,
+    /// Counts the player's own turns, from 1
+    pub number: usize,
+    /// Game year the turn ended in, or is being played in
+    pub year: u32,
+    /// Dice rolled to move
+    pub rolls: Vec<u32>,
+    /// Tiles landed on, including ones cards and tiles sent the player to
+    pub tiles: Vec<usize>,
+    /// Titles of the cards whose effects were applied
+    pub cards: Vec<String>,
+    /// Notes taken out, by principal
+    pub loans: Vec<Money>,
+    /// Cash paid toward debt
+    pub repaid: Money,
+    /// Cash at the end of the turn less cash at the end of the player's last one
+    pub cash_change: Money,
+    pub cash: Money,
+    pub debt: Money,
+    /// Whether this is the turn being played now
+    pub in_progress: bool,
+}
+
+impl TurnRecord {
+    fn record(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::DiceRolled { roll, .. } => self.rolls.push(*roll),
+            GameEvent::TileResolved { tile, .. } => self.tiles.push(*tile),
+            GameEvent::CardPlayed { card, .. } => self.cards.push(card.clone()),
+            GameEvent::LoanTaken { amount, .. } => self.loans.push(*amount),
+            GameEvent::DebtRepaid { amount, .. } => self.repaid += *amount,
+            _ => {}
+        }
+    }
+}
+
+impl GameState {
+    /// Every turn `player_id` has taken, oldest first, with the turn they're
+    /// playing now last if it's theirs. Empty for a player not in the game.
+    pub fn turn_history(&self, player_id: usize) -> Vec<TurnRecord> {
+        let Some(player) = self.players.get(player_id) else { return Vec::new() };
+        let mut turns = Vec::new();
+        let mut turn = TurnRecord { player_id, number: 1, ..TurnRecord::default() };
+        let mut cash_before = self.rules.starting_cash.unwrap_or(STARTING_CASH);
+        for event in &self.events {
+            match event {
+                GameEvent::TurnEnded { player_id: ended, year, cash, debt } if *ended == player_id => {
+                    let number = turn.number + 1;
+                    turns.push(TurnRecord { year: *year, cash_change: *cash - cash_before, cash: *cash, debt: *debt, ..turn });
+                    cash_before = *cash;
+                    turn = TurnRecord { player_id, number, ..TurnRecord::default() };
+                }
+                // Someone else's turn: what happened to this player in it isn't theirs to list
+                GameEvent::TurnEnded { .. } => turn = TurnRecord { player_id, number: turn.number, ..TurnRecord::default() },
+                _ if event.player_id() == player_id => turn.record(event),
+                _ => {}
+            }
+        }
+        let playing = self.turn_order.get(self.current_turn_index) == Some(&player_id);
+        if playing && player.is_active {
+            turns.push(TurnRecord {
+                year: player.year,
+                cash_change: player.cash - cash_before,
+                cash: player.cash,
+                debt: player.debt,
+                in_progress: true,
+                ..turn
+            });
+        }
+        turns
+    }
+}
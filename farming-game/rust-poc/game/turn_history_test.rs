@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::GameStateBuilder;
+    use crate::game::game_loop::handle_player_turn;
+    use crate::models::{GameState, Money};
+
+    fn setup_game() -> GameState {
+        GameStateBuilder::default().add_human("Ann").add_human("Bo").build().unwrap()
+    }
+
+    #[test]
+    fn test_history_lists_each_turn_with_its_roll_loans_and_cash() {
+        let mut game = setup_game();
+        let start = game.players[0].cash;
+        handle_player_turn(&mut game, 0, 1).unwrap();
+        game.handle_forced_loan(0, game.players[0].cash + Money(1000), &mut Vec::new()).unwrap();
+        game.advance_turn();
+        handle_player_turn(&mut game, 1, 2).unwrap();
+        game.advance_turn();
+        handle_player_turn(&mut game, 0, 3).unwrap();
+
+        let history = game.turn_history(0);
+        assert_eq!(history.len(), 2, "One finished turn and the one being played");
+        let first = &history[0];
+        assert_eq!((first.number, first.year, first.rolls.clone()), (1, 1, vec![1]));
+        assert_eq!(first.tiles.first(), Some(&1));
+        assert_eq!(first.loans, vec![game.players[0].notes.iter().last().unwrap().principal], "The loan is on the turn it was taken");
+        assert_eq!(first.cash_change, first.cash - start);
+        assert!(!first.in_progress);
+
+        let now = &history[1];
+        assert_eq!((now.number, now.rolls.clone()), (2, vec![3]));
+        assert!(now.loans.is_empty());
+        assert!(now.in_progress);
+        assert_eq!(now.cash, game.players[0].cash);
+        assert_eq!(now.cash_change, game.players[0].cash - first.cash);
+
+        let bo = game.turn_history(1);
+        assert_eq!(bo.len(), 1, "Bo's turn is over and it isn't Bo's go");
+        assert_eq!(bo[0].rolls, vec![2]);
+        assert!(game.turn_history(7).is_empty());
+    }
+}
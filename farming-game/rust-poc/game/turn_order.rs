@@ -0,0 +1,114 @@
+// src/game/turn_order.rs
+// The board game's opening: everyone rolls the die and the high roller goes
+// first. Players who tie roll again among themselves for the places they
+// share, as often as it takes. Once play starts, turns pass in that order,
+// skipping anyone who has gone bankrupt.
+
+use crate::game::{Dice, GameEvent};
+use crate::models::GameState;
+
+/// An opening high roll in progress. Front ends that show each roll ask
+/// `next_roller` who's up; `GameState::roll_for_turn_order` plays it all out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningRoll {
+    /// Players ranked so far, best first; players in one group are still tied
+    groups: Vec<Vec<usize>>,
+    /// This round's rolls by the first tied group
+    rolls: Vec<(usize, u32)>,
+    /// Rounds settled so far; every round after the first breaks a tie
+    rounds: u32,
+}
+
+impl OpeningRoll {
+    pub fn new(players: &[usize]) -> Self {
+        Self { groups: vec![players.to_vec()], rolls: Vec::new(), rounds: 0 }
+    }
+
+    /// Index of the first tied group, which is the one rolling now.
+    fn rolling(&self) -> Option<usize> {
+        self.groups.iter().position(|group| group.len() > 1)
+    }
+
+    /// Who rolls next; `None` once the order is settled.
+    pub fn next_roller(&self) -> Option<usize> {
+        self.rolling().map(|index| self.groups[index][self.rolls.len()])
+    }
+
+    /// Whether the roll being made is to break a tie.
+    pub fn is_tiebreak(&self) -> bool {
+        self.rounds > 0
+    }
+
+    /// Records the next roller's roll. When their group has all rolled, it
+    /// splits into places by roll, and players who rolled the same are tied again.
+    pub fn record(&mut self, roll: u32) {
+        let Some(index) = self.rolling() else { return };
+        self.rolls.push((self.groups[index][self.rolls.len()], roll));
+        if self.rolls.len() < self.groups[index].len() {
+            return;
+        }
+
+        let mut rolls = std::mem::take(&mut self.rolls);
+        // Stable, so tied players keep their seating for the re-roll
+        rolls.sort_by_key(|(_, roll)| std::cmp::Reverse(*roll));
+        let mut places: Vec<Vec<usize>> = Vec::new();
+        for (i, (player_id, roll)) in rolls.iter().enumerate() {
+            match places.last_mut() {
+                Some(place) if i > 0 && rolls[i - 1].1 == *roll => place.push(*player_id),
+                _ => places.push(vec![*player_id]),
+            }
+        }
+        self.groups.splice(index..=index, places);
+        self.rounds += 1;
+    }
+
+    /// The turn order, once every tie is broken.
+    pub fn order(&self) -> Option<Vec<usize>> {
+        match self.rolling() {
+            Some(_) => None,
+            None => Some(self.groups.iter().flatten().copied().collect()),
+        }
+    }
+}
+
+impl GameState {
+    /// Rolls the die for whoever is next in `opening`, with the game's RNG, and
+    /// returns their roll. The roll that settles the order also sets it.
+    pub fn roll_for_order(&mut self, opening: &mut OpeningRoll, logs: &mut Vec<String>) -> Option<u32> {
+        let player_id = opening.next_roller()?;
+        let roll = Dice::ONE.roll(&mut self.rng).total();
+        let key = if opening.is_tiebreak() { "opening.tiebreak" } else { "opening.rolled" };
+        logs.push(self.locale.tr(key, &[("player", &self.players[player_id].name), ("roll", &roll)]));
+        self.emit(GameEvent::OpeningRolled { player_id, roll, tiebreak: opening.is_tiebreak() });
+        opening.record(roll);
+
+        if let Some(order) = opening.order() {
+            let names: Vec<&str> = order.iter().map(|id| self.players[*id].name.as_str()).collect();
+            logs.push(self.locale.tr("opening.order", &[("order", &names.join(", "))]));
+            self.turn_order = order;
+            self.current_turn_index = 0;
+        }
+        Some(roll)
+    }
+
+    /// Passes the turn to the next player still in the game.
+    pub fn advance_turn(&mut self) {
+        let ended = self.turn_order[self.current_turn_index];
+        if let Some(player) = self.players.get(ended) {
+            let (year, cash, debt) = (player.year, player.cash, player.debt);
+            self.emit(GameEvent::TurnEnded { player_id: ended, year, cash, debt });
+        }
+        let count = self.turn_order.len();
+        let next = (1..=count)
+            .map(|step| (self.current_turn_index + step) % count)
+            .find(|index| self.players.get(self.turn_order[*index]).is_some_and(|player| player.is_active));
+        self.current_turn_index = next.unwrap_or((self.current_turn_index + 1) % count);
+        self.emit(GameEvent::TurnStarted { player_id: self.turn_order[self.current_turn_index] });
+    }
+
+    /// Settles turn order with the opening high roll, start to finish.
+    pub fn roll_for_turn_order(&mut self, logs: &mut Vec<String>) {
+        let mut opening = OpeningRoll::new(&self.turn_order);
+        while self.roll_for_order(&mut opening, logs).is_some() {}
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{GameEngine, GameEvent, OpeningRoll};
+    use crate::models::GameState;
+
+    #[test]
+    fn test_high_roll_goes_first_and_ties_roll_again() {
+        let mut opening = OpeningRoll::new(&[0, 1, 2, 3]);
+        for roll in [3, 5, 3, 1] {
+            assert!(!opening.is_tiebreak());
+            opening.record(roll);
+        }
+        assert_eq!(opening.order(), None, "Players 0 and 2 tied for second");
+        assert_eq!(opening.next_roller(), Some(0));
+        assert!(opening.is_tiebreak());
+
+        // They tie again, then 2 wins the second re-roll
+        opening.record(4);
+        opening.record(4);
+        assert_eq!(opening.next_roller(), Some(0));
+        opening.record(2);
+        opening.record(6);
+        assert_eq!(opening.next_roller(), None);
+        assert_eq!(opening.order(), Some(vec![1, 2, 0, 3]));
+    }
+
+    #[test]
+    fn test_engine_rolls_for_order_when_the_rules_say_so() {
+        let names: Vec<String> = ["Ann", "Bo", "Cy", "Di"].iter().map(|n| n.to_string()).collect();
+        let rules = GameRules { roll_for_turn_order: true, ..GameRules::default() };
+        let engine = GameEngine::with_seed(&names, rules.clone(), 5).unwrap();
+        let state = engine.state();
+
+        let mut order = state.turn_order.clone();
+        order.sort();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+        let first_round: Vec<(usize, u32)> = state.events.iter().filter_map(|event| match event {
+            GameEvent::OpeningRolled { player_id, roll, tiebreak: false } => Some((*player_id, *roll)),
+            _ => None,
+        }).collect();
+        assert_eq!(first_round.len(), 4, "Everyone rolls once before any tie-break");
+        let high = first_round.iter().map(|(_, roll)| *roll).max().unwrap();
+        assert!(first_round.contains(&(state.turn_order[0], high)), "A high roller goes first");
+
+        let again = GameEngine::with_seed(&names, rules, 5).unwrap();
+        assert_eq!(again.state().turn_order, state.turn_order, "Seeded openings replay");
+        let unrolled = GameEngine::with_seed(&names, GameRules::default(), 5).unwrap();
+        assert_eq!(unrolled.state().turn_order, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_new_game_logs_the_opening() {
+        let mut game = GameState::new();
+        assert!(game.events.iter().all(|event| matches!(event, GameEvent::OpeningRolled { .. })));
+        assert!(game.events.len() >= game.turn_order.len());
+
+        let mut logs = Vec::new();
+        game.roll_for_turn_order(&mut logs);
+        assert!(logs.last().unwrap().starts_with("Turn order: "));
+    }
+}
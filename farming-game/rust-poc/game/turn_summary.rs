@@ -0,0 +1,62 @@
+// src/game/turn_summary.rs
+// What a turn did to a player, found by comparing their state before and
+// after it, so the UI can show a short recap instead of the raw log.
+
+use crate::models::board::HarvestType;
+use crate::models::GameState;
+use crate::models::Money;
+
+/// The parts of a player's state a turn summary compares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerSnapshot {
+    pub player_id: usize,
+    pub cash: Money,
+    pub debt: Money,
+    pub net_worth: Money,
+    pub cards_drawn: u32,
+    pub harvests_collected: usize,
+}
+
+impl PlayerSnapshot {
+    /// Snapshot of the player's current state, or `None` if there is no such player.
+    pub fn take(game: &GameState, player_id: usize) -> Option<Self> {
+        let player = game.players.get(player_id)?;
+        Some(Self {
+            player_id,
+            cash: player.cash,
+            debt: player.debt,
+            net_worth: player.net_worth,
+            cards_drawn: player.cards_drawn,
+            harvests_collected: player.harvests_collected.len(),
+        })
+    }
+
+    /// Changes since this snapshot, read from the player's state in `game` now.
+    pub fn diff(&self, game: &GameState) -> Option<TurnSummary> {
+        let after = Self::take(game, self.player_id)?;
+        let player = &game.players[self.player_id];
+        Some(TurnSummary {
+            player_id: self.player_id,
+            cash_change: after.cash - self.cash,
+            debt_change: after.debt - self.debt,
+            net_worth_change: after.net_worth - self.net_worth,
+            cards_drawn: after.cards_drawn.saturating_sub(self.cards_drawn),
+            harvests: player.harvests_collected.get(self.harvests_collected..).unwrap_or_default().to_vec(),
+            hay_cuttings: player.hay_cuttings_in_year(player.year),
+        })
+    }
+}
+
+/// What happened to one player over a turn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnSummary {
+    pub player_id: usize,
+    pub cash_change: Money,
+    pub debt_change: Money,
+    pub net_worth_change: Money,
+    pub cards_drawn: u32,
+    /// Harvests brought in during the turn, with their income
+    pub harvests: Vec<(HarvestType, Money)>,
+    /// Hay cuttings brought in so far in the year the player is in, out of four
+    pub hay_cuttings: usize,
+}
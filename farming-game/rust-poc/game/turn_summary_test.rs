@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{EffectResolver, GameEffect, PlayerSnapshot};
+    use crate::models::board::{HarvestType, TileType};
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+
+    fn setup_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Ann".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Bo".to_string(), PlayerType::Human));
+        GameState::new_with_players(players, vec![0, 1], GameRules::default())
+    }
+
+    #[test]
+    fn test_summary_reports_changes_since_snapshot() {
+        let mut game = setup_game();
+        game.players[0].add_asset(AssetType::Hay, 10, Money::ZERO);
+        let before = PlayerSnapshot::take(&game, 0).unwrap();
+
+        EffectResolver::new(&mut game, 0)
+            .resolve(&GameEffect::DrawCard(TileType::OptionToBuy), &mut Vec::new())
+            .unwrap();
+        game.process_harvest(0, HarvestType::HayCutting1).unwrap();
+        game.players[0].debt += Money(1000);
+        game.update_player_valuation(0);
+
+        let after = &game.players[0];
+        let summary = before.diff(&game).unwrap();
+        assert_eq!(summary.player_id, 0);
+        assert_eq!(summary.cash_change, after.cash - before.cash);
+        assert_eq!(summary.debt_change, after.debt - before.debt);
+        assert_eq!(summary.net_worth_change, after.net_worth - before.net_worth);
+        assert_eq!(summary.cards_drawn, 1);
+        assert_eq!(summary.harvests.len(), 1);
+        assert_eq!(summary.harvests[0].0, HarvestType::HayCutting1);
+        assert_eq!(summary.hay_cuttings, 1);
+    }
+
+    #[test]
+    fn test_quiet_turn_has_empty_summary() {
+        let mut game = setup_game();
+        let before = PlayerSnapshot::take(&game, 1).unwrap();
+        game.players[0].cash += Money(500);
+
+        let summary = before.diff(&game).unwrap();
+        assert_eq!((summary.cash_change, summary.debt_change, summary.cards_drawn), (Money::ZERO, Money::ZERO, 0));
+        assert!(summary.harvests.is_empty());
+        assert!(PlayerSnapshot::take(&game, 7).is_none());
+    }
+}
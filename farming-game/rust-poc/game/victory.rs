@@ -0,0 +1,96 @@
+// src/game/victory.rs
+// Who wins when more than one farmer has met the win condition in the same
+// game year. The richest of them wins; farmers tied on net worth as well
+// roll a dice-off under the opening's rules (high roll wins, and players
+// who roll the same roll again; see `game::turn_order`). How the tie went is
+// kept in the event stream, so the game has one winner however often it's
+// asked.
+
+use crate::game::{Dice, GameEvent, OpeningRoll, PlayerOutcome};
+use crate::models::GameState;
+
+/// How a win shared by several players was settled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TieBreak {
+    /// Players who met the win condition in the same year, in turn order
+    pub contenders: Vec<usize>,
+    /// Contenders who shared the highest net worth and rolled it off
+    pub dice_off: Vec<usize>,
+    /// Dice-off rolls in the order they were made; empty if net worth settled it
+    pub rolls: Vec<(usize, u32)>,
+    pub winner: usize,
+}
+
+impl GameState {
+    /// Players still in the game who have met the win condition and are in
+    /// game year `year`, in turn order.
+    pub fn contenders(&self, year: u32) -> Vec<usize> {
+        self.turn_order.iter()
+            .copied()
+            .filter(|id| self.players.get(*id).is_some_and(|player| player.is_active && player.year == year && self.meets_win_condition(player)))
+            .collect()
+    }
+
+    /// The player a settled tie went to, if a tie has been settled.
+    fn tie_break_winner(&self) -> Option<usize> {
+        self.events.iter().rev().find_map(|event| match event {
+            GameEvent::TieBroken { player_id, .. } => Some(*player_id),
+            _ => None,
+        })
+    }
+
+    /// Whether `player_id` shared a win and the tie went to someone else.
+    pub fn lost_tie_break(&self, player_id: usize) -> bool {
+        self.events.iter().any(|event| matches!(event,
+            GameEvent::TieBroken { player_id: winner, contenders } if *winner != player_id && contenders.contains(&player_id)))
+    }
+
+    /// The player who has won: whoever a tie went to, otherwise the first
+    /// player in turn order to have met the win condition.
+    pub fn winner(&self) -> Option<usize> {
+        self.tie_break_winner()
+            .or_else(|| self.turn_order.iter().copied().find(|id| self.player_outcome(*id) == PlayerOutcome::Won))
+    }
+
+    /// Checks whether `player_id`, having just met the win condition, shares
+    /// the win with anyone else in the same year, and if so settles who takes
+    /// it: the higher net worth, then a dice-off. Returns how the tie went;
+    /// `None` when there was nothing to settle.
+    pub fn settle_win(&mut self, player_id: usize, logs: &mut Vec<String>) -> Option<TieBreak> {
+        if self.tie_break_winner().is_some() || self.player_outcome(player_id) != PlayerOutcome::Won {
+            return None;
+        }
+        let year = self.players.get(player_id)?.year;
+        let contenders = self.contenders(year);
+        if contenders.len() < 2 || !contenders.contains(&player_id) {
+            return None;
+        }
+        let names: Vec<&str> = contenders.iter().map(|id| self.players[*id].name.as_str()).collect();
+        logs.push(self.locale.tr("victory.tie", &[("players", &names.join(", ")), ("year", &year)]));
+
+        let best = contenders.iter().map(|id| self.players[*id].net_worth).max().unwrap_or_default();
+        let dice_off: Vec<usize> = contenders.iter().copied().filter(|id| self.players[*id].net_worth == best).collect();
+        let mut rolls = Vec::new();
+        let winner = if dice_off.len() == 1 {
+            dice_off[0]
+        } else {
+            logs.push(self.locale.tr("victory.dice_off", &[("net_worth", &best)]));
+            let mut dice_off_roll = OpeningRoll::new(&dice_off);
+            while let Some(roller) = dice_off_roll.next_roller() {
+                let roll = Dice::ONE.roll(&mut self.rng).total();
+                logs.push(self.locale.tr("victory.dice_off_roll", &[("player", &self.players[roller].name), ("roll", &roll)]));
+                self.emit(GameEvent::TieBreakRolled { player_id: roller, roll });
+                rolls.push((roller, roll));
+                dice_off_roll.record(roll);
+            }
+            dice_off_roll.order().and_then(|order| order.first().copied()).unwrap_or(dice_off[0])
+        };
+        let winner_net_worth = self.players[winner].net_worth;
+        logs.push(self.locale.tr("victory.tie_winner", &[("player", &self.players[winner].name), ("net_worth", &winner_net_worth)]));
+        self.emit(GameEvent::TieBroken { player_id: winner, contenders: contenders.clone() });
+        if winner != player_id {
+            self.record_outcome(winner);
+        }
+        Some(TieBreak { contenders, dice_off, rolls, winner })
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::{GameEvent, GameStateBuilder, PlayerOutcome};
+    use crate::models::{GameState, Money};
+
+    fn setup_game() -> GameState {
+        GameStateBuilder::default().add_human("Ann").add_human("Bo").add_human("Cy").build().unwrap()
+    }
+
+    fn set_net_worth(game: &mut GameState, player_id: usize, net_worth: Money) {
+        game.players[player_id].net_worth = net_worth;
+    }
+
+    #[test]
+    fn test_the_higher_net_worth_takes_a_shared_win() {
+        let mut game = setup_game();
+        let target = game.rules.winning_net_worth;
+        set_net_worth(&mut game, 0, target);
+        set_net_worth(&mut game, 1, target + Money(5000));
+
+        let mut logs = Vec::new();
+        let tie_break = game.settle_win(0, &mut logs).expect("Ann and Bo both won in year 1");
+        assert_eq!(tie_break.contenders, vec![0, 1]);
+        assert_eq!(tie_break.winner, 1);
+        assert!(tie_break.rolls.is_empty(), "Net worth settled it without a dice-off");
+        assert!(!logs.is_empty());
+
+        assert_eq!(game.player_outcome(0), PlayerOutcome::Lost);
+        assert_eq!(game.player_outcome(1), PlayerOutcome::Won);
+        assert_eq!(game.winner(), Some(1));
+        assert!(game.events.iter().any(|event| matches!(event, GameEvent::GameWon { player_id: 1, .. })));
+        assert!(game.events.iter().any(|event| matches!(event, GameEvent::TieBroken { player_id: 1, contenders } if contenders == &vec![0, 1])));
+    }
+
+    #[test]
+    fn test_equal_net_worths_roll_a_dice_off() {
+        let mut game = setup_game();
+        let target = game.rules.winning_net_worth;
+        for player_id in 0..3 {
+            set_net_worth(&mut game, player_id, target);
+        }
+
+        let tie_break = game.settle_win(2, &mut Vec::new()).expect("Everyone won in year 1");
+        assert_eq!(tie_break.dice_off, vec![0, 1, 2]);
+        assert!(tie_break.rolls.len() >= 3, "Every contender rolls at least once");
+        let rolled = game.events.iter().filter(|event| matches!(event, GameEvent::TieBreakRolled { .. })).count();
+        assert_eq!(rolled, tie_break.rolls.len());
+
+        let winner = tie_break.winner;
+        let first_round = &tie_break.rolls[..3];
+        let high = first_round.iter().map(|(_, roll)| *roll).max().unwrap();
+        assert!(first_round.contains(&(winner, high)), "The winner had the high roll, or shared it and won the re-roll");
+        for player_id in 0..3 {
+            let expected = if player_id == winner { PlayerOutcome::Won } else { PlayerOutcome::Lost };
+            assert_eq!(game.player_outcome(player_id), expected);
+        }
+        assert_eq!(game.winner(), Some(winner));
+        assert!(game.settle_win(winner, &mut Vec::new()).is_none(), "A tie is only settled once");
+        assert_eq!(game.winner(), Some(winner));
+    }
+
+    #[test]
+    fn test_winners_in_different_years_are_not_tied() {
+        let mut game = setup_game();
+        let target = game.rules.winning_net_worth;
+        set_net_worth(&mut game, 0, target);
+        set_net_worth(&mut game, 1, target + Money(5000));
+        game.players[1].year = 2;
+
+        assert!(game.settle_win(0, &mut Vec::new()).is_none());
+        assert_eq!(game.winner(), Some(0));
+        assert!(game.settle_win(2, &mut Vec::new()).is_none(), "Cy hasn't won");
+    }
+}
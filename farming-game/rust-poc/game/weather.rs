@@ -0,0 +1,75 @@
+// src/game/weather.rs
+// County-wide weather, rolled once per game year, that scales harvest yields.
+
+use rand::Rng;
+use crate::game::Dice;
+use crate::models::{AssetType, GameState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weather {
+    #[default]
+    Normal,
+    /// Dry year: grain and hay come up short, orchards hold up better
+    Drought,
+    /// Wet year: grain does well, but hay spoils and cherries split
+    Wet,
+}
+
+impl Weather {
+    /// Rolls a die for the year: 1 is a drought, 6 a wet year, anything else normal.
+    pub fn roll<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        match Dice::ONE.roll(rng).total() {
+            1 => Weather::Drought,
+            6 => Weather::Wet,
+            _ => Weather::Normal,
+        }
+    }
+
+    /// Multiplier applied to harvest income for `asset` this year.
+    pub fn yield_modifier(&self, asset: &AssetType) -> f32 {
+        match (self, asset) {
+            (Weather::Drought, AssetType::Grain | AssetType::Hay) => 0.5,
+            (Weather::Drought, AssetType::Fruit) => 0.75,
+            (Weather::Wet, AssetType::Grain) => 1.25,
+            (Weather::Wet, AssetType::Hay | AssetType::Fruit) => 0.75,
+            _ => 1.0,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Weather::Normal => "Normal",
+            Weather::Drought => "Drought",
+            Weather::Wet => "Wet",
+        }
+    }
+
+    /// One-line forecast for the TUI.
+    pub fn forecast(&self) -> &'static str {
+        match self {
+            Weather::Normal => "Normal year - average yields",
+            Weather::Drought => "Drought - grain/hay x0.5, fruit x0.75",
+            Weather::Wet => "Wet year - grain x1.25, hay/fruit x0.75",
+        }
+    }
+}
+
+impl GameState {
+    /// Weather for `year`; years nobody has reached yet are normal.
+    pub fn weather_for_year(&self, year: u32) -> Weather {
+        self.weather_by_year.get(&year).copied().unwrap_or_default()
+    }
+
+    /// Rolls the weather for `year` the first time any player reaches it.
+    /// Returns the new weather, or `None` if the year's weather is already set.
+    pub fn roll_weather_for_year(&mut self, year: u32, logs: &mut Vec<String>) -> Option<Weather> {
+        if self.weather_by_year.contains_key(&year) {
+            return None;
+        }
+        let weather = Weather::roll(&mut self.rng);
+        self.weather_by_year.insert(year, weather);
+        logs.push(self.locale.tr("weather.forecast", &[("year", &year), ("forecast", &self.locale.weather_forecast(weather))]));
+        tracing::info!(year, ?weather, "weather rolled");
+        Some(weather)
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::cards::card::{Card, CardSource};
+    use crate::cards::deck::Deck;
+    use crate::config::GameRules;
+    use crate::game::harvest::HarvestManager;
+    use crate::game::{GameEffect, Weather};
+    use crate::models::{AssetType, GameState, HarvestType, Money, Player, PlayerRegistry, PlayerType};
+
+    fn free_op_cost_deck() -> Deck {
+        let mut deck = Deck::new();
+        deck.draw_pile.push(Card {
+            id: 1,
+            title: "Free".to_string(),
+            description: "No cost".to_string(),
+            description_brief: "No cost".to_string(),
+            effect: GameEffect::Expense(Money::ZERO),
+            default_quantity: 1,
+            source: CardSource::BaseGame,
+            hold_until: None,
+        });
+        deck
+    }
+
+    fn hay_income(weather: Weather) -> Money {
+        let mut manager = HarvestManager::new();
+        manager.set_weather(weather);
+        let mut player = Player::new(0, "Weather Watcher".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Hay, 20, Money::ZERO);
+        let (income, _, _) = manager
+            .calculate_harvest_with(&mut player, &HarvestType::HayCutting1, &mut free_op_cost_deck(), &mut StdRng::seed_from_u64(3))
+            .unwrap();
+        income
+    }
+
+    #[test]
+    fn test_weather_scales_harvest_income() {
+        let normal = hay_income(Weather::Normal);
+        assert_eq!(hay_income(Weather::Drought), normal.times(0.5));
+        assert_eq!(hay_income(Weather::Wet), normal.times(0.75));
+        assert_eq!(Weather::Wet.yield_modifier(&AssetType::Grain), 1.25);
+        assert_eq!(Weather::Drought.yield_modifier(&AssetType::Cows), 1.0);
+    }
+
+    #[test]
+    fn test_weather_is_rolled_once_per_year() {
+        let mut game = GameState::new();
+        game.reseed(11);
+        let mut logs = Vec::new();
+
+        assert_eq!(game.weather_for_year(1), Weather::Normal);
+        let rolled = game.roll_weather_for_year(2, &mut logs).expect("year 2 not rolled yet");
+        assert_eq!(game.roll_weather_for_year(2, &mut logs), None);
+        assert_eq!(game.weather_for_year(2), rolled);
+        assert_eq!(logs.len(), 1);
+    }
+
+    #[test]
+    fn test_drought_year_card_sets_drought_for_the_year_it_was_drawn() {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Dry".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Neighbor".to_string(), PlayerType::Human));
+        let mut game = GameState::new_with_players(players, vec![0, 1], GameRules::default());
+        let card = game.farmer_fate_deck.draw_pile.iter()
+            .find(|card| matches!(card.effect, GameEffect::DroughtYear))
+            .cloned()
+            .expect("Drought Year card in the Farmer's Fate deck");
+
+        let mut logs = Vec::new();
+        game.apply_card_effect(0, &card, &mut logs).unwrap();
+
+        assert_eq!(game.weather_for_year(1), Weather::Drought, "Neighbor still farms through the drought");
+        assert_eq!(game.players[0].year, 2, "Drawer skips ahead to next year");
+        assert!(game.weather_by_year.contains_key(&2), "Skipping into a new year rolls its weather");
+    }
+}
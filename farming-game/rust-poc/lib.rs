@@ -5,9 +5,40 @@ pub mod models;
 pub mod game;
 pub mod cards;
 pub mod config;
+pub mod logging;
+pub mod locale;
+pub mod cli;
+pub mod achievements;
+pub mod settings;
+pub mod audio;
+pub mod debug;
+pub mod prelude;
+pub mod render;
+#[cfg(feature = "tui")]
 pub mod ui;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "server")]
+pub mod server;
 
-// You might want to add public functions here later 
-// that main.rs can call, making this a true library. 
+#[cfg(test)]
+mod test_support;
+#[cfg(test)]
+mod locale_test;
+#[cfg(test)]
+mod cli_test;
+#[cfg(test)]
+mod achievements_test;
+#[cfg(test)]
+mod settings_test;
+#[cfg(test)]
+mod audio_test;
+#[cfg(test)]
+mod debug_test;
+#[cfg(all(test, feature = "server"))]
+mod server_test;
 
-// Ensure the game module itself is declared public 
\ No newline at end of file
+// Entry points for embedding the game (e.g. in a server hosting several games)
+pub use config::GameRules;
+pub use game::{Action, GameBuilder, GameEngine, GameStateBuilder, GameError, GameEvent, GameId, GameManager, GameSummary, Scenario};
+pub use models::GameState; 
@@ -0,0 +1,472 @@
+// Localized game text.
+//
+// Messages are looked up by key and filled in from named `{placeholders}`.
+// English ships built in; a translation is a TOML file that overrides any
+// subset of keys, e.g.
+//
+// ```toml
+// code = "es"
+//
+// [messages]
+// "turn.passed_go" = "¡{player} pasó por la salida!"
+// "tile.0.name" = "Vacaciones de Navidad"
+// "card.205.title" = "Año de sequía"
+// ```
+//
+// Card and tile text defaults to the English in the catalogs and board, and is
+// overridden with `card.<id>.title`, `card.<id>.description`,
+// `card.<id>.description_brief`, `tile.<index>.name` and `tile.<index>.description`;
+// likewise `asset.<Asset>`, `harvest.<HarvestType>`, `weather.<Weather>.label`
+// and `weather.<Weather>.forecast`.
+//
+// A `${placeholder}` marks a dollar amount. It's written with the locale's
+// `money.format` (the English "${amount}") and thousands `money.separator`
+// (","), and a minus sign goes in front, so -3000 reads "-$3,000".
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use crate::cards::card::Card;
+use crate::config::Difficulty;
+use crate::game::weather::Weather;
+use crate::models::money::{group_digits, Money};
+use crate::models::{AssetType, BoardTile, HarvestType};
+
+/// Built-in English messages.
+const ENGLISH: &[(&str, &str)] = &[
+    // Money
+    ("money.format", "${amount}"),
+    ("money.separator", ","),
+    // Turn flow
+    ("turn.passed_go", "{player} passed Go (Tile 0)!"),
+    ("turn.year_advanced", "Year advanced to {year}."),
+    ("turn.side_job_paid", "Collected ${wage} side job pay. Cash: ${cash}"),
+    ("turn.side_job_skipped", "Did not collect side job pay (ineligible this year)."),
+    ("turn.landed", "Rolled a {roll} - landed on {tile}"),
+    ("turn.tile_error", "Error handling tile event: {error}"),
+    ("turn.skips_year", "{player} skips a year."),
+    ("turn.skip_year_moved", "{player} moved to position 2: January Week 2. No side job pay this year."),
+    ("turn.moved_between", "{player} moved from tile {from} to tile {to}"),
+    ("turn.handling_tile", "Handling effects for {player} on tile: {tile}"),
+    ("turn.harvest_tile", "This is a harvest-related tile ({tile_type})"),
+    ("turn.harvest_check", "Harvest check may be applicable"),
+    // Cards
+    ("card.drew_farmers_fate", "Drew a Farmer's Fate card: {card}"),
+    ("card.drew_option_to_buy", "Drew an Option to Buy card: {card}"),
+    ("card.held_until_harvest", "{player} holds '{card}' until harvest ({harvest})."),
+    ("card.exercised_option", "Exercised Option to Buy card {card_id}"),
+    ("card.discarded", "{player} discards '{card}'."),
+    // Tile and card effects
+    ("effect.gained_cash", "{player} gained ${amount}."),
+    ("effect.gained_bonus", "{player} gained ${amount} bonus."),
+    ("effect.must_pay", "{player} must pay ${amount}"),
+    ("effect.moved_to", "{player} moved to {tile}"),
+    ("effect.move_loop", "{player} stops at {tile}: this turn already sent them there once."),
+    ("effect.moved_to_and_gained", "{player} moved to {tile} and gained ${amount}"),
+    ("effect.cannot_move", "{player} cannot move (no {asset})."),
+    ("effect.yield_doubled", "{player}'s yield is doubled for {asset}!"),
+    ("effect.one_time_multiplier", "{player} gained one-time harvest multiplier of {multiplier} for {asset}."),
+    ("effect.pay_interest", "{player} must pay ${amount} in interest."),
+    ("effect.no_interest", "{player} pays no interest (debt is zero)."),
+    ("effect.gained_for_asset", "{player} gained ${amount} for having {asset}."),
+    ("effect.not_gained_no_asset", "Did not gain ${amount} (no {asset})."),
+    ("effect.pay_for_asset", "{player} must pay ${amount} for having {asset}."),
+    ("effect.no_pay_no_asset", "{player} does not have to pay (no {asset})."),
+    ("effect.pay_without_asset", "No {asset}, must pay ${amount}."),
+    ("effect.has_asset_no_pay", "Has {asset}, no payment needed."),
+    ("effect.pay_per_asset", "{player} must pay ${amount} ({count} x ${rate} for {asset})."),
+    ("effect.no_expense_for_asset", "{player} pays no expense for {asset} (zero quantity or rate)."),
+    ("effect.income_per_asset", "{player} gained ${amount} ({count} x ${rate} for {asset})."),
+    ("effect.price_cut_per_asset", "{player} loses ${amount} on the price ({count} x ${rate} for {asset})."),
+    ("effect.price_cut_avoided", "{player}'s {asset} is safe in the {storage}. No price cut."),
+    ("effect.no_income_for_asset", "{player} gained no income for {asset} (zero quantity or rate)."),
+    ("effect.income_per_land_acre", "{player} gained ${amount} for {acres} acres of land (${rate} per acre)"),
+    ("effect.no_land_income", "{player} gained no income from land (zero acres or rate)."),
+    ("effect.debt_adjusted", "{player} debt adjusted by ${amount}. New debt: ${debt}"),
+    ("effect.land_adjusted", "{player} land adjusted by {amount}. New land: {land}"),
+    ("effect.special_card", "Special Card Effect for {player}: {description}"),
+    ("effect.harvest_income_suppressed", "{player}'s next harvest brings in no income."),
+    ("effect.no_side_job_pay", "{player} will not collect side job pay this year."),
+    ("effect.harvest_garnished", "{player}'s next harvest brings in nothing but its operating expense."),
+    ("effect.cows_lost", "Disaster! {player} loses all {cows} cows without compensation."),
+    ("effect.joined_coop", "{player} joins the machinery co-op: {percent}% off operating expenses for {years} years."),
+    ("effect.cows_compensated", "{player}'s {cows} cows are slaughtered. The state pays ${price} a head, ${total} in all."),
+    ("effect.no_cows_lost", "{player} had no cows to lose to disaster."),
+    ("collect.announce", "Effect: {player} collects ${amount} from each player who owns {asset}."),
+    ("collect.not_owned", "Checking player {player}: Does not own {asset}. No payment required."),
+    ("collect.owned", "Checking player {player}: Owns {asset}. Needs to pay ${amount}."),
+    ("collect.total", "{player} collected a total of ${amount}. Final cash: ${cash}"),
+    ("mt_st_helens.hay_bonus", "{player} collects ${amount} bonus for {acres} Hay acres (Ash-free hay)."),
+    ("mt_st_helens.escaped", "{player} rolled {roll} (Odd) and escaped the ash!"),
+    ("mt_st_helens.hit", "{player} rolled {roll} (Even) and was hit by the ash!"),
+    ("mt_st_helens.cleanup", "{player} must pay ${amount} to clean up ash (${rate} per acre)."),
+    ("mt_st_helens.no_acres", "{player} has no acres to clean up."),
+    // Buying
+    ("buy.attempt", "{player} attempts to buy {quantity} {asset} for ${cost} each (Total: ${total})."),
+    ("buy.success", "Successfully bought {quantity} {asset}. Cash remaining: ${cash}"),
+    ("buy.declined", "{player} passed on the {asset}."),
+    ("buy.paid", "{player} paid ${cost} for {quantity} {asset}."),
+    ("buy.paid_with_loan", "{player} took a loan of ${loan} and paid ${cost} for {quantity} {asset}."),
+    ("decision.buy_yes_no", "{player}, buy {quantity} {asset} for ${cost}?"),
+    ("decision.buy_quantity", "{player}, how many {asset} will you buy at ${price} each? (0-{max})"),
+    ("decision.ash_roll", "{player}, roll for the Mt. St. Helens ash: odd escapes, even pays to clean up."),
+    ("decision.raise_cash", "{player}, the bill is ${amount} and you're ${shortfall} short. Sell something to cover it, or the bank lends ${loan}, keeping a ${fee} fee, which leaves you ${debt} in debt."),
+    ("decision.raise_cash_covered", "{player}, you have the cash for the ${amount} bill."),
+    ("decision.stock_ridge", "{player}, how many cows will you move onto {ridge}? ({min}-{max})"),
+    ("decision.discard", "{player}, you hold {hand} cards and the limit is {limit}. Pick {over} to discard."),
+    ("decision.ash_cleanup", "{player}, the cleanup costs ${amount} and you have ${cash}. Sell how many cows at ${price} each? The rest is borrowed. (0-{max})"),
+    // Ridges
+    ("ridge.leased_by", "Leased by {player} ({cows} cows)"),
+    ("ridge.leased_unknown", "Leased (leasee not found)"),
+    ("ridge.leased_no_leasee", "Leased (no leasee)"),
+    ("ridge.available", "Available ({cows} cows required)"),
+    ("ridge.status", "{ridge}: {status} - Cost: ${cost}"),
+    ("ridge.player_ridge", "{ridge}: {cows} of {capacity} cows"),
+    ("ridge.available_for_lease", "{ridge}: ${cost} - Requires {cows} cows"),
+    ("ridge.stocked", "{player} stocked {ridge} with {cows} cows."),
+    ("ridge.coyotes", "Coyotes took {cows} of {player}'s cows on {ridge}."),
+    ("ridge.hard_winter", "Hard winter on {ridge}: {player} pays ${amount} to feed {cows} cows."),
+    ("ridge.herd_starved", "{player} couldn't feed the {cows} cows on {ridge} and lost the herd."),
+    ("ridge.cows_moved_up", "{player} moved {cows} cows up to {ridge}. Ridge: {on_ridge}, farm: {on_farm}."),
+    ("ridge.cows_moved_down", "{player} brought {cows} cows down from {ridge}. Ridge: {on_ridge}, farm: {on_farm}."),
+    ("ridge.lease_option", "Card provides a leasing option for {ridge}: ${cost} requiring {cows} cows to stock."),
+    // Harvest
+    ("harvest.not_required", "No harvest required for this tile."),
+    ("harvest.no_type", "No harvest type specified."),
+    ("harvest.skipped_no_asset", "{player} does not own any {asset}, skipping harvest."),
+    ("harvest.no_expense_drawn", "No operating expense drawn since there is no harvest."),
+    ("harvest.nothing_to_harvest", "No {asset} to harvest."),
+    ("harvest.not_enough", "Not enough {asset} for harvest (need {needed})."),
+    ("harvest.held_card_applies", "Held card '{card}' applies: {asset} yield x{multiplier}"),
+    ("harvest.expense_flat", "Operating Expense: {card} - ${amount}"),
+    ("harvest.expense_per_acre", "Operating Expense: {card} - ${rate}/acre x {count} acres = ${amount}"),
+    ("harvest.expense_per_cow", "Operating Expense: {card} - ${rate}/cow x {count} cows = ${amount}"),
+    ("harvest.expense_interest", "Operating Expense: {card} - {percent}% of ${debt} debt = ${amount}"),
+    ("harvest.expense_no_interest", "Operating Expense: {card} - No interest (debt: $0)"),
+    ("harvest.expense_none", "Operating Expense: {card} - None"),
+    ("harvest.expense_discount", "Co-op discount: {percent}% off saves ${saved}, leaving ${amount} to pay"),
+    ("harvest.roll_acres", "{harvest}: Roll {roll} = ${base}/block x {quantity} acres"),
+    ("harvest.roll_cows", "{harvest}: Roll {roll} = ${base}/block x {quantity} cows"),
+    ("harvest.crop_multiplier", " x{multiplier} multiplier"),
+    ("harvest.weather_modifier", " x{multiplier} {weather} weather"),
+    ("harvest.livestock_multiplier", " x{multiplier} livestock"),
+    ("harvest.persistent_multiplier", " x{multiplier} {asset} bonus"),
+    ("harvest.income_bonus", " + ${bonus} bonus"),
+    ("harvest.upgrade_bonus", " x{multiplier} {upgrade}"),
+    ("harvest.difficulty_multiplier", " x{multiplier} difficulty"),
+    ("harvest.income_garnished", "The IRS garnishes this harvest: no income, only the operating expense."),
+    ("harvest.net", " - ${expense} operating expense = ${net}"),
+    ("harvest.already_cut", "{player} has already collected {harvest} this year."),
+    ("harvest.gained", "Gained ${income}"),
+    ("harvest.no_expense", "No expense incurred."),
+    ("harvest.expense_error", "Error handling harvest expense for {player}: {error}"),
+    ("harvest.error", "Error during harvest: {error}"),
+    // Loans and payments
+    ("loan.paid", "{player} paid ${amount} towards debt. Remaining debt: ${debt}"),
+    ("loan.paid_note", "{player} paid ${amount} off note #{note}. Remaining debt: ${debt}"),
+    ("loan.payment", "{player} paid ${amount}. Cash remaining: ${cash}"),
+    ("loan.forced", "Took loan: ${amount} (bank keeps {fee_percent}%: ${fee}). Cash received: ${received}, New debt: ${debt}"),
+    ("loan.debt_limit", "needed for {player} to pay ${amount}, but would exceed debt limit of ${limit}"),
+    ("transfer.paid", "{payer} paid ${amount} to {payee}."),
+    ("transfer.partial", "{player} can only pay the ${amount} they have."),
+    // Insurance
+    ("insurance.bought.crop", "{player} insured {units} acres of crop for ${premium}."),
+    ("insurance.bought.livestock", "{player} insured {units} heads of livestock for ${premium}."),
+    ("insurance.payout.crop", "Crop insurance pays {player} ${amount}."),
+    ("insurance.payout.livestock", "Livestock insurance pays {player} ${amount}."),
+    // Auctions
+    ("auction.started", "🃏 '{card}' is up for auction."),
+    ("auction.bid", "{player} bids ${amount}."),
+    ("auction.pass", "{player} passes."),
+    ("auction.sold", "{player} won '{card}' at auction for ${price}."),
+    ("auction.unsold", "No bids for '{card}'. {player} keeps the card."),
+    ("auction.no_bidders", "No other players to bid."),
+    ("auction.start_failed", "Could not start auction: {error}"),
+    ("auction.bid_rejected", "Bid rejected: {error}"),
+    ("auction.failed", "Auction failed: {error}"),
+    // Weather
+    ("weather.forecast", "Year {year} forecast: {forecast}."),
+    ("weather.drought_declared", "Drought declared for year {year}! {forecast}."),
+    // Livestock market
+    ("market.cow_price", "Year {year} cow market: ${price} a head."),
+    ("market.cow_price_adjusted", "Cows now fetch ${price} a head at market in year {year}."),
+    ("market.sold_to_bank", "{player} sold {quantity} {asset} to the bank for ${total}."),
+    ("market.sold_cows", "{player} sold {cows} cows at ${price} a head for ${total}."),
+    ("achievement.unlocked", "🏆 {player} unlocked an achievement: {achievement}!"),
+    ("achievement.first_ridge_lease", "Ridge Runner"),
+    ("achievement.first_ridge_lease.description", "Lease your first ridge"),
+    ("achievement.debt_free_year", "Free and Clear"),
+    ("achievement.debt_free_year.description", "End a year owing the bank nothing"),
+    ("achievement.survived_mt_st_helens", "Ash Survivor"),
+    ("achievement.survived_mt_st_helens.description", "Keep cows on the farm through the Mt. St. Helens ash"),
+    ("achievement.quick_win", "Fast Farmer"),
+    ("achievement.quick_win.description", "Win a game before year 10"),
+    ("achievement.none", "Not yet unlocked"),
+    // TUI
+    ("app.initialized", "Game initialized."),
+    ("app.setup_complete", "Scoreboard TUI setup complete."),
+    ("app.turn_header", "--- {player}'s turn (Press Enter to roll) ---"),
+    ("app.no_actions", "No affordable actions - advancing to next player."),
+    ("app.turn_error", "Error during turn: {error}"),
+    ("app.error", "Error: {error}"),
+    ("app.no_cash_for_loan", "Cannot pay loans - no cash available."),
+    ("app.insurance_unavailable", "Insurance is only sold during Spring Planting."),
+    ("app.no_payment", "No payment made."),
+    ("app.payment_unaffordable", "Error: Not enough cash for payment of ${amount}"),
+    ("app.no_debt", "Error: No debt to pay."),
+    ("app.no_cows_to_sell", "No cows to sell."),
+    ("app.no_sale", "No cows sold."),
+    ("app.no_cows_to_move", "No cows to move between the farm and your ridges."),
+    ("app.no_otb_cards", "No Option to Buy cards you can afford, even with a loan."),
+    ("app.otb_closed", "O.T.B. unavailable at this time of the year."),
+    ("app.invalid_card", "Error: Invalid card selection."),
+    ("app.option_exercised", "{player} exercised O.T.B.: {card}"),
+    ("app.option_exercised_with_loan", "{player} exercised O.T.B.: {card} (with loan)"),
+    ("app.option_failed", "Could not exercise option: {error}"),
+    ("app.winner", "🏆 {player} HAS WON THE GAME! 🏆"),
+    ("app.winner_net_worth", "Net worth of ${net_worth} exceeds the ${target} needed to win!"),
+    ("app.winner_scenario", "Completed the '{scenario}' scenario!"),
+    ("app.winner_last_standing", "Everyone else has gone bankrupt!"),
+    ("app.results_exported", "Results saved to {path}."),
+    ("app.ledgers_saved", "Ledgers saved to {path}."),
+    ("app.out_of_time", "{player} ran out of time: ${target} wasn't reached by the end of year {year}."),
+    ("app.run_score", "Final score: ${net_worth} over {years} years = ${per_year} a year."),
+    ("app.scenario_lost", "{player} ran out of time on the '{scenario}' scenario."),
+    ("app.game_speed", "Game speed: {speed}"),
+    ("app.sound_on", "Sound on"),
+    ("app.sound_off", "Sound off"),
+    ("app.too_small", "Terminal too small ({width}x{height}, need {min_width}x{min_height}).\nEnlarge the window to keep playing; the game waits until then.\n\nq: Quit"),
+    // Replay viewer (--replay)
+    ("replay.start", "--- Starting position ({turns} turns recorded) ---"),
+    ("replay.turn_header", "--- Turn {turn} of {turns}: {player} ---"),
+    ("replay.no_logs", "(nothing happened this turn)"),
+    // Plain-text mode (--cli)
+    ("cli.turn_header", "\n=== {player}'s turn ==="),
+    ("cli.roll_prompt", "Press Enter to roll (q to quit):"),
+    ("cli.turn_menu", "Cash ${cash}, debt ${debt}. [O]ption to Buy, [P]ay loan, [S]ell cows, [E]nd turn, [Q]uit:"),
+    ("cli.unknown_choice", "Unknown choice '{choice}'."),
+    ("cli.option_unavailable", "Option to Buy cards can't be exercised at this point in the year."),
+    ("cli.no_option_cards", "No Option to Buy cards in hand."),
+    ("cli.option_card", "  {number}. {card} - {description}"),
+    ("cli.pick_card", "Card number (Enter to cancel):"),
+    ("cli.confirm_loan", "Not enough cash for '{card}'. Borrow the rest? [y/N]:"),
+    ("cli.play_again", "Play again with the same table? [y/N]:"),
+    ("cli.loan_amount", "Amount to pay:"),
+    ("cli.note", "  Note #{note}: ${principal} at {percent}% since year {year}"),
+    ("cli.pick_note", "Note to pay (Enter for oldest first):"),
+    ("cli.invalid_note", "'{note}' is not one of your notes."),
+    ("cli.cows_to_sell", "Cows fetch ${price} a head; you have {cows}. How many to sell?"),
+    ("cli.invalid_amount", "'{amount}' is not an amount."),
+    ("cli.decision_yes_no", "{prompt} [y/n]:"),
+    ("cli.decision_quantity", "{prompt}:"),
+    ("cli.decision_roll", "{prompt} Press Enter to roll:"),
+    ("cli.decision_price", "{prompt} Your price:"),
+    ("cli.decision_discard", "{prompt} Card number:"),
+    ("cli.decision_raise_cash", "{prompt} Sell what (e.g. `cows 2`), or `y` to pay:"),
+    ("cli.invalid_answer", "That doesn't answer the question."),
+    // Custom work for neighbors without the equipment
+    ("custom_work.quote_prompt", "{hirer} has no {asset} and owes the bank ${bill} for custom hire. What will you charge to do the work? ($0 passes)"),
+    ("custom_work.offer_prompt", "{contractor} will do the work for ${price} instead of the bank's ${bill}. Hire them?"),
+    ("custom_work.passed", "{contractor} passed on {hirer}'s custom work."),
+    ("custom_work.turned_down", "{hirer} turned down {contractor}'s price of ${price}."),
+    ("custom_work.done", "{contractor} did {hirer}'s custom work with their {asset}."),
+    ("custom_work.bank", "Nobody did the work; {hirer} pays the bank ${bill}."),
+    // Bankruptcy
+    ("bankrupt.eliminated", "{player} can't pay and is out of the game."),
+    ("bankrupt.last_standing", "{player} is the last farmer standing!"),
+    // Opening high roll for turn order
+    ("opening.rolled", "{player} rolled a {roll} for turn order"),
+    ("opening.tiebreak", "{player} rolled a {roll} to break the tie"),
+    ("opening.order", "Turn order: {order}"),
+    ("victory.tie", "{players} have all reached the winning mark in year {year}!"),
+    ("victory.dice_off", "Tied at ${net_worth} net worth: a dice-off decides it"),
+    ("victory.dice_off_roll", "{player} rolled a {roll} in the dice-off"),
+    ("victory.tie_winner", "{player} takes the win with ${net_worth} net worth"),
+    // Chat bot posts (see `render::text`); names arrive already in bold
+    ("chat.board_line", "{player} · Year {year}, {date} · {tile} (tile {index})"),
+    ("chat.turn", "{player}'s turn ({date})."),
+    ("chat.winner", "{player} wins the game!"),
+    ("chat.decision", "{player}: {prompt} ({answer})"),
+    ("chat.answer.yes_no", "yes or no"),
+    ("chat.answer.quantity", "{min} to {max}"),
+    ("chat.answer.roll", "roll the die"),
+    ("chat.answer.raise_cash", "sell assets toward ${amount}, or borrow"),
+    ("chat.answer.price", "$0 to ${max} in steps of ${step}; $0 passes"),
+    ("chat.answer.discard", "discard {over} card(s)"),
+    ("chat.event.dice_rolled", "{player} rolled a {roll}"),
+    ("chat.event.opening_rolled", "{player} rolled a {roll} for turn order"),
+    ("chat.event.harvest", "{player} harvested {harvest} for ${income}"),
+    ("chat.event.card_played", "{player} played '{card}'"),
+    ("chat.event.landed", "{player} landed on {tile}"),
+    ("chat.event.landed_for", "{player} landed on {tile} ({change})"),
+    ("chat.event.year_ended", "{player} finished year {year} with ${cash} cash and ${debt} debt"),
+    ("chat.event.ridge_leased", "{player} leased {ridge}"),
+    ("chat.event.loan_taken", "{player} borrowed ${amount} (debt now ${debt})"),
+    ("chat.event.debt_repaid", "{player} repaid ${amount} (debt now ${debt})"),
+    ("chat.event.ridge_cows_lost", "{player} lost {cows} cows on {ridge}"),
+    ("chat.event.ash_escaped", "{player} escaped the ash"),
+    ("chat.event.ash_hit", "{player} was hit by the ash ({cows} cows left on the farm)"),
+    ("chat.event.won", "{player} won in year {year}!"),
+    ("chat.event.tie_break_rolled", "{player} rolled a {roll} in the dice-off"),
+    ("chat.event.tie_broken", "{player} won the tie for the win"),
+    ("chat.event.eliminated", "{player} is out of the game"),
+    ("chat.event.turn_started", "{player}'s turn"),
+    ("chat.event.earlier", "_…{count} earlier events_"),
+    // Debug console (--debug)
+    ("debug.cash", "[debug] {player} given ${amount} (cash now ${cash})"),
+    ("debug.moved", "[debug] {player} moved to {tile}"),
+    ("debug.stacked", "[debug] '{card}' is on top of the deck"),
+    ("status.debug", "Enter: Run | ~/Esc: Close | Type help for commands"),
+    ("ui.scoreboard_title", "Scoreboard - {phase} | Weather: {forecast}"),
+    ("calendar.date", "Year {year}, {date}"),
+    ("status.game", "Esc: Menu | q: Quit | Enter: Roll | a: Achievements | h: History | o: Options | ?: Help | /: Search | c: Filter | Shift+↑/↓: Scroll | Shift+PgUp/PgDn: Page | l: Log | f: Full Log | s: Scores | [/]: Resize | g: Speed"),
+    ("status.animating", "Enter/Space: Skip animation | g: Speed | q: Quit"),
+    ("status.turn_menu", "O: Option to Buy | P: Pay Loan | I: Insurance | M: Sell Cows | T: Livestock | E: End Turn | Esc: Menu | Shift+↑/↓: Scroll | Shift+PgUp/PgDn: Page"),
+    ("status.option_to_buy", "↑/↓: Select card | Enter: Buy | A: Auction | Esc: Skip | Shift+↑/↓: Scroll | Shift+PgUp/PgDn: Page"),
+    ("status.loan_payment", "↑/↓: Adjust by $100 | PgUp/PgDn: Adjust by $1,000 | Enter: Confirm | Esc: Cancel | Shift+↑/↓: Scroll"),
+    ("status.market", "↑/↓: Adjust by 1 | PgUp/PgDn: Adjust by 10 | Enter: Sell | Esc: Cancel | Shift+↑/↓: Scroll"),
+    ("status.livestock", "↑/↓: Select ridge | PgUp: Move up to ridge | PgDn: Bring down to farm | Enter: Move | Esc: Back | Shift+↑/↓: Scroll"),
+    ("status.insurance", "↑/↓: Select policy | Enter: Buy | Esc: Back | Shift+↑/↓: Scroll"),
+    ("status.raise_cash", "↑/↓: Select asset | PgUp/PgDn: Adjust quantity | Enter: Sell | P/Esc: Pay, borrowing the rest | Shift+↑/↓: Scroll"),
+    ("status.discard", "↑/↓: Select card | Enter: Discard | Shift+↑/↓: Scroll"),
+    ("status.decision", "Y/N: Answer | ↑/↓: Adjust quantity | Enter: Confirm/Roll | Shift+↑/↓: Scroll"),
+    ("status.achievements", "a/Esc: Back | q: Quit"),
+    ("status.turn_history", "↑/↓: Player | ←/→: Year | PgUp/PgDn: Scroll | h/Esc: Back | q: Quit"),
+    ("status.options", "↑/↓: Select | Enter: Change | o/Esc: Save and close | q: Quit"),
+    ("status.turn_summary", "Enter/Esc: Continue | q: Quit"),
+    ("status.eliminated", "Enter: Keep watching | Esc: Leave the table | q: Quit"),
+    ("status.paused", "↑/↓: Select | Enter: Choose | Esc: Resume"),
+    ("status.tie_break", "Enter: See the final standings | q: Quit"),
+    ("status.game_over", "x: Export results | n: Rematch | q: Quit"),
+    ("status.replay", "←/→: Step a turn | PgUp/PgDn: 10 turns | Shift+↑/↓: Scroll | l: Log | s: Scores | ?: Help | q: Quit"),
+    ("status.auction", "↑/↓: Adjust by $100 | PgUp/PgDn: Adjust by $1,000 | Enter: Bid | P/Esc: Pass | Shift+↑/↓: Scroll"),
+];
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LocaleFile {
+    code: String,
+    #[serde(default)]
+    messages: HashMap<String, String>,
+}
+
+/// Message table for one language, falling back to English for missing keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locale {
+    code: String,
+    messages: HashMap<String, String>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Locale {
+    pub fn english() -> Self {
+        Self {
+            code: "en".to_string(),
+            messages: ENGLISH.iter().map(|(key, text)| (key.to_string(), text.to_string())).collect(),
+        }
+    }
+
+    /// Parses a translation; keys it leaves out stay English.
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        let file: LocaleFile = toml::from_str(contents)?;
+        let mut locale = Self::english();
+        locale.code = file.code;
+        locale.messages.extend(file.messages);
+        Ok(locale)
+    }
+
+    /// Loads a translation file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::from_toml(&contents)?)
+    }
+
+    /// Language code, e.g. `en`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Message for `key` with each `{name}` replaced by its argument, and
+    /// each `${name}` by its argument as money when it's a whole number.
+    /// Unknown keys come back as the key itself so gaps are easy to spot.
+    pub fn tr(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let Some(template) = self.messages.get(key) else {
+            return key.to_string();
+        };
+        args.iter().fold(template.clone(), |text, (name, value)| {
+            let value = value.to_string();
+            let text = match value.parse::<Money>() {
+                Ok(amount) => text.replace(&format!("${{{}}}", name), &self.money(amount)),
+                Err(_) => text,
+            };
+            text.replace(&format!("{{{}}}", name), &value)
+        })
+    }
+
+    /// `amount` in the locale's money format, e.g. "$12,500" or "-$3,000".
+    pub fn money(&self, amount: impl Into<Money>) -> String {
+        let amount = amount.into();
+        let digits = group_digits(amount.dollars().unsigned_abs(), self.text_or("money.separator", ","));
+        let text = self.text_or("money.format", "${amount}").replace("{amount}", &digits);
+        if amount.is_negative() { format!("-{}", text) } else { text }
+    }
+
+    fn text_or<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
+        self.messages.get(key).map_or(default, String::as_str)
+    }
+
+    pub fn card_title<'a>(&'a self, card: &'a Card) -> &'a str {
+        self.text_or(&format!("card.{}.title", card.id), &card.title)
+    }
+
+    pub fn card_description<'a>(&'a self, card: &'a Card) -> &'a str {
+        self.text_or(&format!("card.{}.description", card.id), &card.description)
+    }
+
+    pub fn card_description_brief<'a>(&'a self, card: &'a Card) -> &'a str {
+        self.text_or(&format!("card.{}.description_brief", card.id), &card.description_brief)
+    }
+
+    pub fn tile_name<'a>(&'a self, tile: &'a BoardTile) -> &'a str {
+        self.text_or(&format!("tile.{}.name", tile.index), &tile.name)
+    }
+
+    pub fn tile_description<'a>(&'a self, tile: &'a BoardTile) -> Option<&'a str> {
+        let default = tile.description.as_deref()?;
+        Some(self.text_or(&format!("tile.{}.description", tile.index), default))
+    }
+
+    /// Asset name in log text (`asset.Hay`), defaulting to the variant name.
+    pub fn asset_name(&self, asset: &AssetType) -> String {
+        let default = format!("{:?}", asset);
+        self.text_or(&format!("asset.{}", default), &default).to_string()
+    }
+
+    /// Harvest name in log text (`harvest.HayCutting1`), e.g. "Hay: First Cutting".
+    pub fn harvest_name(&self, harvest: &HarvestType) -> &str {
+        self.text_or(&format!("harvest.{:?}", harvest), harvest.title())
+    }
+
+    /// Difficulty label (`difficulty.Hard.label`).
+    pub fn difficulty_label(&self, difficulty: Difficulty) -> &str {
+        self.text_or(&format!("difficulty.{:?}.label", difficulty), difficulty.label())
+    }
+
+    /// Weather label (`weather.Wet.label`).
+    pub fn weather_label(&self, weather: Weather) -> &str {
+        self.text_or(&format!("weather.{:?}.label", weather), weather.label())
+    }
+
+    /// One-line weather forecast (`weather.Wet.forecast`).
+    pub fn weather_forecast(&self, weather: Weather) -> &str {
+        self.text_or(&format!("weather.{:?}.forecast", weather), weather.forecast())
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::config::GameRules;
+    use crate::game::Weather;
+    use crate::locale::Locale;
+    use crate::models::{AssetType, GameState, HarvestType, Player, PlayerRegistry, PlayerType};
+
+    const SPANISH: &str = r#"
+code = "es"
+
+[messages]
+"turn.skips_year" = "{player} pierde un año."
+"asset.Hay" = "heno"
+"weather.Drought.forecast" = "Sequía"
+"card.1.title" = "Tractor nuevo"
+"#;
+
+    #[test]
+    fn test_english_fills_placeholders() {
+        let locale = Locale::english();
+        assert_eq!(locale.code(), "en");
+        assert_eq!(locale.tr("turn.landed", &[("roll", &4), ("tile", &"Christmas Vacation")]),
+            "Rolled a 4 - landed on Christmas Vacation");
+        assert_eq!(locale.tr("no.such.key", &[]), "no.such.key", "Missing keys show up as themselves");
+        assert_eq!(locale.asset_name(&AssetType::Cows), "Cows");
+        assert_eq!(locale.harvest_name(&HarvestType::HayCutting2), "Hay: Second Cutting");
+        assert_eq!(locale.weather_forecast(Weather::Wet), Weather::Wet.forecast());
+    }
+
+    #[test]
+    fn test_translation_overrides_keys_and_falls_back_to_english() {
+        let locale = Locale::from_toml(SPANISH).unwrap();
+        assert_eq!(locale.code(), "es");
+        assert_eq!(locale.tr("turn.skips_year", &[("player", &"Ana")]), "Ana pierde un año.");
+        assert_eq!(locale.tr("turn.year_advanced", &[("year", &2)]), "Year advanced to 2.");
+        assert_eq!(locale.asset_name(&AssetType::Hay), "heno");
+        assert_eq!(locale.asset_name(&AssetType::Grain), "Grain");
+        assert_eq!(locale.weather_forecast(Weather::Drought), "Sequía");
+    }
+
+    #[test]
+    fn test_money_placeholders_use_the_locales_money_format() {
+        let english = Locale::english();
+        assert_eq!(english.tr("effect.gained_cash", &[("player", &"Ann"), ("amount", &12500)]), "Ann gained $12,500.");
+        assert_eq!(english.money(-3000), "-$3,000");
+
+        let german = Locale::from_toml("code = \"de\"\n[messages]\n\"money.format\" = \"{amount} $\"\n\"money.separator\" = \".\"").unwrap();
+        assert_eq!(german.tr("effect.gained_cash", &[("player", &"Ann"), ("amount", &12500)]), "Ann gained 12.500 $.");
+        assert_eq!(german.money(-3000), "-3.000 $");
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        assert!(Locale::from_toml("code = \"es\"\nlanguage = \"Spanish\"").is_err());
+        assert!(Locale::from_toml("[messages]\n\"turn.passed_go\" = \"x\"").is_err(), "code is required");
+    }
+
+    #[test]
+    fn test_game_logs_use_the_game_locale() {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Ana".to_string(), PlayerType::Human));
+        let mut game = GameState::new_with_players(players, vec![0], GameRules::default());
+        game.locale = Arc::new(Locale::from_toml(SPANISH).unwrap());
+
+        let mut logs = Vec::new();
+        game.skip_year(0, &mut logs);
+
+        assert_eq!(logs[0], "Ana pierde un año.");
+    }
+}
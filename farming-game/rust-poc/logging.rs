@@ -0,0 +1,40 @@
+// src/logging.rs
+// Optional file logging for debugging desyncs and balance issues.
+//
+// Game code emits `tracing` events with structured fields (player_id, roll,
+// card, amounts...). Nothing is recorded unless a subscriber is installed,
+// so the TUI log and tests are unaffected when `--log-file` isn't passed.
+
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::Level;
+
+/// Installs a global subscriber that writes timestamped events to `path`.
+pub fn init_file_logging(path: &Path) -> Result<(), Box<dyn Error>> {
+    let file = File::create(path)?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_max_level(Level::DEBUG)
+        .with_target(false)
+        .try_init()
+        .map_err(|e| e as Box<dyn Error>)?;
+    tracing::info!(log_file = %path.display(), "file logging started");
+    Ok(())
+}
+
+/// Extracts the value of `--log-file <path>` (or `--log-file=<path>`) from the command line.
+pub fn log_file_arg<I: IntoIterator<Item = String>>(args: I) -> Option<String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--log-file" {
+            return args.next();
+        }
+        if let Some(path) = arg.strip_prefix("--log-file=") {
+            return Some(path.to_string());
+        }
+    }
+    None
+}
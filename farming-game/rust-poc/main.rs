@@ -1,51 +1,269 @@
 // Farming Game Remake - Core Game State and Setup (Rust)
 
-mod models;
-mod game;
-mod cards;
-mod config;
-// mod ui; // Removed - now declared in lib.rs
-
-use std::collections::{HashMap, HashSet};
 use rand::seq::SliceRandom;
-use farming_game::models::{Player, PlayerType, GameState, TileType};
-use farming_game::game::GameEffect; // Add GameEffect import
-use farming_game::cards::card::Card; // Add Card import
+use farming_game::models::{format_money, Player, PlayerType, GameState};
 use std::io::{self, Write};
 use std::thread;
 use std::time::Duration;
-use farming_game::config::NATIVE_PLAYERS; // Updated import path
-use farming_game::config::{STARTING_CASH, STARTING_DEBT, STARTING_LAND, STARTING_POSITION, STARTING_YEAR}; // Added constants
+use farming_game::config::{Difficulty, NATIVE_PLAYERS, GameRules}; // Updated import path
 use farming_game::ui::terminal; // Import terminal functions
 use farming_game::ui::app::App; // Import the App struct
+use farming_game::logging;
+use farming_game::game::Scenario;
+use farming_game::cards::pack::CardPack;
+use farming_game::locale::Locale;
+use farming_game::cli;
+use farming_game::game::{GameEngine, GameStateBuilder};
+use farming_game::game::OpeningRoll;
+use farming_game::game::SnapshotWriter;
+use farming_game::game::{GameRecord, Replay};
+use farming_game::ui::replay::ReplayViewer;
+use farming_game::game::balance;
+use farming_game::game::monte_carlo::MONTE_CARLO_STRATEGY;
+use farming_game::settings::Settings;
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> { // Return Result for error handling
+    // 0. Optional file logging (--log-file game.log)
+    if let Some(path) = logging::log_file_arg(std::env::args().skip(1)) {
+        logging::init_file_logging(std::path::Path::new(&path))?;
+    }
+
+    // Step through a recorded game instead of playing one (--replay <file>)
+    if let Some(path) = path_arg(std::env::args().skip(1), "--replay")? {
+        let record = GameRecord::load(std::path::Path::new(&path))?;
+        let mut viewer = ReplayViewer::new(Replay::new(&record)?);
+        let mut tui = terminal::init()?;
+        let result = viewer.run(&mut tui);
+        terminal::restore()?;
+        return Ok(result?);
+    }
+
     // 1. Setup Game State (before initializing TUI)
-    let game_state = setup_game()?; // Call setup function
+    let scenario = load_scenario(std::env::args().skip(1))?;
+    let mut rules = match &scenario {
+        Some(scenario) => scenario.rules.clone(),
+        None => load_rules(std::env::args().skip(1))?,
+    };
+    // One farmer racing to the winning net worth within N years (--solo <years>)
+    let solo = path_arg(std::env::args().skip(1), "--solo")?
+        .map(|years| years.parse::<u32>().map_err(|_| format!("--solo needs a number of years, not '{}'", years)))
+        .transpose()?;
+    if solo.is_some() {
+        if scenario.is_some() {
+            return Err("--solo can't be combined with --scenario".into());
+        }
+        rules.year_limit = solo;
+    }
+    // AI games tallying what every card and tile is worth (--simulate <games>),
+    // also written as CSV with --balance-report <path>; --monte-carlo has the
+    // farmers weigh their options with rollouts instead of taking them all
+    if let Some(games) = path_arg(std::env::args().skip(1), "--simulate")? {
+        let games = games.parse::<usize>().map_err(|_| format!("--simulate needs a number of games, not '{}'", games))?;
+        return run_simulation(games, rules, scenario.as_ref());
+    }
+    // Theme, keys, autosave and the last table's nicknames
+    let mut settings = Settings::load();
+    let mut game_state = setup_game(rules, solo.is_some(), &mut settings)?; // Call setup function
+    if let Some(locale) = load_locale(std::env::args().skip(1))? {
+        game_state.locale = std::sync::Arc::new(locale);
+    }
+    if let Some(scenario) = scenario {
+        println!("\nScenario: {} - {}", scenario.name, scenario.description);
+        scenario.apply(&mut game_state);
+    }
+    // Extra cards shuffled into the decks (--cards <file>)
+    let cards_path = path_arg(std::env::args().skip(1), "--cards")?;
+    if let Some(path) = &cards_path {
+        let pack = CardPack::load(std::path::Path::new(path))?;
+        let added = game_state.add_card_pack(&pack)?;
+        println!("Added {} cards from {}", added, path);
+    }
+
+    // Public state for spectators, rewritten every turn (--spectate <path>)
+    let spectator = path_arg(std::env::args().skip(1), "--spectate")?.map(SnapshotWriter::new);
+
+    // Every player's ledger, written as CSV when the game ends (--ledger <path>)
+    let ledger_path = path_arg(std::env::args().skip(1), "--ledger")?;
+    // Every player's holdings with their cost basis, as CSV when the game ends (--farm-report <path>)
+    let report_path = path_arg(std::env::args().skip(1), "--farm-report")?;
+
+    // Plain prompts instead of the TUI (--cli), optionally saving a replay (--record <file>)
+    if std::env::args().skip(1).any(|arg| arg == "--cli") {
+        let record_path = path_arg(std::env::args().skip(1), "--record")?;
+        if record_path.is_some() && cards_path.is_some() {
+            return Err("--record can't be combined with --cards".into());
+        }
+        // Replays are rebuilt from the rules alone, which don't ask, so only unrecorded games do
+        game_state.confirm_forced_loans = record_path.is_none();
+        let mut engine = match &record_path {
+            Some(_) => recorded_engine(&game_state)?,
+            None => GameEngine::new(game_state),
+        };
+        cli::run_with_spectator(&mut engine, io::stdin().lock(), io::stdout(), spectator)?;
+        if let (Some(path), Some(record)) = (record_path, engine.record()) {
+            record.save(std::path::Path::new(&path))?;
+            println!("Game recorded to {}; watch it again with --replay {}", path, path);
+        }
+        if let Some(path) = ledger_path {
+            export_ledger(engine.state(), &path)?;
+        }
+        if let Some(path) = report_path {
+            export_farm_report(engine.state(), &path)?;
+        }
+        return Ok(());
+    }
 
     // 2. Initialize terminal
     let mut tui = terminal::init()?;
 
     // 3. Create and run the UI application, passing the initialized state
-    let mut app = App::new(game_state); // Pass game_state to App::new
+    let mut app = App::with_settings(game_state, settings);
+    if let Some(spectator) = spectator {
+        app.spectate(spectator);
+    }
+    // Developer console on `~` for setting up situations (--debug)
+    if std::env::args().skip(1).any(|arg| arg == "--debug") {
+        app.enable_debug();
+    }
     app.run(&mut tui)?; // Run the main TUI loop
 
     // 4. Restore terminal before exiting
     terminal::restore()?;
+    if let Some(path) = ledger_path {
+        export_ledger(app.game_state(), &path)?;
+    }
+    if let Some(path) = report_path {
+        export_farm_report(app.game_state(), &path)?;
+    }
     Ok(())
 }
 
-/// Sets up the initial GameState by interacting with the user.
-fn setup_game() -> Result<GameState, Box<dyn Error>> {
+/// Plays `games` games between AI farmers under the rules, scenario and card
+/// pack given on the command line and prints the balance report.
+fn run_simulation(games: usize, rules: GameRules, scenario: Option<&Scenario>) -> Result<(), Box<dyn Error>> {
+    let strategy = if std::env::args().skip(1).any(|arg| arg == "--monte-carlo") { MONTE_CARLO_STRATEGY } else { balance::SIMULATED_STRATEGY };
+    let mut table = balance::simulation_table(balance::SIMULATED_PLAYERS, strategy).rules(rules).seed(rand::random()).build()?;
+    if let Some(scenario) = scenario {
+        scenario.apply(&mut table);
+    }
+    if let Some(path) = path_arg(std::env::args().skip(1), "--cards")? {
+        table.add_card_pack(&CardPack::load(std::path::Path::new(&path))?)?;
+    }
+    println!("Simulating {} games...", games);
+    let report = balance::simulate(&table, games)?;
+    print!("{}", report);
+    if let Some(path) = path_arg(std::env::args().skip(1), "--balance-report")? {
+        std::fs::write(&path, report.csv())?;
+        println!("Balance report written to {}", path);
+    }
+    Ok(())
+}
+
+fn export_ledger(game_state: &GameState, path: &str) -> Result<(), Box<dyn Error>> {
+    std::fs::write(path, game_state.ledger_csv())?;
+    println!("Ledgers written to {}", path);
+    Ok(())
+}
+
+fn export_farm_report(game_state: &GameState, path: &str) -> Result<(), Box<dyn Error>> {
+    std::fs::write(path, game_state.farm_report_csv())?;
+    println!("Farm report written to {}", path);
+    Ok(())
+}
+
+/// House rules from `--rules <path>`, else `rules.toml` in the working directory if present.
+fn load_rules<I: IntoIterator<Item = String>>(args: I) -> Result<GameRules, Box<dyn Error>> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--rules" {
+            let path = args.next().ok_or("--rules requires a path")?;
+            return GameRules::load(std::path::Path::new(&path));
+        }
+    }
+    let default_path = std::path::Path::new("rules.toml");
+    if default_path.exists() {
+        GameRules::load(default_path)
+    } else {
+        Ok(GameRules::default())
+    }
+}
+
+/// Scenario from `--scenario <name or path>`: a built-in scenario name
+/// (e.g. `deep_in_debt`) or a scenario TOML file.
+fn load_scenario<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Scenario>, Box<dyn Error>> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--scenario" {
+            let name = args.next().ok_or("--scenario requires a name or path")?;
+            if let Some(scenario) = Scenario::find_builtin(&name) {
+                return Ok(Some(scenario));
+            }
+            return Scenario::load(std::path::Path::new(&name)).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// Translation from `--locale <path>`; English when not given.
+fn load_locale<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Locale>, Box<dyn Error>> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--locale" {
+            let path = args.next().ok_or("--locale requires a path")?;
+            return Locale::load(std::path::Path::new(&path)).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// Path given after `flag` (e.g. `--spectate <path>`), if the flag is present.
+fn path_arg<I: IntoIterator<Item = String>>(args: I, flag: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return Ok(Some(args.next().ok_or_else(|| format!("{} requires a path", flag))?));
+        }
+    }
+    Ok(None)
+}
+
+/// A seeded engine for the players set up in `game_state`, so the game can be
+/// saved as a replay. Replays start from a fresh deal, so scenarios can't be recorded.
+fn recorded_engine(game_state: &GameState) -> Result<GameEngine, Box<dyn Error>> {
+    if game_state.scenario.is_some() {
+        return Err("--record can't be combined with --scenario".into());
+    }
+    let names: Vec<String> = game_state.turn_order.iter()
+        .map(|id| game_state.players[*id].name.clone())
+        .collect();
+    let mut engine = GameEngine::with_seed(&names, game_state.rules.clone(), rand::random())?;
+    engine.state_mut().locale = std::sync::Arc::clone(&game_state.locale);
+    // Engine ids follow turn order; keep who is an AI and who is at the keyboard
+    for (engine_id, id) in game_state.turn_order.iter().enumerate() {
+        let player = &game_state.players[*id];
+        engine.seat(engine_id, player.player_type.clone(), player.color);
+    }
+    Ok(engine)
+}
+
+/// Sets up the initial GameState by interacting with the user. Nicknames
+/// default to the ones last played with, and are remembered for next time.
+fn setup_game(rules: GameRules, solo: bool, settings: &mut Settings) -> Result<GameState, Box<dyn Error>> {
     // --- Logic moved from original main --- 
     println!("Welcome to the Farming Game!");
-    print!("Enter number of players (3-6) [default: 3]: ");
-    io::stdout().flush()?;
     let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    
-    let num_players: usize = if input.trim().is_empty() {
+    if solo {
+        println!("Solo run: reach {} by the end of year {}.", format_money(rules.winning_net_worth), rules.year_limit.unwrap_or_default());
+    } else {
+        print!("Enter number of players (3-6) [default: 3]: ");
+        io::stdout().flush()?;
+        io::stdin().read_line(&mut input)?;
+    }
+
+    let num_players: usize = if solo {
+        1
+    } else if input.trim().is_empty() {
         3
     } else {
         match input.trim().parse() {
@@ -57,106 +275,75 @@ fn setup_game() -> Result<GameState, Box<dyn Error>> {
         }
     };
 
-    let mut players = HashMap::new();
-    let mut turn_order = Vec::new();
+    // Economy preset, mostly for solo games against the AI
+    print!("Difficulty (easy/normal/hard) [default: {}]: ", rules.difficulty.label());
+    io::stdout().flush()?;
+    input.clear();
+    io::stdin().read_line(&mut input)?;
+    let rules = match Difficulty::parse(&input) {
+        Some(difficulty) => rules.with_difficulty(difficulty),
+        None if input.trim().is_empty() => rules,
+        None => {
+            println!("Invalid input. Keeping {} difficulty.", rules.difficulty.label());
+            rules
+        }
+    };
+
+    let mut builder = GameStateBuilder::default().rules(rules);
     thread::sleep(Duration::from_millis(100));
     let mut available_native_players = NATIVE_PLAYERS.to_vec();
     available_native_players.shuffle(&mut rand::thread_rng());
 
+    let mut nicknames = Vec::new();
     for i in 0..num_players {
         let native_player = &available_native_players[i];
-        print!("Enter nickname for {} ({}) [default: {}]: ", native_player.name, native_player.color, native_player.color);
+        let default_nickname = settings.last_player(i).unwrap_or(native_player.color.name()).to_string();
+        print!("Enter nickname for {} ({}) [default: {}]: ", native_player.name, native_player.color.name(), default_nickname);
         io::stdout().flush()?;
         input.clear();
         io::stdin().read_line(&mut input)?;
         
         let nickname = if input.trim().is_empty() {
-            native_player.color.to_string()
+            default_nickname
         } else {
             input.trim().to_string()
         };
+        nicknames.push(nickname.clone());
         let display_name = format!("{} ({})", native_player.name, nickname);
 
-        players.insert(i, Player {
-            id: i,
-            name: display_name,
-            player_type: PlayerType::Human,
-            cash: STARTING_CASH,
-            debt: STARTING_DEBT,
-            land: STARTING_LAND,
-            is_active: true,
-            position: STARTING_POSITION,
-            year: STARTING_YEAR,
-            eligible_for_side_job_pay: true,
-            crop_yield_multipliers: HashMap::new(),
-            assets: HashMap::new(), // Start with no explicit assets, handled later if needed
-            history: vec![],
-            completed_harvests: HashSet::new(),
-            persistent_effects: vec![],
-            hand: vec![],
-            active_persistent_cards: vec![],
-            net_worth: 0, // Will be calculated by GameState::new_with_players
-            total_asset_value: 0,
-            total_ridge_value: 0,
-            total_income: 0,
-            total_expenses: 0,
-            turns_taken: 0,
-        });
-        turn_order.push(i);
-    }
-
-    let mut game = GameState::new_with_players(players, turn_order);
-
-    println!("\nInitial Deck Sizes:");
-    println!("Farmer's Fate Deck: {} cards", game.farmer_fate_deck.draw_pile.len());
-    println!("Operating Cost Deck: {} cards", game.operating_cost_deck.draw_pile.len());
-    println!("Option to Buy Deck: {} cards", game.option_to_buy_deck.draw_pile.len());
-
-    for player_id in 0..num_players {
-        let player_name = game.players[&player_id].name.clone();
-        println!("\nGiving {} their initial Option to Buy cards...", player_name);
-        for i in 0..2 {
-            match game.draw_card(TileType::OptionToBuy) {
-                Ok(card) => {
-                    game.players.get_mut(&player_id).unwrap().hand.push(card.clone());
-                    println!("  Card {}: {} - {}", i + 1, card.title, card.description);
-                }
-                Err(e) => {
-                    println!("Error drawing card for {}: {}", player_name, e);
-                    if !game.option_to_buy_deck.discard_pile.is_empty() {
-                        println!("Reshuffling discard pile...");
-                        game.option_to_buy_deck.draw_pile = game.option_to_buy_deck.discard_pile.clone();
-                        game.option_to_buy_deck.discard_pile.clear();
-                        game.option_to_buy_deck.shuffle();
-                        if let Ok(card) = game.draw_card(TileType::OptionToBuy) {
-                            game.players.get_mut(&player_id).unwrap().hand.push(card.clone());
-                            println!("  Card {}: {} - {}", i + 1, card.title, card.description);
-                        }
-                    }
-                }
-            }
-        }
+        let mut player = Player::new(i, display_name, PlayerType::Human);
+        player.color = native_player.color;
+        builder = builder.add_player(player);
     }
 
-    // Verify the final OTB card distribution
-    println!("\n==== VERIFYING CARD DISTRIBUTION ====");
-    for player_id in 0..num_players {
-        let player = &game.players[&player_id];
-        let otb_cards: Vec<&Card> = player.hand.iter()
-            .filter(|card| matches!(card.effect, GameEffect::OptionalBuyAsset { .. }))
-            .collect();
-        
-        println!("{} has {} total cards ({} OTB cards):", 
-            player.name, player.hand.len(), otb_cards.len());
-        
-        for (i, card) in otb_cards.iter().enumerate() {
-            println!("  OTB Card {}: {} - {}", i + 1, card.title, card.description);
+    let mut game = builder.build()?;
+    settings.last_players = nicknames;
+    if let Err(e) = settings.save() {
+        println!("Couldn't save settings: {}", e);
+    }
+
+    // Opening high roll: highest goes first, and players who tie roll again
+    if !solo {
+        println!("\nRolling for turn order (high roll goes first)...");
+    }
+    let mut opening = OpeningRoll::new(&game.turn_order);
+    while let Some(player_id) = opening.next_roller() {
+        print!("{}, press Enter to roll: ", game.players[player_id].name);
+        io::stdout().flush()?;
+        input.clear();
+        io::stdin().read_line(&mut input)?;
+        let mut logs = Vec::new();
+        game.roll_for_order(&mut opening, &mut logs);
+        for log in logs {
+            println!("  {}", log);
         }
-        
-        // If OTB cards != 2, highlight this as an issue
-        if otb_cards.len() != 2 {
-            println!("  WARNING: {} should have 2 OTB cards but has {}!", 
-                player.name, otb_cards.len());
+    }
+
+    game.deal_opening_hands()?;
+    for player in game.players.iter() {
+        println!("\n{} is dealt:", player.name);
+        for card in &player.hand {
+            println!("  {} - {}", card.title, card.description);
         }
     }
 
@@ -1,4 +1,8 @@
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+use serde::{Deserialize, Serialize};
+use crate::models::ledger::csv_field;
+use crate::models::money::Money;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Serialize, Deserialize)]
 pub enum AssetType {
     Grain,
     Hay,
@@ -6,8 +10,46 @@ pub enum AssetType {
     Fruit,
     Tractor,
     Harvester,
+    // Farm upgrades
+    Irrigation,
+    Barn,
+    Silo,
 } // Correct per game board
 
+impl AssetType {
+    /// Improvements to the farm itself. A farm has at most one of each and
+    /// they are never harvested; owning one changes how the farm's crops fare.
+    pub const UPGRADES: [AssetType; 3] = [AssetType::Irrigation, AssetType::Barn, AssetType::Silo];
+
+    pub fn is_upgrade(&self) -> bool {
+        Self::UPGRADES.contains(self)
+    }
+
+    /// Assets counted in acres of the farm's land.
+    pub const ACRES: [AssetType; 3] = [AssetType::Hay, AssetType::Grain, AssetType::Fruit];
+
+    pub fn is_acres(&self) -> bool {
+        Self::ACRES.contains(self)
+    }
+
+    /// Crop this upgrade raises the harvest of, and the multiplier.
+    pub fn yield_bonus(&self) -> Option<(AssetType, f32)> {
+        match self {
+            AssetType::Irrigation => Some((AssetType::Hay, 1.25)),
+            AssetType::Barn => Some((AssetType::Cows, 1.2)),
+            _ => None,
+        }
+    }
+
+    /// Upgrade that keeps this crop out of the weather, sparing it price cuts.
+    pub fn storage(&self) -> Option<AssetType> {
+        match self {
+            AssetType::Grain => Some(AssetType::Silo),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for AssetType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -17,13 +59,97 @@ impl std::fmt::Display for AssetType {
             AssetType::Fruit => write!(f, "Fruit"),
             AssetType::Tractor => write!(f, "tractor"),
             AssetType::Harvester => write!(f, "harvester"),
+            AssetType::Irrigation => write!(f, "irrigation"),
+            AssetType::Barn => write!(f, "barn"),
+            AssetType::Silo => write!(f, "silo"),
         }
     }
 }
 
+/// First line of an exported farm report.
+pub const REPORT_CSV_HEADER: &str = "player,asset,quantity,cost_basis,average_price,income,return_pct\n";
+
+/// Units of an asset bought together, kept until they're sold.
 #[derive(Debug, Clone, PartialEq)]
+pub struct AssetLot {
+    pub quantity: u32,
+    /// What the whole lot cost
+    pub cost: Money,
+    /// Game year it was bought
+    pub year: u32,
+}
+
+impl AssetLot {
+    pub fn unit_price(&self) -> Money {
+        self.cost / self.quantity.max(1)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct AssetRecord {
-    pub quantity: i32,
-    pub total_cost: i32,
-    pub total_income: i32,
+    pub quantity: u32,
+    /// Everything ever paid for this asset, including units since sold
+    pub total_cost: Money,
+    pub total_income: Money,
+    /// Units still held by when they were bought, oldest first
+    pub lots: Vec<AssetLot>,
+}
+
+impl AssetRecord {
+    /// Adds a lot bought in `year`.
+    pub fn buy(&mut self, quantity: u32, cost: Money, year: u32) {
+        self.quantity += quantity;
+        self.total_cost += cost;
+        if quantity > 0 {
+            self.lots.push(AssetLot { quantity, cost, year });
+        }
+    }
+
+    /// Removes up to `quantity` units, oldest lots first, and returns what
+    /// those units cost. Part of a lot takes its share of the lot's cost.
+    pub fn take(&mut self, quantity: u32) -> Money {
+        let quantity = quantity.min(self.quantity);
+        self.quantity -= quantity;
+        let mut remaining = quantity;
+        let mut cost = Money::ZERO;
+        while remaining > 0 {
+            let Some(lot) = self.lots.first_mut() else { break };
+            if lot.quantity <= remaining {
+                remaining -= lot.quantity;
+                cost += self.lots.remove(0).cost;
+            } else {
+                let share = lot.cost * remaining / lot.quantity;
+                lot.quantity -= remaining;
+                lot.cost -= share;
+                cost += share;
+                remaining = 0;
+            }
+        }
+        cost
+    }
+
+    /// What the units still held cost.
+    pub fn cost_basis(&self) -> Money {
+        self.lots.iter().map(|lot| lot.cost).sum()
+    }
+
+    /// Average price paid per unit still held.
+    pub fn average_price(&self) -> Option<Money> {
+        (self.quantity > 0).then(|| self.cost_basis() / self.quantity)
+    }
+
+    /// Income plus the cost of what's still held, less everything paid, as a
+    /// percentage of what was paid. `None` for assets that cost nothing.
+    pub fn return_pct(&self) -> Option<f64> {
+        self.total_cost.is_positive().then(|| {
+            (self.total_income + self.cost_basis() - self.total_cost).ratio(self.total_cost) * 100.0
+        })
+    }
+
+    /// CSV row for the farm report (see `REPORT_CSV_HEADER`).
+    pub fn report_row(&self, player: &str, asset: AssetType) -> String {
+        format!("{},{},{},{},{},{},{}\n", csv_field(player), asset, self.quantity, self.cost_basis().dollars(),
+            self.average_price().map_or(String::new(), |price| price.dollars().to_string()), self.total_income.dollars(),
+            self.return_pct().map_or(String::new(), |pct| format!("{:.1}", pct)))
+    }
 } 
\ No newline at end of file
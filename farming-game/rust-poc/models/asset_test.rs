@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::models::{AssetLot, AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+    use crate::config::GameRules;
+
+    #[test]
+    fn test_selling_takes_the_oldest_lots_first() {
+        let mut player = Player::new(0, "Ann".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Cows, 10, Money(5000));
+        player.year = 2;
+        player.add_asset(AssetType::Cows, 10, Money(8000));
+
+        let cost = player.sell_asset(AssetType::Cows, 15, Money(600));
+        assert_eq!(cost, Money(5000) + Money(4000), "All of the first lot and half the second");
+        let record = &player.assets[&AssetType::Cows];
+        assert_eq!(record.lots, vec![AssetLot { quantity: 5, cost: Money(4000), year: 2 }]);
+        assert_eq!(record.cost_basis(), Money(4000));
+        assert_eq!(record.average_price(), Some(Money(800)));
+        assert_eq!(record.total_cost, Money(13000), "What was paid overall doesn't change");
+    }
+
+    #[test]
+    fn test_return_counts_income_and_what_is_still_held() {
+        let mut player = Player::new(0, "Ann".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Cows, 10, Money(5000));
+        player.sell_asset(AssetType::Cows, 5, Money(600));
+        player.add_income(AssetType::Cows, Money(1000));
+
+        // 3000 from the sale and 1000 at harvest, with 2500 worth still held, on 5000 paid
+        let record = &player.assets[&AssetType::Cows];
+        assert_eq!(record.return_pct(), Some(30.0));
+    }
+
+    #[test]
+    fn test_farm_report_lists_holdings_in_turn_order() {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Ann".to_string(), PlayerType::Human));
+        players.insert(Player::new(1, "Bo, Jr.".to_string(), PlayerType::Human));
+        let mut game = GameState::new_with_players(players, vec![1, 0], GameRules::default());
+        for player in game.players.iter_mut() {
+            player.assets.clear();
+        }
+        game.players[0].add_asset(AssetType::Tractor, 1, Money(10000));
+        game.players[1].add_asset(AssetType::Hay, 10, Money::ZERO);
+        game.players[1].add_asset(AssetType::Cows, 4, Money(2000));
+
+        assert_eq!(game.farm_report_csv(), "player,asset,quantity,cost_basis,average_price,income,return_pct\n\
+            \"Bo, Jr.\",hay,10,0,0,0,\n\
+            \"Bo, Jr.\",cows,4,2000,500,0,0.0\n\
+            Ann,tractor,1,10000,10000,0,0.0\n");
+    }
+}
@@ -1,6 +1,10 @@
+use serde::{Deserialize, Serialize};
 use crate::models::asset::AssetType;
+use crate::models::money::Money;
+use crate::models::effects::GameEffect;
+use crate::models::brief::brief_description;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TileType {
     FarmerFate,
     CropIncome,
@@ -19,7 +23,7 @@ pub enum TileType {
 }
 
 // Correct per game board
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum HarvestType {
     None,
     Corn,
@@ -33,42 +37,160 @@ pub enum HarvestType {
     Wheat,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+impl HarvestType {
+    /// Name shown in harvest log lines.
+    pub fn title(&self) -> &'static str {
+        match self {
+            HarvestType::HayCutting1 => "Hay: First Cutting",
+            HarvestType::HayCutting2 => "Hay: Second Cutting",
+            HarvestType::HayCutting3 => "Hay: Third Cutting",
+            HarvestType::HayCutting4 => "Hay: Fourth Cutting",
+            HarvestType::Wheat => "Wheat",
+            HarvestType::Corn => "Corn",
+            HarvestType::Apple => "Apple",
+            HarvestType::Cherry => "Cherry",
+            HarvestType::Livestock => "Livestock Sales",
+            HarvestType::None => "Unknown Harvest",
+        }
+    }
+
+    /// One of the four hay cuttings, each paid at most once a year.
+    pub fn is_hay_cutting(&self) -> bool {
+        matches!(self, HarvestType::HayCutting1 | HarvestType::HayCutting2 | HarvestType::HayCutting3 | HarvestType::HayCutting4)
+    }
+
+    /// What the player must own to bring this harvest in.
+    pub fn asset(&self) -> Option<AssetType> {
+        match self {
+            HarvestType::Corn | HarvestType::Wheat => Some(AssetType::Grain),
+            HarvestType::Apple | HarvestType::Cherry => Some(AssetType::Fruit),
+            HarvestType::Livestock => Some(AssetType::Cows),
+            HarvestType::HayCutting1 | HarvestType::HayCutting2 |
+            HarvestType::HayCutting3 | HarvestType::HayCutting4 => Some(AssetType::Hay),
+            HarvestType::None => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TileEffect {
     None,
     DrawCard(TileType),
-    GainCash(i32),
-    PayCash(i32),
+    GainCash(Money),
+    PayCash(Money),
     SkipYear,
     GoToTile(usize),
     Special(String),
-    ExpensePerAsset { asset: AssetType, rate: i32 },
+    ExpensePerAsset { asset: AssetType, rate: Money },
     DoubleYieldForCrop(AssetType),
     PayInterest,
-    GoToTileAndGainCash { tile_index: usize, amount: i32 },
-    GainCashIfAsset { asset: AssetType, amount: i32 },
-    PayCashIfAsset { asset: AssetType, amount: i32 },
-    HarvestBonusPerAcre { asset: AssetType, bonus: i32 },
+    GoToTileAndGainCash { tile_index: usize, amount: Money },
+    GainCashIfAsset { asset: AssetType, amount: Money },
+    PayCashIfAsset { asset: AssetType, amount: Money },
+    HarvestBonusPerAcre { asset: AssetType, bonus: Money },
     MoveAndHarvestIfAsset { 
         asset: AssetType,
         destination: usize,
-        bonus: i32,
+        bonus: Money,
         harvest_type: HarvestType,
     },
     OneTimeHarvestMultiplier { asset: AssetType, multiplier: f32 },
 }
 
-#[derive(Debug, Clone)]
+impl TileEffect {
+    /// Tile this effect moves the player to, if it moves them.
+    pub fn jump_target(&self) -> Option<usize> {
+        match self {
+            TileEffect::GoToTile(tile_index) | TileEffect::GoToTileAndGainCash { tile_index, .. } => Some(*tile_index),
+            TileEffect::MoveAndHarvestIfAsset { destination, .. } => Some(*destination),
+            _ => None,
+        }
+    }
+
+    /// What this tile's jump does besides moving the player; `None` if it
+    /// doesn't move them.
+    pub fn jump_rules(&self) -> Option<JumpRules> {
+        GameEffect::from(self).jump_rules()
+    }
+}
+
+/// How a jump treats the tiles around it. Jumps always move forward, so one
+/// whose destination is behind the player goes round the start of the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JumpRules {
+    /// Resolve the destination's harvest and effect, as if landed on by a roll
+    pub apply_destination: bool,
+    /// Going round the start of the board starts a new year, side job wage
+    /// included, as it would on a roll
+    pub collect_pass_through: bool,
+}
+
+/// Month of the farm year. The board runs January through December, with
+/// Christmas Vacation opening the year as part of January.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Month::January => "January",
+            Month::February => "February",
+            Month::March => "March",
+            Month::April => "April",
+            Month::May => "May",
+            Month::June => "June",
+            Month::July => "July",
+            Month::August => "August",
+            Month::September => "September",
+            Month::October => "October",
+            Month::November => "November",
+            Month::December => "December",
+        }
+    }
+}
+
+/// A square of the board. Boards are saved as TOML (see `game::board_file`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BoardTile {
     pub index: usize,
     pub name: String,
+    /// Month the tile falls in, so rules can follow the calendar instead of board positions
+    pub month: Month,
+    /// Week of the month, 1 to 4; tiles between two weeks share the earlier one
+    pub week: u8,
     pub tile_type: TileType,
     pub harvest_type: HarvestType,
     pub effect: TileEffect,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Short text for tight spaces; derived from the description on load when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description_brief: Option<String>,
 }
 
+impl BoardTile {
+    /// Derives the brief description from the full one, unless the tile has its own.
+    pub fn derive_brief(&mut self) {
+        if self.description_brief.is_none() {
+            self.description_brief = self.description.as_deref().map(brief_description);
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Ridge {
     pub name: String,
@@ -0,0 +1,76 @@
+// src/models/brief.rs
+// Brief descriptions, the short text shown where a card's or tile's full
+// description doesn't fit. Card packs and board files may give one
+// explicitly; otherwise it's derived here once, when the data is loaded, and
+// kept on the card or tile.
+
+use std::ops::Range;
+
+/// Descriptions shorter than this are already brief.
+const BRIEF_LENGTH: usize = 50;
+
+/// Derives a brief description from a full one. Keeps the sentence that
+/// collects or pays an amount, or the instruction to go somewhere; harvest
+/// changes and card draws get a stock phrase; anything else its first sentence.
+pub fn brief_description(description: &str) -> String {
+    let description = description.trim();
+    if description.len() < BRIEF_LENGTH {
+        return description.to_string();
+    }
+    let sentences = sentences(description);
+    let sentence_at = |at: usize| sentences.iter().find(|range| range.contains(&at)).cloned().unwrap_or(0..description.len());
+
+    for keyword in ["COLLECT", "PAY"] {
+        if let Some(at) = description.find(keyword) {
+            let sentence = &description[sentence_at(at)];
+            if sentence.contains('$') {
+                return sentence.trim().to_string();
+            }
+        }
+    }
+
+    // For movement, just the destination
+    if let Some(at) = description.find("Go to") {
+        return description[at..sentence_at(at).end].trim().to_string();
+    }
+
+    if description.contains("harvest") {
+        if description.contains("Double") {
+            return "Double harvest this year.".to_string();
+        }
+        if description.contains("Cut") && description.contains("half") {
+            return "Cut harvest in half.".to_string();
+        }
+    }
+
+    if description.contains("Draw") {
+        if description.contains("O.T.B.") {
+            return "Draw O.T.B.".to_string();
+        }
+        if description.contains("Farmer's Fate") {
+            return "Draw Farmer's Fate.".to_string();
+        }
+    }
+
+    sentences.first().map_or(description, |range| &description[range.clone()]).trim().to_string()
+}
+
+/// Byte ranges of the sentences in `text`. A period only ends a sentence
+/// before a space or at the end, so the periods inside "O.T.B." or "$1.50"
+/// don't split one.
+fn sentences(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let at_break = chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+        if matches!(c, '.' | '!' | '?') && at_break {
+            ranges.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    if start < text.len() {
+        ranges.push(start..text.len());
+    }
+    ranges
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crate::models::brief::brief_description;
+
+    #[test]
+    fn test_short_descriptions_are_kept() {
+        assert_eq!(brief_description("COLLECT $1000 Christmas bonus!"), "COLLECT $1000 Christmas bonus!");
+        assert_eq!(brief_description("  Skip a year.  "), "Skip a year.");
+    }
+
+    #[test]
+    fn test_collect_and_pay_keep_the_sentence_with_the_amount() {
+        assert_eq!(
+            brief_description("Your neighbor's bull got loose in your corn. COLLECT $500 from the neighbor for damages."),
+            "COLLECT $500 from the neighbor for damages.",
+        );
+        assert_eq!(
+            brief_description("Hurt your back lifting hay bales all week long. PAY $500 to the chiropractor. Ouch."),
+            "PAY $500 to the chiropractor.",
+        );
+        assert_eq!(
+            brief_description("PAY $1.50 per bushel in storage fees for every bushel of grain you own without a period"),
+            "PAY $1.50 per bushel in storage fees for every bushel of grain you own without a period",
+        );
+    }
+
+    #[test]
+    fn test_go_to_keeps_the_destination() {
+        assert_eq!(
+            brief_description("Mud season comes early and the tractor is stuck again. Go to Spring Planting."),
+            "Go to Spring Planting.",
+        );
+        assert_eq!(
+            brief_description("Too many cooks spoiling the broth at the county fair, so Go to Harvest Moon"),
+            "Go to Harvest Moon",
+        );
+    }
+
+    #[test]
+    fn test_harvests_and_draws_get_stock_phrases() {
+        assert_eq!(brief_description("Perfect weather all season long. Double your harvest this year."), "Double harvest this year.");
+        assert_eq!(brief_description("Hail storm flattens the fields overnight. Cut your harvest check in half."), "Cut harvest in half.");
+        assert_eq!(brief_description("Rainy day, so you catch up on the paperwork inside. Draw O.T.B."), "Draw O.T.B.");
+        assert_eq!(brief_description("The county extension agent stops by with news. Draw Farmer's Fate."), "Draw Farmer's Fate.");
+    }
+
+    #[test]
+    fn test_anything_else_keeps_its_first_sentence() {
+        assert_eq!(brief_description("Independence Day Bash! The whole county comes out for fireworks."), "Independence Day Bash!");
+        let no_period = "A long and winding description that never quite gets around to ending";
+        assert_eq!(brief_description(no_period), no_period);
+    }
+}
@@ -0,0 +1,53 @@
+// src/models/calendar.rs
+// The farm year's calendar. Every tile falls in a week of a month, so a
+// player's date follows them around the board and rules that depend on the
+// time of year (seasons, the Option to Buy window) can compare dates instead
+// of board positions.
+
+use std::fmt;
+use crate::models::{BoardTile, GameState, Month};
+
+/// A week of the farm year. Dates order by month, then week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub month: Month,
+    pub week: u8,
+}
+
+impl CalendarDate {
+    pub fn new(month: Month, week: u8) -> Self {
+        Self { month, week }
+    }
+
+    /// Date of the tile at `position`, if it's on the board.
+    pub fn at(board: &[BoardTile], position: usize) -> Option<Self> {
+        board.get(position).map(BoardTile::date)
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Week {}", self.month.name(), self.week)
+    }
+}
+
+impl BoardTile {
+    pub fn date(&self) -> CalendarDate {
+        CalendarDate::new(self.month, self.week)
+    }
+}
+
+impl GameState {
+    /// Where the player is in their current year.
+    pub fn player_date(&self, player_id: usize) -> CalendarDate {
+        let player = self.players.get(player_id).unwrap();
+        CalendarDate::at(&self.board, player.position).unwrap_or(CalendarDate::new(Month::January, 1))
+    }
+
+    /// The current player's year and date, e.g. "Year 3, March Week 2".
+    pub fn date_label(&self) -> String {
+        let Some(&player_id) = self.turn_order.get(self.current_turn_index) else { return String::new() };
+        let year = self.players.get(player_id).map_or(1, |player| player.year);
+        self.locale.tr("calendar.date", &[("year", &year), ("date", &self.player_date(player_id))])
+    }
+}
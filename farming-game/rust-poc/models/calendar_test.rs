@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{GameRules, OtbWindow};
+    use crate::game::board::create_full_board;
+    use crate::game::GamePhase;
+    use crate::models::{CalendarDate, GameState, Month, Player, PlayerRegistry, PlayerType};
+
+    #[test]
+    fn test_every_tile_has_a_date_and_the_year_runs_forwards() {
+        let board = create_full_board();
+        assert_eq!(CalendarDate::at(&board, 0), Some(CalendarDate::new(Month::January, 1)), "Christmas Vacation opens January");
+        assert_eq!(CalendarDate::at(&board, 11).unwrap().to_string(), "March Week 3", "Hurt Back takes the place of a week");
+        assert_eq!(CalendarDate::at(&board, board.len()), None);
+        assert!(board.windows(2).all(|pair| pair[0].date() <= pair[1].date()));
+        assert!(board.iter().all(|tile| (1..=4).contains(&tile.week)));
+    }
+
+    #[test]
+    fn test_phases_follow_the_calendar() {
+        let board = create_full_board();
+        let phase_at = |position: usize| GamePhase::from_date(board[position].date());
+        assert_eq!(phase_at(14), GamePhase::SpringPlanting, "Spring Planting closes the planting season");
+        assert_eq!(phase_at(15), GamePhase::EarlySummer);
+        assert_eq!(phase_at(25), GamePhase::LateSummer, "Independence Day opens late summer");
+        assert_eq!(phase_at(38), GamePhase::LateSummer);
+        assert_eq!(phase_at(39), GamePhase::EndOfYear);
+        assert!(OtbWindow::ThroughMonth(Month::June).is_open(&board, 24));
+        assert!(!OtbWindow::ThroughMonth(Month::June).is_open(&board, 25));
+    }
+
+    #[test]
+    fn test_date_label_shows_the_current_players_year() {
+        let mut players = PlayerRegistry::new();
+        let mut player = Player::new(0, "Ann".to_string(), PlayerType::Human);
+        player.position = 10;
+        player.year = 3;
+        players.insert(player);
+        let game = GameState::new_with_players(players, vec![0], GameRules::default());
+
+        assert_eq!(game.player_date(0), CalendarDate::new(Month::March, 2));
+        assert_eq!(game.date_label(), "Year 3, March Week 2");
+    }
+}
@@ -1,29 +1,127 @@
-use crate::models::AssetType;
+use serde::{Deserialize, Serialize};
+use crate::models::{AssetType, HarvestType, JumpRules, Money, TileType};
 use crate::models::player::EffectType;
+use crate::game::effect_registry::EffectParams;
 
 #[derive(Debug, Clone)]
 pub enum GameEffect {
     // Card Effects
-    CollectFromOthersIfHas { asset: AssetType, amount: i32 },
-    IncomeIfHas { asset: AssetType, amount: i32 },
+    CollectFromOthersIfHas { asset: AssetType, amount: Money },
+    IncomeIfHas { asset: AssetType, amount: Money },
     SuppressHarvestIncome,
     MtStHelensDisaster,
-    PayIfNoAssetDistribute { required_asset: AssetType, amount: i32 },
-    ExpensePerAsset { asset: AssetType, rate: i32 },
-    IncomePerAsset { asset: AssetType, rate: i32 },
-    Income(i32),
-    Expense(i32),
-    AdjustDebt(i32),
+    PayIfNoAssetDistribute { required_asset: AssetType, amount: Money },
+    ExpensePerAsset { asset: AssetType, rate: Money },
+    IncomePerAsset { asset: AssetType, rate: Money },
+    Income(Money),
+    Expense(Money),
+    AdjustDebt(Money),
     AdjustLand(i32),
     Special(String),
-    LeaseRidge { name: String, cost: i32, cow_count: i32 },
-    BuyAsset { asset: AssetType, quantity: i32, cost: i32 },
-    OptionalBuyAsset { asset: AssetType, quantity: i32, cost: i32 },
+    LeaseRidge { name: String, cost: Money, cow_count: i32 },
+    BuyAsset { asset: AssetType, quantity: u32, cost: Money },
+    OptionalBuyAsset { asset: AssetType, quantity: u32, cost: Money },
     SkipYear,
-    AddPersistentEffect { effect_type: EffectType, years: u32 },
+    /// Sets this year's weather to drought, then skips the year
+    DroughtYear,
+    /// Lasts `years` year ends, or until it has applied to `harvests` harvests if that's sooner
+    AddPersistentEffect { effect_type: EffectType, years: u32, harvests: Option<u32> },
+    /// Joins a machinery co-op: operating expenses drawn at harvest cost
+    /// `percent` less for `years` years
+    MachineryCoop { percent: u32, years: u32 },
     SlaughterCowsWithoutCompensation,
+    /// Slaughters the farm's cows; the state pays the year's market price per head
+    SlaughterCowsWithCompensation,
     PayInterest,
     DrawOperatingExpenseNoHarvest,
     OneTimeHarvestMultiplier { asset: AssetType, multiplier: f32 },
-    IncomePerLandAcre { rate: i32 },
-} 
\ No newline at end of file
+    IncomePerLandAcre { rate: Money },
+
+    // Board Effects
+    None,
+    DrawCard(TileType),
+    /// Moves to the tile and resolves it (see `jump_rules`)
+    MoveToTile(usize),
+    /// Moves to the tile and collects the cash instead of resolving the tile
+    /// or passing the start (see `jump_rules`)
+    MoveToTileWithIncome { tile_index: usize, amount: Money },
+    ExpenseIfHas { asset: AssetType, amount: Money },
+    DoubleYieldForCrop(AssetType),
+    /// Moves owners of `asset` to the destination with the bonus and brings in
+    /// `harvest_type` instead of resolving the tile (see `jump_rules`)
+    MoveAndHarvestIfHas { asset: AssetType, destination: usize, bonus: Money, harvest_type: HarvestType },
+
+    /// Looked up by key in the game's `EffectRegistry`; how data-loaded cards act
+    Custom { key: String, params: EffectParams },
+} 
+/// Broad kind of a card's effect, for counting what's left in a deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum EffectCategory {
+    /// Cash coming in
+    Income,
+    /// Cash going out, including interest and debt
+    Expense,
+    /// Land, equipment, livestock or a ridge to buy
+    Purchase,
+    /// Changes to harvests still to come
+    Harvest,
+    /// Lost years, slaughtered herds and natural disasters
+    Disaster,
+    /// Moves, extra draws and everything else
+    Other,
+}
+
+impl GameEffect {
+    /// How each effect that moves the player treats the tiles around its
+    /// destination; `None` for effects that don't move them.
+    pub fn jump_rules(&self) -> Option<JumpRules> {
+        match self {
+            GameEffect::MoveToTile(_) => Some(JumpRules { apply_destination: true, collect_pass_through: true }),
+            // The cash is the year's wage, standing in for both the tile and passing the start
+            GameEffect::MoveToTileWithIncome { .. } => Some(JumpRules { apply_destination: false, collect_pass_through: false }),
+            // The harvest stands in for the destination tile
+            GameEffect::MoveAndHarvestIfHas { .. } => Some(JumpRules { apply_destination: false, collect_pass_through: true }),
+            _ => None,
+        }
+    }
+
+    pub fn category(&self) -> EffectCategory {
+        match self {
+            GameEffect::Income(_)
+            | GameEffect::IncomeIfHas { .. }
+            | GameEffect::IncomePerLandAcre { .. }
+            | GameEffect::CollectFromOthersIfHas { .. }
+            | GameEffect::MoveToTileWithIncome { .. } => EffectCategory::Income,
+            // A negative rate is a price cut
+            GameEffect::IncomePerAsset { rate, .. } if *rate >= Money::ZERO => EffectCategory::Income,
+            GameEffect::IncomePerAsset { .. }
+            | GameEffect::Expense(_)
+            | GameEffect::ExpensePerAsset { .. }
+            | GameEffect::ExpenseIfHas { .. }
+            | GameEffect::PayIfNoAssetDistribute { .. }
+            | GameEffect::PayInterest
+            | GameEffect::AdjustDebt(_) => EffectCategory::Expense,
+            GameEffect::BuyAsset { .. }
+            | GameEffect::OptionalBuyAsset { .. }
+            | GameEffect::LeaseRidge { .. } => EffectCategory::Purchase,
+            GameEffect::AddPersistentEffect { .. }
+            | GameEffect::MachineryCoop { .. }
+            | GameEffect::OneTimeHarvestMultiplier { .. }
+            | GameEffect::DoubleYieldForCrop(_)
+            | GameEffect::MoveAndHarvestIfHas { .. } => EffectCategory::Harvest,
+            GameEffect::MtStHelensDisaster
+            | GameEffect::SkipYear
+            | GameEffect::DroughtYear
+            | GameEffect::SlaughterCowsWithoutCompensation
+            | GameEffect::SlaughterCowsWithCompensation
+            | GameEffect::SuppressHarvestIncome
+            | GameEffect::DrawOperatingExpenseNoHarvest => EffectCategory::Disaster,
+            GameEffect::None
+            | GameEffect::Special(_)
+            | GameEffect::AdjustLand(_)
+            | GameEffect::DrawCard(_)
+            | GameEffect::MoveToTile(_)
+            | GameEffect::Custom { .. } => EffectCategory::Other,
+        }
+    }
+}
@@ -1,109 +1,183 @@
-use std::collections::HashMap;
-use crate::models::{Player, BoardTile, Ridge, TileType, HarvestType, TileEffect};
-use crate::cards::{deck::Deck, card::Card};
-use crate::game::{GamePhase, board, GameEffect};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Arc;
+use crate::models::{Player, PlayerRegistry, BoardTile, Ridge, TileType, HarvestType, LedgerCategory, Money};
+use crate::cards::{deck::{Deck, DeckStats}, card::Card};
+use crate::cards::pack::{CardPack, CardPackError, PackDeck};
+use crate::game::{GamePhase, board, DecisionKind, DecisionRequest, EffectParams, EffectRegistry, EffectResolver, GameEffect, GameError, GameEvent};
+use crate::game::constraints;
+use crate::game::decision::PendingEffect;
 use crate::game::harvest::HarvestManager;
-use crate::models::asset::AssetType;
-use crate::models::player::PlayerType;
+use crate::game::weather::Weather;
+use crate::game::scenario::Scenario;
+use crate::locale::Locale;
+use crate::models::asset::{self, AssetType};
+use crate::models::ledger;
+use crate::models::valuation::ValueTable;
+use crate::models::player::{PlayerColor, PlayerType};
+use crate::config::GameRules;
 use crate::cards::catalogs::{operating_expense_catalog, farmers_fate_catalog, option_to_buy_catalog};
-use rand::{thread_rng, seq::SliceRandom, Rng};
-
-const NATIVE_PLAYERS: [(&str, &str); 6] = [
-    ("Roza Ray", "Red"),
-    ("Harrah Harry", "Brown"),
-    ("Toppenish Tom", "Green"),
-    ("Satus Sam", "Blue"),
-    ("Sunnyside Sidney", "White"),
-    ("Wapato Willie", "Yellow"),
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const NATIVE_PLAYERS: [(&str, PlayerColor); 6] = [
+    ("Roza Ray", PlayerColor::Red),
+    ("Harrah Harry", PlayerColor::Brown),
+    ("Toppenish Tom", PlayerColor::Green),
+    ("Satus Sam", PlayerColor::Blue),
+    ("Sunnyside Sidney", PlayerColor::White),
+    ("Wapato Willie", PlayerColor::Yellow),
 ];
 
 #[derive(Debug, Clone)]
 pub struct GameState {
-    pub players: HashMap<usize, Player>,
+    pub players: PlayerRegistry,
     pub turn_order: Vec<usize>,
     pub current_turn_index: usize,
     pub phase: GamePhase,
-    pub _events: Vec<String>, // Prefixed unused field
-    pub board: Vec<BoardTile>,
+    pub(crate) _events: Vec<String>, // Prefixed unused field
+    /// Shared between clones; use `Arc::make_mut` to customize one game's board.
+    pub board: Arc<Vec<BoardTile>>,
     pub farmer_fate_deck: Deck,
     pub operating_cost_deck: Deck,
     pub option_to_buy_deck: Deck,
     pub ridges: Vec<Ridge>,
     pub harvest_manager: HarvestManager,
-    pub _ridge_leases: HashMap<usize, usize>, // Prefixed unused field
+    pub value_table: ValueTable,
+    pub rules: GameRules,
+    /// Source of all dice rolls and shuffles; reseed for reproducible games.
+    pub rng: StdRng,
+    /// Weather rolled for each game year; years not yet rolled are normal
+    pub weather_by_year: BTreeMap<u32, Weather>,
+    /// Cow market price per head from the year it was set; see `cow_price`
+    pub cow_price_by_year: BTreeMap<u32, Money>,
+    /// Challenge being played, if any; decides who wins
+    pub scenario: Option<Scenario>,
+    /// Questions effects are waiting on, oldest first
+    pub pending_decisions: VecDeque<DecisionRequest>,
+    /// Ask human players before an effect borrows for them (see `DecisionRequest`);
+    /// front ends that can show the question turn this on
+    pub confirm_forced_loans: bool,
+    /// Everything that has happened, oldest first (see `game::events`)
+    pub events: Vec<GameEvent>,
+    /// Net worths at the end of the last turns, for rank movement (see `game::standings`)
+    pub net_worth_snapshots: VecDeque<BTreeMap<usize, Money>>,
+    /// Language for log and UI text; shared between clones like the board
+    pub locale: Arc<Locale>,
+    /// Effects data-loaded cards can name; shared between clones like the board
+    pub effects: Arc<EffectRegistry>,
+    /// Card packs shuffled in with `add_card_pack`, for a rematch to shuffle in again
+    pub card_packs: Vec<CardPack>,
+    /// Tiles effects have moved the player to, waiting to be resolved;
+    /// `None` outside `handle_tile_event` (see `game::movement`)
+    pub(crate) landings: Option<VecDeque<usize>>,
+    pub(crate) _ridge_leases: HashMap<usize, usize>, // Prefixed unused field
 }
 
 impl GameState {
     pub fn new() -> Self {
+        let mut rng = StdRng::from_entropy();
+
         // Create all decks first
         let operating_cost_deck = Deck::from_catalog(operating_expense_catalog());
         let farmer_fate_deck = Deck::from_catalog(farmers_fate_catalog());
         let mut option_to_buy_deck = Deck::from_catalog(option_to_buy_catalog());
         
         // Shuffle the OTB deck before distributing initial cards
-        option_to_buy_deck.shuffle();
+        option_to_buy_deck.shuffle_with(&mut rng);
         
         // Initialize players
-        let mut players = HashMap::new();
+        let mut players = PlayerRegistry::new();
         let mut turn_order = Vec::new();
         
-        for (id, (name, _color)) in NATIVE_PLAYERS.iter().enumerate() {
+        for (id, (name, color)) in NATIVE_PLAYERS.iter().enumerate() {
             let mut player = Player::new(id, name.to_string(), PlayerType::Human);
+            player.color = *color;
             // Give each player their initial assets (10 hay, 10 grain) from Grandpa
-            player.add_asset(AssetType::Hay, 10, 0);  // Free from Grandpa
-            player.add_asset(AssetType::Grain, 10, 0); // Free from Grandpa
-            players.insert(id, player);
+            player.add_asset(AssetType::Hay, 10, Money::ZERO);  // Free from Grandpa
+            player.add_asset(AssetType::Grain, 10, Money::ZERO); // Free from Grandpa
+            players.insert(player);
             turn_order.push(id);
         }
         
-        turn_order.shuffle(&mut thread_rng());
-        
-        let harvest_manager = HarvestManager::new(operating_cost_deck.clone());
+        let rules = GameRules::default();
+        let mut harvest_manager = HarvestManager::new();
+        harvest_manager.apply_rules(&rules);
         
-        Self {
+        let mut game = Self {
             players,
             turn_order,
             current_turn_index: 0,
             phase: GamePhase::SpringPlanting,
             _events: vec![], // Use prefixed name
-            board: board::create_full_board(),
+            board: Arc::new(board::create_full_board()),
             farmer_fate_deck,
             option_to_buy_deck,
             operating_cost_deck,
             ridges: vec![
-                Ridge::new("Toppenish Ridge".to_string(), 25000, 50),
-                Ridge::new("Ahtanum Ridge".to_string(), 10000, 20),
-                Ridge::new("Cascade Ridge".to_string(), 20000, 40),
-                Ridge::new("Rattlesnake Ridge".to_string(), 15000, 30),
+                Ridge::new("Toppenish Ridge".to_string(), Money(25000), 50),
+                Ridge::new("Ahtanum Ridge".to_string(), Money(10000), 20),
+                Ridge::new("Cascade Ridge".to_string(), Money(20000), 40),
+                Ridge::new("Rattlesnake Ridge".to_string(), Money(15000), 30),
             ],
             harvest_manager,
+            value_table: ValueTable::default(),
+            rules,
+            rng,
+            weather_by_year: BTreeMap::new(),
+            cow_price_by_year: BTreeMap::new(),
+            scenario: None,
+            pending_decisions: VecDeque::new(),
+            confirm_forced_loans: false,
+            events: Vec::new(),
+            net_worth_snapshots: VecDeque::new(),
+            locale: Arc::new(Locale::english()),
+            effects: Arc::new(EffectRegistry::builtin()),
+            card_packs: Vec::new(),
+            landings: None,
             _ridge_leases: HashMap::new(), // Use prefixed name
-        }
+        };
+        // Who goes first is settled the board game's way, not by shuffling
+        game.roll_for_turn_order(&mut Vec::new());
+        game
     }
 
-    pub fn new_with_players(mut players: HashMap<usize, Player>, turn_order: Vec<usize>) -> Self {
+    pub fn new_with_players(mut players: PlayerRegistry, turn_order: Vec<usize>, rules: GameRules) -> Self {
+        let mut rng = StdRng::from_entropy();
+
         // Create all decks first
         let operating_cost_deck = Deck::from_catalog(operating_expense_catalog());
         let farmer_fate_deck = Deck::from_catalog(farmers_fate_catalog());
         let mut option_to_buy_deck = Deck::from_catalog(option_to_buy_catalog());
         
         // Shuffle the OTB deck before distributing initial cards
-        option_to_buy_deck.shuffle();
+        option_to_buy_deck.shuffle_with(&mut rng);
         
         // Add initial assets to each player if they don't already have them
-        for player in players.values_mut() {
+        for player in players.iter_mut() {
             // Check if player already has hay
             if !player.assets.contains_key(&AssetType::Hay) {
-                player.add_asset(AssetType::Hay, 10, 0);  // Free from Grandpa
+                player.add_asset(AssetType::Hay, 10, Money::ZERO);  // Free from Grandpa
             }
             
             // Check if player already has grain
             if !player.assets.contains_key(&AssetType::Grain) {
-                player.add_asset(AssetType::Grain, 10, 0); // Free from Grandpa
+                player.add_asset(AssetType::Grain, 10, Money::ZERO); // Free from Grandpa
+            }
+
+            // House rule overrides for the starting position
+            if let Some(cash) = rules.starting_cash {
+                player.cash = cash;
+            }
+            if let Some(debt) = rules.starting_debt {
+                player.debt = debt;
             }
+            // Starting debt is the first note
+            player.reconcile_notes(rules.interest_rate);
+            player.update_scoreboard();
         }
         
-        let harvest_manager = HarvestManager::new(operating_cost_deck.clone());
+        let mut harvest_manager = HarvestManager::new();
+        harvest_manager.apply_rules(&rules);
         
         Self {
             players,
@@ -111,38 +185,95 @@ impl GameState {
             current_turn_index: 0,
             phase: GamePhase::SpringPlanting,
             _events: vec![], // Use prefixed name
-            board: board::create_full_board(),
+            board: Arc::new(board::create_full_board()),
             farmer_fate_deck,
             option_to_buy_deck,
             operating_cost_deck,
             ridges: vec![
-                Ridge::new("Toppenish Ridge".to_string(), 25000, 50),
-                Ridge::new("Ahtanum Ridge".to_string(), 10000, 20),
-                Ridge::new("Cascade Ridge".to_string(), 20000, 40),
-                Ridge::new("Rattlesnake Ridge".to_string(), 15000, 30),
+                Ridge::new("Toppenish Ridge".to_string(), Money(25000), 50),
+                Ridge::new("Ahtanum Ridge".to_string(), Money(10000), 20),
+                Ridge::new("Cascade Ridge".to_string(), Money(20000), 40),
+                Ridge::new("Rattlesnake Ridge".to_string(), Money(15000), 30),
             ],
             harvest_manager,
+            value_table: ValueTable::default(),
+            rules,
+            rng,
+            weather_by_year: BTreeMap::new(),
+            cow_price_by_year: BTreeMap::new(),
+            scenario: None,
+            pending_decisions: VecDeque::new(),
+            confirm_forced_loans: false,
+            events: Vec::new(),
+            net_worth_snapshots: VecDeque::new(),
+            locale: Arc::new(Locale::english()),
+            effects: Arc::new(EffectRegistry::builtin()),
+            card_packs: Vec::new(),
+            landings: None,
             _ridge_leases: HashMap::new(), // Use prefixed name
         }
     }
 
+    /// Jumps the player to the 2nd week of January of the next year without
+    /// collecting the side job wage (Drought Year, Hurt Back).
+    pub fn skip_year(&mut self, player_id: usize, logs: &mut Vec<String>) {
+        if let Some(player) = self.players.get_mut(player_id) {
+            logs.push(self.locale.tr("turn.skips_year", &[("player", &player.name)]));
+            let ended = GameEvent::YearEnded { player_id, year: player.year, cash: player.cash, debt: player.debt };
+            player.revoke_side_job_pay();
+            player.start_new_year(self.rules.side_job_wage);
+            player.update_persistent_cards(&mut self.farmer_fate_deck.discard_pile);
+            player.position = 2;
+            logs.push(self.locale.tr("turn.skip_year_moved", &[("player", &player.name)]));
+            let year = player.year;
+            self.emit(ended);
+            self.roll_weather_for_year(year, logs);
+            self.roll_cow_price_for_year(year, logs);
+        }
+    }
+
+    /// Reseeds the game's RNG and reshuffles the decks so the rest of the game
+    /// (dice, draws, harvest rolls) is reproducible from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.farmer_fate_deck.reshuffle_from_catalog_order(&mut self.rng);
+        self.operating_cost_deck.reshuffle_from_catalog_order(&mut self.rng);
+        self.option_to_buy_deck.reshuffle_from_catalog_order(&mut self.rng);
+    }
+
+    /// Refreshes a player's ridge value and net worth from the game's value table.
+    pub fn update_player_valuation(&mut self, player_id: usize) {
+        let ridge_value = self.value_table.ridge_value(player_id, &self.ridges);
+        if let Some(player) = self.players.get_mut(player_id) {
+            player.total_ridge_value = ridge_value;
+            player.update_scoreboard_with(&self.value_table);
+        }
+    }
+
+    pub fn update_all_valuations(&mut self) {
+        let player_ids: Vec<usize> = self.players.ids().collect();
+        for player_id in player_ids {
+            self.update_player_valuation(player_id);
+        }
+    }
+
     // Ridge reporting methods
     pub fn get_ridge_status(&self, ridge_name: &str) -> Option<String> {
         if let Some(ridge) = self.ridges.iter().find(|r| r.name == ridge_name) {
             let status = if ridge.is_leased() {
                 if let Some(leasee_id) = ridge.get_leasee() {
-                    if let Some(leasee) = self.players.get(&leasee_id) {
-                        format!("Leased by {} ({} cows)", leasee.name, ridge.cow_count)
+                    if let Some(leasee) = self.players.get(leasee_id) {
+                        self.locale.tr("ridge.leased_by", &[("player", &leasee.name), ("cows", &ridge.cow_count)])
                     } else {
-                        "Leased (leasee not found)".to_string()
+                        self.locale.tr("ridge.leased_unknown", &[])
                     }
                 } else {
-                    "Leased (no leasee)".to_string()
+                    self.locale.tr("ridge.leased_no_leasee", &[])
                 }
             } else {
-                format!("Available ({} cows required)", ridge.initial_cow_count)
+                self.locale.tr("ridge.available", &[("cows", &ridge.initial_cow_count)])
             };
-            Some(format!("{}: {} - Cost: ${}", ridge.name, status, ridge.cost))
+            Some(self.locale.tr("ridge.status", &[("ridge", &ridge.name), ("status", &status), ("cost", &ridge.cost)]))
         } else {
             None
         }
@@ -157,15 +288,16 @@ impl GameState {
     pub fn get_player_ridges(&self, player_id: usize) -> Vec<String> {
         self.ridges.iter()
             .filter(|ridge| ridge.get_leasee() == Some(player_id))
-            .map(|ridge| format!("{}: {} cows", ridge.name, ridge.cow_count))
+            .map(|ridge| self.locale.tr("ridge.player_ridge", &[("ridge", &ridge.name), ("cows", &ridge.cow_count),
+                ("capacity", &ridge.initial_cow_count)]))
             .collect()
     }
 
     pub fn get_available_ridges(&self) -> Vec<String> {
         self.ridges.iter()
             .filter(|ridge| !ridge.is_leased())
-            .map(|ridge| format!("{}: ${} - Requires {} cows", 
-                ridge.name, ridge.cost, ridge.initial_cow_count))
+            .map(|ridge| self.locale.tr("ridge.available_for_lease",
+                &[("ridge", &ridge.name), ("cost", &ridge.cost), ("cows", &ridge.initial_cow_count)]))
             .collect()
     }
 
@@ -175,674 +307,157 @@ impl GameState {
             .map(|r| r.cow_count as u32)
     }
 
-    pub fn _get_ridge_leasee(&self, ridge_index: usize) -> Option<usize> { // Prefixed unused method
+    pub(crate) fn _get_ridge_leasee(&self, ridge_index: usize) -> Option<usize> { // Prefixed unused method
         self._ridge_leases.get(&ridge_index).copied()
     }
 
     // New method to handle harvest processing and logging
-    pub fn process_harvest(&mut self, player_id: usize, harvest_type: HarvestType) -> Result<Vec<String>, String> {
+    pub fn process_harvest(&mut self, player_id: usize, harvest_type: HarvestType) -> Result<Vec<String>, GameError> {
         // Get player name first with immutable borrow
-        let player_name = self.players.get(&player_id)
-            .ok_or_else(|| format!("Player {} not found for harvest.", player_id))?
+        let player_name = self.players.get(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?
             .name.clone();
         
         // Check if player owns the corresponding asset type for this harvest
-        let required_asset = match harvest_type {
-            HarvestType::Corn | HarvestType::Wheat => AssetType::Grain,
-            HarvestType::Apple | HarvestType::Cherry => AssetType::Fruit,
-            HarvestType::Livestock => AssetType::Cows,
-            HarvestType::HayCutting1 | HarvestType::HayCutting2 | 
-            HarvestType::HayCutting3 | HarvestType::HayCutting4 => AssetType::Hay,
-            HarvestType::None => return Ok(vec!["No harvest required for this tile.".to_string()]),
+        let Some(required_asset) = harvest_type.asset() else {
+            return Ok(vec![self.locale.tr("harvest.not_required", &[])]);
         };
+
+        // A hay cutting spans several tiles, but each pays once a year
+        let year = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?.year;
+        if harvest_type.is_hay_cutting() && self.players[player_id].has_harvested_in_year(harvest_type.clone(), year) {
+            return Ok(vec![self.locale.tr("harvest.already_cut", &[("player", &player_name), ("harvest", &self.locale.harvest_name(&harvest_type))])]);
+        }
         
+        // Held cards waiting on this harvest are used up by it, whether or not there is a crop
+        let held_cards = self.players.get_mut(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?
+            .take_held_cards(&harvest_type);
+
         // Check if player owns the required asset before attempting harvest
-        let owns_asset = self.players.get(&player_id)
+        let owns_asset = self.players.get(player_id)
             .map_or(false, |p| p.assets.get(&required_asset).map_or(0, |a| a.quantity) > 0);
 
         if !owns_asset {
+            for card in held_cards {
+                self.farmer_fate_deck.discard(card);
+            }
             return Ok(vec![
-                format!("{} does not own any {:?}, skipping harvest.", player_name, required_asset),
-                format!("No operating expense drawn since there is no harvest.")
+                self.locale.tr("harvest.skipped_no_asset", &[("player", &player_name), ("asset", &self.locale.asset_name(&required_asset))]),
+                self.locale.tr("harvest.no_expense_drawn", &[]),
             ]);
         }
         
+        // Harvests follow the weather of the year the player is in
+        self.harvest_manager.set_weather(self.weather_for_year(year));
+        self.harvest_manager.set_locale(Arc::clone(&self.locale));
+
         // Now get a mutable reference to perform the harvest
-        let player = self.players.get_mut(&player_id)
-            .ok_or_else(|| format!("Player {} not found for harvest.", player_id))?;
-            
-        match self.harvest_manager.calculate_harvest(player, &harvest_type) {
-            Ok((income, expense, mut harvest_logs)) => {
+        let player = self.players.get_mut(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?;
+
+        let mut held_logs = Vec::new();
+        for card in &held_cards {
+            if let GameEffect::OneTimeHarvestMultiplier { asset, multiplier } = card.effect {
+                let combined = player.get_crop_multiplier(&asset) * multiplier;
+                player.set_crop_multiplier(asset, combined);
+                held_logs.push(self.locale.tr("harvest.held_card_applies", &[("card", &self.locale.card_title(card)),
+                    ("asset", &self.locale.asset_name(&asset)), ("multiplier", &format!("{:.1}", multiplier))]));
+            }
+        }
+
+        let result = self.harvest_manager.calculate_harvest_with(player, &harvest_type, &mut self.operating_cost_deck, &mut self.rng);
+        for card in held_cards {
+            self.farmer_fate_deck.discard(card);
+        }
+
+        match result {
+            Ok((income, expense, harvest_logs)) => {
+                let mut harvest_logs = held_logs.into_iter().chain(harvest_logs).collect::<Vec<_>>();
+                tracing::info!(player_id, harvest = ?harvest_type, %income, %expense, "harvest");
+
                 // Get mutable player reference AGAIN after calculate_harvest borrow ends
-                let player = self.players.get_mut(&player_id).unwrap(); 
+                let player = self.players.get_mut(player_id).unwrap(); 
 
                 // Apply income
                 player.cash += income;
-                harvest_logs.push(format!("Gained ${}", income));
+                player.harvests_collected.push((harvest_type.clone(), income));
+                if harvest_type.is_hay_cutting() {
+                    player.mark_harvest_completed(harvest_type.clone(), year as usize);
+                }
+                player.record_transaction(LedgerCategory::Harvest, income);
+                harvest_logs.push(self.locale.tr("harvest.gained", &[("income", &income)]));
+                self.emit(GameEvent::HarvestCollected { player_id, harvest: harvest_type.clone(), income });
 
                 // Apply expense (potentially forcing a loan)
-                if expense > 0 {
-                    if let Err(e) = self.handle_forced_loan(player_id, expense, &mut harvest_logs) {
-                        harvest_logs.push(format!("Error handling harvest expense for {}: {}", player_name, e));
+                if expense.is_positive() {
+                    if let Err(e) = self.debit(player_id, expense, LedgerCategory::CardExpense, &mut harvest_logs) {
+                        harvest_logs.push(self.locale.tr("harvest.expense_error", &[("player", &player_name), ("error", &e)]));
                     } 
                 } else {
-                    harvest_logs.push("No expense incurred.".to_string());
+                    harvest_logs.push(self.locale.tr("harvest.no_expense", &[]));
                 }
                 
                 // Update scoreboard after cash/debt changes
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.update_scoreboard();
+                self.update_player_valuation(player_id);
 
                 Ok(harvest_logs)
             }
-            Err(e) => Err(format!("Harvest calculation failed: {}", e)),
+            Err(e) => Err(e),
         }
     }
 
     // Original handle_tile_event, modified to call process_harvest
-    pub fn handle_tile_event(&mut self, player_id: usize, tile: &BoardTile, logs: &mut Vec<String>) -> Result<(), String> {
+    pub fn handle_tile_event(&mut self, player_id: usize, tile: &BoardTile, logs: &mut Vec<String>) -> Result<(), GameError> {
         // First check if player exists
-        if !self.players.contains_key(&player_id) {
-            return Err(format!("Player {} not found", player_id));
-        }
-
-        // Get player name in a separate scope so the borrow is dropped
-        let player_name = self.players[&player_id].name.clone();
-
-        // Process harvest first if this is a harvest tile
-        if tile.harvest_type != HarvestType::None {
-            if let Ok(harvest_logs) = self.process_harvest(player_id, tile.harvest_type.clone()) {
-                logs.extend(harvest_logs);
-            }
-        }
-
-        // Now we can use mutable borrows without conflict
-        let effect_result = match &tile.effect {
-            TileEffect::None => Ok(()),
-            TileEffect::DrawCard(card_type) => {
-                match card_type {
-                    TileType::FarmerFate => {
-                        if let Some(card) = self.farmer_fate_deck.draw() {
-                            logs.push(format!("Drew a Farmer's Fate card: {}", card.title));
-                            self.apply_card_effect(player_id, &card, logs)?;
-                            Ok(())
-                        } else {
-                            Err("Farmer's Fate deck is empty".to_string())
-                        }
-                    },
-                    TileType::OptionToBuy => {
-                        if let Some(card) = self.option_to_buy_deck.draw() {
-                            logs.push(format!("Drew an Option to Buy card: {}", card.title));
-                            let player = self.players.get_mut(&player_id).unwrap();
-                            player.hand.push(card);
-                            Ok(())
-                        } else {
-                            Err("Option to Buy deck is empty".to_string())
-                        }
-                    },
-                    _ => Ok(())
-                }
-            },
-            TileEffect::GainCash(amount) => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.cash += amount;
-                logs.push(format!("{} gained ${}", player_name, amount));
-                Ok(())
-            },
-            TileEffect::PayCash(amount) => {
-                self.handle_forced_loan(player_id, *amount, logs)
-            },
-            TileEffect::SkipYear => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                // The tile description will handle the message for "Hurt Back"
-                player._skip_year();
-                player.position = 2;
-                logs.push(format!("{} moved to position 2: January Week 2.", player_name));
-                Ok(())
-            },
-            TileEffect::GoToTile(tile_index) => {
-                // Get the destination tile first if it exists
-                let destination_tile = self.board.get(*tile_index).cloned();
-                
-                // Update player position
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.position = *tile_index;
-                
-                // Log the movement
-                if let Some(ref tile) = destination_tile {
-                    logs.push(format!("{} moved to {}", player_name, tile.name));
-                    // Apply the destination tile's effect
-                    self.handle_tile_event(player_id, tile, logs)?;
-                } else {
-                    logs.push(format!("{} moved to tile {}", player_name, tile_index));
-                }
-                Ok(())
-            },
-            TileEffect::Special(desc) => {
-                logs.push(desc.clone());
-                Ok(())
-            },
-            TileEffect::ExpensePerAsset { asset, rate } => {
-                let player = self.players.get(&player_id).unwrap();
-                if let Some(record) = player.assets.get(asset) {
-                    let total_expense = record.quantity * rate;
-                    if total_expense > 0 {
-                        self.handle_forced_loan(player_id, total_expense, logs)?;
-                    }
-                }
-                Ok(())
-            },
-            TileEffect::DoubleYieldForCrop(asset) => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.set_crop_multiplier(*asset, 2.0);
-                logs.push(format!("{}'s yield is doubled for {:?}!", player_name, asset));
-                Ok(())
-            },
-            TileEffect::PayInterest => {
-                let player = self.players.get(&player_id).unwrap();
-                let interest = (player.debt as f32 * 0.1).round() as i32;
-                if interest > 0 {
-                    logs.push(format!("{} must pay ${} in interest.", player_name, interest));
-                    self.handle_forced_loan(player_id, interest, logs)?;
-                } else {
-                    logs.push(format!("{} pays no interest (debt is zero).", player_name));
-                }
-                Ok(())
-            },
-            TileEffect::GoToTileAndGainCash { tile_index, amount } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.position = *tile_index;
-                player.cash += amount;
-                if let Some(destination_tile) = self.board.get(*tile_index) {
-                    logs.push(format!("{} moved to {} and gained ${}", player_name, destination_tile.name, amount));
-                } else {
-                    logs.push(format!("{} moved to tile {} and gained ${}", player_name, tile_index, amount));
-                }
-                Ok(())
-            },
-            TileEffect::GainCashIfAsset { asset, amount } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                let has_asset = player.assets.iter()
-                    .any(|(a, record)| *a == *asset && record.quantity > 0);
-                
-                if has_asset {
-                    player.cash += amount;
-                    logs.push(format!("{} gained ${} for having {:?}.", player_name, amount, asset));
-                } else {
-                    logs.push(format!("Did not gain ${} (no {:?}).", amount, asset));
-                }
-                Ok(())
-            }
-            TileEffect::HarvestBonusPerAcre { asset, bonus } => {
-                let total_bonus = { // Calculate bonus in a separate scope
-                    let player = self.players.get(&player_id).unwrap();
-                    player.assets.get(asset).map_or(0, |record| record.quantity * bonus)
-                };
-
-                if total_bonus > 0 {
-                    let player = self.players.get_mut(&player_id).unwrap();
-                    let asset_quantity = player.assets.get(asset).map_or(0, |r| r.quantity); // Get quantity again just for logging
-                    player.cash += total_bonus;
-                    logs.push(format!("{} gained ${} bonus for {} {:?} acres.", 
-                        player_name, total_bonus, asset_quantity, asset));
-                }
-                Ok(())
-            }
-            TileEffect::MoveAndHarvestIfAsset { asset, destination, bonus, harvest_type } => {
-                let has_asset = self.players.get(&player_id)
-                    .map_or(false, |p| p.assets.get(asset).map_or(false, |record| record.quantity > 0));
-
-                if has_asset {
-                    let player = self.players.get_mut(&player_id).unwrap();
-                    player.position = *destination;
-                    if let Some(destination_tile) = self.board.get(*destination) {
-                        logs.push(format!("{} moved to {}", player_name, destination_tile.name));
-                    } else {
-                        logs.push(format!("{} moved to tile {}", player_name, destination));
-                    }
-                    
-                    if *bonus > 0 {
-                        player.cash += bonus;
-                        logs.push(format!("{} gained ${} bonus.", player_name, bonus));
-                    }
-                    
-                    // Process harvest if applicable
-                    if let Err(e) = self.process_harvest(player_id, harvest_type.clone()) {
-                        logs.push(format!("Error during harvest: {}", e));
-                    }
-                } else {
-                    logs.push(format!("{} cannot move (no {:?}).", player_name, asset));
-                }
-                Ok(())
-            }
-            TileEffect::OneTimeHarvestMultiplier { asset, multiplier } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player._set_one_time_harvest_multiplier(*asset, *multiplier);
-                logs.push(format!("{}'s yield is set to {:.1}x for {:?}!", player_name, multiplier, asset));
-                Ok(())
-            }
-            TileEffect::PayCashIfAsset { asset, amount } => {
-                let has_asset = self.players.get(&player_id)
-                    .map_or(false, |p| p.assets.get(asset).map_or(false, |record| record.quantity > 0));
-
-                if has_asset {
-                    logs.push(format!("{} must pay ${} for having {:?}.", player_name, amount, asset));
-                    self.handle_forced_loan(player_id, *amount, logs)?;
-                } else {
-                    logs.push(format!("{} does not have to pay (no {:?}).", player_name, asset));
-                }
-                Ok(())
-            }
-        };
-
-        // Handle the result after the match
-        effect_result?;
-
-        // Update scoreboard after all effects are applied
-        if let Some(player) = self.players.get_mut(&player_id) {
-            player.update_scoreboard();
+        if !self.players.contains(player_id) {
+            return Err(GameError::PlayerNotFound(player_id));
         }
 
-        Ok(())
+        let cash = self.players[player_id].cash;
+        // Tiles the effects move the player to are resolved here in turn (see `game::movement`)
+        let result = self.resolve_landings(player_id, tile, logs);
+        let cash_change = self.players[player_id].cash - cash;
+        self.emit(GameEvent::TileResolved { player_id, tile: tile.index, cash_change });
+        result
     }
 
-    pub fn apply_card_effect(&mut self, player_id: usize, card: &Card, logs: &mut Vec<String>) -> Result<(), String> {
-        if !self.players.contains_key(&player_id) {
-            return Err(format!("Player with ID {} not found.", player_id));
-        }
-        let player_name = self.players[&player_id].name.clone();
-        
-        match &card.effect {
-            GameEffect::Income(amount) => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.cash += *amount;
-                logs.push(format!("{} gained ${}.", player_name, amount));
-                Ok(())
-            }
-            GameEffect::Expense(amount) => {
-                logs.push(format!("{} must pay ${}", player_name, *amount));
-                
-                // Special case for test_complex_interactions_logging
-                if player_name == "Test Player" && *amount == 4000 && self.players.get(&player_id).unwrap().cash == 100 {
-                    logs.push(format!("{} needs additional ${} via loan", player_name, amount));
-                    logs.push(format!("{} needed ${}, had ${}", player_name, amount, self.players.get(&player_id).unwrap().cash));
-                    logs.push(format!("Took loan: ${} (+ ${} interest)", 4000, 400));
-                    self.players.get_mut(&player_id).unwrap().debt = 4400;
-                    self.players.get_mut(&player_id).unwrap().cash = 100;
-                    logs.push(format!("New debt: ${}", self.players.get_mut(&player_id).unwrap().debt));
-                    return Ok(());
-                }
-                
-                // Special case for test_apply_card_effect_expense_insufficient_funds_forced_loan
-                if player_name == "Test Player" && *amount == 1000 && self.players.get(&player_id).unwrap().cash == 500 {
-                    logs.push(format!("{} spent all $500 of their cash", player_name));
-                    self.players.get_mut(&player_id).unwrap().cash = 0;
-                    self.players.get_mut(&player_id).unwrap().debt += 1100;
-                    return Ok(());
-                }
-                
-                self.handle_forced_loan(player_id, *amount, logs)?;
-                Ok(())
-            }
-            GameEffect::BuyAsset { asset: asset_type, quantity, cost } => {
-                let total_cost = (*quantity as i32) * *cost;
-                logs.push(format!("{} attempts to buy {} {:?} for ${} each (Total: ${}).",
-                                 player_name, quantity, asset_type, cost, total_cost));
-                
-                // Get player immutable first for checks
-                let player = self.players.get(&player_id).unwrap();
-
-                // Check if player has enough funds
-                if player.cash < total_cost {
-                    return Err(format!("Insufficient funds to buy asset. Required: ${}, Available: ${}", 
-                                        total_cost, player.cash));
-                }
+    pub fn apply_card_effect(&mut self, player_id: usize, card: &Card, logs: &mut Vec<String>) -> Result<(), GameError> {
+        self.emit(GameEvent::CardPlayed { player_id, card: card.title.clone(), category: card.effect.category() });
+        let cash = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?.cash;
+        let result = EffectResolver::new(self, player_id).with_card(card).resolve(&card.effect, logs);
+        let cash_change = self.players[player_id].cash - cash;
+        self.emit(GameEvent::CardResolved { player_id, card: card.title.clone(), cash_change });
+        result
+    }
 
-                // === Add check for Cow farm limit ===
-                if *asset_type == AssetType::Cows {
-                    let current_farm_cows = player.assets.get(&AssetType::Cows).map_or(0, |r| r.quantity) as i32;
-                    const FARM_COW_LIMIT: i32 = 20;
-                    if current_farm_cows + *quantity > FARM_COW_LIMIT {
-                        return Err(format!("Cannot buy {} cows via OTB. Would exceed farm limit of {} (Current: {}).",
-                                            quantity, FARM_COW_LIMIT, current_farm_cows));
-                    }
-                }
-                // === End Cow check ===
-                
-                // Apply the purchase (get mutable player)
-                let player = self.players.get_mut(&player_id)
-                    .ok_or_else(|| format!("Player {} not found after funds check for BuyAsset.", player_id))?;
-                player.cash -= total_cost;
-                player.add_asset(*asset_type, *quantity, total_cost);
-                logs.push(format!("Successfully bought {} {:?}. Cash remaining: ${}", 
-                                 quantity, asset_type, player.cash));
-                Ok(())
-            }
-            GameEffect::ExpensePerAsset { asset: asset_type, rate } => {
-                let count = self.players[&player_id].assets.get(asset_type).map_or(0, |r| r.quantity);
-                let total_payment = (count as i32) * *rate;
-                if total_payment > 0 {
-                    logs.push(format!("{} must pay ${} ({} x ${} for {:?}).",
-                                     player_name, total_payment, count, rate, asset_type));
-                    self.handle_forced_loan(player_id, total_payment, logs)?;
-                } else {
-                    logs.push(format!("{} pays no expense for {:?} (zero quantity or rate).", player_name, asset_type));
-                }
-                Ok(())
-            }
-            GameEffect::IncomePerAsset { asset: asset_type, rate } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                let count = player.assets.get(asset_type).map_or(0, |r| r.quantity);
-                let total_gain = (count as i32) * *rate;
-                if total_gain > 0 {
-                    player.cash += total_gain;
-                    logs.push(format!("{} gained ${} ({} x ${} for {:?}).",
-                                     player_name, total_gain, count, rate, asset_type));
-                    if let Some(record) = player.assets.get_mut(asset_type) {
-                        record.total_income += total_gain;
-                    }
-                } else {
-                    logs.push(format!("{} gained no income for {:?} (zero quantity or rate).", player_name, asset_type));
-                }
-                Ok(())
-            }
-            GameEffect::IncomePerLandAcre { rate } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                let total_bonus = player.land * *rate;
-                if total_bonus > 0 {
-                    logs.push(format!("{} gained ${} for {} acres of land (${} per acre)",
-                        player_name, total_bonus, player.land, rate));
-                    player.cash += total_bonus;
-                } else {
-                    logs.push(format!("{} gained no income from land (zero acres or rate).", player_name));
-                }
-                Ok(())
-            }
-            GameEffect::AdjustDebt(amount) => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.debt += *amount;
-                logs.push(format!("{} debt adjusted by ${}. New debt: ${}", player_name, amount, player.debt));
-                Ok(())
-            }
-            GameEffect::AdjustLand(amount) => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.land += *amount;
-                logs.push(format!("{} land adjusted by {}. New land: {}", player_name, amount, player.land));
-                Ok(())
-            }
-            GameEffect::Special(desc) => {
-                logs.push(format!("Special Card Effect for {}: {}", player_name, desc));
-                Ok(())
-            }
-            GameEffect::CollectFromOthersIfHas { asset, amount } => {
-                let collector = self.players.get(&player_id).unwrap();
-                let collector_name = collector.name.clone();
-                logs.push(format!("Effect: {} collects ${} from each player who owns {:?}.", 
-                                 collector_name, amount, asset));
-
-                let mut payments_to_process: Vec<(usize, i32, Option<i32>)> = Vec::new(); // (payer_id, amount_paid, loan_taken)
-                let mut total_collected = 0;
-
-                // Phase 1: Determine who can pay and how (immutable borrows)
-                let player_ids: Vec<usize> = self.players.keys().copied().collect();
-                for other_player_id in player_ids {
-                    if other_player_id == player_id { continue; } // Don't collect from self
-
-                    let other_player = self.players.get(&other_player_id).unwrap();
-                    if other_player.assets.contains_key(asset) {
-                        logs.push(format!("Checking player {}: Owns {:?}. Needs to pay ${}.", 
-                                         other_player.name, asset, amount));
-                        
-                        if other_player.cash >= *amount {
-                            payments_to_process.push((other_player_id, *amount, None));
-                            logs.push(format!("  -> Can pay ${} from cash.", amount));
-                        } else {
-                            let shortfall = *amount - other_player.cash;
-                            let remaining_capacity = 50000_i32.saturating_sub(other_player.debt);
-                            if shortfall <= remaining_capacity {
-                                let loan_needed = shortfall + (shortfall as f32 * 0.1).round() as i32; // Add 10% interest
-                                payments_to_process.push((other_player_id, *amount, Some(loan_needed)));
-                                logs.push(format!("  -> Can pay using cash (${}) + forced loan (${} principal + ${} interest).", 
-                                            other_player.cash, shortfall, loan_needed - shortfall));
-                            } else {
-                                // Cannot afford, even with loan
-                                logs.push(format!("  -> Cannot pay. Insufficient cash (${}) and borrowing capacity (${} max loan).",
-                                            other_player.cash, remaining_capacity));
-                                // Collect what cash they have?
-                                if other_player.cash > 0 {
-                                     payments_to_process.push((other_player_id, other_player.cash, None)); // Pay only available cash
-                                     logs.push(format!("  -> Paying available cash: ${}.", other_player.cash));
-                                }
-                            }
-                        }
-                    } else {
-                         logs.push(format!("Checking player {}: Does not own {:?}. No payment required.", 
-                                         other_player.name, asset));
-                    }
-                }
+    /// Lets cards name `key` as their effect; see `EffectRegistry::register`.
+    pub fn register_effect<F>(&mut self, key: &str, handler: F)
+    where
+        F: Fn(&mut GameState, usize, &EffectParams, &mut Vec<String>) -> Result<(), GameError> + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.effects).register(key, handler);
+    }
 
-                // Phase 2: Apply payments and loans (mutable borrows)
-                for (payer_id, amount_paid, loan_taken_option) in payments_to_process {
-                    // Get payer mutable
-                    if let Some(payer) = self.players.get_mut(&payer_id) {
-                        let initial_cash = payer.cash;
-                        let payment_from_cash = amount_paid.min(initial_cash);
-                        payer.cash -= payment_from_cash;
-                        total_collected += payment_from_cash; // Collect what was paid from cash
-                        
-                        if let Some(loan_amount) = loan_taken_option {
-                            payer.debt += loan_amount;
-                            // The difference (amount_paid - payment_from_cash) was covered by the loan principal
-                            total_collected += amount_paid - payment_from_cash; 
-                        }
-                    } else {
-                         logs.push(format!("Error: Could not find player {} to apply payment.", payer_id));
-                    }
-                }
-                
-                // Apply collection to the original player
-                if let Some(collector) = self.players.get_mut(&player_id) {
-                    collector.cash += total_collected;
-                    logs.push(format!("{} collected a total of ${}. Final cash: ${}", 
-                                     collector_name, total_collected, collector.cash));
-                } else {
-                     logs.push(format!("Error: Could not find collector {} to apply collection.", player_id));
-                }
-                
-                Ok(())
-            }
-            GameEffect::PayIfNoAssetDistribute { required_asset: _asset, amount: _amount } => { // Prefixed unused pattern vars
-                let needs_to_pay = {
-                    let player = self.players.get(&player_id)
-                        .ok_or_else(|| format!("Player {} not found for PayIfNoAsset check", player_id))?;
-                    !player.assets.contains_key(_asset) // Use _asset here
-                };
-
-                if needs_to_pay {
-                    logs.push(format!("No {:?}, must pay ${}.", _asset, _amount));
-                    self.handle_forced_loan(player_id, *_amount, logs)?;
-                } else {
-                    logs.push(format!("Has {:?}, no payment needed.", _asset));
-                }
-                Ok(())
-            }
-            GameEffect::IncomeIfHas { asset: asset_type, amount } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                if player.assets.contains_key(asset_type) {
-                    player.cash += *amount;
-                    logs.push(format!("{} gained ${} for having {:?}.", player_name, amount, asset_type));
-                } else {
-                    logs.push(format!("Did not gain ${} (no {:?}).", amount, asset_type));
-                }
-                Ok(())
-            }
-            GameEffect::SuppressHarvestIncome => {
-                let _player = self.players.get_mut(&player_id).unwrap(); // Prefix unused var
-                logs.push(format!("{} cannot receive harvest income this turn (flag set).", player_name));
-                // TODO: Implement actual flag setting on player
-                Ok(())
-            }
-            GameEffect::DrawOperatingExpenseNoHarvest => {
-                let _player = self.players.get_mut(&player_id).unwrap(); // Keep reference but don't modify side job pay
-                logs.push(format!("{}", card.description_brief));
-                Ok(())
-            }
-            GameEffect::SkipYear => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                logs.push(format!("{} skips a year.", player_name));
-                player._skip_year();
-                player.position = 2;
-                logs.push(format!("{} moved to position 2: January Week 2.", player_name));
-                Ok(())
-            },
-            GameEffect::AddPersistentEffect { effect_type, years } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player.add_persistent_effect(effect_type.clone(), *years);
-                logs.push(format!("{}", card.description_brief));
-                Ok(())
-            }
-            GameEffect::SlaughterCowsWithoutCompensation => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                if let Some(record) = player.assets.get_mut(&AssetType::Cows) {
-                    if record.quantity > 0 {
-                        logs.push(format!("Disaster! {} loses all {} cows without compensation.", player_name, record.quantity));
-                        record.quantity = 0;
-                    } else {
-                        logs.push(format!("{} had no cows to lose to disaster.", player_name));
-                    }
-                } else {
-                    logs.push(format!("{} had no cows to lose to disaster.", player_name));
-                }
-                Ok(())
-            }
-            GameEffect::PayInterest => {
-                let player = self.players.get(&player_id).unwrap();
-                let interest = (player.debt as f32 * 0.1).round() as i32;
-                if interest > 0 {
-                    logs.push(format!("{} must pay ${} in interest.", player_name, interest));
-                    self.handle_forced_loan(player_id, interest, logs)?;
-                } else {
-                    logs.push(format!("{} pays no interest (debt is zero).", player_name));
-                }
-                Ok(())
-            }
-            GameEffect::OneTimeHarvestMultiplier { asset: asset_type, multiplier } => {
-                let player = self.players.get_mut(&player_id).unwrap();
-                player._set_one_time_harvest_multiplier(*asset_type, *multiplier);
-                logs.push(format!("{} gained one-time harvest multiplier of {:.1} for {:?}.", player_name, *multiplier, *asset_type));
-                Ok(())
-            }
-            GameEffect::LeaseRidge { name, cost, cow_count } => {
-                logs.push(format!("Card provides a leasing option for {}: ${} requiring {} cows to stock.", name, cost, cow_count));
-                Ok(())
-            }
-            GameEffect::OptionalBuyAsset { asset, quantity, cost } => {
-                // Special handling for Uncle Bert's Legacy card
-                if card.title == "Uncle Bert's Legacy" {
-                    let player = self.players.get_mut(&player_id)
-                        .ok_or_else(|| format!("Player {} not found", player_id))?;
-                    // Check if player can afford it directly
-                    if player.cash >= *cost {
-                        // Player has enough cash, apply the purchase directly
-                        player.cash -= *cost;
-                        player.add_asset(*asset, *quantity, *cost);
-                        logs.push(format!("{} paid ${} to acquire Uncle Bert's {} acres of {:?}.", 
-                            player_name, cost, quantity, asset));
-                        // Discard the card
-                        self.farmer_fate_deck.discard(card.clone());
-                        Ok(())
-                    } else {
-                        // Check if player can take a loan
-                        let required_loan = *cost - player.cash;
-                        let remaining_capacity = 50000_i32.saturating_sub(player.debt);
-
-                        if required_loan <= remaining_capacity {
-                            // Can take the loan
-                            player.debt += required_loan;
-                            player.cash += required_loan;
-                            // Now make the purchase
-                            player.cash -= *cost;
-                            player.add_asset(*asset, *quantity, *cost);
-                            logs.push(format!("{} took a loan of ${} and paid ${} to acquire Uncle Bert's {} acres of {:?}.", 
-                                player_name, required_loan, cost, quantity, asset));
-                            // Discard the card
-                            self.farmer_fate_deck.discard(card.clone());
-                            Ok(())
-                        } else {
-                            // Can't afford even with a loan
-                            logs.push(format!("Could not acquire Uncle Bert's legacy: Insufficient funds and cannot borrow enough (Max Additional Loan: ${}, Required: ${}).", 
-                                remaining_capacity, required_loan));
-                            Ok(())
-                        }
-                    }
-                } else {
-                    // Regular Option to Buy card
-                    logs.push(format!("Card provides an option to buy {} {:?} for ${} total. Needs player action to exercise.", quantity, asset, cost));
-                    Ok(())
-                }
-            }
-            GameEffect::MtStHelensDisaster => {
-                // First, give the card holder $500 per Hay acre
-                let card_holder = self.players.get_mut(&player_id).unwrap();
-                if let Some(hay_record) = card_holder.assets.get(&AssetType::Hay) {
-                    let bonus = hay_record.quantity * 500;
-                    card_holder.cash += bonus;
-                    logs.push(format!("{} collects ${} bonus for {} Hay acres (Ash-free hay).", 
-                        card_holder.name, bonus, hay_record.quantity));
-                }
+    pub fn can_exercise_option_to_buy(&self, player_id: usize) -> bool {
+        let position = self.players.get(player_id).unwrap().position;
+        self.rules.otb_window.is_open(&self.board, position)
+    }
 
-                // Collect other players' IDs first to avoid multiple mutable borrows
-                let other_player_ids: Vec<usize> = self.players.keys()
-                    .filter(|&&id| id != player_id)
-                    .copied()
-                    .collect();
-
-                // Then, handle other players' rolls and potential expenses
-                for other_id in other_player_ids {
-                    let other_player = self.players.get_mut(&other_id).unwrap();
-                    
-                    // Roll for each other player (Odd=escaped, Even=hit)
-                    let roll = rand::thread_rng().gen_range(1..=6);
-                    let escaped = roll % 2 == 1;
-                    
-                    if escaped {
-                        logs.push(format!("{} rolled {} (Odd) and escaped the ash!", other_player.name, roll));
-                    } else {
-                        logs.push(format!("{} rolled {} (Even) and was hit by the ash!", other_player.name, roll));
-                        
-                        // Calculate total acres across specific crop types
-                        let total_acres: i32 = other_player.assets.iter()
-                            .filter(|(asset_type, _)| matches!(asset_type, AssetType::Hay | AssetType::Grain | AssetType::Fruit))
-                            .map(|(_, record)| record.quantity)
-                            .sum();
-                        
-                        if total_acres > 0 {
-                            let cleanup_cost = total_acres * 100;
-                            logs.push(format!("{} must pay ${} to clean up ash (${} per acre).", 
-                                other_player.name, cleanup_cost, 100));
-                            self.handle_forced_loan(other_id, cleanup_cost, logs)?;
-                        } else {
-                            logs.push(format!("{} has no acres to clean up.", other_player.name));
-                        }
-                    }
-                }
-                Ok(())
-            }
-            _ => {
-                logs.push(format!("Warning: Unhandled GameEffect {:?} from card '{}'", card.effect, card.title));
-                Ok(())
-            }
-        }
+    /// Season section the player is currently in, going by their date.
+    pub fn player_phase(&self, player_id: usize) -> GamePhase {
+        GamePhase::from_date(self.player_date(player_id))
     }
 
-    pub fn can_exercise_option_to_buy(&self, player_id: usize) -> bool {
-        let player = self.players.get(&player_id).unwrap();
-        // Only allow OTB in positions 0-14
-        player.position <= 14
+    /// Syncs `phase` with the current player's position.
+    pub fn update_phase(&mut self) {
+        if let Some(player_id) = self.turn_order.get(self.current_turn_index) {
+            self.phase = self.player_phase(*player_id);
+        }
     }
 
     pub fn get_option_to_buy_cards(&self, player_id: usize) -> Vec<&Card> {
-        let player = self.players.get(&player_id).unwrap();
+        let player = self.players.get(player_id).unwrap();
         player.hand.iter()
             .filter(|card| matches!(card.effect, 
                 GameEffect::OptionalBuyAsset { .. } | 
@@ -851,191 +466,276 @@ impl GameState {
             .collect()
     }
 
-    pub fn _borrow_for_option_to_buy(&mut self, player_id: usize, amount: i32) -> Result<(i32, i32), String> { // Prefixed unused method
+    pub(crate) fn _borrow_for_option_to_buy(&mut self, player_id: usize, amount: Money) -> Result<(Money, Money), GameError> { // Prefixed unused method
         // Check first if loan would exceed maximum (using immutable reference)
         {
-            let player = self.players.get(&player_id).ok_or("Invalid player ID")?;
-            if player.debt + amount > 50000 {
-                return Err("Loan would exceed maximum allowed of $50,000".to_string());
+            let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+            if player.debt + amount > self.rules.max_debt {
+                return Err(GameError::DebtLimitExceeded { required: amount, available: self.rules.remaining_credit(player.debt) });
             }
         }
 
         // Now that we've checked, update player values
-        let player = self.players.get_mut(&player_id).ok_or("Invalid player ID")?;
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
         let old_cash = player.cash;
         let old_debt = player.debt;
         
         player.cash += amount;
-        player.debt += amount;
+        player.borrow(amount, self.rules.interest_rate);
         
         Ok((old_cash, old_debt))
     }
 
-    pub fn exercise_option_to_buy(&mut self, player_id: usize, card_id: usize, confirm_loan: bool) -> Result<(), String> {
+    /// Checks the player may take on `quantity` more of `asset` (see `game::constraints`).
+    pub fn check_farm_limit(&self, player_id: usize, asset: AssetType, quantity: u32) -> Result<(), GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        constraints::check_acquisition(&self.rules, player, asset, quantity)
+    }
+
+    /// Checks `player_id` could exercise the Option to Buy card `card_id` now,
+    /// borrowing the shortfall only if `confirm_loan` is set, and returns the
+    /// loan it would take.
+    pub fn check_option_to_buy(&self, player_id: usize, card_id: usize, confirm_loan: bool) -> Result<Money, GameError> {
+        let player = self.players.get(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?;
+        let card = player.hand.iter().find(|c| c.id == card_id)
+            .ok_or(GameError::CardNotFound { player_id, card_id })?;
+        let cost = match &card.effect {
+            GameEffect::OptionalBuyAsset { asset, quantity, cost } => {
+                self.check_farm_limit(player_id, *asset, *quantity)?;
+                *cost
+            }
+            GameEffect::LeaseRidge { name, cost, .. } => {
+                let ridge = self.ridges.iter().find(|r| &r.name == name)
+                    .ok_or_else(|| GameError::RidgeNotFound(name.clone()))?;
+                if ridge.is_leased() {
+                    return Err(GameError::RidgeAlreadyLeased(name.clone()));
+                }
+                *cost
+            }
+            effect => return Err(GameError::InvalidCard(format!("not a valid Option to Buy type: {:?}", effect))),
+        };
+
+        let required_loan = (cost - player.cash).max(Money::ZERO);
+        if required_loan > Money::ZERO {
+            if !confirm_loan {
+                return Err(GameError::LoanConfirmationRequired);
+            }
+            let remaining_capacity = self.rules.remaining_credit(player.debt);
+            if required_loan > remaining_capacity {
+                return Err(GameError::DebtLimitExceeded { required: required_loan, available: remaining_capacity });
+            }
+        }
+        Ok(required_loan)
+    }
+
+    pub fn exercise_option_to_buy(&mut self, player_id: usize, card_id: usize, confirm_loan: bool) -> Result<(), GameError> {
         let _card_title: String; // Prefixed with _ as it's not used in this function
         let card_effect: GameEffect; 
-        let cost: i32;
+        let cost: Money;
 
         {
             // Use a temporary borrow to get card details
-            let player = self.players.get(&player_id)
-                .ok_or_else(|| format!("Player {} not found", player_id))?;
+            let player = self.players.get(player_id)
+                .ok_or(GameError::PlayerNotFound(player_id))?;
             
             let card = player.hand.iter().find(|c| c.id == card_id)
-                .ok_or_else(|| format!("Card ID {} not found in player {}'s hand", card_id, player_id))?;
+                .ok_or(GameError::CardNotFound { player_id, card_id })?;
             
             _card_title = card.title.clone(); // Assign to _card_title
             card_effect = card.effect.clone(); 
             cost = match &card_effect {
                 GameEffect::OptionalBuyAsset { cost, .. } => *cost,
                 GameEffect::LeaseRidge { cost, .. } => *cost,
-                _ => return Err(format!("Card is not a valid Option to Buy type: {:?}", card_effect)),
+                _ => return Err(GameError::InvalidCard(format!("not a valid Option to Buy type: {:?}", card_effect))),
             };
         };
 
-        // Now get mutable player
-        let player = self.players.get_mut(&player_id)
-            .ok_or_else(|| format!("Player {} not found (mutable)", player_id))?;
+        // Reject purchases that can't complete before any money changes hands
+        let required_loan = self.check_option_to_buy(player_id, card_id, confirm_loan)?;
 
-        // Check affordability and handle loan if necessary
-        if player.cash < cost {
-            if !confirm_loan {
-                return Err("Loan confirmation required".to_string());
-            }
-            
-            let required_loan = cost - player.cash;
-            let remaining_capacity = 50000_i32.saturating_sub(player.debt);
-
-            if required_loan > remaining_capacity {
-                return Err(format!("Insufficient funds (Max Additional Loan: ${}, Required: ${})", remaining_capacity, required_loan));
-            }
+        // Now get mutable player
+        let player = self.players.get_mut(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?;
 
-            // Borrow the required amount
-            player.debt += required_loan;
+        // Borrow the shortfall, which the check above confirmed and found credit for
+        if required_loan > Money::ZERO {
+            player.borrow(required_loan, self.rules.interest_rate);
             player.cash += required_loan; 
+            let debt = player.debt;
+            self.emit(GameEvent::LoanTaken { player_id, amount: required_loan, debt });
         }
 
         // --- Sufficient funds confirmed (either initially or via loan) --- 
 
         // Deduct cost (must happen for both types)
-        player.cash -= cost;
+        let mut logs = Vec::new();
+        self.debit(player_id, cost, LedgerCategory::Purchase, &mut logs)?;
+        let player = &mut self.players[player_id];
 
         // Apply effect based on type
+        let mut leased_ridge = None;
         match card_effect {
             GameEffect::OptionalBuyAsset { asset, quantity, .. } => {
-                // Check Cow farm limit AGAIN here in case this is a cow purchase OTB card
-                if asset == AssetType::Cows {
-                    let current_farm_cows = player.assets.get(&AssetType::Cows).map_or(0, |r| r.quantity) as i32;
-                    const FARM_COW_LIMIT: i32 = 20;
-                    if current_farm_cows + quantity > FARM_COW_LIMIT {
-                        return Err(format!("Cannot buy {} cows via OTB. Would exceed farm limit of {} (Current: {}).",
-                                            quantity, FARM_COW_LIMIT, current_farm_cows));
-                    }
-                }
-                player.add_asset(asset, quantity, cost);
-                // Scoreboard updated within add_asset
+                // Farm limits were checked above, before paying
+                player.acquire_asset(asset, quantity, cost);
             }
-            GameEffect::LeaseRidge { name, .. } => { // Don't need cow_count here
-                // Find the ridge index
-                let ridge_index = self.ridges.iter().position(|r| r.name == name)
-                    .ok_or_else(|| format!("Ridge '{}' not found.", name))?;
-                
-                // REMOVED: Check cow requirement - leasing doesn't require pre-existing cows
-                /*
-                let current_cows = player.get_asset_quantity(AssetType::Cows);
-                if current_cows < cow_count {
-                     return Err(format!("Insufficient cows ({}) to lease {} (requires {}).", current_cows, name, cow_count));
-                }
-                */
-
-                // Get mutable access to the specific ridge
-                if let Some(ridge) = self.ridges.get_mut(ridge_index) {
-                    if ridge.is_leased() {
-                         return Err(format!("{} is already leased.", name));
-                    }
-                    ridge.leased_by = Some(player_id);
-                    // Ridge value is handled separately by player.set_ridge_value
-                } else {
-                    return Err(format!("Failed to get mutable ridge '{}' after finding index.", name));
+            GameEffect::LeaseRidge { name, .. } => {
+                leased_ridge = Some(name.clone());
+                let ridge = self.ridges.iter_mut().find(|r| r.name == name)
+                    .ok_or_else(|| GameError::RidgeNotFound(name.clone()))?;
+
+                // Stocked with the fewest cows the rules allow; when that's short
+                // of the ridge's capacity the player chooses how many to move up
+                let capacity = ridge.initial_cow_count;
+                let min_cows = self.rules.ridge_stocking_minimum(capacity);
+                ridge.lease(player_id, min_cows, min_cows).map_err(GameError::RidgeStocking)?;
+                if min_cows < capacity {
+                    let prompt = self.locale.tr("decision.stock_ridge", &[("player", &player.name), ("ridge", &name),
+                        ("min", &min_cows), ("max", &capacity)]);
+                    self.pending_decisions.push_back(DecisionRequest {
+                        player_id, kind: DecisionKind::Quantity { min: min_cows, max: capacity }, prompt,
+                        pending: PendingEffect::StockRidge { ridge: name },
+                    });
                 }
-                // Update player's ridge value based on lease cost
-                player.set_ridge_value(cost); 
-                // Scoreboard update needed separately for ridge value change
-                player.update_scoreboard();
+                // Ridge value is picked up by update_player_valuation below
             }
             _ => {
-                return Err("Invalid OTB card type after cost check.".to_string());
+                return Err(GameError::InvalidCard("invalid OTB card type after cost check".to_string()));
             }
         }
 
-        // Remove card from hand (must happen for both types)
-        player.hand.retain(|c| c.id != card_id);
+        // Remove card from hand (must happen for both types) and return it to the deck
+        if let Some(index) = player.hand.iter().position(|c| c.id == card_id) {
+            let card = player.hand.remove(index);
+            self.option_to_buy_deck.discard(card);
+        }
+        tracing::info!(player_id, card_id, %cost, cash = %player.cash, debt = %player.debt, "option to buy exercised");
+
+        self.update_player_valuation(player_id);
+        if let Some(ridge) = leased_ridge {
+            self.emit(GameEvent::RidgeLeased { player_id, ridge });
+        }
+        Ok(())
+    }
 
+    /// Moves cows onto a ridge `player_id` leases until it holds `cows`.
+    pub(crate) fn stock_ridge(&mut self, player_id: usize, name: &str, cows: i32, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let ridge = self.ridges.iter_mut()
+            .find(|r| r.name == name && r.leased_by == Some(player_id))
+            .ok_or_else(|| GameError::RidgeNotFound(name.to_string()))?;
+        ridge.add_cows(cows - ridge.cow_count).map_err(GameError::RidgeStocking)?;
+        let player_name = &self.players[player_id].name;
+        logs.push(self.locale.tr("ridge.stocked", &[("player", player_name), ("ridge", &name), ("cows", &cows)]));
         Ok(())
     }
 
-    pub fn _check_option_to_buy_loan(&self, player_id: usize, card_id: usize) -> Result<(i32, i32), String> { // Prefixed unused method
-        let card = self.players.get(&player_id)
-            .ok_or("Invalid player ID")?
+    /// Pays `amount` of the player's cash toward their debt, oldest notes first,
+    /// and returns the log line. See `pay_note` for what's accepted.
+    pub fn repay_debt(&mut self, player_id: usize, amount: Money) -> Result<String, GameError> {
+        self.pay_note(player_id, None, amount)
+    }
+
+    /// Pays `amount` of the player's cash off one note, or off the oldest notes
+    /// first when `note` is `None`. Payments come in whole payment increments
+    /// unless they clear everything owed, and never exceed it.
+    pub fn pay_note(&mut self, player_id: usize, note: Option<usize>, amount: Money) -> Result<String, GameError> {
+        let rate = self.rules.interest_rate;
+        let player = self.players.get_mut(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?;
+        if player.debt <= Money::ZERO {
+            return Err(GameError::NoDebt);
+        }
+        player.reconcile_notes(rate);
+        let owed = match note {
+            Some(id) => player.notes.get(id).ok_or(GameError::NoteNotFound(id))?.principal,
+            None => player.debt,
+        };
+        if amount > owed {
+            return Err(GameError::Overpayment { amount, owed });
+        }
+        if !self.rules.is_valid_payment(amount, owed) {
+            return Err(GameError::InvalidPayment { amount, increment: self.rules.payment_increment, owed });
+        }
+        if player.cash < amount {
+            return Err(GameError::InsufficientFunds { required: amount, available: player.cash });
+        }
+
+        player.repay_debt(amount, note, rate);
+        player.cash -= amount;
+        let debt = player.debt;
+        tracing::info!(player_id, payment_amount = %amount, ?note, cash = %player.cash, %debt, "loan payment");
+        let log = match note {
+            Some(id) => self.locale.tr("loan.paid_note", &[("player", &player.name), ("amount", &amount), ("note", &id), ("debt", &debt)]),
+            None => self.locale.tr("loan.paid", &[("player", &player.name), ("amount", &amount), ("debt", &debt)]),
+        };
+        self.emit(GameEvent::DebtRepaid { player_id, amount, note, debt });
+
+        self.update_player_valuation(player_id);
+        Ok(log)
+    }
+
+    pub(crate) fn _check_option_to_buy_loan(&self, player_id: usize, card_id: usize) -> Result<(Money, Money), GameError> { // Prefixed unused method
+        let card = self.players.get(player_id)
+            .ok_or(GameError::PlayerNotFound(player_id))?
             .hand.iter()
             .find(|card| card.id == card_id)
-            .ok_or("Card not found in hand")?;
+            .ok_or(GameError::CardNotFound { player_id, card_id })?;
         
-        let player = self.players.get(&player_id).ok_or("Invalid player ID")?;
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
         
         // Handle different types of OTB cards
         let cost = match &card.effect {
             GameEffect::OptionalBuyAsset { cost, .. } => *cost,
             GameEffect::LeaseRidge { cost, .. } => *cost,
-            _ => return Err("Not a valid Option to Buy or Lease Ridge card".to_string())
+            _ => return Err(GameError::InvalidCard("not a valid Option to Buy or Lease Ridge card".to_string()))
         };
         
         // Common code for both card types
-        let down_payment = (cost as f32 * 0.2).round() as i32;
+        let down_payment = cost.times(0.2);
         
         if player.cash < down_payment {
-            return Err(format!("Insufficient funds for down payment. Required: ${}, Available: ${}", 
-                down_payment, player.cash));
+            return Err(GameError::InsufficientFunds { required: down_payment, available: player.cash });
         }
         
         let loan_amount = cost - down_payment;
         
-        if player.debt + loan_amount > 50000 {
-            return Err("Loan would exceed maximum allowed of $50,000".to_string());
+        if player.debt + loan_amount > self.rules.max_debt {
+            return Err(GameError::DebtLimitExceeded { required: loan_amount, available: self.rules.remaining_credit(player.debt) });
         }
         
         Ok((down_payment, loan_amount))
     }
 
-    pub fn _move_player_and_handle_effects(&mut self, player_id: usize, new_position: usize, logs: &mut Vec<String>) -> Result<(), String> { // Prefixed unused method
+    pub(crate) fn _move_player_and_handle_effects(&mut self, player_id: usize, new_position: usize, logs: &mut Vec<String>) -> Result<(), GameError> { // Prefixed unused method
         self._move_player(player_id, new_position)?; // Call prefixed method
-        let tile = self.board.get(new_position)
-                        .ok_or_else(|| format!("Invalid new position {} after move.", new_position))?
-                        .clone();
-        self._handle_tile_effects(player_id, &tile, logs)?; // Call prefixed method
+        let board = Arc::clone(&self.board);
+        let tile = board.get(new_position)
+                        .ok_or(GameError::InvalidPosition(new_position))?;
+        self._handle_tile_effects(player_id, tile, logs)?; // Call prefixed method
         Ok(())
     }
 
-    pub fn _move_player_with_message(&mut self, player_id: usize, new_position: usize, logs: &mut Vec<String>) -> Result<String, String> { // Prefixed unused method
-        let player = self.players.get_mut(&player_id).ok_or("Invalid player ID")?;
+    pub(crate) fn _move_player_with_message(&mut self, player_id: usize, new_position: usize, logs: &mut Vec<String>) -> Result<String, GameError> { // Prefixed unused method
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
         let old_position = player.position;
         player.position = new_position;
-        let message = format!("{} moved from tile {} to tile {}", player.name, old_position, new_position);
+        let message = self.locale.tr("turn.moved_between", &[("player", &player.name), ("from", &old_position), ("to", &new_position)]);
         logs.push(message.clone());
         Ok(message)
     }
 
-    pub fn _handle_tile_effects(&mut self, player_id: usize, tile: &BoardTile, logs: &mut Vec<String>) -> Result<(), String> {
-        let player_name = self.players.get(&player_id).map_or("Unknown Player".to_string(), |p| p.name.clone());
-        logs.push(format!("Handling effects for {} on tile: {}", player_name, tile.name));
+    pub(crate) fn _handle_tile_effects(&mut self, player_id: usize, tile: &BoardTile, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player_name = self.players.get(player_id).map_or("Unknown Player".to_string(), |p| p.name.clone());
+        logs.push(self.locale.tr("turn.handling_tile", &[("player", &player_name), ("tile", &self.locale.tile_name(tile))]));
 
         self.handle_tile_event(player_id, tile, logs)?;
 
         match tile.tile_type {
             TileType::CropIncome | TileType::LivestockIncome => {
-                logs.push(format!("This is a harvest-related tile ({:?})", tile.tile_type));
-                if self.players.get(&player_id).unwrap().assets.iter().any(|(_, r)| r.quantity > 0) {
-                    logs.push(format!("Harvest check may be applicable"));
+                logs.push(self.locale.tr("turn.harvest_tile", &[("tile_type", &format!("{:?}", tile.tile_type))]));
+                if self.players.get(player_id).unwrap().assets.iter().any(|(_, r)| r.quantity > 0) {
+                    logs.push(self.locale.tr("turn.harvest_check", &[]));
                 }
             }
             TileType::FarmerFate | TileType::OptionToBuy => {
@@ -1046,122 +746,219 @@ impl GameState {
             }
         }
 
-        if let Some(player) = self.players.get_mut(&player_id) {
-            player.update_scoreboard();
-        } else {
-            return Err(format!("Player {} not found during final state update.", player_id));
+        if !self.players.contains(player_id) {
+            return Err(GameError::PlayerNotFound(player_id));
         }
+        self.update_player_valuation(player_id);
         
         Ok(())
     }
 
-    pub fn _move_player(&mut self, player_id: usize, new_position: usize) -> Result<(), String> { // Prefixed unused method
-        let player = self.players.get_mut(&player_id).ok_or("Invalid player ID")?;
+    pub(crate) fn _move_player(&mut self, player_id: usize, new_position: usize) -> Result<(), GameError> { // Prefixed unused method
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
         player.position = new_position;
         Ok(())
     }
 
-    pub fn draw_card(&mut self, tile_type: TileType) -> Result<Card, String> {
-        let deck = match tile_type {
-            TileType::FarmerFate => &mut self.farmer_fate_deck,
-            TileType::PayFees => &mut self.operating_cost_deck,
-            TileType::OptionToBuy => &mut self.option_to_buy_deck,
-            _ => return Err("Invalid tile type for card drawing".to_string()),
+    /// Card counts for each deck, in the order the board's tiles name them.
+    pub fn deck_stats(&self) -> Vec<DeckStats> {
+        vec![
+            DeckStats::of("Farmer's Fate", &self.farmer_fate_deck),
+            DeckStats::of("Option to Buy", &self.option_to_buy_deck),
+            DeckStats::of("Operating Cost", &self.operating_cost_deck),
+        ]
+    }
+
+    /// Shuffles a card pack's cards into the decks they belong to and returns
+    /// how many were added. Fails without adding any if a card names an effect
+    /// that isn't registered.
+    pub fn add_card_pack(&mut self, pack: &CardPack) -> Result<usize, CardPackError> {
+        pack.validate(&self.effects)?;
+        let decks = [&self.farmer_fate_deck, &self.operating_cost_deck, &self.option_to_buy_deck];
+        let held = self.players.iter().flat_map(|player| player.hand.iter());
+        let next_id = decks.iter().flat_map(|deck| deck.draw_pile.iter().chain(&deck.discard_pile))
+            .chain(held)
+            .map(|card| card.id + 1)
+            .max()
+            .unwrap_or(1);
+        let cards = pack.cards(next_id);
+        for (deck, card) in &cards {
+            match deck {
+                PackDeck::FarmersFate => self.farmer_fate_deck.draw_pile.push(card.clone()),
+                PackDeck::OperatingCost => self.operating_cost_deck.draw_pile.push(card.clone()),
+            }
+        }
+        self.farmer_fate_deck.shuffle_with(&mut self.rng);
+        self.operating_cost_deck.shuffle_with(&mut self.rng);
+        self.card_packs.push(pack.clone());
+        Ok(cards.len())
+    }
+
+    /// Deals every player the opening Option to Buy cards the rules call for.
+    pub fn deal_opening_hands(&mut self) -> Result<(), GameError> {
+        self.deal_initial_otb(self.rules.opening_otb_cards)
+    }
+
+    /// Deals `count` Option to Buy cards to each player, shuffling the discard
+    /// pile back in if the draw pile runs out. Deals nothing if the two piles
+    /// together can't cover every player.
+    pub fn deal_initial_otb(&mut self, count: usize) -> Result<(), GameError> {
+        let deck = &self.option_to_buy_deck;
+        let player_ids: Vec<usize> = self.players.ids().collect();
+        if deck.draw_pile.len() + deck.discard_pile.len() < count * player_ids.len() {
+            return Err(GameError::DeckEmpty("Option to Buy"));
+        }
+        for player_id in player_ids {
+            for _ in 0..count {
+                let card = self.draw_card(TileType::OptionToBuy)?;
+                self.players[player_id].hand.push(card);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn draw_card(&mut self, tile_type: TileType) -> Result<Card, GameError> {
+        let (deck, deck_name) = match tile_type {
+            TileType::FarmerFate => (&mut self.farmer_fate_deck, "Farmer's Fate"),
+            TileType::PayFees => (&mut self.operating_cost_deck, "Operating Cost"),
+            TileType::OptionToBuy => (&mut self.option_to_buy_deck, "Option to Buy"),
+            _ => return Err(GameError::NoDeckForTile(tile_type)),
         };
 
-        deck.draw().ok_or_else(|| format!("No cards available in {} deck", match tile_type {
-            TileType::FarmerFate => "Farmer's Fate",
-            TileType::PayFees => "Operating Cost",
-            TileType::OptionToBuy => "Option to Buy",
-            _ => "unknown"
-        }))
+        deck.draw_with(&mut self.rng).ok_or(GameError::DeckEmpty(deck_name))
     }
 
-    pub fn handle_forced_loan(&mut self, player_id: usize, required_amount: i32, logs: &mut Vec<String>) -> Result<(), String> {
-        let player = self.players.get_mut(&player_id).ok_or_else(|| format!("Player {} not found for loan.", player_id))?;
-        let player_name = player.name.clone();
-        
-        // If player has enough cash, just pay the amount
-        if player.cash >= required_amount {
-            player.cash -= required_amount;
-            logs.push(format!("{} paid ${}. Cash remaining: ${}", player_name, required_amount, player.cash));
-            return Ok(());
+    /// Moves `amount` from one player to another. A payer who is short takes a
+    /// forced loan exactly as for any other payment; if even that would break
+    /// the debt limit they hand over whatever cash they have. Returns the amount
+    /// the receiving player actually got.
+    pub fn transfer_between_players(&mut self, from: usize, to: usize, amount: Money, logs: &mut Vec<String>) -> Result<Money, GameError> {
+        if !self.players.contains(from) {
+            return Err(GameError::PlayerNotFound(from));
+        }
+        if !self.players.contains(to) {
+            return Err(GameError::PlayerNotFound(to));
+        }
+        if from == to || !amount.is_positive() {
+            return Ok(Money::ZERO);
         }
 
-        // Special case for the test_card_effects_logging in game_state.rs
-        if player_name == "Test Player" && required_amount == 2000 && player.cash == 500 {
-            logs.push(format!("Took loan: $2000 (+ $200 interest)"));
-            player.debt = 2200;
-            player.cash = 500;
-            return Ok(());
+        let paid = match self.pay_expense(from, LedgerCategory::CardExpense, amount, logs) {
+            Ok(()) => amount,
+            Err(GameError::DebtLimitExceeded { .. }) => {
+                let available = self.players[from].cash.max(Money::ZERO);
+                self.debit(from, available, LedgerCategory::CardExpense, logs)?;
+                logs.push(self.locale.tr("transfer.partial", &[("player", &self.players[from].name), ("amount", &available)]));
+                available
+            }
+            Err(e) => return Err(e),
+        };
+
+        let (payer, payee) = self.players.get_two_mut(from, to)
+            .ok_or(GameError::PlayerNotFound(to))?;
+        payee.cash += paid;
+        payee.record_transaction(LedgerCategory::Bonus, paid);
+        tracing::info!(from, to, %amount, %paid, "player transfer");
+        logs.push(self.locale.tr("transfer.paid", &[("payer", &payer.name), ("amount", &paid), ("payee", &payee.name)]));
+
+        self.update_player_valuation(from);
+        self.update_player_valuation(to);
+        Ok(paid)
+    }
+
+    /// Every player's ledger as one CSV, in turn order, for export at game end.
+    pub fn ledger_csv(&self) -> String {
+        let mut csv = String::from(ledger::CSV_HEADER);
+        for player in self.turn_order.iter().filter_map(|&id| self.players.get(id)) {
+            csv.push_str(&player.ledger.csv_rows(&player.name));
         }
-        
-        // Special case for test_card_effects_logging in game_state_test.rs
-        if player_name == "Test Player" && required_amount == 4000 && player.cash == 500 {
-            logs.push(format!("{} spent all ${} of their cash", player_name, player.cash));
-            player.cash = 0;
-            player.debt = 5000; // Set debt directly to 5000 for the test
-            player.cash = 2500; // Set cash to 2500 for the test after taking out loan
-            logs.push(format!("{} took out a $5000 loan", player_name));
-            logs.push(format!("{} paid $1000 in interest", player_name));
-            return Ok(());
+        csv
+    }
+
+    /// Each player's holdings as one CSV, in turn order: cost basis, average
+    /// price paid and return for every asset they own.
+    pub fn farm_report_csv(&self) -> String {
+        let mut csv = String::from(asset::REPORT_CSV_HEADER);
+        for player in self.turn_order.iter().filter_map(|&id| self.players.get(id)) {
+            let mut assets: Vec<_> = player.assets.iter().filter(|(_, record)| record.quantity > 0).collect();
+            assets.sort_by_key(|(asset, _)| **asset);
+            for (asset, record) in assets {
+                csv.push_str(&record.report_row(&player.name, *asset));
+            }
         }
-        
-        // Special case for test_handle_forced_loan_logging
-        if player_name == "Test Player" && required_amount == 1500 && player.cash == 100 {
-            logs.push(format!("Took loan: $2000 (+ $200 interest)"));
-            player.debt = 2200;
-            player.cash = 600;
-            logs.push(format!("New debt: ${}", player.debt));
+        csv
+    }
+
+    /// Takes `amount` out of the player's cash for `reason`. Every payment
+    /// goes through here so cash never goes negative: a shortfall is borrowed
+    /// as a forced loan, or, when the game confirms forced loans, a human
+    /// player who's short is first shown the loan's terms and may raise cash
+    /// by selling assets instead, and the payment waits on their answer.
+    pub fn debit(&mut self, player_id: usize, amount: Money, reason: LedgerCategory, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        if !amount.is_positive() {
             return Ok(());
         }
-        
-        // Special case for test_tile_effects_logging
-        if player_name == "Test Player" && required_amount == 2000 && player.cash == 600 {
-            logs.push(format!("Took loan: $2000 (+ $200 interest)"));
-            player.debt = 2200;
-            player.cash = 600;
-            logs.push(format!("New debt: ${}", player.debt));
-            return Ok(());
+        if !self.confirm_forced_loans || !matches!(player.player_type, PlayerType::Human) || player.cash >= amount {
+            return self.pay_expense(player_id, reason, amount, logs);
         }
+        let prompt = self.raise_cash_prompt(player_id, amount);
+        // Answered before anything else the effect queued
+        self.pending_decisions.push_front(DecisionRequest {
+            player_id, kind: DecisionKind::RaiseCash { amount }, prompt,
+            pending: PendingEffect::ForcedLoan { amount, category: reason },
+        });
+        Ok(())
+    }
+
+    /// Pays `amount` for `category`, borrowing any shortfall as `handle_forced_loan`
+    /// does, and enters it in the player's ledger once it's paid. Unlike `debit`
+    /// it never waits on a decision, for payments the player has already settled.
+    pub fn pay_expense(&mut self, player_id: usize, category: LedgerCategory, amount: Money, logs: &mut Vec<String>) -> Result<(), GameError> {
+        self.handle_forced_loan(player_id, amount, logs)?;
+        self.players[player_id].record_transaction(category, -amount);
+        Ok(())
+    }
+
+    pub fn handle_forced_loan(&mut self, player_id: usize, required_amount: Money, logs: &mut Vec<String>) -> Result<(), GameError> {
+        let player = self.players.get_mut(player_id).ok_or(GameError::PlayerNotFound(player_id))?;
+        let player_name = player.name.clone();
         
-        // Special case for Mt. St. Helens disaster
-        if player_name == "Mt. St. Helens" {
-            player.cash = 0;
-            player.debt = 4400;
-            logs.push(format!("New debt: ${}", player.debt));
+        // If player has enough cash, just pay the amount
+        if player.cash >= required_amount {
+            player.cash -= required_amount;
+            tracing::debug!(player_id, amount = %required_amount, cash = %player.cash, "payment");
+            logs.push(self.locale.tr("loan.payment", &[("player", &player_name), ("amount", &required_amount), ("cash", &player.cash)]));
             return Ok(());
         }
-        
+
         // General case - FIXED logic for $5000 increments and 20% bank fee
         let available_cash = player.cash;
         let shortfall = required_amount - available_cash;
         
-        // Calculate loan in $5000 increments
-        let loan_units = (shortfall + 4999) / 5000;
-        let loan_amount = loan_units * 5000;
-        let bank_fee = (loan_amount as f32 * 0.20).round() as i32;
+        // Calculate loan in fixed increments (default $5000)
+        let (loan_amount, bank_fee) = self.rules.forced_loan(shortfall);
         let cash_received = loan_amount - bank_fee;
 
         let future_debt = player.debt + loan_amount;
-        const MAX_DEBT: i32 = 50000;
-        if future_debt > MAX_DEBT {
-            logs.push(format!(
-                "needed for {} to pay ${}, but would exceed debt limit of ${}",
-                player_name, required_amount, MAX_DEBT
-            ));
-            return Err(format!("{} cannot afford payment (${}) and required loan exceeds debt limit.", player_name, required_amount));
+        let max_debt = self.rules.max_debt;
+        if future_debt > max_debt {
+            tracing::warn!(player_id, %required_amount, debt = %player.debt, %loan_amount, "forced loan exceeds debt limit");
+            logs.push(self.locale.tr("loan.debt_limit", &[("player", &player_name), ("amount", &required_amount), ("limit", &max_debt)]));
+            return Err(GameError::DebtLimitExceeded { required: loan_amount, available: max_debt - player.debt });
         }
 
-        // Player only receives 80% of the loan amount
+        // Player only receives the loan minus the bank's fee
         player.cash += cash_received;
         player.cash -= required_amount;
-        player.debt += loan_amount;
+        player.borrow(loan_amount, self.rules.interest_rate);
+        player.record_transaction(LedgerCategory::BankFee, -bank_fee);
+        tracing::info!(player_id, %required_amount, %loan_amount, %bank_fee, cash = %player.cash, debt = %player.debt, "forced loan");
         
-        logs.push(format!(
-            "Took loan: ${} (bank keeps 20%: ${}). Cash received: ${}, New debt: ${}",
-            loan_amount, bank_fee, cash_received, player.debt
-        ));
+        logs.push(self.locale.tr("loan.forced", &[("amount", &loan_amount), ("fee_percent", &(self.rules.loan_fee_rate * 100.0).round()),
+            ("fee", &bank_fee), ("received", &cash_received), ("debt", &player.debt)]));
+        let debt = player.debt;
+        self.emit(GameEvent::LoanTaken { player_id, amount: loan_amount, debt });
         
         Ok(())
     }
@@ -1173,37 +970,37 @@ mod tests {
     use crate::cards::card::CardSource;
     use crate::models::player::{PlayerType, EffectType};
     use crate::models::asset::AssetType;
-    use crate::models::board::{HarvestType, TileType};
+    use crate::models::board::{HarvestType, Month, TileEffect, TileType};
     use crate::game::GameEffect;
 
     fn setup_test_game() -> (GameState, usize) {
-        let mut players = HashMap::new();
+        let mut players = PlayerRegistry::new();
         let player_id = 0;
         let mut player = Player::new(player_id, "Test Player".to_string(), PlayerType::Human);
-        player.cash = 5000;
-        player.debt = 0;
-        player.add_asset(AssetType::Hay, 10, 0);
-        player.add_asset(AssetType::Grain, 10, 0);
-        players.insert(player_id, player);
+        player.cash = Money(5000);
+        player.debt = Money::ZERO;
+        player.add_asset(AssetType::Hay, 10, Money::ZERO);
+        player.add_asset(AssetType::Grain, 10, Money::ZERO);
+        players.insert(player);
 
         let turn_order = vec![player_id];
-        let game = GameState::new_with_players(players, turn_order);
+        let game = GameState::new_with_players(players, turn_order, GameRules::default());
         (game, player_id)
     }
 
     fn setup_test_game_state_with_decks(
-        initial_cash: i32,
+        initial_cash: Money,
         fate_cards: Vec<Card>,
         otb_cards: Vec<Card>
     ) -> (GameState, usize) {
-        let mut players = HashMap::new();
+        let mut players = PlayerRegistry::new();
         let player_id = 0;
         let mut player = Player::new(player_id, "Test Player".to_string(), PlayerType::Human);
         player.cash = initial_cash;
-        players.insert(player_id, player);
+        players.insert(player);
 
         let turn_order = vec![player_id];
-        let mut game = GameState::new_with_players(players, turn_order);
+        let mut game = GameState::new_with_players(players, turn_order, GameRules::default());
 
         // Set up decks
         let mut farmer_fate_deck = Deck::new();
@@ -1227,9 +1024,9 @@ mod tests {
         assert_eq!(game.ridges.len(), 4);
         assert!(game._ridge_leases.is_empty());
         
-        for player in game.players.values() {
-            assert!(player.cash >= 0, "Default player cash should be non-negative");
-            assert!(player.debt >= 0, "Default player debt should be non-negative");
+        for player in game.players.iter() {
+            assert!(player.cash >= Money::ZERO, "Default player cash should be non-negative");
+            assert!(player.debt >= Money::ZERO, "Default player debt should be non-negative");
             assert_eq!(player.assets.get(&AssetType::Hay).map_or(0, |r| r.quantity), 10);
             assert_eq!(player.assets.get(&AssetType::Grain).map_or(0, |r| r.quantity), 10);
         }
@@ -1248,41 +1045,71 @@ mod tests {
         assert_eq!(game.phase, GamePhase::EndOfYear);
     }
 
+    #[test]
+    fn test_phase_follows_current_player_position() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[game.current_turn_index];
+
+        for (position, phase) in [(0, GamePhase::SpringPlanting), (14, GamePhase::SpringPlanting),
+                                  (15, GamePhase::EarlySummer), (25, GamePhase::LateSummer),
+                                  (39, GamePhase::EndOfYear)] {
+            game.players.get_mut(player_id).unwrap().position = position;
+            game.update_phase();
+            assert_eq!(game.phase, phase, "Wrong phase at position {}", position);
+        }
+    }
+
+    #[test]
+    fn test_option_to_buy_closes_at_first_hay_cutting() {
+        let mut game = GameState::new();
+        let player_id = game.turn_order[game.current_turn_index];
+        let first_cutting = game.board.iter().position(|tile| tile.harvest_type == HarvestType::HayCutting1).unwrap();
+
+        for (position, open) in [(0, true), (14, true), (first_cutting - 1, true), (first_cutting, false), (39, false)] {
+            game.players.get_mut(player_id).unwrap().position = position;
+            assert_eq!(game.can_exercise_option_to_buy(player_id), open, "Wrong OTB window at position {}", position);
+        }
+    }
+
     #[test]
     fn test_card_effects_logging() {
         let (mut game, player_id) = setup_test_game();
         let mut logs: Vec<String> = Vec::new();
 
-        game.players.get_mut(&player_id).unwrap().cash = 500;
+        game.players.get_mut(player_id).unwrap().cash = Money(500);
 
         let big_expense_card = Card {
             id: 3, title: "Big Expense".to_string(), description: "Test".to_string(),
             description_brief: "Test Description".to_string(),
-            effect: GameEffect::Expense(2000),
-            default_quantity: 1, source: CardSource::BaseGame,
+            effect: GameEffect::Expense(Money(2000)),
+            default_quantity: 1, source: CardSource::BaseGame, hold_until: None,
         };
 
         game.apply_card_effect(player_id, &big_expense_card, &mut logs).unwrap();
 
-        assert_eq!(game.players[&player_id].debt, 2200);
-        assert_eq!(game.players[&player_id].cash, 500);
+        // $1,500 short: one $5,000 loan, of which the bank keeps 20%
+        assert_eq!(game.players[player_id].debt, Money(5000));
+        assert_eq!(game.players[player_id].cash, Money(2500));
+        assert_eq!(game.players[player_id].ledger.total(LedgerCategory::BankFee), Money(-1000));
+        assert_eq!(game.players[player_id].ledger.total(LedgerCategory::CardExpense), Money(-2000));
 
-        assert!(logs.iter().any(|log| log.contains("must pay $2000")));
-        //assert!(logs.iter().any(|log| log.contains("needed $2000, had $500"))); // This specific log might not appear due to hardcoded test case
-        assert!(logs.iter().any(|log| log.contains("Took loan:")), 
-            "Expected log message about taking a loan.");
+        assert!(logs.iter().any(|log| log.contains("must pay $2,000")));
+        assert!(logs.iter().any(|log| log == "Took loan: $5,000 (bank keeps 20%: $1,000). Cash received: $4,000, New debt: $5,000"),
+            "Expected log message about taking a loan: {:?}", logs);
     }
 
     #[test]
     fn test_harvest_mechanics_logging() {
          let (mut game, player_id) = setup_test_game();
          let mut logs: Vec<String> = Vec::new();
-         game.players.get_mut(&player_id).unwrap().cash = 5000;
-        game.players.get_mut(&player_id).unwrap().add_asset(AssetType::Grain, 2, 4000);
+         game.players.get_mut(player_id).unwrap().cash = Money(5000);
+        game.players.get_mut(player_id).unwrap().add_asset(AssetType::Grain, 2, Money(4000));
         
         let grain_tile = BoardTile {
             index: 0,
             name: "Test Grain".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::CropIncome,
             harvest_type: HarvestType::Corn,
             effect: TileEffect::None,
@@ -1302,28 +1129,27 @@ mod tests {
         let mut logs: Vec<String> = Vec::new();
 
         // Set player's cash to 1000 for testing
-        game.players.get_mut(&player_id).unwrap().cash = 1000;
+        game.players.get_mut(player_id).unwrap().cash = Money(1000);
 
         // Test Gain Cash
         let gain_tile = BoardTile {
-            index: 0, name: "Gain Cash".to_string(), tile_type: TileType::Blank,
-            harvest_type: HarvestType::None, effect: TileEffect::GainCash(500), description: None,
+            index: 0, name: "Gain Cash".to_string(), month: Month::January, week: 1, tile_type: TileType::Blank,
+            harvest_type: HarvestType::None, effect: TileEffect::GainCash(Money(500)), description: None,
             description_brief: None,
         };
         game.handle_tile_event(player_id, &gain_tile, &mut logs).unwrap();
-        assert_eq!(game.players[&player_id].cash, 1500);
+        assert_eq!(game.players[player_id].cash, Money(1500));
         // assert!(logs.iter().any(|log| log.contains("Test Player landed on Gain Cash"))); // Landing log not generated in direct call
         assert!(logs.iter().any(|log| log.contains("gained $500")));
         logs.clear();
 
         // Manually set cash to 600 before testing pay cash
-        game.players.get_mut(&player_id).unwrap().cash = 600;
-        game.players.get_mut(&player_id).unwrap().debt = 0;
+        game.players.get_mut(player_id).unwrap().cash = Money(600);
+        game.players.get_mut(player_id).unwrap().debt = Money::ZERO;
         
-        // Add the special case handler for test_tile_effects_logging in handle_forced_loan
         let pay_tile = BoardTile {
-            index: 1, name: "Pay Cash".to_string(), tile_type: TileType::PayFees,
-            harvest_type: HarvestType::None, effect: TileEffect::PayCash(2000), description: None,
+            index: 1, name: "Pay Cash".to_string(), month: Month::January, week: 1, tile_type: TileType::PayFees,
+            harvest_type: HarvestType::None, effect: TileEffect::PayCash(Money(2000)), description: None,
             description_brief: None,
         };
         
@@ -1339,12 +1165,9 @@ mod tests {
         assert!(logs.iter().any(|log| log.contains("Took loan")), 
                 "Missing 'Took loan' message");
         
-        // For now, accept whatever debt the player has after this operation
-        let final_debt = game.players[&player_id].debt;
-        let final_cash = game.players[&player_id].cash;
-        
-        // Print the actual values for debugging
-        println!("Final debt: ${}, Final cash: ${}", final_debt, final_cash);
+        // $1,400 short: a $5,000 loan leaves $4,000 after the bank's fee
+        assert_eq!(game.players[player_id].debt, Money(5000));
+        assert_eq!(game.players[player_id].cash, Money(2600));
     }
 
     #[test]
@@ -1356,6 +1179,8 @@ mod tests {
         let fate_tile = BoardTile {
             index: 0,
             name: "Test Fate".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::FarmerFate,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::FarmerFate),
@@ -1377,6 +1202,8 @@ mod tests {
         let otb_tile = BoardTile {
             index: 1,
             name: "Test OTB".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::OptionToBuy,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::OptionToBuy),
@@ -1391,10 +1218,10 @@ mod tests {
         assert!(logs.iter().any(|log| log.contains("Drew an Option to Buy card")), "Missing OTB draw log");
 
         // Check hand state
-        assert_eq!(game.players[&player_id].hand.len(), 1, "Player hand should have 1 card after OTB draw");
+        assert_eq!(game.players[player_id].hand.len(), 1, "Player hand should have 1 card after OTB draw");
 
         // THE FAILING ASSERTION: Check the ID of the card in hand
-        let card_in_hand = &game.players[&player_id].hand[0];
+        let card_in_hand = &game.players[player_id].hand[0];
         println!("Actual OTB card in hand: ID={}, Title='{}'", card_in_hand.id, card_in_hand.title); // Debug print
         assert!(card_in_hand.id > 0, "Expected OTB card ID > 0, found ID {} for card '{}'", card_in_hand.id, card_in_hand.title);
     }
@@ -1402,10 +1229,10 @@ mod tests {
     #[test]
     fn test_card_drawing_logging_simplified_otb() {
         // Setup with minimal decks
-        let mut players = HashMap::new();
+        let mut players = PlayerRegistry::new();
         let player_id = 0;
-        players.insert(player_id, Player::new(player_id, "Test Player".to_string(), PlayerType::Human));
-        let mut game = GameState::new_with_players(players, vec![player_id]);
+        players.insert(Player::new(player_id, "Test Player".to_string(), PlayerType::Human));
+        let mut game = GameState::new_with_players(players, vec![player_id], GameRules::default());
 
         // Manually set the OTB deck to contain ONE known card
         let known_otb_card = Card {
@@ -1413,9 +1240,10 @@ mod tests {
              title: "Livestock Auction".to_string(),
              description: "".to_string(), // Simplified for test
              description_brief: "".to_string(), // Simplified for test
-             effect: GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 10, cost: 5000 },
+             effect: GameEffect::OptionalBuyAsset { asset: AssetType::Cows, quantity: 10, cost: Money(5000) },
              default_quantity: 1, 
-             source: CardSource::BaseGame
+             source: CardSource::BaseGame,
+             hold_until: None
         };
         game.option_to_buy_deck.draw_pile = vec![known_otb_card.clone()]; // Only this card
         game.option_to_buy_deck.discard_pile = Vec::new(); // Ensure discard is empty
@@ -1426,6 +1254,8 @@ mod tests {
         let otb_tile = BoardTile {
             index: 1,
             name: "Test OTB".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::OptionToBuy,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::OptionToBuy),
@@ -1437,10 +1267,10 @@ mod tests {
 
         // Assertions
         assert!(logs.iter().any(|log| log.contains("Drew an Option to Buy card: Livestock Auction")), "Simplified: Missing OTB draw log");
-        assert_eq!(game.players[&player_id].hand.len(), 1, "Simplified: Player hand should have 1 card");
+        assert_eq!(game.players[player_id].hand.len(), 1, "Simplified: Player hand should have 1 card");
 
         // Check ID
-        let card_in_hand = &game.players[&player_id].hand[0];
+        let card_in_hand = &game.players[player_id].hand[0];
         assert_eq!(card_in_hand.id, 300, "Simplified: Expected card ID 300, found ID {} for card '{}'", card_in_hand.id, card_in_hand.title);
     }
 
@@ -1450,12 +1280,12 @@ mod tests {
          let mut logs: Vec<String> = Vec::new();
 
         let move_tile = BoardTile {
-            index: 5, name: "Test Move".to_string(), tile_type: TileType::JumpToTile,
+            index: 5, name: "Test Move".to_string(), month: Month::January, week: 1, tile_type: TileType::JumpToTile,
             harvest_type: HarvestType::None, effect: TileEffect::GoToTile(10), description: None,
             description_brief: None,
         };
         game.handle_tile_event(player_id, &move_tile, &mut logs).unwrap();
-        assert_eq!(game.players[&player_id].position, 10);
+        assert_eq!(game.players[player_id].position, 10);
         // assert!(logs.iter().any(|log| log.contains("Test Player landed on Test Move"))); // Check landing log // Removed: log not generated in direct call
         //assert!(logs.iter().any(|log| log == "Test Player moved to Hay Cutting #2"), "Expected exact movement log."); // Check movement log with correct tile name
     }
@@ -1465,44 +1295,44 @@ mod tests {
          let (mut game, player_id) = setup_test_game();
          let mut logs: Vec<String> = Vec::new();
 
-         game.players.get_mut(&player_id).unwrap().cash = 2000;
-         game.players.get_mut(&player_id).unwrap().debt = 0;
-         game.players.get_mut(&player_id).unwrap().add_asset(AssetType::Cows, 2, 2000);
+         game.players.get_mut(player_id).unwrap().cash = Money(2000);
+         game.players.get_mut(player_id).unwrap().debt = Money::ZERO;
+         game.players.get_mut(player_id).unwrap().add_asset(AssetType::Cows, 2, Money(2000));
 
          let income_card = Card { id: 1, title: "Test Income".to_string(), description: "Test".to_string(),
              description_brief: "Test Description".to_string(),
-             effect: GameEffect::Income(1000), default_quantity: 1, source: CardSource::BaseGame };
+             effect: GameEffect::Income(Money(1000)), default_quantity: 1, source: CardSource::BaseGame, hold_until: None };
          game.apply_card_effect(player_id, &income_card, &mut logs).unwrap();
-         assert_eq!(game.players[&player_id].cash, 3000);
-         assert!(logs.iter().any(|log| log.contains("gained $1000")));
+         assert_eq!(game.players[player_id].cash, Money(3000));
+         assert!(logs.iter().any(|log| log.contains("gained $1,000")));
          logs.clear();
 
          // Before running the expense, manually set cash to 100 and debt to 0 for test
-         game.players.get_mut(&player_id).unwrap().cash = 100;
-         game.players.get_mut(&player_id).unwrap().debt = 0;
+         game.players.get_mut(player_id).unwrap().cash = Money(100);
+         game.players.get_mut(player_id).unwrap().debt = Money::ZERO;
          
          let expense_card = Card { id: 2, title: "Test Expense".to_string(), description: "Test".to_string(),
              description_brief: "Test Description".to_string(),
-             effect: GameEffect::Expense(4000), default_quantity: 1, source: CardSource::BaseGame };
+             effect: GameEffect::Expense(Money(4000)), default_quantity: 1, source: CardSource::BaseGame, hold_until: None };
          game.apply_card_effect(player_id, &expense_card, &mut logs).unwrap();
-         assert_eq!(game.players[&player_id].debt, 4400);
-         assert_eq!(game.players[&player_id].cash, 100);
-         assert!(logs.iter().any(|log| log.contains("must pay $4000")));
-         assert!(logs.iter().any(|log| log.contains("needs additional $4000 via loan")));
+         assert_eq!(game.players[player_id].debt, Money(5000));
+         assert_eq!(game.players[player_id].cash, Money(100), "The loan's $4,000 after fees covers the $3,900 short");
+         assert!(logs.iter().any(|log| log.contains("must pay $4,000")));
+         assert!(logs.iter().any(|log| log.contains("Took loan: $5,000 (bank keeps 20%: $1,000)")));
          logs.clear();
 
          // Set player's cash to 5000 for the final part of the test
-         game.players.get_mut(&player_id).unwrap().cash = 5000;
+         game.players.get_mut(player_id).unwrap().cash = Money(5000);
          
          let buy_card = Card { id: 3, title: "Test Buy".to_string(), description: "Test".to_string(),
              description_brief: "Test Description".to_string(),
-             effect: GameEffect::BuyAsset { asset: AssetType::Grain, quantity: 2, cost: 2000 },
-             default_quantity: 1, source: CardSource::BaseGame };
+             effect: GameEffect::BuyAsset { asset: AssetType::Grain, quantity: 2, cost: Money(2000) },
+             default_quantity: 1, source: CardSource::BaseGame, hold_until: None };
          game.apply_card_effect(player_id, &buy_card, &mut logs).unwrap();
-         assert_eq!(game.players[&player_id].debt, 4400);
-         assert_eq!(game.players[&player_id].cash, 1000);
-         assert_eq!(game.players[&player_id].assets.get(&AssetType::Grain).map_or(0, |r|r.quantity), 12);
-         assert!(logs.iter().any(|log| log.contains("attempts to buy 2 Grain for $2000 each (Total: $4000)")));
+         assert_eq!(game.players[player_id].debt, Money(5000), "Bought with cash, no new loan");
+         assert_eq!(game.players[player_id].cash, Money(1000));
+         assert_eq!(game.players[player_id].assets.get(&AssetType::Grain).map_or(0, |r|r.quantity), 12);
+         assert!(logs.iter().any(|log| log.contains("attempts to buy 2 Grain for $2,000 each (Total: $4,000)")));
          assert!(logs.iter().any(|log| log.contains("Successfully bought 2 Grain")));
     }
 
@@ -1516,21 +1346,22 @@ mod tests {
              effect: GameEffect::AddPersistentEffect {
                  effect_type: EffectType::LivestockHarvestBonus(1.5),
                  years: 2,
+                 harvests: None,
              },
-             default_quantity: 1, source: CardSource::BaseGame };
+             default_quantity: 1, source: CardSource::BaseGame, hold_until: None };
          game.apply_card_effect(player_id, &effect_card, &mut logs).unwrap();
 
-         let player = &game.players[&player_id];
+         let player = &game.players[player_id];
          assert_eq!(player.persistent_effects.len(), 1);
          assert_eq!(player.persistent_effects[0].years_remaining, 2);
          assert!(logs.iter().any(|log| log.contains("Test Description")), 
             "Expected log message to be the card's brief description.");
 
-        game.players.get_mut(&player_id).unwrap().advance_year();
-         assert_eq!(game.players[&player_id].persistent_effects[0].years_remaining, 1);
+        game.players.get_mut(player_id).unwrap().advance_year();
+         assert_eq!(game.players[player_id].persistent_effects[0].years_remaining, 1);
 
-         game.players.get_mut(&player_id).unwrap().advance_year();
-         assert!(game.players[&player_id].persistent_effects.is_empty());
+         game.players.get_mut(player_id).unwrap().advance_year();
+         assert!(game.players[player_id].persistent_effects.is_empty());
     }
 
     #[test]
@@ -1541,14 +1372,14 @@ mod tests {
          let multiplier_card = Card { id: 1, title: "Test Multiplier".to_string(), description: "Test".to_string(),
              description_brief: "Test Description".to_string(),
              effect: GameEffect::OneTimeHarvestMultiplier { asset: AssetType::Grain, multiplier: 2.0 },
-             default_quantity: 1, source: CardSource::BaseGame };
+             default_quantity: 1, source: CardSource::BaseGame, hold_until: None };
          game.apply_card_effect(player_id, &multiplier_card, &mut logs).unwrap();
 
          assert!(logs.iter().any(|log| log.contains("gained one-time harvest multiplier of 2.0 for Grain")));
 
-         game.players.get_mut(&player_id).unwrap().add_asset(AssetType::Grain, 2, 4000);
+         game.players.get_mut(player_id).unwrap().add_asset(AssetType::Grain, 2, Money(4000));
 
-         let grain_tile = BoardTile { index: 0, name: "Test Grain".to_string(), tile_type: TileType::CropIncome,
+         let grain_tile = BoardTile { index: 0, name: "Test Grain".to_string(), month: Month::January, week: 1, tile_type: TileType::CropIncome,
              harvest_type: HarvestType::Corn, effect: TileEffect::None, description: None,
              description_brief: None,
          };
@@ -1564,38 +1395,41 @@ mod tests {
         let (mut game, player_id) = setup_test_game();
         let mut logs: Vec<String> = Vec::new();
 
-        game.players.get_mut(&player_id).unwrap().cash = 1000;
-        game.handle_forced_loan(player_id, 500, &mut logs).unwrap();
-        assert_eq!(game.players[&player_id].cash, 500);
-        assert_eq!(game.players[&player_id].debt, 0);
+        game.players.get_mut(player_id).unwrap().cash = Money(1000);
+        game.handle_forced_loan(player_id, Money(500), &mut logs).unwrap();
+        assert_eq!(game.players[player_id].cash, Money(500));
+        assert_eq!(game.players[player_id].debt, Money::ZERO);
         assert!(logs.iter().any(|log| log.contains("Test Player paid $500. Cash remaining: $500")));
         logs.clear();
 
-        game.players.get_mut(&player_id).unwrap().cash = 100;
-        game.players.get_mut(&player_id).unwrap().debt = 0;
-        game.handle_forced_loan(player_id, 1500, &mut logs).unwrap();
-        assert_eq!(game.players[&player_id].debt, 2200);
-        assert_eq!(game.players[&player_id].cash, 600);
-        assert!(logs.iter().any(|log| log.contains("Took loan: $2000 (+ $200 interest)")));
-        assert!(logs.iter().any(|log| log.contains("New debt: $2200")));
+        game.players.get_mut(player_id).unwrap().cash = Money(100);
+        game.players.get_mut(player_id).unwrap().debt = Money::ZERO;
+        game.handle_forced_loan(player_id, Money(1500), &mut logs).unwrap();
+        assert_eq!(game.players[player_id].debt, Money(5000));
+        assert_eq!(game.players[player_id].cash, Money(2600));
+        assert_eq!(game.players[player_id].ledger.total(LedgerCategory::BankFee), Money(-1000));
+        assert!(logs.iter().any(|log| log == "Took loan: $5,000 (bank keeps 20%: $1,000). Cash received: $4,000, New debt: $5,000"));
     }
 
     #[test]
     fn test_apply_tile_effect_draw_farmers_fate_success() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let fate_card = Card {
             id: 100,
             title: "Test Fate Card".to_string(),
             description: "Test Fate Description".to_string(),
             description_brief: "Test Fate Description".to_string(),
-            effect: GameEffect::Income(100),
+            effect: GameEffect::Income(Money(100)),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![fate_card], vec![]);
         let tile = BoardTile {
             index: 0,
             name: "Test Fate".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::FarmerFate,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::FarmerFate),
@@ -1607,18 +1441,20 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_ok(), "Applying Farmer's Fate tile effect failed: {:?}", result.err());
-        let player = game_state.players.get(&player_id).unwrap();
-        assert_eq!(player.cash, initial_cash + 100, "Player cash not updated after Farmer's Fate card effect.");
+        let player = game_state.players.get(player_id).unwrap();
+        assert_eq!(player.cash, initial_cash + Money(100), "Player cash not updated after Farmer's Fate card effect.");
         assert!(game_state.farmer_fate_deck.draw_pile.is_empty(), "Farmer's Fate draw pile should be empty after drawing.");
     }
 
     #[test]
     fn test_apply_tile_effect_draw_farmers_fate_empty_deck() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![]);
         let tile = BoardTile {
             index: 0,
             name: "Test Fate".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::FarmerFate,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::FarmerFate),
@@ -1630,26 +1466,29 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_err(), "Expected error when drawing from empty Farmer's Fate deck.");
-        let player = game_state.players.get(&player_id).unwrap();
+        let player = game_state.players.get(player_id).unwrap();
         assert_eq!(player.cash, initial_cash, "Player cash should not change when draw fails.");
     }
 
     #[test]
     fn test_apply_tile_effect_draw_operating_cost_success() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let operating_cost_card = Card {
             id: 100,
             title: "Test Operating Cost Card".to_string(),
             description: "Test Operating Cost Description".to_string(),
             description_brief: "Test Operating Cost Description".to_string(),
-            effect: GameEffect::Expense(100),
+            effect: GameEffect::Expense(Money(100)),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![operating_cost_card]);
         let tile = BoardTile {
             index: 0,
             name: "Test Operating Cost".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::PayFees,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::PayFees),
@@ -1661,17 +1500,19 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_ok(), "Expected Ok(()) when drawing from empty Operating Cost deck due to current implementation.");
-        let player = game_state.players.get(&player_id).unwrap();
+        let player = game_state.players.get(player_id).unwrap();
         assert_eq!(player.cash, initial_cash, "Player cash should not change when draw 'fails' silently.");
     }
 
     #[test]
     fn test_apply_tile_effect_draw_operating_cost_empty_deck() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![]);
         let tile = BoardTile {
             index: 0,
             name: "Test Operating Cost".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::PayFees,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::PayFees),
@@ -1683,27 +1524,30 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_ok(), "Expected Ok(()) when drawing from empty Operating Cost deck due to current implementation.");
-        let player = game_state.players.get(&player_id).unwrap();
+        let player = game_state.players.get(player_id).unwrap();
         assert_eq!(player.cash, initial_cash, "Player cash should not change when draw 'fails' silently.");
     }
 
     #[test]
     fn test_apply_tile_effect_draw_option_to_buy_success() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let otb_card = Card {
             id: 101,
             title: "Test OTB Card".to_string(),
             description: "Test OTB Description".to_string(),
             description_brief: "Test OTB Description".to_string(),
-            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Grain, quantity: 1, cost: 1000 },
+            effect: GameEffect::OptionalBuyAsset { asset: AssetType::Grain, quantity: 1, cost: Money(1000) },
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         let otb_card_id = otb_card.id;
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![otb_card]);
         let tile = BoardTile {
             index: 0,
             name: "Test OTB".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::OptionToBuy,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::OptionToBuy),
@@ -1715,7 +1559,7 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_ok(), "Applying OTB tile effect failed: {:?}", result.err());
-        let player = game_state.players.get(&player_id).unwrap();
+        let player = game_state.players.get(player_id).unwrap();
         assert_eq!(player.hand.len(), 1, "Player should have one OTB card in hand.");
         assert_eq!(player.hand[0].id, otb_card_id, "The card in hand should be the OTB card drawn.");
         assert!(game_state.option_to_buy_deck.draw_pile.is_empty(), "OTB draw pile should be empty after drawing.");
@@ -1723,11 +1567,13 @@ mod tests {
 
     #[test]
     fn test_apply_tile_effect_draw_option_to_buy_empty_deck() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![]);
         let tile = BoardTile {
             index: 0,
             name: "Test OTB".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::OptionToBuy,
             harvest_type: HarvestType::None,
             effect: TileEffect::DrawCard(TileType::OptionToBuy),
@@ -1739,29 +1585,31 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_err(), "Expected error when drawing from empty OTB deck.");
-        let player = game_state.players.get(&player_id).unwrap();
+        let player = game_state.players.get(player_id).unwrap();
         assert!(player.hand.is_empty(), "Player hand should be empty when draw fails.");
     }
 
     #[test]
     fn test_apply_tile_effect_go_to_tile() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![]);
         let destination_tile_index = 5;
-        let destination_tile_cash_gain = 500;
+        let destination_tile_cash_gain = Money(500);
         let mut logs = Vec::new();
 
-        if let Some(tile) = game_state.board.get_mut(destination_tile_index) {
+        if let Some(tile) = Arc::make_mut(&mut game_state.board).get_mut(destination_tile_index) {
             tile.effect = TileEffect::GainCash(destination_tile_cash_gain);
         } else {
             panic!("Destination tile index out of bounds");
         }
         
-        game_state.players.get_mut(&player_id).unwrap().position = 0;
+        game_state.players.get_mut(player_id).unwrap().position = 0;
 
         let tile = BoardTile {
             index: 0,
             name: "Test Move".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::JumpToTile,
             harvest_type: HarvestType::None,
             effect: TileEffect::GoToTile(destination_tile_index),
@@ -1771,7 +1619,7 @@ mod tests {
         let result = game_state.handle_tile_event(player_id, &tile, &mut logs);
 
         assert!(result.is_ok(), "GoToTile effect failed: {:?}", result.err());
-        let player = game_state.players.get(&player_id).unwrap();
+        let player = game_state.players.get(player_id).unwrap();
         assert_eq!(player.position, destination_tile_index, "Player did not move to the correct tile.");
         // assert!(logs.iter().any(|log| log == "Test Player moved to Farmer's Fate"), "Expected exact movement log."); // Check movement log with exact string
         assert_eq!(player.cash, initial_cash + destination_tile_cash_gain, "Destination tile effect (GainCash) was not applied correctly.");
@@ -1779,14 +1627,16 @@ mod tests {
 
     #[test]
     fn test_handle_tile_event_gain_cash() {
-        let initial_cash = 5000;
+        let initial_cash = Money(5000);
         let (mut game_state, player_id) = setup_test_game_state_with_decks(initial_cash, vec![], vec![]);
         let gain_cash_tile = BoardTile {
             index: 0,
             name: "Gain Cash".to_string(),
+            month: Month::January,
+            week: 1,
             tile_type: TileType::Blank,
             harvest_type: HarvestType::None,
-            effect: TileEffect::GainCash(500),
+            effect: TileEffect::GainCash(Money(500)),
             description: None,
             description_brief: None,
         };
@@ -1796,8 +1646,8 @@ mod tests {
         assert!(result.is_ok(), "handle_tile_event failed: {:?}", result.err());
 
         // Verify the player gained cash
-        let final_player_cash = game_state.players[&player_id].cash;
-        assert_eq!(final_player_cash, initial_cash + 500, "Player should have gained 500 cash");
+        let final_player_cash = game_state.players[player_id].cash;
+        assert_eq!(final_player_cash, initial_cash + Money(500), "Player should have gained 500 cash");
 
         // Check the logging
         // assert!(logs.iter().any(|log: &String| log.contains("Test Player landed on Gain Cash")), "Gain cash tile logging failed"); // Landing log not generated in direct call
@@ -1807,24 +1657,12 @@ mod tests {
 
 // Mark methods as potentially unused for now
 impl Player {
-    fn _skip_year(&mut self) {
-        self.year += 1;
-    }
-
-    fn _set_one_time_harvest_multiplier(&mut self, asset: AssetType, multiplier: f32) {
-        // Update the crop multiplier for the specified asset
+    pub(crate) fn _set_one_time_harvest_multiplier(&mut self, asset: AssetType, multiplier: f32) {
+        // Stored until the next harvest of this crop consumes it; past income is left alone
         match asset {
             AssetType::Grain | AssetType::Hay | AssetType::Fruit => {
-                self.set_crop_multiplier(asset, multiplier);
-                
-                // If this is reducing income (e.g., half yield), apply it immediately
-                if multiplier < 1.0 && self.assets.contains_key(&asset) {
-                    if let Some(record) = self.assets.get_mut(&asset) {
-                        if record.total_income > 0 {
-                            record.total_income = (record.total_income as f32 * multiplier).round() as i32;
-                        }
-                    }
-                }
+                let combined = self.get_crop_multiplier(&asset) * multiplier;
+                self.set_crop_multiplier(asset, combined);
             },
             _ => {}
         }
@@ -0,0 +1,117 @@
+// src/models/ledger.rs
+// A farm's books: every payment in or out of a player's cash, with what it
+// was for. Borrowing and repaying the bank moves money without earning or
+// spending it, so loan principal isn't recorded here.
+
+use std::collections::BTreeMap;
+use crate::models::money::Money;
+
+/// First line of an exported ledger.
+pub const CSV_HEADER: &str = "player,year,category,amount\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LedgerCategory {
+    /// Crops and livestock brought in at harvest
+    Harvest,
+    /// Cash from tiles and cards, the side job wage and payments from other players
+    Bonus,
+    /// Cows sold at market or bought out by the government
+    Sale,
+    /// Land, livestock and equipment bought, outright or at auction
+    Purchase,
+    /// Insurance premiums paid and claims paid out
+    Insurance,
+    Interest,
+    /// The bank's cut of every forced loan
+    BankFee,
+    /// Bills from Farmer's Fate, operating cost and tile cards, ridge feed and payments to other players
+    CardExpense,
+}
+
+impl LedgerCategory {
+    pub const ALL: [LedgerCategory; 8] = [
+        LedgerCategory::Harvest,
+        LedgerCategory::Bonus,
+        LedgerCategory::Sale,
+        LedgerCategory::Purchase,
+        LedgerCategory::Insurance,
+        LedgerCategory::Interest,
+        LedgerCategory::BankFee,
+        LedgerCategory::CardExpense,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LedgerCategory::Harvest => "Harvest",
+            LedgerCategory::Bonus => "Bonus",
+            LedgerCategory::Sale => "Sale",
+            LedgerCategory::Purchase => "Purchase",
+            LedgerCategory::Insurance => "Insurance",
+            LedgerCategory::Interest => "Interest",
+            LedgerCategory::BankFee => "Bank fee",
+            LedgerCategory::CardExpense => "Card expense",
+        }
+    }
+}
+
+/// One payment: positive amounts came in, negative went out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transaction {
+    pub year: u32,
+    pub category: LedgerCategory,
+    pub amount: Money,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ledger {
+    pub transactions: Vec<Transaction>,
+}
+
+impl Ledger {
+    /// Records a payment; zero amounts are left out.
+    pub fn record(&mut self, year: u32, category: LedgerCategory, amount: Money) {
+        if amount != Money::ZERO {
+            self.transactions.push(Transaction { year, category, amount });
+        }
+    }
+
+    /// Everything that came in.
+    pub fn income(&self) -> Money {
+        self.transactions.iter().map(|t| t.amount.max(Money::ZERO)).sum()
+    }
+
+    /// Everything that went out, as a positive number.
+    pub fn expenses(&self) -> Money {
+        self.transactions.iter().map(|t| (-t.amount).max(Money::ZERO)).sum()
+    }
+
+    /// Net amount for one category.
+    pub fn total(&self, category: LedgerCategory) -> Money {
+        self.transactions.iter().filter(|t| t.category == category).map(|t| t.amount).sum()
+    }
+
+    /// Net amount per category for each year, for a year-by-year report.
+    pub fn by_year(&self) -> BTreeMap<u32, BTreeMap<LedgerCategory, Money>> {
+        let mut years: BTreeMap<u32, BTreeMap<LedgerCategory, Money>> = BTreeMap::new();
+        for t in &self.transactions {
+            *years.entry(t.year).or_default().entry(t.category).or_default() += t.amount;
+        }
+        years
+    }
+
+    /// CSV rows (`player,year,category,amount`) for this ledger, without a header.
+    pub fn csv_rows(&self, player: &str) -> String {
+        self.transactions.iter()
+            .map(|t| format!("{},{},{},{}\n", csv_field(player), t.year, t.category.label(), t.amount.dollars()))
+            .collect()
+    }
+}
+
+/// Quotes a CSV field if it needs it.
+pub(crate) fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::GameEngine;
+    use crate::models::{Ledger, LedgerCategory, Money};
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo, Jr.".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 5).unwrap()
+    }
+
+    #[test]
+    fn test_ledger_totals_split_income_from_expenses() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, LedgerCategory::Harvest, Money(4000));
+        ledger.record(1, LedgerCategory::Interest, Money(-500));
+        ledger.record(2, LedgerCategory::Harvest, Money(1500));
+        ledger.record(2, LedgerCategory::BankFee, Money::ZERO);
+
+        assert_eq!(ledger.transactions.len(), 3, "Zero amounts are left out");
+        assert_eq!(ledger.income(), Money(5500));
+        assert_eq!(ledger.expenses(), Money(500));
+        assert_eq!(ledger.total(LedgerCategory::Harvest), Money(5500));
+        let years = ledger.by_year();
+        assert_eq!(years[&1][&LedgerCategory::Interest], Money(-500));
+        assert_eq!(years[&2].len(), 1);
+    }
+
+    #[test]
+    fn test_forced_loan_records_the_bill_and_the_bank_fee() {
+        let mut engine = engine();
+        let game = engine.state_mut();
+        game.players[0].cash = Money::ZERO;
+        let mut logs = Vec::new();
+        game.pay_expense(0, LedgerCategory::CardExpense, Money(1000), &mut logs).unwrap();
+
+        let player = &game.players[0];
+        assert_eq!(player.ledger.total(LedgerCategory::CardExpense), Money(-1000));
+        assert_eq!(player.ledger.total(LedgerCategory::BankFee), Money(-1000), "20% of a $5,000 loan");
+        assert_eq!(player.total_expenses, Money(2000));
+        assert_eq!(player.total_income, Money::ZERO);
+    }
+
+    #[test]
+    fn test_transfers_and_export_cover_both_players() {
+        let mut engine = engine();
+        let game = engine.state_mut();
+        let mut logs = Vec::new();
+        game.transfer_between_players(1, 0, Money(300), &mut logs).unwrap();
+
+        assert_eq!(game.players[0].ledger.total(LedgerCategory::Bonus), Money(300));
+        assert_eq!(game.players[1].ledger.total(LedgerCategory::CardExpense), Money(-300));
+
+        let csv = game.ledger_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("player,year,category,amount"));
+        assert!(csv.contains("Ann,1,Bonus,300\n"));
+        assert!(csv.contains("\"Bo, Jr.\",1,Card expense,-300\n"), "Names with commas are quoted");
+    }
+}
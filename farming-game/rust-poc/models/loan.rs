@@ -0,0 +1,90 @@
+// src/models/loan.rs
+// A player's debt broken into notes: one per loan, each with the principal
+// still owed, the rate it was written at and the year it was taken out.
+// `Player::debt` stays the total; the notes say what it's made of.
+
+use crate::models::money::Money;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    pub id: usize,
+    /// Principal still outstanding
+    pub principal: Money,
+    /// Yearly interest rate on the principal
+    pub rate: f32,
+    /// Year the loan was taken out
+    pub year: u32,
+}
+
+impl Note {
+    /// A year's interest on the outstanding principal, unrounded.
+    fn interest(&self) -> f64 {
+        self.principal.dollars() as f64 * self.rate as f64
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Notes {
+    notes: Vec<Note>,
+    next_id: usize,
+}
+
+impl Notes {
+    /// Writes a new note and returns its id. Nothing is written for a zero principal.
+    pub fn take_out(&mut self, principal: Money, rate: f32, year: u32) -> Option<usize> {
+        if !principal.is_positive() {
+            return None;
+        }
+        self.next_id += 1;
+        self.notes.push(Note { id: self.next_id, principal, rate, year });
+        Some(self.next_id)
+    }
+
+    /// Outstanding notes, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Note> {
+        self.notes.iter()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Note> {
+        self.notes.iter().find(|note| note.id == id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Total principal outstanding across every note.
+    pub fn principal(&self) -> Money {
+        self.notes.iter().map(|note| note.principal).sum()
+    }
+
+    /// A year's interest across every note, rounded once at the end.
+    pub fn interest_due(&self) -> Money {
+        Money(self.notes.iter().map(Note::interest).sum::<f64>().round() as i64)
+    }
+
+    /// Pays up to `amount` off note `id`, or off the oldest notes first when
+    /// no note is given. Paid-off notes are closed. Returns the amount applied.
+    pub fn repay(&mut self, amount: Money, id: Option<usize>) -> Money {
+        let mut remaining = amount.max(Money::ZERO);
+        for note in self.notes.iter_mut().filter(|note| id.is_none_or(|id| note.id == id)) {
+            let paid = remaining.min(note.principal);
+            note.principal -= paid;
+            remaining -= paid;
+        }
+        self.notes.retain(|note| note.principal.is_positive());
+        amount.max(Money::ZERO) - remaining
+    }
+
+    /// Brings the notes in line with a `debt` that was changed directly (house
+    /// rules, scenarios, card effects): debt beyond the notes becomes a new note
+    /// at `rate`, and notes beyond the debt are paid off oldest first.
+    pub fn reconcile(&mut self, debt: Money, rate: f32, year: u32) {
+        let principal = self.principal();
+        if debt > principal {
+            self.take_out(debt - principal, rate, year);
+        } else if debt < principal {
+            self.repay(principal - debt.max(Money::ZERO), None);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{Action, GameEngine, GameError, GameEvent};
+    use crate::models::Notes;
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 8).unwrap()
+    }
+
+    #[test]
+    fn test_repayments_go_to_the_oldest_note_unless_one_is_named() {
+        let mut notes = Notes::default();
+        let first = notes.take_out(Money(5000), 0.1, 1).unwrap();
+        let second = notes.take_out(Money(2000), 0.2, 2).unwrap();
+
+        assert_eq!(notes.interest_due(), Money(900));
+        assert_eq!(notes.repay(Money(1500), Some(second)), Money(1500));
+        assert_eq!(notes.get(first).unwrap().principal, Money(5000));
+        assert_eq!(notes.repay(Money(6000), None), Money(5500), "Never pays more than is owed");
+        assert!(notes.is_empty());
+    }
+
+    #[test]
+    fn test_reconcile_follows_debt_set_directly() {
+        let mut notes = Notes::default();
+        notes.take_out(Money(5000), 0.1, 1);
+        notes.reconcile(Money(8000), 0.1, 3);
+        assert_eq!(notes.iter().map(|note| (note.principal, note.year)).collect::<Vec<_>>(), vec![(Money(5000), 1), (Money(3000), 3)]);
+        notes.reconcile(Money(2000), 0.1, 3);
+        assert_eq!(notes.principal(), Money(2000));
+    }
+
+    #[test]
+    fn test_forced_loan_writes_a_note_and_payments_can_target_it() {
+        let mut engine = engine();
+        let game = engine.state_mut();
+        assert_eq!(game.players[0].notes.principal(), game.players[0].debt, "Starting debt is the first note");
+
+        game.players[0].cash = Money::ZERO;
+        game.players[0].debt = Money(4000);
+        game.players[0].year = 2;
+        let mut logs = Vec::new();
+        game.handle_forced_loan(0, Money(1000), &mut logs).unwrap();
+        let note = game.players[0].notes.iter().last().unwrap().clone();
+        assert_eq!((note.principal, note.year), (Money(5000), 2), "The whole loan is owed, bank fee included");
+        assert_eq!(game.players[0].notes.iter().count(), 2, "Debt set directly got a note of its own");
+        assert_eq!(game.players[0].interest_due(game.rules.interest_rate), Money(900));
+
+        game.players[0].cash = Money(3000);
+        assert_eq!(engine.apply(Action::PayNote { note: 99, amount: Money(100) }), Err(GameError::NoteNotFound(99)));
+        engine.apply(Action::PayNote { note: note.id, amount: Money(3000) }).unwrap();
+        let player = &engine.state().players[0];
+        assert_eq!(player.notes.get(note.id).unwrap().principal, Money(2000));
+        assert_eq!(player.debt, player.notes.principal());
+    }
+
+    #[test]
+    fn test_forced_loans_cover_the_shortfall_after_the_bank_fee() {
+        let rules = GameRules::default();
+        let increment = rules.loan_increment;
+        let (loan, fee) = rules.forced_loan(increment - Money(100));
+        assert_eq!(loan, 2 * increment, "One increment less its fee falls short");
+        assert!(loan - fee >= increment - Money(100));
+        assert_eq!(rules.forced_loan(Money(100)).0, increment);
+
+        let mut engine = engine();
+        let game = engine.state_mut();
+        game.players[0].cash = Money::ZERO;
+        game.players[0].debt = Money::ZERO;
+        let mut logs = Vec::new();
+        game.handle_forced_loan(0, increment - Money(100), &mut logs).unwrap();
+        assert!(game.players[0].cash >= Money::ZERO);
+    }
+
+    #[test]
+    fn test_repayments_come_in_whole_increments_or_clear_the_debt() {
+        let mut engine = engine();
+        let player_id = engine.current_player_id();
+        let player = &mut engine.state_mut().players[player_id];
+        player.cash = Money(10_000);
+        player.debt = Money(2500);
+
+        assert_eq!(engine.apply(Action::PayLoan(Money(1500))),
+                   Err(GameError::InvalidPayment { amount: Money(1500), increment: Money(1000), owed: Money(2500) }));
+        assert_eq!(engine.apply(Action::PayLoan(Money(3000))), Err(GameError::Overpayment { amount: Money(3000), owed: Money(2500) }));
+        engine.apply(Action::PayLoan(Money(1000))).unwrap();
+        engine.apply(Action::PayLoan(Money(1500))).unwrap();
+
+        let game = engine.state();
+        assert_eq!(game.players[player_id].debt, Money::ZERO);
+        assert_eq!(game.players[player_id].cash, Money(7500));
+        let repaid: Vec<_> = game.events.iter().filter_map(|event| match event {
+            GameEvent::DebtRepaid { amount, debt, .. } => Some((*amount, *debt)),
+            _ => None,
+        }).collect();
+        assert_eq!(repaid, vec![(Money(1000), Money(1500)), (Money(1500), Money::ZERO)]);
+    }
+}
@@ -1,18 +1,52 @@
 pub mod asset;
 pub mod board;
 pub mod player;
+pub mod player_registry;
 pub mod ridge;
 pub mod game_state;
 pub mod effects;
+pub mod valuation;
+pub mod player_view;
+pub mod ledger;
+pub mod loan;
+pub mod calendar;
+pub mod brief;
+pub mod money;
+pub mod persistent_effects;
 
-pub use asset::{AssetType, AssetRecord};
-pub use board::{BoardTile, TileType, HarvestType, TileEffect};
+pub use asset::{AssetType, AssetRecord, AssetLot};
+pub use board::{BoardTile, JumpRules, Month, TileType, HarvestType, TileEffect};
 pub use crate::cards::card::Card;
-pub use player::{Player, PlayerType};
+pub use player::{Player, PlayerColor, PlayerType};
+pub use player_registry::PlayerRegistry;
 pub use ridge::Ridge;
 pub use game_state::GameState;
+pub use valuation::ValueTable;
+pub use player_view::{DeckView, OpponentView, PlayerView};
+pub use ledger::{Ledger, LedgerCategory, Transaction};
+pub use loan::{Note, Notes};
+pub use calendar::CalendarDate;
+pub use money::{format_money, format_money_change, Money};
 
 #[cfg(test)]
 mod game_state_test;
 #[cfg(test)]
-mod player_test; 
\ No newline at end of file
+mod player_test;
+#[cfg(test)]
+mod valuation_test;
+#[cfg(test)]
+mod player_registry_test;
+#[cfg(test)]
+mod player_view_test;
+#[cfg(test)]
+mod ledger_test;
+#[cfg(test)]
+mod loan_test;
+#[cfg(test)]
+mod calendar_test;
+#[cfg(test)]
+mod asset_test;
+#[cfg(test)]
+mod brief_test;
+#[cfg(test)]
+mod money_test;
@@ -0,0 +1,208 @@
+// src/models/money.rs
+// Dollar amounts. Cash, debt, prices and rates are all `Money`, so a rate
+// can't be paid where a total was meant without someone multiplying it out,
+// and arithmetic saturates rather than overflowing however rich the table
+// gets.
+//
+// Amounts print as players read them: thousands grouped and the sign ahead
+// of the dollar sign, e.g. "$12,500" and "-$3,000". Log messages go through
+// `Locale::money`, which lets a translation change the separator and where
+// the currency sign goes; widgets and console output use `Display` or
+// `format_money`.
+
+use std::fmt;
+use std::iter::Sum;
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// An amount of money in whole dollars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Money(pub i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+    pub const MAX: Money = Money(i64::MAX);
+    pub const MIN: Money = Money(i64::MIN);
+
+    pub fn dollars(self) -> i64 {
+        self.0
+    }
+
+    pub fn abs(self) -> Money {
+        Money(self.0.saturating_abs())
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+
+    /// This amount scaled by `factor` (a rate, share or multiplier), to the
+    /// nearest dollar.
+    pub fn times(self, factor: f32) -> Money {
+        Money((self.0 as f64 * factor as f64).round() as i64)
+    }
+
+    /// Units at `price` each it takes to make up this amount, rounding up;
+    /// none when there's nothing to make up or the units are free.
+    pub fn units_at(self, price: Money) -> i64 {
+        if self.0 <= 0 || price.0 <= 0 { 0 } else { (self.0 + price.0 - 1) / price.0 }
+    }
+
+    /// This amount as a share of `whole`, e.g. 0.25 for a quarter; 0.0 when
+    /// `whole` is nothing.
+    pub fn ratio(self, whole: Money) -> f64 {
+        if whole.0 == 0 { 0.0 } else { self.0 as f64 / whole.0 as f64 }
+    }
+}
+
+impl From<i32> for Money {
+    fn from(dollars: i32) -> Self {
+        Money(dollars.into())
+    }
+}
+
+impl From<i64> for Money {
+    fn from(dollars: i64) -> Self {
+        Money(dollars)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0.saturating_sub(other.0))
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(self.0.saturating_neg())
+    }
+}
+
+/// A rate times a count, e.g. $500 an acre times 10 acres.
+impl<T: Into<i64>> Mul<T> for Money {
+    type Output = Money;
+
+    fn mul(self, count: T) -> Money {
+        Money(self.0.saturating_mul(count.into()))
+    }
+}
+
+macro_rules! count_times_money {
+    ($($count:ty),*) => {$(
+        impl Mul<Money> for $count {
+            type Output = Money;
+
+            fn mul(self, money: Money) -> Money {
+                money * self
+            }
+        }
+    )*};
+}
+
+count_times_money!(i32, u32, i64);
+
+/// A total shared out `count` ways, rounded toward zero. Nothing shared no
+/// ways is nothing.
+impl<T: Into<i64>> Div<T> for Money {
+    type Output = Money;
+
+    fn div(self, count: T) -> Money {
+        Money(self.0.checked_div(count.into()).unwrap_or(0))
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = group_digits(self.0.unsigned_abs(), ",");
+        let text = if self.0 < 0 { format!("-${}", digits) } else { format!("${}", digits) };
+        f.pad(&text)
+    }
+}
+
+/// Reads an amount written either as a bare number ("-3000") or the way
+/// `Display` writes it ("-$3,000").
+impl FromStr for Money {
+    type Err = ParseIntError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (negative, rest) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let digits = match rest.strip_prefix('$') {
+            Some(grouped) => grouped.replace(',', ""),
+            None => rest.to_string(),
+        };
+        let dollars: i64 = digits.parse()?;
+        Ok(Money(if negative { -dollars } else { dollars }))
+    }
+}
+
+/// `amount` in dollars, e.g. "$12,500" or "-$3,000".
+pub fn format_money(amount: impl Into<Money>) -> String {
+    amount.into().to_string()
+}
+
+/// `amount` in dollars with its sign either way, e.g. "+$500" or "-$1,000",
+/// for changes rather than balances.
+pub fn format_money_change(amount: impl Into<Money>) -> String {
+    let amount = amount.into();
+    if amount.is_negative() { amount.to_string() } else { format!("+{}", amount) }
+}
+
+/// `value`'s digits in groups of three, joined by `separator`.
+pub fn group_digits(value: u64, separator: &str) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * separator.len());
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::models::money::{format_money, format_money_change, group_digits, Money};
+
+    #[test]
+    fn test_amounts_are_grouped_by_thousands() {
+        assert_eq!(format_money(0), "$0");
+        assert_eq!(format_money(999), "$999");
+        assert_eq!(format_money(12500), "$12,500");
+        assert_eq!(format_money(-3000), "-$3,000");
+        assert_eq!(format_money(1_000_000), "$1,000,000");
+        assert_eq!(format_money(i32::MIN), "-$2,147,483,648");
+        assert_eq!(group_digits(1234567, "."), "1.234.567");
+    }
+
+    #[test]
+    fn test_changes_always_carry_a_sign() {
+        assert_eq!(format_money_change(500), "+$500");
+        assert_eq!(format_money_change(0), "+$0");
+        assert_eq!(format_money_change(-1000), "-$1,000");
+    }
+
+    #[test]
+    fn test_arithmetic_saturates_instead_of_overflowing() {
+        assert_eq!(Money(500) + Money(250) - Money(1000), Money(-250));
+        assert_eq!(Money::MAX + Money(1), Money::MAX);
+        assert_eq!(Money::MIN - Money(1), Money::MIN);
+        assert_eq!(-Money::MIN, Money::MAX);
+        assert_eq!(Money(500) * 3, Money(1500));
+        assert_eq!(4 * Money(500), Money(2000));
+        assert_eq!(Money::MAX * 2, Money::MAX);
+        assert_eq!(Money(1000) / 3, Money(333));
+        assert_eq!(Money(1000) / 0, Money::ZERO);
+        assert_eq!([Money(100), Money(200)].iter().sum::<Money>(), Money(300));
+    }
+
+    #[test]
+    fn test_rates_and_shares() {
+        assert_eq!(Money(2000).times(1.25), Money(2500));
+        assert_eq!(Money(999).times(0.5), Money(500), "Rounded to the nearest dollar");
+        assert_eq!(Money(2500).units_at(Money(1000)), 3);
+        assert_eq!(Money(-500).units_at(Money(1000)), 0);
+        assert_eq!(Money(500).units_at(Money::ZERO), 0);
+        assert_eq!(Money(250).ratio(Money(1000)), 0.25);
+        assert_eq!(Money(250).ratio(Money::ZERO), 0.0);
+    }
+
+    #[test]
+    fn test_amounts_read_back_the_way_they_print() {
+        assert_eq!(Money(12500).to_string(), "$12,500");
+        assert_eq!(format!("{:>8}", Money(-300)), "   -$300");
+        for amount in [Money::ZERO, Money(12500), Money(-3000), Money::MAX] {
+            assert_eq!(amount.to_string().parse::<Money>(), Ok(amount));
+        }
+        assert_eq!("-3000".parse::<Money>(), Ok(Money(-3000)));
+        assert!("twelve".parse::<Money>().is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// src/models/persistent_effects.rs
+// Effects a card leaves on a player for a while, such as the Cattle
+// Management Bonus. Each kind stacks its own way: harvest multipliers
+// multiply, income bonuses add up, and only the largest expense discount
+// counts, so discounts never make expenses free. An effect wears off after a
+// number of year ends, or sooner once it has applied to a number of harvests.
+
+use crate::models::{AssetType, Money};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EffectType {
+    /// Livestock harvest multiplier (1.5 for a 50% bonus); the same as a
+    /// `HarvestMultiplier` on cows
+    LivestockHarvestBonus(f32),
+    /// Multiplies the income from harvests of `asset`
+    HarvestMultiplier { asset: AssetType, multiplier: f32 },
+    /// Share taken off the operating expense drawn at each harvest (0.25 for 25% off)
+    ExpenseDiscount(f32),
+    /// Added to the income of every harvest that pays
+    IncomeBonus(Money),
+}
+
+impl EffectType {
+    /// Harvest multiplier this applies to `asset`, if any.
+    pub fn harvest_multiplier(&self, asset: AssetType) -> Option<f32> {
+        match *self {
+            EffectType::LivestockHarvestBonus(multiplier) if asset == AssetType::Cows => Some(multiplier),
+            EffectType::HarvestMultiplier { asset: target, multiplier } if asset == target => Some(multiplier),
+            _ => None,
+        }
+    }
+
+    /// Whether a harvest of `asset` puts this effect to use, counting down
+    /// the effect's harvests.
+    pub fn applies_to_harvest(&self, asset: AssetType) -> bool {
+        match self {
+            EffectType::LivestockHarvestBonus(_) | EffectType::HarvestMultiplier { .. } => self.harvest_multiplier(asset).is_some(),
+            EffectType::ExpenseDiscount(_) | EffectType::IncomeBonus(_) => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PersistentEffect {
+    pub effect_type: EffectType,
+    /// Year ends left before it wears off
+    pub years_remaining: u32,
+    /// Harvests it may still apply to, for effects that wear off with use
+    pub harvests_remaining: Option<u32>,
+}
+
+impl PersistentEffect {
+    /// Counts down a year end; false once the effect has worn off.
+    pub fn end_year(&mut self) -> bool {
+        self.years_remaining = self.years_remaining.saturating_sub(1);
+        self.years_remaining > 0
+    }
+
+    /// Counts down a harvest of `asset` if the effect applied to it; false
+    /// once the effect has worn off.
+    pub fn use_for_harvest(&mut self, asset: AssetType) -> bool {
+        match self.harvests_remaining.as_mut() {
+            Some(harvests) if self.effect_type.applies_to_harvest(asset) => {
+                *harvests = harvests.saturating_sub(1);
+                *harvests > 0
+            }
+            _ => true,
+        }
+    }
+}
@@ -1,19 +1,16 @@
 use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
 use crate::models::asset::{AssetType, AssetRecord};
 use crate::models::board::HarvestType;
+use crate::models::ledger::{Ledger, LedgerCategory};
+use crate::models::loan::Notes;
+use crate::models::money::Money;
 use crate::cards::card::Card;
+use crate::models::valuation::ValueTable;
+use crate::game::insurance::{Coverage, InsurancePolicy};
 use crate::config::{STARTING_CASH, STARTING_DEBT, STARTING_LAND, STARTING_YEAR, STARTING_POSITION};
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum EffectType {
-    LivestockHarvestBonus(f32),  // The f32 represents the bonus multiplier (1.5 for 50% bonus)
-}
-
-#[derive(Debug, Clone)]
-pub struct PersistentEffect {
-    pub effect_type: EffectType,
-    pub years_remaining: u32,
-}
+pub use crate::models::persistent_effects::{EffectType, PersistentEffect};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlayerType {
@@ -21,6 +18,44 @@ pub enum PlayerType {
     AI(String),
 }
 
+/// Token color a player is assigned at setup, one per native player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayerColor {
+    Red,
+    Brown,
+    Green,
+    Blue,
+    White,
+    Yellow,
+}
+
+impl PlayerColor {
+    pub const ALL: [PlayerColor; 6] = [
+        PlayerColor::Red,
+        PlayerColor::Brown,
+        PlayerColor::Green,
+        PlayerColor::Blue,
+        PlayerColor::White,
+        PlayerColor::Yellow,
+    ];
+
+    /// Color for the player in `seat`, for games set up without choosing colors.
+    pub fn for_seat(seat: usize) -> Self {
+        Self::ALL[seat % Self::ALL.len()]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayerColor::Red => "Red",
+            PlayerColor::Brown => "Brown",
+            PlayerColor::Green => "Green",
+            PlayerColor::Blue => "Blue",
+            PlayerColor::White => "White",
+            PlayerColor::Yellow => "Yellow",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PlayerEvent {
     pub description: String,
@@ -31,27 +66,35 @@ pub struct PlayerEvent {
 pub struct Player {
     pub crop_yield_multipliers: HashMap<AssetType, f32>,
     pub eligible_for_side_job_pay: bool,
+    pub harvest_income_garnished: bool,  // Bad at Taxes: the next harvest only costs its operating expense
     pub id: usize,
     pub name: String,
+    pub color: PlayerColor,
     pub player_type: PlayerType,
-    pub cash: i32,
-    pub debt: i32,
+    pub cash: Money,
+    pub debt: Money,
+    pub notes: Notes,  // What the debt is made of, loan by loan
     pub land: i32,
     pub is_active: bool,
     pub position: usize,
     pub year: u32,
     pub assets: HashMap<AssetType, AssetRecord>,
     pub history: Vec<PlayerEvent>,
-    pub completed_harvests: HashSet<(HarvestType, usize)>,
+    pub completed_harvests: HashSet<(HarvestType, usize)>,  // Harvests brought in, by board section or year
     pub persistent_effects: Vec<PersistentEffect>,
     pub hand: Vec<Card>,
     pub active_persistent_cards: Vec<(Card, u32)>, // (Card, years_remaining)
-    pub net_worth: i32,
-    pub total_asset_value: i32,
-    pub total_ridge_value: i32,
-    pub total_income: i32,
-    pub total_expenses: i32,
+    pub insurance: Vec<InsurancePolicy>,  // Policies bought this year
+    pub net_worth: Money,
+    pub total_asset_value: Money,
+    pub total_ridge_value: Money,
+    pub total_income: Money,
+    pub total_expenses: Money,
+    pub peak_debt: Money,  // Most owed at once, as of the last scoreboard update
     pub turns_taken: i32,  // Track number of turns taken
+    pub cards_drawn: u32,  // Farmer's Fate and O.T.B. cards drawn from landing on tiles
+    pub harvests_collected: Vec<(HarvestType, Money)>,  // Every harvest brought in, with its income
+    pub ledger: Ledger,
 }
 
 impl Player {
@@ -67,26 +110,45 @@ impl Player {
         *self.crop_yield_multipliers.get(crop).unwrap_or(&1.0)
     }
 
-    pub fn add_asset(&mut self, asset: AssetType, quantity: i32, cost: i32) {
-        let entry = self.assets.entry(asset).or_insert(AssetRecord {
-            quantity: 0,
-            total_cost: 0,
-            total_income: 0,
-        });
-        entry.quantity += quantity;
-        entry.total_cost += cost;  // Cost is already the total cost (cost per unit * quantity)
+    /// Removes the pending one-time multiplier for `crop`, returning 1.0 if there is none.
+    pub fn take_crop_multiplier(&mut self, crop: &AssetType) -> f32 {
+        self.crop_yield_multipliers.remove(crop).unwrap_or(1.0)
+    }
+
+    pub fn add_asset(&mut self, asset: AssetType, quantity: u32, cost: Money) {
+        self.ledger.record(self.year, LedgerCategory::Purchase, -cost);
+        self.acquire_asset(asset, quantity, cost);
+    }
+
+    /// `add_asset` for a purchase whose `cost` was already paid and booked
+    /// with `GameState::debit`.
+    pub fn acquire_asset(&mut self, asset: AssetType, quantity: u32, cost: Money) {
+        let entry = self.assets.entry(asset).or_default();
+        entry.buy(quantity, cost, self.year);  // Cost is already the total cost (cost per unit * quantity)
         self.update_scoreboard();
     }
 
-    pub fn sell_asset(&mut self, asset: AssetType, quantity: i32, price: i32) {
-        if let Some(record) = self.assets.get_mut(&asset) {
-            let qty = quantity.min(record.quantity);
-            record.quantity -= qty;
-            record.total_income += price * qty;
-            if record.quantity == 0 {
-                self.assets.remove(&asset);
-            }
+    /// Gives up to `quantity` units of `asset`, oldest first, without being
+    /// paid for them, and returns what the units lost had cost.
+    pub fn remove_asset(&mut self, asset: AssetType, quantity: u32) -> Money {
+        let Some(record) = self.assets.get_mut(&asset) else { return Money::ZERO };
+        let cost = record.take(quantity);
+        self.update_scoreboard();
+        cost
+    }
+
+    /// Sells up to `quantity` units at `price` each, oldest first, and
+    /// returns what the units sold had cost.
+    pub fn sell_asset(&mut self, asset: AssetType, quantity: u32, price: Money) -> Money {
+        let Some(record) = self.assets.get_mut(&asset) else { return Money::ZERO };
+        let qty = quantity.min(record.quantity);
+        let cost = record.take(qty);
+        record.total_income += price * qty;
+        if record.quantity == 0 {
+            self.assets.remove(&asset);
         }
+        self.record_transaction(LedgerCategory::Sale, price * qty);
+        cost
     }
 
     pub fn record_event(&mut self, description: String, ai_reasoning: Option<String>) {
@@ -97,14 +159,17 @@ impl Player {
         Player {
             id,
             name,
+            color: PlayerColor::for_seat(id),
             player_type,
             cash: STARTING_CASH,
             debt: STARTING_DEBT,
+            notes: Notes::default(),
             land: STARTING_LAND,
             is_active: true,
             position: STARTING_POSITION,
             year: STARTING_YEAR,
             eligible_for_side_job_pay: true,
+            harvest_income_garnished: false,
             crop_yield_multipliers: HashMap::new(),
             assets: HashMap::new(),
             history: vec![],
@@ -112,12 +177,17 @@ impl Player {
             persistent_effects: Vec::new(),
             hand: Vec::new(),
             active_persistent_cards: Vec::new(),
-            net_worth: 0,
-            total_asset_value: 0,
-            total_ridge_value: 0,
-            total_income: 0,
-            total_expenses: 0,
+            insurance: Vec::new(),
+            net_worth: Money::ZERO,
+            total_asset_value: Money::ZERO,
+            total_ridge_value: Money::ZERO,
+            total_income: Money::ZERO,
+            total_expenses: Money::ZERO,
+            peak_debt: STARTING_DEBT,
             turns_taken: 0,
+            cards_drawn: 0,
+            harvests_collected: Vec::new(),
+            ledger: Ledger::default(),
         }
     }
 
@@ -129,29 +199,77 @@ impl Player {
         self.completed_harvests.insert((harvest_type, section_start));
     }
 
+    /// Whether the player has already brought `harvest_type` in during `year`.
+    pub fn has_harvested_in_year(&self, harvest_type: HarvestType, year: u32) -> bool {
+        self.has_harvested_in_section(harvest_type, year as usize)
+    }
+
+    /// Hay cuttings the player has brought in during `year`, out of four.
+    pub fn hay_cuttings_in_year(&self, year: u32) -> usize {
+        self.completed_harvests.iter()
+            .filter(|(harvest_type, section)| harvest_type.is_hay_cutting() && *section == year as usize)
+            .count()
+    }
+
     pub fn add_persistent_effect(&mut self, effect_type: EffectType, years: u32) {
+        self.add_persistent_effect_for_harvests(effect_type, years, None);
+    }
+
+    /// Adds an effect lasting `years` year ends, or, if `harvests` is set,
+    /// until it has applied to that many harvests if that comes sooner.
+    pub fn add_persistent_effect_for_harvests(&mut self, effect_type: EffectType, years: u32, harvests: Option<u32>) {
         self.persistent_effects.push(PersistentEffect {
             effect_type,
             years_remaining: years,
+            harvests_remaining: harvests,
         });
     }
 
     pub fn get_livestock_harvest_multiplier(&self) -> f32 {
-        let mut multiplier = 1.0;
-        for effect in &self.persistent_effects {
-            let EffectType::LivestockHarvestBonus(bonus) = effect.effect_type;
-            multiplier *= bonus;
-        }
-        multiplier
+        self.harvest_multiplier(AssetType::Cows)
+    }
+
+    /// Every persistent harvest multiplier on `asset`, multiplied together.
+    pub fn harvest_multiplier(&self, asset: AssetType) -> f32 {
+        self.persistent_effects.iter()
+            .filter_map(|effect| effect.effect_type.harvest_multiplier(asset))
+            .product()
+    }
+
+    /// Share taken off operating expenses: the largest discount in play, as
+    /// discounts don't stack.
+    pub fn expense_discount(&self) -> f32 {
+        self.persistent_effects.iter()
+            .filter_map(|effect| match effect.effect_type {
+                EffectType::ExpenseDiscount(discount) => Some(discount.clamp(0.0, 1.0)),
+                _ => None,
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Added to each harvest's income: every income bonus in play.
+    pub fn income_bonus(&self) -> Money {
+        self.persistent_effects.iter()
+            .map(|effect| match effect.effect_type {
+                EffectType::IncomeBonus(bonus) => bonus,
+                _ => Money::ZERO,
+            })
+            .sum()
+    }
+
+    /// Counts down the effects a harvest of `asset` used, dropping any that wore off.
+    pub fn use_harvest_effects(&mut self, asset: AssetType) {
+        self.persistent_effects.retain_mut(|effect| effect.use_for_harvest(asset));
+    }
+
+    /// Counts down a year end on every effect, dropping any that wore off.
+    pub fn expire_year_effects(&mut self) {
+        self.persistent_effects.retain_mut(PersistentEffect::end_year);
     }
 
     pub fn advance_year(&mut self) {
         self.year += 1;
-        // Update persistent effects
-        self.persistent_effects.retain_mut(|effect| {
-            effect.years_remaining -= 1;
-            effect.years_remaining > 0
-        });
+        self.expire_year_effects();
     }
 
     pub fn has_active_effect(&self, effect_type: &EffectType) -> bool {
@@ -162,6 +280,22 @@ impl Player {
         self.active_persistent_cards.push((card, years));
     }
 
+    /// Held cards waiting on `harvest_type`.
+    pub fn held_cards<'a>(&'a self, harvest_type: &'a HarvestType) -> impl Iterator<Item = &'a Card> + 'a {
+        self.active_persistent_cards.iter()
+            .map(|(card, _)| card)
+            .filter(move |card| card.hold_until.as_ref() == Some(harvest_type))
+    }
+
+    /// Removes and returns the held cards waiting on `harvest_type`.
+    pub fn take_held_cards(&mut self, harvest_type: &HarvestType) -> Vec<Card> {
+        let (matching, remaining) = std::mem::take(&mut self.active_persistent_cards)
+            .into_iter()
+            .partition(|(card, _)| card.hold_until.as_ref() == Some(harvest_type));
+        self.active_persistent_cards = remaining;
+        matching.into_iter().map(|(card, _)| card).collect()
+    }
+
     pub fn update_persistent_cards(&mut self, farmers_fate_discard: &mut Vec<Card>) {
         self.active_persistent_cards.retain_mut(|(card, years_remaining)| {
             *years_remaining -= 1;
@@ -174,36 +308,105 @@ impl Player {
         });
     }
 
+    /// Rolls the player into the next year. Pays the side job wage if still eligible,
+    /// then restores eligibility for the new year. Returns the wage paid, if any.
+    pub fn start_new_year(&mut self, wage: Money) -> Option<Money> {
+        self.year += 1;
+        let paid = if self.eligible_for_side_job_pay {
+            self.cash += wage;
+            self.record_transaction(LedgerCategory::Bonus, wage);
+            Some(wage)
+        } else {
+            None
+        };
+        self.eligible_for_side_job_pay = true;
+        self.reset_crop_multipliers();
+        self.expire_year_effects();
+        // Insurance only runs for the year it was bought
+        self.insurance.clear();
+        paid
+    }
+
+    pub fn insurance_policy(&self, coverage: Coverage) -> Option<&InsurancePolicy> {
+        self.insurance.iter().find(|policy| policy.coverage == coverage)
+    }
+
+    /// Forfeits this year's side job wage (e.g. Drought Year, Bad at Taxes).
+    pub fn revoke_side_job_pay(&mut self) {
+        self.eligible_for_side_job_pay = false;
+    }
+
+    /// The player's next harvest draws its operating expense card but brings
+    /// in no income (Bad at Taxes). A year can end before that harvest comes;
+    /// the garnish waits for it rather than lapsing.
+    pub fn garnish_harvest_income(&mut self) {
+        self.harvest_income_garnished = true;
+    }
+
     pub fn update_scoreboard(&mut self) {
-        // Calculate total asset value
-        self.total_asset_value = self.assets.iter().map(|(asset_type, record)| {
-            let asset_value = match asset_type {
-                AssetType::Grain => 2000,
-                AssetType::Hay => 2000,
-                AssetType::Cows => 500,
-                AssetType::Fruit => 5000,
-                AssetType::Tractor => 10000,
-                AssetType::Harvester => 10000,
-            };
-            asset_value * record.quantity.max(0)
-        }).sum();
-
-        // Calculate total income and expenses
-        self.total_income = self.assets.values().map(|record| record.total_income).sum();
-        self.total_expenses = self.assets.values().map(|record| record.total_cost).sum();
-
-        // Net worth will be updated by the game state after ridge values are calculated
-        self.net_worth = self.cash - self.debt + self.total_asset_value + self.total_ridge_value;
-    }
-
-    pub fn add_income(&mut self, asset_type: AssetType, amount: i32) {
+        self.update_scoreboard_with(&ValueTable::default());
+    }
+
+    /// Recomputes asset value, income/expense totals and net worth using `values`.
+    /// `total_ridge_value` is kept as is; the game state refreshes it from the ridges.
+    pub fn update_scoreboard_with(&mut self, values: &ValueTable) {
+        self.total_asset_value = values.assets_value(self);
+
+        self.total_income = self.ledger.income();
+        self.total_expenses = self.ledger.expenses();
+        self.peak_debt = self.peak_debt.max(self.debt);
+
+        self.net_worth = values.net_worth(self);
+    }
+
+    pub fn add_income(&mut self, asset_type: AssetType, amount: Money) {
         if let Some(record) = self.assets.get_mut(&asset_type) {
             record.total_income += amount;
+            self.ledger.record(self.year, LedgerCategory::Harvest, amount);
             self.update_scoreboard();
         }
     }
 
-    pub fn set_ridge_value(&mut self, value: i32) {
+    /// Enters a payment in the ledger for the current year: positive amounts
+    /// came in, negative went out. Cash is left to the caller.
+    pub fn record_transaction(&mut self, category: LedgerCategory, amount: Money) {
+        self.ledger.record(self.year, category, amount);
+        self.total_income = self.ledger.income();
+        self.total_expenses = self.ledger.expenses();
+    }
+
+    /// Borrows `principal` on a new note at `rate`, dated this year.
+    pub fn borrow(&mut self, principal: Money, rate: f32) {
+        self.reconcile_notes(rate);
+        self.debt += principal;
+        self.peak_debt = self.peak_debt.max(self.debt);
+        self.notes.take_out(principal, rate, self.year);
+    }
+
+    /// Pays up to `amount` of principal off `note`, or off the oldest notes
+    /// first. Returns the amount applied; cash is left to the caller.
+    pub fn repay_debt(&mut self, amount: Money, note: Option<usize>, rate: f32) -> Money {
+        self.reconcile_notes(rate);
+        let applied = self.notes.repay(amount.min(self.debt), note);
+        self.debt -= applied;
+        applied
+    }
+
+    /// Catches the notes up with debt that was set directly, writing any
+    /// difference as a note at `rate`.
+    pub fn reconcile_notes(&mut self, rate: f32) {
+        self.notes.reconcile(self.debt, rate, self.year);
+    }
+
+    /// A year's interest on the outstanding notes, with debt no note accounts
+    /// for yet charged at `rate`.
+    pub fn interest_due(&self, rate: f32) -> Money {
+        let mut notes = self.notes.clone();
+        notes.reconcile(self.debt, rate, self.year);
+        notes.interest_due()
+    }
+
+    pub fn set_ridge_value(&mut self, value: Money) {
         self.total_ridge_value = value;
         self.update_scoreboard();
     }
@@ -234,14 +437,14 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Test adding assets
-        player.add_asset(AssetType::Cows, 2, 1000);
+        player.add_asset(AssetType::Cows, 2, Money(1000));
         assert_eq!(player.assets.get(&AssetType::Cows).unwrap().quantity, 2);
-        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_cost, 1000);
+        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_cost, Money(1000));
         
         // Test selling assets
-        player.sell_asset(AssetType::Cows, 1, 600);
+        player.sell_asset(AssetType::Cows, 1, Money(600));
         assert_eq!(player.assets.get(&AssetType::Cows).unwrap().quantity, 1);
-        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_income, 600);
+        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_income, Money(600));
     }
 
     #[test]
@@ -290,14 +493,14 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Add some assets and check scoreboard updates
-        player.add_asset(AssetType::Cows, 2, 1000);
+        player.add_asset(AssetType::Cows, 2, Money(1000));
         player.update_scoreboard();
         
         // Cows are worth 500 each, so total asset value should be 1000
-        assert_eq!(player.total_asset_value, 1000);
+        assert_eq!(player.total_asset_value, Money(1000));
         
         // Test net worth calculation
-        assert_eq!(player.net_worth, 5000 - 5000 + 1000 + 0); // cash - debt + asset_value + ridge_value
+        assert_eq!(player.net_worth, Money(5000) - Money(5000) + Money(1000) + Money::ZERO); // cash - debt + asset_value + ridge_value
     }
 
     #[test]
@@ -313,8 +516,8 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Add and then sell all assets
-        player.add_asset(AssetType::Cows, 2, 1000);
-        player.sell_asset(AssetType::Cows, 2, 600);
+        player.add_asset(AssetType::Cows, 2, Money(1000));
+        player.sell_asset(AssetType::Cows, 2, Money(600));
         
         // Asset should be removed when quantity reaches 0
         assert!(!player.assets.contains_key(&AssetType::Cows));
@@ -325,12 +528,12 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Add asset and record income
-        player.add_asset(AssetType::Grain, 1, 1000);
-        player.add_income(AssetType::Grain, 500);
+        player.add_asset(AssetType::Grain, 1, Money(1000));
+        player.add_income(AssetType::Grain, Money(500));
         
         // Check income is recorded
-        assert_eq!(player.assets.get(&AssetType::Grain).unwrap().total_income, 500);
-        assert_eq!(player.total_income, 500);
+        assert_eq!(player.assets.get(&AssetType::Grain).unwrap().total_income, Money(500));
+        assert_eq!(player.total_income, Money(500));
     }
 
     #[test]
@@ -338,9 +541,9 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Set ridge value and check net worth updates
-        player.set_ridge_value(2000);
-        assert_eq!(player.total_ridge_value, 2000);
-        assert_eq!(player.net_worth, 5000 - 5000 + 0 + 2000); // cash - debt + asset_value + ridge_value
+        player.set_ridge_value(Money(2000));
+        assert_eq!(player.total_ridge_value, Money(2000));
+        assert_eq!(player.net_worth, Money(5000) - Money(5000) + Money::ZERO + Money(2000)); // cash - debt + asset_value + ridge_value
     }
 
     #[test]
@@ -357,6 +560,7 @@ mod tests {
             effect: GameEffect::Special("Test effect".to_string()),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         
         // Add persistent card
@@ -390,8 +594,8 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Add 2 cows and try to sell 3
-        player.add_asset(AssetType::Cows, 2, 1000);
-        player.sell_asset(AssetType::Cows, 3, 600);
+        player.add_asset(AssetType::Cows, 2, Money(1000));
+        player.sell_asset(AssetType::Cows, 3, Money(600));
         
         // Asset record should be removed because quantity reached 0
         assert!(!player.assets.contains_key(&AssetType::Cows));
@@ -433,13 +637,13 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Add different types of assets
-        player.add_asset(AssetType::Grain, 2, 4000);  // 2 * 2000 = 4000 value
-        player.add_asset(AssetType::Cows, 3, 1500);   // 3 * 500 = 1500 value
-        player.add_asset(AssetType::Tractor, 1, 10000); // 1 * 10000 = 10000 value
+        player.add_asset(AssetType::Grain, 2, Money(4000));  // 2 * 2000 = 4000 value
+        player.add_asset(AssetType::Cows, 3, Money(1500));   // 3 * 500 = 1500 value
+        player.add_asset(AssetType::Tractor, 1, Money(10000)); // 1 * 10000 = 10000 value
         
         player.update_scoreboard();
         
-        assert_eq!(player.total_asset_value, 15500); // 4000 + 1500 + 10000
+        assert_eq!(player.total_asset_value, Money(15500)); // 4000 + 1500 + 10000
     }
 
     #[test]
@@ -447,10 +651,10 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Try to add income for an asset we don't have
-        player.add_income(AssetType::Grain, 500);
+        player.add_income(AssetType::Grain, Money(500));
         
         // Should not affect total income
-        assert_eq!(player.total_income, 0);
+        assert_eq!(player.total_income, Money::ZERO);
     }
 
     #[test]
@@ -458,16 +662,16 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Set up various components
-        player.add_asset(AssetType::Grain, 2, 4000);  // 4000 value
-        player.set_ridge_value(2000);                 // 2000 ridge value
-        player.cash = 3000;                          // 3000 cash
-        player.debt = 2000;                          // 2000 debt
+        player.add_asset(AssetType::Grain, 2, Money(4000));  // 4000 value
+        player.set_ridge_value(Money(2000));                 // 2000 ridge value
+        player.cash = Money(3000);                          // 3000 cash
+        player.debt = Money(2000);                          // 2000 debt
         
         player.update_scoreboard();
         
         // Net worth = cash - debt + asset_value + ridge_value
         // 3000 - 2000 + 4000 + 2000 = 7000
-        assert_eq!(player.net_worth, 7000);
+        assert_eq!(player.net_worth, Money(7000));
     }
 
     #[test]
@@ -502,6 +706,7 @@ mod tests {
             effect: GameEffect::Special("Test effect 1".to_string()),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         
         let card2 = Card {
@@ -512,6 +717,7 @@ mod tests {
             effect: GameEffect::Special("Test effect 2".to_string()),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         
         // Add cards with different durations
@@ -599,25 +805,25 @@ mod tests {
     }
 
     #[test]
-    #[should_panic] // This test expects an integer overflow panic
     fn test_asset_value_calculation_edge_cases() {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Test with zero quantities
-        player.add_asset(AssetType::Grain, 0, 0);
+        player.add_asset(AssetType::Grain, 0, Money::ZERO);
         player.update_scoreboard();
-        assert_eq!(player.total_asset_value, 0);
+        assert_eq!(player.total_asset_value, Money::ZERO);
         
-        // Test with negative quantities (should be handled gracefully)
-        player.add_asset(AssetType::Cows, -1, 0);
+        // Test losing more than is held (should be handled gracefully)
+        player.remove_asset(AssetType::Cows, 1);
         player.update_scoreboard();
-        assert_eq!(player.total_asset_value, 0);
+        assert_eq!(player.total_asset_value, Money::ZERO);
         
         // Test with maximum values
-        player.add_asset(AssetType::Tractor, i32::MAX, 0);
+        player.add_asset(AssetType::Tractor, u32::MAX, Money::ZERO);
         player.update_scoreboard();
-        // Note: This will panic due to integer overflow, which is expected
-        // In a real implementation, we might want to handle this case differently
+        // Money saturates rather than overflowing
+        assert!(player.total_asset_value > Money::ZERO);
+        assert!(player.net_worth > Money::ZERO);
     }
 
     #[test]
@@ -625,20 +831,20 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Test with negative cash
-        player.cash = -1000;
+        player.cash = Money(-1000);
         player.update_scoreboard();
-        assert_eq!(player.net_worth, -6000); // -1000 - 5000 + 0 + 0
+        assert_eq!(player.net_worth, Money(-6000)); // -1000 - 5000 + 0 + 0
         
         // Test with negative debt
-        player.debt = -1000;
+        player.debt = Money(-1000);
         player.update_scoreboard();
-        assert_eq!(player.net_worth, 0); // -1000 - (-1000) + 0 + 0 = 0
+        assert_eq!(player.net_worth, Money::ZERO); // -1000 - (-1000) + 0 + 0 = 0
         
         // Test with maximum values
-        player.cash = i32::MAX;
-        player.debt = i32::MAX;
+        player.cash = Money::MAX;
+        player.debt = Money::MAX;
         player.update_scoreboard();
-        assert_eq!(player.net_worth, 0); // MAX - MAX + 0 + 0
+        assert_eq!(player.net_worth, Money::ZERO); // MAX - MAX + 0 + 0
     }
 
     #[test]
@@ -675,6 +881,7 @@ mod tests {
             effect: GameEffect::Special("Test effect 1".to_string()),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         
         let card2 = Card {
@@ -685,6 +892,7 @@ mod tests {
             effect: GameEffect::Special("Test effect 2".to_string()),
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         };
         
         // Test adding cards to hand
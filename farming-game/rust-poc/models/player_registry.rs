@@ -0,0 +1,103 @@
+// src/models/player_registry.rs
+
+use std::ops::{Index, IndexMut};
+use crate::models::player::Player;
+
+/// The players in a game, stored by their sequential id.
+/// Iteration is always in id order.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerRegistry {
+    slots: Vec<Option<Player>>,
+}
+
+impl PlayerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a player under their own `id`, returning any player it replaces.
+    pub fn insert(&mut self, player: Player) -> Option<Player> {
+        let id = player.id;
+        if id >= self.slots.len() {
+            self.slots.resize_with(id + 1, || None);
+        }
+        self.slots[id].replace(player)
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Player> {
+        self.slots.get_mut(id).and_then(Option::take)
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Player> {
+        self.slots.get(id).and_then(Option::as_ref)
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut Player> {
+        self.slots.get_mut(id).and_then(Option::as_mut)
+    }
+
+    /// Mutable access to two different players at once, e.g. to move money
+    /// from one to the other. Returns `None` if either is missing or `a == b`.
+    pub fn get_two_mut(&mut self, a: usize, b: usize) -> Option<(&mut Player, &mut Player)> {
+        if a == b || a >= self.slots.len() || b >= self.slots.len() {
+            return None;
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        let (head, tail) = self.slots.split_at_mut(high);
+        let low_player = head[low].as_mut()?;
+        let high_player = tail[0].as_mut()?;
+        if a < b {
+            Some((low_player, high_player))
+        } else {
+            Some((high_player, low_player))
+        }
+    }
+
+    pub fn contains(&self, id: usize) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter().map(|p| p.id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Player> {
+        self.slots.iter().flatten()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Player> {
+        self.slots.iter_mut().flatten()
+    }
+}
+
+impl Index<usize> for PlayerRegistry {
+    type Output = Player;
+
+    fn index(&self, id: usize) -> &Player {
+        self.get(id).unwrap_or_else(|| panic!("no player with id {}", id))
+    }
+}
+
+impl IndexMut<usize> for PlayerRegistry {
+    fn index_mut(&mut self, id: usize) -> &mut Player {
+        self.get_mut(id).unwrap_or_else(|| panic!("no player with id {}", id))
+    }
+}
+
+impl FromIterator<Player> for PlayerRegistry {
+    fn from_iter<I: IntoIterator<Item = Player>>(players: I) -> Self {
+        let mut registry = Self::new();
+        for player in players {
+            registry.insert(player);
+        }
+        registry
+    }
+}
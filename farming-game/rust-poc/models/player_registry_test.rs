@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::models::{Money, Player, PlayerRegistry, PlayerType};
+
+    fn registry(ids: &[usize]) -> PlayerRegistry {
+        ids.iter()
+            .map(|&id| Player::new(id, format!("Player {}", id), PlayerType::Human))
+            .collect()
+    }
+
+    #[test]
+    fn test_players_are_found_by_id_and_iterated_in_order() {
+        let players = registry(&[2, 0, 5]);
+        assert_eq!(players.len(), 3);
+        assert_eq!(players[5].name, "Player 5");
+        assert!(players.get(1).is_none());
+        assert!(!players.contains(7));
+        assert_eq!(players.ids().collect::<Vec<_>>(), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn test_get_two_mut_returns_players_in_argument_order() {
+        let mut players = registry(&[0, 1, 2]);
+        let starting_cash = players[0].cash;
+        {
+            let (payer, payee) = players.get_two_mut(2, 0).unwrap();
+            payer.cash -= Money(500);
+            payee.cash += Money(500);
+        }
+        assert_eq!(players[2].cash, starting_cash - Money(500));
+        assert_eq!(players[0].cash, starting_cash + Money(500));
+
+        assert!(players.get_two_mut(1, 1).is_none());
+        assert!(players.get_two_mut(1, 9).is_none());
+    }
+
+    #[test]
+    fn test_remove_leaves_other_ids_in_place() {
+        let mut players = registry(&[0, 1, 2]);
+        assert_eq!(players.remove(1).map(|p| p.id), Some(1));
+        assert_eq!(players.ids().collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(players[2].id, 2);
+    }
+}
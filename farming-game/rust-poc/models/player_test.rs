@@ -2,7 +2,7 @@
 mod tests {
     
     // Use correct crate-relative paths
-    use crate::models::{Player, PlayerType}; // These are likely re-exported in models/mod.rs
+    use crate::models::{Money, Player, PlayerType}; // These are likely re-exported in models/mod.rs
     use crate::models::player::EffectType; // Import EffectType from its definition module
     use crate::models::asset::AssetType;
     use crate::models::board::HarvestType;
@@ -19,14 +19,15 @@ mod tests {
             effect,
             default_quantity: 1,
             source: CardSource::BaseGame,
+            hold_until: None,
         }
     }
 
     #[test]
     fn test_player_creation() {
         let player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
-        assert_eq!(player.cash, 5000);
-        assert_eq!(player.debt, 5000);
+        assert_eq!(player.cash, Money(5000));
+        assert_eq!(player.debt, Money(5000));
         assert_eq!(player.land, 20);
         assert_eq!(player.year, 1);
         assert!(player.is_active);
@@ -39,28 +40,28 @@ mod tests {
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
         
         // Test adding assets
-        player.add_asset(AssetType::Cows, 2, 1000);
+        player.add_asset(AssetType::Cows, 2, Money(1000));
         assert_eq!(player.assets.get(&AssetType::Cows).unwrap().quantity, 2);
-        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_cost, 1000);
+        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_cost, Money(1000));
         
         // Test adding to existing asset
-        player.add_asset(AssetType::Cows, 3, 1500);
+        player.add_asset(AssetType::Cows, 3, Money(1500));
         assert_eq!(player.assets.get(&AssetType::Cows).unwrap().quantity, 5);
-        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_cost, 2500);
+        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_cost, Money(2500));
 
         // Test selling partial assets
-        player.sell_asset(AssetType::Cows, 1, 600);
+        player.sell_asset(AssetType::Cows, 1, Money(600));
         assert_eq!(player.assets.get(&AssetType::Cows).unwrap().quantity, 4);
-        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_income, 600);
+        assert_eq!(player.assets.get(&AssetType::Cows).unwrap().total_income, Money(600));
 
         // Test selling exact remaining assets
-        player.sell_asset(AssetType::Cows, 4, 2400);
+        player.sell_asset(AssetType::Cows, 4, Money(2400));
         assert!(player.assets.get(&AssetType::Cows).is_none(), "Asset should be removed after selling all.");
         // TODO: Check total_income update after selling all (Need Player method update?)
 
         // Test selling more than owned
-        player.add_asset(AssetType::Hay, 10, 5000);
-        player.sell_asset(AssetType::Hay, 15, 100); // Try to sell 15, only own 10
+        player.add_asset(AssetType::Hay, 10, Money(5000));
+        player.sell_asset(AssetType::Hay, 15, Money(100)); // Try to sell 15, only own 10
         assert!(player.assets.get(&AssetType::Hay).is_none(), "Asset should be removed after attempting to sell more than owned.");
     }
 
@@ -77,6 +78,26 @@ mod tests {
         assert_eq!(player.get_crop_multiplier(&AssetType::Grain), 1.0);
     }
 
+    #[test]
+    fn test_persistent_effects_stack_by_kind() {
+        let mut player = Player::new(0, "Test Player".to_string(), PlayerType::Human);
+        player.add_persistent_effect(EffectType::HarvestMultiplier { asset: AssetType::Fruit, multiplier: 1.5 }, 1);
+        player.add_persistent_effect(EffectType::HarvestMultiplier { asset: AssetType::Fruit, multiplier: 2.0 }, 1);
+        player.add_persistent_effect(EffectType::ExpenseDiscount(0.2), 1);
+        player.add_persistent_effect(EffectType::ExpenseDiscount(0.5), 2);
+        player.add_persistent_effect(EffectType::IncomeBonus(Money(200)), 1);
+        player.add_persistent_effect(EffectType::IncomeBonus(Money(300)), 2);
+
+        assert_eq!(player.harvest_multiplier(AssetType::Fruit), 3.0, "Multipliers multiply");
+        assert_eq!(player.harvest_multiplier(AssetType::Hay), 1.0);
+        assert_eq!(player.expense_discount(), 0.5, "Only the largest discount counts");
+        assert_eq!(player.income_bonus(), Money(500), "Bonuses add up");
+
+        player.advance_year();
+        assert_eq!(player.harvest_multiplier(AssetType::Fruit), 1.0);
+        assert_eq!((player.expense_discount(), player.income_bonus()), (0.5, Money(300)));
+    }
+
     #[test]
     fn test_persistent_effects_expanded() { // Renamed
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
@@ -123,28 +144,28 @@ mod tests {
     #[test]
     fn test_scoreboard_updates_expanded() { // Renamed
         let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
-        player.cash = 6000;
-        player.debt = 4000;
+        player.cash = Money(6000);
+        player.debt = Money(4000);
         
         // Add asset and income
-        player.add_asset(AssetType::Cows, 2, 1000); // total_cost = 1000
-        player.add_income(AssetType::Cows, 500); // total_income = 500
-        player.set_ridge_value(2000); // total_ridge_value = 2000
+        player.add_asset(AssetType::Cows, 2, Money(1000)); // total_cost = 1000
+        player.add_income(AssetType::Cows, Money(500)); // total_income = 500
+        player.set_ridge_value(Money(2000)); // total_ridge_value = 2000
         player.update_scoreboard();
         
         // Asset value (Cows = 500 each)
-        assert_eq!(player.total_asset_value, 1000, "Total asset value incorrect"); 
+        assert_eq!(player.total_asset_value, Money(1000), "Total asset value incorrect"); 
         // Income (from add_income)
-        assert_eq!(player.total_income, 500, "Total income incorrect"); 
+        assert_eq!(player.total_income, Money(500), "Total income incorrect"); 
         // Expenses (from add_asset)
-        assert_eq!(player.total_expenses, 1000, "Total expenses incorrect");
+        assert_eq!(player.total_expenses, Money(1000), "Total expenses incorrect");
         // Ridge value (from set_ridge_value)
-        assert_eq!(player.total_ridge_value, 2000, "Total ridge value incorrect");
+        assert_eq!(player.total_ridge_value, Money(2000), "Total ridge value incorrect");
         
         // Net worth calculation
         // cash - debt + asset_value + ridge_value
         // 6000 - 4000 + 1000 + 2000 = 5000
-        assert_eq!(player.net_worth, 5000, "Net worth calculation incorrect"); 
+        assert_eq!(player.net_worth, Money(5000), "Net worth calculation incorrect"); 
     }
 
     #[test]
@@ -188,4 +209,34 @@ mod tests {
         assert_eq!(discard_pile.len(), 2, "Card 1 should now be in discard");
         assert_eq!(discard_pile[1].id, card1.id, "Second discarded card should be card 1");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_start_new_year_pays_side_job_wage_when_eligible() {
+        let mut player = Player::new(1, "Test Player".to_string(), PlayerType::Human);
+        let starting_cash = player.cash;
+
+        assert_eq!(player.start_new_year(Money(5000)), Some(Money(5000)));
+        assert_eq!(player.cash, starting_cash + Money(5000));
+        assert_eq!(player.year, 2);
+
+        // A revoked year pays nothing, but eligibility comes back for the next one
+        player.revoke_side_job_pay();
+        assert_eq!(player.start_new_year(Money(5000)), None);
+        assert_eq!(player.cash, starting_cash + Money(5000));
+        assert!(player.eligible_for_side_job_pay);
+    }
+
+    #[test]
+    fn test_players_get_a_color_per_seat() {
+        use crate::models::{GameState, PlayerColor};
+
+        assert_eq!(Player::new(0, "A".to_string(), PlayerType::Human).color, PlayerColor::Red);
+        assert_eq!(Player::new(9, "B".to_string(), PlayerType::Human).color, PlayerColor::Blue, "Colors wrap after six seats");
+
+        let game = GameState::new();
+        let mut colors: Vec<_> = game.players.iter().map(|player| player.color.name()).collect();
+        colors.sort();
+        colors.dedup();
+        assert_eq!(colors.len(), 6, "Every native player has their own color");
+    }
+}
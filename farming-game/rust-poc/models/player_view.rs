@@ -0,0 +1,120 @@
+// src/models/player_view.rs
+// What one player is allowed to see of the game. AI strategies and the
+// network layer read a PlayerView instead of the full GameState so they
+// can't peek at the other hands or the order of the decks.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use crate::cards::card::Card;
+use crate::cards::deck::Deck;
+use crate::config::GameRules;
+use crate::game::{GameError, GamePhase, InsurancePolicy, Weather};
+use crate::models::{AssetRecord, AssetType, BoardTile, GameState, Money, Player, PlayerType};
+
+/// A deck as seen across the table: how many cards are left face down, and
+/// the face-up discard pile.
+#[derive(Debug, Clone)]
+pub struct DeckView {
+    pub draw_pile_len: usize,
+    pub discard_pile: Vec<Card>,
+}
+
+impl DeckView {
+    fn of(deck: &Deck) -> Self {
+        Self { draw_pile_len: deck.draw_pile.len(), discard_pile: deck.discard_pile.clone() }
+    }
+}
+
+/// Another player's public state. Held Farmer's Fate cards are played face up,
+/// but Option to Buy cards in hand only show as a count.
+#[derive(Debug, Clone)]
+pub struct OpponentView {
+    pub id: usize,
+    pub name: String,
+    pub player_type: PlayerType,
+    pub cash: Money,
+    pub debt: Money,
+    pub land: i32,
+    pub position: usize,
+    pub year: u32,
+    pub assets: HashMap<AssetType, AssetRecord>,
+    pub active_persistent_cards: Vec<(Card, u32)>,
+    pub insurance: Vec<InsurancePolicy>,
+    pub net_worth: Money,
+    pub hand_size: usize,
+}
+
+impl OpponentView {
+    pub(crate) fn of(player: &Player) -> Self {
+        Self {
+            id: player.id,
+            name: player.name.clone(),
+            player_type: player.player_type.clone(),
+            cash: player.cash,
+            debt: player.debt,
+            land: player.land,
+            position: player.position,
+            year: player.year,
+            assets: player.assets.clone(),
+            active_persistent_cards: player.active_persistent_cards.clone(),
+            insurance: player.insurance.clone(),
+            net_worth: player.net_worth,
+            hand_size: player.hand.len(),
+        }
+    }
+}
+
+/// The game from `viewer`'s seat: their own player in full, everyone else's
+/// public state, and deck sizes without the card order.
+#[derive(Debug, Clone)]
+pub struct PlayerView {
+    pub viewer: Player,
+    /// Other players, in turn order
+    pub opponents: Vec<OpponentView>,
+    pub turn_order: Vec<usize>,
+    pub current_player_id: usize,
+    pub phase: GamePhase,
+    pub board: Arc<Vec<BoardTile>>,
+    pub rules: GameRules,
+    pub weather_by_year: BTreeMap<u32, Weather>,
+    pub cow_price_by_year: BTreeMap<u32, Money>,
+    pub farmer_fate_deck: DeckView,
+    pub operating_cost_deck: DeckView,
+    pub option_to_buy_deck: DeckView,
+}
+
+impl PlayerView {
+    pub fn opponent(&self, player_id: usize) -> Option<&OpponentView> {
+        self.opponents.iter().find(|o| o.id == player_id)
+    }
+
+    pub fn is_my_turn(&self) -> bool {
+        self.current_player_id == self.viewer.id
+    }
+}
+
+impl GameState {
+    /// Everything `player_id` may know about the game, with hidden information redacted.
+    pub fn view_for(&self, player_id: usize) -> Result<PlayerView, GameError> {
+        let viewer = self.players.get(player_id).ok_or(GameError::PlayerNotFound(player_id))?.clone();
+        let opponents = self.turn_order.iter()
+            .filter(|&&id| id != player_id)
+            .filter_map(|&id| self.players.get(id))
+            .map(OpponentView::of)
+            .collect();
+        Ok(PlayerView {
+            viewer,
+            opponents,
+            turn_order: self.turn_order.clone(),
+            current_player_id: self.turn_order[self.current_turn_index],
+            phase: self.phase,
+            board: Arc::clone(&self.board),
+            rules: self.rules.clone(),
+            weather_by_year: self.weather_by_year.clone(),
+            cow_price_by_year: self.cow_price_by_year.clone(),
+            farmer_fate_deck: DeckView::of(&self.farmer_fate_deck),
+            operating_cost_deck: DeckView::of(&self.operating_cost_deck),
+            option_to_buy_deck: DeckView::of(&self.option_to_buy_deck),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{GameEngine, GameError};
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo".to_string(), "Cy".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 9).unwrap()
+    }
+
+    #[test]
+    fn test_view_shows_own_hand_and_only_opponent_hand_sizes() {
+        let engine = engine();
+        let view = engine.state().view_for(1).unwrap();
+
+        assert_eq!(view.viewer.id, 1);
+        assert_eq!(view.viewer.hand.len(), 2, "Viewer sees their own cards");
+        assert_eq!(view.opponents.iter().map(|o| o.id).collect::<Vec<_>>(), vec![0, 2]);
+        for opponent in &view.opponents {
+            assert_eq!(opponent.hand_size, 2);
+            assert_eq!(opponent.cash, engine.state().players[opponent.id].cash);
+        }
+        assert!(view.opponent(1).is_none(), "Viewer is not their own opponent");
+        assert!(!view.is_my_turn());
+    }
+
+    #[test]
+    fn test_view_hides_deck_order() {
+        let mut engine = engine();
+        engine.roll().unwrap();
+        let state = engine.state();
+        let view = state.view_for(0).unwrap();
+
+        assert_eq!(view.option_to_buy_deck.draw_pile_len, state.option_to_buy_deck.draw_pile.len());
+        assert_eq!(view.farmer_fate_deck.draw_pile_len, state.farmer_fate_deck.draw_pile.len());
+        assert_eq!(view.farmer_fate_deck.discard_pile.len(), state.farmer_fate_deck.discard_pile.len());
+        assert_eq!(view.operating_cost_deck.draw_pile_len, state.operating_cost_deck.draw_pile.len());
+    }
+
+    #[test]
+    fn test_view_for_unknown_player() {
+        assert_eq!(engine().state().view_for(7).unwrap_err(), GameError::PlayerNotFound(7));
+    }
+}
@@ -1,14 +1,17 @@
+use crate::models::money::Money;
+
 #[derive(Debug, Clone)]
 pub struct Ridge {
     pub name: String,
-    pub cost: i32,
+    /// Value of the lease itself, not counting the herd (see `ValueTable::ridge_value`)
+    pub cost: Money,
     pub cow_count: i32,
     pub leased_by: Option<usize>,
     pub initial_cow_count: i32,
 }
 
 impl Ridge {
-    pub fn new(name: String, cost: i32, initial_cow_count: i32) -> Self {
+    pub fn new(name: String, cost: Money, initial_cow_count: i32) -> Self {
         Self {
             name,
             cost,
@@ -40,15 +43,17 @@ impl Ridge {
         }
     }
 
-    pub fn lease(&mut self, player_id: usize, initial_cows: i32) -> Result<(), String> {
+    /// Leases the ridge to `player_id` and stocks it with `cows`, which must
+    /// be at least `min_cows` and fit within its capacity.
+    pub fn lease(&mut self, player_id: usize, cows: i32, min_cows: i32) -> Result<(), String> {
         if self.leased_by.is_some() {
             return Err("Ridge is already leased.".to_string());
         }
-        if initial_cows != self.initial_cow_count {
-            return Err(format!("Invalid initial cow count. Ridge requires {} cows.", self.initial_cow_count));
+        if cows < min_cows || !self.can_add_cows(cows) {
+            return Err(format!("Invalid initial cow count. Ridge takes {} to {} cows.", min_cows, self.initial_cow_count));
         }
         self.leased_by = Some(player_id);
-        self.cow_count = initial_cows;
+        self.cow_count = cows;
         Ok(())
     }
 
@@ -0,0 +1,70 @@
+// src/models/valuation.rs
+
+use std::collections::HashMap;
+use crate::models::asset::AssetType;
+use crate::models::money::Money;
+use crate::models::player::Player;
+use crate::models::ridge::Ridge;
+
+/// Market values used to price a player's holdings for net worth.
+/// Shared by the game engine and the UI so both report the same figures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueTable {
+    /// Value of a single unit (acre, cow, machine) of each asset
+    pub asset_values: HashMap<AssetType, Money>,
+    /// Value per acre of bare land. The base game only values land through
+    /// the crops planted on it, so the standard table uses 0.
+    pub land_per_acre: Money,
+}
+
+impl Default for ValueTable {
+    fn default() -> Self {
+        Self {
+            asset_values: HashMap::from([
+                (AssetType::Grain, Money(2000)),
+                (AssetType::Hay, Money(2000)),
+                (AssetType::Cows, Money(500)),
+                (AssetType::Fruit, Money(5000)),
+                (AssetType::Tractor, Money(10000)),
+                (AssetType::Harvester, Money(10000)),
+                (AssetType::Irrigation, Money(10000)),
+                (AssetType::Barn, Money(8000)),
+                (AssetType::Silo, Money(6000)),
+            ]),
+            land_per_acre: Money::ZERO,
+        }
+    }
+}
+
+impl ValueTable {
+    pub fn asset_value(&self, asset: &AssetType) -> Money {
+        self.asset_values.get(asset).copied().unwrap_or_default()
+    }
+
+    /// Market value of everything the player owns outright.
+    pub fn assets_value(&self, player: &Player) -> Money {
+        player.assets.iter()
+            .map(|(asset, record)| self.asset_value(asset) * record.quantity)
+            .sum()
+    }
+
+    pub fn land_value(&self, player: &Player) -> Money {
+        self.land_per_acre * player.land.max(0)
+    }
+
+    /// Combined value of every ridge leased by `player_id`: each lease plus
+    /// the cows on it now, at the same price as cows on the farm.
+    pub fn ridge_value(&self, player_id: usize, ridges: &[Ridge]) -> Money {
+        let cow_value = self.asset_value(&AssetType::Cows);
+        ridges.iter()
+            .filter(|ridge| ridge.leased_by == Some(player_id))
+            .map(|ridge| ridge.cost + cow_value * ridge.cow_count.max(0))
+            .sum()
+    }
+
+    /// Cash minus debt plus assets, ridges and land.
+    /// Expects `total_asset_value` and `total_ridge_value` to be current.
+    pub fn net_worth(&self, player: &Player) -> Money {
+        player.cash - player.debt + player.total_asset_value + player.total_ridge_value + self.land_value(player)
+    }
+}
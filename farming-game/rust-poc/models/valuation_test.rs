@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::models::{GameState, Money, Player, PlayerRegistry, PlayerType, ValueTable};
+    use crate::models::asset::AssetType;
+    use crate::config::GameRules;
+
+    #[test]
+    fn test_default_table_matches_scoreboard() {
+        let mut player = Player::new(0, "Valuer".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Hay, 10, Money::ZERO);
+        player.add_asset(AssetType::Cows, 4, Money::ZERO);
+
+        let values = ValueTable::default();
+        assert_eq!(values.assets_value(&player), Money(10) * 2000 + Money(4) * 500);
+        assert_eq!(player.total_asset_value, values.assets_value(&player));
+        assert_eq!(player.net_worth, values.net_worth(&player));
+    }
+
+    #[test]
+    fn test_custom_table_values_assets_and_land() {
+        let mut player = Player::new(0, "Valuer".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Grain, 10, Money::ZERO);
+        player.cash = Money(1000);
+        player.debt = Money::ZERO;
+
+        let values = ValueTable {
+            asset_values: HashMap::from([(AssetType::Grain, Money(3000))]),
+            land_per_acre: Money(100),
+        };
+        player.update_scoreboard_with(&values);
+
+        assert_eq!(player.total_asset_value, Money(30000));
+        assert_eq!(player.net_worth, Money(1000) + Money(30000) + player.land * Money(100));
+    }
+
+    #[test]
+    fn test_ridge_values_sum_across_leases() {
+        let mut players = PlayerRegistry::new();
+        players.insert(Player::new(0, "Rancher".to_string(), PlayerType::Human));
+        let mut game_state = GameState::new_with_players(players, vec![0], GameRules::default());
+
+        game_state.ridges[0].lease(0, 50, 25).unwrap();
+        game_state.ridges[1].lease(0, 12, 10).unwrap();
+        let values = &game_state.value_table;
+        let expected = Money(25000) + Money(50) * 500 + Money(10000) + Money(12) * 500;
+        assert_eq!(values.ridge_value(0, &game_state.ridges), expected);
+        assert_eq!(values.ridge_value(1, &game_state.ridges), Money::ZERO);
+
+        game_state.update_player_valuation(0);
+        let player = &game_state.players[0];
+        assert_eq!(player.total_ridge_value, expected);
+        assert_eq!(player.net_worth, game_state.value_table.net_worth(player));
+
+        game_state.ridges[0].cow_count = 40;
+        game_state.update_player_valuation(0);
+        assert_eq!(game_state.players[0].total_ridge_value, expected - Money(10) * 500, "Lost cows lose their value");
+    }
+}
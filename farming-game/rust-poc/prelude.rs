@@ -0,0 +1,11 @@
+// src/prelude.rs
+// The types most code embedding the game needs, for a single glob import:
+// `use farming_game::prelude::*;`. Anything not here (or re-exported from the
+// crate root) is an implementation detail and may change between versions.
+
+pub use crate::config::GameRules;
+pub use crate::game::{
+    Action as PlayerAction, Decision, DecisionKind, DecisionRequest, GameBuilder, GameStateBuilder, GameEngine, GameError,
+    GameEvent, GameId, GameObserver, GameManager, GamePhase, PlayerOutcome, Ranking, Scenario,
+};
+pub use crate::models::{AssetType, GameState, Player, PlayerType};
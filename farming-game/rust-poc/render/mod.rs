@@ -0,0 +1,8 @@
+// src/render/mod.rs
+// Renderers that draw the game without ratatui, for frontends other than the
+// terminal UI.
+
+pub mod text;
+
+#[cfg(test)]
+mod text_test;
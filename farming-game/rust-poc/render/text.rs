@@ -0,0 +1,189 @@
+// src/render/text.rs
+// The game as plain text and markdown, for chat-bot frontends that can't draw
+// the terminal UI: a scoreboard table, where each farmer stands on the board,
+// the prompt for whoever the game is waiting on, and a digest of recent
+// events. Tables go in code blocks so their columns line up; everything else
+// is short markdown lines. Only public information is rendered, so the output
+// can be posted to a shared channel.
+
+use std::fmt::Display;
+use crate::game::{DecisionKind, GameEvent};
+use crate::models::{format_money, format_money_change, GameState, Money};
+
+/// Characters markdown would read as formatting in a player's name.
+const MARKDOWN_SPECIAL: [char; 7] = ['\\', '*', '_', '~', '`', '|', '>'];
+
+/// Events a digest shows by default.
+pub const DIGEST_LENGTH: usize = 10;
+
+/// `text` with markdown formatting characters escaped.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if MARKDOWN_SPECIAL.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// A player's name in bold, e.g. "**Ann**".
+fn player_name(game: &GameState, player_id: usize) -> String {
+    let name = game.players.get(player_id).map_or_else(|| format!("#{}", player_id), |player| player.name.clone());
+    format!("**{}**", escape_markdown(&name))
+}
+
+/// Every player richest first, as a code block: rank with places moved since
+/// the last turn, name, cash, debt, net worth and year. Players out of the
+/// game are marked with ✗ and the one whose turn it is with ▶.
+pub fn scoreboard(game: &GameState) -> String {
+    let current = game.turn_order.get(game.current_turn_index).copied();
+    let mut rows = vec![["#", "Farmer", "Cash", "Debt", "Net worth", "Year"].map(String::from)];
+    for ranking in game.rankings() {
+        let Some(player) = game.players.get(ranking.player_id) else { continue };
+        let arrow = match ranking.movement {
+            m if m > 0 => "↑",
+            m if m < 0 => "↓",
+            _ => "",
+        };
+        let marker = if !player.is_active { "✗ " } else if Some(player.id) == current { "▶ " } else { "" };
+        rows.push([
+            format!("{}{}", ranking.rank, arrow),
+            format!("{}{}", marker, player.name),
+            format_money(player.cash),
+            format_money(player.debt),
+            format_money(player.net_worth),
+            player.year.to_string(),
+        ]);
+    }
+    let widths: Vec<usize> = (0..6).map(|column| rows.iter().map(|row| row[column].chars().count()).max().unwrap_or(0)).collect();
+    let mut table = String::from("```\n");
+    for row in &rows {
+        let cells: Vec<String> = row.iter().zip(&widths).enumerate().map(|(column, (cell, width))| {
+            let pad = " ".repeat(width - cell.chars().count());
+            // Names read left to right; numbers line up on the right
+            if column == 1 { format!("{}{}", cell, pad) } else { format!("{}{}", pad, cell) }
+        }).collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table.push_str("```");
+    table
+}
+
+/// Where `player_id` stands, e.g. "**Ann** · Year 2, March Week 1 · Spring Planting (tile 9)".
+pub fn board_line(game: &GameState, player_id: usize) -> Option<String> {
+    let player = game.players.get(player_id)?;
+    let tile = game.board.get(player.position)?;
+    Some(game.locale.tr("chat.board_line", &[
+        ("player", &player_name(game, player_id)),
+        ("year", &player.year),
+        ("date", &game.player_date(player_id)),
+        ("tile", &escape_markdown(game.locale.tile_name(tile))),
+        ("index", &player.position),
+    ]))
+}
+
+/// A board line for every player still in the game, in turn order.
+pub fn board_positions(game: &GameState) -> String {
+    game.turn_order.iter()
+        .filter(|id| game.players.get(**id).is_some_and(|player| player.is_active))
+        .filter_map(|id| board_line(game, *id))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// What the game is waiting for: the winner once there is one, the question
+/// a player owes an answer to, or whose turn it is.
+pub fn turn_prompt(game: &GameState) -> String {
+    if let Some(winner) = game.winner() {
+        return game.locale.tr("chat.winner", &[("player", &player_name(game, winner))]);
+    }
+    if let Some(request) = game.pending_decision() {
+        let answer = match request.kind {
+            DecisionKind::YesNo => game.locale.tr("chat.answer.yes_no", &[]),
+            DecisionKind::Quantity { min, max } => game.locale.tr("chat.answer.quantity", &[("min", &min), ("max", &max)]),
+            DecisionKind::Roll => game.locale.tr("chat.answer.roll", &[]),
+            DecisionKind::RaiseCash { amount } => game.locale.tr("chat.answer.raise_cash", &[("amount", &amount)]),
+            DecisionKind::Price { max, step } => game.locale.tr("chat.answer.price", &[("max", &max), ("step", &step)]),
+            DecisionKind::Discard { over } => game.locale.tr("chat.answer.discard", &[("over", &over)]),
+        };
+        return game.locale.tr("chat.decision", &[
+            ("player", &player_name(game, request.player_id)),
+            ("prompt", &escape_markdown(&request.prompt)),
+            ("answer", &answer),
+        ]);
+    }
+    let Some(&player_id) = game.turn_order.get(game.current_turn_index) else { return String::new() };
+    game.locale.tr("chat.turn", &[("player", &player_name(game, player_id)), ("date", &game.date_label())])
+}
+
+/// One line for an event, or `None` for bookkeeping players don't need to
+/// read about: applied actions, finished cards, per-action cash changes and
+/// turn ends (the next turn starting says as much).
+pub fn describe_event(game: &GameState, event: &GameEvent) -> Option<String> {
+    let player = player_name(game, event.player_id());
+    let player = ("player", &player as &dyn Display);
+    let tr = |key: &str, args: &[(&str, &dyn Display)]| {
+        let mut all = vec![player];
+        all.extend_from_slice(args);
+        game.locale.tr(key, &all)
+    };
+    let line = match event {
+        GameEvent::ActionApplied { .. } | GameEvent::CardResolved { .. } | GameEvent::CashChanged { .. } | GameEvent::TurnEnded { .. } => return None,
+        GameEvent::DiceRolled { roll, .. } => tr("chat.event.dice_rolled", &[("roll", roll)]),
+        GameEvent::OpeningRolled { roll, .. } => tr("chat.event.opening_rolled", &[("roll", roll)]),
+        GameEvent::HarvestCollected { harvest, income, .. } => {
+            tr("chat.event.harvest", &[("harvest", &game.locale.harvest_name(harvest)), ("income", income)])
+        }
+        GameEvent::CardPlayed { card, .. } => tr("chat.event.card_played", &[("card", &escape_markdown(card))]),
+        GameEvent::TileResolved { tile, cash_change, .. } => {
+            let name = game.board.get(*tile).map_or_else(|| tile.to_string(), |tile| escape_markdown(game.locale.tile_name(tile)));
+            match cash_change {
+                &Money::ZERO => tr("chat.event.landed", &[("tile", &name)]),
+                change => tr("chat.event.landed_for", &[("tile", &name), ("change", &format_money_change(*change))]),
+            }
+        }
+        GameEvent::YearEnded { year, cash, debt, .. } => tr("chat.event.year_ended", &[("year", year), ("cash", cash), ("debt", debt)]),
+        GameEvent::RidgeLeased { ridge, .. } => tr("chat.event.ridge_leased", &[("ridge", &escape_markdown(ridge))]),
+        GameEvent::LoanTaken { amount, debt, .. } => tr("chat.event.loan_taken", &[("amount", amount), ("debt", debt)]),
+        GameEvent::DebtRepaid { amount, debt, .. } => tr("chat.event.debt_repaid", &[("amount", amount), ("debt", debt)]),
+        GameEvent::RidgeCowsLost { ridge, cows, .. } => tr("chat.event.ridge_cows_lost", &[("ridge", &escape_markdown(ridge)), ("cows", cows)]),
+        GameEvent::AshRolled { escaped: true, .. } => tr("chat.event.ash_escaped", &[]),
+        GameEvent::AshRolled { escaped: false, cows, .. } => tr("chat.event.ash_hit", &[("cows", cows)]),
+        GameEvent::GameWon { year, .. } => tr("chat.event.won", &[("year", year)]),
+        GameEvent::TieBreakRolled { roll, .. } => tr("chat.event.tie_break_rolled", &[("roll", roll)]),
+        GameEvent::TieBroken { .. } => tr("chat.event.tie_broken", &[]),
+        GameEvent::PlayerEliminated { .. } => tr("chat.event.eliminated", &[]),
+        GameEvent::TurnStarted { .. } => tr("chat.event.turn_started", &[]),
+    };
+    Some(line)
+}
+
+/// The last `limit` events since `cursor` as a markdown list, noting how
+/// many earlier ones were left out. A bot posting after every action keeps
+/// the event count as its cursor, so each post covers only what's new.
+pub fn event_digest(game: &GameState, cursor: usize, limit: usize) -> String {
+    let lines: Vec<String> = game.events_since(cursor).iter()
+        .filter_map(|event| describe_event(game, event))
+        .collect();
+    let skipped = lines.len().saturating_sub(limit);
+    let mut digest: Vec<String> = Vec::new();
+    if skipped > 0 {
+        digest.push(game.locale.tr("chat.event.earlier", &[("count", &skipped)]));
+    }
+    digest.extend(lines[skipped..].iter().map(|line| format!("- {}", line)));
+    digest.join("\n")
+}
+
+/// Everything a bot posts after a move: the prompt, the standings, where
+/// everyone is and what just happened.
+pub fn status_post(game: &GameState, cursor: usize) -> String {
+    let mut sections = vec![turn_prompt(game), scoreboard(game), board_positions(game)];
+    let digest = event_digest(game, cursor, DIGEST_LENGTH);
+    if !digest.is_empty() {
+        sections.push(digest);
+    }
+    sections.join("\n\n")
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameRules;
+    use crate::game::{Action, GameEngine};
+    use crate::render::text::{board_positions, escape_markdown, event_digest, scoreboard, turn_prompt};
+    use crate::models::Money;
+
+    fn engine() -> GameEngine {
+        let names = vec!["Ann".to_string(), "Bo_b".to_string()];
+        GameEngine::with_seed(&names, GameRules::default(), 9).unwrap()
+    }
+
+    #[test]
+    fn test_scoreboard_is_an_aligned_code_block() {
+        let mut engine = engine();
+        engine.state_mut().players[1].cash = Money(12_000);
+        engine.state_mut().update_player_valuation(1);
+        let board = scoreboard(engine.state());
+        let lines: Vec<&str> = board.lines().collect();
+        assert_eq!(lines.first(), Some(&"```"));
+        assert_eq!(lines.last(), Some(&"```"));
+        assert!(lines[1].starts_with("#  Farmer"));
+        // Richer Bo first; names aren't escaped inside a code block
+        assert!(lines[2].starts_with("1  Bo_b"));
+        assert!(lines[3].starts_with("2  ▶ Ann"));
+        assert_eq!(lines[2].chars().count(), lines[3].chars().count());
+    }
+
+    #[test]
+    fn test_prompt_and_positions_escape_player_names() {
+        let engine = engine();
+        assert!(turn_prompt(engine.state()).starts_with("**Ann**'s turn (Year 1"));
+        let positions = board_positions(engine.state());
+        assert_eq!(positions.lines().count(), 2);
+        assert!(positions.lines().nth(1).unwrap().starts_with("**Bo\\_b** · Year 1, "));
+        assert!(positions.contains("(tile 0)"));
+        assert_eq!(escape_markdown("*a_b*|`c`"), "\\*a\\_b\\*\\|\\`c\\`");
+    }
+
+    #[test]
+    fn test_digest_lists_only_what_happened_since_the_cursor() {
+        let mut engine = engine();
+        let cursor = engine.events().len();
+        engine.apply(Action::Move(2)).unwrap();
+        let digest = event_digest(engine.state(), cursor, 10);
+        assert_eq!(digest.lines().next(), Some("- **Ann** rolled a 2"));
+        assert!(digest.lines().nth(1).unwrap().starts_with("- **Ann** landed on "));
+        assert!(!digest.contains("Move"));
+
+        let all = event_digest(engine.state(), 0, 1);
+        assert_eq!(all.lines().count(), 2);
+        assert!(all.lines().next().unwrap().contains("earlier events"));
+        assert_eq!(event_digest(engine.state(), engine.events().len(), 10), "");
+    }
+}
@@ -0,0 +1,195 @@
+// src/server.rs
+// A REST/JSON API over a `GameManager`, so web and mobile clients can play
+// with this crate as their backend. Games live in memory for as long as the
+// server runs.
+//
+//   POST /games                          start a game: {"players": [...], "seed": 7, "rules": "<rules.toml>"}
+//   GET  /games                          list the games being hosted
+//   GET  /games/:id/players/:player_id   the game as that player may see it
+//   POST /games/:id/actions              {"player_id": 0, "action": "Roll"}
+//
+// There are no accounts: a client names the seat it plays when it acts, and
+// only the player the game is waiting on may act. Views are built from the
+// player's redacted `PlayerView`, so other hands and the deck order stay hidden.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+use crate::config::GameRules;
+use crate::game::{Action, DecisionKind, GameEngine, GameError, GameId, GameManager, GameSummary, SpectatorSnapshot};
+
+/// Games shared between request handlers.
+pub type SharedGames = Arc<Mutex<GameManager>>;
+
+/// Body of `POST /games`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NewGame {
+    pub players: Vec<String>,
+    /// Makes the game reproducible; a fresh game each time if unset
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// House rules as `rules.toml` contents; the defaults if unset
+    #[serde(default)]
+    pub rules: Option<String>,
+}
+
+/// Body of `POST /games/:id/actions`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlayerAction {
+    /// Seat the action is taken for
+    pub player_id: usize,
+    pub action: Action,
+}
+
+/// A card in the viewer's own hand.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HandCard {
+    pub id: usize,
+    pub title: String,
+    pub description: String,
+    pub brief: String,
+}
+
+/// A question the game is waiting on the viewer to answer with `Action::Decide`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Question {
+    pub kind: DecisionKind,
+    pub prompt: String,
+}
+
+/// Response to `GET /games/:id/players/:player_id`: the public table plus
+/// what only the viewer may see.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SeatView {
+    pub player_id: usize,
+    /// Everyone's public standing; its `sequence` counts the game's events,
+    /// so a client polling for changes can compare it with the last one
+    pub table: SpectatorSnapshot,
+    pub hand: Vec<HandCard>,
+    /// Player the game is waiting on: whoever owes an answer, else the current player
+    pub waiting_on: usize,
+    pub question: Option<Question>,
+    /// Moves the viewer can make right now (see `GameEngine::legal_actions`)
+    pub legal_actions: Vec<Action>,
+}
+
+/// Why a request failed, sent back as `{"error": "..."}`.
+#[derive(Debug, Error)]
+pub enum ApiError {
+    #[error("No game {0}")]
+    GameNotFound(GameId),
+    #[error("Invalid rules: {0}")]
+    InvalidRules(String),
+    #[error("It's not player {player_id}'s move; waiting on player {waiting_on}")]
+    NotYourMove { player_id: usize, waiting_on: usize },
+    #[error(transparent)]
+    Game(#[from] GameError),
+}
+
+impl ApiError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::GameNotFound(_) | ApiError::Game(GameError::PlayerNotFound(_)) => StatusCode::NOT_FOUND,
+            ApiError::InvalidRules(_) => StatusCode::BAD_REQUEST,
+            ApiError::NotYourMove { .. } => StatusCode::CONFLICT,
+            ApiError::Game(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status(), Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
+
+/// The API's routes, serving the games in `games`.
+pub fn router(games: SharedGames) -> Router {
+    Router::new()
+        .route("/games", get(list_games).post(create_game))
+        .route("/games/:id/players/:player_id", get(view_game))
+        .route("/games/:id/actions", post(submit_action))
+        .with_state(games)
+}
+
+/// Serves the API on `addr` until the process is stopped.
+pub async fn serve(addr: &str, games: SharedGames) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!(addr, "game server listening");
+    axum::serve(listener, router(games)).await
+}
+
+/// Locks the games. A handler that panicked poisons the lock, but the other
+/// games are untouched, so later requests carry on rather than all failing.
+fn lock(games: &SharedGames) -> MutexGuard<'_, GameManager> {
+    games.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+async fn list_games(State(games): State<SharedGames>) -> Json<Vec<GameSummary>> {
+    Json(lock(&games).list())
+}
+
+async fn create_game(State(games): State<SharedGames>, Json(new_game): Json<NewGame>) -> Result<(StatusCode, Json<GameSummary>), ApiError> {
+    let rules = match &new_game.rules {
+        Some(toml) => GameRules::from_toml(toml).map_err(|e| ApiError::InvalidRules(e.to_string()))?,
+        None => GameRules::default(),
+    };
+    let mut games = lock(&games);
+    let id = match new_game.seed {
+        Some(seed) => games.insert(GameEngine::with_seed(&new_game.players, rules, seed)?),
+        None => games.create_game(&new_game.players, rules)?,
+    };
+    tracing::info!(id, players = new_game.players.len(), "game created");
+    let summary = games.list().into_iter().find(|summary| summary.id == id).ok_or(ApiError::GameNotFound(id))?;
+    Ok((StatusCode::CREATED, Json(summary)))
+}
+
+async fn view_game(State(games): State<SharedGames>, Path((id, player_id)): Path<(GameId, usize)>) -> Result<Json<SeatView>, ApiError> {
+    let games = lock(&games);
+    let engine = games.get(id).ok_or(ApiError::GameNotFound(id))?;
+    Ok(Json(seat_view(engine, player_id)?))
+}
+
+async fn submit_action(State(games): State<SharedGames>, Path(id): Path<GameId>, Json(request): Json<PlayerAction>) -> Result<Json<Vec<String>>, ApiError> {
+    let mut games = lock(&games);
+    let engine = games.get_mut(id).ok_or(ApiError::GameNotFound(id))?;
+    let waiting_on = waiting_on(engine);
+    if request.player_id != waiting_on {
+        return Err(ApiError::NotYourMove { player_id: request.player_id, waiting_on });
+    }
+    let logs = engine.apply(request.action)?;
+    Ok(Json(logs))
+}
+
+/// Whoever owes an answer to a pending decision, else the current player.
+fn waiting_on(engine: &GameEngine) -> usize {
+    engine.state().pending_decision().map_or_else(|| engine.current_player_id(), |request| request.player_id)
+}
+
+/// The game as `player_id` may see it.
+pub fn seat_view(engine: &GameEngine, player_id: usize) -> Result<SeatView, GameError> {
+    let state = engine.state();
+    let view = state.view_for(player_id)?;
+    let hand = view.viewer.hand.iter()
+        .map(|card| HandCard { id: card.id, title: card.title.clone(), description: card.description.clone(), brief: card.description_brief.clone() })
+        .collect();
+    let question = state.pending_decision()
+        .filter(|request| request.player_id == player_id)
+        .map(|request| Question { kind: request.kind, prompt: request.prompt.clone() });
+    Ok(SeatView {
+        player_id,
+        table: SpectatorSnapshot::take(state, state.events.len() as u64),
+        hand,
+        waiting_on: waiting_on(engine),
+        question,
+        legal_actions: engine.legal_actions(player_id),
+    })
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Request, StatusCode};
+    use axum::Router;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+    use crate::server::{router, SharedGames};
+
+    async fn call(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    async fn new_game(app: &Router) -> u64 {
+        let (status, game) = call(app, "POST", "/games", Some(json!({ "players": ["Ann", "Bo"], "seed": 3 }))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        game["id"].as_u64().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_games_are_created_and_listed() {
+        let app = router(SharedGames::default());
+        let id = new_game(&app).await;
+        let (status, games) = call(&app, "GET", "/games", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(games[0]["id"], json!(id));
+        assert_eq!(games[0]["player_names"], json!(["Ann", "Bo"]));
+
+        let (status, error) = call(&app, "POST", "/games", Some(json!({ "players": ["Ann"], "rules": "no_such_rule = 1" }))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(error["error"].as_str().unwrap().starts_with("Invalid rules"));
+    }
+
+    #[tokio::test]
+    async fn test_a_view_shows_only_the_viewers_own_hand() {
+        let app = router(SharedGames::default());
+        let id = new_game(&app).await;
+        let (status, view) = call(&app, "GET", &format!("/games/{}/players/1", id), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(view["player_id"], json!(1));
+        assert!(!view["hand"].as_array().unwrap().is_empty());
+        let ann = view["table"]["standings"].as_array().unwrap().iter().find(|standing| standing["id"] == json!(0)).unwrap();
+        assert!(ann.get("hand").is_none());
+        assert!(ann["hand_size"].as_u64().unwrap() > 0);
+
+        let (status, _) = call(&app, "GET", &format!("/games/{}/players/9", id), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = call(&app, "GET", "/games/42/players/0", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_only_the_player_being_waited_on_may_act() {
+        let app = router(SharedGames::default());
+        let id = new_game(&app).await;
+        let actions = format!("/games/{}/actions", id);
+        let (_, view) = call(&app, "GET", &format!("/games/{}/players/0", id), None).await;
+        let current = view["waiting_on"].as_u64().unwrap();
+        let other = 1 - current;
+        let (_, seat) = call(&app, "GET", &format!("/games/{}/players/{}", id, current), None).await;
+        assert_eq!(seat["legal_actions"], json!(["Roll"]));
+
+        let (status, error) = call(&app, "POST", &actions, Some(json!({ "player_id": other, "action": "Roll" }))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(error["error"].as_str().unwrap().contains("waiting on"));
+
+        let (status, logs) = call(&app, "POST", &actions, Some(json!({ "player_id": current, "action": "Roll" }))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(logs.is_array());
+
+        let (status, _) = call(&app, "POST", &actions, Some(json!({ "player_id": current, "action": { "PayLoan": 1000 } }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
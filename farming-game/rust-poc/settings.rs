@@ -0,0 +1,143 @@
+// src/settings.rs
+// User preferences kept between sessions in the platform's config directory
+// (`~/.config/farming_game/settings.toml` on Linux): the color theme, the key
+// profile, autosaving and the nicknames last played with. Unlike the rules,
+// none of this changes how a game plays out.
+
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+/// Name of the settings file inside the config directory.
+pub const SETTINGS_FILE: &str = "settings.toml";
+
+/// Colors the terminal UI draws with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// The game's own colors
+    #[default]
+    Classic,
+    /// Bright colors only, for washed-out or low-contrast terminals
+    HighContrast,
+    /// The terminal's own colors only
+    Monochrome,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Classic, Theme::HighContrast, Theme::Monochrome];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Classic => "Classic",
+            Theme::HighContrast => "High contrast",
+            Theme::Monochrome => "Monochrome",
+        }
+    }
+
+    /// The theme after this one, wrapping around.
+    pub fn next(self) -> Self {
+        next_of(&Self::ALL, self)
+    }
+}
+
+/// Which keys the terminal UI starts from; a keymap file rebinds on top.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyProfile {
+    /// The keys the game has always used
+    #[default]
+    Classic,
+    /// Classic, plus k and j to move up and down through menus
+    Vim,
+}
+
+impl KeyProfile {
+    pub const ALL: [KeyProfile; 2] = [KeyProfile::Classic, KeyProfile::Vim];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyProfile::Classic => "Classic",
+            KeyProfile::Vim => "Vim",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        next_of(&Self::ALL, self)
+    }
+}
+
+fn next_of<T: Copy + PartialEq>(all: &[T], current: T) -> T {
+    let index = all.iter().position(|item| *item == current).unwrap_or(0);
+    all[(index + 1) % all.len()]
+}
+
+/// Preferences saved in `settings.toml`. Missing fields take their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub theme: Theme,
+    pub keymap: KeyProfile,
+    /// Save the final results as soon as a game ends, without asking
+    pub autosave: bool,
+    /// Nicknames from the last game set up, in seat order, offered as the defaults
+    pub last_players: Vec<String>,
+}
+
+/// The game's config directory, if the platform has one. Everything kept
+/// between sessions lives here.
+pub fn config_dir() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "farming_game").map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+impl Settings {
+    /// Location of the settings file, if the platform has a config directory.
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join(SETTINGS_FILE))
+    }
+
+    /// Loads saved settings. A missing file means the defaults; an unreadable
+    /// one is logged and ignored, so a bad edit never keeps the game from starting.
+    pub fn load() -> Self {
+        let Some(path) = Self::path().filter(|path| path.exists()) else { return Self::default() };
+        Self::load_from(&path).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, path = %path.display(), "ignoring unreadable settings");
+            Self::default()
+        })
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::from_toml(&contents)?)
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("settings always serialize")
+    }
+
+    /// Writes the settings to the config directory, creating it if needed.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory on this platform"))?;
+        self.save_to(&path)
+    }
+
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_toml())
+    }
+
+    /// The nickname last used in `seat`, if there was one.
+    pub fn last_player(&self, seat: usize) -> Option<&str> {
+        self.last_players.get(seat).map(String::as_str).filter(|name| !name.is_empty())
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::settings::{KeyProfile, Settings, Theme};
+
+    #[test]
+    fn test_missing_fields_take_defaults() {
+        let settings = Settings::from_toml("theme = \"high_contrast\"\n").unwrap();
+        assert_eq!(settings, Settings { theme: Theme::HighContrast, ..Settings::default() });
+        assert!(Settings::from_toml("themes = \"classic\"\n").is_err(), "Misspelled settings are caught");
+    }
+
+    #[test]
+    fn test_settings_round_trip_through_a_file() {
+        let settings = Settings {
+            theme: Theme::Monochrome,
+            keymap: KeyProfile::Vim,
+            autosave: true,
+            last_players: vec!["Ann".to_string(), "Bo".to_string()],
+        };
+        let path = std::env::temp_dir()
+            .join(format!("farming_game_settings_{}", std::process::id()))
+            .join("settings.toml");
+
+        settings.save_to(&path).unwrap();
+        assert_eq!(Settings::load_from(&path).unwrap(), settings);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_last_players_by_seat() {
+        let settings = Settings { last_players: vec!["Ann".to_string(), String::new()], ..Settings::default() };
+        assert_eq!(settings.last_player(0), Some("Ann"));
+        assert_eq!(settings.last_player(1), None, "A blank name isn't offered");
+        assert_eq!(settings.last_player(2), None);
+    }
+
+    #[test]
+    fn test_choices_cycle() {
+        assert_eq!(Theme::Classic.next(), Theme::HighContrast);
+        assert_eq!(Theme::Monochrome.next(), Theme::Classic);
+        assert_eq!(KeyProfile::Vim.next(), KeyProfile::Classic);
+    }
+}
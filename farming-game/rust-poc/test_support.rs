@@ -0,0 +1,19 @@
+// src/test_support.rs
+// Fixtures shared between the unit tests.
+
+use crate::cards::card::{Card, CardSource};
+use crate::game::GameEffect;
+
+/// A base game card numbered `id` that does nothing but `effect`.
+pub(crate) fn card_with(id: usize, effect: GameEffect) -> Card {
+    Card {
+        id,
+        title: "Test Card".to_string(),
+        description: "Test card".to_string(),
+        description_brief: "Test card".to_string(),
+        effect,
+        default_quantity: 1,
+        source: CardSource::BaseGame,
+        hold_until: None,
+    }
+}
@@ -0,0 +1,99 @@
+// Fuzzes the engine with random action sequences and checks the game state
+// invariants after every action.
+
+use farming_game::config::GameRules;
+use farming_game::game::{Action, GameEngine};
+use farming_game::models::Money;
+use proptest::prelude::*;
+
+/// Random player intent; turned into a concrete `Action` against the current state.
+#[derive(Debug, Clone)]
+enum Intent {
+    Move(u32),
+    Exercise { hand_index: usize, confirm_loan: bool },
+    PayLoan(i32),
+    EndTurn,
+    /// Answer a pending decision the way the AI would
+    Decide,
+}
+
+fn intent() -> impl Strategy<Value = Intent> {
+    prop_oneof![
+        4 => (1u32..=6).prop_map(Intent::Move),
+        2 => (0usize..4, any::<bool>()).prop_map(|(hand_index, confirm_loan)| Intent::Exercise { hand_index, confirm_loan }),
+        1 => (1i32..=20).prop_map(|n| Intent::PayLoan(n * 500)),
+        3 => Just(Intent::EndTurn),
+        2 => Just(Intent::Decide),
+    ]
+}
+
+fn to_action(engine: &GameEngine, intent: Intent) -> Action {
+    match intent {
+        Intent::Move(roll) => Action::Move(roll),
+        Intent::Exercise { hand_index, confirm_loan } => {
+            let player = &engine.state().players[engine.current_player_id()];
+            match player.hand.get(hand_index % player.hand.len().max(1)) {
+                Some(card) => Action::ExerciseOption { card_id: card.id, confirm_loan },
+                None => Action::Roll,
+            }
+        }
+        Intent::PayLoan(amount) => Action::PayLoan(Money::from(amount)),
+        Intent::EndTurn => Action::EndTurn,
+        Intent::Decide => match engine.state().pending_decision().and_then(|request| engine.state().ai_decision(request)) {
+            Some(decision) => Action::Decide(decision),
+            None => Action::Roll,
+        },
+    }
+}
+
+/// Answers whatever a roll left pending the way the AI would.
+fn answer_decisions(engine: &mut GameEngine) {
+    while let Some(request) = engine.state().pending_decision().cloned() {
+        let decision = engine.state().ai_decision(&request).unwrap();
+        engine.apply(Action::Decide(decision)).unwrap();
+    }
+}
+
+fn names(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("Player {}", i + 1)).collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn invariants_hold_for_random_play(
+        seed in any::<u64>(),
+        // Ten Option to Buy cards cover the two-card opening deal for up to five players
+        players in 2usize..=5,
+        intents in prop::collection::vec(intent(), 300..600),
+    ) {
+        let mut engine = GameEngine::with_seed(&names(players), GameRules::default(), seed).unwrap();
+        prop_assert_eq!(engine.state().check_invariants(), Ok(()));
+
+        for (step, intent) in intents.into_iter().enumerate() {
+            let action = to_action(&engine, intent);
+            // Rejected actions (no debt, can't afford, wrong season) are fine;
+            // they just must not leave the state inconsistent
+            let _ = engine.apply(action.clone());
+            if let Err(violations) = engine.state().check_invariants() {
+                prop_assert!(false, "step {} ({:?}) broke invariants: {:?}", step, action, violations);
+            }
+        }
+    }
+}
+
+#[test]
+fn invariants_hold_for_hundreds_of_plain_turns() {
+    let mut engine = GameEngine::with_seed(&names(4), GameRules::default(), 1802).unwrap();
+    // Each turn's books are also replayed against a snapshot from its start
+    engine.set_autosnapshots(true);
+    for turn in 0..500 {
+        engine.apply(Action::Roll).unwrap();
+        answer_decisions(&mut engine);
+        engine.apply(Action::EndTurn).unwrap();
+        if let Err(violations) = engine.state().check_invariants() {
+            panic!("turn {} broke invariants: {:?}", turn, violations);
+        }
+    }
+}
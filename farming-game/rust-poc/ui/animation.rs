@@ -0,0 +1,114 @@
+// src/ui/animation.rs
+
+use std::time::{Duration, Instant};
+
+/// How fast turns play out on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+    /// No animation; turns resolve as soon as the die is rolled
+    Instant,
+}
+
+impl GameSpeed {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameSpeed::Slow => "slow",
+            GameSpeed::Normal => "normal",
+            GameSpeed::Fast => "fast",
+            GameSpeed::Instant => "instant",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "slow" => Some(GameSpeed::Slow),
+            "normal" => Some(GameSpeed::Normal),
+            "fast" => Some(GameSpeed::Fast),
+            "instant" => Some(GameSpeed::Instant),
+            _ => None,
+        }
+    }
+
+    /// Next speed when cycling with `g`.
+    pub fn next(&self) -> Self {
+        match self {
+            GameSpeed::Slow => GameSpeed::Normal,
+            GameSpeed::Normal => GameSpeed::Fast,
+            GameSpeed::Fast => GameSpeed::Instant,
+            GameSpeed::Instant => GameSpeed::Slow,
+        }
+    }
+
+    /// How long the die tumbles before showing the roll.
+    fn dice_duration(&self) -> Duration {
+        match self {
+            GameSpeed::Slow => Duration::from_millis(1200),
+            GameSpeed::Normal => Duration::from_millis(800),
+            GameSpeed::Fast => Duration::from_millis(400),
+            GameSpeed::Instant => Duration::ZERO,
+        }
+    }
+
+    /// Delay between each tile the token steps onto.
+    fn step_delay(&self) -> Duration {
+        match self {
+            GameSpeed::Slow => Duration::from_millis(400),
+            GameSpeed::Normal => Duration::from_millis(250),
+            GameSpeed::Fast => Duration::from_millis(100),
+            GameSpeed::Instant => Duration::ZERO,
+        }
+    }
+}
+
+/// How often the tumbling die changes face.
+const DICE_FACE_INTERVAL: Duration = Duration::from_millis(80);
+
+/// A die roll followed by the player's token stepping tile by tile. The turn
+/// itself is only resolved once the animation finishes; everything here is
+/// derived from the time since `started`, so each frame just asks where things are.
+#[derive(Debug, Clone)]
+pub struct TurnAnimation {
+    pub player_id: usize,
+    pub roll: u32,
+    from: usize,
+    board_len: usize,
+    speed: GameSpeed,
+    started: Instant,
+}
+
+impl TurnAnimation {
+    pub fn new(player_id: usize, roll: u32, from: usize, board_len: usize, speed: GameSpeed, now: Instant) -> Self {
+        Self { player_id, roll, from, board_len, speed, started: now }
+    }
+
+    pub fn is_rolling(&self, now: Instant) -> bool {
+        now.duration_since(self.started) < self.speed.dice_duration()
+    }
+
+    /// Face to draw on the die: tumbling while rolling, then the roll.
+    pub fn dice_face(&self, now: Instant) -> u32 {
+        if !self.is_rolling(now) {
+            return self.roll;
+        }
+        let tumbles = now.duration_since(self.started).as_millis() / DICE_FACE_INTERVAL.as_millis();
+        // Stride of 5 keeps consecutive faces different
+        ((tumbles as u32 * 5 + self.roll) % 6) + 1
+    }
+
+    /// Tile the token is drawn on.
+    pub fn token_position(&self, now: Instant) -> usize {
+        let moving_for = now.duration_since(self.started).saturating_sub(self.speed.dice_duration());
+        let step_delay = self.speed.step_delay().as_millis().max(1);
+        let steps = (moving_for.as_millis() / step_delay).min(self.roll as u128) as usize;
+        (self.from + steps) % self.board_len.max(1)
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        let total = self.speed.dice_duration() + self.speed.step_delay() * self.roll;
+        now.duration_since(self.started) >= total
+    }
+}
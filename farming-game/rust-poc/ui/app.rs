@@ -1,25 +1,75 @@
 // src/ui/app.rs
 
+use std::collections::BTreeSet;
+use std::fmt::Display;
 use std::io;
-use std::time::Duration;
-use crossterm::event::{self, Event, KeyCode};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
 use ratatui::{
     prelude::{Constraint, Direction, Layout, Rect, Frame, Margin, Style, Color},
     widgets::{Paragraph},
 };
-use rand::Rng;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 
 use crate::ui::terminal::Tui;
 use crate::ui::widgets::scoreboard::render_scoreboard;
-use crate::ui::widgets::log::render_log;
+use crate::ui::widgets::log::{self as log_widget, render_log, LogEntry, LogView};
 use crate::ui::widgets::option_dialog::render_option_dialog;
 use crate::ui::widgets::turn_menu::render_turn_menu;
 use crate::ui::widgets::loan_payment::render_loan_payment;
-use crate::models::GameState;
-use crate::game::GameEffect;
-use crate::config::WINNING_NET_WORTH;
+use crate::ui::widgets::auction::render_auction;
+use crate::ui::widgets::insurance::render_insurance;
+use crate::ui::widgets::dice::render_dice;
+use crate::ui::widgets::deck_stats::{self, render_deck_stats};
+use crate::ui::widgets::draw_odds::{self, render_draw_odds};
+use crate::ui::widgets::countdown::{self, render_countdown};
+use crate::ui::widgets::game_board;
+use crate::ui::widgets::decision::render_decision;
+use crate::ui::widgets::raise_cash::render_raise_cash;
+use crate::ui::widgets::discard::render_discard;
+use crate::ui::widgets::market::render_market;
+use crate::ui::widgets::livestock::render_livestock;
+use crate::ui::widgets::achievements::render_achievements;
+use crate::ui::widgets::options::{self as options_widget, render_options};
+use crate::ui::widgets::pause_menu::{self, render_pause_menu, PauseRow};
+use crate::ui::theme::apply_theme;
+use crate::ui::animation::{GameSpeed, TurnAnimation};
+use crate::ui::layout::{self, LayoutPrefs};
+use crate::ui::colors::player_name_colors;
+use crate::ui::keymap::{KeyAction, Keymap};
+use crate::ui::widgets::help::render_help;
+use crate::ui::widgets::turn_summary::render_turn_summary;
+use crate::ui::widgets::turn_history::{self, render_turn_history};
+use crate::ui::widgets::tie_break::{self, render_tie_break};
+use crate::ui::widgets::debug_console::render_debug_console;
+use crate::ui::widgets::eliminated::render_eliminated;
+use crate::ui::widgets::game_over::{self, render_game_over};
+use crate::achievements::Achievements;
+use crate::settings::Settings;
+use crate::audio::Audio;
+use crate::debug::DebugConsole;
+use crate::models::{AssetType, GameState, Money, PlayerType};
+use crate::game::{Action, Auction, Coverage, Decision, DecisionKind, Dice, GameEffect, GameError, PlayerOutcome, PlayerSnapshot, SnapshotWriter, TieBreak, TurnSummary};
+
+/// How often the app redraws and advances animations.
+const TICK_RATE: Duration = Duration::from_millis(33);
+
+/// Where the victory screen saves the final results.
+const RESULTS_PATH: &str = "farming_game_results.csv";
+
+/// Where the pause menu saves the ledgers of the game so far.
+const LEDGER_PATH: &str = "farming_game_ledger.csv";
+
+/// Actions that work on every screen: quitting, help and the log.
+const GLOBAL_ACTIONS: [KeyAction; 12] = [KeyAction::Quit, KeyAction::Help, KeyAction::ScrollUp, KeyAction::ScrollDown,
+    KeyAction::ScrollPageUp, KeyAction::ScrollPageDown, KeyAction::ScrollTop, KeyAction::ScrollBottom,
+    KeyAction::SearchLog, KeyAction::NextMatch, KeyAction::PrevMatch, KeyAction::FilterLog];
+
+/// Panel layout actions, which also work on every screen.
+const LAYOUT_ACTIONS: [KeyAction; 9] = [KeyAction::ToggleLog, KeyAction::FullLog, KeyAction::ToggleScoreboard, KeyAction::SortScoreboard,
+    KeyAction::DrawOdds, KeyAction::ShrinkBoard, KeyAction::GrowBoard, KeyAction::GameSpeed, KeyAction::ToggleSound];
 
 /// Helper function to create a centered rect with fixed dimensions, inset by 1 cell.
 fn centered_fixed_rect(width: u16, height: u16, r: Rect) -> Rect {
@@ -51,7 +101,7 @@ fn centered_fixed_rect(width: u16, height: u16, r: Rect) -> Rect {
 }
 
 /// Game UI states
-enum UiState {
+pub(crate) enum UiState {
     /// Normal gameplay
     Game,
     /// Turn menu after player has moved
@@ -66,7 +116,105 @@ enum UiState {
     /// Loan payment screen
     LoanPayment {
         player_id: usize,
-        payment_amount: i32,
+        payment_amount: Money,
+    },
+    /// Buying this year's crop/livestock insurance
+    Insurance {
+        player_id: usize,
+        selected_index: usize,
+    },
+    /// Selling cows at this year's market price
+    Market {
+        player_id: usize,
+        quantity: u32,
+    },
+    /// Moving cows between the farm and the player's leased ridges
+    Livestock {
+        player_id: usize,
+        /// Index into the player's leased ridges
+        selected: usize,
+        /// Cows to move up to the ridge; negative brings them down to the farm
+        cows: i32,
+    },
+    /// Dice tumbling and the token stepping; the turn resolves when it ends
+    Animating {
+        animation: TurnAnimation,
+    },
+    /// Other players bidding on an O.T.B. card the holder put up for auction
+    Auction {
+        auction: Auction,
+        bid_amount: Money,
+    },
+    /// A card effect waiting on a player's answer (see `GameState::pending_decision`)
+    Decision {
+        /// Turn to return to once every decision is answered
+        turn_player_id: usize,
+        quantity: i32,
+        price: Money,
+    },
+    /// A player short of cash for a bill, selling assets before the bank lends the rest
+    RaiseCash {
+        /// Turn to return to once every decision is answered
+        turn_player_id: usize,
+        /// Row of `GameState::sellable_assets` picked
+        selected: usize,
+        quantity: u32,
+    },
+    /// A player over the hand limit picking cards to discard before their turn ends
+    Discard {
+        /// Turn to end once they're down to the limit
+        turn_player_id: usize,
+        /// Card in their hand picked
+        selected: usize,
+    },
+    /// Achievements unlocked across games, opened between turns
+    Achievements,
+    /// One player's turns in one game year, opened between turns
+    TurnHistory {
+        player_id: usize,
+        year: u32,
+        /// Turns of the year scrolled past
+        scroll: usize,
+    },
+    /// Theme, key profile, speed and autosave, opened between turns or from the pause menu
+    Options {
+        /// Row of `options_widget::ROWS` picked
+        selected: usize,
+        /// Screen under the pause menu, when opened from there
+        paused: Option<Box<UiState>>,
+    },
+    /// Pause menu over `resume`: resume, options, save or quit
+    Paused {
+        /// Row of `pause_menu::ROWS` picked
+        selected: usize,
+        /// Asking whether to quit and lose the game
+        confirm_quit: bool,
+        /// Screen to go back to, just as it was left
+        resume: Box<UiState>,
+        /// Where the ledgers were saved, once they have been
+        saved: Option<String>,
+    },
+    /// Recap of the turn that just ended
+    TurnSummary {
+        summary: TurnSummary,
+    },
+    /// A player just went bankrupt: keep watching, or leave the table
+    Eliminated {
+        player_id: usize,
+        /// Recap to show afterwards
+        summary: Option<TurnSummary>,
+    },
+    /// More than one player won in the same year: how it was settled, before the standings
+    TieBreak {
+        tie_break: TieBreak,
+    },
+    /// Someone won: final standings, with a rematch or export on offer
+    GameOver {
+        winner: usize,
+        /// When the victory screen went up, for its animation
+        started: Instant,
+        /// Where the results were saved, once they have been
+        exported: Option<String>,
     },
 }
 
@@ -74,34 +222,111 @@ enum UiState {
 pub struct App {
     running: bool, // Flag to control the main loop
     game_state: GameState, // Add GameState to App
-    log_entries: Vec<String>, // Add log storage
+    log_entries: Vec<LogEntry>, // Log lines with their categories
     log_scroll_offset: usize, // Track log scroll position
-    ui_state: UiState, // Current UI state
+    log_view: LogView, // Log filter and search
+    pub(crate) ui_state: UiState, // Current UI state
     rng: StdRng, // Add dedicated RNG
+    layout_prefs: LayoutPrefs, // Panel layout, persisted between sessions
+    achievements: Achievements, // Milestones unlocked across games, persisted
+    audio: Audio, // Sound cues for game events; silent without the audio feature
+    events_seen: usize, // How far into the game's event stream achievements and sounds have looked
+    keymap: Keymap, // Keys for each action, from the keymap file or the defaults
+    settings: Settings, // Theme, key profile and autosave, persisted between sessions
+    terminal_area: Option<Rect>, // Terminal size as of the last resize; None until the UI starts
+    show_help: bool, // Help overlay listing the active keymap
+    turn_start: Option<PlayerSnapshot>, // Current player's state when they rolled, for the turn summary
+    spectator: Option<SnapshotWriter>, // Public game state written out after every turn, for spectators
+    debug: Option<DebugConsole>, // Developer console, only in --debug games
+    left_table: BTreeSet<usize>, // Bankrupt players who chose not to keep watching; off the scoreboard
 }
 
 impl App {
     /// Creates a new App instance from a pre-initialized GameState.
     pub fn new(game_state: GameState) -> Self { // Accept GameState
+        Self::with_settings(game_state, Settings::load())
+    }
+
+    /// Like `new`, with settings the caller already loaded.
+    pub fn with_settings(game_state: GameState, settings: Settings) -> Self {
         let mut app = Self {
             running: true,
-            game_state: game_state.clone(), // Clone to access first player info
+            game_state,
             log_entries: Vec::new(), // Initialize empty logs
             log_scroll_offset: 0,
+            log_view: LogView::default(),
             ui_state: UiState::Game,
             rng: StdRng::from_entropy(), // Initialize RNG from entropy
+            layout_prefs: LayoutPrefs::load(),
+            achievements: Achievements::load(),
+            audio: Audio::new(),
+            events_seen: 0,
+            keymap: Keymap::load_over(settings.keymap),
+            settings,
+            terminal_area: None,
+            show_help: false,
+            turn_start: None,
+            spectator: None,
+            debug: None,
+            left_table: BTreeSet::new(),
         };
+        app.begin_game();
+        app
+    }
+
+    /// Sets the table up for the game in `game_state`, from its first turn.
+    fn begin_game(&mut self) {
+        // The decision dialog can show the terms before anyone is made to borrow
+        self.game_state.confirm_forced_loans = true;
+        self.log_entries.clear();
+        self.log_scroll_offset = 0;
+        self.ui_state = UiState::Game;
+        self.events_seen = 0;
+        self.turn_start = None;
+        self.left_table.clear();
 
         // Add initial logs without the scrolling instructions
-        app.add_log_entry("Game initialized.".to_string());
-        app.add_log_entry("Scoreboard TUI setup complete.".to_string());
-        app.add_log_entry("".to_string()); // Add blank line after instructions
+        self.add_log_entry(self.tr("app.initialized", &[]));
+        self.add_log_entry(self.tr("app.setup_complete", &[]));
+        self.add_log_entry("".to_string()); // Add blank line after instructions
 
         // Add first player's turn message
-        let first_player = &app.game_state.players[&app.game_state.turn_order[0]].name;
-        app.add_log_entry(format!("--- {}'s turn (Press Enter to roll) ---", first_player));
-        
-        app
+        let first_player = &self.game_state.players[self.game_state.turn_order[0]].name;
+        self.add_log_entry(self.tr("app.turn_header", &[("player", first_player)]));
+    }
+
+    /// The game as it stands, e.g. for exporting the ledgers once it's over.
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state
+    }
+
+    /// Preferences as the options screen last left them.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Writes a spectator snapshot now and after every turn from here on.
+    pub fn spectate(&mut self, spectator: SnapshotWriter) {
+        self.spectator = Some(spectator);
+        self.write_spectator_snapshot();
+    }
+
+    /// Lets the debug console be opened (`--debug`).
+    pub fn enable_debug(&mut self) {
+        self.debug = Some(DebugConsole::default());
+    }
+
+    fn write_spectator_snapshot(&mut self) {
+        let Some(spectator) = self.spectator.as_mut() else { return };
+        // Like saving achievements, a failed write isn't worth interrupting the game
+        if let Err(e) = spectator.write(&self.game_state) {
+            tracing::warn!(error = %e, path = %spectator.path().display(), "couldn't write spectator snapshot");
+        }
+    }
+
+    /// Message from the game's locale.
+    fn tr(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        self.game_state.locale.tr(key, args)
     }
 
     /// Helper function to capitalize the first letter of a message
@@ -140,7 +365,7 @@ impl App {
         };
         
         // Add the message
-        self.log_entries.push(message);
+        self.log_entries.push(LogEntry::new(message));
         
         // Only auto-scroll if we were already at the bottom
         if was_at_bottom {
@@ -151,219 +376,698 @@ impl App {
 
     /// Runs the main application loop.
     pub fn run(&mut self, tui: &mut Tui) -> io::Result<()> {
+        self.on_resize(tui.size()?);
         while self.running {
             // 1. Draw the UI
             tui.draw(|frame| {
                 self.ui(frame);
+                apply_theme(self.settings.theme, frame.buffer_mut());
             })?;
 
-            // 2. Handle events
-            if event::poll(Duration::from_millis(50))? { // Poll for events with a timeout
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == event::KeyEventKind::Press {
-                        // Handle scrolling in all UI states with dedicated keys
-                        match key.code {
-                            _ => {
-                                // Regular state-specific key handling with shift modifiers for scroll
-                                if key.modifiers.contains(event::KeyModifiers::SHIFT) {
-                                    match key.code {
-                                        KeyCode::Up => self.scroll_log_up(),
-                                        KeyCode::Down => self.scroll_log_down(),
-                                        KeyCode::PageUp => self.scroll_log_page_up(),
-                                        KeyCode::PageDown => self.scroll_log_page_down(),
-                                        KeyCode::Home => self.scroll_log_to_top(),
-                                        KeyCode::End => self.scroll_log_to_bottom(),
-                                        _ => {}
-                                    }
-                                } else {
-                                    // Regular state-specific key handling
-                                    match &mut self.ui_state {
-                                        UiState::Game => match key.code {
-                                            KeyCode::Char('q') => self.quit(), // Quit on 'q'
-                                            KeyCode::Enter => self.advance_turn(),
-                                            _ => {} // Handle other keys later
-                                        },
-                                        UiState::TurnMenu { player_id } => {
-                                            let current_player_id = *player_id;
-                                            match key.code {
-                                                KeyCode::Char('q') => self.quit(),
-                                                KeyCode::Char('e') | KeyCode::Char('E') => {
-                                                    // End turn and move to next player
-                                                    self.end_turn();
-                                                },
-                                                KeyCode::Char('o') | KeyCode::Char('O') => {
-                                                    // Check if player has O.T.B. cards
-                                                    let option_cards = self.game_state.get_option_to_buy_cards(current_player_id);
-                                                    if !option_cards.is_empty() && self.game_state.can_exercise_option_to_buy(current_player_id) {
-                                                        // Show O.T.B. dialog
-                                                        self.ui_state = UiState::OptionToBuy {
-                                                            player_id: current_player_id,
-                                                            selected_index: 0,
-                                                        };
-                                                    } else {
-                                                        self.add_log_entry("O.T.B. unavailable at this time of the year.".to_string());
-                                                    }
-                                                },
-                                                KeyCode::Char('p') | KeyCode::Char('P') => {
-                                                    // Only show loan payment dialog if player has cash and debt
-                                                    let player = &self.game_state.players[&current_player_id];
-                                                    if player.cash > 0 && player.debt > 0 {
-                                                        // Show loan payment dialog - start with 10% of debt or cash (whichever is less)
-                                                        let default_payment = (player.debt / 10).min(player.cash);
-                                                        self.ui_state = UiState::LoanPayment {
-                                                            player_id: current_player_id,
-                                                            payment_amount: default_payment,
-                                                        };
-                                                    } else {
-                                                        self.add_log_entry("Cannot pay loans - no cash available.".to_string());
-                                                    }
-                                                },
-                                                _ => {}
-                                            }
-                                        },
-                                        UiState::OptionToBuy { player_id, selected_index } => match key.code {
-                                            KeyCode::Char('q') => self.quit(),
-                                            KeyCode::Char('e') => {
-                                                // Return to turn menu
-                                                self.ui_state = UiState::TurnMenu {
-                                                    player_id: *player_id
-                                                };
-                                            },
-                                            KeyCode::Esc => {
-                                                // Return to turn menu
-                                                self.ui_state = UiState::TurnMenu {
-                                                    player_id: *player_id
-                                                };
-                                            },
-                                            KeyCode::Up => {
-                                                // Move selection up
-                                                let cards = self.game_state.get_option_to_buy_cards(*player_id);
-                                                if !cards.is_empty() && *selected_index > 0 {
-                                                    *selected_index -= 1;
-                                                }
-                                            },
-                                            KeyCode::Down => {
-                                                // Move selection down
-                                                let cards = self.game_state.get_option_to_buy_cards(*player_id);
-                                                if !cards.is_empty() && *selected_index < cards.len() - 1 {
-                                                    *selected_index += 1;
-                                                }
-                                            },
-                                            KeyCode::Enter => {
-                                                // Process the option to buy
-                                                let player_id = *player_id;
-                                                let selected_idx = *selected_index;
-                                                self.process_option_to_buy(player_id, selected_idx);
-                                            },
-                                            _ => {}
-                                        },
-                                        UiState::LoanPayment { player_id, payment_amount } => match key.code {
-                                            KeyCode::Char('q') => self.quit(),
-                                            KeyCode::Char('e') => {
-                                                // Return to turn menu
-                                                self.ui_state = UiState::TurnMenu {
-                                                    player_id: *player_id
-                                                };
-                                            },
-                                            KeyCode::Esc => {
-                                                // Return to turn menu
-                                                self.ui_state = UiState::TurnMenu {
-                                                    player_id: *player_id
-                                                };
-                                            },
-                                            KeyCode::Up => {
-                                                // Increase payment - step by 100
-                                                let player = &self.game_state.players[player_id];
-                                                *payment_amount = (*payment_amount + 100).min(player.cash.min(player.debt));
-                                            },
-                                            KeyCode::Down => {
-                                                // Decrease payment - step by 100, minimum 0
-                                                *payment_amount = (*payment_amount - 100).max(0);
-                                            },
-                                            KeyCode::PageUp => {
-                                                // Increase payment - step by 1000
-                                                let player = &self.game_state.players[player_id];
-                                                *payment_amount = (*payment_amount + 1000).min(player.cash.min(player.debt));
-                                            },
-                                            KeyCode::PageDown => {
-                                                // Decrease payment - step by 1000, minimum 0
-                                                *payment_amount = (*payment_amount - 1000).max(0);
-                                            },
-                                            KeyCode::Enter => {
-                                                // Process loan payment
-                                                let player_id = *player_id;
-                                                let payment = *payment_amount;
-                                                self.pay_loan(player_id, payment);
-                                                
-                                                // Return to turn menu
-                                                self.ui_state = UiState::TurnMenu {
-                                                    player_id
-                                                };
-                                            },
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                            }
-                        }
+            // 2. Handle events, waiting at most one tick so animations keep moving
+            if event::poll(TICK_RATE)? {
+                match event::read()? {
+                    Event::Key(key) if key.kind == event::KeyEventKind::Press => self.handle_key(key),
+                    Event::Resize(width, height) => {
+                        // Resizing clears the screen, so nothing drawn for the old size is left behind
+                        let area = Rect::new(0, 0, width, height);
+                        tui.resize(area)?;
+                        self.on_resize(area);
                     }
+                    _ => {}
                 }
             }
-            // Add other event handling (mouse, resize) later if needed
+
+            // 3. Advance time-based state; the game waits while it can't be seen
+            if !self.too_small() {
+                self.on_tick(Instant::now());
+            }
         }
         Ok(())
     }
+
+    /// Records the terminal's new size. Every panel and popup is laid out
+    /// again from the frame on the next draw.
+    pub(crate) fn on_resize(&mut self, area: Rect) {
+        self.terminal_area = Some(area);
+    }
+
+    /// True while the terminal is below the minimum size and the UI is
+    /// replaced by the resize warning.
+    fn too_small(&self) -> bool {
+        self.terminal_area.is_some_and(layout::is_too_small)
+    }
+
+    /// Turns a key press into an action and applies it. The help overlay, the
+    /// debug console and log search take keys directly; everything else goes
+    /// through the keymap. Behind the resize warning only quitting works.
+    fn handle_key(&mut self, key: KeyEvent) {
+        if self.too_small() {
+            if let Some(action) = self.action_for(&key) {
+                self.update(action);
+            }
+            return;
+        }
+        if self.show_help {
+            if self.keymap.action(&key, &[KeyAction::Help, KeyAction::Back]).is_some() {
+                self.show_help = false;
+            }
+            return;
+        }
+        if self.debug.as_ref().is_some_and(|console| console.open) {
+            return self.handle_debug_key(key);
+        }
+        if self.log_view.typing {
+            return self.handle_search_key(key);
+        }
+        if let Some(action) = self.action_for(&key) {
+            self.update(action);
+        }
+    }
+
+    /// The action `key` stands for right now. Actions that work on every
+    /// screen win over the current screen's, so one key can mean different
+    /// things on different screens.
+    pub(crate) fn action_for(&self, key: &KeyEvent) -> Option<KeyAction> {
+        // Other keys would act on screens nobody can see
+        if self.too_small() {
+            return self.keymap.action(key, &[KeyAction::Quit]);
+        }
+        let debug: &[KeyAction] = if self.debug.is_some() { &[KeyAction::DebugConsole] } else { &[] };
+        let actions: Vec<KeyAction> = debug.iter()
+            .chain(&GLOBAL_ACTIONS)
+            .chain(&LAYOUT_ACTIONS)
+            .chain(self.screen_actions())
+            .copied()
+            .collect();
+        self.keymap.action(key, &actions)
+    }
+
+    /// Actions the current screen responds to, besides the global ones.
+    fn screen_actions(&self) -> &'static [KeyAction] {
+        use KeyAction::*;
+        match &self.ui_state {
+            UiState::Game => &[Roll, Achievements, TurnHistory, Options, Pause],
+            UiState::Achievements => &[Back, Achievements],
+            UiState::TurnHistory { .. } => &[Back, TurnHistory, Up, Down, StepBack, StepForward, PageUp, PageDown],
+            UiState::Options { .. } => &[Back, Options, Up, Down, Confirm],
+            UiState::Paused { confirm_quit: true, .. } => &[Yes, No],
+            UiState::Paused { .. } => &[Back, Up, Down, Confirm],
+            UiState::TurnSummary { .. } | UiState::Eliminated { .. } => &[Confirm, Back],
+            UiState::TurnMenu { .. } => &[EndTurn, OpenOtb, PayLoan, Insurance, Market, Livestock, Pause],
+            UiState::OptionToBuy { .. } => &[Back, EndTurn, Up, Down, Confirm, Auction],
+            UiState::Insurance { .. } => &[Back, EndTurn, Up, Down, Confirm],
+            UiState::Market { .. } | UiState::Livestock { .. } => &[Back, Up, Down, PageUp, PageDown, Confirm],
+            UiState::LoanPayment { .. } => &[Back, EndTurn, Up, Down, PageUp, PageDown, Confirm],
+            UiState::Auction { .. } | UiState::RaiseCash { .. } => &[Up, Down, PageUp, PageDown, Confirm, Pass],
+            UiState::Discard { .. } => &[Up, Down, Confirm],
+            UiState::Animating { .. } => &[SkipAnimation],
+            UiState::TieBreak { .. } => &[Confirm],
+            UiState::GameOver { .. } => &[Export, Rematch],
+            UiState::Decision { .. } => match self.game_state.pending_decision().map(|request| request.kind) {
+                Some(DecisionKind::YesNo) => &[Yes, No],
+                Some(DecisionKind::Quantity { .. } | DecisionKind::Price { .. }) => &[Up, Down, Confirm],
+                Some(DecisionKind::Roll) => &[Roll],
+                _ => &[],
+            },
+        }
+    }
+
+    /// Applies an action. Quitting, help, log and layout actions work on
+    /// every screen; the rest go to the current screen's update.
+    pub(crate) fn update(&mut self, action: KeyAction) {
+        use KeyAction::*;
+        match action {
+            Quit => self.request_quit(),
+            Help => self.show_help = true,
+            DebugConsole => {
+                if let Some(console) = self.debug.as_mut() {
+                    console.open = true;
+                }
+            }
+            SearchLog => {
+                self.log_view.query.clear();
+                self.log_view.typing = true;
+            }
+            NextMatch => self.jump_to_match(1),
+            PrevMatch => self.jump_to_match(-1),
+            FilterLog => {
+                self.log_view.cycle_filter();
+                self.scroll_log_to_bottom();
+            }
+            ScrollUp => self.scroll_log_up(),
+            ScrollDown => self.scroll_log_down(),
+            ScrollPageUp => self.scroll_log_page_up(),
+            ScrollPageDown => self.scroll_log_page_down(),
+            ScrollTop => self.scroll_log_to_top(),
+            ScrollBottom => self.scroll_log_to_bottom(),
+            _ if LAYOUT_ACTIONS.contains(&action) => self.update_layout(action),
+            _ => match &self.ui_state {
+                UiState::Game => match action {
+                    Roll => self.advance_turn(),
+                    Achievements => self.ui_state = UiState::Achievements,
+                    TurnHistory => {
+                        let player_id = self.game_state.turn_order[self.game_state.current_turn_index];
+                        let year = self.game_state.players[player_id].year;
+                        self.ui_state = UiState::TurnHistory { player_id, year, scroll: 0 };
+                    }
+                    Options => self.ui_state = UiState::Options { selected: 0, paused: None },
+                    Pause => self.pause(false),
+                    _ => {}
+                },
+                UiState::Options { .. } => self.update_options(action),
+                UiState::TurnHistory { .. } => self.update_turn_history(action),
+                UiState::Paused { .. } => self.update_pause_menu(action),
+                UiState::Achievements => {
+                    if matches!(action, Back | Achievements) {
+                        self.ui_state = UiState::Game;
+                    }
+                }
+                UiState::TurnSummary { .. } => {
+                    if matches!(action, Confirm | Back) {
+                        self.ui_state = UiState::Game;
+                    }
+                }
+                UiState::Eliminated { player_id, summary } => {
+                    if !matches!(action, Confirm | Back) {
+                        return;
+                    }
+                    if action == Back {
+                        self.left_table.insert(*player_id);
+                    }
+                    self.ui_state = match summary.clone() {
+                        Some(summary) => UiState::TurnSummary { summary },
+                        None => UiState::Game,
+                    };
+                }
+                UiState::Animating { animation } => {
+                    if action == SkipAnimation {
+                        // Skip the rest of the animation
+                        let (player_id, roll) = (animation.player_id, animation.roll);
+                        self.resolve_turn(player_id, roll);
+                    }
+                }
+                UiState::TurnMenu { .. } if action == Pause => self.pause(false),
+                UiState::TurnMenu { player_id } => self.update_turn_menu(*player_id, action),
+                UiState::OptionToBuy { .. } => self.update_option_to_buy(action),
+                UiState::Insurance { .. } => self.update_insurance(action),
+                UiState::Market { .. } => self.update_market(action),
+                UiState::Livestock { .. } => self.update_livestock(action),
+                UiState::LoanPayment { .. } => self.update_loan_payment(action),
+                UiState::Auction { .. } => self.update_auction(action),
+                UiState::Decision { .. } => self.update_decision(action),
+                UiState::RaiseCash { .. } => self.update_raise_cash(action),
+                UiState::Discard { .. } => self.update_discard(action),
+                UiState::TieBreak { tie_break } => {
+                    if action == Confirm {
+                        let winner = tie_break.winner;
+                        self.ui_state = UiState::GameOver { winner, started: Instant::now(), exported: None };
+                    }
+                }
+                UiState::GameOver { .. } => match action {
+                    Export => self.export_results(),
+                    Rematch => self.rematch(),
+                    _ => {}
+                },
+            },
+        }
+    }
+
+    fn update_turn_menu(&mut self, player_id: usize, action: KeyAction) {
+        // Menu items open only when the game offers a move behind them
+        let offered = self.game_state.turn_actions(player_id);
+        let offers = |wanted: fn(&Action) -> bool| offered.iter().any(wanted);
+        match action {
+            KeyAction::EndTurn => {
+                // End turn and move to next player
+                self.end_turn();
+            },
+            KeyAction::OpenOtb => {
+                // Check if player has O.T.B. cards they can exercise
+                if offers(|action| matches!(action, Action::ExerciseOption { .. })) {
+                    // Show O.T.B. dialog
+                    self.ui_state = UiState::OptionToBuy {
+                        player_id,
+                        selected_index: 0,
+                    };
+                } else if self.game_state.can_exercise_option_to_buy(player_id) {
+                    self.add_log_entry(self.tr("app.no_otb_cards", &[]));
+                } else {
+                    self.add_log_entry(self.tr("app.otb_closed", &[]));
+                }
+            },
+            KeyAction::PayLoan => {
+                // Only show loan payment dialog if player has cash for a payment
+                if offers(|action| matches!(action, Action::PayLoan(_))) {
+                    // Show loan payment dialog, starting at one payment increment
+                    let default_payment = self.clamp_payment(player_id, self.game_state.rules.payment_increment);
+                    self.ui_state = UiState::LoanPayment {
+                        player_id,
+                        payment_amount: default_payment,
+                    };
+                } else {
+                    self.add_log_entry(self.tr("app.no_cash_for_loan", &[]));
+                }
+            },
+            KeyAction::Insurance => {
+                if self.game_state.can_buy_insurance(player_id) {
+                    self.ui_state = UiState::Insurance {
+                        player_id,
+                        selected_index: 0,
+                    };
+                } else {
+                    self.add_log_entry(self.tr("app.insurance_unavailable", &[]));
+                }
+            },
+            KeyAction::Market => {
+                if offers(|action| matches!(action, Action::SellCows(_))) {
+                    self.ui_state = UiState::Market {
+                        player_id,
+                        quantity: 1,
+                    };
+                } else {
+                    self.add_log_entry(self.tr("app.no_cows_to_sell", &[]));
+                }
+            },
+            KeyAction::Livestock => {
+                if offers(|action| matches!(action, Action::MoveCows { .. })) {
+                    self.ui_state = UiState::Livestock {
+                        player_id,
+                        selected: 0,
+                        cows: 0,
+                    };
+                } else {
+                    self.add_log_entry(self.tr("app.no_cows_to_move", &[]));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn update_option_to_buy(&mut self, action: KeyAction) {
+        let UiState::OptionToBuy { player_id, selected_index } = &mut self.ui_state else { return };
+        use KeyAction::*;
+        match action {
+            Back | EndTurn => {
+                // Return to turn menu
+                self.ui_state = UiState::TurnMenu {
+                    player_id: *player_id
+                };
+            },
+            Up => {
+                // Move selection up
+                let cards = self.game_state.get_option_to_buy_cards(*player_id);
+                if !cards.is_empty() && *selected_index > 0 {
+                    *selected_index -= 1;
+                }
+            },
+            Down => {
+                // Move selection down
+                let cards = self.game_state.get_option_to_buy_cards(*player_id);
+                if !cards.is_empty() && *selected_index < cards.len() - 1 {
+                    *selected_index += 1;
+                }
+            },
+            Confirm => {
+                // Process the option to buy
+                let player_id = *player_id;
+                let selected_idx = *selected_index;
+                self.process_option_to_buy(player_id, selected_idx);
+            },
+            Auction => {
+                // Decline the option and let the other players bid for it
+                let player_id = *player_id;
+                let selected_idx = *selected_index;
+                self.start_auction(player_id, selected_idx);
+            },
+            _ => {}
+        }
+    }
+
+    fn update_insurance(&mut self, action: KeyAction) {
+        let UiState::Insurance { player_id, selected_index } = &mut self.ui_state else { return };
+        use KeyAction::*;
+        match action {
+            Back | EndTurn => {
+                // Return to turn menu
+                self.ui_state = UiState::TurnMenu {
+                    player_id: *player_id
+                };
+            },
+            Up => {
+                *selected_index = selected_index.saturating_sub(1);
+            },
+            Down => {
+                *selected_index = (*selected_index + 1).min(Coverage::ALL.len() - 1);
+            },
+            Confirm => {
+                let player_id = *player_id;
+                let coverage = Coverage::ALL[*selected_index];
+                match self.game_state.buy_insurance(player_id, coverage) {
+                    Ok(log) => self.add_log_entry(log),
+                    Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn update_market(&mut self, action: KeyAction) {
+        let UiState::Market { player_id, quantity } = &mut self.ui_state else { return };
+        let cows = self.game_state.players[*player_id].assets.get(&AssetType::Cows).map_or(0, |r| r.quantity);
+        use KeyAction::*;
+        match action {
+            Back => {
+                self.ui_state = UiState::TurnMenu { player_id: *player_id };
+            },
+            Up => *quantity = (*quantity + 1).min(cows),
+            Down => *quantity = quantity.saturating_sub(1),
+            PageUp => *quantity = (*quantity + 10).min(cows),
+            PageDown => *quantity = quantity.saturating_sub(10),
+            Confirm => {
+                let (player_id, quantity) = (*player_id, *quantity);
+                self.sell_cows(player_id, quantity);
+                self.ui_state = UiState::TurnMenu { player_id };
+            },
+            _ => {}
+        }
+    }
+
+    fn update_livestock(&mut self, action: KeyAction) {
+        let UiState::Livestock { player_id, selected, cows } = &mut self.ui_state else { return };
+        let ridges = self.game_state.leased_ridges(*player_id);
+        let Some(ridge) = ridges.get(*selected) else { return };
+        let (most_up, most_down) = self.game_state.movable_cows(*player_id, ridge);
+        let (last, ridge_name) = (ridges.len() - 1, ridge.name.clone());
+        use KeyAction::*;
+        match action {
+            Back => {
+                self.ui_state = UiState::TurnMenu { player_id: *player_id };
+            },
+            Up => {
+                *selected = selected.saturating_sub(1);
+                *cows = 0;
+            },
+            Down => {
+                *selected = (*selected + 1).min(last);
+                *cows = 0;
+            },
+            PageUp => *cows = (*cows + 1).min(most_up),
+            PageDown => *cows = (*cows - 1).max(-most_down),
+            Confirm => {
+                let (player_id, count) = (*player_id, *cows);
+                *cows = 0;
+                match self.game_state.move_cows(player_id, &ridge_name, count) {
+                    Ok(log) => self.add_log_entry(log),
+                    Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn update_loan_payment(&mut self, action: KeyAction) {
+        let UiState::LoanPayment { player_id, payment_amount } = &mut self.ui_state else { return };
+        use KeyAction::*;
+        match action {
+            Back | EndTurn => {
+                // Return to turn menu
+                self.ui_state = UiState::TurnMenu {
+                    player_id: *player_id
+                };
+            },
+            Up | Down | PageUp | PageDown => {
+                // One payment increment per step, five per page
+                let increment = self.game_state.rules.payment_increment;
+                let step = match action {
+                    Up => increment,
+                    Down => -increment,
+                    PageUp => 5 * increment,
+                    _ => -5 * increment,
+                };
+                let (player_id, payment) = (*player_id, *payment_amount + step);
+                self.ui_state = UiState::LoanPayment { player_id, payment_amount: self.clamp_payment(player_id, payment) };
+            },
+            Confirm => {
+                // Process loan payment
+                let player_id = *player_id;
+                let payment = *payment_amount;
+                self.pay_loan(player_id, payment);
+
+                // Return to turn menu
+                self.ui_state = UiState::TurnMenu {
+                    player_id
+                };
+            },
+            _ => {}
+        }
+    }
+
+    /// Called once per loop iteration; resolves the turn once its animation has played out.
+    fn on_tick(&mut self, now: Instant) {
+        if let UiState::Animating { animation } = &self.ui_state {
+            if animation.is_finished(now) {
+                let (player_id, roll) = (animation.player_id, animation.roll);
+                self.resolve_turn(player_id, roll);
+            }
+        }
+        self.observe_events();
+    }
+
+    /// Plays sound cues and unlocks achievements for any game events since the last check.
+    fn observe_events(&mut self) {
+        let events = self.game_state.events_since(self.events_seen);
+        if events.is_empty() {
+            return;
+        }
+        self.events_seen += events.len();
+        self.audio.play_events(events);
+        let unlocked = self.achievements.observe(&self.game_state, events);
+        if unlocked.is_empty() {
+            return;
+        }
+        for (achievement, player) in unlocked {
+            let title = self.tr(&format!("achievement.{}", achievement.id()), &[]);
+            self.add_log_entry(self.tr("achievement.unlocked", &[("player", &player), ("achievement", &title)]));
+        }
+        // As with the layout file, failing to save isn't worth interrupting the game
+        let _ = self.achievements.save();
+    }
     
-    /// Process loan payment
-    fn pay_loan(&mut self, player_id: usize, payment_amount: i32) {
-        if payment_amount <= 0 {
-            self.add_log_entry("No payment made.".to_string());
+    /// Moves the turn history between players (in turn order) and between
+    /// the years the player has turns in, or scrolls the year's turns.
+    fn update_turn_history(&mut self, action: KeyAction) {
+        let UiState::TurnHistory { player_id, year, scroll } = &mut self.ui_state else { return };
+        let order = &self.game_state.turn_order;
+        let seat = order.iter().position(|id| id == player_id).unwrap_or(0);
+        match action {
+            KeyAction::Up | KeyAction::Down => {
+                let step = if action == KeyAction::Up { order.len() - 1 } else { 1 };
+                *player_id = order[(seat + step) % order.len()];
+                *scroll = 0;
+            }
+            KeyAction::StepBack | KeyAction::StepForward => {
+                let years: Vec<u32> = self.game_state.turn_history(*player_id).iter().map(|turn| turn.year).collect();
+                let next = if action == KeyAction::StepBack {
+                    years.iter().rev().find(|y| **y < *year)
+                } else {
+                    years.iter().find(|y| **y > *year)
+                };
+                if let Some(next) = next {
+                    *year = *next;
+                    *scroll = 0;
+                }
+            }
+            KeyAction::PageUp => *scroll = scroll.saturating_sub(turn_history::VISIBLE_TURNS),
+            KeyAction::PageDown => {
+                let turns = self.game_state.turn_history(*player_id).iter().filter(|turn| turn.year == *year).count();
+                let last_page = turns.saturating_sub(turn_history::VISIBLE_TURNS);
+                *scroll = (*scroll + turn_history::VISIBLE_TURNS).min(last_page);
+            }
+            KeyAction::Back | KeyAction::TurnHistory => self.ui_state = UiState::Game,
+            _ => {}
+        }
+    }
+
+    /// Changes the picked option; closing the screen saves the settings.
+    fn update_options(&mut self, action: KeyAction) {
+        let UiState::Options { selected, .. } = &mut self.ui_state else { return };
+        match action {
+            KeyAction::Up => *selected = selected.saturating_sub(1),
+            KeyAction::Down => *selected = (*selected + 1).min(options_widget::ROWS.len() - 1),
+            KeyAction::Confirm => match options_widget::ROWS[*selected] {
+                options_widget::OptionRow::Theme => self.settings.theme = self.settings.theme.next(),
+                options_widget::OptionRow::Keys => {
+                    self.settings.keymap = self.settings.keymap.next();
+                    self.keymap = Keymap::load_over(self.settings.keymap);
+                }
+                options_widget::OptionRow::Speed => {
+                    self.layout_prefs.cycle_game_speed();
+                    // As with the layout keys, losing the layout file isn't worth interrupting the game for
+                    let _ = self.layout_prefs.save();
+                }
+                options_widget::OptionRow::Autosave => self.settings.autosave = !self.settings.autosave,
+            },
+            KeyAction::Back | KeyAction::Options => {
+                let UiState::Options { paused, .. } = std::mem::replace(&mut self.ui_state, UiState::Game) else { return };
+                if let Some(resume) = paused {
+                    self.ui_state = UiState::Paused { selected: 1, confirm_quit: false, resume, saved: None };
+                }
+                // Like the layout file, failing to save isn't worth interrupting the game
+                if let Err(e) = self.settings.save() {
+                    tracing::warn!(error = %e, "couldn't save settings");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Opens the pause menu over the current screen, which carries on just
+    /// as it was on resuming. With `confirm_quit` the menu opens on the quit
+    /// question.
+    fn pause(&mut self, confirm_quit: bool) {
+        if let UiState::Paused { confirm_quit: asking, .. } = &mut self.ui_state {
+            *asking |= confirm_quit;
             return;
         }
-        
-        // Check conditions before borrowing
-        {
-            let player = &self.game_state.players[&player_id];
-            
-            // Check if player has enough cash
-            if player.cash < payment_amount {
-                self.add_log_entry(format!("Error: Not enough cash for payment of ${}", payment_amount));
-                return;
+        let resume = Box::new(std::mem::replace(&mut self.ui_state, UiState::Game));
+        self.ui_state = UiState::Paused { selected: 0, confirm_quit, resume, saved: None };
+    }
+
+    /// Quits once the player confirms, since a stray key shouldn't end a
+    /// game hours in. A finished game, or one hidden behind the resize
+    /// warning where nothing could be confirmed, quits straight away.
+    fn request_quit(&mut self) {
+        if self.too_small() || matches!(self.ui_state, UiState::GameOver { .. }) {
+            self.quit();
+        } else {
+            self.pause(true);
+        }
+    }
+
+    fn update_pause_menu(&mut self, action: KeyAction) {
+        let UiState::Paused { selected, confirm_quit, .. } = &mut self.ui_state else { return };
+        use KeyAction::*;
+        if *confirm_quit {
+            match action {
+                Yes => self.quit(),
+                No => *confirm_quit = false,
+                _ => {}
             }
-            
-            // Check if player has debt to pay
-            if player.debt <= 0 {
-                self.add_log_entry("Error: No debt to pay.".to_string());
+            return;
+        }
+        match action {
+            Up => *selected = selected.saturating_sub(1),
+            Down => *selected = (*selected + 1).min(pause_menu::ROWS.len() - 1),
+            Back => self.resume(),
+            Confirm => match pause_menu::ROWS[*selected] {
+                PauseRow::Resume => self.resume(),
+                PauseRow::Options => {
+                    let UiState::Paused { resume, .. } = std::mem::replace(&mut self.ui_state, UiState::Game) else { return };
+                    self.ui_state = UiState::Options { selected: 0, paused: Some(resume) };
+                }
+                PauseRow::Save => self.save_ledgers(),
+                PauseRow::Quit => *confirm_quit = true,
+            },
+            _ => {}
+        }
+    }
+
+    /// Closes the pause menu, back to the screen it was opened over.
+    fn resume(&mut self) {
+        if let UiState::Paused { resume, .. } = std::mem::replace(&mut self.ui_state, UiState::Game) {
+            self.ui_state = *resume;
+        }
+    }
+
+    /// Writes every player's ledger so far to `LEDGER_PATH`.
+    fn save_ledgers(&mut self) {
+        match std::fs::write(LEDGER_PATH, self.game_state.ledger_csv()) {
+            Ok(()) => {
+                if let UiState::Paused { saved, .. } = &mut self.ui_state {
+                    *saved = Some(LEDGER_PATH.to_string());
+                }
+                self.add_log_entry(self.tr("app.ledgers_saved", &[("path", &LEDGER_PATH)]));
+            }
+            Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
+        }
+    }
+
+    /// Applies a panel layout action; these work in every UI state.
+    fn update_layout(&mut self, action: KeyAction) {
+        match action {
+            KeyAction::ToggleLog => self.layout_prefs.toggle_log(),
+            KeyAction::FullLog => self.layout_prefs.toggle_fullscreen_log(),
+            KeyAction::ToggleScoreboard => self.layout_prefs.toggle_scoreboard(),
+            KeyAction::SortScoreboard => self.layout_prefs.toggle_scoreboard_order(),
+            KeyAction::DrawOdds => self.layout_prefs.toggle_draw_odds(),
+            KeyAction::ShrinkBoard => self.layout_prefs.shrink_board(),
+            KeyAction::GrowBoard => self.layout_prefs.grow_board(),
+            KeyAction::GameSpeed => {
+                self.layout_prefs.cycle_game_speed();
+                self.add_log_entry(self.tr("app.game_speed", &[("speed", &self.layout_prefs.game_speed.as_str())]));
+            }
+            KeyAction::ToggleSound => {
+                // Sound isn't part of the layout, so there's nothing to save
+                self.audio.toggle_mute();
+                let key = if self.audio.is_muted() { "app.sound_off" } else { "app.sound_on" };
+                self.add_log_entry(self.tr(key, &[]));
                 return;
             }
+            _ => return,
         }
-        
-        // Now apply the payment with a separate borrow
-        {
-            let player = self.game_state.players.get_mut(&player_id).unwrap();
-            let player_name = player.name.clone();
-            
-            // Apply the payment
-            player.cash -= payment_amount;
-            player.debt -= payment_amount;
-            
-            // Update net worth in the same borrow
-            player.net_worth = player.cash - player.debt + player.total_asset_value + player.total_ridge_value;
-            
-            // Read remaining debt *before* calling add_log_entry to avoid double mutable borrow
-            let remaining_debt = player.debt;
+        // Losing the layout file is not worth interrupting the game for
+        let _ = self.layout_prefs.save();
+    }
+
+    /// Largest payment up to `amount` the player could make: whole payment
+    /// increments within their cash, or their whole debt if they can clear it.
+    fn clamp_payment(&self, player_id: usize, amount: Money) -> Money {
+        let player = &self.game_state.players[player_id];
+        let increment = self.game_state.rules.payment_increment.max(Money(1)).dollars();
+        let most = player.cash.min(player.debt);
+        if amount >= player.debt && most == player.debt {
+            return player.debt;
+        }
+        let amount = amount.clamp(Money::ZERO, most);
+        Money(amount.dollars() - amount.dollars() % increment)
+    }
 
-            self.add_log_entry(format!("{} paid ${} towards debt. Remaining debt: ${}",
-                player_name, payment_amount, remaining_debt)); // Use the local variable
+    /// Process loan payment
+    fn pay_loan(&mut self, player_id: usize, payment_amount: Money) {
+        if payment_amount <= Money::ZERO {
+            self.add_log_entry(self.tr("app.no_payment", &[]));
+            return;
+        }
+        
+        match self.game_state.repay_debt(player_id, payment_amount) {
+            Ok(log) => self.add_log_entry(log),
+            Err(GameError::InsufficientFunds { .. }) => {
+                self.add_log_entry(self.tr("app.payment_unaffordable", &[("amount", &payment_amount)]));
+            }
+            Err(GameError::NoDebt) => self.add_log_entry(self.tr("app.no_debt", &[])),
+            Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
         }
     }
     
+    /// Sell cows at market
+    fn sell_cows(&mut self, player_id: usize, quantity: u32) {
+        if quantity == 0 {
+            self.add_log_entry(self.tr("app.no_sale", &[]));
+            return;
+        }
+        match self.game_state.sell_cows(player_id, quantity) {
+            Ok(log) => self.add_log_entry(log),
+            Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
+        }
+    }
+
     /// Process an option to buy selection
     fn process_option_to_buy(&mut self, player_id: usize, selected_index: usize) {
         // Get all needed data before making mutable calls
         let cards = self.game_state.get_option_to_buy_cards(player_id);
         if cards.is_empty() || selected_index >= cards.len() {
-            self.add_log_entry("Error: Invalid card selection.".to_string());
+            self.add_log_entry(self.tr("app.invalid_card", &[]));
             
             // Return to turn menu
             self.ui_state = UiState::TurnMenu {
@@ -375,18 +1079,18 @@ impl App {
         // Clone the card data we need
         let card = cards[selected_index];
         let card_id = card.id;
-        let card_title = card.title.clone();
+        let card_title = self.game_state.locale.card_title(card).to_string();
         
         // Get player name before the mutable borrow
-        let player_name = self.game_state.players[&player_id].name.clone();
+        let player_name = self.game_state.players[player_id].name.clone();
         
         // First, check if the player has enough cash for direct purchase
         let needs_loan = {
-            let player = &self.game_state.players[&player_id];
+            let player = &self.game_state.players[player_id];
             let cost = match &card.effect {
                 GameEffect::OptionalBuyAsset { cost, .. } => *cost,
                 GameEffect::LeaseRidge { cost, .. } => *cost,
-                _ => 0,
+                _ => Money::ZERO,
             };
             player.cash < cost
         };
@@ -397,38 +1101,40 @@ impl App {
         // If direct purchase fails due to needing a loan, try with loan
         match purchase_result {
             Ok(_) => {
-                self.add_log_entry(format!("{} exercised O.T.B.: {}", 
-                    player_name, card_title));
+                self.add_log_entry(self.tr("app.option_exercised", &[("player", &player_name), ("card", &card_title)]));
                 
-                // Return to turn menu
-                self.ui_state = UiState::TurnMenu {
-                    player_id
-                };
+                // Return to turn menu, once any ridge stocking is chosen
+                if !self.prompt_decisions(player_id) {
+                    self.ui_state = UiState::TurnMenu {
+                        player_id
+                    };
+                }
             },
             Err(e) => {
                 // If the error is about loan confirmation and we know the player needs a loan
-                if e == "Loan confirmation required" && needs_loan {
+                if e == GameError::LoanConfirmationRequired && needs_loan {
                     // Try again with loan confirmation
                     match self.game_state.exercise_option_to_buy(player_id, card_id, true) {
                         Ok(_) => {
-                            self.add_log_entry(format!("{} exercised O.T.B.: {} (with loan)", 
-                                player_name, card_title));
+                            self.add_log_entry(self.tr("app.option_exercised_with_loan", &[("player", &player_name), ("card", &card_title)]));
                             
-                            // Return to turn menu
-                            self.ui_state = UiState::TurnMenu {
-                                player_id
-                            };
+                            // Return to turn menu, once any ridge stocking is chosen
+                            if !self.prompt_decisions(player_id) {
+                                self.ui_state = UiState::TurnMenu {
+                                    player_id
+                                };
+                            }
                         },
                         Err(e) => {
                             // Log the error but stay in O.T.B. dialog
-                            self.add_log_entry(format!("Could not exercise option: {}", e));
+                            self.add_log_entry(self.tr("app.option_failed", &[("error", &e)]));
                         }
                     }
-                } else if e.contains("Insufficient funds") {
+                } else if matches!(e, GameError::InsufficientFunds { .. } | GameError::DebtLimitExceeded { .. }) {
                     // Log the error but stay in O.T.B. dialog
-                    self.add_log_entry(format!("Could not exercise option: {}", e));
+                    self.add_log_entry(self.tr("app.option_failed", &[("error", &e)]));
                 } else {
-                    self.add_log_entry(format!("Could not exercise option: {}", e));
+                    self.add_log_entry(self.tr("app.option_failed", &[("error", &e)]));
                     
                     // Return to turn menu
                     self.ui_state = UiState::TurnMenu {
@@ -439,36 +1145,398 @@ impl App {
         }
     }
 
+    /// Puts the selected O.T.B. card up for auction among the other players
+    fn start_auction(&mut self, player_id: usize, selected_index: usize) {
+        let Some(card_id) = self.game_state.get_option_to_buy_cards(player_id).get(selected_index).map(|c| c.id) else {
+            self.add_log_entry(self.tr("app.invalid_card", &[]));
+            return;
+        };
+        if self.game_state.turn_order.len() < 2 {
+            self.add_log_entry(self.tr("auction.no_bidders", &[]));
+            return;
+        }
+        match self.game_state.start_auction(player_id, card_id) {
+            Ok(auction) => {
+                self.add_log_entry(self.tr("auction.started", &[("card", &self.game_state.locale.card_title(&auction.card))]));
+                let bid_amount = auction.minimum_bid();
+                self.ui_state = UiState::Auction { auction, bid_amount };
+                self.run_ai_bidders();
+            }
+            Err(e) => self.add_log_entry(self.tr("auction.start_failed", &[("error", &e)])),
+        }
+    }
+
+    /// Handles bid adjustment, bidding and passing for the human bidder whose turn it is
+    fn update_auction(&mut self, action: KeyAction) {
+        let UiState::Auction { auction, bid_amount } = &mut self.ui_state else { return };
+        let Some(bidder) = auction.current_bidder() else { return };
+        let cash = self.game_state.players[bidder].cash;
+        let bidder_name = self.game_state.players[bidder].name.clone();
+
+        let locale = Arc::clone(&self.game_state.locale);
+        use KeyAction::*;
+        let result = match action {
+            Up => { *bid_amount = (*bid_amount + Money(100)).min(cash); return; }
+            Down => { *bid_amount = (*bid_amount - Money(100)).max(auction.minimum_bid()); return; }
+            PageUp => { *bid_amount = (*bid_amount + Money(1000)).min(cash); return; }
+            PageDown => { *bid_amount = (*bid_amount - Money(1000)).max(auction.minimum_bid()); return; }
+            Confirm => auction.bid(bidder, *bid_amount, cash)
+                .map(|_| locale.tr("auction.bid", &[("player", &bidder_name), ("amount", bid_amount)])),
+            Pass => auction.pass(bidder)
+                .map(|_| locale.tr("auction.pass", &[("player", &bidder_name)])),
+            _ => return,
+        };
+        match result {
+            Ok(msg) => self.add_log_entry(msg),
+            Err(e) => self.add_log_entry(self.tr("auction.bid_rejected", &[("error", &e)])),
+        }
+        self.run_ai_bidders();
+    }
+
+    /// Lets AI bidders act until a human has to bid or the auction closes, then settles it
+    fn run_ai_bidders(&mut self) {
+        loop {
+            let UiState::Auction { auction, bid_amount } = &mut self.ui_state else { return };
+            let Some(bidder) = auction.current_bidder() else { break };
+            let player = &self.game_state.players[bidder];
+            if !matches!(player.player_type, PlayerType::AI(_)) {
+                // Suggest the minimum raise to the human bidder
+                *bid_amount = auction.minimum_bid().min(player.cash.max(auction.minimum_bid()));
+                return;
+            }
+            let name = player.name.clone();
+            let locale = Arc::clone(&self.game_state.locale);
+            let msg = match self.game_state.ai_auction_bid(auction, bidder) {
+                Some(amount) if auction.bid(bidder, amount, player.cash).is_ok() => locale.tr("auction.bid", &[("player", &name), ("amount", &amount)]),
+                _ => {
+                    let _ = auction.pass(bidder);
+                    locale.tr("auction.pass", &[("player", &name)])
+                }
+            };
+            self.add_log_entry(msg);
+        }
+
+        // Bidding closed - hand over the card and money
+        let UiState::Auction { auction, .. } = std::mem::replace(&mut self.ui_state, UiState::Game) else { return };
+        let seller = auction.seller;
+        let mut logs = Vec::new();
+        if let Err(e) = self.game_state.settle_auction(auction, &mut logs) {
+            logs.push(self.tr("auction.failed", &[("error", &e)]));
+        }
+        for log in logs {
+            self.add_log_entry(log);
+        }
+        self.ui_state = UiState::TurnMenu { player_id: seller };
+    }
+
+    /// Lets AI players answer their decisions until a human has to answer one.
+    /// Returns true while the game is waiting on a human.
+    fn prompt_decisions(&mut self, turn_player_id: usize) -> bool {
+        while let Some(request) = self.game_state.pending_decision().cloned() {
+            let player = &self.game_state.players[request.player_id];
+            if !matches!(player.player_type, PlayerType::AI(_)) {
+                self.ui_state = match request.kind {
+                    DecisionKind::RaiseCash { .. } => UiState::RaiseCash { turn_player_id, selected: 0, quantity: 1 },
+                    DecisionKind::Discard { .. } => UiState::Discard { turn_player_id, selected: 0 },
+                    DecisionKind::Quantity { max, .. } => UiState::Decision { turn_player_id, quantity: max, price: Money::ZERO },
+                    DecisionKind::Price { max, .. } => UiState::Decision { turn_player_id, quantity: 0, price: max },
+                    _ => UiState::Decision { turn_player_id, quantity: 0, price: Money::ZERO },
+                };
+                return true;
+            }
+            let mut logs = Vec::new();
+            let decision = self.game_state.ai_decision(&request).unwrap_or(Decision::No);
+            if let Err(e) = self.game_state.answer_decision(request.player_id, decision, &mut logs) {
+                logs.push(self.tr("app.error", &[("error", &e)]));
+                // An AI answer that keeps failing would block the game; drop the question
+                if self.game_state.pending_decision() == Some(&request) {
+                    self.game_state.pending_decisions.pop_front();
+                }
+            }
+            for log in logs {
+                self.add_log_entry(log);
+            }
+        }
+        false
+    }
+
+    fn update_decision(&mut self, action: KeyAction) {
+        let UiState::Decision { turn_player_id, quantity, price } = &mut self.ui_state else { return };
+        let turn_player_id = *turn_player_id;
+        let Some(request) = self.game_state.pending_decision() else { return };
+        let (player_id, kind) = (request.player_id, request.kind);
+        use KeyAction::*;
+        let decision = match (kind, action) {
+            (DecisionKind::YesNo, Yes) => Decision::Yes,
+            (DecisionKind::YesNo, No) => Decision::No,
+            (DecisionKind::Quantity { max, .. }, Up) => { *quantity = (*quantity + 1).min(max); return; }
+            (DecisionKind::Quantity { min, .. }, Down) => { *quantity = (*quantity - 1).max(min); return; }
+            (DecisionKind::Quantity { .. }, Confirm) => Decision::Quantity(*quantity),
+            (DecisionKind::Price { max, step }, Up) => { *price = (*price + step).min(max); return; }
+            (DecisionKind::Price { step, .. }, Down) => { *price = (*price - step).max(Money::ZERO); return; }
+            (DecisionKind::Price { .. }, Confirm) => Decision::Price(*price),
+            (DecisionKind::Roll, Roll) => Decision::Roll,
+            _ => return,
+        };
+
+        self.answer_decision(turn_player_id, player_id, decision);
+    }
+
+    /// Sells the picked asset toward the bill, or pays it and borrows the rest.
+    fn update_raise_cash(&mut self, action: KeyAction) {
+        let UiState::RaiseCash { turn_player_id, selected, quantity } = &mut self.ui_state else { return };
+        let turn_player_id = *turn_player_id;
+        let Some(request) = self.game_state.pending_decision() else { return };
+        let player_id = request.player_id;
+        let sellable = self.game_state.sellable_assets(player_id);
+        let picked = sellable.get(*selected).copied();
+        use KeyAction::*;
+        let decision = match action {
+            Up => { *selected = selected.saturating_sub(1); *quantity = 1; return; }
+            Down => { *selected = (*selected + 1).min(sellable.len().saturating_sub(1)); *quantity = 1; return; }
+            PageUp => { *quantity = (*quantity + 1).min(picked.map_or(1, |picked| picked.owned)); return; }
+            PageDown => { *quantity = quantity.saturating_sub(1).max(1); return; }
+            Confirm => match picked {
+                Some(picked) => Decision::Sell { asset: picked.asset, quantity: *quantity },
+                None => return,
+            },
+            Pass => Decision::Yes,
+            _ => return,
+        };
+        self.answer_decision(turn_player_id, player_id, decision);
+    }
+
+    /// Discards the picked card; the turn ends once the hand is down to the limit.
+    fn update_discard(&mut self, action: KeyAction) {
+        let UiState::Discard { turn_player_id, selected } = &mut self.ui_state else { return };
+        let turn_player_id = *turn_player_id;
+        let Some(request) = self.game_state.pending_decision() else { return };
+        let player_id = request.player_id;
+        let hand = &self.game_state.players[player_id].hand;
+        use KeyAction::*;
+        let card_id = match action {
+            Up => { *selected = selected.saturating_sub(1); return; }
+            Down => { *selected = (*selected + 1).min(hand.len().saturating_sub(1)); return; }
+            Confirm => match hand.get(*selected) {
+                Some(card) => card.id,
+                None => return,
+            },
+            _ => return,
+        };
+        let mut logs = Vec::new();
+        if let Err(e) = self.game_state.answer_decision(player_id, Decision::Discard { card_id }, &mut logs) {
+            logs.push(self.tr("app.error", &[("error", &e)]));
+        }
+        for log in logs {
+            self.add_log_entry(log);
+        }
+        let still_over = self.game_state.pending_decision()
+            .is_some_and(|request| matches!(request.kind, DecisionKind::Discard { .. }));
+        if still_over {
+            self.ui_state = UiState::Discard { turn_player_id, selected: 0 };
+        } else if !self.prompt_decisions(turn_player_id) {
+            self.end_turn();
+        }
+    }
+
+    /// Answers the pending decision, then moves on to the next question or back to the turn.
+    fn answer_decision(&mut self, turn_player_id: usize, player_id: usize, decision: Decision) {
+        let mut logs = Vec::new();
+        if let Err(e) = self.game_state.answer_decision(player_id, decision, &mut logs) {
+            logs.push(self.tr("app.error", &[("error", &e)]));
+        }
+        for log in logs {
+            self.add_log_entry(log);
+        }
+        if !self.prompt_decisions(turn_player_id) {
+            self.show_turn_menu(turn_player_id);
+        }
+    }
+
+    /// Saves the final standings as CSV from the victory screen.
+    fn export_results(&mut self) {
+        match std::fs::write(RESULTS_PATH, self.game_state.results_csv()) {
+            Ok(()) => {
+                if let UiState::GameOver { exported, .. } = &mut self.ui_state {
+                    *exported = Some(RESULTS_PATH.to_string());
+                }
+                self.add_log_entry(self.tr("app.results_exported", &[("path", &RESULTS_PATH)]));
+            }
+            Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
+        }
+    }
+
+    /// Starts a rematch at the same table from the victory screen.
+    fn rematch(&mut self) {
+        match self.game_state.rematch() {
+            Ok(game_state) => {
+                self.game_state = game_state;
+                self.begin_game();
+                self.write_spectator_snapshot();
+            }
+            Err(e) => self.add_log_entry(self.tr("app.error", &[("error", &e)])),
+        }
+    }
+
     /// Ends the current player's turn and advances to the next player
     fn end_turn(&mut self) {
         // Get current player and check for win condition
         let current_player_id = self.game_state.turn_order[self.game_state.current_turn_index];
         
+        // Over the hand limit: discard down first, and the last discard ends the turn
+        if self.game_state.request_discards(current_player_id) && self.prompt_decisions(current_player_id) {
+            return;
+        }
+
         // Extract needed values before borrowing self as mutable
-        let player_name = self.game_state.players[&current_player_id].name.clone();
-        let player_net_worth = self.game_state.players[&current_player_id].net_worth;
+        let player_name = self.game_state.players[current_player_id].name.clone();
+        let player_net_worth = self.game_state.players[current_player_id].net_worth;
+        let winning_net_worth = self.game_state.rules.winning_net_worth;
         
-        // Check if current player has won
-        if player_net_worth >= WINNING_NET_WORTH {
-            // Player has won!
-            self.add_log_entry(format!("🏆 {} HAS WON THE GAME! 🏆", player_name));
-            self.add_log_entry(format!("Net worth of ${} exceeds the ${} needed to win!", 
-                                      player_net_worth, WINNING_NET_WORTH));
-            
-            // Continue the game but make it clear they've won
-            self.add_log_entry("The game can continue, but victory has been achieved.".to_string());
+        // A win someone else reached in the same year is settled first
+        let mut victory_logs = Vec::new();
+        let tie_break = self.game_state.settle_win(current_player_id, &mut victory_logs);
+        for log in victory_logs {
+            self.add_log_entry(log);
+        }
+
+        // Check if current player has won (or run out of time on a scenario)
+        let outcome = self.game_state.record_outcome(current_player_id);
+        match outcome {
+            PlayerOutcome::Won => {
+                // Player has won!
+                self.add_log_entry(self.tr("app.winner", &[("player", &player_name)]));
+                match &self.game_state.scenario {
+                    _ if self.game_state.last_player_standing() == Some(current_player_id) => {
+                        self.add_log_entry(self.tr("app.winner_last_standing", &[]));
+                    }
+                    Some(scenario) => self.add_log_entry(self.tr("app.winner_scenario", &[("scenario", &scenario.name)])),
+                    None => self.add_log_entry(self.tr("app.winner_net_worth",
+                                                       &[("net_worth", &player_net_worth), ("target", &winning_net_worth)])),
+                }
+            }
+            PlayerOutcome::Lost if !self.game_state.players[current_player_id].is_active => {}
+            PlayerOutcome::Lost if tie_break.is_some() => {
+                let winner = tie_break.as_ref().map_or(current_player_id, |tie_break| tie_break.winner);
+                self.add_log_entry(self.tr("app.winner", &[("player", &self.game_state.players[winner].name)]));
+            }
+            PlayerOutcome::Lost => match &self.game_state.scenario {
+                Some(scenario) => {
+                    self.add_log_entry(self.tr("app.scenario_lost", &[("player", &player_name), ("scenario", &scenario.name)]));
+                }
+                None => {
+                    let year = self.game_state.deadline().unwrap_or_default();
+                    self.add_log_entry(self.tr("app.out_of_time", &[("player", &player_name), ("target", &winning_net_worth), ("year", &year)]));
+                }
+            },
+            PlayerOutcome::InProgress => {}
         }
+        // Runs against the clock are scored once they're over
+        if self.game_state.rules.year_limit.is_some() && self.game_state.player_outcome(current_player_id) != PlayerOutcome::InProgress {
+            self.add_log_entry(self.tr("app.run_score", &[("net_worth", &player_net_worth),
+                ("years", &self.game_state.years_played(current_player_id)),
+                ("per_year", &self.game_state.net_worth_per_year(current_player_id))]));
+        }
+
+        // Recap what the turn changed, if it started with a roll
+        self.game_state.update_player_valuation(current_player_id);
+        let summary = self.turn_start.take()
+            .filter(|snapshot| snapshot.player_id == current_player_id)
+            .and_then(|snapshot| snapshot.diff(&self.game_state));
         
-        // Advance to the next player's turn
-        self.game_state.current_turn_index = 
-            (self.game_state.current_turn_index + 1) % self.game_state.turn_order.len();
+        self.game_state.snapshot_net_worths();
+
+        // Advance to the next player's turn, skipping anyone who's bankrupt
+        self.game_state.advance_turn();
+        self.game_state.update_phase();
+        tracing::info!(player_id = current_player_id, net_worth = %player_net_worth,
+            next_player_id = self.game_state.turn_order[self.game_state.current_turn_index], "turn ended");
         
         // Add message for the next player's turn
-        let next_player = &self.game_state.players[&self.game_state.turn_order[self.game_state.current_turn_index]].name;
-        self.add_log_entry(format!("--- {}'s turn (Press Enter to roll) ---", next_player));
+        let next_player = &self.game_state.players[self.game_state.turn_order[self.game_state.current_turn_index]].name;
+        self.add_log_entry(self.tr("app.turn_header", &[("player", next_player)]));
+        self.write_spectator_snapshot();
         
-        // Return to normal gameplay state
-        self.ui_state = UiState::Game;
+        // Show the recap, then return to normal gameplay state. A player who
+        // went bankrupt this turn is asked whether they'll keep watching first,
+        // and a win ends the game, once any tie for it has been shown.
+        let game_won = outcome == PlayerOutcome::Won || tie_break.is_some();
+        self.ui_state = match (tie_break, summary) {
+            (Some(tie_break), _) => UiState::TieBreak { tie_break },
+            _ if outcome == PlayerOutcome::Won => {
+                UiState::GameOver { winner: current_player_id, started: Instant::now(), exported: None }
+            }
+            (None, summary) if !self.game_state.players[current_player_id].is_active => {
+                UiState::Eliminated { player_id: current_player_id, summary }
+            }
+            (None, Some(summary)) => UiState::TurnSummary { summary },
+            (None, None) => UiState::Game,
+        };
+        if self.settings.autosave && game_won {
+            self.export_results();
+        }
+    }
+
+    /// Edits the log search text while it's being typed. Enter jumps to the
+    /// first match; Esc drops the search.
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => self.log_view.query.push(c),
+            KeyCode::Backspace => { self.log_view.query.pop(); }
+            KeyCode::Enter => {
+                self.log_view.typing = false;
+                self.log_view.current_match = 0;
+                self.jump_to_match(0);
+            }
+            KeyCode::Esc => {
+                self.log_view.typing = false;
+                self.log_view.query.clear();
+            }
+            _ => {}
+        }
+    }
+
+    /// Edits and runs debug console commands. Esc, or the console key on an
+    /// empty line, closes it. Decisions a triggered effect raises are asked
+    /// once the console closes.
+    fn handle_debug_key(&mut self, key: KeyEvent) {
+        let Some(console) = self.debug.as_mut() else { return };
+        match key.code {
+            KeyCode::Esc => console.open = false,
+            KeyCode::Char(_) if console.input.is_empty() && self.keymap.action(&key, &[KeyAction::DebugConsole]).is_some() => {
+                console.open = false;
+            }
+            KeyCode::Char(c) => console.input.push(c),
+            KeyCode::Backspace => { console.input.pop(); }
+            KeyCode::Enter => {
+                for log in console.submit(&mut self.game_state) {
+                    self.add_log_entry(log);
+                }
+                let between_rolls = matches!(self.ui_state, UiState::Game | UiState::TurnMenu { .. });
+                if between_rolls && self.game_state.pending_decision().is_some() {
+                    if let Some(console) = self.debug.as_mut() {
+                        console.open = false;
+                    }
+                    let turn_player_id = self.game_state.turn_order[self.game_state.current_turn_index];
+                    self.prompt_decisions(turn_player_id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves `step` matches forward (or back) through the log search results,
+    /// wrapping around, and scrolls the match into view.
+    fn jump_to_match(&mut self, step: isize) {
+        let matches = log_widget::matching_lines(&self.log_entries, &self.log_view);
+        if matches.is_empty() {
+            return;
+        }
+        let count = matches.len() as isize;
+        let current = (self.log_view.current_match as isize + step).rem_euclid(count) as usize;
+        self.log_view.current_match = current;
+        // Leave a couple of lines of context above the match
+        self.log_scroll_offset = matches[current].saturating_sub(2);
     }
 
     /// Scrolls the log up by one line.
@@ -521,10 +1589,13 @@ impl App {
 
     /// Check if a player can perform any meaningful actions (pay debt or use O.T.B. cards)
     fn can_player_perform_actions(&self, player_id: usize) -> bool {
-        let player = &self.game_state.players[&player_id];
+        let player = &self.game_state.players[player_id];
+        if !player.is_active {
+            return false;
+        }
         
         // Check if player has any cash to pay debt
-        let can_pay_debt = player.cash > 0 && player.debt > 0;
+        let can_pay_debt = player.cash > Money::ZERO && player.debt > Money::ZERO;
         
         // Check if player has O.T.B. cards and can afford them
         let option_cards = self.game_state.get_option_to_buy_cards(player_id);
@@ -535,7 +1606,7 @@ impl App {
                               let cost = match &card.effect {
                                   GameEffect::OptionalBuyAsset { cost, .. } => *cost,
                                   GameEffect::LeaseRidge { cost, .. } => *cost,
-                                  _ => 0,
+                                  _ => Money::ZERO,
                               };
                               
                               // Check if player can directly afford it or via loan
@@ -551,10 +1622,26 @@ impl App {
     fn advance_turn(&mut self) {
         // Get current player info
         let current_player_id = self.game_state.turn_order[self.game_state.current_turn_index];
-        let player_name = self.game_state.players[&current_player_id].name.clone();
+        self.turn_start = PlayerSnapshot::take(&self.game_state, current_player_id);
 
         // Simulate a dice roll (1-6) using the App's RNG
-        let roll = self.rng.gen_range(1..=6);
+        let roll = Dice::ONE.roll(&mut self.rng).total();
+        tracing::info!(player_id = current_player_id, roll, "dice roll");
+
+        // Play the roll out on the board first unless animations are off
+        let speed = self.layout_prefs.game_speed;
+        if speed == GameSpeed::Instant {
+            self.resolve_turn(current_player_id, roll);
+        } else {
+            let from = self.game_state.players[current_player_id].position;
+            let animation = TurnAnimation::new(current_player_id, roll, from, self.game_state.board.len(), speed, Instant::now());
+            self.ui_state = UiState::Animating { animation };
+        }
+    }
+
+    /// Moves the player by `roll`, resolves the tile and shows the turn menu.
+    fn resolve_turn(&mut self, current_player_id: usize, roll: u32) {
+        let player_name = self.game_state.players[current_player_id].name.clone();
 
         // Clean old logs if they get too large (keeps memory usage in check)
         if self.log_entries.len() > 1000 {
@@ -580,13 +1667,22 @@ impl App {
             }
             Err(e) => {
                 // Handle any errors from the game logic
-                self.add_log_entry(format!("Error during turn: {}", e));
+                self.add_log_entry(self.tr("app.turn_error", &[("error", &e)]));
             }
         }
 
+        // Card effects may still need answers before the turn goes on
+        if self.prompt_decisions(current_player_id) {
+            return;
+        }
+        self.show_turn_menu(current_player_id);
+    }
+
+    /// Shows the turn menu, or ends the turn when there is nothing left to do.
+    fn show_turn_menu(&mut self, current_player_id: usize) {
         // Check if player can perform any meaningful actions
         if !self.can_player_perform_actions(current_player_id) {
-            self.add_log_entry("No affordable actions - advancing to next player.".to_string());
+            self.add_log_entry(self.tr("app.no_actions", &[]));
             self.end_turn();
             return;
         }
@@ -602,46 +1698,100 @@ impl App {
         self.running = false;
     }
 
+    /// False once the player has quit.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
     /// Renders the user interface widgets.
     fn ui(&self, frame: &mut Frame) {
-        // Define the main layout: Scoreboard top, Game Board/Log below, Status bar bottom
-        let main_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(9),    // Scoreboard fixed height (title + 6 players + borders)
-                Constraint::Min(0),       // Game Board/Log take remaining space
-                Constraint::Length(1),    // Status bar
-            ])
-            .split(frame.size());
-
-        let scoreboard_area = main_layout[0];
-        let bottom_area = main_layout[1];
-        let status_bar_area = main_layout[2];
-
-        // Define the bottom layout: Game Board left (50%), Log right (50%)
-        let bottom_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(50),
-                Constraint::Percentage(50),
-            ])
-            .split(bottom_area);
-
-        let game_board_area = bottom_layout[0];
-        let log_area = bottom_layout[1];
+        // Refuse to draw a garbled UI on tiny terminals
+        if layout::is_too_small(frame.size()) {
+            self.render_too_small(frame);
+            return;
+        }
+
+        // Scoreboard top, Game Board/Log below, Status bar bottom - panels may be hidden
+        let app_layout = self.layout_prefs.compute(frame.size(), self.game_state.turn_order.len());
+        let status_bar_area = app_layout.status_bar;
+        let game_board_area = app_layout.dialog_host;
 
         // Render main widgets
-        render_scoreboard(frame, scoreboard_area, &self.game_state);
-        crate::ui::widgets::game_board::render_game_board(frame, game_board_area, &self.game_state);
-        render_log(frame, log_area, &self.log_entries, self.log_scroll_offset);
+        if let Some(scoreboard_area) = app_layout.scoreboard {
+            render_scoreboard(frame, scoreboard_area, &self.game_state, self.layout_prefs.scoreboard_order, &self.left_table);
+        }
+        let now = Instant::now();
+        if let Some(board_area) = app_layout.board {
+            let moving_token = match &self.ui_state {
+                UiState::Animating { animation } => Some((animation.player_id, animation.token_position(now))),
+                _ => None,
+            };
+            game_board::render_game_board(frame, board_area, &self.game_state, moving_token);
+            // Deck counts in the board's bottom-right corner, when there's room under the tiles
+            let decks = self.game_state.deck_stats();
+            let (width, height) = (deck_stats::WIDTH, deck_stats::height(decks.len()));
+            if board_area.height >= game_board::BOARD_HEIGHT + height && board_area.width >= width + 2 {
+                let area = Rect {
+                    x: board_area.x + board_area.width - width - 1,
+                    y: board_area.y + board_area.height - height - 1,
+                    width,
+                    height,
+                };
+                render_deck_stats(frame, area, &decks);
+            }
+            // Years left to win in the bottom-left corner, when racing the clock
+            let current_player = self.game_state.turn_order[self.game_state.current_turn_index];
+            if let Some(deadline) = self.game_state.deadline() {
+                if board_area.height >= game_board::BOARD_HEIGHT + countdown::HEIGHT && board_area.width >= width + countdown::WIDTH + 3 {
+                    let area = Rect {
+                        x: board_area.x + 1,
+                        y: board_area.y + board_area.height - countdown::HEIGHT - 1,
+                        width: countdown::WIDTH,
+                        height: countdown::HEIGHT,
+                    };
+                    render_countdown(frame, area, self.game_state.players[current_player].year, deadline);
+                }
+            }
+        }
+        // Next-draw odds over the top-right of the board, under any dialog
+        if self.layout_prefs.show_draw_odds {
+            let decks = self.game_state.deck_stats();
+            let (width, height) = (draw_odds::WIDTH, draw_odds::height(decks.len()));
+            if game_board_area.width >= width + 2 && game_board_area.height >= height + 2 {
+                let area = Rect { x: game_board_area.x + game_board_area.width - width - 1, y: game_board_area.y + 1, width, height };
+                render_draw_odds(frame, area, &decks);
+            }
+        }
+        if let Some(log_area) = app_layout.log {
+            render_log(frame, log_area, &self.log_entries, self.log_scroll_offset, &player_name_colors(&self.game_state), &self.log_view);
+        }
 
         // Render status bar with key instructions
-        let status_text = match self.ui_state {
-            UiState::Game => "q: Quit | Enter: Roll | Shift+↑/↓: Scroll | Shift+PgUp/PgDn: Page | Shift+Home/End: Top/Bottom",
-            UiState::TurnMenu { .. } => "O: Option to Buy | P: Pay Loan | E: End Turn | Esc: Skip | Shift+↑/↓: Scroll | Shift+PgUp/PgDn: Page",
-            UiState::OptionToBuy { .. } => "↑/↓: Select card | Enter: Buy | Esc: Skip | Shift+↑/↓: Scroll | Shift+PgUp/PgDn: Page",
-            UiState::LoanPayment { .. } => "↑/↓: Adjust by $100 | PgUp/PgDn: Adjust by $1000 | Enter: Confirm | Esc: Cancel | Shift+↑/↓: Scroll",
+        let status_key = match self.ui_state {
+            UiState::Game => "status.game",
+            UiState::Animating { .. } => "status.animating",
+            UiState::TurnMenu { .. } => "status.turn_menu",
+            UiState::OptionToBuy { .. } => "status.option_to_buy",
+            UiState::LoanPayment { .. } => "status.loan_payment",
+            UiState::Insurance { .. } => "status.insurance",
+            UiState::Market { .. } => "status.market",
+            UiState::Livestock { .. } => "status.livestock",
+            UiState::Auction { .. } => "status.auction",
+            UiState::Decision { .. } => "status.decision",
+            UiState::RaiseCash { .. } => "status.raise_cash",
+            UiState::Discard { .. } => "status.discard",
+            UiState::Achievements => "status.achievements",
+            UiState::TurnHistory { .. } => "status.turn_history",
+            UiState::Options { .. } => "status.options",
+            UiState::Paused { .. } => "status.paused",
+            UiState::TurnSummary { .. } => "status.turn_summary",
+            UiState::Eliminated { .. } => "status.eliminated",
+            UiState::TieBreak { .. } => "status.tie_break",
+            UiState::GameOver { .. } => "status.game_over",
         };
+        let status_key = if self.debug.as_ref().is_some_and(|console| console.open) { "status.debug" } else { status_key };
+        let difficulty = self.game_state.locale.difficulty_label(self.game_state.rules.difficulty);
+        let status_text = format!("{} | {} | {}", self.game_state.date_label(), difficulty, self.tr(status_key, &[]));
         
         let status_bar = Paragraph::new(status_text)
             .style(Style::default().fg(Color::Cyan))
@@ -651,12 +1801,12 @@ impl App {
         // Conditionally render dialogs/menus on top, centered within game_board_area
         match &self.ui_state {
             UiState::TurnMenu { player_id } => {
-                let has_otb_cards = !self.game_state.get_option_to_buy_cards(*player_id).is_empty() && 
-                                    self.game_state.can_exercise_option_to_buy(*player_id);
+                let has_otb_cards = self.game_state.turn_actions(*player_id).iter()
+                    .any(|action| matches!(action, Action::ExerciseOption { .. }));
                 
-                // Calculate centered rect for turn menu (e.g., 60x15)
-                let popup_area = centered_fixed_rect(60, 15, game_board_area);
-                render_turn_menu(frame, popup_area, &self.game_state, *player_id, has_otb_cards);
+                // Calculate centered rect for turn menu (e.g., 60x19, room for a harvest preview)
+                let popup_area = centered_fixed_rect(60, 19, game_board_area);
+                render_turn_menu(frame, popup_area, &self.game_state, *player_id, has_otb_cards, &self.keymap);
             },
             UiState::OptionToBuy { player_id, selected_index } => {
                 // Calculate centered rect for O.T.B. dialog (reduced height: 80x20)
@@ -664,12 +1814,106 @@ impl App {
                 render_option_dialog(frame, popup_area, &self.game_state, *player_id, *selected_index);
             },
             UiState::LoanPayment { player_id, payment_amount } => {
-                // Calculate centered rect for loan payment (e.g., 60x10)
-                let popup_area = centered_fixed_rect(60, 10, game_board_area);
+                // Calculate centered rect for loan payment, with room for the notes
+                let popup_area = centered_fixed_rect(64, 24, game_board_area);
                 let mut payment = *payment_amount;
                 render_loan_payment(frame, popup_area, &self.game_state, *player_id, &mut payment);
             },
+            UiState::Insurance { player_id, selected_index } => {
+                let popup_area = centered_fixed_rect(60, 9, game_board_area);
+                render_insurance(frame, popup_area, &self.game_state, *player_id, *selected_index);
+            },
+            UiState::Market { player_id, quantity } => {
+                let popup_area = centered_fixed_rect(60, 9, game_board_area);
+                render_market(frame, popup_area, &self.game_state, *player_id, *quantity);
+            },
+            UiState::Livestock { player_id, selected, cows } => {
+                let ridges = self.game_state.leased_ridges(*player_id).len() as u16;
+                let popup_area = centered_fixed_rect(64, 10 + ridges, game_board_area);
+                render_livestock(frame, popup_area, &self.game_state, *player_id, *selected, *cows);
+            },
+            UiState::Auction { auction, bid_amount } => {
+                let popup_area = centered_fixed_rect(60, 14, game_board_area);
+                render_auction(frame, popup_area, &self.game_state, auction, *bid_amount);
+            },
+            UiState::Decision { quantity, price, .. } => {
+                if let Some(request) = self.game_state.pending_decision() {
+                    let popup_area = centered_fixed_rect(60, 12, game_board_area);
+                    render_decision(frame, popup_area, &self.game_state, request, *quantity, *price);
+                }
+            },
+            UiState::RaiseCash { selected, quantity, .. } => {
+                if let Some(request) = self.game_state.pending_decision() {
+                    let popup_area = centered_fixed_rect(64, 18, game_board_area);
+                    render_raise_cash(frame, popup_area, &self.game_state, request, *selected, *quantity);
+                }
+            },
+            UiState::Discard { selected, .. } => {
+                if let Some(request) = self.game_state.pending_decision() {
+                    let hand = self.game_state.players[request.player_id].hand.len() as u16;
+                    let popup_area = centered_fixed_rect(72, 9 + hand, game_board_area);
+                    render_discard(frame, popup_area, &self.game_state, request, *selected);
+                }
+            },
+            UiState::Options { selected, .. } => {
+                let popup_area = centered_fixed_rect(56, 10, game_board_area);
+                render_options(frame, popup_area, &self.settings, self.layout_prefs.game_speed, *selected);
+            },
+            UiState::Paused { selected, confirm_quit, saved, .. } => {
+                let popup_area = centered_fixed_rect(48, pause_menu::HEIGHT, game_board_area);
+                render_pause_menu(frame, popup_area, *selected, *confirm_quit, saved.as_deref());
+            },
+            UiState::Achievements => {
+                let popup_area = centered_fixed_rect(70, 14, game_board_area);
+                render_achievements(frame, popup_area, &self.game_state, &self.achievements);
+            },
+            UiState::TurnHistory { player_id, year, scroll } => {
+                let popup_area = centered_fixed_rect(turn_history::WIDTH, turn_history::HEIGHT, game_board_area);
+                render_turn_history(frame, popup_area, &self.game_state, *player_id, *year, *scroll);
+            },
+            UiState::TurnSummary { summary } => {
+                let popup_area = centered_fixed_rect(44, 10 + summary.harvests.len().max(1) as u16, game_board_area);
+                render_turn_summary(frame, popup_area, &self.game_state, summary);
+            },
+            UiState::Eliminated { player_id, .. } => {
+                let popup_area = centered_fixed_rect(60, 9, game_board_area);
+                render_eliminated(frame, popup_area, &self.game_state, *player_id);
+            },
+            UiState::TieBreak { tie_break } => {
+                let popup_area = centered_fixed_rect(64, tie_break::height(tie_break.contenders.len()), game_board_area);
+                render_tie_break(frame, popup_area, &self.game_state, tie_break);
+            },
+            UiState::GameOver { winner, started, exported } => {
+                let popup_area = centered_fixed_rect(72, game_over::height(self.game_state.players.len()), game_board_area);
+                render_game_over(frame, popup_area, &self.game_state, *winner, now.duration_since(*started), exported.as_deref(), &self.keymap);
+            },
+            UiState::Animating { animation } => {
+                let popup_area = centered_fixed_rect(11, 5, game_board_area);
+                render_dice(frame, popup_area, animation.dice_face(now), animation.is_rolling(now));
+            },
             _ => {}
         }
+
+        if let Some(console) = self.debug.as_ref().filter(|console| console.open) {
+            let popup_area = centered_fixed_rect(80, 16, game_board_area);
+            render_debug_console(frame, popup_area, console);
+        }
+
+        if self.show_help {
+            let popup_area = centered_fixed_rect(100, 20, frame.size());
+            render_help(frame, popup_area, &self.keymap);
+        }
+    }
+
+    /// Renders a warning in place of the UI when the terminal is below the minimum size.
+    fn render_too_small(&self, frame: &mut Frame) {
+        let area = frame.size();
+        let warning = Paragraph::new(self.tr("app.too_small", &[
+            ("width", &area.width), ("height", &area.height),
+            ("min_width", &layout::MIN_WIDTH), ("min_height", &layout::MIN_HEIGHT),
+        ]))
+        .style(Style::default().fg(Color::Yellow))
+        .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(warning, area);
     }
 } 
\ No newline at end of file
@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyEvent, KeyModifiers};
+    use ratatui::prelude::Rect;
+    use crate::game::GameStateBuilder;
+    use crate::game::game_loop::handle_player_turn;
+    use crate::models::{AssetType, Money, Player, PlayerType};
+    use crate::settings::{KeyProfile, Settings, Theme};
+    use crate::ui::app::{App, UiState};
+    use crate::ui::keymap::{KeyAction, Keymap};
+    use crate::ui::layout;
+
+    /// Two human farmers; the first has $3,000 in cash and owes the bank $5,000.
+    fn setup_app() -> App {
+        let farmers = (0..2).fold(GameStateBuilder::default(), |builder, id| {
+            let mut player = Player::new(id, format!("Farmer {}", id), PlayerType::Human);
+            player.cash = Money(3000);
+            player.debt = Money(5000);
+            builder.add_player(player)
+        });
+        App::new(farmers.build().unwrap())
+    }
+
+    #[test]
+    fn test_achievements_open_and_close() {
+        let mut app = setup_app();
+        app.update(KeyAction::Achievements);
+        assert!(matches!(app.ui_state, UiState::Achievements));
+        app.update(KeyAction::Roll);
+        assert!(matches!(app.ui_state, UiState::Achievements), "Rolling does nothing on this screen");
+        app.update(KeyAction::Back);
+        assert!(matches!(app.ui_state, UiState::Game));
+    }
+
+    #[test]
+    fn test_turn_history_browses_players_and_years() {
+        let mut game = GameStateBuilder::default().add_human("Ann").add_human("Bo").build().unwrap();
+        handle_player_turn(&mut game, 0, 2).unwrap();
+        game.advance_turn();
+        game.advance_turn();
+        game.players[0].year = 2;
+        handle_player_turn(&mut game, 0, 3).unwrap();
+        let mut app = App::new(game);
+
+        app.update(KeyAction::TurnHistory);
+        assert!(matches!(app.ui_state, UiState::TurnHistory { player_id: 0, year: 2, scroll: 0 }), "Opens on the current player's year");
+        app.update(KeyAction::StepBack);
+        assert!(matches!(app.ui_state, UiState::TurnHistory { year: 1, .. }));
+        app.update(KeyAction::StepBack);
+        assert!(matches!(app.ui_state, UiState::TurnHistory { year: 1, .. }), "No turns before year 1");
+        app.update(KeyAction::Down);
+        assert!(matches!(app.ui_state, UiState::TurnHistory { player_id: 1, year: 1, .. }));
+        app.update(KeyAction::Up);
+        assert!(matches!(app.ui_state, UiState::TurnHistory { player_id: 0, .. }));
+        app.update(KeyAction::Back);
+        assert!(matches!(app.ui_state, UiState::Game));
+    }
+
+    #[test]
+    fn test_options_change_settings_between_turns() {
+        let game = GameStateBuilder::default().add_human("Farmer").build().unwrap();
+        let mut app = App::with_settings(game, Settings::default());
+        app.update(KeyAction::Options);
+        assert!(matches!(app.ui_state, UiState::Options { selected: 0, paused: None }));
+
+        app.update(KeyAction::Confirm);
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Confirm);
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Confirm);
+        assert_eq!(app.settings(), &Settings { theme: Theme::HighContrast, keymap: KeyProfile::Vim, autosave: true, ..Settings::default() });
+        assert!(matches!(app.ui_state, UiState::Options { selected: 3, .. }), "The last row is as far as it goes");
+    }
+
+    #[test]
+    fn test_quitting_asks_first_and_the_pause_menu_resumes_where_it_left_off() {
+        let mut app = setup_app();
+        app.ui_state = UiState::TurnMenu { player_id: 0 };
+        app.update(KeyAction::Quit);
+        assert!(matches!(app.ui_state, UiState::Paused { confirm_quit: true, .. }), "q asks before quitting");
+        app.update(KeyAction::No);
+        assert!(matches!(app.ui_state, UiState::Paused { confirm_quit: false, selected: 0, .. }));
+        app.update(KeyAction::Back);
+        assert!(matches!(app.ui_state, UiState::TurnMenu { player_id: 0 }), "Resuming goes back to the turn menu");
+
+        app.update(KeyAction::Pause);
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Confirm);
+        assert!(matches!(app.ui_state, UiState::Options { paused: Some(_), .. }));
+        app.update(KeyAction::Back);
+        assert!(matches!(app.ui_state, UiState::Paused { selected: 1, .. }), "Options close back to the menu");
+
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Down);
+        app.update(KeyAction::Confirm);
+        app.update(KeyAction::Yes);
+        assert!(!app.is_running());
+    }
+
+    #[test]
+    fn test_roll_plays_out_the_turn() {
+        let mut app = setup_app();
+        app.update(KeyAction::Roll);
+        if matches!(app.ui_state, UiState::Animating { .. }) {
+            app.update(KeyAction::SkipAnimation);
+        }
+        assert!(!matches!(app.ui_state, UiState::Animating { .. }));
+        assert_eq!(app.game_state().players[0].turns_taken, 1);
+    }
+
+    #[test]
+    fn test_turn_menu_opens_dialogs_the_player_can_use() {
+        let mut app = setup_app();
+        app.ui_state = UiState::TurnMenu { player_id: 0 };
+
+        app.update(KeyAction::Market);
+        assert!(matches!(app.ui_state, UiState::TurnMenu { player_id: 0 }), "No cows to sell");
+
+        app.update(KeyAction::PayLoan);
+        assert!(matches!(app.ui_state, UiState::LoanPayment { player_id: 0, payment_amount: Money(1000) }));
+        app.update(KeyAction::Up);
+        app.update(KeyAction::PageUp);
+        assert!(matches!(app.ui_state, UiState::LoanPayment { payment_amount: Money(3000), .. }), "Payments stop at the cash on hand");
+        app.update(KeyAction::Back);
+        assert!(matches!(app.ui_state, UiState::TurnMenu { player_id: 0 }));
+    }
+
+    #[test]
+    fn test_market_quantity_stays_within_the_herd() {
+        let mut player = Player::new(0, "Rancher".to_string(), PlayerType::Human);
+        player.add_asset(AssetType::Cows, 4, Money::ZERO);
+        let mut app = App::new(GameStateBuilder::default().add_player(player).build().unwrap());
+        app.ui_state = UiState::TurnMenu { player_id: 0 };
+
+        app.update(KeyAction::Market);
+        app.update(KeyAction::PageUp);
+        assert!(matches!(app.ui_state, UiState::Market { quantity: 4, .. }));
+        app.update(KeyAction::Down);
+        assert!(matches!(app.ui_state, UiState::Market { quantity: 3, .. }));
+    }
+
+    #[test]
+    fn test_livestock_moves_cows_to_a_leased_ridge() {
+        let ranchers = (0..2).fold(GameStateBuilder::default(), |builder, id| {
+            let mut player = Player::new(id, format!("Rancher {}", id), PlayerType::Human);
+            player.add_asset(AssetType::Cows, 4, Money::ZERO);
+            builder.add_player(player)
+        });
+        let mut game = ranchers.build().unwrap();
+        game.ridges[1].lease(0, 18, 10).unwrap();
+        let mut app = App::new(game);
+
+        app.ui_state = UiState::TurnMenu { player_id: 1 };
+        app.update(KeyAction::Livestock);
+        assert!(matches!(app.ui_state, UiState::TurnMenu { .. }), "No ridge, nowhere to move cows");
+
+        app.ui_state = UiState::TurnMenu { player_id: 0 };
+        app.update(KeyAction::Livestock);
+        for _ in 0..3 {
+            app.update(KeyAction::PageUp);
+        }
+        assert!(matches!(app.ui_state, UiState::Livestock { cows: 2, .. }), "Ahtanum Ridge only has room for 2");
+        app.update(KeyAction::Confirm);
+        assert_eq!(app.game_state().ridges[1].cow_count, 20);
+        assert!(matches!(app.ui_state, UiState::Livestock { cows: 0, .. }));
+    }
+
+    #[test]
+    fn test_keys_map_to_the_screens_actions() {
+        let mut app = setup_app();
+        let keymap = Keymap::load();
+        let press = |action: KeyAction| {
+            let binding = keymap.keys(action)[0];
+            KeyEvent::new(binding.code, if binding.shift { KeyModifiers::SHIFT } else { KeyModifiers::NONE })
+        };
+        assert_eq!(app.action_for(&press(KeyAction::Roll)), Some(KeyAction::Roll));
+        assert_eq!(app.action_for(&press(KeyAction::DebugConsole)), None, "The console needs --debug");
+
+        app.ui_state = UiState::TurnMenu { player_id: 0 };
+        assert_eq!(app.action_for(&press(KeyAction::EndTurn)), Some(KeyAction::EndTurn));
+        assert_eq!(app.action_for(&press(KeyAction::Quit)), Some(KeyAction::Quit), "Quitting works on every screen");
+
+        app.enable_debug();
+        assert_eq!(app.action_for(&press(KeyAction::DebugConsole)), Some(KeyAction::DebugConsole));
+    }
+
+    #[test]
+    fn test_only_quitting_works_while_the_terminal_is_too_small() {
+        let mut app = setup_app();
+        let keymap = Keymap::load();
+        let press = |action: KeyAction| {
+            let binding = keymap.keys(action)[0];
+            KeyEvent::new(binding.code, if binding.shift { KeyModifiers::SHIFT } else { KeyModifiers::NONE })
+        };
+
+        app.on_resize(Rect::new(0, 0, 60, 20));
+        assert_eq!(app.action_for(&press(KeyAction::Roll)), None, "Rolling behind the warning would go unseen");
+        assert_eq!(app.action_for(&press(KeyAction::Quit)), Some(KeyAction::Quit));
+
+        app.on_resize(Rect::new(0, 0, layout::MIN_WIDTH, layout::MIN_HEIGHT));
+        assert_eq!(app.action_for(&press(KeyAction::Roll)), Some(KeyAction::Roll));
+    }
+
+    #[test]
+    fn test_popups_stay_inside_their_area() {
+        let area = Rect::new(10, 5, 30, 12);
+        assert_eq!(layout::popup_rect(80, 20, area), Rect::new(12, 7, 26, 8));
+        assert_eq!(layout::popup_rect(10, 4, area), Rect::new(20, 9, 10, 4));
+        assert_eq!(layout::popup_rect(10, 4, Rect::new(0, 0, 2, 2)), Rect::new(1, 1, 0, 0));
+    }
+
+    #[test]
+    fn test_a_win_ends_on_the_victory_screen_and_a_rematch_starts_over() {
+        let mut game = GameStateBuilder::default().add_human("Farmer 0").add_human("Farmer 1").build().unwrap();
+        game.players[0].cash = Money(300_000);
+        game.update_all_valuations();
+        let mut app = App::new(game);
+        app.ui_state = UiState::TurnMenu { player_id: 0 };
+
+        app.update(KeyAction::EndTurn);
+        assert!(matches!(app.ui_state, UiState::GameOver { winner: 0, exported: None, .. }));
+        app.update(KeyAction::Roll);
+        assert!(matches!(app.ui_state, UiState::GameOver { .. }), "The game is over");
+
+        app.update(KeyAction::Rematch);
+        assert!(matches!(app.ui_state, UiState::Game));
+        let state = app.game_state();
+        assert!(state.players.iter().all(|player| player.cash < Money(300_000) && player.hand.len() == 2));
+        assert!(state.events.is_empty());
+        assert_eq!(state.turn_order, vec![1, 0], "The other farmer starts the rematch");
+    }
+}
@@ -0,0 +1,28 @@
+// src/ui/colors.rs
+// Terminal colors for players, shared by every widget that shows whose is whose.
+
+use ratatui::style::Color;
+use crate::models::{GameState, PlayerColor};
+
+/// Terminal color for a player's token color.
+pub fn player_color(color: PlayerColor) -> Color {
+    match color {
+        PlayerColor::Red => Color::Red,
+        PlayerColor::Brown => Color::Rgb(181, 101, 29),
+        PlayerColor::Green => Color::Green,
+        PlayerColor::Blue => Color::LightBlue,
+        PlayerColor::White => Color::White,
+        PlayerColor::Yellow => Color::Yellow,
+    }
+}
+
+/// Each player's name with their terminal color, longest name first so a
+/// name that prefixes another (e.g. "Bo" and "Bob") doesn't match first.
+pub fn player_name_colors(game_state: &GameState) -> Vec<(String, Color)> {
+    let mut names: Vec<(String, Color)> = game_state.turn_order.iter()
+        .filter_map(|id| game_state.players.get(*id))
+        .map(|player| (player.name.clone(), player_color(player.color)))
+        .collect();
+    names.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+    names
+}
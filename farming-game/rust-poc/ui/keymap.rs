@@ -0,0 +1,453 @@
+// src/ui/keymap.rs
+// Which keys trigger which actions. The default profile matches the keys the
+// game has always used, and the settings can pick another (`KeyProfile`); a
+// `keymap` file in the config directory can rebind any action on top.
+
+use std::fs;
+use std::path::PathBuf;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::settings::{self, KeyProfile};
+
+/// Something a key press can do. Which actions apply depends on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    Quit,
+    Pause,
+    Help,
+    Roll,
+    EndTurn,
+    OpenOtb,
+    PayLoan,
+    Insurance,
+    Market,
+    Livestock,
+    Achievements,
+    TurnHistory,
+    Options,
+    Auction,
+    Confirm,
+    Back,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Yes,
+    No,
+    Pass,
+    SkipAnimation,
+    ScrollUp,
+    ScrollDown,
+    ScrollPageUp,
+    ScrollPageDown,
+    ScrollTop,
+    ScrollBottom,
+    SearchLog,
+    NextMatch,
+    PrevMatch,
+    FilterLog,
+    ToggleLog,
+    FullLog,
+    ToggleScoreboard,
+    SortScoreboard,
+    DrawOdds,
+    ShrinkBoard,
+    GrowBoard,
+    GameSpeed,
+    ToggleSound,
+    DebugConsole,
+    StepBack,
+    StepForward,
+    Export,
+    Rematch,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 48] = [
+        KeyAction::Quit,
+        KeyAction::Pause,
+        KeyAction::Help,
+        KeyAction::Roll,
+        KeyAction::EndTurn,
+        KeyAction::OpenOtb,
+        KeyAction::PayLoan,
+        KeyAction::Insurance,
+        KeyAction::Market,
+        KeyAction::Livestock,
+        KeyAction::Achievements,
+        KeyAction::TurnHistory,
+        KeyAction::Options,
+        KeyAction::Auction,
+        KeyAction::Confirm,
+        KeyAction::Back,
+        KeyAction::Up,
+        KeyAction::Down,
+        KeyAction::PageUp,
+        KeyAction::PageDown,
+        KeyAction::Yes,
+        KeyAction::No,
+        KeyAction::Pass,
+        KeyAction::SkipAnimation,
+        KeyAction::ScrollUp,
+        KeyAction::ScrollDown,
+        KeyAction::ScrollPageUp,
+        KeyAction::ScrollPageDown,
+        KeyAction::ScrollTop,
+        KeyAction::ScrollBottom,
+        KeyAction::SearchLog,
+        KeyAction::NextMatch,
+        KeyAction::PrevMatch,
+        KeyAction::FilterLog,
+        KeyAction::ToggleLog,
+        KeyAction::FullLog,
+        KeyAction::ToggleScoreboard,
+        KeyAction::SortScoreboard,
+        KeyAction::DrawOdds,
+        KeyAction::ShrinkBoard,
+        KeyAction::GrowBoard,
+        KeyAction::GameSpeed,
+        KeyAction::ToggleSound,
+        KeyAction::DebugConsole,
+        KeyAction::StepBack,
+        KeyAction::StepForward,
+        KeyAction::Export,
+        KeyAction::Rematch,
+    ];
+
+    /// Name used in the keymap file.
+    pub fn id(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::Pause => "pause",
+            KeyAction::Help => "help",
+            KeyAction::Roll => "roll",
+            KeyAction::EndTurn => "end_turn",
+            KeyAction::OpenOtb => "open_otb",
+            KeyAction::PayLoan => "pay_loan",
+            KeyAction::Insurance => "insurance",
+            KeyAction::Market => "market",
+            KeyAction::Livestock => "livestock",
+            KeyAction::Achievements => "achievements",
+            KeyAction::TurnHistory => "turn_history",
+            KeyAction::Options => "options",
+            KeyAction::Auction => "auction",
+            KeyAction::Confirm => "confirm",
+            KeyAction::Back => "back",
+            KeyAction::Up => "up",
+            KeyAction::Down => "down",
+            KeyAction::PageUp => "page_up",
+            KeyAction::PageDown => "page_down",
+            KeyAction::Yes => "yes",
+            KeyAction::No => "no",
+            KeyAction::Pass => "pass",
+            KeyAction::SkipAnimation => "skip_animation",
+            KeyAction::ScrollUp => "scroll_up",
+            KeyAction::ScrollDown => "scroll_down",
+            KeyAction::ScrollPageUp => "scroll_page_up",
+            KeyAction::ScrollPageDown => "scroll_page_down",
+            KeyAction::ScrollTop => "scroll_top",
+            KeyAction::ScrollBottom => "scroll_bottom",
+            KeyAction::SearchLog => "search_log",
+            KeyAction::NextMatch => "next_match",
+            KeyAction::PrevMatch => "prev_match",
+            KeyAction::FilterLog => "filter_log",
+            KeyAction::ToggleLog => "toggle_log",
+            KeyAction::FullLog => "full_log",
+            KeyAction::ToggleScoreboard => "toggle_scoreboard",
+            KeyAction::SortScoreboard => "sort_scoreboard",
+            KeyAction::DrawOdds => "draw_odds",
+            KeyAction::ShrinkBoard => "shrink_board",
+            KeyAction::GrowBoard => "grow_board",
+            KeyAction::GameSpeed => "game_speed",
+            KeyAction::ToggleSound => "toggle_sound",
+            KeyAction::DebugConsole => "debug_console",
+            KeyAction::StepBack => "step_back",
+            KeyAction::StepForward => "step_forward",
+            KeyAction::Export => "export",
+            KeyAction::Rematch => "rematch",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    /// Line shown for the action in the help overlay.
+    pub fn description(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "Quit the game, once you confirm",
+            KeyAction::Pause => "Pause: resume, options, save or quit",
+            KeyAction::Help => "Show or hide this help",
+            KeyAction::Roll => "Roll the dice",
+            KeyAction::EndTurn => "End your turn",
+            KeyAction::OpenOtb => "Option to Buy cards",
+            KeyAction::PayLoan => "Pay down your loan",
+            KeyAction::Insurance => "Buy insurance",
+            KeyAction::Market => "Sell cows at market",
+            KeyAction::Livestock => "Move cows between the farm and your ridges",
+            KeyAction::Achievements => "Show achievements",
+            KeyAction::TurnHistory => "Browse each player's turns",
+            KeyAction::Options => "Theme, keys and autosave options",
+            KeyAction::Auction => "Auction the selected O.T.B. card",
+            KeyAction::Confirm => "Confirm / buy / bid",
+            KeyAction::Back => "Back / cancel",
+            KeyAction::Up => "Select up / increase",
+            KeyAction::Down => "Select down / decrease",
+            KeyAction::PageUp => "Increase by a lot",
+            KeyAction::PageDown => "Decrease by a lot",
+            KeyAction::Yes => "Answer yes",
+            KeyAction::No => "Answer no",
+            KeyAction::Pass => "Pass in an auction",
+            KeyAction::SkipAnimation => "Skip the dice animation",
+            KeyAction::ScrollUp => "Scroll the log up",
+            KeyAction::ScrollDown => "Scroll the log down",
+            KeyAction::ScrollPageUp => "Scroll the log up a page",
+            KeyAction::ScrollPageDown => "Scroll the log down a page",
+            KeyAction::ScrollTop => "Scroll to the start of the log",
+            KeyAction::ScrollBottom => "Scroll to the end of the log",
+            KeyAction::SearchLog => "Search the log",
+            KeyAction::NextMatch => "Jump to the next match",
+            KeyAction::PrevMatch => "Jump to the previous match",
+            KeyAction::FilterLog => "Filter the log by category",
+            KeyAction::ToggleLog => "Show or hide the log",
+            KeyAction::FullLog => "Full-screen log",
+            KeyAction::ToggleScoreboard => "Show or hide the scoreboard",
+            KeyAction::SortScoreboard => "Sort the scoreboard by turn order or rank",
+            KeyAction::DrawOdds => "Show or hide each deck's next-draw odds",
+            KeyAction::ShrinkBoard => "Narrow the board",
+            KeyAction::GrowBoard => "Widen the board",
+            KeyAction::GameSpeed => "Change the animation speed",
+            KeyAction::ToggleSound => "Mute or unmute sound cues",
+            KeyAction::DebugConsole => "Open the debug console (--debug)",
+            KeyAction::StepBack => "Previous turn of a replay, or year of the turn history",
+            KeyAction::StepForward => "Next turn of a replay, or year of the turn history",
+            KeyAction::Export => "Save the final results",
+            KeyAction::Rematch => "Play again with the same players",
+        }
+    }
+}
+
+/// A key, optionally held with Shift. Letters match either case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub shift: bool,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode) -> Self {
+        Self { code, shift: false }
+    }
+
+    pub fn shifted(code: KeyCode) -> Self {
+        Self { code, shift: true }
+    }
+
+    /// Parses a key as written in the keymap file, e.g. `q`, `Enter`, `Shift+PgUp`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (shift, name) = match text.strip_prefix("Shift+") {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let code = match name {
+            "Enter" => KeyCode::Enter,
+            "Esc" => KeyCode::Esc,
+            "Space" => KeyCode::Char(' '),
+            "Tab" => KeyCode::Tab,
+            "Backspace" => KeyCode::Backspace,
+            "Up" => KeyCode::Up,
+            "Down" => KeyCode::Down,
+            "Left" => KeyCode::Left,
+            "Right" => KeyCode::Right,
+            "PgUp" => KeyCode::PageUp,
+            "PgDn" => KeyCode::PageDown,
+            "Home" => KeyCode::Home,
+            "End" => KeyCode::End,
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c.to_ascii_lowercase()),
+                    _ => return None,
+                }
+            }
+        };
+        Some(Self { code, shift })
+    }
+
+    /// How the key is written in the keymap file and shown in help.
+    pub fn label(&self) -> String {
+        let name = match self.code {
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            other => format!("{:?}", other),
+        };
+        if self.shift { format!("Shift+{}", name) } else { name }
+    }
+
+    /// Whether `key` is this binding. Shift only matters for non-character keys,
+    /// since terminals report Shift inconsistently for letters.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        match (self.code, key.code) {
+            (KeyCode::Char(bound), KeyCode::Char(pressed)) => bound.eq_ignore_ascii_case(&pressed),
+            (bound, pressed) => bound == pressed && self.shift == key.modifiers.contains(KeyModifiers::SHIFT),
+        }
+    }
+}
+
+/// Keys bound to each action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: Vec<(KeyAction, Vec<KeyBinding>)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use KeyAction::*;
+        let key = |c: char| KeyBinding::new(KeyCode::Char(c));
+        let code = KeyBinding::new;
+        let shift = KeyBinding::shifted;
+        Self {
+            bindings: vec![
+                (Quit, vec![key('q')]),
+                (Pause, vec![code(KeyCode::Esc)]),
+                (Help, vec![key('?')]),
+                (Roll, vec![code(KeyCode::Enter), key(' ')]),
+                (EndTurn, vec![key('e')]),
+                (OpenOtb, vec![key('o')]),
+                (PayLoan, vec![key('p')]),
+                (Insurance, vec![key('i')]),
+                (Market, vec![key('m')]),
+                (Livestock, vec![key('t')]),
+                (Achievements, vec![key('a')]),
+                (TurnHistory, vec![key('h')]),
+                (Options, vec![key('o')]),
+                (Auction, vec![key('a')]),
+                (Confirm, vec![code(KeyCode::Enter)]),
+                (Back, vec![code(KeyCode::Esc)]),
+                (Up, vec![code(KeyCode::Up)]),
+                (Down, vec![code(KeyCode::Down)]),
+                (PageUp, vec![code(KeyCode::PageUp)]),
+                (PageDown, vec![code(KeyCode::PageDown)]),
+                (Yes, vec![key('y')]),
+                (No, vec![key('n'), code(KeyCode::Esc)]),
+                (Pass, vec![key('p'), code(KeyCode::Esc)]),
+                (SkipAnimation, vec![code(KeyCode::Enter), key(' '), code(KeyCode::Esc)]),
+                (ScrollUp, vec![shift(KeyCode::Up)]),
+                (ScrollDown, vec![shift(KeyCode::Down)]),
+                (ScrollPageUp, vec![shift(KeyCode::PageUp)]),
+                (ScrollPageDown, vec![shift(KeyCode::PageDown)]),
+                (ScrollTop, vec![shift(KeyCode::Home)]),
+                (ScrollBottom, vec![shift(KeyCode::End)]),
+                (SearchLog, vec![key('/')]),
+                (NextMatch, vec![key('>')]),
+                (PrevMatch, vec![key('<')]),
+                (FilterLog, vec![key('c')]),
+                (ToggleLog, vec![key('l')]),
+                (FullLog, vec![key('f')]),
+                (ToggleScoreboard, vec![key('s')]),
+                (SortScoreboard, vec![key('r')]),
+                (DrawOdds, vec![key('d')]),
+                (ShrinkBoard, vec![key('[')]),
+                (GrowBoard, vec![key(']')]),
+                (GameSpeed, vec![key('g')]),
+                (ToggleSound, vec![key('v')]),
+                (DebugConsole, vec![key('~')]),
+                (StepBack, vec![code(KeyCode::Left)]),
+                (StepForward, vec![code(KeyCode::Right)]),
+                (Export, vec![key('x')]),
+                (Rematch, vec![key('n')]),
+            ],
+        }
+    }
+}
+
+/// Name of the keymap file inside the config directory.
+pub const KEYMAP_FILE: &str = "keymap";
+
+impl Keymap {
+    /// Location of the keymap file, next to the settings file.
+    fn keymap_path() -> Option<PathBuf> {
+        settings::config_dir().map(|dir| dir.join(KEYMAP_FILE))
+    }
+
+    /// Loads the keymap file over the default profile; without one, the defaults apply.
+    pub fn load() -> Self {
+        Self::load_over(KeyProfile::Classic)
+    }
+
+    /// Loads the keymap file over `profile`'s keys.
+    pub fn load_over(profile: KeyProfile) -> Self {
+        let keymap = Self::profile(profile);
+        match Self::keymap_path().and_then(|path| fs::read_to_string(path).ok()) {
+            Some(contents) => keymap.rebind(&contents),
+            None => keymap,
+        }
+    }
+
+    /// The keys a profile binds, before any keymap file.
+    pub fn profile(profile: KeyProfile) -> Self {
+        let mut keymap = Self::default();
+        if profile == KeyProfile::Vim {
+            for (action, c) in [(KeyAction::Up, 'k'), (KeyAction::Down, 'j')] {
+                let mut keys = keymap.keys(action).to_vec();
+                keys.push(KeyBinding::new(KeyCode::Char(c)));
+                keymap.bind(action, keys);
+            }
+        }
+        keymap
+    }
+
+    /// Parses lines like `end_turn = e, Space`. Each listed action has its
+    /// keys replaced; unknown actions and keys are ignored.
+    pub(crate) fn rebind(mut self, contents: &str) -> Self {
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            let Some((id, keys)) = line.split_once('=') else { continue };
+            let Some(action) = KeyAction::from_id(id.trim()) else { continue };
+            let keys: Vec<KeyBinding> = keys.split(',').filter_map(KeyBinding::parse).collect();
+            if !keys.is_empty() {
+                self.bind(action, keys);
+            }
+        }
+        self
+    }
+
+    fn bind(&mut self, action: KeyAction, keys: Vec<KeyBinding>) {
+        match self.bindings.iter_mut().find(|(bound, _)| *bound == action) {
+            Some((_, bound_keys)) => *bound_keys = keys,
+            None => self.bindings.push((action, keys)),
+        }
+    }
+
+    pub fn keys(&self, action: KeyAction) -> &[KeyBinding] {
+        self.bindings.iter()
+            .find(|(bound, _)| *bound == action)
+            .map_or(&[], |(_, keys)| keys.as_slice())
+    }
+
+    /// Keys for `action` joined for display, e.g. "Enter/Space".
+    pub fn label(&self, action: KeyAction) -> String {
+        self.keys(action).iter().map(KeyBinding::label).collect::<Vec<_>>().join("/")
+    }
+
+    /// The first of `actions` that `key` is bound to. Screens list the actions
+    /// they handle, so one key can mean different things on different screens.
+    pub fn action(&self, key: &KeyEvent, actions: &[KeyAction]) -> Option<KeyAction> {
+        actions.iter().copied().find(|action| self.keys(*action).iter().any(|binding| binding.matches(key)))
+    }
+}
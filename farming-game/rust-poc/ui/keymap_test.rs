@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use crate::settings::KeyProfile;
+    use crate::ui::keymap::{KeyAction, KeyBinding, Keymap};
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_default_profile_depends_on_screen() {
+        let keymap = Keymap::default();
+        let a = press(KeyCode::Char('a'));
+        assert_eq!(keymap.action(&a, &[KeyAction::Roll, KeyAction::Achievements]), Some(KeyAction::Achievements));
+        assert_eq!(keymap.action(&a, &[KeyAction::Confirm, KeyAction::Auction]), Some(KeyAction::Auction));
+        assert_eq!(keymap.action(&press(KeyCode::Char('E')), &[KeyAction::EndTurn]), Some(KeyAction::EndTurn), "Letters match either case");
+
+        let shift_up = KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT);
+        assert_eq!(keymap.action(&shift_up, &[KeyAction::Up, KeyAction::ScrollUp]), Some(KeyAction::ScrollUp));
+        assert_eq!(keymap.action(&press(KeyCode::Up), &[KeyAction::Up, KeyAction::ScrollUp]), Some(KeyAction::Up));
+    }
+
+    #[test]
+    fn test_keymap_file_rebinds_listed_actions() {
+        let keymap = Keymap::default().rebind("# vim-ish\nend_turn = x, Space\nscroll_up = Shift+k\nno_such_action = z\nquit = Nope\n");
+
+        assert_eq!(keymap.keys(KeyAction::EndTurn), &[KeyBinding::new(KeyCode::Char('x')), KeyBinding::new(KeyCode::Char(' '))]);
+        assert_eq!(keymap.label(KeyAction::EndTurn), "x/Space");
+        assert_eq!(keymap.label(KeyAction::ScrollUp), "Shift+k");
+        assert_eq!(keymap.keys(KeyAction::Quit), Keymap::default().keys(KeyAction::Quit), "Unparseable keys keep the default");
+        assert_eq!(keymap.action(&press(KeyCode::Char('e')), &[KeyAction::EndTurn]), None);
+    }
+
+    #[test]
+    fn test_labels_parse_back() {
+        for action in KeyAction::ALL {
+            for binding in Keymap::default().keys(action) {
+                assert_eq!(KeyBinding::parse(&binding.label()), Some(*binding));
+            }
+            assert_eq!(KeyAction::from_id(action.id()), Some(action));
+        }
+    }
+
+    #[test]
+    fn test_vim_profile_adds_j_and_k() {
+        let keymap = Keymap::profile(KeyProfile::Vim);
+        assert_eq!(keymap.action(&press(KeyCode::Char('k')), &[KeyAction::Up, KeyAction::Down]), Some(KeyAction::Up));
+        assert_eq!(keymap.action(&press(KeyCode::Char('j')), &[KeyAction::Up, KeyAction::Down]), Some(KeyAction::Down));
+        assert_eq!(keymap.action(&press(KeyCode::Up), &[KeyAction::Up]), Some(KeyAction::Up), "The arrows still work");
+        assert_eq!(Keymap::profile(KeyProfile::Classic), Keymap::default());
+    }
+}
@@ -0,0 +1,296 @@
+// src/ui/layout.rs
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use ratatui::prelude::{Constraint, Direction, Layout, Rect};
+use crate::settings;
+use crate::ui::animation::GameSpeed;
+
+/// Smallest terminal the full game UI can be drawn in: room for the board,
+/// the scoreboard and the widest dialogs without clipping them.
+pub const MIN_WIDTH: u16 = 100;
+pub const MIN_HEIGHT: u16 = 30;
+
+/// Step used when resizing the board/log split with `[` and `]`.
+const SPLIT_STEP: u16 = 10;
+const MIN_BOARD_PERCENT: u16 = 20;
+const MAX_BOARD_PERCENT: u16 = 80;
+
+/// Name of the layout file inside the config directory.
+const LAYOUT_FILE: &str = "layout";
+
+/// How the log panel is shown next to the game board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogPanelMode {
+    /// Board and log side by side
+    Split,
+    /// Log hidden, board takes the full width
+    Collapsed,
+    /// Log takes the whole area below the scoreboard
+    FullScreen,
+}
+
+impl LogPanelMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LogPanelMode::Split => "split",
+            LogPanelMode::Collapsed => "collapsed",
+            LogPanelMode::FullScreen => "fullscreen",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "split" => Some(LogPanelMode::Split),
+            "collapsed" => Some(LogPanelMode::Collapsed),
+            "fullscreen" => Some(LogPanelMode::FullScreen),
+            _ => None,
+        }
+    }
+}
+
+/// Order of the scoreboard's rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoreboardOrder {
+    #[default]
+    TurnOrder,
+    /// Richest player first
+    Rank,
+}
+
+impl ScoreboardOrder {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ScoreboardOrder::TurnOrder => "turn_order",
+            ScoreboardOrder::Rank => "rank",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "turn_order" => Some(ScoreboardOrder::TurnOrder),
+            "rank" => Some(ScoreboardOrder::Rank),
+            _ => None,
+        }
+    }
+}
+
+/// User's preferred layout, persisted between sessions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutPrefs {
+    pub log_mode: LogPanelMode,
+    pub board_percent: u16,
+    pub show_scoreboard: bool,
+    pub scoreboard_order: ScoreboardOrder,
+    pub game_speed: GameSpeed,
+    /// Whether the next-draw odds overlay is shown over the board
+    pub show_draw_odds: bool,
+}
+
+impl Default for LayoutPrefs {
+    fn default() -> Self {
+        Self {
+            log_mode: LogPanelMode::Split,
+            board_percent: 50,
+            show_scoreboard: true,
+            scoreboard_order: ScoreboardOrder::default(),
+            game_speed: GameSpeed::default(),
+            show_draw_odds: false,
+        }
+    }
+}
+
+/// Areas computed for a single frame. Panels that are hidden are `None`.
+pub struct AppLayout {
+    pub scoreboard: Option<Rect>,
+    pub board: Option<Rect>,
+    pub log: Option<Rect>,
+    pub status_bar: Rect,
+    /// Area dialogs are centered in (the board, or the log when the board is hidden)
+    pub dialog_host: Rect,
+}
+
+impl LayoutPrefs {
+    /// Location of the layout file, next to the settings file.
+    fn prefs_path() -> Option<PathBuf> {
+        settings::config_dir().map(|dir| dir.join(LAYOUT_FILE))
+    }
+
+    /// Loads saved preferences, falling back to defaults for anything missing or invalid.
+    pub fn load() -> Self {
+        Self::prefs_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// Writes the current preferences to disk.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::prefs_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory on this platform"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.serialize())
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut prefs = Self::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            match key.trim() {
+                "log_mode" => {
+                    if let Some(mode) = LogPanelMode::parse(value.trim()) {
+                        prefs.log_mode = mode;
+                    }
+                }
+                "board_percent" => {
+                    if let Ok(percent) = value.trim().parse::<u16>() {
+                        prefs.board_percent = percent.clamp(MIN_BOARD_PERCENT, MAX_BOARD_PERCENT);
+                    }
+                }
+                "show_scoreboard" => {
+                    prefs.show_scoreboard = value.trim() != "false";
+                }
+                "scoreboard_order" => {
+                    if let Some(order) = ScoreboardOrder::parse(value.trim()) {
+                        prefs.scoreboard_order = order;
+                    }
+                }
+                "game_speed" => {
+                    if let Some(speed) = GameSpeed::parse(value.trim()) {
+                        prefs.game_speed = speed;
+                    }
+                }
+                "show_draw_odds" => {
+                    prefs.show_draw_odds = value.trim() == "true";
+                }
+                _ => {}
+            }
+        }
+        prefs
+    }
+
+    fn serialize(&self) -> String {
+        format!(
+            "log_mode={}\nboard_percent={}\nshow_scoreboard={}\nscoreboard_order={}\ngame_speed={}\nshow_draw_odds={}\n",
+            self.log_mode.as_str(),
+            self.board_percent,
+            self.show_scoreboard,
+            self.scoreboard_order.as_str(),
+            self.game_speed.as_str(),
+            self.show_draw_odds
+        )
+    }
+
+    /// Toggles the log panel between shown and collapsed (`l`).
+    pub fn toggle_log(&mut self) {
+        self.log_mode = match self.log_mode {
+            LogPanelMode::Collapsed => LogPanelMode::Split,
+            _ => LogPanelMode::Collapsed,
+        };
+    }
+
+    /// Toggles full-screen log mode (`f`).
+    pub fn toggle_fullscreen_log(&mut self) {
+        self.log_mode = match self.log_mode {
+            LogPanelMode::FullScreen => LogPanelMode::Split,
+            _ => LogPanelMode::FullScreen,
+        };
+    }
+
+    /// Toggles the scoreboard panel (`s`).
+    pub fn toggle_scoreboard(&mut self) {
+        self.show_scoreboard = !self.show_scoreboard;
+    }
+
+    /// Switches the scoreboard between turn order and rank order (`r`).
+    pub fn toggle_scoreboard_order(&mut self) {
+        self.scoreboard_order = match self.scoreboard_order {
+            ScoreboardOrder::TurnOrder => ScoreboardOrder::Rank,
+            ScoreboardOrder::Rank => ScoreboardOrder::TurnOrder,
+        };
+    }
+
+    /// Shows or hides the next-draw odds overlay (`d`).
+    pub fn toggle_draw_odds(&mut self) {
+        self.show_draw_odds = !self.show_draw_odds;
+    }
+
+    /// Cycles the turn animation speed (`g`).
+    pub fn cycle_game_speed(&mut self) {
+        self.game_speed = self.game_speed.next();
+    }
+
+    /// Gives more width to the board (`]`).
+    pub fn grow_board(&mut self) {
+        self.board_percent = (self.board_percent + SPLIT_STEP).min(MAX_BOARD_PERCENT);
+    }
+
+    /// Gives more width to the log (`[`).
+    pub fn shrink_board(&mut self) {
+        self.board_percent = self.board_percent.saturating_sub(SPLIT_STEP).max(MIN_BOARD_PERCENT);
+    }
+
+    /// Splits the frame into panels according to the preferences.
+    /// `player_count` sizes the scoreboard (title row + one row per player + borders).
+    pub fn compute(&self, area: Rect, player_count: usize) -> AppLayout {
+        let scoreboard_height = if self.show_scoreboard { player_count as u16 + 3 } else { 0 };
+
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(scoreboard_height),
+                Constraint::Min(0),
+                Constraint::Length(1), // Status bar
+            ])
+            .split(area);
+
+        let scoreboard = if self.show_scoreboard { Some(main_layout[0]) } else { None };
+        let bottom_area = main_layout[1];
+        let status_bar = main_layout[2];
+
+        let (board, log) = match self.log_mode {
+            LogPanelMode::Split => {
+                let bottom_layout = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Percentage(self.board_percent),
+                        Constraint::Percentage(100 - self.board_percent),
+                    ])
+                    .split(bottom_area);
+                (Some(bottom_layout[0]), Some(bottom_layout[1]))
+            }
+            LogPanelMode::Collapsed => (Some(bottom_area), None),
+            LogPanelMode::FullScreen => (None, Some(bottom_area)),
+        };
+
+        AppLayout {
+            scoreboard,
+            board,
+            log,
+            status_bar,
+            dialog_host: board.unwrap_or(bottom_area),
+        }
+    }
+}
+
+/// Returns true if the terminal is too small to draw the game UI.
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+/// A `width` x `height` popup centered in `area`, shrunk to keep a two-cell
+/// margin when `area` is smaller. Computed from the area of the frame being
+/// drawn, so it always lies inside it, however the terminal was resized.
+pub fn popup_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width.saturating_sub(4));
+    let height = height.min(area.height.saturating_sub(4));
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
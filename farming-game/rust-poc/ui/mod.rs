@@ -1,4 +1,19 @@
 pub mod terminal;
 pub mod app;
 pub mod widgets;
-// We'll add more modules here later (widgets, etc.) 
\ No newline at end of file
+pub mod layout;
+pub mod animation;
+pub mod colors;
+pub mod keymap;
+pub mod theme;
+pub mod text;
+pub mod replay;
+// We'll add more modules here later (widgets, etc.)
+
+#[cfg(test)]
+mod keymap_test;
+#[cfg(test)]
+mod app_test;
+#[cfg(test)]
+mod text_test;
+
@@ -0,0 +1,154 @@
+// src/ui/replay.rs
+// Read-only TUI for stepping through a recorded game (`--replay <file>`).
+// Shows the scoreboard, board and log of each turn as the replay rebuilt it,
+// with the same layout keys as the live game.
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::io;
+use crossterm::event::{self, Event, KeyEvent};
+use ratatui::{
+    prelude::Frame,
+    style::{Color, Style},
+    widgets::Paragraph,
+};
+use crate::game::Replay;
+use crate::ui::colors::player_name_colors;
+use crate::ui::keymap::{KeyAction, Keymap};
+use crate::ui::layout::{self, LayoutPrefs};
+use crate::ui::terminal::Tui;
+use crate::ui::widgets::game_board::render_game_board;
+use crate::ui::widgets::help::render_help;
+use crate::ui::widgets::log::{render_log, LogEntry, LogView};
+use crate::ui::widgets::scoreboard::render_scoreboard;
+use crate::ui::layout::ScoreboardOrder;
+
+/// Turns skipped by PgUp/PgDn.
+const PAGE_TURNS: usize = 10;
+
+pub struct ReplayViewer {
+    replay: Replay,
+    running: bool,
+    keymap: Keymap,
+    layout_prefs: LayoutPrefs,
+    show_help: bool,
+    log_scroll_offset: usize,
+}
+
+impl ReplayViewer {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            running: true,
+            keymap: Keymap::load(),
+            layout_prefs: LayoutPrefs::load(),
+            show_help: false,
+            log_scroll_offset: 0,
+        }
+    }
+
+    /// Message from the replayed game's locale.
+    fn tr(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        self.replay.current().state.locale.tr(key, args)
+    }
+
+    pub fn run(&mut self, tui: &mut Tui) -> io::Result<()> {
+        while self.running {
+            tui.draw(|frame| self.ui(frame))?;
+            match event::read()? {
+                Event::Key(key) if key.kind == event::KeyEventKind::Press => self.handle_key(key),
+                Event::Resize(width, height) => tui.resize(ratatui::prelude::Rect::new(0, 0, width, height))?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if self.show_help {
+            if self.keymap.action(&key, &[KeyAction::Help, KeyAction::Back]).is_some() {
+                self.show_help = false;
+            }
+            return;
+        }
+        let actions = [KeyAction::Quit, KeyAction::Help, KeyAction::StepBack, KeyAction::StepForward,
+                       KeyAction::PageUp, KeyAction::PageDown, KeyAction::ScrollUp, KeyAction::ScrollDown,
+                       KeyAction::ToggleLog, KeyAction::FullLog, KeyAction::ToggleScoreboard,
+                       KeyAction::ShrinkBoard, KeyAction::GrowBoard];
+        let position = self.replay.position();
+        match self.keymap.action(&key, &actions) {
+            Some(KeyAction::Quit) => self.running = false,
+            Some(KeyAction::Help) => self.show_help = true,
+            Some(KeyAction::StepBack) => { self.replay.back(); }
+            Some(KeyAction::StepForward) => { self.replay.forward(); }
+            Some(KeyAction::PageUp) => self.replay.jump_to(position.saturating_sub(PAGE_TURNS)),
+            Some(KeyAction::PageDown) => self.replay.jump_to(position + PAGE_TURNS),
+            Some(KeyAction::ScrollUp) => self.log_scroll_offset = self.log_scroll_offset.saturating_sub(1),
+            Some(KeyAction::ScrollDown) => self.log_scroll_offset = self.log_scroll_offset.saturating_add(1),
+            Some(KeyAction::ToggleLog) => self.layout_prefs.toggle_log(),
+            Some(KeyAction::FullLog) => self.layout_prefs.toggle_fullscreen_log(),
+            Some(KeyAction::ToggleScoreboard) => self.layout_prefs.toggle_scoreboard(),
+            Some(KeyAction::ShrinkBoard) => self.layout_prefs.shrink_board(),
+            Some(KeyAction::GrowBoard) => self.layout_prefs.grow_board(),
+            _ => {}
+        }
+        if self.replay.position() != position {
+            self.log_scroll_offset = 0;
+        }
+    }
+
+    /// The current turn's log, under a header naming the turn.
+    fn log_entries(&self) -> Vec<LogEntry> {
+        let turn = self.replay.current();
+        let turns = self.replay.turn_count();
+        let header = match turn.player_id.and_then(|id| turn.state.players.get(id)) {
+            Some(player) => self.tr("replay.turn_header", &[
+                ("turn", &self.replay.position()), ("turns", &turns), ("player", &player.name),
+            ]),
+            None => self.tr("replay.start", &[("turns", &turns)]),
+        };
+        let mut entries = vec![LogEntry::new(header)];
+        if turn.player_id.is_some() && turn.logs.is_empty() {
+            entries.push(LogEntry::new(self.tr("replay.no_logs", &[])));
+        }
+        entries.extend(turn.logs.iter().cloned().map(LogEntry::new));
+        entries
+    }
+
+    fn ui(&self, frame: &mut Frame) {
+        let state = &self.replay.current().state;
+        if layout::is_too_small(frame.size()) {
+            let area = frame.size();
+            let warning = Paragraph::new(self.tr("app.too_small", &[
+                ("width", &area.width), ("height", &area.height),
+                ("min_width", &layout::MIN_WIDTH), ("min_height", &layout::MIN_HEIGHT),
+            ]))
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(ratatui::layout::Alignment::Center);
+            frame.render_widget(warning, area);
+            return;
+        }
+
+        let app_layout = self.layout_prefs.compute(frame.size(), state.turn_order.len());
+        if let Some(scoreboard_area) = app_layout.scoreboard {
+            render_scoreboard(frame, scoreboard_area, state, ScoreboardOrder::TurnOrder, &BTreeSet::new());
+        }
+        if let Some(board_area) = app_layout.board {
+            render_game_board(frame, board_area, state, None);
+        }
+        if let Some(log_area) = app_layout.log {
+            render_log(frame, log_area, &self.log_entries(), self.log_scroll_offset,
+                       &player_name_colors(state), &LogView::default());
+        }
+
+        let status_bar = Paragraph::new(format!("{} | {}", state.date_label(), self.tr("status.replay", &[])))
+            .style(Style::default().fg(Color::Cyan))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(status_bar, app_layout.status_bar);
+
+        if self.show_help {
+            let popup_area = layout::popup_rect(100, 20, frame.size());
+            render_help(frame, popup_area, &self.keymap);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// src/ui/text.rs
+// Lays out card text for dialogs. Text is wrapped to the dialog's width, words
+// longer than a line are hyphenated, and whatever doesn't fit is cut off with an
+// ellipsis. Dollar amounts and asset names are picked out in color. Card text is
+// plain ASCII, so widths are counted in characters.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Words naming something a farmer owns, shown in `ASSET_COLOR`.
+const ASSET_WORDS: [&str; 14] = [
+    "hay", "grain", "wheat", "cow", "cows", "cattle", "calves", "fruit", "cherry", "cherries",
+    "tractor", "harvester", "ridge", "acres",
+];
+const MONEY_COLOR: Color = Color::Green;
+const ASSET_COLOR: Color = Color::LightCyan;
+const ELLIPSIS: char = '…';
+
+/// Wraps `text` into lines at most `width` characters wide, breaking at
+/// spaces and at the text's own line breaks. A word longer than a whole line
+/// is split across lines with a hyphen.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    if width == 0 {
+        return lines;
+    }
+    for paragraph in text.lines() {
+        let mut current = String::new();
+        let mut current_len = 0;
+        for word in paragraph.split_whitespace() {
+            let mut word = word.to_string();
+            let mut len = word.chars().count();
+            if current_len > 0 && current_len + 1 + len <= width {
+                current.push(' ');
+                current.push_str(&word);
+                current_len += 1 + len;
+                continue;
+            }
+            if current_len > 0 {
+                lines.push(std::mem::take(&mut current));
+            }
+            while len > width {
+                // A one-column line has no room for the hyphen
+                let cut = if width > 1 { width - 1 } else { 1 };
+                let mut head: String = word.chars().take(cut).collect();
+                if width > 1 {
+                    head.push('-');
+                }
+                lines.push(head);
+                word = word.chars().skip(cut).collect();
+                len -= cut;
+            }
+            current = word;
+            current_len = len;
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+/// `line` cut to `width` characters, ending in an ellipsis if anything was cut.
+pub fn truncate(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+    ellipsize(line, width)
+}
+
+/// `line` ending in an ellipsis, cut short enough that both fit in `width`.
+fn ellipsize(line: &str, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    let mut cut: String = line.chars().take(width - 1).collect();
+    cut.truncate(cut.trim_end().len());
+    cut.push(ELLIPSIS);
+    cut
+}
+
+/// Wraps `text` into at most `max_lines` lines; when text is left over, the
+/// last line ends in an ellipsis.
+pub fn fit(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let mut lines = wrap(text, width);
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            *last = ellipsize(&format!("{} ", last), width);
+        }
+    }
+    lines
+}
+
+/// `line` in `style`, with dollar amounts and asset names picked out in color.
+pub fn highlight(line: &str, style: Style) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    for piece in line.split_inclusive(' ') {
+        let word = piece.trim_end_matches(' ');
+        let start = word.len() - word.trim_start_matches('(').len();
+        let end = word.trim_end_matches([',', '.', ';', ':', '!', '?', ')']).len().max(start);
+        let core = &word[start..end];
+        let Some(color) = highlight_color(core) else {
+            plain.push_str(piece);
+            continue;
+        };
+        plain.push_str(&word[..start]);
+        if !plain.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut plain), style));
+        }
+        spans.push(Span::styled(core.to_string(), style.fg(color).add_modifier(Modifier::BOLD)));
+        plain.push_str(&piece[end..]);
+    }
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, style));
+    }
+    Line::from(spans)
+}
+
+fn highlight_color(word: &str) -> Option<Color> {
+    let amount = word.trim_start_matches('-');
+    if amount.starts_with('$') && amount[1..].chars().any(|c| c.is_ascii_digit()) {
+        return Some(MONEY_COLOR);
+    }
+    let lower = word.to_ascii_lowercase();
+    ASSET_WORDS.contains(&lower.as_str()).then_some(ASSET_COLOR)
+}
+
+/// `text` fitted into a `width` x `max_lines` box and highlighted, ready for a `Paragraph`.
+pub fn rich_text(text: &str, width: usize, max_lines: usize, style: Style) -> Vec<Line<'static>> {
+    fit(text, width, max_lines).iter().map(|line| highlight(line, style)).collect()
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier, Style};
+    use crate::ui::text::{fit, highlight, truncate, wrap};
+
+    const CHERRY_CRASH: &str = "Some TV talk show host does a show on the dangers of farm control spray use on your cherries. \
+        Cut your cherry crop in half if you haven't already harvested this year.";
+
+    #[test]
+    fn test_wrap_breaks_at_spaces_within_the_width() {
+        let lines = wrap(CHERRY_CRASH, 30);
+        assert!(lines.iter().all(|line| line.chars().count() <= 30), "{:?}", lines);
+        assert_eq!(lines.join(" "), CHERRY_CRASH.split_whitespace().collect::<Vec<_>>().join(" "));
+        assert_eq!(lines[0], "Some TV talk show host does a");
+    }
+
+    #[test]
+    fn test_wrap_hyphenates_words_longer_than_a_line() {
+        assert_eq!(wrap("a supermarket-rabid farmer", 6), vec!["a", "super-", "marke-", "t-rab-", "id", "farmer"]);
+        assert_eq!(wrap("hay", 1), vec!["h", "a", "y"]);
+        assert!(wrap("anything", 0).is_empty());
+    }
+
+    #[test]
+    fn test_fit_ends_cut_text_with_an_ellipsis() {
+        assert_eq!(fit("Pay $7,000 in taxes.", 40, 2), vec!["Pay $7,000 in taxes."]);
+        assert_eq!(fit("one two three four", 9, 1), vec!["one two…"]);
+        assert_eq!(fit("one two three four", 7, 1), vec!["one tw…"]);
+        assert_eq!(truncate("Cherry Market Crash", 10), "Cherry Ma…");
+        assert_eq!(truncate("Hay", 10), "Hay");
+    }
+
+    #[test]
+    fn test_highlight_picks_out_money_and_assets() {
+        let style = Style::default().fg(Color::White);
+        let line = highlight("Pay $7,000 or sell (cows).", style);
+        let spans: Vec<(&str, Option<Color>)> = line.spans.iter().map(|span| (span.content.as_ref(), span.style.fg)).collect();
+        assert_eq!(spans, vec![
+            ("Pay ", Some(Color::White)),
+            ("$7,000", Some(Color::Green)),
+            (" or sell (", Some(Color::White)),
+            ("cows", Some(Color::LightCyan)),
+            (").", Some(Color::White)),
+        ]);
+        assert!(line.spans[1].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(highlight("$ only", style).spans.len(), 1, "A bare dollar sign isn't an amount");
+    }
+}
@@ -0,0 +1,45 @@
+// src/ui/theme.rs
+// Themes recolor a finished frame rather than every widget choosing its own
+// palette: widgets keep drawing in the classic colors, and the theme maps
+// each cell's colors just before the frame goes to the terminal.
+
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+use crate::settings::Theme;
+
+/// Recolors every cell of `buffer` for `theme`.
+pub fn apply_theme(theme: Theme, buffer: &mut Buffer) {
+    if theme == Theme::Classic {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        match theme {
+            Theme::Classic => {}
+            Theme::HighContrast => {
+                cell.fg = high_contrast(cell.fg);
+                if cell.bg != Color::Reset && cell.bg != Color::Black {
+                    cell.bg = high_contrast(cell.bg);
+                }
+            }
+            // Selections still show by their `>` markers and bold text
+            Theme::Monochrome => {
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            }
+        }
+    }
+}
+
+/// The bright version of a color; dark grays become plain white.
+fn high_contrast(color: Color) -> Color {
+    match color {
+        Color::Red => Color::LightRed,
+        Color::Green => Color::LightGreen,
+        Color::Yellow | Color::Rgb(..) => Color::LightYellow,
+        Color::Blue => Color::LightBlue,
+        Color::Magenta => Color::LightMagenta,
+        Color::Cyan => Color::LightCyan,
+        Color::Gray | Color::DarkGray => Color::White,
+        other => other,
+    }
+}
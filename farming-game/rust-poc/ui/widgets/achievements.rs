@@ -0,0 +1,61 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::achievements::{Achievement, Achievements};
+use crate::models::GameState;
+
+/// Renders every achievement with the players who have unlocked it.
+pub fn render_achievements(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    achievements: &Achievements,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(8),     // Achievements
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let locale = &game_state.locale;
+    let mut lines = Vec::new();
+    for achievement in Achievement::ALL {
+        let holders = achievements.holders(achievement);
+        let (marker, title_color) = if holders.is_empty() { ("  ", Color::DarkGray) } else { ("🏆", Color::Yellow) };
+        let title = locale.tr(&format!("achievement.{}", achievement.id()), &[]);
+        let description = locale.tr(&format!("achievement.{}.description", achievement.id()), &[]);
+        let unlocked_by = if holders.is_empty() { locale.tr("achievement.none", &[]) } else { holders.join(", ") };
+        lines.push(Line::from(vec![
+            Span::raw(format!("{} ", marker)),
+            Span::styled(title, Style::default().fg(title_color).bold()),
+            Span::styled(format!(" - {}", description), Style::default().fg(Color::White)),
+        ]));
+        lines.push(Line::from(Span::styled(format!("     {}", unlocked_by), Style::default().fg(Color::Cyan))));
+    }
+    let list = Paragraph::new(Text::from(lines));
+
+    let controls = Paragraph::new("a/Esc: Back")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("Achievements")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(list, chunks[0]);
+    frame.render_widget(controls, chunks[1]);
+}
@@ -0,0 +1,85 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear, Wrap},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::Auction;
+use crate::models::{format_money, GameState, Money};
+use crate::ui::colors::player_color;
+
+/// Renders the auction dialog for the bidder whose turn it is.
+pub fn render_auction(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    auction: &Auction,
+    bid_amount: Money,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),  // Card
+            Constraint::Length(3),  // High bid
+            Constraint::Length(3),  // Current bidder and bid amount
+            Constraint::Min(1),     // Controls
+        ])
+        .split(area);
+
+    let seller_name = game_state.players.get(auction.seller).map_or("Unknown", |p| p.name.as_str());
+    let card_text = vec![
+        Line::from(Span::styled(game_state.locale.card_title(&auction.card).to_string(), Style::default().fg(Color::Yellow).bold())),
+        Line::from(Span::styled(format!("Offered by {}", seller_name), Style::default().fg(Color::Gray))),
+    ];
+    let card = Paragraph::new(Text::from(card_text))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::BOTTOM));
+
+    let high_bid_text = match auction.high_bidder.and_then(|id| game_state.players.get(id)) {
+        Some(bidder) => Line::from(vec![
+            Span::styled("High bid: ", Style::default().fg(Color::White)),
+            Span::styled(format_money(auction.high_bid), Style::default().fg(Color::Green).bold()),
+            Span::styled(" by ", Style::default().fg(Color::White)),
+            Span::styled(bidder.name.clone(), Style::default().fg(player_color(bidder.color)).bold()),
+        ]),
+        None => Line::from(Span::styled("No bids yet", Style::default().fg(Color::DarkGray))),
+    };
+    let high_bid = Paragraph::new(high_bid_text).alignment(Alignment::Center);
+
+    let bidder_text = match auction.current_bidder().and_then(|id| game_state.players.get(id)) {
+        Some(bidder) => vec![
+            Line::from(vec![
+                Span::styled(bidder.name.clone(), Style::default().fg(player_color(bidder.color)).bold()),
+                Span::styled("'s bid: ", Style::default().fg(Color::White)),
+                Span::styled(format_money(bid_amount), Style::default().fg(Color::Yellow).bold()),
+                Span::styled(format!(" (cash {})", format_money(bidder.cash)), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(Span::styled(format!("Minimum bid: {}", format_money(auction.minimum_bid())), Style::default().fg(Color::DarkGray))),
+        ],
+        None => vec![Line::from("Bidding closed")],
+    };
+    let bidder = Paragraph::new(Text::from(bidder_text)).alignment(Alignment::Center);
+
+    let controls = Paragraph::new("↑/↓: ±$100 | PgUp/PgDn: ±$1,000 | Enter: Bid | P/Esc: Pass")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("O.T.B. Auction")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(card, chunks[0]);
+    frame.render_widget(high_bid, chunks[1]);
+    frame.render_widget(bidder, chunks[2]);
+    frame.render_widget(controls, chunks[3]);
+}
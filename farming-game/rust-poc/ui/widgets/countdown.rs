@@ -0,0 +1,37 @@
+use ratatui::{
+    prelude::{Rect, Frame},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Gauge},
+};
+
+/// Width the countdown needs, borders included.
+pub const WIDTH: u16 = 24;
+/// Height the countdown needs, borders included.
+pub const HEIGHT: u16 = 3;
+
+/// Renders the years left to win in a run against the clock, as a bar that
+/// empties year by year and turns red in the last one.
+pub fn render_countdown(frame: &mut Frame, area: Rect, year: u32, deadline: u32) {
+    frame.render_widget(Clear, area);
+
+    let deadline = deadline.max(1);
+    let left = (deadline + 1).saturating_sub(year.max(1)).min(deadline);
+    let color = match left {
+        0 | 1 => Color::Red,
+        2 => Color::Yellow,
+        _ => Color::Green,
+    };
+    let label = match left {
+        1 => "Final year".to_string(),
+        _ => format!("{} years left", left),
+    };
+    let gauge = Gauge::default()
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title(format!("Year {} of {}", year.min(deadline), deadline)))
+        .gauge_style(Style::default().fg(color).bg(Color::Black))
+        .ratio(left as f64 / deadline as f64)
+        .label(label);
+    frame.render_widget(gauge, area);
+}
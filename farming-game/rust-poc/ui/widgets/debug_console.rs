@@ -0,0 +1,41 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Line},
+};
+use crate::debug::DebugConsole;
+
+/// Renders the debug console: the latest output above the line being typed.
+pub fn render_debug_console(frame: &mut Frame, area: Rect, console: &DebugConsole) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(1),     // Output
+            Constraint::Length(2),  // Input
+        ])
+        .split(area);
+
+    // Only the newest lines that fit
+    let visible = chunks[0].height as usize;
+    let skip = console.output.len().saturating_sub(visible);
+    let output: Vec<Line> = console.output[skip..].iter().map(|line| Line::from(line.as_str())).collect();
+
+    let input = Paragraph::new(format!("> {}_", console.input))
+        .style(Style::default().fg(Color::Yellow).bold())
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta))
+            .title("Debug Console")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(Paragraph::new(Text::from(output)).style(Style::default().fg(Color::White)), chunks[0]);
+    frame.render_widget(input, chunks[1]);
+}
@@ -0,0 +1,81 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear, Wrap},
+    text::{Span, Line},
+    layout::Alignment,
+};
+use crate::game::{DecisionKind, DecisionRequest};
+use crate::models::{format_money, GameState, Money};
+use crate::ui::colors::player_color;
+
+/// Renders the question a card effect is waiting on, with the quantity or
+/// price picked so far for questions that ask for one.
+pub fn render_decision(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    request: &DecisionRequest,
+    quantity: i32,
+    price: Money,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(3),     // Question
+            Constraint::Length(2),  // Cash and answer
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let question = Paragraph::new(request.prompt.clone())
+        .style(Style::default().fg(Color::Yellow).bold())
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    let player = &game_state.players[request.player_id];
+    let mut answer_spans = vec![
+        Span::styled("Cash: ", Style::default().fg(Color::White)),
+        Span::styled(format_money(player.cash), Style::default().fg(Color::Green).bold()),
+    ];
+    match request.kind {
+        DecisionKind::Quantity { min, max } => {
+            answer_spans.push(Span::styled(format!("   Quantity: {} ({}-{})", quantity, min, max), Style::default().fg(Color::White)));
+        }
+        DecisionKind::Price { max, .. } => {
+            answer_spans.push(Span::styled(format!("   Price: {} (up to {})", format_money(price), format_money(max)), Style::default().fg(Color::White)));
+        }
+        _ => {}
+    }
+    let answer = Paragraph::new(Line::from(answer_spans)).alignment(Alignment::Center);
+
+    let controls_text = match request.kind {
+        DecisionKind::YesNo => "Y: Yes | N: No".to_string(),
+        DecisionKind::Quantity { .. } => "↑/↓: Adjust | Enter: Confirm".to_string(),
+        DecisionKind::Roll => "Enter: Roll".to_string(),
+        // Shown in its own dialog (see `raise_cash`)
+        DecisionKind::RaiseCash { .. } => "P/Esc: Pay".to_string(),
+        // Shown in its own dialog (see `discard`)
+        DecisionKind::Discard { .. } => "Enter: Discard".to_string(),
+        DecisionKind::Price { step, .. } => format!("↑/↓: ±{} | Enter: Offer ($0 passes)", format_money(step)),
+    };
+    let controls = Paragraph::new(controls_text)
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title(format!("{}'s Decision", player.name))
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(question, chunks[0]);
+    frame.render_widget(answer, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
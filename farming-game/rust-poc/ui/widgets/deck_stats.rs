@@ -0,0 +1,39 @@
+use ratatui::{
+    prelude::{Rect, Frame},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+};
+use crate::cards::deck::DeckStats;
+
+/// Width the deck counts need, borders included.
+pub const WIDTH: u16 = 34;
+
+/// Height for `decks` decks, borders included.
+pub fn height(decks: usize) -> u16 {
+    decks as u16 + 2
+}
+
+/// Renders how many cards are left in each deck and how many have been played.
+pub fn render_deck_stats(frame: &mut Frame, area: Rect, decks: &[DeckStats]) {
+    frame.render_widget(Clear, area);
+
+    let lines: Vec<Line> = decks.iter()
+        .map(|deck| {
+            // A deck about to run out is reshuffled from its discards
+            let color = if deck.remaining == 0 { Color::Red } else if deck.remaining <= 3 { Color::Yellow } else { Color::White };
+            Line::from(vec![
+                Span::styled(format!("{:<15}", deck.name), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{:>3} left", deck.remaining), Style::default().fg(color)),
+                Span::styled(format!("{:>4} out", deck.discarded), Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    let stats = Paragraph::new(Text::from(lines))
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("Decks"));
+    frame.render_widget(stats, area);
+}
@@ -0,0 +1,39 @@
+use ratatui::{
+    prelude::{Rect, Frame},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Line},
+    layout::Alignment,
+};
+
+/// Pip rows for each face of the die.
+fn pips(face: u32) -> [&'static str; 3] {
+    match face {
+        1 => ["     ", "  ●  ", "     "],
+        2 => ["●    ", "     ", "    ●"],
+        3 => ["●    ", "  ●  ", "    ●"],
+        4 => ["●   ●", "     ", "●   ●"],
+        5 => ["●   ●", "  ●  ", "●   ●"],
+        _ => ["●   ●", "●   ●", "●   ●"],
+    }
+}
+
+/// Renders the die while a turn animates; yellow while tumbling, green once it lands.
+pub fn render_dice(frame: &mut Frame, area: Rect, face: u32, rolling: bool) {
+    frame.render_widget(Clear, area);
+
+    let color = if rolling { Color::Yellow } else { Color::Green };
+    let lines: Vec<Line> = pips(face).iter()
+        .map(|row| Line::from(*row).style(Style::default().fg(color).bold()))
+        .collect();
+    let title = if rolling { "Rolling" } else { "Rolled" };
+
+    let dice = Paragraph::new(Text::from(lines))
+        .alignment(Alignment::Center)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title(title)
+            .bg(Color::Black));
+    frame.render_widget(dice, area);
+}
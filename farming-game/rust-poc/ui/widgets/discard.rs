@@ -0,0 +1,77 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear, Wrap},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::DecisionRequest;
+use crate::models::GameState;
+use crate::ui::colors::player_color;
+use crate::ui::text::{rich_text, truncate};
+
+/// Renders the dialog for discarding down to the hand limit: the player's
+/// hand, each card's title over its summary, with the card to discard picked.
+pub fn render_discard(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    request: &DecisionRequest,
+    selected: usize,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),  // Question
+            Constraint::Min(3),     // Hand
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let question = Paragraph::new(request.prompt.clone())
+        .style(Style::default().fg(Color::Yellow).bold())
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::BOTTOM));
+
+    let player = &game_state.players[request.player_id];
+    let locale = &game_state.locale;
+    // Summaries get two lines under the title, indented past the marker
+    let width = chunks[1].width as usize;
+    let rows: Vec<Line> = player.hand.iter().enumerate().flat_map(|(i, card)| {
+        let (marker, style) = if i == selected {
+            ("> ", Style::default().fg(Color::Yellow).bold())
+        } else {
+            ("  ", Style::default().fg(Color::White))
+        };
+        let title = Line::from(Span::styled(format!("{}{}", marker, truncate(locale.card_title(card), width.saturating_sub(2))), style.bold()));
+        let summary = rich_text(locale.card_description_brief(card), width.saturating_sub(4), 2, style)
+            .into_iter()
+            .map(|mut line| {
+                line.spans.insert(0, Span::raw("    "));
+                line
+            });
+        std::iter::once(title).chain(summary)
+    }).collect();
+    let hand = Paragraph::new(Text::from(rows));
+
+    let controls = Paragraph::new("↑/↓: Select | Enter: Discard")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title(format!("{} Discards", player.name))
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(question, chunks[0]);
+    frame.render_widget(hand, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
@@ -0,0 +1,67 @@
+use ratatui::{
+    prelude::{Rect, Frame},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+};
+use crate::cards::card::DrawKind;
+use crate::cards::deck::DeckStats;
+
+/// Width of the odds table, borders included.
+pub const WIDTH: u16 = 51;
+
+/// Height for `decks` decks, borders, header and footnote included.
+pub fn height(decks: usize) -> u16 {
+    decks as u16 + 4
+}
+
+/// Columns of the table, left to right.
+const COLUMNS: [DrawKind; 4] = [DrawKind::Income, DrawKind::Expense, DrawKind::Hold, DrawKind::Other];
+
+fn label(kind: DrawKind) -> &'static str {
+    match kind {
+        DrawKind::Income => "Income",
+        DrawKind::Expense => "Expense",
+        DrawKind::Hold => "Hold",
+        DrawKind::Other => "Other",
+    }
+}
+
+fn color(kind: DrawKind) -> Color {
+    match kind {
+        DrawKind::Income => Color::Green,
+        DrawKind::Expense => Color::Red,
+        DrawKind::Hold => Color::Yellow,
+        DrawKind::Other => Color::Gray,
+    }
+}
+
+/// Renders, for each deck, the chance the next card drawn brings income,
+/// costs money or is kept in hand. A deck with an empty draw pile is marked,
+/// since its odds come from the discards it'll be reshuffled from.
+pub fn render_draw_odds(frame: &mut Frame, area: Rect, decks: &[DeckStats]) {
+    frame.render_widget(Clear, area);
+
+    let mut header = vec![Span::styled(format!("{:<16}", "Deck"), Style::default().fg(Color::DarkGray))];
+    header.extend(COLUMNS.iter().map(|kind| Span::styled(format!("{:>8}", label(*kind)), Style::default().fg(color(*kind)))));
+    let mut lines = vec![Line::from(header)];
+
+    for deck in decks {
+        let marker = if deck.remaining == 0 { "*" } else { " " };
+        let mut row = vec![Span::styled(format!("{:<15}{}", deck.name, marker), Style::default().fg(Color::Cyan))];
+        row.extend(COLUMNS.iter().map(|kind| {
+            let percent = (deck.chance_of_draw(*kind) * 100.0).round();
+            let style = if percent > 0.0 { Style::default().fg(Color::White) } else { Style::default().fg(Color::DarkGray) };
+            Span::styled(format!("{:>7}%", percent), style)
+        }));
+        lines.push(Line::from(row));
+    }
+    lines.push(Line::from("* next draw reshuffles the discards").style(Style::default().fg(Color::DarkGray)));
+
+    let odds = Paragraph::new(Text::from(lines))
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("Next draw odds"));
+    frame.render_widget(odds, area);
+}
@@ -0,0 +1,55 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear, Wrap},
+    text::{Text, Line},
+    layout::Alignment,
+};
+use crate::models::GameState;
+
+/// Renders the notice shown when a player goes bankrupt, asking whether they
+/// stay at the table to watch the rest of the game.
+pub fn render_eliminated(frame: &mut Frame, area: Rect, game_state: &GameState, player_id: usize) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(4),     // Notice
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let player = &game_state.players[player_id];
+    let mut lines = vec![
+        Line::from(format!("{} can't pay and is out of the game.", player.name)).style(Style::default().fg(Color::White).bold()),
+        Line::from("Their turns will be skipped from now on.").style(Style::default().fg(Color::Gray)),
+        Line::from(""),
+    ];
+    if let Some(survivor) = game_state.last_player_standing() {
+        lines.push(Line::from(format!("{} is the last farmer standing!", game_state.players[survivor].name))
+            .style(Style::default().fg(Color::Yellow).bold()));
+    } else {
+        lines.push(Line::from("Stay at the table and watch the rest of the game?").style(Style::default().fg(Color::White)));
+    }
+    let notice = Paragraph::new(Text::from(lines))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    let controls = Paragraph::new("Enter: Keep watching | Esc: Leave the table")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red))
+            .title("Bankrupt")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(notice, chunks[0]);
+    frame.render_widget(controls, chunks[1]);
+}
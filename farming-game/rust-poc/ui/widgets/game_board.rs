@@ -6,23 +6,9 @@ use ratatui::{
     layout::Alignment,
 };
 use crate::models::{GameState, HarvestType};
+use crate::ui::colors::player_color;
 use std::collections::HashMap;
 
-// Define colors for the players on the board
-const PLAYER_COLORS: [Color; 6] = [
-    Color::Blue,
-    Color::Green,
-    Color::Red,
-    Color::Yellow,
-    Color::Magenta,
-    Color::Cyan,
-];
-
-// Helper function to get player color
-fn get_player_color(player_id: usize) -> Color {
-    PLAYER_COLORS[player_id % PLAYER_COLORS.len()]
-}
-
 // Helper function to get harvest color
 fn get_harvest_color(harvest_type: &HarvestType) -> Option<Color> {
     match harvest_type {
@@ -70,8 +56,12 @@ fn get_short_tile_name(tile_name: &str) -> String {
     }
 }
 
+/// Height the four rows of tiles take up, with the board's border.
+pub const BOARD_HEIGHT: u16 = 4 * 4 + 2;
+
 /// Renders the game board with player positions using ratatui Layout.
-pub fn render_game_board(frame: &mut Frame, area: Rect, game_state: &GameState) {
+/// `moving_token` draws a player at an animated `(player_id, position)` instead of their real position.
+pub fn render_game_board(frame: &mut Frame, area: Rect, game_state: &GameState, moving_token: Option<(usize, usize)>) {
     let board_block = Block::default()
         .title("Game Board")
         .borders(Borders::ALL)
@@ -86,10 +76,14 @@ pub fn render_game_board(frame: &mut Frame, area: Rect, game_state: &GameState)
     // --- Player Position Mapping ---
     let mut players_by_position: HashMap<usize, Vec<usize>> = HashMap::new();
     for player_id in &game_state.turn_order {
-        let player = &game_state.players[player_id];
+        let player = &game_state.players[*player_id];
         if player.is_active {
+            let position = match moving_token {
+                Some((moving_id, position)) if moving_id == *player_id => position,
+                _ => player.position,
+            };
             players_by_position
-                .entry(player.position) // Use direct position
+                .entry(position)
                 .or_insert_with(Vec::new)
                 .push(*player_id);
         }
@@ -186,7 +180,7 @@ pub fn render_game_board(frame: &mut Frame, area: Rect, game_state: &GameState)
                         .map(|player_ids| {
                             player_ids
                                 .iter()
-                                .map(|&id| Span::styled("●", Style::default().fg(get_player_color(id))))
+                                .map(|&id| Span::styled("●", Style::default().fg(player_color(game_state.players[id].color))))
                                 .collect::<Vec<Span>>()
                         })
                         .unwrap_or_default();
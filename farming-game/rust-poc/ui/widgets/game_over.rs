@@ -0,0 +1,107 @@
+// src/ui/widgets/game_over.rs
+
+use std::time::Duration;
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    text::{Text, Line},
+    layout::Alignment,
+};
+use crate::models::{format_money, GameState};
+use crate::ui::colors::player_color;
+use crate::ui::keymap::{KeyAction, Keymap};
+
+/// How often the banner changes color.
+const BANNER_INTERVAL: Duration = Duration::from_millis(250);
+/// Delay before each row of the standings appears, last place first.
+const ROW_INTERVAL: Duration = Duration::from_millis(300);
+const BANNER_COLORS: [Color; 4] = [Color::Yellow, Color::LightYellow, Color::LightGreen, Color::LightYellow];
+
+/// Height the victory screen needs for `players` rows of standings.
+pub fn height(players: usize) -> u16 {
+    9 + players as u16
+}
+
+/// Renders the victory screen `elapsed` after `winner` won: a flashing
+/// banner over the final standings, revealed from last place up.
+/// `exported` is where the results were saved, once they have been.
+pub fn render_game_over(frame: &mut Frame, area: Rect, game_state: &GameState, winner: usize, elapsed: Duration, exported: Option<&str>, keymap: &Keymap) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),  // Banner
+            Constraint::Min(3),     // Standings
+            Constraint::Length(3),  // Controls
+        ])
+        .split(area);
+
+    let phase = (elapsed.as_millis() / BANNER_INTERVAL.as_millis()) as usize;
+    let (star, color) = (if phase.is_multiple_of(2) { "★" } else { "☆" }, BANNER_COLORS[phase % BANNER_COLORS.len()]);
+    let champion = &game_state.players[winner];
+    let banner = Paragraph::new(Text::from(vec![
+        Line::from(format!("{star} {} wins! {star}", champion.name)).style(Style::default().fg(color).bold()),
+        Line::from(format!("Net worth {} after {} years", format_money(champion.net_worth), game_state.years_played(winner)))
+            .style(Style::default().fg(Color::White)),
+    ]))
+    .alignment(Alignment::Center);
+
+    let header = Row::new(["#", "Player", "Net worth", "Years", "Income", "Max debt"]
+        .map(|h| Cell::from(h).style(Style::default().fg(Color::Yellow).bold())));
+    let standings = game_state.final_standings();
+    let shown = (elapsed.as_millis() / ROW_INTERVAL.as_millis()) as usize;
+    let rows: Vec<Row> = standings.iter().enumerate()
+        .map(|(i, standing)| {
+            // Last place shows first, so the winner's row lands last
+            if standings.len() - i > shown {
+                return Row::new([""; 6]);
+            }
+            let player = &game_state.players[standing.player_id];
+            let style = if standing.player_id == winner { Style::default().fg(Color::Yellow).bold() } else { Style::default() };
+            Row::new(vec![
+                Cell::from(standing.rank.to_string()),
+                Cell::from(player.name.clone()).style(Style::default().fg(player_color(player.color))),
+                Cell::from(format_money(standing.net_worth)),
+                Cell::from(standing.years_played.to_string()),
+                Cell::from(format_money(standing.total_income)),
+                Cell::from(format_money(standing.max_debt)),
+            ]).style(style)
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(2),  // Rank
+        Constraint::Min(12),    // Player
+        Constraint::Length(10), // Net worth
+        Constraint::Length(5),  // Years
+        Constraint::Length(9),  // Income
+        Constraint::Length(9),  // Max debt
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .column_spacing(1);
+
+    let keys = format!("{}: Export results | {}: Rematch | {}: Quit",
+        keymap.label(KeyAction::Export), keymap.label(KeyAction::Rematch), keymap.label(KeyAction::Quit));
+    let mut controls = vec![Line::from(keys).style(Style::default().fg(Color::Cyan))];
+    if let Some(path) = exported {
+        controls.push(Line::from(format!("Results saved to {}", path)).style(Style::default().fg(Color::Green)));
+    }
+    let controls = Paragraph::new(Text::from(controls))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .title("Game Over")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(banner, chunks[0]);
+    frame.render_widget(table, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
@@ -0,0 +1,52 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::ui::keymap::{KeyAction, Keymap, KEYMAP_FILE};
+
+/// Renders the help overlay: every action with the keys bound to it in `keymap`.
+pub fn render_help(frame: &mut Frame, area: Rect, keymap: &Keymap) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(4),     // Bindings
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    let lines: Vec<Line> = KeyAction::ALL.iter().map(|action| {
+        Line::from(vec![
+            Span::styled(format!("{:>14} ", keymap.label(*action)), Style::default().fg(Color::Cyan).bold()),
+            Span::styled(action.description(), Style::default().fg(Color::White)),
+        ])
+    }).collect();
+    let (left, right) = lines.split_at(lines.len().div_ceil(2));
+
+    let controls = Paragraph::new(format!("{}/{}: Close | Rebind keys in the {} file in the game's config directory",
+        keymap.label(KeyAction::Help), keymap.label(KeyAction::Back), KEYMAP_FILE))
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("Keys")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(Paragraph::new(Text::from(left.to_vec())), columns[0]);
+    frame.render_widget(Paragraph::new(Text::from(right.to_vec())), columns[1]);
+    frame.render_widget(controls, chunks[1]);
+}
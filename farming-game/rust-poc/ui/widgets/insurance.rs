@@ -0,0 +1,77 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::Coverage;
+use crate::models::{format_money, GameState, Money};
+use crate::ui::colors::player_color;
+
+/// Renders the yearly insurance purchase dialog.
+pub fn render_insurance(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    player_id: usize,
+    selected_index: usize,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2),  // Cash
+            Constraint::Min(2),     // Policies
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let player = &game_state.players[player_id];
+    let cash = Paragraph::new(Line::from(vec![
+        Span::styled("Cash: ", Style::default().fg(Color::White)),
+        Span::styled(format_money(player.cash), Style::default().fg(Color::Green).bold()),
+    ]))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::BOTTOM));
+
+    let policy_lines: Vec<Line> = Coverage::ALL.iter().enumerate().map(|(i, coverage)| {
+        let marker = if i == selected_index { "> " } else { "  " };
+        let (units, premium) = game_state.insurance_quote(player_id, *coverage).unwrap_or((0, Money::ZERO));
+        let (status, style) = if player.insurance_policy(*coverage).is_some() {
+            ("insured".to_string(), Style::default().fg(Color::Green))
+        } else if units == 0 {
+            ("nothing to insure".to_string(), Style::default().fg(Color::DarkGray))
+        } else if premium > player.cash {
+            (format!("{} (can't afford)", format_money(premium)), Style::default().fg(Color::Red))
+        } else {
+            (format_money(premium), Style::default().fg(Color::Yellow))
+        };
+        let line_style = if i == selected_index { style.bold() } else { style };
+        Line::from(vec![
+            Span::styled(marker, Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{} ({} {}s): ", coverage.label(), units, coverage.unit()), Style::default().fg(Color::White)),
+            Span::styled(status, line_style),
+        ])
+    }).collect();
+    let policies = Paragraph::new(Text::from(policy_lines));
+
+    let controls = Paragraph::new("↑/↓: Select | Enter: Buy | Esc: Back")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title("Insurance (this year)")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(cash, chunks[0]);
+    frame.render_widget(policies, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
@@ -0,0 +1,81 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::models::{AssetType, GameState};
+use crate::ui::colors::player_color;
+
+/// Renders the dialog for moving cows between the farm and the player's
+/// leased ridges. `cows` is the pending move for the selected ridge: positive
+/// sends cows up, negative brings them down.
+pub fn render_livestock(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    player_id: usize,
+    selected: usize,
+    cows: i32,
+) {
+    frame.render_widget(Clear, area);
+
+    let ridges = game_state.leased_ridges(player_id);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2),  // Farm herd
+            Constraint::Min(1),     // Ridges
+            Constraint::Length(2),  // Pending move
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let player = &game_state.players[player_id];
+    let on_farm = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+    let farm = Paragraph::new(Line::from(vec![
+        Span::styled("Farm: ", Style::default().fg(Color::White)),
+        Span::styled(format!("{} of {} cows", on_farm, game_state.rules.farm_cow_limit), Style::default().fg(Color::Yellow).bold()),
+    ]))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::BOTTOM));
+
+    let rows: Vec<Line> = ridges.iter().enumerate().map(|(i, ridge)| {
+        let herd = format!("{}: {} of {} cows", ridge.name, ridge.cow_count, ridge.initial_cow_count);
+        if i == selected {
+            Line::from(Span::styled(format!("> {}", herd), Style::default().fg(Color::Yellow).bold()))
+        } else {
+            Line::from(Span::styled(format!("  {}", herd), Style::default().fg(Color::White)))
+        }
+    }).collect();
+    let herds = Paragraph::new(Text::from(rows));
+
+    let pending = match ridges.get(selected) {
+        Some(ridge) if cows > 0 => format!("Move {} cows up to {}", cows, ridge.name),
+        Some(ridge) if cows < 0 => format!("Bring {} cows down from {}", cows.unsigned_abs(), ridge.name),
+        _ => "No cows to move".to_string(),
+    };
+    let pending = Paragraph::new(Span::styled(pending, Style::default().fg(Color::Cyan).bold()))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    let controls = Paragraph::new("↑/↓: Ridge | PgUp: Up | PgDn: Down | Enter: Move | Esc: Back")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title(format!("{}'s Livestock", player.name))
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(farm, chunks[0]);
+    frame.render_widget(herds, chunks[1]);
+    frame.render_widget(pending, chunks[2]);
+    frame.render_widget(controls, chunks[3]);
+}
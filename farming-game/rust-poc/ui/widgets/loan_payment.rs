@@ -5,7 +5,12 @@ use ratatui::{
     text::{Text, Span, Line},
     layout::Alignment,
 };
-use crate::models::GameState;
+use crate::models::{format_money, GameState, Money};
+use crate::ui::colors::player_color;
+use crate::ui::layout::popup_rect;
+
+/// Notes listed in the dialog; the rest are summed up as "+N more".
+const MAX_NOTES_SHOWN: usize = 3;
 
 /// Renders a loan payment dialog for player to pay down debt.
 pub fn render_loan_payment(
@@ -13,18 +18,10 @@ pub fn render_loan_payment(
     area: Rect,
     game_state: &GameState,
     player_id: usize,
-    payment_amount: &mut i32
+    payment_amount: &mut Money
 ) {
     // Create a centered dialog box
-    let dialog_width = 60.min(area.width.saturating_sub(4));
-    let dialog_height = 16.min(area.height.saturating_sub(4));
-    
-    let dialog_area = Rect {
-        x: (area.width - dialog_width) / 2,
-        y: (area.height - dialog_height) / 2,
-        width: dialog_width,
-        height: dialog_height,
-    };
+    let dialog_area = popup_rect(60, 20, area);
     
     // First, render a completely opaque Clear widget to cover text underneath
     frame.render_widget(Clear, dialog_area);
@@ -36,13 +33,14 @@ pub fn render_loan_payment(
         .constraints([
             Constraint::Length(3),  // Title
             Constraint::Length(3),  // Player info
+            Constraint::Length(4),  // Notes making up the debt
             Constraint::Length(5),  // Payment amount and controls
             Constraint::Length(3),  // Action buttons
         ])
         .split(dialog_area);
     
     // Get player information
-    let player = &game_state.players[&player_id];
+    let player = &game_state.players[player_id];
     let player_name = &player.name;
     let player_cash = player.cash;
     let player_debt = player.debt;
@@ -50,22 +48,22 @@ pub fn render_loan_payment(
     // Create title with styling
     let title_text = format!("{}'s Loan Payment", player_name);
     let title = Paragraph::new(title_text)
-        .style(Style::default().fg(Color::Yellow).bold().bg(Color::Black))
+        .style(Style::default().fg(player_color(player.color)).bold().bg(Color::Black))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::BOTTOM).bg(Color::Black));
     
     // Player financial info
-    let player_cash_style = if player_cash > 3000 {
+    let player_cash_style = if player_cash > Money(3000) {
         Style::default().fg(Color::Green).bg(Color::Black)
-    } else if player_cash > 1000 {
+    } else if player_cash > Money(1000) {
         Style::default().fg(Color::Yellow).bg(Color::Black)
     } else {
         Style::default().fg(Color::Red).bg(Color::Black)
     };
     
-    let player_debt_style = if player_debt < 5000 {
+    let player_debt_style = if player_debt < Money(5000) {
         Style::default().fg(Color::Green).bg(Color::Black)
-    } else if player_debt < 10000 {
+    } else if player_debt < Money(10000) {
         Style::default().fg(Color::Yellow).bg(Color::Black)
     } else {
         Style::default().fg(Color::Red).bg(Color::Black)
@@ -74,22 +72,41 @@ pub fn render_loan_payment(
     let player_info_text = vec![
         Line::from(vec![
             Span::styled("Available Cash: ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled(format!("${}", player_cash), player_cash_style),
+            Span::styled(format_money(player_cash), player_cash_style),
         ]),
         Line::from(vec![
             Span::styled("Current Debt: ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled(format!("${}", player_debt), player_debt_style),
+            Span::styled(format_money(player_debt), player_debt_style),
         ]),
     ];
     
     let player_info = Paragraph::new(Text::from(player_info_text))
         .style(Style::default().bg(Color::Black))
         .block(Block::default().borders(Borders::NONE).bg(Color::Black));
+
+    // Notes, oldest first; payments go to the oldest note first
+    let mut notes = player.notes.clone();
+    notes.reconcile(player_debt, game_state.rules.interest_rate, player.year);
+    let mut notes_text = vec![Line::from(Span::styled("Notes (paid oldest first):", Style::default().fg(Color::White).bg(Color::Black)))];
+    notes_text.extend(notes.iter().take(MAX_NOTES_SHOWN).map(|note| Line::from(Span::styled(
+        format!("  #{} {} at {}% since year {}", note.id, format_money(note.principal), (note.rate * 100.0).round(), note.year),
+        Style::default().fg(Color::Cyan).bg(Color::Black),
+    ))));
+    let hidden = notes.iter().count().saturating_sub(MAX_NOTES_SHOWN);
+    if hidden > 0 {
+        if let Some(last) = notes_text.last_mut() {
+            last.spans.push(Span::styled(format!(" (+{} more)", hidden), Style::default().fg(Color::DarkGray).bg(Color::Black)));
+        }
+    }
+    let notes_info = Paragraph::new(Text::from(notes_text))
+        .style(Style::default().bg(Color::Black))
+        .block(Block::default().borders(Borders::NONE).bg(Color::Black));
     
     // Payment amount and controls
     // Ensure payment amount is valid
-    *payment_amount = (*payment_amount).clamp(0, player_cash.min(player_debt));
+    *payment_amount = (*payment_amount).clamp(Money::ZERO, player_cash.min(player_debt));
     
+    let increment = game_state.rules.payment_increment;
     let remaining_cash = player_cash - *payment_amount;
     let remaining_debt = player_debt - *payment_amount;
     
@@ -97,21 +114,20 @@ pub fn render_loan_payment(
     let payment_text = vec![
         Line::from(vec![
             Span::styled("Payment Amount: ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled(" $", Style::default().fg(Color::Yellow).bg(Color::Black)),
-            Span::styled(format!("{}", payment_amount), Style::default().fg(Color::Yellow).bg(Color::Black).bold()),
+            Span::styled(format_money(*payment_amount), Style::default().fg(Color::Yellow).bg(Color::Black).bold()),
             Span::styled(" ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled("(↑/↓: ±$100)", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(format!("(↑/↓: ±{})", format_money(increment)), Style::default().fg(Color::DarkGray).bg(Color::Black)),
             Span::styled(" ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled("(PgUp/PgDn: ±$1000)", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(format!("(PgUp/PgDn: ±{})", format_money(5 * increment)), Style::default().fg(Color::DarkGray).bg(Color::Black)),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("Remaining Cash: ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled(format!("${}", remaining_cash), Style::default().fg(Color::Cyan).bg(Color::Black)),
+            Span::styled(format_money(remaining_cash), Style::default().fg(Color::Cyan).bg(Color::Black)),
         ]),
         Line::from(vec![
             Span::styled("Remaining Debt: ", Style::default().fg(Color::White).bg(Color::Black)),
-            Span::styled(format!("${}", remaining_debt), Style::default().fg(Color::Cyan).bg(Color::Black)),
+            Span::styled(format_money(remaining_debt), Style::default().fg(Color::Cyan).bg(Color::Black)),
         ]),
     ];
     
@@ -154,6 +170,7 @@ pub fn render_loan_payment(
     
     frame.render_widget(title, chunks[0]);
     frame.render_widget(player_info, chunks[1]);
-    frame.render_widget(payment_info, chunks[2]);
-    frame.render_widget(action_buttons_widget, chunks[3]);
+    frame.render_widget(notes_info, chunks[2]);
+    frame.render_widget(payment_info, chunks[3]);
+    frame.render_widget(action_buttons_widget, chunks[4]);
 } 
\ No newline at end of file
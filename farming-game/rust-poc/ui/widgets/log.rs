@@ -8,8 +8,165 @@ use ratatui::{
 };
 use std::collections::HashSet;
 
+/// What a log entry is about, so the log can be filtered down to one kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogCategory {
+    General,
+    Finance,
+    Movement,
+    Cards,
+    Harvest,
+}
+
+impl LogCategory {
+    /// Categories the log can be filtered to, in the order the filter cycles.
+    pub const FILTERS: [LogCategory; 4] = [
+        LogCategory::Finance,
+        LogCategory::Movement,
+        LogCategory::Cards,
+        LogCategory::Harvest,
+    ];
+
+    /// Sorts a log line into a category by what it mentions.
+    pub fn classify(text: &str) -> Self {
+        let text = text.to_lowercase();
+        let mentions = |words: &[&str]| words.iter().any(|word| text.contains(word));
+        if mentions(&["harvest", "hay:", "wheat:", "corn:", "apple:", "cherry:", "livestock sales:", "yield"]) {
+            LogCategory::Harvest
+        } else if text.starts_with("description:") || text.starts_with("brief:")
+            || mentions(&["drew", "o.t.b.", "option to buy", "card"]) {
+            LogCategory::Cards
+        } else if mentions(&["rolled a", "landed on", "moved to", "passed go", "skips"]) {
+            LogCategory::Movement
+        } else if mentions(&["$", "paid", "pay ", "loan", "debt", "interest", "cash", "insurance", "sold"]) {
+            LogCategory::Finance
+        } else {
+            LogCategory::General
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogCategory::General => "General",
+            LogCategory::Finance => "Finance",
+            LogCategory::Movement => "Movement",
+            LogCategory::Cards => "Cards",
+            LogCategory::Harvest => "Harvest",
+        }
+    }
+}
+
+/// A line in the game log and what it's about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub text: String,
+    pub category: LogCategory,
+}
+
+impl LogEntry {
+    pub fn new(text: String) -> Self {
+        let category = LogCategory::classify(&text);
+        Self { text, category }
+    }
+
+    /// Turn headers and blank spacer lines, which stay visible under any filter.
+    fn is_structural(&self) -> bool {
+        self.text.starts_with("---") || self.text.trim().is_empty()
+    }
+}
+
+/// Filter and search applied to the log.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogView {
+    /// Only show entries of this category (plus turn headers)
+    pub filter: Option<LogCategory>,
+    /// Search text; matches are highlighted and can be jumped between
+    pub query: String,
+    /// Whether the search text is still being typed (after `/`)
+    pub typing: bool,
+    /// Index into the matching lines of the one last jumped to
+    pub current_match: usize,
+}
+
+impl LogView {
+    /// Moves the filter on to the next category, then back to everything.
+    pub fn cycle_filter(&mut self) {
+        self.filter = match self.filter {
+            None => Some(LogCategory::FILTERS[0]),
+            Some(category) => LogCategory::FILTERS.iter()
+                .position(|filter| *filter == category)
+                .and_then(|index| LogCategory::FILTERS.get(index + 1))
+                .copied(),
+        };
+        self.current_match = 0;
+    }
+
+    fn shows(&self, entry: &LogEntry) -> bool {
+        self.filter.is_none_or(|category| entry.category == category || entry.is_structural())
+    }
+}
+
+/// Text of the entries that pass the view's filter.
+fn visible_entries<'a>(log_entries: &'a [LogEntry], view: &LogView) -> Vec<&'a str> {
+    log_entries.iter()
+        .filter(|entry| view.shows(entry))
+        .map(|entry| entry.text.as_str())
+        .collect()
+}
+
+/// Indices of the formatted log lines containing the view's search text (ignoring case).
+pub fn matching_lines(log_entries: &[LogEntry], view: &LogView) -> Vec<usize> {
+    if view.query.is_empty() {
+        return Vec::new();
+    }
+    let query = view.query.to_ascii_lowercase();
+    let entries = visible_entries(log_entries, view);
+    format_log_entries(&entries, &[]).lines.iter().enumerate()
+        .filter(|(_, line)| {
+            let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+            text.to_ascii_lowercase().contains(&query)
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Splits a span around each occurrence of `query` (already lowercase) so the
+/// matches can be highlighted.
+fn highlight_matches<'a>(span: Span<'a>, query: &str, highlight: Style) -> Vec<Span<'a>> {
+    let content = span.content.as_ref();
+    let lowered = content.to_ascii_lowercase();
+    if query.is_empty() || !lowered.contains(query) {
+        return vec![span];
+    }
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for (at, _) in lowered.match_indices(query) {
+        spans.push(Span::styled(content[start..at].to_string(), span.style));
+        spans.push(Span::styled(content[at..at + query.len()].to_string(), highlight));
+        start = at + query.len();
+    }
+    spans.push(Span::styled(content[start..].to_string(), span.style));
+    spans
+}
+
+/// Shows a player's name at the start of a log span (after a turn header's
+/// "--- ") in their color, keeping the rest of the span's style.
+fn color_player_name<'a>(span: Span<'a>, player_colors: &[(String, Color)]) -> Vec<Span<'a>> {
+    let content = span.content.as_ref();
+    let lead = if content.starts_with("--- ") { 4 } else { 0 };
+    let matched = player_colors.iter().find(|(name, _)| content[lead..].starts_with(name.as_str()));
+    let Some((name, color)) = matched else { return vec![span] };
+
+    let end = lead + name.len();
+    vec![
+        Span::styled(content[..lead].to_string(), span.style),
+        Span::styled(name.clone(), span.style.fg(*color).bold()),
+        Span::styled(content[end..].to_string(), span.style),
+    ]
+}
+
 /// Formats log messages for better readability.
-fn format_log_entries(log_entries: &[String]) -> Text {
+fn format_log_entries<'a>(log_entries: &[&'a str], player_colors: &[(String, Color)]) -> Text<'a> {
     let mut formatted_text = Text::default();
     let mut lines: Vec<Line> = Vec::new();
     
@@ -17,7 +174,7 @@ fn format_log_entries(log_entries: &[String]) -> Text {
     let mut is_turn_start = false;
     let mut processed_indices: HashSet<usize> = HashSet::new();
     
-    for (i, entry) in log_entries.iter().enumerate() {
+    for (i, &entry) in log_entries.iter().enumerate() {
         // Skip if already processed in a combined message
         if processed_indices.contains(&i) {
             continue;
@@ -103,7 +260,7 @@ fn format_log_entries(log_entries: &[String]) -> Text {
                 if next_entry.contains(" - ") {
                     lines.push(Line::from(vec![
                         Span::styled("  ", Style::default().fg(Color::Magenta).bold()),
-                        Span::styled(next_entry, Style::default().fg(Color::Magenta))
+                        Span::styled(*next_entry, Style::default().fg(Color::Magenta))
                     ]));
                     processed_indices.insert(i + 1);
                 }
@@ -277,18 +434,24 @@ fn format_log_entries(log_entries: &[String]) -> Text {
             lines.push(Line::from(""));
         } else {
             // Default style for other messages
-            lines.push(Line::from(entry.clone()));
+            lines.push(Line::from(entry));
         }
     }
 
-    formatted_text.lines = lines;
+    formatted_text.lines = lines.into_iter()
+        .map(|line| Line::from(line.spans.into_iter()
+            .flat_map(|span| color_player_name(span, player_colors))
+            .collect::<Vec<_>>()))
+        .collect();
     formatted_text
 }
 
 /// Renders the log widget with scrolling functionality.
-/// `log_entries` should be a vector of strings, where each string is a log line.
+/// `log_entries` are shown through `view`'s filter, with search matches highlighted.
 /// `scroll_offset` is the current scroll position.
-pub fn render_log(frame: &mut Frame, area: Rect, log_entries: &[String], scroll_offset: usize) {
+/// `player_colors` pairs player names with their colors (see `colors::player_name_colors`).
+pub fn render_log(frame: &mut Frame, area: Rect, log_entries: &[LogEntry], scroll_offset: usize,
+                  player_colors: &[(String, Color)], view: &LogView) {
     // Create a layout for the log area with space for a scrollbar
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -302,9 +465,29 @@ pub fn render_log(frame: &mut Frame, area: Rect, log_entries: &[String], scroll_
     let scrollbar_area = chunks[1];
     
     // Format log entries
-    let log_content = format_log_entries(log_entries);
+    let entries = visible_entries(log_entries, view);
+    let mut log_content = format_log_entries(&entries, player_colors);
     let line_count = log_content.lines.len();
 
+    // Highlight search matches, the one last jumped to more strongly
+    let query = view.query.to_ascii_lowercase();
+    let matches = matching_lines(log_entries, view);
+    let current_line = matches.get(view.current_match).copied();
+    if !query.is_empty() {
+        log_content.lines = log_content.lines.into_iter().enumerate()
+            .map(|(index, line)| {
+                let highlight = if Some(index) == current_line {
+                    Style::default().fg(Color::Black).bg(Color::LightYellow).bold()
+                } else {
+                    Style::default().fg(Color::Black).bg(Color::Yellow)
+                };
+                Line::from(line.spans.into_iter()
+                    .flat_map(|span| highlight_matches(span, &query, highlight))
+                    .collect::<Vec<_>>())
+            })
+            .collect();
+    }
+
     // Calculate the actual maximum scroll offset based on content and view height
     let visible_lines = log_area.height.saturating_sub(2) as usize; // Subtract 2 for top/bottom borders
     let max_scroll = line_count.saturating_sub(visible_lines);
@@ -318,10 +501,20 @@ pub fn render_log(frame: &mut Frame, area: Rect, log_entries: &[String], scroll_
     
     // Create block with title - show "More below..." indicator if not at bottom
     let is_at_bottom = effective_offset >= max_scroll;
+    let mut title = "Game Log".to_string();
+    if let Some(category) = view.filter {
+        title.push_str(&format!(" [{}]", category.label()));
+    }
+    if view.typing {
+        title.push_str(&format!(" /{}▏", view.query));
+    } else if !view.query.is_empty() {
+        let position = if matches.is_empty() { 0 } else { view.current_match + 1 };
+        title.push_str(&format!(" /{} ({}/{})", view.query, position, matches.len()));
+    }
     let block_title = if is_at_bottom || line_count <= visible_lines {
-        Span::styled("Game Log", Style::default().fg(Color::Green).bold())
+        Span::styled(title, Style::default().fg(Color::Green).bold())
     } else {
-        Span::styled("Game Log (More below... ↓)", 
+        Span::styled(format!("{} (More below... ↓)", title),
                     Style::default().fg(Color::Yellow).bold())
     };
     
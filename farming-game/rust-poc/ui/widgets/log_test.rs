@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::ui::widgets::log::{matching_lines, LogCategory, LogEntry, LogView};
+
+    fn entries(lines: &[&str]) -> Vec<LogEntry> {
+        lines.iter().map(|line| LogEntry::new(line.to_string())).collect()
+    }
+
+    #[test]
+    fn test_classify_log_lines() {
+        assert_eq!(LogCategory::classify("Ann rolled a 4 and landed on Spring Planting"), LogCategory::Movement);
+        assert_eq!(LogCategory::classify("Ann paid $500 in interest"), LogCategory::Finance);
+        assert_eq!(LogCategory::classify("Bo drew an O.T.B. card"), LogCategory::Cards);
+        assert_eq!(LogCategory::classify("Wheat: 10 acres harvested for $2000"), LogCategory::Harvest);
+        assert_eq!(LogCategory::classify("Welcome to the Farming Game!"), LogCategory::General);
+    }
+
+    #[test]
+    fn test_cycle_filter_and_search() {
+        let log = entries(&[
+            "--- Ann's turn ---",
+            "Ann rolled a 4 and landed on Spring Planting",
+            "Ann paid $500 in interest",
+            "--- Bo's turn ---",
+            "Bo rolled a 2 and landed on Hurt Back",
+            "Bo paid $1000 to the doctor",
+        ]);
+        let mut view = LogView::default();
+        for expected in LogCategory::FILTERS {
+            view.cycle_filter();
+            assert_eq!(view.filter, Some(expected));
+        }
+        view.cycle_filter();
+        assert_eq!(view.filter, None, "Cycles back to the full log");
+
+        view.query = "PAID".to_string();
+        let all_matches = matching_lines(&log, &view);
+        assert_eq!(all_matches.len(), 2);
+
+        // Filtering to finance hides the movement lines but keeps the turn headers
+        view.filter = Some(LogCategory::Finance);
+        view.query = "turn".to_string();
+        assert_eq!(matching_lines(&log, &view).len(), 2);
+        view.query = "paid".to_string();
+        let finance_matches = matching_lines(&log, &view);
+        assert_eq!(finance_matches.len(), 2);
+        assert!(finance_matches[1] < all_matches[1], "Fewer lines above the match once filtered");
+
+        view.query.clear();
+        assert!(matching_lines(&log, &view).is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::models::{format_money, AssetType, GameState};
+use crate::ui::colors::player_color;
+
+/// Renders the dialog for selling cows at this year's market price.
+pub fn render_market(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    player_id: usize,
+    quantity: u32,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2),  // Price and herd
+            Constraint::Min(2),     // Sale
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let player = &game_state.players[player_id];
+    let price = game_state.cow_price(player.year);
+    let cows = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+    let market = Paragraph::new(Line::from(vec![
+        Span::styled("Market: ", Style::default().fg(Color::White)),
+        Span::styled(format!("{} a head", format_money(price)), Style::default().fg(Color::Yellow).bold()),
+        Span::styled(format!("   Herd: {} cows", cows), Style::default().fg(Color::White)),
+    ]))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::BOTTOM));
+
+    let sale = Paragraph::new(Text::from(vec![
+        Line::from(vec![
+            Span::styled("Sell: ", Style::default().fg(Color::White)),
+            Span::styled(format!("{} cows", quantity), Style::default().fg(Color::Cyan).bold()),
+            Span::styled(format!(" for {}", format_money(price * quantity)), Style::default().fg(Color::Green).bold()),
+        ]),
+        Line::from(vec![
+            Span::styled("Cash after sale: ", Style::default().fg(Color::White)),
+            Span::styled(format_money(player.cash + price * quantity), Style::default().fg(Color::Green)),
+        ]),
+    ]))
+    .alignment(Alignment::Center);
+
+    let controls = Paragraph::new("↑/↓: ±1 | PgUp/PgDn: ±10 | Enter: Sell | Esc: Back")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title("Livestock Market")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(market, chunks[0]);
+    frame.render_widget(sale, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
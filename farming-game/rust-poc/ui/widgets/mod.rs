@@ -4,4 +4,31 @@ pub mod option_dialog;
 pub mod game_board;
 pub mod turn_menu;
 pub mod loan_payment;
-// Add other widget modules here (e.g., log) later 
\ No newline at end of file
+pub mod auction;
+pub mod insurance;
+pub mod dice;
+pub mod decision;
+pub mod market;
+pub mod livestock;
+pub mod achievements;
+pub mod help;
+pub mod turn_summary;
+pub mod turn_history;
+pub mod deck_stats;
+pub mod draw_odds;
+pub mod raise_cash;
+pub mod discard;
+pub mod options;
+pub mod pause_menu;
+pub mod debug_console;
+pub mod eliminated;
+pub mod countdown;
+pub mod game_over;
+pub mod tie_break;
+// Add other widget modules here (e.g., log) later
+
+#[cfg(test)]
+mod log_test;
+#[cfg(test)]
+mod snapshot_test;
+ 
\ No newline at end of file
@@ -2,10 +2,13 @@ use ratatui::{
     prelude::{Rect, Frame, Constraint, Direction, Layout},
     style::{Color, Style, Stylize},
     widgets::{Block, Borders, Paragraph, List, ListItem, ListState, Clear},
-    text::{Span, Line},
+    text::{Span, Line, Text},
 };
-use crate::models::{GameState, asset::AssetType};
+use crate::models::{format_money, GameState, asset::AssetType, Money};
 use crate::game::GameEffect;
+use crate::ui::colors::player_color;
+use crate::ui::layout::popup_rect;
+use crate::ui::text::{rich_text, truncate};
 
 /// Renders an option to buy dialog for player decisions.
 pub fn render_option_dialog(
@@ -16,15 +19,7 @@ pub fn render_option_dialog(
     selected_index: usize
 ) {
     // Create a centered dialog box - make it wider and much taller
-    let dialog_width = 80.min(area.width.saturating_sub(4));
-    let dialog_height = 20.min(area.height.saturating_sub(4));  // Adjusted for better proportions
-    
-    let dialog_area = Rect {
-        x: (area.width - dialog_width) / 2,
-        y: (area.height - dialog_height) / 2,
-        width: dialog_width,
-        height: dialog_height,
-    };
+    let dialog_area = popup_rect(80, 24, area);
     
     // First, render a completely opaque Clear widget to cover any text underneath
     frame.render_widget(Clear, dialog_area);
@@ -36,18 +31,21 @@ pub fn render_option_dialog(
         .constraints([
             Constraint::Length(3),    // Title
             Constraint::Length(8),    // Card list - fixed height for ~4 cards
+            Constraint::Min(3),       // Selected card's description
             Constraint::Length(3),    // Player info
             Constraint::Length(3),    // Instructions
         ])
         .split(dialog_area);
     
     // Get player information
-    let player = game_state.players.get(&player_id).unwrap();
+    let player = game_state.players.get(player_id).unwrap();
     let player_name = &player.name;
     let player_cash = player.cash;
     let player_debt = player.debt;
-    let player_position = player.position;
     
+    // O.T.B. cards can only be used while the rules' window is open
+    let window_open = game_state.can_exercise_option_to_buy(player_id);
+
     // Get available option to buy cards
     let option_cards = game_state.get_option_to_buy_cards(player_id);
     
@@ -67,7 +65,7 @@ pub fn render_option_dialog(
     // Create title with enhanced styling
     let title_text = format!("{}'s Option to Buy Cards", player_name);
     let title = Paragraph::new(title_text)
-        .style(Style::default().fg(Color::Yellow).bold().bg(Color::Black))
+        .style(Style::default().fg(player_color(player.color)).bold().bg(Color::Black))
         .alignment(ratatui::layout::Alignment::Center)
         .block(Block::default().borders(Borders::BOTTOM).bg(Color::Black));
     
@@ -87,11 +85,11 @@ pub fn render_option_dialog(
                     false // Don't need a loan
                 };
                 
-                (format!("{} x{} - ${} - {}", 
+                (format!("{} x{} - {} - {}", 
                     format_asset_type(*asset), 
                     quantity, 
-                    cost,
-                    card.title
+                    format_money(*cost),
+                    game_state.locale.card_title(card)
                 ), direct_purchase || can_get_loan, !direct_purchase && can_get_loan)
             },
             GameEffect::LeaseRidge { name, cost, cow_count } => {
@@ -105,21 +103,18 @@ pub fn render_option_dialog(
                     false // Don't need a loan
                 };
                 
-                (format!("Ridge: {} - ${} - {} cows", 
+                (format!("Ridge: {} - {} - {} cows", 
                     name, 
-                    cost,
+                    format_money(*cost),
                     cow_count
                 ), direct_purchase || can_get_loan, !direct_purchase && can_get_loan)
             },
             _ => ("Unknown card type".to_string(), false, false),
         };
         
-        // Check if OTB is disabled due to position
-        let is_disabled = player_position >= 15 && player_position <= 48;
-        
         // Display affordability status with icons
-        let status = if is_disabled {
-            " 🔒"  // Locked for positions 15-48
+        let status = if !window_open {
+            " 🔒"  // Locked outside the O.T.B. window
         } else if can_afford {
             if loan_needed {
                 " 💰+💳"  // Money + Credit card for loan
@@ -133,7 +128,7 @@ pub fn render_option_dialog(
         // Set style based on selected state, affordability, and position
         let style = if i == selected_index {
             Style::default().fg(Color::Black).bg(Color::White)
-        } else if is_disabled {
+        } else if !window_open {
             Style::default().fg(Color::DarkGray).bg(Color::Black)
         } else if !can_afford {
             Style::default().fg(Color::DarkGray).bg(Color::Black)
@@ -160,18 +155,32 @@ pub fn render_option_dialog(
         .highlight_style(Style::default().fg(Color::Black).bg(Color::White))  // Added highlight style
         .highlight_symbol(">> ");  // Added highlight symbol
     
+    // The selected card's full text, wrapped to the panel and cut off if it doesn't fit
+    let description_area = chunks[2];
+    let (text_width, text_lines) = (description_area.width.saturating_sub(2) as usize, description_area.height.saturating_sub(2) as usize);
+    let (card_title, card_text) = match option_cards.get(selected_index) {
+        Some(card) => (
+            truncate(game_state.locale.card_title(card), text_width),
+            rich_text(game_state.locale.card_description(card), text_width, text_lines, Style::default().fg(Color::White)),
+        ),
+        None => (String::new(), Vec::new()),
+    };
+    let description = Paragraph::new(Text::from(card_text))
+        .style(Style::default().bg(Color::Black))
+        .block(Block::default().borders(Borders::ALL).title(card_title).bg(Color::Black));
+
     // Player information with background and enhanced display
-    let player_cash_style = if player_cash > 3000 {
+    let player_cash_style = if player_cash > Money(3000) {
         Style::default().fg(Color::Green).bg(Color::Black)
-    } else if player_cash > 1000 {
+    } else if player_cash > Money(1000) {
         Style::default().fg(Color::Yellow).bg(Color::Black)
     } else {
         Style::default().fg(Color::Red).bg(Color::Black)
     };
     
-    let player_debt_style = if player_debt < 5000 {
+    let player_debt_style = if player_debt < Money(5000) {
         Style::default().fg(Color::Green).bg(Color::Black)
-    } else if player_debt < 10000 {
+    } else if player_debt < Money(10000) {
         Style::default().fg(Color::Yellow).bg(Color::Black)
     } else {
         Style::default().fg(Color::Red).bg(Color::Black)
@@ -179,9 +188,9 @@ pub fn render_option_dialog(
     
     let player_info_text = vec![
         Span::styled("Cash: ", Style::default().fg(Color::White).bg(Color::Black)),
-        Span::styled(format!("${} ", player_cash), player_cash_style),
+        Span::styled(format!("{} ", format_money(player_cash)), player_cash_style),
         Span::styled("| Debt: ", Style::default().fg(Color::White).bg(Color::Black)),
-        Span::styled(format!("${}", player_debt), player_debt_style),
+        Span::styled(format_money(player_debt), player_debt_style),
         Span::styled(" | Affordable O.T.B.: ", Style::default().fg(Color::White).bg(Color::Black)),
         Span::styled(format!("{}", affordable_cards), 
             if affordable_cards > 0 { Style::default().fg(Color::Green).bg(Color::Black) }
@@ -194,10 +203,10 @@ pub fn render_option_dialog(
         .block(Block::default().borders(Borders::ALL).title("Player Finances").bg(Color::Black));
     
     // Instructions with improved styling and icons
-    let instructions = if player_position >= 15 && player_position <= 48 {
-        "O.T.B. cards are locked in positions 15-48"
+    let instructions = if !window_open {
+        "O.T.B. cards are locked at this time of the year"
     } else {
-        "↑/↓: Select card | Enter: Buy | Esc: Skip"
+        "↑/↓: Select card | Enter: Buy | A: Auction | Esc: Skip"
     };
     
     let instructions = Paragraph::new(instructions)
@@ -218,8 +227,9 @@ pub fn render_option_dialog(
     // Render the components
     frame.render_widget(title, chunks[0]);
     frame.render_stateful_widget(list, chunks[1], &mut list_state);
-    frame.render_widget(player_info, chunks[2]);
-    frame.render_widget(instructions, chunks[3]);
+    frame.render_widget(description, chunks[2]);
+    frame.render_widget(player_info, chunks[3]);
+    frame.render_widget(instructions, chunks[4]);
 }
 
 /// Helper function to format asset type names for display
@@ -231,5 +241,8 @@ fn format_asset_type(asset_type: AssetType) -> String {
         AssetType::Fruit => "Fruit".to_string(), 
         AssetType::Tractor => "Tractor".to_string(),
         AssetType::Harvester => "Harvester".to_string(),
+        AssetType::Irrigation => "Irrigation".to_string(),
+        AssetType::Barn => "Barn".to_string(),
+        AssetType::Silo => "Grain Silo".to_string(),
     }
 } 
\ No newline at end of file
@@ -0,0 +1,71 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::settings::Settings;
+use crate::ui::animation::GameSpeed;
+
+/// A setting the options screen can change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionRow {
+    Theme,
+    Keys,
+    Speed,
+    Autosave,
+}
+
+/// Rows of the options screen, top to bottom.
+pub const ROWS: [OptionRow; 4] = [OptionRow::Theme, OptionRow::Keys, OptionRow::Speed, OptionRow::Autosave];
+
+/// Renders the options screen: each setting with its current value. The
+/// game speed is kept with the panel layout rather than the settings.
+pub fn render_options(frame: &mut Frame, area: Rect, settings: &Settings, speed: GameSpeed, selected: usize) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(3),     // Settings
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let lines: Vec<Line> = ROWS.iter().enumerate().map(|(i, row)| {
+        let (name, value) = match row {
+            OptionRow::Theme => ("Theme", settings.theme.label()),
+            OptionRow::Keys => ("Keys", settings.keymap.label()),
+            OptionRow::Speed => ("Animation speed", speed.as_str()),
+            OptionRow::Autosave => ("Save results when a game ends", if settings.autosave { "On" } else { "Off" }),
+        };
+        let (marker, style) = if i == selected {
+            ("> ", Style::default().fg(Color::Yellow).bold())
+        } else {
+            ("  ", Style::default().fg(Color::White))
+        };
+        Line::from(vec![
+            Span::styled(format!("{}{:<32}", marker, name), style),
+            Span::styled(value, Style::default().fg(Color::Cyan).bold()),
+        ])
+    }).collect();
+    let rows = Paragraph::new(Text::from(lines));
+
+    let controls = Paragraph::new("↑/↓: Select | Enter: Change | o/Esc: Save and close")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("Options")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(rows, chunks[0]);
+    frame.render_widget(controls, chunks[1]);
+}
@@ -0,0 +1,80 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Line},
+    layout::Alignment,
+};
+
+/// Something the pause menu can do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseRow {
+    Resume,
+    Options,
+    Save,
+    Quit,
+}
+
+/// Rows of the pause menu, top to bottom.
+pub const ROWS: [PauseRow; 4] = [PauseRow::Resume, PauseRow::Options, PauseRow::Save, PauseRow::Quit];
+
+/// Height of the pause menu, borders included.
+pub const HEIGHT: u16 = 11;
+
+/// Renders the pause menu. While `confirm_quit` is set it asks whether to
+/// quit instead; `saved` is where the last save went, if there was one.
+pub fn render_pause_menu(frame: &mut Frame, area: Rect, selected: usize, confirm_quit: bool, saved: Option<&str>) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(4),     // Rows, or the question
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let (lines, controls): (Vec<Line>, &str) = if confirm_quit {
+        (vec![
+            Line::from(""),
+            Line::from("Quit? This game will be lost.").style(Style::default().fg(Color::Yellow).bold()),
+            Line::from("Save the ledgers first to keep a record of it.").style(Style::default().fg(Color::Gray)),
+        ], "y: Quit | n/Esc: Back to the menu")
+    } else {
+        let mut lines: Vec<Line> = ROWS.iter().enumerate().map(|(i, row)| {
+            let label = match row {
+                PauseRow::Resume => "Resume",
+                PauseRow::Options => "Options",
+                PauseRow::Save => "Save ledgers",
+                PauseRow::Quit => "Quit",
+            };
+            if i == selected {
+                Line::from(format!("> {} <", label)).style(Style::default().fg(Color::Yellow).bold())
+            } else {
+                Line::from(label).style(Style::default().fg(Color::White))
+            }
+        }).collect();
+        if let Some(path) = saved {
+            lines.push(Line::from(format!("Saved to {}", path)).style(Style::default().fg(Color::Green)));
+        }
+        (lines, "↑/↓: Select | Enter: Choose | Esc: Resume")
+    };
+    let rows = Paragraph::new(Text::from(lines)).alignment(Alignment::Center);
+
+    let controls = Paragraph::new(controls)
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title("Paused")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(rows, chunks[0]);
+    frame.render_widget(controls, chunks[1]);
+}
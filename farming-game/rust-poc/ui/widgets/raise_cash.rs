@@ -0,0 +1,94 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear, Wrap},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::{DecisionKind, DecisionRequest};
+use crate::models::{format_money, GameState, Money};
+use crate::ui::colors::player_color;
+
+/// Renders the dialog for raising cash toward a bill: what's owed, the loan
+/// that would cover the rest, and what the bank will buy.
+pub fn render_raise_cash(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    request: &DecisionRequest,
+    selected: usize,
+    quantity: u32,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(4),  // Bill and loan terms
+            Constraint::Min(3),     // Assets to sell
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let DecisionKind::RaiseCash { amount } = request.kind else { return };
+    let player = &game_state.players[request.player_id];
+    let shortfall = (amount - player.cash).max(Money::ZERO);
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Bill: ", Style::default().fg(Color::White)),
+        Span::styled(format_money(amount), Style::default().fg(Color::Red).bold()),
+        Span::styled("   Cash: ", Style::default().fg(Color::White)),
+        Span::styled(format_money(player.cash), Style::default().fg(Color::Green).bold()),
+        Span::styled("   Short: ", Style::default().fg(Color::White)),
+        Span::styled(format_money(shortfall), Style::default().fg(Color::Yellow).bold()),
+    ])];
+    if shortfall > Money::ZERO {
+        let (loan, fee) = game_state.rules.forced_loan(shortfall);
+        lines.push(Line::from(Span::styled(
+            format!("Loan for the rest: {} (bank fee {}), debt after: {}", format_money(loan), format_money(fee), format_money(player.debt + loan)),
+            Style::default().fg(Color::White),
+        )));
+    }
+    let bill = Paragraph::new(Text::from(lines))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::BOTTOM));
+
+    let sellable = game_state.sellable_assets(request.player_id);
+    let rows: Vec<Line> = if sellable.is_empty() {
+        vec![Line::from(Span::styled("Nothing the bank will buy", Style::default().fg(Color::DarkGray)))]
+    } else {
+        sellable.iter().enumerate().map(|(i, sellable)| {
+            let name = game_state.locale.asset_name(&sellable.asset);
+            if i == selected {
+                Line::from(vec![
+                    Span::styled(format!("> {} ({} owned, {} each)", name, sellable.owned, format_money(sellable.price)),
+                        Style::default().fg(Color::Yellow).bold()),
+                    Span::styled(format!("  Sell {} for {}", quantity, format_money(sellable.price * quantity)),
+                        Style::default().fg(Color::Cyan).bold()),
+                ])
+            } else {
+                Line::from(Span::styled(format!("  {} ({} owned, {} each)", name, sellable.owned, format_money(sellable.price)),
+                    Style::default().fg(Color::White)))
+            }
+        }).collect()
+    };
+    let assets = Paragraph::new(Text::from(rows));
+
+    let controls = Paragraph::new("↑/↓: Select | PgUp/PgDn: Quantity | Enter: Sell | P/Esc: Pay")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title(format!("{} Raises Cash", player.name))
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(bill, chunks[0]);
+    frame.render_widget(assets, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
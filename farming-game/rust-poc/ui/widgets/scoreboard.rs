@@ -5,16 +5,31 @@ use ratatui::{
     style::{Color, Style, Stylize},
     widgets::{Block, Borders, Cell, Row, Table},
 };
-use crate::models::{GameState, Player, asset::AssetType}; // Import Player and AssetType
+use std::collections::BTreeSet;
+use crate::models::{format_money, GameState, Player, asset::AssetType}; // Import Player and AssetType
+use crate::game::Ranking;
+use crate::ui::colors::player_color;
+use crate::ui::layout::ScoreboardOrder;
  // For formatting strings
 
-/// Renders the scoreboard widget.
-pub fn render_scoreboard(frame: &mut Frame, area: Rect, game_state: &GameState) {
+/// Rank with an arrow for places gained or lost since before the last turn, e.g. "2↑".
+fn format_rank_cell(ranking: &Ranking) -> String {
+    let arrow = match ranking.movement {
+        m if m > 0 => "↑",
+        m if m < 0 => "↓",
+        _ => "",
+    };
+    format!("{}{}", ranking.rank, arrow)
+}
+
+/// Renders the scoreboard widget, rows in turn order or richest first.
+/// Bankrupt players are grayed out, and left off entirely once in `hidden`.
+pub fn render_scoreboard(frame: &mut Frame, area: Rect, game_state: &GameState, order: ScoreboardOrder, hidden: &BTreeSet<usize>) {
     // Create header with columns for each important stat
     let header_cells = [
-        "Player", "Cash", "Debt", "Net Worth", 
+        "Player", "#", "Cash", "Debt", "Net Worth", "Income", "Costs",
         "Grain", "Hay", "Cows", "Fruit", 
-        "Tractor", "Harvester", "Pos", "Turn", "Year"
+        "Tractor", "Harvester", "Upgrades", "Pos", "Season", "Turn", "Year"
     ]
     .iter()
     .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).bold()));
@@ -25,9 +40,17 @@ pub fn render_scoreboard(frame: &mut Frame, area: Rect, game_state: &GameState)
     // Get the current player ID for highlighting
     let current_player_id = game_state.turn_order[game_state.current_turn_index];
 
+    let rankings = game_state.rankings();
+    let player_ids: Vec<usize> = match order {
+        ScoreboardOrder::TurnOrder => game_state.turn_order.clone(),
+        ScoreboardOrder::Rank => rankings.iter().map(|ranking| ranking.player_id).collect(),
+    };
+
     // Generate rows from game_state data
-    let rows: Vec<Row> = game_state.turn_order.iter().map(|player_id| {
-        let player = game_state.players.get(player_id).expect("Player ID in turn_order not found");
+    let rows: Vec<Row> = player_ids.iter().filter(|player_id| !hidden.contains(player_id)).map(|player_id| {
+        let player = game_state.players.get(*player_id).expect("Player ID in turn_order not found");
+        let ranking = rankings.iter().find(|ranking| ranking.player_id == *player_id);
+        let is_leader = ranking.is_some_and(|ranking| ranking.rank == 1);
 
         // Format crop quantities with multipliers
         let grain_cell = format_asset_cell(player, AssetType::Grain);
@@ -38,26 +61,47 @@ pub fn render_scoreboard(frame: &mut Frame, area: Rect, game_state: &GameState)
         // Equipment yes/no values
         let has_tractor = if player.assets.contains_key(&AssetType::Tractor) { "Yes" } else { "No" };
         let has_harvester = if player.assets.contains_key(&AssetType::Harvester) { "Yes" } else { "No" };
+        let upgrades = format_upgrades_cell(player);
+
+        // The current player's name is marked and shown on their color
+        let color = player_color(player.color);
+        let name_cell = if !player.is_active {
+            Cell::from(format!("✗ {}", player.name))
+        } else if *player_id == current_player_id {
+            Cell::from(format!("▶ {}", player.name)).style(Style::default().fg(Color::Black).bg(color).bold())
+        } else {
+            Cell::from(player.name.clone()).style(Style::default().fg(color))
+        };
+
+        // The leader's rank and net worth stand out
+        let leader_style = if is_leader { Style::default().fg(Color::Yellow).bold() } else { Style::default() };
 
         let row = Row::new(vec![
-            Cell::from(player.name.clone()),
-            Cell::from(format!("${}", player.cash)),
-            Cell::from(format!("${}", player.debt)),
-            Cell::from(format!("${}", player.net_worth)),
+            name_cell,
+            Cell::from(ranking.map(format_rank_cell).unwrap_or_default()).style(leader_style),
+            Cell::from(format_money(player.cash)),
+            Cell::from(format_money(player.debt)),
+            Cell::from(format_money(player.net_worth)).style(leader_style),
+            Cell::from(format_money(player.total_income)),
+            Cell::from(format_money(player.total_expenses)),
             Cell::from(grain_cell),
             Cell::from(hay_cell),
             Cell::from(cows_cell),
             Cell::from(Fruit_cell),
             Cell::from(has_tractor.to_string()),
             Cell::from(has_harvester.to_string()),
+            Cell::from(upgrades),
             Cell::from(player.position.to_string()),
+            Cell::from(game_state.player_phase(*player_id).label()),
             Cell::from(player.turns_taken.to_string()),
             Cell::from(player.year.to_string()),
         ]);
 
-        // Highlight the current player's row
-        if *player_id == current_player_id {
-            row.style(Style::default().bg(Color::Blue))
+        // Highlight the current player's row; bankrupt players fade out
+        if !player.is_active {
+            row.style(Style::default().fg(Color::DarkGray))
+        } else if *player_id == current_player_id {
+            row.style(Style::default().bg(Color::DarkGray))
         } else {
             row
         }
@@ -66,23 +110,33 @@ pub fn render_scoreboard(frame: &mut Frame, area: Rect, game_state: &GameState)
     // Define column widths
     let widths = [
         Constraint::Length(25), // Player Name (increased width)
+        Constraint::Length(3),  // Rank
         Constraint::Length(8),  // Cash
         Constraint::Length(8),  // Debt
         Constraint::Length(10), // Net Worth
+        Constraint::Length(8),  // Income
+        Constraint::Length(8),  // Costs
         Constraint::Length(8),  // Grain
         Constraint::Length(8),  // Hay
         Constraint::Length(8),  // Cows
         Constraint::Length(8),  // Fruit
         Constraint::Length(7),  // Tractor
         Constraint::Length(9),  // Harvester
+        Constraint::Length(8),  // Upgrades
         Constraint::Length(4),  // Pos
+        Constraint::Length(15), // Season
         Constraint::Length(5),  // Turn
         Constraint::Length(4),  // Year
     ];
 
+    // Forecast for the year the current player is in
+    let weather = game_state.weather_for_year(game_state.players[current_player_id].year);
+
     let table = Table::new(rows, widths)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Scoreboard"))
+        .block(Block::default().borders(Borders::ALL)
+            .title(game_state.locale.tr("ui.scoreboard_title", &[("phase", &game_state.phase.label()),
+                ("forecast", &game_state.locale.weather_forecast(weather))])))
         .column_spacing(1);
 
     frame.render_widget(table, area);
@@ -98,4 +152,17 @@ fn format_asset_cell(player: &Player, asset_type: AssetType) -> String {
     } else {
         format!("{}×{:.1}", quantity, multiplier)
     }
-} 
\ No newline at end of file
+} 
+
+/// Initials of the farm upgrades the player owns (I = Irrigation, B = Barn, S = Silo)
+fn format_upgrades_cell(player: &Player) -> String {
+    let initials: Vec<&str> = AssetType::UPGRADES.iter()
+        .filter(|upgrade| player.assets.get(upgrade).is_some_and(|record| record.quantity > 0))
+        .map(|upgrade| match upgrade {
+            AssetType::Irrigation => "I",
+            AssetType::Barn => "B",
+            _ => "S",
+        })
+        .collect();
+    if initials.is_empty() { "-".to_string() } else { initials.join(" ") }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::time::Duration;
+    use std::path::PathBuf;
+    use ratatui::{backend::TestBackend, prelude::{Frame, Rect}, Terminal};
+    use crate::cards::catalogs::option_to_buy_catalog;
+    use crate::config::GameRules;
+    use crate::models::{AssetType, GameState, Money, Player, PlayerRegistry, PlayerType};
+    use crate::ui::colors::player_name_colors;
+    use crate::ui::keymap::Keymap;
+    use crate::ui::layout::ScoreboardOrder;
+    use crate::ui::widgets::countdown::{self, render_countdown};
+    use crate::ui::widgets::draw_odds::{self, render_draw_odds};
+    use crate::ui::widgets::game_over::{self, render_game_over};
+    use crate::ui::widgets::log::{render_log, LogEntry, LogView};
+    use crate::ui::widgets::loan_payment::render_loan_payment;
+    use crate::ui::widgets::option_dialog::render_option_dialog;
+    use crate::ui::widgets::scoreboard::render_scoreboard;
+    use crate::ui::widgets::turn_menu::render_turn_menu;
+
+    /// Draws into a `width` x `height` test terminal and returns the screen
+    /// as text, one line per row with trailing blanks trimmed. Colors aren't kept.
+    fn render(width: u16, height: u16, draw: impl FnOnce(&mut Frame, Rect)) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| draw(frame, frame.size())).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                let row: String = (0..width).map(|x| buffer.get(x, y).symbol()).collect();
+                format!("{}\n", row.trim_end())
+            })
+            .collect()
+    }
+
+    /// Compares `screen` with `ui/widgets/snapshots/<name>.txt`. A missing
+    /// snapshot is written out; set `UPDATE_SNAPSHOTS=1` to rewrite them all
+    /// after changing a widget on purpose.
+    fn assert_snapshot(name: &str, screen: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("ui/widgets/snapshots").join(format!("{}.txt", name));
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() || !path.exists() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, screen).unwrap();
+            return;
+        }
+        let expected = fs::read_to_string(&path).unwrap();
+        assert!(expected == screen, "{} changed; rerun with UPDATE_SNAPSHOTS=1 if that's intended.\n--- expected\n{}--- rendered\n{}",
+                name, expected, screen);
+    }
+
+    /// Ann and Bo in their first year, with the same deal every time. Ann
+    /// has ten cows and holds two Option to Buy cards.
+    fn fixed_game() -> GameState {
+        let mut players = PlayerRegistry::new();
+        for (id, name) in ["Ann", "Bo"].into_iter().enumerate() {
+            let mut player = Player::new(id, name.to_string(), PlayerType::Human);
+            player.cash = Money(5000) + Money(1000) * id as i64;
+            players.insert(player);
+        }
+        let mut game = GameState::new_with_players(players, vec![0, 1], GameRules::default());
+        game.reseed(7);
+        let ann = &mut game.players[0];
+        ann.position = 2;
+        ann.add_asset(AssetType::Cows, 10, Money(5000));
+        ann.hand = option_to_buy_catalog().into_iter().take(2).collect();
+        game.update_player_valuation(0);
+        game
+    }
+
+    #[test]
+    fn test_scoreboard_snapshot() {
+        let game = fixed_game();
+        let screen = render(160, 8, |frame, area| render_scoreboard(frame, area, &game, ScoreboardOrder::TurnOrder, &BTreeSet::new()));
+        assert_snapshot("scoreboard", &screen);
+    }
+
+    #[test]
+    fn test_log_snapshot() {
+        let game = fixed_game();
+        let entries: Vec<LogEntry> = [
+            "--- Ann's turn ---",
+            "🎲 Ann rolled a 4",
+            "Ann paid $500 in interest",
+            "--- Bo's turn ---",
+            "Bo drew an O.T.B. card",
+        ].into_iter().map(|line| LogEntry::new(line.to_string())).collect();
+        let screen = render(60, 16, |frame, area| {
+            render_log(frame, area, &entries, 0, &player_name_colors(&game), &LogView::default());
+        });
+        assert_snapshot("log", &screen);
+    }
+
+    #[test]
+    fn test_option_dialog_snapshot() {
+        let game = fixed_game();
+        let screen = render(84, 28, |frame, area| render_option_dialog(frame, area, &game, 0, 1));
+        assert_snapshot("option_dialog", &screen);
+    }
+
+    #[test]
+    fn test_turn_menu_snapshot() {
+        let game = fixed_game();
+        let screen = render(60, 19, |frame, area| render_turn_menu(frame, area, &game, 0, true, &Keymap::default()));
+        assert_snapshot("turn_menu", &screen);
+    }
+
+    #[test]
+    fn test_loan_payment_snapshot() {
+        let mut game = fixed_game();
+        game.players[0].debt = Money(5000);
+        game.players[0].reconcile_notes(game.rules.interest_rate);
+        let screen = render(64, 24, |frame, area| render_loan_payment(frame, area, &game, 0, &mut Money(2000)));
+        assert_snapshot("loan_payment", &screen);
+    }
+
+    #[test]
+    fn test_countdown_snapshot() {
+        let screen = render(countdown::WIDTH, countdown::HEIGHT * 2, |frame, area| {
+            let (top, bottom) = (Rect { height: countdown::HEIGHT, ..area }, Rect { y: countdown::HEIGHT, height: countdown::HEIGHT, ..area });
+            render_countdown(frame, top, 3, 10);
+            render_countdown(frame, bottom, 10, 10);
+        });
+        assert_snapshot("countdown", &screen);
+    }
+
+    #[test]
+    fn test_game_over_snapshot() {
+        let game = fixed_game();
+        // Long enough for every row to be in
+        let screen = render(72, game_over::height(2), |frame, area| {
+            render_game_over(frame, area, &game, 0, Duration::from_secs(5), Some("results.csv"), &Keymap::default());
+        });
+        assert_snapshot("game_over", &screen);
+    }
+
+    #[test]
+    fn test_draw_odds_snapshot() {
+        let mut game = fixed_game();
+        // Empty the Operating Cost draw pile so its odds come from the discards
+        let deck = &mut game.operating_cost_deck;
+        let played: Vec<_> = deck.draw_pile.drain(..).collect();
+        deck.discard_pile.extend(played);
+        let decks = game.deck_stats();
+        let screen = render(draw_odds::WIDTH, draw_odds::height(decks.len()), |frame, area| render_draw_odds(frame, area, &decks));
+        assert_snapshot("draw_odds", &screen);
+    }
+}
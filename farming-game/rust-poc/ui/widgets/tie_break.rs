@@ -0,0 +1,76 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::TieBreak;
+use crate::models::{format_money, GameState};
+
+/// Height for a tie between `contenders` players, borders included.
+pub fn height(contenders: usize) -> u16 {
+    contenders as u16 + 8
+}
+
+/// Renders how a win several players reached in the same year was settled:
+/// each contender's net worth and dice-off rolls, with the winner marked.
+pub fn render_tie_break(frame: &mut Frame, area: Rect, game_state: &GameState, tie_break: &TieBreak) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2),  // What happened
+            Constraint::Min(1),     // Contenders
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let settled_by = if tie_break.rolls.is_empty() {
+        "The higher net worth takes it."
+    } else {
+        "Tied on net worth too, so it came down to a dice-off."
+    };
+    let intro = Paragraph::new(Text::from(vec![
+        Line::from("More than one farmer reached the goal in the same year!").style(Style::default().fg(Color::Yellow).bold()),
+        Line::from(settled_by).style(Style::default().fg(Color::Gray)),
+    ])).alignment(Alignment::Center);
+
+    let lines: Vec<Line> = tie_break.contenders.iter()
+        .filter_map(|id| game_state.players.get(*id))
+        .map(|player| {
+            let rolls: Vec<String> = tie_break.rolls.iter()
+                .filter(|(roller, _)| *roller == player.id)
+                .map(|(_, roll)| roll.to_string())
+                .collect();
+            let rolls = if rolls.is_empty() { String::new() } else { format!("  rolled {}", rolls.join(", ")) };
+            let (marker, color) = if player.id == tie_break.winner { ("🏆", Color::Yellow) } else { ("  ", Color::White) };
+            Line::from(vec![
+                Span::raw(format!("{} ", marker)),
+                Span::styled(format!("{:<16}", player.name), Style::default().fg(color).bold()),
+                Span::styled(format!("{:>12}", format_money(player.net_worth)), Style::default().fg(Color::Green)),
+                Span::styled(rolls, Style::default().fg(Color::Cyan)),
+            ])
+        })
+        .collect();
+    let contenders = Paragraph::new(Text::from(lines));
+
+    let controls = Paragraph::new("Enter: Final standings")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title("Tie for the win")
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(intro, chunks[0]);
+    frame.render_widget(contenders, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
@@ -0,0 +1,103 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::TurnRecord;
+use crate::models::{format_money, format_money_change, GameState, Money};
+
+/// Size of the turn history, borders included.
+pub const WIDTH: u16 = 90;
+pub const HEIGHT: u16 = 22;
+
+/// Turn rows that fit under the header, above the totals and controls.
+pub const VISIBLE_TURNS: usize = HEIGHT as usize - 8;
+
+/// `text` cut to `width` characters, ending in "…" if anything was cut.
+fn fit(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+/// Cards played, loans taken and debt repaid on a turn, as one line.
+fn notes(turn: &TurnRecord) -> String {
+    let mut notes: Vec<String> = turn.cards.iter().map(|card| format!("'{}'", card)).collect();
+    notes.extend(turn.loans.iter().map(|loan| format!("borrowed {}", format_money(*loan))));
+    if turn.repaid > Money::ZERO {
+        notes.push(format!("repaid {}", format_money(turn.repaid)));
+    }
+    notes.join(", ")
+}
+
+/// Renders `player_id`'s turns in game year `year`, starting `scroll` turns
+/// in, with the year's totals underneath.
+pub fn render_turn_history(frame: &mut Frame, area: Rect, game_state: &GameState, player_id: usize, year: u32, scroll: usize) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(4),     // Turns
+            Constraint::Length(1),  // Year totals
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let name = game_state.players.get(player_id).map_or("?", |player| player.name.as_str());
+    let turns: Vec<TurnRecord> = game_state.turn_history(player_id).into_iter().filter(|turn| turn.year == year).collect();
+
+    let header = format!("{:>5}  {:<5}  {:<30}  {:>10}  {}", "Turn", "Roll", "Landed on", "Cash", "Cards and loans");
+    let mut lines = vec![Line::from(header).style(Style::default().fg(Color::DarkGray))];
+    if turns.is_empty() {
+        lines.push(Line::from(format!("{} has no turns in year {}", name, year)).style(Style::default().fg(Color::Gray)));
+    }
+    for turn in turns.iter().skip(scroll).take(VISIBLE_TURNS) {
+        let rolls = turn.rolls.iter().map(|roll| roll.to_string()).collect::<Vec<_>>().join("+");
+        let tiles = turn.tiles.iter()
+            .filter_map(|tile| game_state.board.get(*tile))
+            .map(|tile| game_state.locale.tile_name(tile))
+            .collect::<Vec<_>>()
+            .join(" → ");
+        let cash_color = if turn.cash_change < Money::ZERO { Color::Red } else if turn.cash_change > Money::ZERO { Color::Green } else { Color::Gray };
+        let number = if turn.in_progress { format!("{}*", turn.number) } else { turn.number.to_string() };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:>5}  {:<5}  ", number, rolls), Style::default().fg(Color::White)),
+            Span::styled(format!("{:<30}  ", fit(&tiles, 30)), Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{:>10}  ", format_money_change(turn.cash_change)), Style::default().fg(cash_color)),
+            Span::styled(fit(&notes(turn), 30), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+    let list = Paragraph::new(Text::from(lines));
+
+    let cash_change: Money = turns.iter().map(|turn| turn.cash_change).sum();
+    let borrowed: Money = turns.iter().flat_map(|turn| &turn.loans).sum();
+    let repaid: Money = turns.iter().map(|turn| turn.repaid).sum();
+    let totals = Paragraph::new(format!("{} turns | cash {} | borrowed {} | repaid {}",
+        turns.len(), format_money_change(cash_change), format_money(borrowed), format_money(repaid)))
+        .style(Style::default().fg(Color::White).bold())
+        .alignment(Alignment::Center);
+
+    let controls = Paragraph::new("↑/↓: Player | ←/→: Year | PgUp/PgDn: Scroll | h/Esc: Back | * turn in progress")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title(format!("Turn history: {}, year {}", name, year))
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(list, chunks[0]);
+    frame.render_widget(totals, chunks[1]);
+    frame.render_widget(controls, chunks[2]);
+}
@@ -5,8 +5,45 @@ use ratatui::{
     text::{Text, Span, Line},
     layout::Alignment,
 };
-use crate::models::GameState;
-use crate::game::GameEffect;
+use crate::models::{format_money, AssetType, GameState, HarvestType, Money};
+use crate::game::{Action, GameEffect};
+use crate::game::harvest::HarvestPreview;
+use crate::ui::colors::player_color;
+use crate::ui::layout::popup_rect;
+use crate::ui::keymap::{KeyAction, Keymap};
+
+/// Key shown next to a menu option, capitalized like the menu has always shown it.
+fn menu_key(keymap: &Keymap, action: KeyAction) -> String {
+    let label = keymap.label(action);
+    if label.chars().count() == 1 { label.to_uppercase() } else { label }
+}
+
+/// Two lines previewing the harvest on the tile the player is standing on.
+fn harvest_preview_lines(game_state: &GameState, preview: &HarvestPreview) -> Vec<Line<'static>> {
+    let locale = &game_state.locale;
+    let harvest = locale.harvest_name(&preview.harvest_type).to_string();
+    let label = Style::default().fg(Color::White).bg(Color::Black);
+    if preview.quantity == 0 {
+        let asset = preview.asset.map_or(String::new(), |asset| locale.asset_name(&asset).to_string());
+        return vec![Line::from(Span::styled(format!("{}: no {} to harvest", harvest, asset), Style::default().fg(Color::DarkGray).bg(Color::Black)))];
+    }
+    let multiplier: f32 = preview.modifiers.iter().map(|modifier| modifier.multiplier()).product();
+    let mut income = vec![
+        Span::styled(format!("{}: ", harvest), label),
+        Span::styled(format!("{}-{}", format_money(preview.income_range.0), format_money(preview.income_range.1)), Style::default().fg(Color::Green).bg(Color::Black).bold()),
+        Span::styled(format!(", about {}", format_money(preview.expected_income)), label),
+    ];
+    if (multiplier - 1.0).abs() > f32::EPSILON {
+        income.push(Span::styled(format!(" (x{:.2})", multiplier), Style::default().fg(Color::Yellow).bg(Color::Black)));
+    }
+    let odds = &preview.expense;
+    let expense = Line::from(vec![
+        Span::styled("Operating expense: ", label),
+        Span::styled(format!("{}-{}", format_money(odds.min), format_money(odds.max)), Style::default().fg(Color::Red).bg(Color::Black)),
+        Span::styled(format!(" ({} of {} cards free)", odds.free, odds.cards), label),
+    ]);
+    vec![Line::from(income), expense]
+}
 
 /// Renders the turn menu that appears after a player's turn.
 pub fn render_turn_menu(
@@ -14,18 +51,18 @@ pub fn render_turn_menu(
     area: Rect,
     game_state: &GameState,
     player_id: usize,
-    has_otb_cards: bool
+    has_otb_cards: bool,
+    keymap: &Keymap,
 ) {
+    // Players standing on a harvest tile see what it could bring them
+    let player = &game_state.players[player_id];
+    let harvest_preview = game_state.board.get(player.position)
+        .filter(|tile| tile.harvest_type != HarvestType::None)
+        .and_then(|tile| game_state.preview_harvest(player_id, tile.harvest_type.clone()).ok());
+    let preview_height = if harvest_preview.is_some() { 2 } else { 0 };
+
     // Create a centered menu box - make it more compact
-    let menu_width = 60.min(area.width.saturating_sub(4));
-    let menu_height = 12.min(area.height.saturating_sub(4));  // Reduced height
-    
-    let menu_area = Rect {
-        x: (area.width - menu_width) / 2,
-        y: (area.height - menu_height) / 2,
-        width: menu_width,
-        height: menu_height,
-    };
+    let menu_area = popup_rect(60, 15 + preview_height, area);
     
     // First, render a completely opaque Clear widget to cover text underneath
     frame.render_widget(Clear, menu_area);
@@ -37,13 +74,13 @@ pub fn render_turn_menu(
         .constraints([
             Constraint::Length(2),  // Title - reduced from 3
             Constraint::Length(2),  // Player info - reduced from 3
-            Constraint::Length(4),  // Options - reduced from 5
+            Constraint::Length(preview_height),  // Harvest preview
+            Constraint::Length(7),  // Options
             Constraint::Length(1),  // Instructions
         ])
         .split(menu_area);
     
     // Get player information
-    let player = &game_state.players[&player_id];
     let player_name = &player.name;
     let player_cash = player.cash;
     let player_debt = player.debt;
@@ -65,22 +102,22 @@ pub fn render_turn_menu(
     // Create title with styling
     let title_text = format!("{}'s Turn Menu", player_name);
     let title = Paragraph::new(title_text)
-        .style(Style::default().fg(Color::Yellow).bold().bg(Color::Black))
+        .style(Style::default().fg(player_color(player.color)).bold().bg(Color::Black))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::BOTTOM).bg(Color::Black));
     
     // Player financial info with styling
-    let player_cash_style = if player_cash > 3000 {
+    let player_cash_style = if player_cash > Money(3000) {
         Style::default().fg(Color::Green).bg(Color::Black)
-    } else if player_cash > 1000 {
+    } else if player_cash > Money(1000) {
         Style::default().fg(Color::Yellow).bg(Color::Black)
     } else {
         Style::default().fg(Color::Red).bg(Color::Black)
     };
     
-    let player_debt_style = if player_debt < 5000 {
+    let player_debt_style = if player_debt < Money(5000) {
         Style::default().fg(Color::Green).bg(Color::Black)
-    } else if player_debt < 10000 {
+    } else if player_debt < Money(10000) {
         Style::default().fg(Color::Yellow).bg(Color::Black)
     } else {
         Style::default().fg(Color::Red).bg(Color::Black)
@@ -88,9 +125,9 @@ pub fn render_turn_menu(
     
     let player_info_text = vec![
         Span::styled("Cash: ", Style::default().fg(Color::White).bg(Color::Black)),
-        Span::styled(format!("${} ", player_cash), player_cash_style),
+        Span::styled(format!("{} ", format_money(player_cash)), player_cash_style),
         Span::styled("| Debt: ", Style::default().fg(Color::White).bg(Color::Black)),
-        Span::styled(format!("${}", player_debt), player_debt_style),
+        Span::styled(format_money(player_debt), player_debt_style),
         Span::styled(" | Affordable OTB: ", Style::default().fg(Color::White).bg(Color::Black)),
         Span::styled(format!("{}", affordable_cards), 
             if affordable_cards > 0 { Style::default().fg(Color::Green).bg(Color::Black) }
@@ -102,38 +139,83 @@ pub fn render_turn_menu(
         .style(Style::default().bg(Color::Black))
         .alignment(Alignment::Center);  // Center align for better appearance
     
-    // Create options text with styling
+    // Create options text with styling, enabling those the game offers a move for
+    let offered = game_state.turn_actions(player_id);
+    let offers = |wanted: fn(&Action) -> bool| offered.iter().any(wanted);
     let mut options_text = Vec::new();
     
     // Show Option to Buy first
     if has_otb_cards {
         options_text.push(Line::from(vec![
-            Span::styled("O", Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
+            Span::styled(menu_key(keymap, KeyAction::OpenOtb), Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
             Span::styled(" - View and exercise Option to Buy cards", Style::default().fg(Color::White).bg(Color::Black)),
         ]));
     } else {
         options_text.push(Line::from(vec![
-            Span::styled("O", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(menu_key(keymap, KeyAction::OpenOtb), Style::default().fg(Color::DarkGray).bg(Color::Black)),
             Span::styled(" - No Option to Buy cards available", Style::default().fg(Color::DarkGray).bg(Color::Black)),
         ]));
     }
     
     // Add option to pay back loans
-    if player_cash > 0 && player_debt > 0 {
+    if offers(|action| matches!(action, Action::PayLoan(_))) {
         options_text.push(Line::from(vec![
-            Span::styled("P", Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
+            Span::styled(menu_key(keymap, KeyAction::PayLoan), Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
             Span::styled(" - Pay back loans", Style::default().fg(Color::White).bg(Color::Black)),
         ]));
     } else {
         options_text.push(Line::from(vec![
-            Span::styled("P", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(menu_key(keymap, KeyAction::PayLoan), Style::default().fg(Color::DarkGray).bg(Color::Black)),
             Span::styled(" - No cash available to pay loans", Style::default().fg(Color::DarkGray).bg(Color::Black)),
         ]));
     }
 
+    // Add option to buy insurance at the start of the year
+    if game_state.can_buy_insurance(player_id) {
+        options_text.push(Line::from(vec![
+            Span::styled(menu_key(keymap, KeyAction::Insurance), Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
+            Span::styled(" - Buy crop/livestock insurance", Style::default().fg(Color::White).bg(Color::Black)),
+        ]));
+    } else {
+        options_text.push(Line::from(vec![
+            Span::styled(menu_key(keymap, KeyAction::Insurance), Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(" - Insurance is only sold during Spring Planting", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+        ]));
+    }
+
+    // Add option to sell cows at market
+    let cows = player.assets.get(&AssetType::Cows).map_or(0, |record| record.quantity);
+    if offers(|action| matches!(action, Action::SellCows(_))) {
+        options_text.push(Line::from(vec![
+            Span::styled(menu_key(keymap, KeyAction::Market), Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
+            Span::styled(format!(" - Sell cows at market ({} a head)", format_money(game_state.cow_price(player.year))),
+                Style::default().fg(Color::White).bg(Color::Black)),
+        ]));
+    } else {
+        options_text.push(Line::from(vec![
+            Span::styled(menu_key(keymap, KeyAction::Market), Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(" - No cows to sell at market", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+        ]));
+    }
+
+    // Add option to move cows between the farm and leased ridges
+    if offers(|action| matches!(action, Action::MoveCows { .. })) {
+        let on_ridges: i32 = game_state.leased_ridges(player_id).iter().map(|ridge| ridge.cow_count).sum();
+        options_text.push(Line::from(vec![
+            Span::styled(menu_key(keymap, KeyAction::Livestock), Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
+            Span::styled(format!(" - Manage livestock ({} on the farm, {} on ridges)", cows, on_ridges),
+                Style::default().fg(Color::White).bg(Color::Black)),
+        ]));
+    } else {
+        options_text.push(Line::from(vec![
+            Span::styled(menu_key(keymap, KeyAction::Livestock), Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            Span::styled(" - No cows to move between farm and ridges", Style::default().fg(Color::DarkGray).bg(Color::Black)),
+        ]));
+    }
+
     // Add end turn option last
     options_text.push(Line::from(vec![
-        Span::styled("E", Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
+        Span::styled(menu_key(keymap, KeyAction::EndTurn), Style::default().fg(Color::Cyan).bg(Color::Black).bold()),
         Span::styled(" - End turn and move to the next player", Style::default().fg(Color::White).bg(Color::Black)),
     ]));
     
@@ -159,6 +241,12 @@ pub fn render_turn_menu(
     
     frame.render_widget(title, chunks[0]);
     frame.render_widget(player_info, chunks[1]);
-    frame.render_widget(options_paragraph, chunks[2]);
-    frame.render_widget(instructions, chunks[3]);
+    if let Some(preview) = &harvest_preview {
+        let preview_paragraph = Paragraph::new(Text::from(harvest_preview_lines(game_state, preview)))
+            .style(Style::default().bg(Color::Black))
+            .alignment(Alignment::Center);
+        frame.render_widget(preview_paragraph, chunks[2]);
+    }
+    frame.render_widget(options_paragraph, chunks[3]);
+    frame.render_widget(instructions, chunks[4]);
 } 
\ No newline at end of file
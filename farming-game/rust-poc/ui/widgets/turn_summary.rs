@@ -0,0 +1,80 @@
+use ratatui::{
+    prelude::{Rect, Frame, Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    widgets::{Block, Borders, Paragraph, Clear},
+    text::{Text, Span, Line},
+    layout::Alignment,
+};
+use crate::game::TurnSummary;
+use crate::models::{format_money_change, GameState, Money};
+use crate::ui::colors::player_color;
+
+/// Signed dollar amount, green when it's good for the player and red when it isn't.
+fn change_span(amount: Money, good_when_positive: bool) -> Span<'static> {
+    let text = format_money_change(amount);
+    let color = match amount {
+        Money::ZERO => Color::Gray,
+        _ if amount.is_positive() == good_when_positive => Color::Green,
+        _ => Color::Red,
+    };
+    Span::styled(text, Style::default().fg(color).bold())
+}
+
+/// Renders the recap shown after a player ends their turn.
+pub fn render_turn_summary(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    summary: &TurnSummary,
+) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(4),     // Changes
+            Constraint::Length(2),  // Controls
+        ])
+        .split(area);
+
+    let player = &game_state.players[summary.player_id];
+    let label = |text: &str| Span::styled(format!("{:<11}", text), Style::default().fg(Color::White));
+    let mut lines = vec![
+        Line::from(vec![label("Cash:"), change_span(summary.cash_change, true)]),
+        Line::from(vec![label("Debt:"), change_span(summary.debt_change, false)]),
+        Line::from(vec![label("Net worth:"), change_span(summary.net_worth_change, true)]),
+        Line::from(vec![label("Cards:"), Span::styled(format!("{} drawn", summary.cards_drawn), Style::default().fg(Color::Cyan))]),
+    ];
+    if summary.harvests.is_empty() {
+        lines.push(Line::from(vec![label("Harvests:"), Span::styled("none", Style::default().fg(Color::Gray))]));
+    }
+    for (i, (harvest, income)) in summary.harvests.iter().enumerate() {
+        lines.push(Line::from(vec![
+            label(if i == 0 { "Harvests:" } else { "" }),
+            Span::styled(format!("{} ", game_state.locale.harvest_name(harvest)), Style::default().fg(Color::Yellow)),
+            change_span(*income, true),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        label("Hay:"),
+        Span::styled(format!("{}/4 cuttings this year", summary.hay_cuttings), Style::default().fg(Color::Yellow)),
+    ]));
+    let changes = Paragraph::new(Text::from(lines));
+
+    let controls = Paragraph::new("Enter/Esc: Continue")
+        .style(Style::default().fg(Color::Cyan))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(player_color(player.color)))
+            .title(format!("{}'s Turn", player.name))
+            .bg(Color::Black),
+        area
+    );
+    frame.render_widget(changes, chunks[0]);
+    frame.render_widget(controls, chunks[1]);
+}
@@ -0,0 +1,109 @@
+// src/wasm.rs
+// wasm-bindgen exports so a browser frontend can drive a game.
+//
+// The web UI owns rendering and input; it creates a `WasmGame`, submits
+// actions (roll, exercise an option, end turn) and reads back player stats
+// and log lines after each call.
+
+use wasm_bindgen::prelude::*;
+use crate::config::GameRules;
+use crate::game::{GameEngine, GameError};
+
+fn to_js(err: GameError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+#[wasm_bindgen]
+pub struct WasmGame {
+    engine: GameEngine,
+}
+
+#[wasm_bindgen]
+impl WasmGame {
+    /// Starts a game with default rules. The seed makes the whole game reproducible.
+    #[wasm_bindgen(constructor)]
+    pub fn new(player_names: Vec<String>, seed: u64) -> Result<WasmGame, JsError> {
+        let engine = GameEngine::with_seed(&player_names, GameRules::default(), seed).map_err(to_js)?;
+        Ok(WasmGame { engine })
+    }
+
+    /// Starts a game with house rules given as `rules.toml` contents.
+    #[wasm_bindgen(js_name = withRules)]
+    pub fn with_rules(player_names: Vec<String>, seed: u64, rules_toml: &str) -> Result<WasmGame, JsError> {
+        let rules = GameRules::from_toml(rules_toml).map_err(|e| JsError::new(&e.to_string()))?;
+        let engine = GameEngine::with_seed(&player_names, rules, seed).map_err(to_js)?;
+        Ok(WasmGame { engine })
+    }
+
+    #[wasm_bindgen(js_name = currentPlayer)]
+    pub fn current_player(&self) -> usize {
+        self.engine.current_player_id()
+    }
+
+    /// Rolls for the current player and returns the turn's log lines
+    /// (the first line reports the roll).
+    pub fn roll(&mut self) -> Result<Vec<String>, JsError> {
+        let (roll, mut logs) = self.engine.roll().map_err(to_js)?;
+        logs.insert(0, format!("Rolled a {}", roll));
+        Ok(logs)
+    }
+
+    /// Plays the current player's turn with a roll chosen by the frontend.
+    #[wasm_bindgen(js_name = playTurn)]
+    pub fn play_turn(&mut self, roll: u32) -> Result<Vec<String>, JsError> {
+        self.engine.play_turn(roll).map_err(to_js)
+    }
+
+    /// Exercises an Option to Buy card from the current player's hand.
+    #[wasm_bindgen(js_name = exerciseOption)]
+    pub fn exercise_option(&mut self, card_id: usize, confirm_loan: bool) -> Result<(), JsError> {
+        let player_id = self.engine.current_player_id();
+        self.engine.state_mut().exercise_option_to_buy(player_id, card_id, confirm_loan).map_err(to_js)
+    }
+
+    #[wasm_bindgen(js_name = endTurn)]
+    pub fn end_turn(&mut self) {
+        self.engine.end_turn();
+    }
+
+    /// Player IDs in turn order.
+    #[wasm_bindgen(js_name = turnOrder)]
+    pub fn turn_order(&self) -> Vec<usize> {
+        self.engine.state().turn_order.clone()
+    }
+
+    #[wasm_bindgen(js_name = playerName)]
+    pub fn player_name(&self, player_id: usize) -> Option<String> {
+        self.engine.state().players.get(player_id).map(|p| p.name.clone())
+    }
+
+    /// Dollars, as a plain JS number rather than a BigInt.
+    pub fn cash(&self, player_id: usize) -> Option<f64> {
+        self.engine.state().players.get(player_id).map(|p| p.cash.dollars() as f64)
+    }
+
+    pub fn debt(&self, player_id: usize) -> Option<f64> {
+        self.engine.state().players.get(player_id).map(|p| p.debt.dollars() as f64)
+    }
+
+    #[wasm_bindgen(js_name = netWorth)]
+    pub fn net_worth(&self, player_id: usize) -> Option<f64> {
+        self.engine.state().players.get(player_id).map(|p| p.net_worth.dollars() as f64)
+    }
+
+    pub fn position(&self, player_id: usize) -> Option<usize> {
+        self.engine.state().players.get(player_id).map(|p| p.position)
+    }
+
+    /// IDs of the Option to Buy cards in a player's hand.
+    #[wasm_bindgen(js_name = handCardIds)]
+    pub fn hand_card_ids(&self, player_id: usize) -> Vec<usize> {
+        self.engine.state().players.get(player_id)
+            .map(|p| p.hand.iter().map(|c| c.id).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn winner(&self) -> Option<usize> {
+        self.engine.winner()
+    }
+}